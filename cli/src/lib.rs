@@ -116,6 +116,8 @@ pub enum StartError {
     ListenOsSignal,
     #[error("Unable to start Torii (Iroha HTTP API Gateway)")]
     StartTorii,
+    #[error("Block bodies were pruned, but the state snapshot covering them could not be loaded")]
+    PrunedBlocksWithoutSnapshot,
 }
 
 struct NetworkRelay {
@@ -288,6 +290,11 @@ impl Iroha<ToriiNotStarted> {
                 live_query_store_handle.clone(),
             )
         });
+        // Replaying blocks requires at least the body of the latest block in the state
+        let pruned_height = kura.pruned_height();
+        if pruned_height > 0 && state.view().height() <= pruned_height {
+            return Err(Report::new(StartError::PrunedBlocksWithoutSnapshot));
+        }
         let state = Arc::new(state);
 
         let queue = Arc::new(Queue::from_config(config.queue, events_sender.clone()));
@@ -319,6 +326,10 @@ impl Iroha<ToriiNotStarted> {
             sumeragi_metrics: SumeragiMetrics {
                 dropped_messages: metrics_reporter.metrics().dropped_messages.clone(),
                 view_changes: metrics_reporter.metrics().view_changes.clone(),
+                view_changes_total: metrics_reporter.metrics().view_changes_total.clone(),
+                leader: metrics_reporter.metrics().leader.clone(),
+                proxy_tail_latency_ms: metrics_reporter.metrics().proxy_tail_latency_ms.clone(),
+                rejected_proposals: metrics_reporter.metrics().rejected_proposals.clone(),
             },
         };
         // Starting Sumeragi requires no async context enabled
@@ -368,6 +379,7 @@ impl Iroha<ToriiNotStarted> {
 
         let torii = Torii::new(
             config.common.chain_id.clone(),
+            config.common.key_pair.clone(),
             kiso.clone(),
             config.torii,
             Arc::clone(&queue),
@@ -618,6 +630,8 @@ pub enum ConfigError {
     SameNetworkAndToriiAddrs,
     #[error("Invalid directory path found")]
     InvalidDirPath,
+    #[error("Block bodies are pruned, but state snapshots are not created")]
+    PruningWithoutSnapshots,
     #[error("Network error: cannot listen to address `{addr}`")]
     CannotBindAddress { addr: SocketAddr },
 }
@@ -698,6 +712,23 @@ fn validate_config(config: &Config, submit_genesis: bool) -> Result<(), ConfigEr
         ").attach_printable(config.sumeragi.trusted_peers.clone().into_attachment().display_as_debug()));
     }
 
+    if config.kura.blocks_retention.is_some()
+        && !matches!(
+            config.snapshot.mode,
+            iroha_config::snapshot::Mode::ReadWrite
+        )
+    {
+        emitter.emit(
+            Report::new(ConfigError::PruningWithoutSnapshots).attach_printable(
+                "\
+            Reason: `kura.blocks_retention` is set, so bodies of old blocks are discarded,\n\
+            and the state can only be restored from a snapshot on restart.\n\
+            Either set `snapshot.mode` to `read_write`, or unset `kura.blocks_retention`.\
+        ",
+            ),
+        );
+    }
+
     if config.network.address.value() == config.torii.address.value() {
         emitter.emit(
             Report::new(ConfigError::SameNetworkAndToriiAddrs)
@@ -5,7 +5,7 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     marker::PhantomData,
-    num::{NonZeroU32, NonZeroU64},
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
     thread,
     time::Duration,
 };
@@ -16,26 +16,26 @@ use futures_util::StreamExt;
 use http_default::{AsyncWebSocketStream, WebSocketStream};
 pub use iroha_config::client_api::ConfigDTO;
 use iroha_data_model::{
-    events::pipeline::{
-        BlockEventFilter, BlockStatus, PipelineEventBox, PipelineEventFilterBox,
-        TransactionEventFilter, TransactionStatus,
-    },
+    events::pipeline::{PipelineEventBox, TransactionEventFilter, TransactionStatus},
     query::QueryOutputBox,
 };
 use iroha_logger::prelude::*;
 use iroha_telemetry::metrics::Status;
-use iroha_torii_const::uri as torii_uri;
+use iroha_torii_const::{header as torii_header, uri as torii_uri};
 use iroha_version::prelude::*;
-use parity_scale_codec::DecodeAll;
+use parity_scale_codec::{DecodeAll, Encode as _};
 use rand::Rng;
 use url::Url;
 
-use self::{blocks_api::AsyncBlockStream, events_api::AsyncEventStream};
+use self::{
+    blocks_api::{AsyncBlockStream, AsyncBlockSummaryStream},
+    events_api::AsyncEventStream,
+};
 use crate::{
     config::Config,
     crypto::{HashOf, KeyPair},
     data_model::{
-        block::SignedBlock,
+        block::{stream::BlockSummary, SignedBlock},
         isi::Instruction,
         prelude::*,
         query::{predicate::PredicateBox, Pagination, Query, Sorting},
@@ -109,7 +109,8 @@ where
                 | StatusCode::UNAUTHORIZED
                 | StatusCode::FORBIDDEN
                 | StatusCode::NOT_FOUND
-                | StatusCode::UNPROCESSABLE_ENTITY => Err(ValidationFail::decode_all(
+                | StatusCode::UNPROCESSABLE_ENTITY
+                | StatusCode::TOO_MANY_REQUESTS => Err(ValidationFail::decode_all(
                     &mut resp.body().as_ref(),
                 )
                     .map_or_else(
@@ -460,6 +461,7 @@ impl Client {
         let mut tx_builder = match instructions.into() {
             Executable::Instructions(instructions) => tx_builder.with_instructions(instructions),
             Executable::Wasm(wasm) => tx_builder.with_wasm(wasm),
+            code @ Executable::WasmCode(_) => tx_builder.with_executable(code),
         };
 
         if let Some(transaction_ttl) = self.transaction_ttl {
@@ -558,6 +560,68 @@ impl Client {
         Ok(hash)
     }
 
+    /// Submit a prebuilt transaction under an idempotency key.
+    /// Peer accepts only the first transaction submitted under the key within its
+    /// idempotency window and returns the hash of that transaction for resubmissions,
+    /// so a transaction rebuilt after e.g. a network timeout isn't executed twice.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub fn submit_transaction_with_idempotency_key(
+        &self,
+        transaction: &SignedTransaction,
+        idempotency_key: &str,
+    ) -> Result<HashOf<SignedTransaction>> {
+        iroha_logger::trace!(tx=?transaction, idempotency_key, "Submitting");
+        let (req, hash) = self.prepare_transaction_request::<DefaultRequestBuilder>(transaction);
+        let response = req
+            .header(torii_header::IDEMPOTENCY_KEY, idempotency_key)
+            .build()?
+            .send()
+            .wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
+        TransactionResponseHandler::handle(&response)?;
+        DecodeAll::decode_all(&mut response.body().as_slice())
+            .wrap_err("Failed to decode hash of the transaction submitted under idempotency key")
+    }
+
+    /// Submit a prebuilt transaction too large for a single request in chunks of at most
+    /// `chunk_len` bytes. Peer reassembles the chunks and handles them as one transaction.
+    ///
+    /// # Errors
+    /// Fails if sending any of the chunks to peer fails or if it responds with error
+    pub fn submit_transaction_in_chunks(
+        &self,
+        transaction: &SignedTransaction,
+        chunk_len: NonZeroUsize,
+    ) -> Result<HashOf<SignedTransaction>> {
+        iroha_logger::trace!(tx=?transaction, "Submitting in chunks");
+        let hash = transaction.hash();
+        for chunk in TransactionChunk::split(transaction, chunk_len) {
+            let resp = DefaultRequestBuilder::new(
+                HttpMethod::POST,
+                self.torii_url
+                    .join(torii_uri::TRANSACTION_CHUNK)
+                    .expect("Valid URI"),
+            )
+            .headers(&self.headers)
+            .body(chunk.encode())
+            .build()?
+            .send()
+            .wrap_err_with(|| format!("Failed to send chunk of transaction with hash {hash:?}"))?;
+
+            if resp.status() != StatusCode::OK {
+                return Err(eyre!(
+                    "Failed to submit transaction chunk {} of {} with HTTP status: {}. {}",
+                    chunk.index(),
+                    chunk.count(),
+                    resp.status(),
+                    std::str::from_utf8(resp.body()).unwrap_or(""),
+                ));
+            }
+        }
+        Ok(hash)
+    }
+
     /// Submit the prebuilt transaction and wait until it is either rejected or committed.
     /// If rejected, return the rejection reason.
     ///
@@ -604,12 +668,7 @@ impl Client {
 
         rt.block_on(async {
             let mut event_iterator = {
-                let filters = vec![
-                    TransactionEventFilter::default().for_hash(hash).into(),
-                    PipelineEventFilterBox::from(
-                        BlockEventFilter::default().for_status(BlockStatus::Applied),
-                    ),
-                ];
+                let filters = vec![TransactionEventFilter::default().for_hash(hash)];
 
                 let event_iterator_result =
                     tokio::time::timeout_at(deadline, self.listen_for_events_async(filters))
@@ -637,30 +696,15 @@ impl Client {
         event_iterator: &mut AsyncEventStream,
         hash: HashOf<SignedTransaction>,
     ) -> Result<HashOf<SignedTransaction>> {
-        let mut block_height = None;
-
         while let Some(event) = event_iterator.next().await {
-            if let EventBox::Pipeline(this_event) = event? {
-                match this_event {
-                    PipelineEventBox::Transaction(transaction_event) => {
-                        match transaction_event.status() {
-                            TransactionStatus::Queued => {}
-                            TransactionStatus::Approved => {
-                                block_height = transaction_event.block_height();
-                            }
-                            TransactionStatus::Rejected(reason) => {
-                                return Err((Clone::clone(&**reason)).into());
-                            }
-                            TransactionStatus::Expired => return Err(eyre!("Transaction expired")),
-                        }
-                    }
-                    PipelineEventBox::Block(block_event) => {
-                        if Some(block_event.header().height()) == block_height {
-                            if let BlockStatus::Applied = block_event.status() {
-                                return Ok(hash);
-                            }
-                        }
+            if let EventBox::Pipeline(PipelineEventBox::Transaction(transaction_event)) = event? {
+                match transaction_event.status() {
+                    TransactionStatus::Queued | TransactionStatus::Approved => {}
+                    TransactionStatus::Rejected(reason) => {
+                        return Err((Clone::clone(&**reason)).into());
                     }
+                    TransactionStatus::Expired => return Err(eyre!("Transaction expired")),
+                    TransactionStatus::Committed => return Ok(hash),
                 }
             }
         }
@@ -670,6 +714,80 @@ impl Client {
         ))
     }
 
+    /// Execute instructions against the current state of the peer without committing them.
+    /// Returns the events the transaction would emit and its rejection reason, if any.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or the peer doesn't accept the transaction
+    pub fn simulate(
+        &self,
+        instructions: impl IntoIterator<Item = impl Instruction>,
+    ) -> Result<TransactionSimulation> {
+        self.simulate_transaction(&self.build_transaction(instructions, UnlimitedMetadata::new()))
+    }
+
+    /// Execute a prebuilt transaction against the current state of the peer without committing it.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or the peer doesn't accept the transaction
+    pub fn simulate_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionSimulation> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            self.torii_url
+                .join(torii_uri::SIMULATE_TRANSACTION)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers)
+        .body(transaction.encode_versioned())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to simulate transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Check whether `account_id` is permitted to execute `instruction` against the current state of the peer.
+    /// Returns the reason the instruction is rejected, if it is, and the permissions of the account.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or this client isn't allowed to read the permissions of `account_id`
+    pub fn can_account_execute(
+        &self,
+        account_id: AccountId,
+        instruction: impl Instruction,
+    ) -> Result<ExecutionVerdict> {
+        let request = CanAccountExecute::new(account_id, instruction)
+            .sign(self.account_id.clone(), &self.key_pair);
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            self.torii_url
+                .join(torii_uri::CAN_ACCOUNT_EXECUTE)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers)
+        .body(request.encode())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to check execution with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
     /// Lower-level Instructions API entry point.
     ///
     /// Returns a tuple with a provided request builder, a hash of the transaction, and a response handler.
@@ -876,6 +994,45 @@ impl Client {
         self.build_query(request).execute()
     }
 
+    /// Query the peer and get its response signed by the peer, so that the response can be
+    /// archived as evidence. Only the first batch of the query output is included in the response.
+    ///
+    /// # Errors
+    /// Fails if sending request fails, the query fails or the peer signature is invalid
+    pub fn request_signed<R: Query>(&self, request: R) -> QueryResult<SignedQueryResponse> {
+        let query = self.sign_query(ClientQueryBuilder::new(request, self.account_id.clone()));
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            self.torii_url
+                .join(torii_uri::SIGNED_QUERY)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers)
+        .body(query.encode())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(
+                ValidationFail::decode_all(&mut resp.body().as_slice()).map_or_else(
+                    |_| {
+                        ResponseReport::with_msg("Query failed", &resp)
+                            .unwrap_or_else(core::convert::identity)
+                            .into()
+                    },
+                    ClientQueryError::Validation,
+                ),
+            );
+        }
+        let response = SignedQueryResponse::decode_all(&mut resp.body().as_slice())
+            .wrap_err("Failed to decode signed query response")?;
+        response
+            .verify()
+            .wrap_err("Query response isn't signed by the peer")?;
+
+        Ok(response)
+    }
+
     /// Query API entry point using cursor.
     ///
     /// You should probably not use this function directly.
@@ -931,6 +1088,80 @@ impl Client {
         events_api::EventIterator::new(self.events_handler(event_filters)?)
     }
 
+    /// Connect (through `WebSocket`) to listen for the status changes of the transaction with the given hash.
+    ///
+    /// The stream ends once the transaction is committed or expires.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::listen_for_events`]
+    pub fn listen_for_transaction_status(
+        &self,
+        hash: HashOf<SignedTransaction>,
+    ) -> Result<impl Iterator<Item = Result<TransactionStatus>>> {
+        let mut finished = false;
+        let events = self.listen_for_events([TransactionEventFilter::default().for_hash(hash)])?;
+
+        Ok(events.map_while(move |event| {
+            if finished {
+                return None;
+            }
+            let status = event.and_then(|event| match event {
+                EventBox::Pipeline(PipelineEventBox::Transaction(transaction_event)) => {
+                    Ok(transaction_event.status().clone())
+                }
+                event => Err(eyre!("Unexpected event: {event:?}")),
+            });
+            finished = matches!(
+                status,
+                Ok(TransactionStatus::Committed | TransactionStatus::Expired) | Err(_)
+            );
+            Some(status)
+        }))
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events,
+    /// receiving first the pipeline events of the blocks committed starting from `height`.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::events_handler`]
+    /// - Forwards from [`events_api::EventIterator::new`]
+    pub fn replay_events(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+        height: NonZeroU64,
+    ) -> Result<impl Iterator<Item = Result<EventBox>>> {
+        events_api::EventIterator::new(
+            self.events_handler(event_filters)?
+                .from_block_height(height),
+        )
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events
+    /// with acknowledged delivery.
+    ///
+    /// The peer keeps the events which weren't acknowledged, including the ones emitted
+    /// while disconnected, and sends them again when listening with the same `delivery` id.
+    /// An event is acknowledged once the next one is requested from the iterator.
+    /// The subscription belongs to the account of the client, which signs the `delivery`.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::events_handler`]
+    /// - Forwards from [`events_api::AcknowledgedEventIterator::new`]
+    pub fn listen_for_events_acknowledged(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+        delivery: AcknowledgedDelivery,
+    ) -> Result<impl Iterator<Item = Result<EventBox>>> {
+        events_api::AcknowledgedEventIterator::new(
+            self.events_handler(event_filters)?
+                .acknowledged(SignedAcknowledgedDelivery::new(
+                    self.account_id.clone(),
+                    delivery,
+                    &self.key_pair,
+                )),
+        )
+    }
+
     /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
     ///
     /// # Errors
@@ -982,6 +1213,31 @@ impl Client {
         blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?).await
     }
 
+    /// Connect (through `WebSocket`) to listen for the summaries of `Iroha` blocks,
+    /// i.e. their headers and transaction hashes
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::blocks_handler`]
+    /// - Forwards from [`blocks_api::BlockSummaryIterator::new`]
+    pub fn listen_for_block_summaries(
+        &self,
+        height: NonZeroU64,
+    ) -> Result<impl Iterator<Item = Result<BlockSummary>>> {
+        blocks_api::BlockSummaryIterator::new(self.blocks_handler(height)?.summaries())
+    }
+
+    /// Connect asynchronously (through `WebSocket`) to listen for the summaries of `Iroha` blocks
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::blocks_handler`]
+    /// - Forwards from [`blocks_api::AsyncBlockSummaryStream::new`]
+    pub async fn listen_for_block_summaries_async(
+        &self,
+        height: NonZeroU64,
+    ) -> Result<AsyncBlockSummaryStream> {
+        blocks_api::AsyncBlockSummaryStream::new(self.blocks_handler(height)?.summaries()).await
+    }
+
     /// Construct a handler for Blocks API. With this handler you can use any WS client you want.
     ///
     /// # Errors
@@ -997,6 +1253,43 @@ impl Client {
         )
     }
 
+    /// Connect (through `WebSocket`) to receive the output of the query batch by batch.
+    ///
+    /// Each item is a batch of at most `fetch_size` elements. The stream ends after the last batch.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::queries_stream_handler`]
+    /// - Forwards from [`queries_api::QueryBatchIterator::new`]
+    pub fn stream_query<R: Query>(
+        &self,
+        request: R,
+        fetch_size: FetchSize,
+    ) -> Result<impl Iterator<Item = Result<QueryOutputBox>>> {
+        queries_api::QueryBatchIterator::new(self.queries_stream_handler(request, fetch_size)?)
+    }
+
+    /// Construct a handler for query streaming API. With this handler you can use any WS client you want.
+    ///
+    /// # Errors
+    /// - if handler construction fails
+    #[inline]
+    pub fn queries_stream_handler<R: Query>(
+        &self,
+        request: R,
+        fetch_size: FetchSize,
+    ) -> Result<queries_api::flow::Init> {
+        let query_builder =
+            ClientQueryBuilder::new(request, self.account_id.clone()).with_fetch_size(fetch_size);
+
+        queries_api::flow::Init::new(
+            self.sign_query(query_builder),
+            self.headers.clone(),
+            self.torii_url
+                .join(torii_uri::QUERY_STREAM)
+                .expect("Valid URI"),
+        )
+    }
+
     /// Get value of config on peer
     ///
     /// # Errors
@@ -1023,6 +1316,148 @@ impl Client {
         serde_json::from_slice(resp.body()).wrap_err("Failed to decode body")
     }
 
+    /// Get transactions of multisignature accounts which wait for more signatures on peer,
+    /// optionally only those of `authority`.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_pending_transactions(
+        &self,
+        authority: Option<&AccountId>,
+    ) -> Result<Vec<SignedTransaction>> {
+        let mut req = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            self.torii_url
+                .join(torii_uri::PENDING_TRANSACTIONS)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers);
+        if let Some(authority) = authority {
+            req = req.param("authority", authority);
+        }
+        let resp = req.build()?.send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get pending transactions with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Get transactions waiting in the queue of peer, optionally only those of `authority`.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_queued_transactions(
+        &self,
+        authority: Option<&AccountId>,
+    ) -> Result<Vec<SignedTransaction>> {
+        let mut req = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            self.torii_url
+                .join(torii_uri::QUEUED_TRANSACTIONS)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers);
+        if let Some(authority) = authority {
+            req = req.param("authority", authority);
+        }
+        let resp = req.build()?.send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get queued transactions with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Get the number of transactions waiting in the queue of peer, overall and per authority,
+    /// along with the creation time of the oldest of them.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_queue_status(&self) -> Result<QueueStatus> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            self.torii_url
+                .join(torii_uri::QUEUE_STATUS)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers)
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get queue status with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Remove a transaction submitted by this client from the queue of peers
+    /// before it is put into a block.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or the transaction isn't in the queue of peer
+    pub fn cancel_transaction(&self, hash: HashOf<SignedTransaction>) -> Result<()> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            self.torii_url
+                .join(torii_uri::CANCEL_TRANSACTION)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers)
+        .body(CancelTransaction::new(hash, &self.key_pair).encode())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to cancel transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sign a transaction of a multisignature account which waits for more signatures
+    /// on peer, as obtained with [`Self::get_pending_transactions`].
+    ///
+    /// # Errors
+    /// Fails if sending request fails or the peer doesn't accept the signature
+    pub fn sign_pending_transaction(&self, transaction: &SignedTransaction) -> Result<()> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            self.torii_url
+                .join(torii_uri::PENDING_TRANSACTIONS)
+                .expect("Valid URI"),
+        )
+        .headers(&self.headers)
+        .param("hash", &transaction.payload_hash())
+        .body(transaction.sign_payload(&self.key_pair).encode())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to sign pending transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Ok(())
+    }
+
     /// Send a request to change the configuration of a specified field.
     ///
     /// # Errors
@@ -1123,6 +1558,15 @@ pub mod stream_api {
                 handler: next_handler,
             })
         }
+
+        /// Send a binary `message` to the stream.
+        ///
+        /// # Errors
+        /// Fails if sending fails
+        pub(super) fn send(&mut self, message: Vec<u8>) -> Result<()> {
+            self.stream.send(WebSocketMessage::Binary(message))?;
+            Ok(())
+        }
     }
 
     impl<E: Events> Iterator for SyncIterator<E> {
@@ -1262,6 +1706,8 @@ pub mod events_api {
             headers: HashMap<String, String>,
             /// Event filter
             filters: Vec<EventFilterBox>,
+            /// Height of the block to replay the events from
+            from_block_height: Option<NonZeroU64>,
         }
 
         impl Init {
@@ -1279,22 +1725,69 @@ pub mod events_api {
                     url: transform_ws_url(url)?,
                     headers,
                     filters,
+                    from_block_height: None,
                 })
             }
-        }
 
-        impl<R: RequestBuilder> FlowInit<R> for Init {
-            type Next = Events;
+            /// Replay the pipeline events of the blocks committed starting from `height`
+            /// before the live events.
+            #[must_use]
+            pub fn from_block_height(mut self, height: NonZeroU64) -> Self {
+                self.from_block_height = Some(height);
+                self
+            }
 
-            fn init(self) -> InitData<R, Self::Next> {
+            /// Deliver the events with acknowledgements, resuming the subscription
+            /// with the id of `delivery` if there is one.
+            pub fn acknowledged(self, delivery: SignedAcknowledgedDelivery) -> AcknowledgedInit {
+                AcknowledgedInit {
+                    init: self,
+                    delivery,
+                }
+            }
+
+            fn request<R: RequestBuilder>(
+                self,
+                acknowledged_delivery: Option<SignedAcknowledgedDelivery>,
+            ) -> (R, Vec<u8>) {
                 let Self {
                     url,
                     headers,
                     filters,
+                    from_block_height,
                 } = self;
 
-                let msg = EventSubscriptionRequest::new(filters).encode();
-                InitData::new(R::new(HttpMethod::GET, url).headers(headers), msg, Events)
+                let msg = EventSubscriptionRequest::new(
+                    filters,
+                    from_block_height,
+                    acknowledged_delivery,
+                )
+                .encode();
+                (R::new(HttpMethod::GET, url).headers(headers), msg)
+            }
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for Init {
+            type Next = Events;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let (req, msg) = self.request(None);
+                InitData::new(req, msg, Events)
+            }
+        }
+
+        /// Initialization struct for Events API flow with acknowledged delivery.
+        pub struct AcknowledgedInit {
+            init: Init,
+            delivery: SignedAcknowledgedDelivery,
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for AcknowledgedInit {
+            type Next = AcknowledgedEvents;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let (req, msg) = self.init.request(Some(self.delivery));
+                InitData::new(req, msg, AcknowledgedEvents)
             }
         }
 
@@ -1310,11 +1803,66 @@ pub mod events_api {
                 Ok(event_socket_message.into())
             }
         }
+
+        /// Events handler for Events API flow with acknowledged delivery
+        #[derive(Debug, Copy, Clone)]
+        pub struct AcknowledgedEvents;
+
+        impl FlowEvents for AcknowledgedEvents {
+            type Event = AcknowledgedEventMessage;
+
+            fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                Ok(AcknowledgedEventMessage::decode_all(
+                    &mut message.as_slice(),
+                )?)
+            }
+        }
     }
 
     /// Iterator for getting events from the `WebSocket` stream.
     pub(super) type EventIterator = stream_api::SyncIterator<flow::Events>;
 
+    /// Iterator for getting events with acknowledged delivery from the `WebSocket` stream.
+    ///
+    /// An event is acknowledged once the next one is requested, so the event being
+    /// processed when the connection is lost is delivered again on resubscription.
+    pub(super) struct AcknowledgedEventIterator {
+        messages: stream_api::SyncIterator<flow::AcknowledgedEvents>,
+        last_sequence_number: Option<u64>,
+    }
+
+    impl AcknowledgedEventIterator {
+        /// Construct [`Self`] and send the subscription request.
+        ///
+        /// # Errors
+        /// Forwards from [`stream_api::SyncIterator::new`]
+        pub(super) fn new(handler: flow::AcknowledgedInit) -> Result<Self> {
+            Ok(Self {
+                messages: stream_api::SyncIterator::new(handler)?,
+                last_sequence_number: None,
+            })
+        }
+    }
+
+    impl Iterator for AcknowledgedEventIterator {
+        type Item = Result<EventBox>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if let Some(sequence_number) = self.last_sequence_number.take() {
+                let acknowledgement = EventAcknowledgement::new(sequence_number);
+                if let Err(error) = self.messages.send(acknowledgement.encode()) {
+                    return Some(Err(error));
+                }
+            }
+
+            let message = self.messages.next()?;
+            Some(message.map(|message| {
+                self.last_sequence_number = Some(message.sequence_number);
+                message.event
+            }))
+        }
+    }
+
     /// Async stream for getting events from the `WebSocket` stream.
     pub type AsyncEventStream = stream_api::AsyncStream<flow::Events>;
 }
@@ -1360,20 +1908,42 @@ mod blocks_api {
                     url: transform_ws_url(url)?,
                 })
             }
-        }
 
-        impl<R: RequestBuilder> FlowInit<R> for Init {
-            type Next = Events;
+            /// Receive only the summaries of the blocks instead of the blocks themselves.
+            pub fn summaries(self) -> SummariesInit {
+                SummariesInit(self)
+            }
 
-            fn init(self) -> InitData<R, Self::Next> {
+            fn request<R: RequestBuilder>(self, full_blocks: bool) -> (R, Vec<u8>) {
                 let Self {
                     height,
                     headers,
                     url,
                 } = self;
 
-                let msg = BlockSubscriptionRequest::new(height).encode();
-                InitData::new(R::new(HttpMethod::GET, url).headers(headers), msg, Events)
+                let msg = BlockSubscriptionRequest::new(height, full_blocks).encode();
+                (R::new(HttpMethod::GET, url).headers(headers), msg)
+            }
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for Init {
+            type Next = Events;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let (req, msg) = self.request(true);
+                InitData::new(req, msg, Events)
+            }
+        }
+
+        /// Initialization struct for Blocks API flow streaming block summaries.
+        pub struct SummariesInit(Init);
+
+        impl<R: RequestBuilder> FlowInit<R> for SummariesInit {
+            type Next = Summaries;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let (req, msg) = self.0.request(false);
+                InitData::new(req, msg, Summaries)
             }
         }
 
@@ -1388,6 +1958,18 @@ mod blocks_api {
                 Ok(BlockMessage::decode_all(&mut message.as_slice()).map(Into::into)?)
             }
         }
+
+        /// Block summaries handler for Blocks API flow
+        #[derive(Debug, Copy, Clone)]
+        pub struct Summaries;
+
+        impl FlowEvents for Summaries {
+            type Event = BlockSummary;
+
+            fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                Ok(BlockSummaryMessage::decode_all(&mut message.as_slice()).map(Into::into)?)
+            }
+        }
     }
 
     /// Iterator for getting blocks from the `WebSocket` stream.
@@ -1395,6 +1977,93 @@ mod blocks_api {
 
     /// Async stream for getting blocks from the `WebSocket` stream.
     pub type AsyncBlockStream = stream_api::AsyncStream<flow::Events>;
+
+    /// Iterator for getting block summaries from the `WebSocket` stream.
+    pub(super) type BlockSummaryIterator = stream_api::SyncIterator<flow::Summaries>;
+
+    /// Async stream for getting block summaries from the `WebSocket` stream.
+    pub type AsyncBlockSummaryStream = stream_api::AsyncStream<flow::Summaries>;
+}
+
+mod queries_api {
+    use super::*;
+    use crate::http::ws::{
+        conn_flow::{Events as FlowEvents, Init as FlowInit, InitData},
+        transform_ws_url,
+    };
+
+    /// Query streaming API flow. For documentation and usage examples, refer to [`crate::http::ws::conn_flow`].
+    pub mod flow {
+        use super::*;
+        use crate::data_model::query::stream::QueryStreamMessage;
+
+        /// Initialization struct for query streaming API flow.
+        pub struct Init {
+            /// Signed query whose output is streamed
+            query: SignedQuery,
+            /// HTTP request headers
+            headers: HashMap<String, String>,
+            /// TORII URL
+            url: Url,
+        }
+
+        impl Init {
+            /// Construct new item with provided query, headers and url.
+            ///
+            /// # Errors
+            /// If [`transform_ws_url`] fails.
+            #[inline]
+            pub(in super::super) fn new(
+                query: SignedQuery,
+                headers: HashMap<String, String>,
+                url: Url,
+            ) -> Result<Self> {
+                Ok(Self {
+                    query,
+                    headers,
+                    url: transform_ws_url(url)?,
+                })
+            }
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for Init {
+            type Next = Events;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let Self {
+                    query,
+                    headers,
+                    url,
+                } = self;
+
+                let msg = query.encode();
+                InitData::new(R::new(HttpMethod::GET, url).headers(headers), msg, Events)
+            }
+        }
+
+        /// Events handler for query streaming API flow
+        #[derive(Debug, Copy, Clone)]
+        pub struct Events;
+
+        impl FlowEvents for Events {
+            type Event = QueryOutputBox;
+
+            fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                match QueryStreamMessage::decode_all(&mut message.as_slice())? {
+                    QueryStreamMessage::Batch(response) => {
+                        let (batch, _cursor) = response.into();
+                        Ok(batch)
+                    }
+                    QueryStreamMessage::Error(error) => {
+                        Err(ClientQueryError::Validation(error).into())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Iterator for getting query output batches from the `WebSocket` stream.
+    pub(super) type QueryBatchIterator = stream_api::SyncIterator<flow::Events>;
 }
 
 pub mod account {
@@ -1411,10 +2080,20 @@ pub mod account {
         FindAccountById::new(account_id)
     }
 
+    /// Construct a query to get account by alias
+    pub fn by_alias(alias: AccountAlias) -> FindAccountByAlias {
+        FindAccountByAlias::new(alias)
+    }
+
     /// Construct a query to get all accounts containing specified asset
     pub fn all_with_asset(asset_definition_id: AssetDefinitionId) -> FindAccountsWithAsset {
         FindAccountsWithAsset::new(asset_definition_id)
     }
+
+    /// Construct a query to get the number of accounts in a domain
+    pub fn count_by_domain_id(domain_id: DomainId) -> FindAccountCountByDomainId {
+        FindAccountCountByDomainId::new(domain_id)
+    }
 }
 
 pub mod asset {
@@ -1441,10 +2120,29 @@ pub mod asset {
         FindAssetsByAccountId::new(account_id)
     }
 
+    /// Construct a query to get all non-fungible token assets by account id
+    pub fn nfts_by_account_id(account_id: AccountId) -> FindNftsByAccountId {
+        FindNftsByAccountId::new(account_id)
+    }
+
     /// Construct a query to get an asset by its id
     pub fn by_id(asset_id: AssetId) -> FindAssetById {
         FindAssetById::new(asset_id)
     }
+
+    /// Construct a query to get the total quantity of an asset over all of its holders
+    pub fn total_quantity(
+        asset_definition_id: AssetDefinitionId,
+    ) -> FindTotalAssetQuantityByAssetDefinitionId {
+        FindTotalAssetQuantityByAssetDefinitionId::new(asset_definition_id)
+    }
+
+    /// Construct a query to get the number of holders of an asset
+    pub fn count_by_definition_id(
+        asset_definition_id: AssetDefinitionId,
+    ) -> FindAssetCountByAssetDefinitionId {
+        FindAssetCountByAssetDefinitionId::new(asset_definition_id)
+    }
 }
 
 pub mod block {
@@ -1466,6 +2164,21 @@ pub mod block {
     pub fn header_by_hash(hash: HashOf<SignedBlock>) -> FindBlockHeaderByHash {
         FindBlockHeaderByHash::new(hash)
     }
+
+    /// Construct a query to find block by hash
+    pub fn by_hash(hash: HashOf<SignedBlock>) -> FindBlockByHash {
+        FindBlockByHash::new(hash)
+    }
+
+    /// Construct a query to find block by height
+    pub fn by_height(height: NonZeroU64) -> FindBlockByHeight {
+        FindBlockByHeight::new(height)
+    }
+
+    /// Construct a query to find blocks from `start` to `end` height inclusive
+    pub fn in_range(start: NonZeroU64, end: NonZeroU64) -> FindBlocksInRange {
+        FindBlocksInRange::new(start, end)
+    }
 }
 
 pub mod domain {
@@ -1481,6 +2194,11 @@ pub mod domain {
     pub fn by_id(domain_id: DomainId) -> FindDomainById {
         FindDomainById::new(domain_id)
     }
+
+    /// Construct a query to get all sub-domains of a domain
+    pub fn descendants(domain_id: DomainId) -> FindDomainDescendants {
+        FindDomainDescendants::new(domain_id)
+    }
 }
 
 pub mod transaction {
@@ -1502,6 +2220,11 @@ pub mod transaction {
     pub fn by_hash(hash: HashOf<SignedTransaction>) -> FindTransactionByHash {
         FindTransactionByHash::new(hash)
     }
+
+    /// Construct a query to retrieve proof of inclusion of transaction by hash
+    pub fn proof_by_hash(hash: HashOf<SignedTransaction>) -> FindTransactionProofByHash {
+        FindTransactionProofByHash::new(hash)
+    }
 }
 
 pub mod trigger {
@@ -1523,6 +2246,18 @@ pub mod permission {
     pub fn by_account_id(account_id: AccountId) -> FindPermissionsByAccountId {
         FindPermissionsByAccountId::new(account_id)
     }
+
+    /// Construct a query to get the terms on which [`Permission`]
+    /// is granted to account with given [`Id`][AccountId]
+    pub fn grant_terms(account_id: AccountId, permission: Permission) -> FindPermissionGrantTerms {
+        FindPermissionGrantTerms::new(account_id, permission)
+    }
+
+    /// Construct a query to get all permissions and roles ever granted to
+    /// or revoked from account with given [`Id`][AccountId]
+    pub fn grant_history(account_id: AccountId) -> FindGrantHistoryByAccountId {
+        FindGrantHistoryByAccountId::new(account_id)
+    }
 }
 
 pub mod role {
@@ -1667,6 +2402,10 @@ mod tests {
                     )),
                 ),
                 (StatusCode::UNPROCESSABLE_ENTITY, ValidationFail::TooComplex),
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    ValidationFail::QueryFailed(QueryExecutionFail::RateLimited(100)),
+                ),
             ];
             for (status_code, err) in responses {
                 let resp = Response::builder().status(status_code).body(err.encode())?;
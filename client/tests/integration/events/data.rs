@@ -1,6 +1,6 @@
 use std::{fmt::Write as _, str::FromStr, sync::mpsc, thread};
 
-use eyre::Result;
+use eyre::{eyre, Result};
 use iroha::data_model::{prelude::*, transaction::WasmSmartContract};
 use parity_scale_codec::Encode as _;
 use serde_json::json;
@@ -286,3 +286,40 @@ fn produce_multiple_events() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn unacknowledged_events_are_delivered_again() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_305).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let event_filter = DomainEventFilter::new().for_events(DomainEventSet::Created);
+    let delivery = AcknowledgedDelivery::new("domains".to_owned(), EventOverflowPolicy::DropOldest);
+    let created_domain_id = |event: Result<EventBox>| -> Result<DomainId> {
+        let event: DataEvent = event?.try_into()?;
+        match event {
+            DataEvent::Domain(DomainEvent::Created(domain)) => Ok(domain.id().clone()),
+            event => Err(eyre!("Unexpected event: {event:?}")),
+        }
+    };
+
+    let garden_id: DomainId = "garden".parse()?;
+    let mut events =
+        client.listen_for_events_acknowledged([event_filter.clone()], delivery.clone())?;
+    client.submit_blocking(Register::domain(Domain::new(garden_id.clone())))?;
+    let event = events.next().expect("Stream closed");
+    assert_eq!(created_domain_id(event)?, garden_id);
+    // Disconnect without acknowledging the event
+    drop(events);
+
+    let meadow_id: DomainId = "meadow".parse()?;
+    client.submit_blocking(Register::domain(Domain::new(meadow_id.clone())))?;
+
+    let created = client
+        .listen_for_events_acknowledged([event_filter], delivery)?
+        .take(2)
+        .map(created_domain_id)
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(created, [garden_id, meadow_id]);
+
+    Ok(())
+}
@@ -6,17 +6,20 @@ use iroha::{
     data_model::{
         parameter::{default::MAX_TRANSACTIONS_IN_BLOCK, ParametersBuilder},
         prelude::*,
+        Level,
     },
 };
 use iroha_config::parameters::actual::Root as Config;
 use iroha_data_model::{
     events::pipeline::{
-        BlockEvent, BlockEventFilter, BlockStatus, TransactionEventFilter, TransactionStatus,
+        BlockEvent, BlockEventFilter, BlockStatus, TransactionEvent, TransactionEventFilter,
+        TransactionStatus,
     },
     isi::error::InstructionExecutionError,
     transaction::error::TransactionRejectionReason,
     ValidationFail,
 };
+use nonzero_ext::nonzero;
 use test_network::*;
 
 // Needed to re-enable ignored tests.
@@ -132,3 +135,79 @@ fn applied_block_must_be_available_in_kura() {
         .get_block_by_height(event.header().height())
         .expect("Block applied event was received earlier");
 }
+
+#[test]
+fn transaction_status_stream_must_end_with_committed() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_265).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let transaction = client.build_transaction(
+        [Fail::new("Dummy instruction".to_owned())],
+        UnlimitedMetadata::new(),
+    );
+    let statuses = client.listen_for_transaction_status(transaction.hash())?;
+    client.submit_transaction(&transaction)?;
+
+    let statuses = statuses.collect::<Result<Vec<_>>>()?;
+    assert!(matches!(
+        statuses.as_slice(),
+        [
+            TransactionStatus::Queued,
+            TransactionStatus::Rejected(_),
+            TransactionStatus::Committed
+        ]
+    ));
+    Ok(())
+}
+
+#[test]
+fn events_of_committed_blocks_are_replayed() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_300).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let transaction = client.build_transaction(
+        [Log::new(
+            Level::INFO,
+            "Committed before subscription".to_owned(),
+        )],
+        UnlimitedMetadata::new(),
+    );
+    client.submit_transaction_blocking(&transaction)?;
+
+    let statuses = client
+        .replay_events(
+            [TransactionEventFilter::default().for_hash(transaction.hash())],
+            nonzero!(1_u64),
+        )?
+        .take(2)
+        .map(|event| {
+            let event: TransactionEvent = event?.try_into()?;
+            Ok(event.status().clone())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        statuses,
+        [TransactionStatus::Approved, TransactionStatus::Committed]
+    );
+    Ok(())
+}
+
+#[test]
+fn block_summaries_are_streamed() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_310).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let transaction = client.build_transaction(
+        [Log::new(Level::INFO, "Summarized".to_owned())],
+        UnlimitedMetadata::new(),
+    );
+    client.submit_transaction_blocking(&transaction)?;
+
+    let summary = client
+        .listen_for_block_summaries(nonzero!(2_u64))?
+        .next()
+        .expect("Block is committed")?;
+    assert_eq!(summary.header().height(), 2);
+    assert_eq!(summary.transactions(), &[transaction.hash()]);
+    Ok(())
+}
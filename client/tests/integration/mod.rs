@@ -15,6 +15,8 @@ mod status_response;
 mod transfer_asset;
 mod triggers;
 mod tx_chain_id;
+mod tx_chunks;
 mod tx_history;
+mod tx_idempotency;
 mod tx_rollback;
 mod upgrade;
@@ -468,3 +468,86 @@ fn associated_permissions_removed_from_role_on_unregister() {
         .into_iter()
         .all(|token| { token != set_kv_in_domain_token }));
 }
+
+#[test]
+fn account_is_readable_only_with_permission() {
+    let chain_id = ChainId::from("0");
+
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_295).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let alice_id = ALICE_ID.clone();
+    let (mouse_id, mouse_keypair) = gen_account_in("hole");
+    let (cat_id, cat_keypair) = gen_account_in("wonderland");
+
+    // Registering mouse in a domain owned by mouse
+    let hole_id: DomainId = "hole".parse().unwrap();
+    client
+        .submit_all_blocking([
+            InstructionBox::from(Register::domain(Domain::new(hole_id.clone()))),
+            Register::account(Account::new(mouse_id.clone())).into(),
+            Register::account(Account::new(cat_id.clone())).into(),
+            Transfer::domain(alice_id, hole_id, mouse_id.clone()).into(),
+        ])
+        .expect("Failed to register mouse and cat");
+    let mut cat_client = client.clone();
+    cat_client.account_id = cat_id.clone();
+    cat_client.key_pair = cat_keypair;
+
+    let _err = cat_client
+        .request(client::account::by_id(mouse_id.clone()))
+        .expect_err("Cat shouldn't be able to read the account of mouse");
+
+    // Granting permission to cat to read the account of mouse
+    let allow_cat_to_read_account = Grant::permission(
+        Permission::new(
+            "CanReadAccount".parse().unwrap(),
+            json!({ "account_id": mouse_id }),
+        ),
+        cat_id,
+    );
+    let grant_read_access_tx = TransactionBuilder::new(chain_id, mouse_id.clone())
+        .with_instructions([allow_cat_to_read_account])
+        .sign(&mouse_keypair);
+    client
+        .submit_transaction_blocking(&grant_read_access_tx)
+        .expect("Failed to grant permission to read the account of mouse");
+
+    let account = cat_client
+        .request(client::account::by_id(mouse_id.clone()))
+        .expect("Cat should be able to read the account of mouse");
+    assert_eq!(account.id(), &mouse_id);
+}
+
+#[test]
+fn world_is_readable_only_with_permission() {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_330).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    client
+        .submit_blocking(Register::account(Account::new(mouse_id.clone())))
+        .expect("Failed to register mouse");
+    let mut mouse_client = client.clone();
+    mouse_client.account_id = mouse_id.clone();
+    mouse_client.key_pair = mouse_keypair;
+
+    let _err = mouse_client
+        .request(client::account::all())
+        .expect_err("Mouse shouldn't be able to list all accounts");
+    let _err = mouse_client
+        .request(client::asset::all())
+        .expect_err("Mouse shouldn't be able to list all assets");
+    let _err = mouse_client
+        .request(client::account::all_with_asset(
+            "rose#wonderland".parse().unwrap(),
+        ))
+        .expect_err("Mouse shouldn't be able to list holders of rose");
+
+    // Alice is granted `CanReadAllAccounts` in genesis
+    let accounts = client
+        .request(client::account::all())
+        .and_then(|accounts| accounts.collect::<QueryResult<Vec<_>>>())
+        .expect("Alice should be able to list all accounts");
+    assert!(accounts.iter().any(|account| account.id() == &mouse_id));
+}
@@ -11,7 +11,9 @@ mod account;
 mod asset;
 mod query_errors;
 mod role;
+mod signed;
 mod smart_contract;
+mod stream;
 
 #[test]
 fn too_big_fetch_size_is_not_allowed() {
@@ -0,0 +1,27 @@
+use eyre::Result;
+use iroha::{
+    client,
+    data_model::{prelude::*, query::QueryOutputBox},
+};
+use test_network::*;
+
+#[test]
+fn query_response_is_signed_by_peer() -> Result<()> {
+    let (_rt, peer, client) = <PeerBuilder>::new().with_port(11_290).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let domain_id: DomainId = "wonderland".parse()?;
+    let signed = client.request_signed(client::domain::by_id(domain_id.clone()))?;
+    signed.verify()?;
+
+    assert_eq!(signed.signature().public_key(), peer.key_pair.public_key());
+    assert!(*signed.attestation().block_height() >= 1);
+
+    let (output, _cursor) = signed.response().clone().into();
+    let QueryOutputBox::Identifiable(IdentifiableBox::Domain(domain)) = output else {
+        panic!("Domain should be returned");
+    };
+    assert_eq!(*domain.id(), domain_id);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use eyre::Result;
+use iroha::{
+    client::{asset, ClientQueryError, QueryResult},
+    data_model::{
+        prelude::*,
+        query::{error::QueryExecutionFail, QueryOutputBox},
+    },
+};
+use nonzero_ext::nonzero;
+use test_network::*;
+
+#[test]
+fn query_output_is_streamed_in_batches() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_280).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let register: Vec<InstructionBox> = ('a'..='j')
+        .map(|c| format!("{c}#wonderland").parse().expect("Valid"))
+        .map(|asset_definition_id| {
+            Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into()
+        })
+        .collect();
+    client.submit_all_blocking(register)?;
+
+    let expected = client
+        .request(asset::all_definitions())?
+        .collect::<QueryResult<Vec<_>>>()?;
+
+    let mut streamed = Vec::new();
+    for batch in client.stream_query(
+        asset::all_definitions(),
+        FetchSize::new(Some(nonzero!(3_u32))),
+    )? {
+        let QueryOutputBox::Vec(batch) = batch? else {
+            panic!("Output of iterable query should be a vector");
+        };
+        assert!(batch.len() <= 3);
+        streamed.extend(batch);
+    }
+
+    let streamed = streamed
+        .into_iter()
+        .map(AssetDefinition::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("Only asset definitions should be streamed");
+    assert_eq!(streamed, expected);
+
+    Ok(())
+}
+
+#[test]
+fn query_stream_reports_error() -> Result<()> {
+    let (_rt, _peer, client) = <PeerBuilder>::new().with_port(11_285).start_with_runtime();
+    wait_for_genesis_committed(&[client.clone()], 0);
+
+    let mut stream =
+        client.stream_query(FindDomainById::new("absent".parse()?), FetchSize::default())?;
+    let error = stream
+        .next()
+        .expect("Error should be sent")
+        .expect_err("Query should fail");
+
+    assert!(matches!(
+        error.downcast_ref::<ClientQueryError>(),
+        Some(ClientQueryError::Validation(ValidationFail::QueryFailed(
+            QueryExecutionFail::Find(_)
+        )))
+    ));
+    assert!(stream.next().is_none());
+
+    Ok(())
+}
@@ -12,6 +12,7 @@ use iroha::{
 use iroha_crypto::KeyPair;
 use iroha_genesis::GenesisNetwork;
 use iroha_logger::info;
+use nonzero_ext::nonzero;
 use serde_json::json;
 use test_network::*;
 use test_samples::ALICE_ID;
@@ -565,6 +566,67 @@ fn trigger_burn_repetitions() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn trigger_execution_limits() -> Result<()> {
+    let (_rt, _peer, mut test_client) = <PeerBuilder>::new().with_port(11_320).start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let asset_definition_id = "rose#wonderland".parse()?;
+    let account_id = ALICE_ID.clone();
+    let asset_id = AssetId::new(asset_definition_id, account_id.clone());
+    let prev_value = get_asset_value(&mut test_client, asset_id.clone());
+
+    let build_trigger = |trigger_id: &TriggerId, instruction_count: u32, limits| {
+        let trigger_instructions =
+            vec![Mint::asset_numeric(1u32, asset_id.clone()); instruction_count as usize];
+        Register::trigger(Trigger::new(
+            trigger_id.clone(),
+            Action::new(
+                trigger_instructions,
+                Repeats::Indefinitely,
+                account_id.clone(),
+                ExecuteTriggerEventFilter::new()
+                    .for_trigger(trigger_id.clone())
+                    .under_authority(account_id.clone()),
+            )
+            .with_limits(limits),
+        ))
+    };
+
+    // Execution exceeding the limit fails
+    let trigger_id = TriggerId::from_str("limited")?;
+    test_client.submit_blocking(build_trigger(
+        &trigger_id,
+        2,
+        ExecutionLimits::default().with_max_instructions(nonzero!(1_u64)),
+    ))?;
+    test_client.submit_blocking(ExecuteTrigger::new(trigger_id))?;
+    assert_eq!(
+        get_asset_value(&mut test_client, asset_id.clone()),
+        prev_value
+    );
+
+    // Trigger is unregistered once its budget is exhausted
+    let trigger_id = TriggerId::from_str("budgeted")?;
+    test_client.submit_blocking(build_trigger(
+        &trigger_id,
+        1,
+        ExecutionLimits::default().with_instruction_budget(2),
+    ))?;
+    for _ in 0..2 {
+        test_client.submit_blocking(ExecuteTrigger::new(trigger_id.clone()))?;
+    }
+    assert_eq!(
+        get_asset_value(&mut test_client, asset_id),
+        prev_value.checked_add(numeric!(2)).unwrap()
+    );
+    let _err = test_client
+        .request(FindTriggerById { id: trigger_id })
+        .expect_err("Trigger should be unregistered");
+
+    Ok(())
+}
+
 #[test]
 fn unregistering_one_of_two_triggers_with_identical_wasm_should_not_cause_original_wasm_loss(
 ) -> Result<()> {
@@ -101,6 +101,53 @@ fn domain_scoped_trigger_must_be_executed_only_on_events_in_its_domain() -> Resu
     Ok(())
 }
 
+#[test]
+fn trigger_must_be_executed_only_on_events_of_matching_entities() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_315).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let account_id = ALICE_ID.clone();
+    let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+    let (carpenter_id, _carpenter_keypair) = gen_account_in("wonderland");
+    test_client.submit_all_blocking([
+        Register::account(Account::new(bob_id.clone())),
+        Register::account(Account::new(carpenter_id.clone())),
+    ])?;
+
+    let asset_definition_id: AssetDefinitionId = "rose#wonderland".parse()?;
+    let asset_id = AssetId::new(asset_definition_id.clone(), account_id.clone());
+    let prev_value = get_asset_value(&test_client, asset_id.clone());
+
+    // Refund every rose given to bob
+    let register_trigger = Register::trigger(Trigger::new(
+        "refund_roses_of_bob".parse()?,
+        Action::new(
+            [Mint::asset_numeric(1u32, asset_id.clone())],
+            Repeats::Indefinitely,
+            account_id,
+            AssetEventFilter::new()
+                .for_asset_definition(asset_definition_id)
+                .for_account(bob_id.clone())
+                .for_events(AssetEventSet::Added),
+        ),
+    ));
+    test_client.submit_blocking(register_trigger)?;
+
+    test_client.submit_blocking(Transfer::asset_numeric(asset_id.clone(), 1u32, bob_id))?;
+    let new_value = get_asset_value(&test_client, asset_id.clone());
+    assert_eq!(new_value, prev_value);
+
+    test_client.submit_blocking(Transfer::asset_numeric(
+        asset_id.clone(),
+        1u32,
+        carpenter_id,
+    ))?;
+    let new_value = get_asset_value(&test_client, asset_id);
+    assert_eq!(new_value, prev_value.checked_sub(Numeric::ONE).unwrap());
+
+    Ok(())
+}
+
 fn get_asset_value(client: &client::Client, asset_id: AssetId) -> Numeric {
     let asset = client.request(client::asset::by_id(asset_id)).unwrap();
 
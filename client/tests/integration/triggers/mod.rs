@@ -3,3 +3,4 @@ mod data_trigger;
 mod event_trigger;
 mod time_trigger;
 mod trigger_rollback;
+mod validation_trigger;
@@ -0,0 +1,68 @@
+use eyre::Result;
+use iroha::data_model::{
+    permission::Permission, prelude::*, transaction::error::TransactionRejectionReason,
+};
+use serde_json::json;
+use test_network::*;
+use test_samples::{gen_account_in, ALICE_ID};
+
+#[test]
+fn only_owners_can_register_validation_triggers() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_325).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    let can_register_mouse_triggers = Permission::new(
+        "CanRegisterUserTrigger".parse()?,
+        json!({ "account_id": mouse_id.clone(), }),
+    );
+    let instructions: [InstructionBox; 2] = [
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Grant::permission(can_register_mouse_triggers, mouse_id.clone()).into(),
+    ];
+    test_client.submit_all_blocking(instructions)?;
+    let mut mouse_client = test_client.clone();
+    mouse_client.account_id = mouse_id.clone();
+    mouse_client.key_pair = mouse_keypair;
+
+    let register_trigger = |name: &str, filter: TransactionValidationEventFilter| {
+        mouse_client.submit_blocking(Register::trigger(Trigger::new(
+            name.parse().expect("Valid"),
+            Action::new(
+                [Fail::new("Vetoed".to_owned())],
+                Repeats::Indefinitely,
+                mouse_id.clone(),
+                filter,
+            ),
+        )))
+    };
+    let is_not_permitted = |result: Result<_>| {
+        let err = result.expect_err("Transaction was not rejected");
+        matches!(
+            err.downcast_ref::<TransactionRejectionReason>(),
+            Some(TransactionRejectionReason::Validation(
+                ValidationFail::NotPermitted(_)
+            ))
+        )
+    };
+
+    // Would veto every transaction on the chain
+    assert!(is_not_permitted(register_trigger(
+        "veto_all",
+        TransactionValidationEventFilter::new()
+    )));
+    assert!(is_not_permitted(register_trigger(
+        "veto_wonderland$wonderland",
+        TransactionValidationEventFilter::new()
+    )));
+    assert!(is_not_permitted(register_trigger(
+        "veto_alice",
+        TransactionValidationEventFilter::new().under_authority(ALICE_ID.clone())
+    )));
+    register_trigger(
+        "veto_mouse",
+        TransactionValidationEventFilter::new().under_authority(mouse_id.clone()),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use std::{num::NonZeroUsize, thread};
+
+use eyre::Result;
+use iroha::{client, data_model::prelude::*};
+use iroha_config::parameters::actual::Root as Config;
+use test_network::*;
+
+#[test]
+fn transaction_submitted_in_chunks_is_committed() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_270).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+    let pipeline_time = Config::pipeline_time();
+
+    let domain_ids: Vec<DomainId> = (0..20)
+        .map(|i| format!("chunked_{i}").parse())
+        .collect::<Result<_, _>>()?;
+    let register_domains: Vec<InstructionBox> = domain_ids
+        .iter()
+        .cloned()
+        .map(|domain_id| Register::domain(Domain::new(domain_id)).into())
+        .collect();
+    let transaction = test_client.build_transaction(register_domains, UnlimitedMetadata::new());
+    test_client
+        .submit_transaction_in_chunks(&transaction, NonZeroUsize::new(64).expect("Non-zero"))?;
+    thread::sleep(pipeline_time * 2);
+
+    for domain_id in domain_ids {
+        test_client.request(client::domain::by_id(domain_id))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use std::thread;
+
+use eyre::Result;
+use iroha::{client, data_model::prelude::*};
+use iroha_config::parameters::actual::Root as Config;
+use test_network::*;
+
+#[test]
+fn resubmission_under_same_idempotency_key_is_deduplicated() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().with_port(11_275).start_with_runtime();
+    wait_for_genesis_committed(&[test_client.clone()], 0);
+    let pipeline_time = Config::pipeline_time();
+
+    let first_domain_id: DomainId = "idempotent_first".parse()?;
+    let second_domain_id: DomainId = "idempotent_second".parse()?;
+    let first = test_client.build_transaction(
+        [Register::domain(Domain::new(first_domain_id.clone()))],
+        UnlimitedMetadata::new(),
+    );
+    let second = test_client.build_transaction(
+        [Register::domain(Domain::new(second_domain_id.clone()))],
+        UnlimitedMetadata::new(),
+    );
+
+    let first_hash = test_client.submit_transaction_with_idempotency_key(&first, "key")?;
+    assert_eq!(first_hash, first.hash());
+    let second_hash = test_client.submit_transaction_with_idempotency_key(&second, "key")?;
+    assert_eq!(second_hash, first_hash);
+    thread::sleep(pipeline_time * 2);
+
+    test_client.request(client::domain::by_id(first_domain_id))?;
+    assert!(test_client
+        .request(client::domain::by_id(second_domain_id))
+        .is_err());
+
+    Ok(())
+}
@@ -27,6 +27,7 @@ class Stderr(Enum):
     )
     WHITESPACES = "White space not allowed"
     INSUFFICIENT_FUNDS = "Not enough quantity to transfer/burn"
+    FOREIGN_DOMAIN_TRANSFER = "Can't transfer domain of another account"
 
 
 class ReservedChars(Enum):
@@ -1,6 +1,7 @@
 import allure  # type: ignore
 import pytest
 
+from common.consts import Stderr
 from src.client_cli import client_cli, have, iroha
 
 
@@ -32,3 +33,38 @@ def test_transfer_domain(
         iroha.should(
             have.domain(GIVEN_registered_domain.name, owned_by=GIVEN_registered_account)
         )
+
+
+@allure.label("sdk_test_id", "transfer_domain_of_another_account")
+def test_transfer_domain_of_another_account(
+    GIVEN_currently_authorized_account,
+    GIVEN_registered_account,
+    GIVEN_registered_domain,
+):
+    with allure.step(
+        f"WHEN {GIVEN_currently_authorized_account} transfers domains "
+        f"to {GIVEN_registered_account}"
+    ):
+        client_cli.execute(
+            f"domain transfer "
+            f"--from={GIVEN_currently_authorized_account} "
+            f"--to={GIVEN_registered_account} "
+            f"--id={GIVEN_registered_domain.name}"
+        )
+    with allure.step(
+        f"WHEN {GIVEN_currently_authorized_account} tries to transfer the domain "
+        f"of {GIVEN_registered_account} back"
+    ):
+        client_cli.execute(
+            f"domain transfer "
+            f"--from={GIVEN_registered_account} "
+            f"--to={GIVEN_currently_authorized_account} "
+            f"--id={GIVEN_registered_domain.name}"
+        )
+    with allure.step(
+        f"THEN {GIVEN_registered_account} should still own {GIVEN_registered_domain}"
+    ):
+        client_cli.should(have.error(Stderr.FOREIGN_DOMAIN_TRANSFER.value))
+        iroha.should(
+            have.domain(GIVEN_registered_domain.name, owned_by=GIVEN_registered_account)
+        )
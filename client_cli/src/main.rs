@@ -78,6 +78,41 @@ impl FromStr for MetadataValueArg {
     }
 }
 
+/// Account argument given either by its id or by an alias registered in its domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountArg {
+    /// Account id in form `signatory@domain_name`
+    Id(AccountId),
+    /// Account alias in form `alias@domain_name`
+    Alias(AccountAlias),
+}
+
+impl FromStr for AccountArg {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<AccountId>()
+            .map(AccountArg::Id)
+            .or_else(|_| s.parse::<AccountAlias>().map(AccountArg::Alias))
+            .map_err(|_| {
+                eyre!("`{s}` is neither an account id `signatory@domain_name` nor an alias `alias@domain_name`")
+            })
+    }
+}
+
+impl AccountArg {
+    /// Resolve the account id, looking the alias up if needed.
+    fn resolve(self, client: &Client) -> Result<AccountId> {
+        match self {
+            AccountArg::Id(id) => Ok(id),
+            AccountArg::Alias(alias) => client
+                .request(iroha::client::account::by_alias(alias.clone()))
+                .map(|account| account.id().clone())
+                .wrap_err_with(|| eyre!("Failed to resolve account alias `{alias}`")),
+        }
+    }
+}
+
 /// Iroha CLI Client provides an ability to interact with Iroha Peers Web API without direct network usage.
 #[derive(clap::Parser, Debug)]
 #[command(name = "iroha", version = concat!("version=", env!("CARGO_PKG_VERSION"), " git_commit_sha=", env!("VERGEN_GIT_SHA")), author)]
@@ -326,22 +361,32 @@ mod blocks {
     pub struct Args {
         /// Block height from which to start streaming blocks
         height: NonZeroU64,
+        /// Stream only block headers and transaction hashes
+        #[arg(short, long)]
+        summaries: bool,
     }
 
     impl RunArgs for Args {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
-            let Args { height } = self;
-            listen(height, context)
+            let Args { height, summaries } = self;
+            listen(height, summaries, context)
         }
     }
 
-    fn listen(height: NonZeroU64, context: &mut dyn RunContext) -> Result<()> {
+    fn listen(height: NonZeroU64, summaries: bool, context: &mut dyn RunContext) -> Result<()> {
         let iroha = context.client_from_config();
         eprintln!("Listening to blocks from height: {height}");
-        iroha
-            .listen_for_blocks(height)
-            .wrap_err("Failed to listen for blocks.")?
-            .try_for_each(|event| context.print_data(&event?))?;
+        if summaries {
+            iroha
+                .listen_for_block_summaries(height)
+                .wrap_err("Failed to listen for block summaries.")?
+                .try_for_each(|summary| context.print_data(&summary?))?;
+        } else {
+            iroha
+                .listen_for_blocks(height)
+                .wrap_err("Failed to listen for blocks.")?
+                .try_for_each(|event| context.print_data(&event?))?;
+        }
         Ok(())
     }
 }
@@ -424,12 +469,12 @@ mod domain {
         /// Domain name as double-quited string
         #[arg(short, long)]
         pub id: DomainId,
-        /// Account from which to transfer (in form `name@domain_name`)
+        /// Account from which to transfer (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long)]
-        pub from: AccountId,
-        /// Account to which to transfer (in form `name@domain_name`)
+        pub from: AccountArg,
+        /// Account to which to transfer (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long)]
-        pub to: AccountId,
+        pub to: AccountArg,
         #[command(flatten)]
         pub metadata: MetadataArgs,
     }
@@ -442,6 +487,9 @@ mod domain {
                 to,
                 metadata,
             } = self;
+            let client = context.client_from_config();
+            let from = from.resolve(&client)?;
+            let to = to.resolve(&client)?;
             let transfer_domain = iroha::data_model::isi::Transfer::domain(from, id, to);
             submit([transfer_domain], metadata.load()?, context)
                 .wrap_err("Failed to transfer domain")
@@ -535,6 +583,10 @@ mod account {
         Grant(Grant),
         /// List all account permissions
         ListPermissions(ListPermissions),
+        /// Register an alias of the account
+        RegisterAlias(RegisterAlias),
+        /// Unregister an alias of the account
+        UnregisterAlias(UnregisterAlias),
     }
 
     impl RunArgs for Args {
@@ -544,6 +596,8 @@ mod account {
                 Args::List,
                 Args::Grant,
                 Args::ListPermissions,
+                Args::RegisterAlias,
+                Args::UnregisterAlias,
             })
         }
     }
@@ -597,9 +651,9 @@ mod account {
 
     #[derive(clap::Args, Debug)]
     pub struct Grant {
-        /// Account id
+        /// Account id or alias
         #[arg(short, long)]
-        pub id: AccountId,
+        pub id: AccountArg,
         /// The JSON/JSON5 file with a permission token
         #[arg(short, long)]
         pub permission: Permission,
@@ -632,6 +686,7 @@ mod account {
                 permission,
                 metadata,
             } = self;
+            let id = id.resolve(&context.client_from_config())?;
             let grant = iroha::data_model::isi::Grant::permission(permission.0, id);
             submit([grant], metadata.load()?, context)
                 .wrap_err("Failed to grant the permission to the account")
@@ -641,15 +696,15 @@ mod account {
     /// List all account permissions
     #[derive(clap::Args, Debug)]
     pub struct ListPermissions {
-        /// Account id
+        /// Account id or alias
         #[arg(short, long)]
-        id: AccountId,
+        id: AccountArg,
     }
 
     impl RunArgs for ListPermissions {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
             let client = context.client_from_config();
-            let find_all_permissions = FindPermissionsByAccountId::new(self.id);
+            let find_all_permissions = FindPermissionsByAccountId::new(self.id.resolve(&client)?);
             let permissions = client
                 .request(find_all_permissions)
                 .wrap_err("Failed to get all account permissions")?;
@@ -657,6 +712,52 @@ mod account {
             Ok(())
         }
     }
+
+    /// Register an alias of the account
+    #[derive(clap::Args, Debug)]
+    pub struct RegisterAlias {
+        /// Alias to register in form `alias@domain_name`
+        #[arg(short, long)]
+        pub alias: AccountAlias,
+        /// Account id or alias the alias refers to
+        #[arg(short, long)]
+        pub id: AccountArg,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for RegisterAlias {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self {
+                alias,
+                id,
+                metadata,
+            } = self;
+            let id = id.resolve(&context.client_from_config())?;
+            let register_alias = iroha::data_model::isi::RegisterAlias::new(alias, id);
+            submit([register_alias], metadata.load()?, context)
+                .wrap_err("Failed to register account alias")
+        }
+    }
+
+    /// Unregister an alias of the account
+    #[derive(clap::Args, Debug)]
+    pub struct UnregisterAlias {
+        /// Alias to unregister in form `alias@domain_name`
+        #[arg(short, long)]
+        pub alias: AccountAlias,
+        #[command(flatten)]
+        pub metadata: MetadataArgs,
+    }
+
+    impl RunArgs for UnregisterAlias {
+        fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let Self { alias, metadata } = self;
+            let unregister_alias = iroha::data_model::isi::UnregisterAlias::new(alias);
+            submit([unregister_alias], metadata.load()?, context)
+                .wrap_err("Failed to unregister account alias")
+        }
+    }
 }
 
 mod asset {
@@ -712,6 +813,9 @@ mod asset {
         /// Value type stored in asset
         #[arg(short, long)]
         pub value_type: AssetValueType,
+        /// Maximum total quantity that can be minted
+        #[arg(long)]
+        pub max_supply: Option<Numeric>,
         #[command(flatten)]
         pub metadata: MetadataArgs,
     }
@@ -722,12 +826,16 @@ mod asset {
                 definition_id,
                 value_type,
                 unmintable,
+                max_supply,
                 metadata,
             } = self;
             let mut asset_definition = AssetDefinition::new(definition_id, value_type);
             if unmintable {
                 asset_definition = asset_definition.mintable_once();
             }
+            if let Some(max_supply) = max_supply {
+                asset_definition = asset_definition.with_max_supply(max_supply);
+            }
             let create_asset_definition =
                 iroha::data_model::isi::Register::asset_definition(asset_definition);
             submit([create_asset_definition], metadata.load()?, context)
@@ -790,9 +898,9 @@ mod asset {
     /// Transfer asset between accounts
     #[derive(clap::Args, Debug)]
     pub struct Transfer {
-        /// Account to which to transfer (in form `name@domain_name`)
+        /// Account to which to transfer (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(long)]
-        pub to: AccountId,
+        pub to: AccountArg,
         /// Asset id to transfer (in form like `asset##account@domain_name`)
         #[arg(long)]
         pub asset_id: AssetId,
@@ -811,6 +919,7 @@ mod asset {
                 quantity,
                 metadata,
             } = self;
+            let to = to.resolve(&context.client_from_config())?;
             let transfer_asset =
                 iroha::data_model::isi::Transfer::asset_numeric(asset_id, quantity, to);
             submit([transfer_asset], metadata.load()?, context).wrap_err("Failed to transfer asset")
@@ -1094,6 +1203,22 @@ mod tests {
         case!(json_str, serde_json::from_str(json_str).unwrap());
     }
 
+    #[test]
+    fn parse_account_arg() {
+        let id =
+            "ed0120BDF918243253B1E731FA096194C8928DA37C4D3226F97EEBD18CF5523D758D6C@wonderland";
+        assert_eq!(
+            AccountArg::from_str(id).expect("valid account id"),
+            AccountArg::Id(id.parse().unwrap())
+        );
+        assert_eq!(
+            AccountArg::from_str("alice@wonderland").expect("valid account alias"),
+            AccountArg::Alias("alice@wonderland".parse().unwrap())
+        );
+        let _invalid =
+            AccountArg::from_str("alice").expect_err("Should fail without a domain but passed");
+    }
+
     #[test]
     fn error_parse_invalid_value() {
         let invalid_str = "not_a_valid_value";
@@ -20,7 +20,7 @@ use url::Url;
 pub use user::{DevTelemetry, Logger, Snapshot};
 
 use crate::{
-    kura::InitMode,
+    kura::{Compression, InitMode},
     parameters::{defaults, user},
 };
 
@@ -129,6 +129,7 @@ impl Genesis {
 pub struct Queue {
     pub capacity: NonZeroUsize,
     pub capacity_per_user: NonZeroUsize,
+    pub block_capacity_per_user: NonZeroUsize,
     pub transaction_time_to_live: Duration,
     pub future_threshold: Duration,
 }
@@ -138,6 +139,10 @@ pub struct Queue {
 pub struct Kura {
     pub init_mode: InitMode,
     pub store_dir: WithOrigin<PathBuf>,
+    pub index_metadata_history: bool,
+    pub index_transactions: bool,
+    pub blocks_retention: Option<NonZeroUsize>,
+    pub compression: Compression,
     pub debug_output_new_blocks: bool,
 }
 
@@ -148,6 +153,7 @@ impl Default for Queue {
             future_threshold: defaults::queue::FUTURE_THRESHOLD,
             capacity: defaults::queue::CAPACITY,
             capacity_per_user: defaults::queue::CAPACITY_PER_USER,
+            block_capacity_per_user: defaults::queue::BLOCK_CAPACITY_PER_USER,
         }
     }
 }
@@ -156,6 +162,7 @@ impl Default for Queue {
 #[allow(missing_docs)]
 pub struct Sumeragi {
     pub trusted_peers: WithOrigin<TrustedPeers>,
+    pub heartbeat_interval: Option<Duration>,
     pub debug_force_soft_fork: bool,
 }
 
@@ -224,6 +231,7 @@ pub struct ChainWide {
     pub ident_length_limits: LengthLimits,
     pub executor_runtime: WasmRuntime,
     pub wasm_runtime: WasmRuntime,
+    pub grant_history_max_len: u32,
 }
 
 impl ChainWide {
@@ -248,6 +256,7 @@ impl Default for ChainWide {
             ident_length_limits: defaults::chain_wide::IDENT_LENGTH_LIMITS,
             executor_runtime: WasmRuntime::default(),
             wasm_runtime: WasmRuntime::default(),
+            grant_history_max_len: defaults::chain_wide::GRANT_HISTORY_MAX_LEN,
         }
     }
 }
@@ -274,6 +283,15 @@ impl Default for WasmRuntime {
 pub struct Torii {
     pub address: WithOrigin<SocketAddr>,
     pub max_content_len_bytes: u64,
+    pub max_chunked_transactions_len_bytes: u64,
+    pub idempotency_window: Duration,
+    pub query_cost_per_second: u32,
+    pub singular_query_cost: u32,
+    pub iterable_query_cost: u32,
+    pub event_buffer_capacity: NonZeroUsize,
+    pub event_buffer_idle_time: Duration,
+    pub event_subscriptions_max: NonZeroUsize,
+    pub event_subscriptions_per_account_max: NonZeroUsize,
 }
 
 /// Complete configuration needed to start regular telemetry.
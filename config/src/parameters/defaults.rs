@@ -16,6 +16,7 @@ pub mod queue {
 
     pub const CAPACITY: NonZeroUsize = nonzero!(2_usize.pow(16));
     pub const CAPACITY_PER_USER: NonZeroUsize = nonzero!(2_usize.pow(16));
+    pub const BLOCK_CAPACITY_PER_USER: NonZeroUsize = nonzero!(2_usize.pow(16));
     // 24 hours
     pub const TRANSACTION_TIME_TO_LIVE: Duration = Duration::from_secs(24 * 60 * 60);
     pub const FUTURE_THRESHOLD: Duration = Duration::from_secs(1);
@@ -77,13 +78,24 @@ pub mod chain_wide {
     /// Default transaction limits
     pub const TRANSACTION_LIMITS: TransactionLimits =
         TransactionLimits::new(MAX_INSTRUCTION_NUMBER, MAX_WASM_SIZE_BYTES);
+    /// Default maximum number of grants and revocations kept in the history of an account
+    pub const GRANT_HISTORY_MAX_LEN: u32 = 2_u32.pow(10);
 }
 
 pub mod torii {
-    use std::time::Duration;
+    use super::*;
 
     pub const MAX_CONTENT_LENGTH: u64 = 2_u64.pow(20) * 16;
+    pub const MAX_CHUNKED_TRANSACTIONS_LENGTH: u64 = 2_u64.pow(20) * 256;
     pub const QUERY_IDLE_TIME: Duration = Duration::from_secs(30);
+    pub const IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(10 * 60);
+    pub const QUERY_COST_PER_SECOND: u32 = 1000;
+    pub const SINGULAR_QUERY_COST: u32 = 1;
+    pub const ITERABLE_QUERY_COST: u32 = 10;
+    pub const EVENT_BUFFER_CAPACITY: NonZeroUsize = nonzero!(2_usize.pow(10));
+    pub const EVENT_BUFFER_IDLE_TIME: Duration = Duration::from_secs(60);
+    pub const EVENT_SUBSCRIPTIONS_MAX: NonZeroUsize = nonzero!(2_usize.pow(10));
+    pub const EVENT_SUBSCRIPTIONS_PER_ACCOUNT_MAX: NonZeroUsize = nonzero!(16_usize);
 }
 
 pub mod telemetry {
@@ -34,7 +34,7 @@ use serde::Deserialize;
 use url::Url;
 
 use crate::{
-    kura::InitMode as KuraInitMode,
+    kura::{Compression as KuraCompression, InitMode as KuraInitMode},
     logger::Format as LoggerFormat,
     parameters::{actual, defaults},
     snapshot::Mode as SnapshotMode,
@@ -221,6 +221,27 @@ pub struct Kura {
         default = "PathBuf::from(defaults::kura::STORE_DIR)"
     )]
     pub store_dir: WithOrigin<PathBuf>,
+    /// If set, values of metadata entries of domains, accounts, asset definitions, assets and
+    /// triggers are recorded after every block, so that they can be queried as of any height.
+    /// The history is kept in the store directory. It is only complete if it was recorded since
+    /// genesis, so enabling it on a peer with existing blocks requires replaying them without
+    /// a snapshot.
+    #[config(env = "KURA_INDEX_METADATA_HISTORY", default)]
+    pub index_metadata_history: bool,
+    /// If set, heights of the blocks with transactions of each account are indexed, so that
+    /// transactions of an account are found without scanning the whole chain. Blocks stored
+    /// before the peer started are indexed in the background, until then the chain is scanned.
+    ///
+    /// Events aren't kept in blocks and no query looks blocks up by their creation time,
+    /// so neither events by entity nor blocks by timestamp are indexed.
+    #[config(env = "KURA_INDEX_TRANSACTIONS", default)]
+    pub index_transactions: bool,
+    /// If set, bodies of blocks deeper than this number are discarded once they are
+    /// covered by a state snapshot; only their headers and hashes are kept.
+    #[config(env = "KURA_BLOCKS_RETENTION")]
+    pub blocks_retention: Option<NonZeroUsize>,
+    #[config(env = "KURA_COMPRESSION", default)]
+    pub compression: KuraCompression,
     #[config(nested)]
     pub debug: KuraDebug,
 }
@@ -230,6 +251,10 @@ impl Kura {
         let Self {
             init_mode,
             store_dir,
+            index_metadata_history,
+            index_transactions,
+            blocks_retention,
+            compression,
             debug:
                 KuraDebug {
                     output_new_blocks: debug_output_new_blocks,
@@ -239,6 +264,10 @@ impl Kura {
         actual::Kura {
             init_mode,
             store_dir,
+            index_metadata_history,
+            index_transactions,
+            blocks_retention,
+            compression,
             debug_output_new_blocks,
         }
     }
@@ -254,6 +283,9 @@ pub struct KuraDebug {
 pub struct Sumeragi {
     #[config(env = "SUMERAGI_TRUSTED_PEERS", default)]
     pub trusted_peers: WithOrigin<TrustedPeers>,
+    /// If set, the leader creates an empty block when no block was created for this long.
+    /// Otherwise blocks are only created when there are transactions.
+    pub heartbeat_interval: Option<HumanDuration>,
     #[config(nested)]
     pub debug: SumeragiDebug,
 }
@@ -282,6 +314,7 @@ impl Sumeragi {
     fn parse_and_push_self(self, self_id: PeerId) -> actual::Sumeragi {
         let Self {
             trusted_peers,
+            heartbeat_interval,
             debug: SumeragiDebug { force_soft_fork },
         } = self;
 
@@ -290,6 +323,7 @@ impl Sumeragi {
                 myself: self_id,
                 others: x.0,
             }),
+            heartbeat_interval: heartbeat_interval.map(HumanDuration::get),
             debug_force_soft_fork: force_soft_fork,
         }
     }
@@ -362,6 +396,10 @@ pub struct Queue {
     /// Use this option to apply throttling.
     #[config(default = "defaults::queue::CAPACITY_PER_USER")]
     pub capacity_per_user: NonZeroUsize,
+    /// The upper limit of the number of transactions of a single user put into one block.
+    /// Use this option to keep users paying high tips from starving the others.
+    #[config(default = "defaults::queue::BLOCK_CAPACITY_PER_USER")]
+    pub block_capacity_per_user: NonZeroUsize,
     /// The transaction will be dropped after this time if it is still in the queue.
     #[config(default = "defaults::queue::TRANSACTION_TIME_TO_LIVE.into()")]
     pub transaction_time_to_live: HumanDuration,
@@ -375,12 +413,14 @@ impl Queue {
         let Self {
             capacity,
             capacity_per_user,
+            block_capacity_per_user,
             transaction_time_to_live,
             future_threshold,
         } = self;
         actual::Queue {
             capacity,
             capacity_per_user,
+            block_capacity_per_user,
             transaction_time_to_live: transaction_time_to_live.0,
             future_threshold: future_threshold.0,
         }
@@ -498,6 +538,8 @@ pub struct ChainWide {
     pub wasm_fuel_limit: u64,
     #[config(default = "defaults::chain_wide::WASM_MAX_MEMORY_BYTES")]
     pub wasm_max_memory: u32,
+    #[config(default = "defaults::chain_wide::GRANT_HISTORY_MAX_LEN")]
+    pub grant_history_max_len: u32,
 }
 
 impl ChainWide {
@@ -517,6 +559,7 @@ impl ChainWide {
             executor_max_memory,
             wasm_fuel_limit,
             wasm_max_memory,
+            grant_history_max_len,
         } = self;
 
         actual::ChainWide {
@@ -538,6 +581,7 @@ impl ChainWide {
                 fuel_limit: wasm_fuel_limit,
                 max_memory_bytes: wasm_max_memory,
             },
+            grant_history_max_len,
         }
     }
 }
@@ -548,8 +592,26 @@ pub struct Torii {
     pub address: WithOrigin<SocketAddr>,
     #[config(default = "defaults::torii::MAX_CONTENT_LENGTH.into()")]
     pub max_content_length: HumanBytes<u64>,
+    #[config(default = "defaults::torii::MAX_CHUNKED_TRANSACTIONS_LENGTH.into()")]
+    pub max_chunked_transactions_length: HumanBytes<u64>,
     #[config(default = "defaults::torii::QUERY_IDLE_TIME.into()")]
     pub query_idle_time: HumanDuration,
+    #[config(default = "defaults::torii::IDEMPOTENCY_WINDOW.into()")]
+    pub idempotency_window: HumanDuration,
+    #[config(default = "defaults::torii::QUERY_COST_PER_SECOND")]
+    pub query_cost_per_second: u32,
+    #[config(default = "defaults::torii::SINGULAR_QUERY_COST")]
+    pub singular_query_cost: u32,
+    #[config(default = "defaults::torii::ITERABLE_QUERY_COST")]
+    pub iterable_query_cost: u32,
+    #[config(default = "defaults::torii::EVENT_BUFFER_CAPACITY")]
+    pub event_buffer_capacity: NonZeroUsize,
+    #[config(default = "defaults::torii::EVENT_BUFFER_IDLE_TIME.into()")]
+    pub event_buffer_idle_time: HumanDuration,
+    #[config(default = "defaults::torii::EVENT_SUBSCRIPTIONS_MAX")]
+    pub event_subscriptions_max: NonZeroUsize,
+    #[config(default = "defaults::torii::EVENT_SUBSCRIPTIONS_PER_ACCOUNT_MAX")]
+    pub event_subscriptions_per_account_max: NonZeroUsize,
 }
 
 impl Torii {
@@ -557,6 +619,15 @@ impl Torii {
         let torii = actual::Torii {
             address: self.address,
             max_content_len_bytes: self.max_content_length.get(),
+            max_chunked_transactions_len_bytes: self.max_chunked_transactions_length.get(),
+            idempotency_window: self.idempotency_window.get(),
+            query_cost_per_second: self.query_cost_per_second,
+            singular_query_cost: self.singular_query_cost,
+            iterable_query_cost: self.iterable_query_cost,
+            event_buffer_capacity: self.event_buffer_capacity,
+            event_buffer_idle_time: self.event_buffer_idle_time.get(),
+            event_subscriptions_max: self.event_subscriptions_max,
+            event_subscriptions_per_account_max: self.event_subscriptions_per_account_max,
         };
 
         let query = actual::LiveQueryStore {
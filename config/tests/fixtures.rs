@@ -115,6 +115,15 @@ fn minimal_config_snapshot() {
                     },
                 },
                 max_content_len_bytes: 16777216,
+                max_chunked_transactions_len_bytes: 268435456,
+                idempotency_window: 600s,
+                query_cost_per_second: 1000,
+                singular_query_cost: 1,
+                iterable_query_cost: 10,
+                event_buffer_capacity: 1024,
+                event_buffer_idle_time: 60s,
+                event_subscriptions_max: 1024,
+                event_subscriptions_per_account_max: 16,
             },
             kura: Kura {
                 init_mode: Strict,
@@ -124,6 +133,10 @@ fn minimal_config_snapshot() {
                         id: ParameterId(kura.store_dir),
                     },
                 },
+                index_metadata_history: false,
+                index_transactions: false,
+                blocks_retention: None,
+                compression: None,
                 debug_output_new_blocks: false,
             },
             sumeragi: Sumeragi {
@@ -155,6 +168,7 @@ fn minimal_config_snapshot() {
                         path: "tests/fixtures/base_trusted_peers.toml",
                     },
                 },
+                heartbeat_interval: None,
                 debug_force_soft_fork: false,
             },
             block_sync: BlockSync {
@@ -175,6 +189,7 @@ fn minimal_config_snapshot() {
             queue: Queue {
                 capacity: 65536,
                 capacity_per_user: 65536,
+                block_capacity_per_user: 65536,
                 transaction_time_to_live: 86400s,
                 future_threshold: 1s,
             },
@@ -205,22 +220,27 @@ fn minimal_config_snapshot() {
                 domain_metadata_limits: Limits {
                     capacity: 1048576,
                     max_entry_len: 4096,
+                    max_total_byte_size: 18446744073709551615,
                 },
                 asset_definition_metadata_limits: Limits {
                     capacity: 1048576,
                     max_entry_len: 4096,
+                    max_total_byte_size: 18446744073709551615,
                 },
                 account_metadata_limits: Limits {
                     capacity: 1048576,
                     max_entry_len: 4096,
+                    max_total_byte_size: 18446744073709551615,
                 },
                 asset_metadata_limits: Limits {
                     capacity: 1048576,
                     max_entry_len: 4096,
+                    max_total_byte_size: 18446744073709551615,
                 },
                 trigger_metadata_limits: Limits {
                     capacity: 1048576,
                     max_entry_len: 4096,
+                    max_total_byte_size: 18446744073709551615,
                 },
                 ident_length_limits: LengthLimits {
                     min: 1,
@@ -234,6 +254,7 @@ fn minimal_config_snapshot() {
                     fuel_limit: 55000000,
                     max_memory_bytes: 524288000,
                 },
+                grant_history_max_len: 1024,
             },
         }"#]].assert_eq(&format!("{config:#?}"));
 }
@@ -40,6 +40,10 @@ async fn measure_block_size_for_n_executors(n_executors: u32) {
     let cfg = Config {
         init_mode: iroha_config::kura::InitMode::Strict,
         debug_output_new_blocks: false,
+        index_metadata_history: false,
+        index_transactions: false,
+        blocks_retention: None,
+        compression: iroha_config::kura::Compression::None,
         store_dir: WithOrigin::inline(dir.path().to_path_buf()),
     };
     let (kura, _) = iroha_core::kura::Kura::new(&cfg).unwrap();
@@ -4,7 +4,7 @@
 //! 2. If a block is received, i.e. deserialized:
 //!     `SignedBlock` -> `ValidBlock` -> `CommittedBlock`
 //! [`Block`]s are organised into a linear sequence over time (also known as the block chain).
-use std::error::Error as _;
+use std::{collections::BTreeSet, error::Error as _};
 
 use iroha_config::parameters::defaults::chain_wide::CONSENSUS_ESTIMATION as DEFAULT_CONSENSUS_ESTIMATION;
 use iroha_crypto::{HashOf, KeyPair, MerkleTree, SignatureOf, SignaturesOf};
@@ -18,6 +18,7 @@ use iroha_genesis::GenesisTransaction;
 use iroha_primitives::unique_vec::UniqueVec;
 use thiserror::Error;
 
+pub use self::event::committed_block_events;
 pub(crate) use self::event::WithEvents;
 pub use self::{chained::Chained, commit::CommittedBlock, valid::ValidBlock};
 use crate::{prelude::*, sumeragi::network_topology::Topology, tx::AcceptTransactionFail};
@@ -31,12 +32,14 @@ pub enum TransactionValidationError {
     NotValid(#[from] TransactionRejectionReason),
     /// A transaction is marked as rejected, but is actually valid
     RejectedIsValid,
+    /// A transaction is scheduled after the block
+    NotDue,
 }
 
 /// Errors occurred on block validation
 #[derive(Debug, displaydoc::Display, Error)]
 pub enum BlockValidationError {
-    /// Block has committed transactions
+    /// Block has committed transactions or repeats a transaction
     HasCommittedTransactions,
     /// Mismatch between the actual and expected hashes of the latest block. Expected: {expected:?}, actual: {actual:?}
     LatestBlockHashMismatch {
@@ -76,6 +79,23 @@ pub enum BlockValidationError {
     ViewChangeIndexTooLarge,
 }
 
+impl BlockValidationError {
+    /// Short name of the kind of the error, e.g. to label metrics
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Self::HasCommittedTransactions => "has_committed_transactions",
+            Self::LatestBlockHashMismatch { .. } => "latest_block_hash_mismatch",
+            Self::LatestBlockHeightMismatch { .. } => "latest_block_height_mismatch",
+            Self::IncorrectHash { .. } => "incorrect_hash",
+            Self::TransactionHashMismatch => "transaction_hash_mismatch",
+            Self::TransactionValidation(_) => "transaction_validation",
+            Self::TopologyMismatch { .. } => "topology_mismatch",
+            Self::SignatureVerification(_) => "signature_verification",
+            Self::ViewChangeIndexTooLarge => "view_change_index_too_large",
+        }
+    }
+}
+
 /// Error during signature verification
 #[derive(thiserror::Error, displaydoc::Display, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SignatureVerificationError {
@@ -126,17 +146,13 @@ mod pending {
     impl BlockBuilder<Pending> {
         /// Create [`Self`]
         ///
-        /// # Panics
-        ///
-        /// if the given list of transaction is empty
+        /// The list of transactions is empty only for heartbeat blocks
         #[inline]
         pub fn new(
             transactions: Vec<AcceptedTransaction>,
             commit_topology: Topology,
             event_recommendations: Vec<EventBox>,
         ) -> Self {
-            assert!(!transactions.is_empty(), "Empty block created");
-
             Self(Pending {
                 commit_topology,
                 transactions,
@@ -326,10 +342,14 @@ mod valid {
                 }
             }
 
-            if block
-                .transactions()
-                .any(|tx| state_block.has_transaction(tx.as_ref().hash()))
-            {
+            // Transactions are told apart by their payloads, so that the same transaction
+            // can't be executed again with other signatures
+            let mut payload_hashes = BTreeSet::new();
+            if block.transactions().any(|tx| {
+                let payload_hash = tx.as_ref().payload_hash();
+                !payload_hashes.insert(payload_hash)
+                    || state_block.has_transaction_payload(payload_hash)
+            }) {
                 return WithEvents::new(Err((
                     block,
                     BlockValidationError::HasCommittedTransactions,
@@ -361,6 +381,12 @@ mod valid {
                 // TODO: Unnecessary clone?
                 .cloned()
                 .try_for_each(|CommittedTransaction { value, error }| {
+                    if value.not_before().is_some_and(|not_before| {
+                        !not_before.is_reached(block.header().height, block.header().timestamp_ms)
+                    }) {
+                        return Err(TransactionValidationError::NotDue);
+                    }
+
                     let transaction_executor = state_block.transaction_executor();
                     let limits = &transaction_executor.transaction_limits;
 
@@ -785,6 +811,50 @@ mod event {
             core::iter::empty()
         }
     }
+
+    /// Pipeline events emitted by the peer while the committed `block` was validated,
+    /// committed and applied, in the order they were emitted.
+    ///
+    /// Data events aren't stored in blocks, so they can't be produced again.
+    pub fn committed_block_events(block: &SignedBlock) -> Vec<PipelineEventBox> {
+        let block_height = block.header().height;
+        let block_hash = block.hash();
+        let block_event = |status| {
+            PipelineEventBox::from(BlockEvent {
+                header: block.header().clone(),
+                hash: block_hash,
+                status,
+            })
+        };
+        let transaction_event = |tx: &CommittedTransaction, status| {
+            PipelineEventBox::from(TransactionEvent {
+                block_height: Some(block_height),
+                hash: tx.as_ref().hash(),
+                status,
+            })
+        };
+
+        block
+            .transactions()
+            .map(|tx| {
+                let status = tx.error.as_ref().map_or_else(
+                    || TransactionStatus::Approved,
+                    |error| TransactionStatus::Rejected(error.clone().into()),
+                );
+                transaction_event(tx, status)
+            })
+            .chain([
+                block_event(BlockStatus::Approved),
+                block_event(BlockStatus::Committed),
+            ])
+            .chain(
+                block
+                    .transactions()
+                    .map(|tx| transaction_event(tx, TransactionStatus::Committed)),
+            )
+            .chain([block_event(BlockStatus::Applied)])
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -27,7 +27,7 @@ impl From<wasm::error::Error> for ValidationFail {
                 use wasm::error::ExportFnCallError::*;
 
                 match call_error {
-                    ExecutionLimitsExceeded(_) => Self::TooComplex,
+                    ExecutionLimitsExceeded(_) | OutOfFuel(_) => Self::TooComplex,
                     HostExecution(error) | Other(error) => {
                         Self::InternalError(format!("{error:#}"))
                     }
@@ -3,7 +3,10 @@
 use std::{num::NonZeroU32, sync::Arc, time::Duration};
 
 use iroha_config::parameters::actual::TransactionGossiper as Config;
-use iroha_data_model::{transaction::SignedTransaction, ChainId};
+use iroha_data_model::{
+    transaction::{CancelTransaction, SignedTransaction},
+    ChainId,
+};
 use iroha_p2p::Broadcast;
 use parity_scale_codec::{Decode, Encode};
 use tokio::sync::mpsc;
@@ -94,20 +97,38 @@ impl TransactionGossiper {
         let txs = self
             .queue
             .n_random_transactions(self.gossip_max_size.get(), &self.state.view());
+        let cancellations = self.queue.cancellations();
 
-        if txs.is_empty() {
+        if txs.is_empty() && cancellations.is_empty() {
             return;
         }
 
-        iroha_logger::trace!(tx_count = txs.len(), "Gossiping transactions");
+        iroha_logger::trace!(
+            tx_count = txs.len(),
+            cancellation_count = cancellations.len(),
+            "Gossiping transactions"
+        );
         self.network.broadcast(Broadcast {
-            data: NetworkMessage::TransactionGossiper(Box::new(TransactionGossip::new(txs))),
+            data: NetworkMessage::TransactionGossiper(Box::new(TransactionGossip::new(
+                txs,
+                cancellations,
+            ))),
         });
     }
 
-    fn handle_transaction_gossip(&self, TransactionGossip { txs }: TransactionGossip) {
+    fn handle_transaction_gossip(
+        &self,
+        TransactionGossip { txs, cancellations }: TransactionGossip,
+    ) {
         iroha_logger::trace!(size = txs.len(), "Received new transaction gossip");
 
+        for cancellation in cancellations {
+            match self.queue.cancel(cancellation) {
+                Ok(()) | Err(crate::queue::Error::NotInQueue) => {}
+                Err(err) => iroha_logger::error!(?err, "Failed to cancel transaction."),
+            }
+        }
+
         let state_view = self.state.view();
         for tx in txs {
             let transaction_limits = &state_view.config.transaction_limits;
@@ -127,6 +148,12 @@ impl TransactionGossiper {
                     }) => {
                         iroha_logger::debug!(tx_payload_hash = %tx.as_ref().hash(), "Transaction already in the queue, ignoring...")
                     }
+                    Err(crate::queue::Failure {
+                        tx,
+                        err: crate::queue::Error::Cancelled,
+                    }) => {
+                        iroha_logger::debug!(tx_payload_hash = %tx.as_ref().hash(), "Transaction cancelled by its authority, ignoring...")
+                    }
                     Err(crate::queue::Failure { tx, err }) => {
                         iroha_logger::error!(?err, tx_payload_hash = %tx.as_ref().hash(), "Failed to enqueue transaction.")
                     }
@@ -142,15 +169,18 @@ impl TransactionGossiper {
 pub struct TransactionGossip {
     /// Batch of transactions.
     pub txs: Vec<SignedTransaction>,
+    /// Cancellations of transactions by their authorities.
+    pub cancellations: Vec<CancelTransaction>,
 }
 
 impl TransactionGossip {
     /// Constructor.
-    pub fn new(txs: Vec<AcceptedTransaction>) -> Self {
+    pub fn new(txs: Vec<AcceptedTransaction>, cancellations: Vec<CancelTransaction>) -> Self {
         Self {
             // Converting into non-accepted transaction because it's not possible
             // to guarantee that the sending peer checked transaction limits
             txs: txs.into_iter().map(Into::into).collect(),
+            cancellations,
         }
     }
 }
@@ -3,19 +3,32 @@
 //! new [`Block`](`crate::block::SignedBlock`)s on the
 //! blockchain.
 use std::{
+    collections::BTreeMap,
     fmt::Debug,
     fs,
     io::{BufWriter, Read, Seek, SeekFrom, Write},
+    num::NonZeroUsize,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
-use iroha_config::{kura::InitMode, parameters::actual::Kura as Config};
+use iroha_config::{
+    kura::{Compression, InitMode},
+    parameters::actual::Kura as Config,
+};
 use iroha_crypto::{Hash, HashOf};
-use iroha_data_model::block::SignedBlock;
+use iroha_data_model::{
+    account::AccountId,
+    block::{BlockHeader, SignedBlock},
+    isi::RemoveKeyValueBox,
+    metadata::MetadataValueBox,
+};
 use iroha_logger::prelude::*;
 use iroha_version::scale::{DecodeVersioned, EncodeVersioned};
-use parity_scale_codec::DecodeAll;
+use parity_scale_codec::{Decode, DecodeAll, Encode};
 use parking_lot::Mutex;
 
 use crate::{block::CommittedBlock, handler::ThreadHandler};
@@ -23,9 +36,17 @@ use crate::{block::CommittedBlock, handler::ThreadHandler};
 const INDEX_FILE_NAME: &str = "blocks.index";
 const DATA_FILE_NAME: &str = "blocks.data";
 const HASHES_FILE_NAME: &str = "blocks.hashes";
+const PRUNED_FILE_NAME: &str = "blocks.pruned";
+const PRUNED_TMP_FILE_NAME: &str = "blocks.pruned.tmp";
+const PRUNE_JOURNAL_FILE_NAME: &str = "blocks.prune";
+const PRUNE_JOURNAL_TMP_FILE_NAME: &str = "blocks.prune.tmp";
 const LOCK_FILE_NAME: &str = "kura.lock";
+const METADATA_HISTORY_FILE_NAME: &str = "metadata_history.log";
+const METADATA_HISTORY_TMP_FILE_NAME: &str = "metadata_history.log.tmp";
 
 const SIZE_OF_BLOCK_HASH: u64 = Hash::LENGTH as u64;
+/// Every zstd frame starts with it, while a versioned block starts with its version.
+const ZSTD_MAGIC_NUMBER: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
 
 /// The interface of Kura subsystem
 #[derive(Debug)]
@@ -39,8 +60,47 @@ pub struct Kura {
     block_data: Mutex<Vec<(HashOf<SignedBlock>, Option<Arc<SignedBlock>>)>>,
     /// Path to file for plain text blocks.
     block_plain_text_path: Option<PathBuf>,
+    /// History of metadata entries, if it is indexed.
+    metadata_history: Option<Mutex<MetadataHistory>>,
+    /// Blocks with transactions of each account, if they are indexed.
+    transaction_index: Option<Mutex<TransactionIndex>>,
+    /// Number of the latest blocks whose bodies are kept. [`None`] for an archival peer.
+    blocks_retention: Option<NonZeroUsize>,
+    /// Number of the first blocks whose bodies were pruned. Only headers are kept for them.
+    pruned_count: AtomicUsize,
 }
 
+/// Heights of the blocks containing transactions of each authority.
+#[derive(Debug, Default)]
+struct TransactionIndex {
+    /// Height of the first indexed block, every block from it up to `height` is indexed.
+    first_height: u64,
+    /// Height of the latest indexed block.
+    height: u64,
+    /// Block heights in ascending order, each height appears once per authority.
+    heights_by_authority: BTreeMap<AccountId, Vec<u64>>,
+}
+
+/// Values of metadata entries after each block that changed them.
+///
+/// The values can't be recomputed from blocks restored from a snapshot, so changes made by
+/// each block are appended to a log in the store directory, which is replayed on init.
+#[derive(Debug)]
+struct MetadataHistory {
+    /// Path to the log of the recorded changes.
+    log_path: PathBuf,
+    /// Height of the first indexed block. Values are known only if it's the genesis block.
+    first_height: u64,
+    /// Height of the latest indexed block, `0` if none is.
+    height: u64,
+    /// Block heights paired with the values set by these blocks, in ascending order.
+    /// `None` stands for a removed entry.
+    values: BTreeMap<RemoveKeyValueBox, Vec<(u64, Option<MetadataValueBox>)>>,
+}
+
+/// Metadata entries changed by a block with their new values.
+type MetadataChanges = Vec<(RemoveKeyValueBox, Option<MetadataValueBox>)>;
+
 impl Kura {
     /// Initialize Kura and start a thread that receives
     /// and stores new blocks.
@@ -51,7 +111,8 @@ impl Kura {
     /// path.
     pub fn new(config: &Config) -> Result<(Arc<Self>, BlockCount)> {
         let store_dir = config.store_dir.resolve_relative_path();
-        let mut block_store = BlockStore::new(&store_dir, LockStatus::Unlocked);
+        let mut block_store =
+            BlockStore::new(&store_dir, LockStatus::Unlocked).with_compression(config.compression);
         block_store.create_files_if_they_do_not_exist()?;
 
         let block_plain_text_path = config
@@ -63,6 +124,14 @@ impl Kura {
             block_store: Mutex::new(block_store),
             block_data: Mutex::new(Vec::new()),
             block_plain_text_path,
+            metadata_history: config.index_metadata_history.then(|| {
+                Mutex::new(MetadataHistory::new(
+                    store_dir.join(METADATA_HISTORY_FILE_NAME),
+                ))
+            }),
+            transaction_index: config.index_transactions.then(Mutex::default),
+            blocks_retention: config.blocks_retention,
+            pruned_count: AtomicUsize::new(0),
         });
 
         let block_count = kura.init()?;
@@ -78,6 +147,10 @@ impl Kura {
             block_store: Mutex::new(BlockStore::new(PathBuf::new(), LockStatus::Locked)),
             block_data: Mutex::new(Vec::new()),
             block_plain_text_path: None,
+            metadata_history: None,
+            transaction_index: None,
+            blocks_retention: None,
+            pruned_count: AtomicUsize::new(0),
         })
     }
 
@@ -108,31 +181,90 @@ impl Kura {
     #[iroha_logger::log(skip_all, name = "kura_init")]
     fn init(self: &Arc<Self>) -> Result<BlockCount> {
         let mut block_store = self.block_store.lock();
+        block_store.recover_pruning()?;
 
         let block_index_count: usize = block_store
             .read_index_count()?
             .try_into()
             .expect("We don't have 4 billion blocks.");
+        let pruned_count: usize = block_store
+            .read_pruned_count()?
+            .try_into()
+            .expect("We don't have 4 billion blocks.");
 
         let block_hashes = match self.mode {
             InitMode::Fast => {
                 Kura::init_fast_mode(&block_store, block_index_count).or_else(|error| {
-                    warn!(%error, "Hashes file is broken. Falling back to strict init mode.");
-                    Kura::init_strict_mode(&mut block_store, block_index_count)
+                    warn!(%error, "Block store is inconsistent. Falling back to strict init mode.");
+                    Kura::init_strict_mode(&mut block_store, block_index_count, pruned_count)
                 })
             }
-            InitMode::Strict => Kura::init_strict_mode(&mut block_store, block_index_count),
+            InitMode::Strict => {
+                Kura::init_strict_mode(&mut block_store, block_index_count, pruned_count)
+            }
         }?;
 
         let block_count = block_hashes.len();
-        info!(mode=?self.mode, block_count, "Kura init complete");
+        let pruned_count = pruned_count.min(block_count);
+        self.pruned_count.store(pruned_count, Ordering::SeqCst);
+        info!(mode=?self.mode, block_count, pruned_count, "Kura init complete");
 
         // The none value is set in order to indicate that the blocks exist on disk but
         // are not yet loaded.
         *self.block_data.lock() = block_hashes.into_iter().map(|hash| (hash, None)).collect();
+        drop(block_store);
+
+        if let Some(history) = &self.metadata_history {
+            history.lock().load(block_count as u64)?;
+        }
+        if self.transaction_index.is_some() {
+            let kura = Arc::clone(self);
+            std::thread::spawn(move || {
+                kura.reindex_transactions(pruned_count as u64 + 1, block_count as u64);
+            });
+        }
+
         Ok(BlockCount(block_count))
     }
 
+    /// Index transactions of the stored blocks from `first_height` to `height`.
+    ///
+    /// Blocks restored from a snapshot aren't applied again, so they are indexed in the background
+    /// from the block store. Until it's done, the index doesn't cover the whole chain and queries
+    /// scan the blocks instead.
+    #[iroha_logger::log(skip_all, name = "kura_reindex_transactions")]
+    fn reindex_transactions(&self, first_height: u64, height: u64) {
+        let Some(index) = &self.transaction_index else {
+            return;
+        };
+
+        let mut reindexed = TransactionIndex::default();
+        for block_height in first_height..=height {
+            let block = {
+                let block_store = self.block_store.lock();
+                // Bodies of the blocks may have been pruned in the meantime
+                if block_height <= self.pruned_height() {
+                    reindexed.index_block(block_height, core::iter::empty());
+                    continue;
+                }
+                block_store.read_block(block_height - 1)
+            };
+            match block {
+                Ok(block) => reindexed.index_block(
+                    block_height,
+                    block.transactions().map(|tx| tx.as_ref().authority()),
+                ),
+                Err(error) => {
+                    warn!(%error, block_height, "Failed to read block, transactions are indexed only up to it");
+                    return;
+                }
+            }
+        }
+
+        index.lock().prepend(reindexed);
+        info!(first_height, height, "Reindexed transactions");
+    }
+
     fn init_fast_mode(
         block_store: &BlockStore,
         block_index_count: usize,
@@ -141,29 +273,66 @@ impl Kura {
             .read_hashes_count()?
             .try_into()
             .expect("We don't have 4 billion blocks.");
-        if block_hashes_count == block_index_count {
-            block_store.read_block_hashes(0, block_hashes_count)
-        } else {
-            Err(Error::HashesFileHeightMismatch)
+        if block_hashes_count != block_index_count {
+            return Err(Error::HashesFileHeightMismatch);
         }
+        let block_hashes = block_store.read_block_hashes(0, block_hashes_count)?;
+
+        // A crash while writing a block leaves the top of the store torn,
+        // so at least the latest block is checked against its hash
+        if let Some(&latest_block_hash) = block_hashes.last() {
+            let latest_block_height = block_index_count as u64;
+            let pruned_count = block_store.read_pruned_count()?;
+            if latest_block_height > pruned_count {
+                let latest_block = block_store.read_block(latest_block_height - 1)?;
+                if latest_block.hash() != latest_block_hash {
+                    return Err(Error::BlockHashMismatch(latest_block_height));
+                }
+            }
+        }
+
+        Ok(block_hashes)
     }
 
     fn init_strict_mode(
         block_store: &mut BlockStore,
         block_index_count: usize,
+        pruned_count: usize,
     ) -> Result<Vec<HashOf<SignedBlock>>, Error> {
         let mut block_hashes = Vec::with_capacity(block_index_count);
 
         let mut block_indices = vec![BlockIndex::default(); block_index_count];
         block_store.read_block_indices(0, &mut block_indices)?;
 
+        // Hashes of pruned blocks can't be recomputed, so they are taken from the hashes
+        // file, and only the links between the stored headers are checked.
+        let pruned_count = pruned_count.min(block_index_count);
+        let pruned_hashes = block_store.read_block_hashes(0, pruned_count)?;
         let mut prev_block_hash = None;
-        for block in block_indices {
+        for (block, hash) in block_indices.iter().zip(pruned_hashes) {
+            let mut header_buffer = vec![0_u8; block.length.try_into()?];
+            block_store.read_block_data(block.start, &mut header_buffer)?;
+            let header = BlockHeader::decode_all(&mut header_buffer.as_slice())?;
+            if prev_block_hash != header.previous_block_hash {
+                error!("Pruned block has wrong previous block hash. Not reading any blocks beyond this height.");
+                break;
+            }
+            block_hashes.push(hash);
+            prev_block_hash = Some(hash);
+        }
+
+        let valid_pruned_count = block_hashes.len();
+        let unpruned_blocks = if valid_pruned_count == pruned_count {
+            &block_indices[pruned_count..]
+        } else {
+            &[]
+        };
+        for block in unpruned_blocks {
             // This is re-allocated every iteration. This could cause a problem.
             let mut block_data_buffer = vec![0_u8; block.length.try_into()?];
 
             match block_store.read_block_data(block.start, &mut block_data_buffer) {
-                Ok(()) => match SignedBlock::decode_all_versioned(&block_data_buffer) {
+                Ok(()) => match decode_stored_block(&block_data_buffer) {
                     Ok(decoded_block) => {
                         if prev_block_hash != decoded_block.header().previous_block_hash {
                             error!("Block has wrong previous block hash. Not reading any blocks beyond this height.");
@@ -185,6 +354,19 @@ impl Kura {
             }
         }
 
+        // Drop the invalid tail, so that new blocks are stored right after the valid ones.
+        // The missing blocks are then received from other peers by block sync.
+        if block_hashes.len() < block_index_count {
+            warn!(
+                valid_block_count = block_hashes.len(),
+                dropped_block_count = block_index_count - block_hashes.len(),
+                "Truncating block store to the last valid block"
+            );
+            block_store.write_index_count(block_hashes.len() as u64)?;
+            if valid_pruned_count < pruned_count {
+                block_store.write_pruned_count(valid_pruned_count as u64)?;
+            }
+        }
         block_store.overwrite_block_hashes(&block_hashes)?;
 
         Ok(block_hashes)
@@ -304,6 +486,9 @@ impl Kura {
         let block_number: usize = (block_height - 1)
             .try_into()
             .expect("Failed to cast to u32.");
+        if block_number < self.pruned_count.load(Ordering::SeqCst) {
+            return None;
+        }
 
         if let Some(block_arc) = data_array_guard[block_number].1.as_ref() {
             return Some(Arc::clone(block_arc));
@@ -319,7 +504,7 @@ impl Kura {
         block_store
             .read_block_data(start, &mut block_buf)
             .expect("Failed to read block data.");
-        let block = SignedBlock::decode_all_versioned(&block_buf).expect("Failed to decode block");
+        let block = decode_stored_block(&block_buf).expect("Failed to decode block");
 
         let block_arc = Arc::new(block);
         data_array_guard[block_number].1 = Some(Arc::clone(&block_arc));
@@ -341,6 +526,94 @@ impl Kura {
         index.and_then(|index| self.get_block_by_height(index as u64 + 1))
     }
 
+    /// Get the header of the block at the provided height. Unlike
+    /// `get_block_by_height`, this also works for blocks whose bodies were pruned.
+    pub fn get_block_header_by_height(&self, block_height: u64) -> Option<BlockHeader> {
+        if block_height > self.pruned_height() {
+            return self
+                .get_block_by_height(block_height)
+                .map(|block| block.header().clone());
+        }
+        if block_height == 0 {
+            return None;
+        }
+
+        // Lock in the same order as `get_block_by_height` does
+        let _data_array_guard = self.block_data.lock();
+        let block_store = self.block_store.lock();
+        let BlockIndex { start, length } = block_store
+            .read_block_index(block_height - 1)
+            .expect("Failed to read block index from disk.");
+
+        let mut header_buf =
+            vec![0_u8; usize::try_from(length).expect("index_len didn't fit in 32-bits")];
+        block_store
+            .read_block_data(start, &mut header_buf)
+            .expect("Failed to read block data.");
+        Some(
+            BlockHeader::decode_all(&mut header_buf.as_slice())
+                .expect("Failed to decode block header"),
+        )
+    }
+
+    /// Whether this peer keeps bodies of all blocks.
+    pub fn is_archival(&self) -> bool {
+        self.blocks_retention.is_none()
+    }
+
+    /// Height of the latest block whose body was pruned, or 0 if there are none.
+    pub fn pruned_height(&self) -> u64 {
+        self.pruned_count.load(Ordering::SeqCst) as u64
+    }
+
+    /// Discard bodies of blocks below `snapshot_height`, while keeping the configured
+    /// number of the latest blocks. Does nothing on an archival peer.
+    ///
+    /// The caller must make sure that the state at `snapshot_height` can be restored
+    /// without these blocks, i.e. that it is stored in a snapshot. The block at
+    /// `snapshot_height` itself is kept, since it's needed to restore the topology.
+    ///
+    /// # Errors
+    /// Fails if the block store can't be rewritten.
+    pub fn prune_blocks(&self, snapshot_height: u64) -> Result<()> {
+        let Some(retention) = self.blocks_retention else {
+            return Ok(());
+        };
+
+        // Nobody may read the blocks from disk until they are rewritten,
+        // so the block store is locked before the blocks are marked as pruned.
+        let mut data_array_guard = self.block_data.lock();
+        let mut block_store = self.block_store.lock();
+        let written_block_count = block_store.read_index_count()?;
+        let new_pruned_count = written_block_count
+            .saturating_sub(retention.get() as u64)
+            .min(snapshot_height.saturating_sub(1));
+        let pruned_count = self.pruned_count.load(Ordering::SeqCst);
+        if new_pruned_count <= pruned_count as u64 {
+            return Ok(());
+        }
+        let new_pruned_count_usize = new_pruned_count.try_into()?;
+
+        for (_hash, block_arc) in &mut data_array_guard[..new_pruned_count_usize] {
+            *block_arc = None;
+        }
+        self.pruned_count
+            .store(new_pruned_count_usize, Ordering::SeqCst);
+        drop(data_array_guard);
+        if let Err(error) = block_store.prune_block_bodies(new_pruned_count) {
+            // Once committed, the pruning is finished on the next init,
+            // otherwise the block store is left as it was
+            if !block_store.has_pending_pruning() {
+                self.pruned_count.store(pruned_count, Ordering::SeqCst);
+            }
+            return Err(error);
+        }
+        drop(block_store);
+
+        info!(pruned_height = new_pruned_count, "Pruned block bodies");
+        Ok(())
+    }
+
     /// Put a block in kura's in memory block store.
     pub fn store_block(&self, block: CommittedBlock) {
         let block = Arc::new(SignedBlock::from(block));
@@ -354,6 +627,302 @@ impl Kura {
         data.pop();
         data.push((block.hash(), Some(block)));
     }
+
+    /// Whether `Kura` keeps the history of metadata entries.
+    pub fn indexes_metadata_history(&self) -> bool {
+        self.metadata_history.is_some()
+    }
+
+    /// Record the values of metadata entries changed by the block at `block_height`.
+    /// Does nothing unless the history is indexed.
+    ///
+    /// Values recorded for the same or higher blocks are discarded first,
+    /// since those blocks have been replaced.
+    pub fn index_metadata_changes(
+        &self,
+        block_height: u64,
+        changes: impl IntoIterator<Item = (RemoveKeyValueBox, Option<MetadataValueBox>)>,
+    ) {
+        let Some(history) = &self.metadata_history else {
+            return;
+        };
+        let mut history = history.lock();
+        if let Err(error) = history.index_block(block_height, changes.into_iter().collect()) {
+            // The log would miss the block, so the history is started over
+            error!(%error, block_height, "Failed to persist metadata history");
+            history.clear();
+        }
+    }
+
+    /// Get the value of the metadata `entry` as of the block at `block_height`.
+    ///
+    /// Returns `None` if the history isn't indexed or doesn't cover the block,
+    /// and `Some(None)` if the entry wasn't set.
+    pub fn metadata_value_at(
+        &self,
+        entry: &RemoveKeyValueBox,
+        block_height: u64,
+    ) -> Option<Option<MetadataValueBox>> {
+        self.metadata_history
+            .as_ref()?
+            .lock()
+            .value_at(entry, block_height)
+    }
+
+    /// Whether heights of blocks with transactions of each account are indexed.
+    pub fn indexes_transactions(&self) -> bool {
+        self.transaction_index.is_some()
+    }
+
+    /// Record the authorities of transactions in the block at `block_height`.
+    ///
+    /// Heights recorded for the same or higher blocks are discarded first,
+    /// since those blocks have been replaced.
+    pub fn index_transaction_authorities<'a>(
+        &self,
+        block_height: u64,
+        authorities: impl IntoIterator<Item = &'a AccountId>,
+    ) {
+        let Some(index) = &self.transaction_index else {
+            return;
+        };
+        index.lock().index_block(block_height, authorities);
+    }
+
+    /// Get heights of the blocks up to `block_height` with transactions of `authority`, in ascending order.
+    ///
+    /// Returns `None` if transactions aren't indexed, or the index doesn't cover
+    /// all the stored blocks up to `block_height` yet.
+    pub fn transaction_heights_by_authority(
+        &self,
+        authority: &AccountId,
+        block_height: u64,
+    ) -> Option<Vec<u64>> {
+        let index = self.transaction_index.as_ref()?.lock();
+        if index.first_height > self.pruned_height() + 1 || index.height < block_height {
+            return None;
+        }
+
+        Some(
+            index
+                .heights_by_authority
+                .get(authority)
+                .map(|heights| {
+                    let end = heights.partition_point(|height| *height <= block_height);
+                    heights[..end].to_vec()
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+
+impl TransactionIndex {
+    /// Record the authorities of transactions in the block at `block_height`.
+    fn index_block<'a>(
+        &mut self,
+        block_height: u64,
+        authorities: impl IntoIterator<Item = &'a AccountId>,
+    ) {
+        if self.height == 0 || block_height > self.height + 1 {
+            // Blocks in between weren't indexed, so the earlier ones aren't of any use
+            self.heights_by_authority.clear();
+            self.first_height = block_height;
+        } else if block_height <= self.height {
+            // The blocks from `block_height` on have been replaced
+            self.heights_by_authority.retain(|_, heights| {
+                heights.retain(|height| *height < block_height);
+                !heights.is_empty()
+            });
+            self.first_height = self.first_height.min(block_height);
+        }
+        self.height = block_height;
+        for authority in authorities {
+            let heights = self
+                .heights_by_authority
+                .entry(authority.clone())
+                .or_default();
+            if heights.last() != Some(&block_height) {
+                heights.push(block_height);
+            }
+        }
+    }
+
+    /// Extend the index with the `earlier` blocks, if they leave no gap before the indexed ones.
+    fn prepend(&mut self, mut earlier: Self) {
+        if self.height == 0 {
+            *self = earlier;
+            return;
+        }
+        if earlier.height == 0
+            || earlier.first_height >= self.first_height
+            || earlier.height + 1 < self.first_height
+        {
+            return;
+        }
+
+        for (authority, heights) in &mut self.heights_by_authority {
+            if let Some(earlier_heights) = earlier.heights_by_authority.remove(authority) {
+                let mut merged: Vec<_> = earlier_heights
+                    .into_iter()
+                    .take_while(|height| *height < self.first_height)
+                    .collect();
+                merged.append(heights);
+                *heights = merged;
+            }
+        }
+        for (authority, mut heights) in earlier.heights_by_authority {
+            heights.retain(|height| *height < self.first_height);
+            if !heights.is_empty() {
+                self.heights_by_authority.insert(authority, heights);
+            }
+        }
+        self.first_height = earlier.first_height;
+    }
+}
+
+impl MetadataHistory {
+    fn new(log_path: PathBuf) -> Self {
+        Self {
+            log_path,
+            first_height: 0,
+            height: 0,
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Restore the history from its log, keeping the blocks up to `block_count`.
+    ///
+    /// A record torn by a crash is discarded, and the log is rewritten
+    /// without the values of replaced blocks, so that it doesn't grow with every restart.
+    fn load(&mut self, block_count: u64) -> Result<()> {
+        let log = match fs::read(&self.log_path) {
+            Ok(log) => log,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(Error::IO(error, self.log_path.clone())),
+        };
+
+        let mut records = log.as_slice();
+        while !records.is_empty() {
+            let Ok((block_height, changes)) = Vec::<u8>::decode(&mut records)
+                .and_then(|record| <(u64, MetadataChanges)>::decode_all(&mut record.as_slice()))
+            else {
+                warn!("Metadata history log is torn, discarding its tail");
+                break;
+            };
+            self.record(block_height, changes);
+        }
+        if self.height > block_count {
+            self.truncate(block_count + 1);
+        }
+
+        self.rewrite_log()
+    }
+
+    /// Record the values set by the block at `block_height` and append them to the log.
+    fn index_block(&mut self, block_height: u64, changes: MetadataChanges) -> Result<()> {
+        // Values set by the blocks in between are unknown, so the earlier ones may be outdated
+        let restart = self.height == 0 || block_height > self.height + 1;
+        if restart {
+            self.clear();
+        }
+        let record = (block_height, &changes).encode().encode();
+        self.record(block_height, changes);
+
+        let mut log = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .append(!restart)
+            .truncate(restart)
+            .open(&self.log_path)
+            .add_err_context(&self.log_path)?;
+        log.write_all(&record).add_err_context(&self.log_path)?;
+        log.sync_data().add_err_context(&self.log_path)
+    }
+
+    /// Record the values set by the block at `block_height`, replacing those of the later blocks.
+    fn record(&mut self, block_height: u64, changes: MetadataChanges) {
+        self.truncate(block_height);
+        if self.height == 0 {
+            self.first_height = block_height;
+        }
+        self.height = block_height;
+        for (entry, value) in changes {
+            self.values
+                .entry(entry)
+                .or_default()
+                .push((block_height, value));
+        }
+    }
+
+    /// Discard the values set by the blocks from `block_height` on.
+    fn truncate(&mut self, block_height: u64) {
+        self.values.retain(|_, values| {
+            values.retain(|(height, _)| *height < block_height);
+            !values.is_empty()
+        });
+        self.height = self.height.min(block_height.saturating_sub(1));
+        if self.height < self.first_height {
+            self.clear();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.first_height = 0;
+        self.height = 0;
+    }
+
+    /// Replace the log with the records of the first and the latest indexed blocks
+    /// and of the blocks which changed the recorded values.
+    fn rewrite_log(&self) -> Result<()> {
+        let mut blocks: BTreeMap<u64, MetadataChanges> = BTreeMap::new();
+        if self.height != 0 {
+            blocks.insert(self.first_height, Vec::new());
+            blocks.insert(self.height, Vec::new());
+        }
+        for (entry, values) in &self.values {
+            for (block_height, value) in values {
+                blocks
+                    .entry(*block_height)
+                    .or_default()
+                    .push((entry.clone(), value.clone()));
+            }
+        }
+
+        let tmp_path = self.log_path.with_file_name(METADATA_HISTORY_TMP_FILE_NAME);
+        let mut log = BufWriter::new(fs::File::create(&tmp_path).add_err_context(&tmp_path)?);
+        for record in blocks {
+            log.write_all(&record.encode().encode())
+                .add_err_context(&tmp_path)?;
+        }
+        log.into_inner()
+            .map_err(std::io::IntoInnerError::into_error)
+            .and_then(|log| log.sync_all())
+            .add_err_context(&tmp_path)?;
+        fs::rename(&tmp_path, &self.log_path).add_err_context(&self.log_path)?;
+        match self.log_path.parent() {
+            Some(store_dir) => sync_dir(store_dir),
+            None => Ok(()),
+        }
+    }
+
+    /// Value of `entry` as of the block at `block_height`, `None` unless the history covers it.
+    fn value_at(
+        &self,
+        entry: &RemoveKeyValueBox,
+        block_height: u64,
+    ) -> Option<Option<MetadataValueBox>> {
+        // Values set before the first indexed block are unknown
+        if self.first_height != 1 || block_height > self.height {
+            return None;
+        }
+
+        let value = self.values.get(entry).and_then(|values| {
+            let after = values.partition_point(|(height, _)| *height <= block_height);
+            values[..after].last()?.1.clone()
+        });
+        Some(value)
+    }
 }
 
 /// Loaded block count
@@ -365,6 +934,7 @@ pub struct BlockCount(pub usize);
 #[derive(Debug)]
 pub struct BlockStore {
     path_to_blockchain: PathBuf,
+    compression: Compression,
 }
 
 impl Drop for BlockStore {
@@ -434,9 +1004,18 @@ impl BlockStore {
         }
         BlockStore {
             path_to_blockchain: store_path.as_ref().to_path_buf(),
+            compression: Compression::None,
         }
     }
 
+    /// Compress blocks appended to this block store. Blocks which are
+    /// already stored are read regardless of their compression.
+    #[must_use]
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     /// Read a series of block indices from the block index file and
     /// attempt to fill all of `dest_buffer`.
     ///
@@ -743,6 +1322,196 @@ impl BlockStore {
         Ok(())
     }
 
+    /// Get the number of the first blocks whose bodies were pruned,
+    /// i.e. for which only the encoded header is stored in the data file.
+    ///
+    /// # Errors
+    /// IO Error.
+    pub fn read_pruned_count(&self) -> Result<u64> {
+        let path = self.path_to_blockchain.join(PRUNED_FILE_NAME);
+        let mut buffer = [0; core::mem::size_of::<u64>()];
+        match fs::File::open(&path) {
+            Ok(mut pruned_file) => {
+                pruned_file.read_exact(&mut buffer).add_err_context(&path)?;
+                Ok(u64::from_le_bytes(buffer))
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(0),
+            Err(error) => Err(Error::IO(error, path)),
+        }
+    }
+
+    /// Replace bodies of the blocks below `new_pruned_count` with their headers.
+    ///
+    /// Only the data of the blocks which weren't pruned yet is rewritten, in place.
+    /// It's first stored in a journal, so that a crash in the middle of the rewrite
+    /// is recovered from with [`Self::recover_pruning`].
+    ///
+    /// # Errors
+    /// IO Error, or a stored block can't be decoded.
+    pub fn prune_block_bodies(&mut self, new_pruned_count: u64) -> Result<()> {
+        if let Some(journal) = self.commit_pruning(new_pruned_count)? {
+            self.apply_prune_journal(journal)?;
+        }
+        Ok(())
+    }
+
+    /// Finish pruning of block bodies interrupted by a crash, if there was any.
+    /// Must be called before the block store is read.
+    ///
+    /// # Errors
+    /// IO Error, or the journal is corrupted.
+    pub fn recover_pruning(&mut self) -> Result<()> {
+        let tmp_journal_path = self.path_to_blockchain.join(PRUNE_JOURNAL_TMP_FILE_NAME);
+        match fs::remove_file(&tmp_journal_path) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+                return Err(Error::IO(error, tmp_journal_path))
+            }
+            _ => {}
+        }
+
+        let journal_path = self.path_to_blockchain.join(PRUNE_JOURNAL_FILE_NAME);
+        let journal = match fs::read(&journal_path) {
+            Ok(journal) => PruneJournal::decode_all(&mut journal.as_slice())?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(Error::IO(error, journal_path)),
+        };
+        warn!(
+            pruned_count = journal.pruned_count,
+            "Block bodies pruning was interrupted, finishing it"
+        );
+        self.apply_prune_journal(journal)
+    }
+
+    /// Whether pruning of block bodies was committed, but not yet applied.
+    pub fn has_pending_pruning(&self) -> bool {
+        self.path_to_blockchain
+            .join(PRUNE_JOURNAL_FILE_NAME)
+            .exists()
+    }
+
+    /// Store the new data of the blocks from the pruned count on in the journal.
+    /// Returns `None` if there is nothing to prune.
+    fn commit_pruning(&mut self, new_pruned_count: u64) -> Result<Option<PruneJournal>> {
+        let block_count = self.read_index_count()?;
+        let pruned_count = self.read_pruned_count()?.min(block_count);
+        let new_pruned_count = new_pruned_count.min(block_count);
+        if new_pruned_count <= pruned_count {
+            return Ok(None);
+        }
+
+        let mut block_indices =
+            vec![BlockIndex::default(); (block_count - pruned_count).try_into()?];
+        self.read_block_indices(pruned_count, &mut block_indices)?;
+        let mut journal = PruneJournal {
+            pruned_count: new_pruned_count,
+            first_block_height: pruned_count,
+            data_start: block_indices[0].start,
+            data: Vec::new(),
+            lengths: Vec::with_capacity(block_indices.len()),
+        };
+        for (block_height, block_index) in (pruned_count..).zip(&block_indices) {
+            let mut block_data = vec![0_u8; block_index.length.try_into()?];
+            self.read_block_data(block_index.start, &mut block_data)?;
+            if block_height < new_pruned_count {
+                block_data = decode_stored_block(&block_data)?.header().encode();
+            }
+            journal.lengths.push(block_data.len() as u64);
+            journal.data.append(&mut block_data);
+        }
+
+        // The journal only counts once it's complete on disk
+        let tmp_journal_path = self.path_to_blockchain.join(PRUNE_JOURNAL_TMP_FILE_NAME);
+        write_synced(&tmp_journal_path, &journal.encode())?;
+        let journal_path = self.path_to_blockchain.join(PRUNE_JOURNAL_FILE_NAME);
+        fs::rename(&tmp_journal_path, &journal_path).add_err_context(&journal_path)?;
+        sync_dir(&self.path_to_blockchain)?;
+
+        Ok(Some(journal))
+    }
+
+    /// Write the data and the indices of the blocks from the `journal`, update the pruned count
+    /// and remove the journal. Applying it again has no further effect.
+    fn apply_prune_journal(&mut self, journal: PruneJournal) -> Result<()> {
+        let data_path = self.path_to_blockchain.join(DATA_FILE_NAME);
+        let mut data_file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(data_path.clone())
+            .add_err_context(&data_path)?;
+        data_file
+            .seek(SeekFrom::Start(journal.data_start))
+            .add_err_context(&data_path)?;
+        data_file
+            .write_all(&journal.data)
+            .add_err_context(&data_path)?;
+        data_file
+            .set_len(journal.data_start + journal.data.len() as u64)
+            .add_err_context(&data_path)?;
+        data_file.sync_all().add_err_context(&data_path)?;
+
+        let index_path = self.path_to_blockchain.join(INDEX_FILE_NAME);
+        let mut index_file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(index_path.clone())
+            .add_err_context(&index_path)?;
+        index_file
+            .seek(SeekFrom::Start(
+                journal.first_block_height * (2 * std::mem::size_of::<u64>() as u64),
+            ))
+            .add_err_context(&index_path)?;
+        let mut index_file = BufWriter::new(index_file);
+        let mut start = journal.data_start;
+        for length in &journal.lengths {
+            index_file
+                .write_all(&start.to_le_bytes())
+                .add_err_context(&index_path)?;
+            index_file
+                .write_all(&length.to_le_bytes())
+                .add_err_context(&index_path)?;
+            start += length;
+        }
+        let index_file = index_file
+            .into_inner()
+            .map_err(std::io::IntoInnerError::into_error)
+            .add_err_context(&index_path)?;
+        index_file
+            .set_len(
+                (journal.first_block_height + journal.lengths.len() as u64)
+                    * (2 * std::mem::size_of::<u64>() as u64),
+            )
+            .add_err_context(&index_path)?;
+        index_file.sync_all().add_err_context(&index_path)?;
+
+        self.write_pruned_count(journal.pruned_count)?;
+        let journal_path = self.path_to_blockchain.join(PRUNE_JOURNAL_FILE_NAME);
+        fs::remove_file(&journal_path).add_err_context(&journal_path)?;
+        sync_dir(&self.path_to_blockchain)
+    }
+
+    /// Set the number of the first blocks whose bodies were pruned.
+    /// The count is replaced atomically, so that a crash doesn't leave it torn.
+    ///
+    /// # Errors
+    /// IO Error.
+    pub fn write_pruned_count(&mut self, pruned_count: u64) -> Result<()> {
+        let tmp_path = self.path_to_blockchain.join(PRUNED_TMP_FILE_NAME);
+        write_synced(&tmp_path, &pruned_count.to_le_bytes())?;
+        let path = self.path_to_blockchain.join(PRUNED_FILE_NAME);
+        fs::rename(&tmp_path, &path).add_err_context(&path)?;
+        sync_dir(&self.path_to_blockchain)
+    }
+
+    /// Read and decode the block at the specified `block_height`.
+    /// Must not be called for blocks whose bodies were pruned.
+    ///
+    /// # Errors
+    /// IO Error, or the stored block can't be decoded.
+    pub fn read_block(&self, block_height: u64) -> Result<SignedBlock> {
+        let BlockIndex { start, length } = self.read_block_index(block_height)?;
+        let mut block_data = vec![0_u8; length.try_into()?];
+        self.read_block_data(start, &mut block_data)?;
+        decode_stored_block(&block_data)
+    }
+
     /// Create the index and data files if they do not
     /// already exist.
     ///
@@ -781,7 +1550,11 @@ impl BlockStore {
     /// Fails if any of the required platform-specific functions
     /// fail.
     pub fn append_block_to_chain(&mut self, block: &SignedBlock) -> Result<()> {
-        let bytes = block.encode_versioned();
+        let bytes = match self.compression {
+            Compression::None => block.encode_versioned(),
+            Compression::Zstd => zstd::stream::encode_all(block.encode_versioned().as_slice(), 0)
+                .map_err(Error::Compression)?,
+        };
         let new_block_height = self.read_index_count()?;
         let start_location_in_data_file = if new_block_height == 0 {
             0
@@ -802,6 +1575,45 @@ impl BlockStore {
     }
 }
 
+/// New data of the blocks from the first one whose body wasn't pruned yet, written
+/// before it replaces the data in the block store.
+#[derive(Encode, Decode)]
+struct PruneJournal {
+    /// Number of the first blocks whose bodies are pruned once the journal is applied.
+    pruned_count: u64,
+    /// Height of the first block whose data is replaced, counting from 0.
+    first_block_height: u64,
+    /// Position of the data of that block in the data file.
+    data_start: u64,
+    /// New data of the blocks, one after another.
+    data: Vec<u8>,
+    /// Length of the new data of each block.
+    lengths: Vec<u64>,
+}
+
+/// Write `data` to the file at `path` and make sure it has reached the disk.
+fn write_synced(path: &Path, data: &[u8]) -> Result<()> {
+    let mut file = fs::File::create(path).add_err_context(&path.to_path_buf())?;
+    file.write_all(data).add_err_context(&path.to_path_buf())?;
+    file.sync_all().add_err_context(&path.to_path_buf())
+}
+
+/// Make sure that the files created, renamed or removed in `dir` have reached the disk.
+fn sync_dir(dir: &Path) -> Result<()> {
+    fs::File::open(dir)
+        .and_then(|dir| dir.sync_all())
+        .add_err_context(&dir.to_path_buf())
+}
+
+/// Decode a block as it is stored in the data file, decompressing it if needed.
+fn decode_stored_block(block_data: &[u8]) -> Result<SignedBlock> {
+    if block_data.starts_with(&ZSTD_MAGIC_NUMBER) {
+        let block_data = zstd::stream::decode_all(block_data).map_err(Error::Compression)?;
+        return Ok(SignedBlock::decode_all_versioned(&block_data)?);
+    }
+    Ok(SignedBlock::decode_all_versioned(block_data)?)
+}
+
 type Result<T, E = Error> = std::result::Result<T, E>;
 /// Error variants for persistent storage logic
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
@@ -827,6 +1639,12 @@ pub enum Error {
     IntConversion(#[from] std::num::TryFromIntError),
     /// Blocks count differs hashes file and index file
     HashesFileHeightMismatch,
+    /// Block at height {0} doesn't match its hash in the hashes file
+    BlockHashMismatch(u64),
+    /// Failed to decode a versioned block
+    Version(#[from] iroha_version::error::Error),
+    /// Failed to compress or decompress block data
+    Compression(#[source] std::io::Error),
 }
 
 trait AddErrContextExt<T> {
@@ -846,6 +1664,7 @@ impl<T> AddErrContextExt<T> for Result<T, std::io::Error> {
 #[cfg(test)]
 mod tests {
 
+    use iroha_data_model::{domain::DomainId, isi::RemoveKeyValue};
     use tempfile::TempDir;
 
     use super::*;
@@ -959,6 +1778,87 @@ mod tests {
         assert_eq!(append_count, block_store.read_index_count().unwrap());
     }
 
+    #[test]
+    fn prune_block_bodies_keeps_headers() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut block_store = BlockStore::new(dir.path(), LockStatus::Unlocked);
+        block_store.create_files_if_they_do_not_exist().unwrap();
+
+        let dummy_block: SignedBlock = ValidBlock::new_dummy().into();
+        for _ in 0..5 {
+            block_store.append_block_to_chain(&dummy_block).unwrap();
+        }
+        block_store.prune_block_bodies(3).unwrap();
+
+        assert_eq!(3, block_store.read_pruned_count().unwrap());
+        assert_eq!(5, block_store.read_index_count().unwrap());
+        assert_eq!(5, block_store.read_hashes_count().unwrap());
+
+        let read_data = |height| {
+            let BlockIndex { start, length } = block_store.read_block_index(height).unwrap();
+            let mut buffer = vec![0_u8; usize::try_from(length).unwrap()];
+            block_store.read_block_data(start, &mut buffer).unwrap();
+            buffer
+        };
+        for height in 0..3 {
+            let header = BlockHeader::decode_all(&mut read_data(height).as_slice()).unwrap();
+            assert_eq!(&header, dummy_block.header());
+        }
+        for height in 3..5 {
+            let block = SignedBlock::decode_all_versioned(&read_data(height)).unwrap();
+            assert_eq!(block, dummy_block);
+        }
+    }
+
+    #[test]
+    fn prune_block_bodies_is_finished_after_crash() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut block_store = BlockStore::new(dir.path(), LockStatus::Unlocked);
+        block_store.create_files_if_they_do_not_exist().unwrap();
+
+        let dummy_block: SignedBlock = ValidBlock::new_dummy().into();
+        for _ in 0..5 {
+            block_store.append_block_to_chain(&dummy_block).unwrap();
+        }
+        block_store.prune_block_bodies(2).unwrap();
+        let pruned_header_data = {
+            let BlockIndex { start, length } = block_store.read_block_index(1).unwrap();
+            let mut buffer = vec![0_u8; usize::try_from(start + length).unwrap()];
+            block_store.read_block_data(0, &mut buffer).unwrap();
+            buffer
+        };
+
+        // A crash tore the data file after the journal was committed
+        let journal = block_store.commit_pruning(4).unwrap().unwrap();
+        assert_eq!(journal.first_block_height, 2);
+        assert!(block_store.has_pending_pruning());
+        block_store
+            .write_block_data(journal.data_start, &journal.data[..journal.data.len() / 2])
+            .unwrap();
+
+        block_store.recover_pruning().unwrap();
+        assert!(!block_store.has_pending_pruning());
+        assert_eq!(4, block_store.read_pruned_count().unwrap());
+        assert_eq!(5, block_store.read_index_count().unwrap());
+
+        let read_data = |height| {
+            let BlockIndex { start, length } = block_store.read_block_index(height).unwrap();
+            let mut buffer = vec![0_u8; usize::try_from(length).unwrap()];
+            block_store.read_block_data(start, &mut buffer).unwrap();
+            buffer
+        };
+        // Headers pruned earlier aren't rewritten
+        let mut buffer = vec![0_u8; pruned_header_data.len()];
+        block_store.read_block_data(0, &mut buffer).unwrap();
+        assert_eq!(buffer, pruned_header_data);
+        for height in 0..4 {
+            let header = BlockHeader::decode_all(&mut read_data(height).as_slice()).unwrap();
+            assert_eq!(&header, dummy_block.header());
+        }
+        let block = SignedBlock::decode_all_versioned(&read_data(4)).unwrap();
+        assert_eq!(block, dummy_block);
+    }
+
     #[test]
     fn append_block_to_chain_increases_hashes_count() {
         let dir = tempfile::tempdir().unwrap();
@@ -995,6 +1895,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compressed_blocks_are_decoded() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut block_store =
+            BlockStore::new(dir.path(), LockStatus::Unlocked).with_compression(Compression::Zstd);
+        block_store.create_files_if_they_do_not_exist().unwrap();
+
+        let dummy_block: SignedBlock = ValidBlock::new_dummy().into();
+        block_store.append_block_to_chain(&dummy_block).unwrap();
+        // Switching compression off doesn't affect already stored blocks
+        let mut block_store = block_store.with_compression(Compression::None);
+        block_store.append_block_to_chain(&dummy_block).unwrap();
+
+        for height in 0..2 {
+            let BlockIndex { start, length } = block_store.read_block_index(height).unwrap();
+            let mut block_data = vec![0_u8; usize::try_from(length).unwrap()];
+            block_store.read_block_data(start, &mut block_data).unwrap();
+            assert_eq!(
+                block_data.starts_with(&ZSTD_MAGIC_NUMBER),
+                height == 0,
+                "only the first block is compressed"
+            );
+            assert_eq!(decode_stored_block(&block_data).unwrap(), dummy_block);
+        }
+    }
+
     #[test]
     fn append_block_to_chain_places_blocks_correctly_in_data_file() {
         let dir = tempfile::tempdir().unwrap();
@@ -1056,8 +1982,185 @@ mod tests {
             store_dir: iroha_config::base::WithOrigin::inline(
                 temp_dir.path().to_str().unwrap().into(),
             ),
+            index_metadata_history: false,
+            index_transactions: false,
+            blocks_retention: None,
+            compression: Compression::None,
+            debug_output_new_blocks: false,
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn torn_block_is_truncated_on_init() {
+        let temp_dir = TempDir::new().unwrap();
+        {
+            let mut block_store = BlockStore::new(temp_dir.path(), LockStatus::Unlocked);
+            block_store.create_files_if_they_do_not_exist().unwrap();
+            let dummy_block: SignedBlock = ValidBlock::new_dummy().into();
+            block_store.append_block_to_chain(&dummy_block).unwrap();
+
+            // Simulate a crash in the middle of writing the second block
+            let block_data = dummy_block.encode_versioned();
+            let torn_block_data = &block_data[..block_data.len() / 2];
+            let BlockIndex { start, length } = block_store.read_block_index(0).unwrap();
+            block_store
+                .write_block_data(start + length, torn_block_data)
+                .unwrap();
+            block_store
+                .write_block_index(1, start + length, torn_block_data.len() as u64)
+                .unwrap();
+            block_store.write_block_hash(1, dummy_block.hash()).unwrap();
+        }
+
+        let (kura, BlockCount(block_count)) = Kura::new(&Config {
+            init_mode: InitMode::Fast,
+            store_dir: iroha_config::base::WithOrigin::inline(
+                temp_dir.path().to_str().unwrap().into(),
+            ),
+            index_metadata_history: false,
+            index_transactions: false,
+            blocks_retention: None,
+            compression: Compression::None,
+            debug_output_new_blocks: false,
+        })
+        .unwrap();
+        assert_eq!(block_count, 1);
+        let block_store = kura.block_store.lock();
+        assert_eq!(block_store.read_index_count().unwrap(), 1);
+        assert_eq!(block_store.read_hashes_count().unwrap(), 1);
+    }
+
+    #[test]
+    fn transaction_index_is_truncated_on_reindex() {
+        let temp_dir = TempDir::new().unwrap();
+        let (kura, _) = Kura::new(&Config {
+            init_mode: InitMode::Strict,
+            store_dir: iroha_config::base::WithOrigin::inline(
+                temp_dir.path().to_str().unwrap().into(),
+            ),
+            index_metadata_history: false,
+            index_transactions: true,
+            blocks_retention: None,
+            compression: Compression::None,
             debug_output_new_blocks: false,
         })
         .unwrap();
+        let (alice_id, _) = test_samples::gen_account_in("wonderland");
+        let (bob_id, _) = test_samples::gen_account_in("wonderland");
+
+        kura.index_transaction_authorities(1, [&alice_id, &alice_id]);
+        kura.index_transaction_authorities(2, [&bob_id]);
+        kura.index_transaction_authorities(3, [&alice_id, &bob_id]);
+        assert_eq!(
+            kura.transaction_heights_by_authority(&alice_id, 3),
+            Some(vec![1, 3])
+        );
+
+        // Block 3 was replaced with a block without transactions of alice
+        kura.index_transaction_authorities(3, [&bob_id]);
+        assert_eq!(
+            kura.transaction_heights_by_authority(&alice_id, 3),
+            Some(vec![1])
+        );
+        assert_eq!(
+            kura.transaction_heights_by_authority(&bob_id, 3),
+            Some(vec![2, 3])
+        );
+        assert_eq!(
+            kura.transaction_heights_by_authority(&bob_id, 2),
+            Some(vec![2])
+        );
+    }
+
+    #[test]
+    fn transaction_index_covers_chain_once_reindexed() {
+        let (alice_id, _) = test_samples::gen_account_in("wonderland");
+        let mut index = TransactionIndex::default();
+
+        // Blocks up to 2 were restored from a snapshot, so indexing starts after them
+        index.index_block(3, [&alice_id]);
+        assert_eq!(index.first_height, 3);
+
+        let mut reindexed = TransactionIndex::default();
+        reindexed.index_block(1, [&alice_id]);
+        reindexed.index_block(2, core::iter::empty());
+        reindexed.index_block(3, core::iter::empty());
+        index.prepend(reindexed);
+        assert_eq!(index.first_height, 1);
+        assert_eq!(index.height, 3);
+        assert_eq!(index.heights_by_authority[&alice_id], vec![1, 3]);
+    }
+
+    #[test]
+    fn metadata_history_is_truncated_on_reindex() {
+        let temp_dir = TempDir::new().unwrap();
+        let kura = Kura::new(&Config {
+            init_mode: InitMode::Strict,
+            store_dir: iroha_config::base::WithOrigin::inline(
+                temp_dir.path().to_str().unwrap().into(),
+            ),
+            index_metadata_history: true,
+            index_transactions: false,
+            blocks_retention: None,
+            compression: Compression::None,
+            debug_output_new_blocks: false,
+        })
+        .unwrap();
+        let (alice_id, _) = test_samples::gen_account_in("wonderland");
+        let entry: RemoveKeyValueBox =
+            RemoveKeyValue::account(alice_id, "key".parse().unwrap()).into();
+        let change = |value: Option<u32>| (entry.clone(), value.map(Into::into));
+
+        kura.index_metadata_changes(1, []);
+        kura.index_metadata_changes(2, [change(Some(1))]);
+        kura.index_metadata_changes(3, []);
+        kura.index_metadata_changes(4, [change(None)]);
+        kura.index_metadata_changes(5, [change(Some(2))]);
+        assert_eq!(kura.metadata_value_at(&entry, 1), Some(None));
+        assert_eq!(kura.metadata_value_at(&entry, 3), Some(Some(1_u32.into())));
+        assert_eq!(kura.metadata_value_at(&entry, 4), Some(None));
+        assert_eq!(kura.metadata_value_at(&entry, 6), None);
+
+        // Block 5 was replaced with a block which didn't touch the entry
+        kura.index_metadata_changes(5, []);
+        assert_eq!(kura.metadata_value_at(&entry, 5), Some(None));
+        assert_eq!(kura.metadata_value_at(&entry, 2), Some(Some(1_u32.into())));
+    }
+
+    #[test]
+    fn metadata_history_is_restored_from_log() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join(METADATA_HISTORY_FILE_NAME);
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let entry: RemoveKeyValueBox =
+            RemoveKeyValue::domain(domain_id, "key".parse().unwrap()).into();
+        let change = |value: u32| (entry.clone(), Some(value.into()));
+
+        let mut history = MetadataHistory::new(log_path.clone());
+        history.index_block(1, vec![change(1)]).unwrap();
+        history.index_block(2, Vec::new()).unwrap();
+        history.index_block(3, vec![change(3)]).unwrap();
+        // Block 3 was replaced
+        history.index_block(3, vec![change(4)]).unwrap();
+        history.index_block(4, vec![change(5)]).unwrap();
+
+        // A crash tore the record of the latest block
+        let log = fs::read(&log_path).unwrap();
+        fs::write(&log_path, &log[..log.len() - 1]).unwrap();
+
+        let mut restored = MetadataHistory::new(log_path.clone());
+        restored.load(4).unwrap();
+        assert_eq!(restored.first_height, 1);
+        assert_eq!(restored.height, 3);
+        assert_eq!(restored.value_at(&entry, 2), Some(Some(1_u32.into())));
+        assert_eq!(restored.value_at(&entry, 3), Some(Some(4_u32.into())));
+
+        // The rewritten log holds the same history
+        let mut reloaded = MetadataHistory::new(log_path);
+        reloaded.load(2).unwrap();
+        assert_eq!(reloaded.height, 2);
+        assert_eq!(reloaded.value_at(&entry, 2), Some(Some(1_u32.into())));
+        assert_eq!(reloaded.value_at(&entry, 3), None);
     }
 }
@@ -34,12 +34,14 @@ impl MetricsReporter {
         kura: Arc<Kura>,
         queue: Arc<Queue>,
     ) -> Self {
+        let metrics = Metrics::default();
+        metrics.archival.set(kura.is_archival().into());
         Self {
             state,
             network,
             queue,
             kura,
-            metrics: Metrics::default(),
+            metrics,
             latest_block_height: Arc::new(Mutex::new(0)),
         }
     }
@@ -69,6 +71,14 @@ impl MetricsReporter {
         let start_index = *lastest_block_height;
         {
             let mut block_index = start_index;
+            // Transactions can't be counted in blocks whose bodies were pruned
+            let pruned_height = self.kura.pruned_height().min(state_view.height());
+            if block_index < pruned_height {
+                self.metrics
+                    .block_height
+                    .inc_by(pruned_height - block_index);
+                block_index = pruned_height;
+            }
             while block_index < state_view.height() {
                 let Some(block) = self.kura.get_block_by_height(block_index + 1) else {
                     break;
@@ -127,6 +137,21 @@ impl MetricsReporter {
         };
 
         self.metrics.connected_peers.set(online_peers_count);
+        // Peers removed from the network shouldn't be reported
+        self.metrics.peer_connected.reset();
+        self.network.online_peers(|online_peers| {
+            let self_public_key = self.network.public_key();
+            for peer_id in state_view.world().peers() {
+                if peer_id.public_key() == self_public_key {
+                    continue;
+                }
+                let public_key = peer_id.public_key().to_string();
+                self.metrics
+                    .peer_connected
+                    .with_label_values(&[public_key.as_str()])
+                    .set(online_peers.contains(peer_id).into());
+            }
+        });
 
         self.metrics
             .domains
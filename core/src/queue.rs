@@ -1,17 +1,20 @@
 //! Module with queue actor
 use core::time::Duration;
-use std::num::NonZeroUsize;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    num::NonZeroUsize,
+};
 
 use crossbeam_queue::ArrayQueue;
-use dashmap::{mapref::entry::Entry, DashMap};
+use dashmap::{mapref::entry::Entry, DashMap, DashSet};
 use eyre::Result;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use iroha_config::parameters::actual::Queue as Config;
-use iroha_crypto::HashOf;
+use iroha_crypto::{HashOf, SignatureOf};
 use iroha_data_model::{
-    account::AccountId,
+    account::{AccountId, SignatureCondition},
     events::pipeline::{TransactionEvent, TransactionStatus},
-    transaction::prelude::*,
+    transaction::{prelude::*, TransactionPayload},
 };
 use iroha_logger::{trace, warn};
 use iroha_primitives::time::TimeSource;
@@ -22,15 +25,50 @@ use crate::{prelude::*, EventsSender};
 
 impl AcceptedTransaction {
     // TODO: We should have another type of transaction like `CheckedTransaction` in the type system?
-    fn is_signatory_consistent(&self) -> bool {
-        let authority = self.as_ref().authority();
-        let signatory = self.as_ref().signature().public_key();
-        authority.signatory_matches(signatory)
+    fn is_signatory_consistent(&self, state_view: &StateView<'_>) -> bool {
+        let tx = self.as_ref();
+        match state_view.world().account(tx.authority()) {
+            Ok(account) => account.is_signed_by(tx.signatories()),
+            // The transaction is rejected during validation if its authority doesn't exist
+            Err(_) => {
+                tx.cosignatures().is_empty()
+                    && tx
+                        .authority()
+                        .signatory_matches(tx.signature().public_key())
+            }
+        }
+    }
+
+    /// Signature condition of the authority account, if it is a multisignature account.
+    fn signature_condition<'state>(
+        &self,
+        state_view: &'state StateView<'_>,
+    ) -> Option<&'state SignatureCondition> {
+        state_view
+            .world()
+            .account(self.as_ref().authority())
+            .ok()
+            .and_then(|account| account.signature_condition.as_ref())
     }
 
-    /// Check if [`self`] is committed or rejected.
+    /// Check if [`self`] is committed or rejected, possibly with other signatures.
     fn is_in_blockchain(&self, state_view: &StateView<'_>) -> bool {
-        state_view.has_transaction(self.as_ref().hash())
+        state_view.has_transaction_payload(self.as_ref().payload_hash())
+    }
+
+    /// Check if the authority is in strict ordering mode and [`self`] lacks
+    /// a sequence number or carries one which was already used.
+    fn is_sequence_outdated(&self, state_view: &StateView<'_>) -> bool {
+        state_view
+            .world()
+            .account(self.as_ref().authority())
+            .ok()
+            .filter(|account| account.strict_ordering)
+            .is_some_and(|account| {
+                self.as_ref()
+                    .sequence()
+                    .map_or(true, |sequence| sequence.get() <= account.sequence)
+            })
     }
 }
 
@@ -44,12 +82,20 @@ pub struct Queue {
     tx_hashes: ArrayQueue<HashOf<SignedTransaction>>,
     /// [`AcceptedTransaction`]s addressed by `Hash`
     accepted_txs: DashMap<HashOf<SignedTransaction>, AcceptedTransaction>,
+    /// Payload `Hash`es of [`Self::accepted_txs`], to tell apart transactions differing only in signatures
+    accepted_payloads: DashSet<HashOf<TransactionPayload>>,
+    /// Transactions of multisignature accounts waiting for more signatures, addressed by payload `Hash`
+    multisig_txs: DashMap<HashOf<TransactionPayload>, AcceptedTransaction>,
+    /// Transactions cancelled by their authorities along with the cancellations, addressed by `Hash`
+    cancelled_txs: DashMap<HashOf<SignedTransaction>, (AcceptedTransaction, CancelTransaction)>,
     /// Amount of transactions per user in the queue
     txs_per_user: DashMap<AccountId, usize>,
     /// The maximum number of transactions in the queue
     capacity: NonZeroUsize,
     /// The maximum number of transactions in the queue per user. Used to apply throttling
     capacity_per_user: NonZeroUsize,
+    /// The maximum number of transactions of a user put into one block
+    block_capacity_per_user: NonZeroUsize,
     /// The time source used to check transaction against
     ///
     /// A mock time source is used in tests for determinism
@@ -79,6 +125,16 @@ pub enum Error {
     IsInQueue,
     /// Signatories in signature and payload mismatch
     SignatoryInconsistent,
+    /// No transaction with the given payload waits for signatures
+    NotPending,
+    /// Signature doesn't match the transaction payload
+    InvalidSignature,
+    /// Sequence number of the transaction is missing or already used by its strictly ordered authority
+    SequenceOutdated,
+    /// No transaction with the given hash is in the queue
+    NotInQueue,
+    /// The transaction is cancelled by its authority
+    Cancelled,
 }
 
 /// Failure that can pop up when pushing transaction into the queue
@@ -96,6 +152,7 @@ impl Queue {
         Config {
             capacity,
             capacity_per_user,
+            block_capacity_per_user,
             transaction_time_to_live,
             future_threshold,
         }: Config,
@@ -105,9 +162,13 @@ impl Queue {
             events_sender,
             tx_hashes: ArrayQueue::new(capacity.get()),
             accepted_txs: DashMap::new(),
+            accepted_payloads: DashSet::new(),
+            multisig_txs: DashMap::new(),
+            cancelled_txs: DashMap::new(),
             txs_per_user: DashMap::new(),
             capacity,
             capacity_per_user,
+            block_capacity_per_user,
             time_source: TimeSource::new_system(),
             tx_time_to_live: transaction_time_to_live,
             future_threshold,
@@ -138,6 +199,19 @@ impl Queue {
         tx_timestamp.saturating_sub(curr_time) > self.future_threshold
     }
 
+    /// If `false`, this transaction is scheduled after the next block and has to wait in the queue.
+    fn is_due(&self, tx: &AcceptedTransaction, state_view: &StateView) -> bool {
+        tx.as_ref().not_before().map_or(true, |not_before| {
+            let curr_time_ms = self
+                .time_source
+                .get_unix_time()
+                .as_millis()
+                .try_into()
+                .expect("Time should fit into u64");
+            not_before.is_reached(state_view.height() + 1, curr_time_ms)
+        })
+    }
+
     /// Returns all pending transactions.
     pub fn all_transactions<'state>(
         &'state self,
@@ -152,6 +226,29 @@ impl Queue {
         })
     }
 
+    /// Returns the number of pending transactions, overall and per authority,
+    /// along with the creation time of the oldest of them.
+    pub fn status(&self, state_view: &StateView) -> QueueStatus {
+        let mut len_per_authority = BTreeMap::<AccountId, u64>::new();
+        let mut oldest_creation_time = None;
+
+        for tx in self.all_transactions(state_view) {
+            let tx = tx.as_ref();
+            *len_per_authority.entry(tx.authority().clone()).or_default() += 1;
+            oldest_creation_time = Some(
+                oldest_creation_time.map_or(tx.creation_time(), |oldest: Duration| {
+                    oldest.min(tx.creation_time())
+                }),
+            );
+        }
+
+        QueueStatus {
+            len: len_per_authority.values().sum(),
+            len_per_authority,
+            oldest_creation_time,
+        }
+    }
+
     /// Returns `n` randomly selected transaction from the queue.
     pub fn n_random_transactions(
         &self,
@@ -175,19 +272,187 @@ impl Queue {
             Err(Error::Expired)
         } else if tx.is_in_blockchain(state_view) {
             Err(Error::InBlockchain)
-        } else if !tx.is_signatory_consistent() {
+        } else if !tx.is_signatory_consistent(state_view) {
             Err(Error::SignatoryInconsistent)
+        } else if tx.is_sequence_outdated(state_view) {
+            Err(Error::SequenceOutdated)
+        } else if self.cancelled_txs.contains_key(&tx.as_ref().hash()) {
+            Err(Error::Cancelled)
         } else {
             Ok(())
         }
     }
 
+    /// Returns transactions of multisignature accounts which wait for more signatures.
+    ///
+    /// If `authority` is given, only the transactions of this account are returned.
+    pub fn pending_multisig_transactions(
+        &self,
+        authority: Option<&AccountId>,
+    ) -> Vec<AcceptedTransaction> {
+        self.multisig_txs.retain(|_, tx| !self.is_expired(tx));
+        self.multisig_txs
+            .iter()
+            .filter(|tx| authority.map_or(true, |authority| tx.as_ref().authority() == authority))
+            .map(|tx| tx.value().clone())
+            .collect()
+    }
+
+    /// Merge signatures of a transaction of a multisignature account with the ones received before.
+    ///
+    /// Returns the transaction once its signatures satisfy the signature condition
+    /// of its authority, and `None` while it waits for more signatures.
+    fn collect_signatures(
+        &self,
+        tx: AcceptedTransaction,
+        state_view: &StateView,
+    ) -> Result<Option<AcceptedTransaction>, Failure> {
+        let Some(condition) = tx.signature_condition(state_view) else {
+            return Ok(Some(tx));
+        };
+        if !tx
+            .as_ref()
+            .signatories()
+            .all(|signatory| condition.signatories().contains(signatory))
+        {
+            return Err(Failure {
+                tx,
+                err: Error::SignatoryInconsistent,
+            });
+        }
+        if self.is_in_future(&tx) {
+            return Err(Failure {
+                tx,
+                err: Error::InFuture,
+            });
+        }
+        if self.is_expired(&tx) {
+            return Err(Failure {
+                tx,
+                err: Error::Expired,
+            });
+        }
+
+        // Get `pending_len` before entry to avoid deadlock
+        let pending_len = self.multisig_txs.len();
+        match self.multisig_txs.entry(tx.as_ref().payload_hash()) {
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().0.merge_signatures(tx.as_ref());
+                if condition.is_satisfied_by(entry.get().as_ref().signatories()) {
+                    return Ok(Some(entry.remove()));
+                }
+            }
+            Entry::Vacant(entry) => {
+                if condition.is_satisfied_by(tx.as_ref().signatories()) {
+                    return Ok(Some(tx));
+                }
+                if pending_len >= self.capacity.get() {
+                    warn!(
+                        max = self.capacity,
+                        "Achieved maximum amount of transactions waiting for signatures"
+                    );
+                    return Err(Failure {
+                        tx,
+                        err: Error::Full,
+                    });
+                }
+                entry.insert(tx);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Attach the `signature` of a signatory to the transaction with the given payload
+    /// which waits for more signatures, and push the transaction into the queue once
+    /// its signatures satisfy the signature condition of its authority.
+    ///
+    /// # Errors
+    /// Fails if no such transaction waits for signatures, if `signature` is not a valid
+    /// signature of one of the signatories, or if pushing the transaction fails
+    pub fn attach_signature(
+        &self,
+        payload_hash: HashOf<TransactionPayload>,
+        signature: SignatureOf<TransactionPayload>,
+        state_view: &StateView,
+    ) -> Result<(), Error> {
+        let Entry::Occupied(mut entry) = self.multisig_txs.entry(payload_hash) else {
+            return Err(Error::NotPending);
+        };
+        let Some(condition) = entry.get().signature_condition(state_view) else {
+            return Err(Error::SignatoryInconsistent);
+        };
+        if !condition.signatories().contains(signature.public_key()) {
+            return Err(Error::SignatoryInconsistent);
+        }
+        entry
+            .get_mut()
+            .0
+            .add_signature(signature)
+            .map_err(|_| Error::InvalidSignature)?;
+        if !condition.is_satisfied_by(entry.get().as_ref().signatories()) {
+            return Ok(());
+        }
+
+        // Remove the entry before pushing to avoid deadlock
+        let tx = entry.remove();
+        self.push(tx, state_view).map_err(|Failure { err, .. }| err)
+    }
+
+    /// Remove the transaction from the queue on request of its authority.
+    ///
+    /// The cancellation is kept until the transaction expires, so that the
+    /// transaction isn't pushed again, e.g. when gossiped by other peers.
+    ///
+    /// # Errors
+    /// Fails if no such transaction is in the queue or if the cancellation isn't signed by its authority
+    pub fn cancel(&self, cancellation: CancelTransaction) -> Result<(), Error> {
+        let hash = *cancellation.hash();
+        cancellation
+            .signature()
+            .verify(&hash)
+            .map_err(|_| Error::InvalidSignature)?;
+        let Entry::Occupied(entry) = self.accepted_txs.entry(hash) else {
+            return Err(Error::NotInQueue);
+        };
+        if !entry
+            .get()
+            .as_ref()
+            .authority()
+            .signatory_matches(cancellation.signature().public_key())
+        {
+            return Err(Error::SignatoryInconsistent);
+        }
+
+        let tx = entry.remove();
+        self.accepted_payloads.remove(&tx.as_ref().payload_hash());
+        self.decrease_per_user_tx_count(tx.as_ref().authority());
+        self.cancelled_txs.insert(hash, (tx, cancellation));
+        Ok(())
+    }
+
+    /// Returns cancellations of the transactions which haven't expired yet.
+    pub fn cancellations(&self) -> Vec<CancelTransaction> {
+        self.cancelled_txs.retain(|_, (tx, _)| !self.is_expired(tx));
+        self.cancelled_txs
+            .iter()
+            .map(|entry| entry.value().1.clone())
+            .collect()
+    }
+
     /// Push transaction into queue.
     ///
+    /// Transactions of multisignature accounts are held back until enough of
+    /// their signatories have pushed the transaction signed by them.
+    ///
     /// # Errors
     /// See [`enum@Error`]
     pub fn push(&self, tx: AcceptedTransaction, state_view: &StateView) -> Result<(), Failure> {
         trace!(?tx, "Pushing to the queue");
+        let Some(tx) = self.collect_signatures(tx, state_view)? else {
+            trace!("Transaction waits for more signatures");
+            return Ok(());
+        };
         if let Err(err) = self.check_tx(&tx, state_view) {
             return Err(Failure { tx, err });
         }
@@ -216,7 +481,14 @@ impl Queue {
             });
         }
 
+        if !self.accepted_payloads.insert(tx.as_ref().payload_hash()) {
+            return Err(Failure {
+                tx,
+                err: Error::IsInQueue,
+            });
+        }
         if let Err(err) = self.check_and_increase_per_user_tx_count(tx.as_ref().authority()) {
+            self.accepted_payloads.remove(&tx.as_ref().payload_hash());
             return Err(Failure { tx, err });
         }
 
@@ -228,6 +500,8 @@ impl Queue {
                 .accepted_txs
                 .remove(&err_hash)
                 .expect("Inserted just before match");
+            self.accepted_payloads
+                .remove(&err_tx.as_ref().payload_hash());
             self.decrease_per_user_tx_count(err_tx.as_ref().authority());
             Failure {
                 tx: err_tx,
@@ -261,15 +535,18 @@ impl Queue {
                 // FIXME: Reachable under high load. Investigate, see if it's a problem.
                 // As practice shows this code is not `unreachable!()`.
                 // When transactions are submitted quickly it can be reached.
-                Entry::Vacant(_) => {
+                Entry::Vacant(_) if !self.cancelled_txs.contains_key(&hash) => {
                     warn!("Looks like we're experiencing a high load");
                     continue;
                 }
+                // Cancelled transactions leave their hashes behind
+                Entry::Vacant(_) => continue,
             };
 
             let tx = entry.get();
             if let Err(e) = self.check_tx(tx, state_view) {
                 let (_, tx) = entry.remove_entry();
+                self.accepted_payloads.remove(&tx.as_ref().payload_hash());
                 self.decrease_per_user_tx_count(tx.as_ref().authority());
                 if let Error::Expired = e {
                     expired_transactions.push(tx);
@@ -303,6 +580,10 @@ impl Queue {
 
     /// Put transactions into provided vector until they fill the whole block or there are no more transactions in the queue.
     ///
+    /// Transactions with higher tips are put first, while the transactions of every user keep
+    /// their order and at most `block_capacity_per_user` of them are put into the block.
+    /// Transactions scheduled after the next block stay in the queue.
+    ///
     /// BEWARE: Shouldn't be called in parallel with itself.
     pub fn get_transactions_for_block(
         &self,
@@ -323,10 +604,39 @@ impl Queue {
 
         let transactions_hashes: IndexSet<HashOf<SignedTransaction>> =
             transactions.iter().map(|tx| tx.as_ref().hash()).collect();
-        let txs = txs_from_queue
-            .filter(|tx| !transactions_hashes.contains(&tx.as_ref().hash()))
-            .take(max_txs_in_block - transactions.len());
-        transactions.extend(txs);
+        let mut txs_per_user = HashMap::<AccountId, usize>::new();
+        for tx in transactions.iter() {
+            *txs_per_user
+                .entry(tx.as_ref().authority().clone())
+                .or_default() += 1;
+        }
+        // Transactions of every user in the order of the queue
+        let mut candidates = IndexMap::<AccountId, VecDeque<AcceptedTransaction>>::new();
+        for tx in txs_from_queue.filter(|tx| {
+            !transactions_hashes.contains(&tx.as_ref().hash()) && self.is_due(tx, state_view)
+        }) {
+            candidates
+                .entry(tx.as_ref().authority().clone())
+                .or_default()
+                .push_back(tx);
+        }
+        while transactions.len() < max_txs_in_block {
+            // `max_by_key` returns the last maximum, so users are reversed to prefer earlier ones
+            let Some((authority, txs)) = candidates
+                .iter_mut()
+                .rev()
+                .filter(|(authority, _)| {
+                    txs_per_user.get(*authority).copied().unwrap_or_default()
+                        < self.block_capacity_per_user.get()
+                })
+                .filter(|(_, txs)| !txs.is_empty())
+                .max_by_key(|(_, txs)| txs.front().and_then(|tx| tx.as_ref().tip()))
+            else {
+                break;
+            };
+            *txs_per_user.entry(authority.clone()).or_default() += 1;
+            transactions.extend(txs.pop_front());
+        }
 
         seen_queue
             .into_iter()
@@ -385,7 +695,7 @@ impl Queue {
 #[cfg(test)]
 // this is `pub` to re-use internal utils
 pub mod tests {
-    use std::{str::FromStr, sync::Arc, thread, time::Duration};
+    use std::{num::NonZeroU64, str::FromStr, sync::Arc, thread, time::Duration};
 
     use iroha_data_model::{prelude::*, transaction::TransactionLimits};
     use nonzero_ext::nonzero;
@@ -408,9 +718,13 @@ pub mod tests {
                 events_sender: tokio::sync::broadcast::Sender::new(1),
                 tx_hashes: ArrayQueue::new(cfg.capacity.get()),
                 accepted_txs: DashMap::new(),
+                accepted_payloads: DashSet::new(),
+                multisig_txs: DashMap::new(),
+                cancelled_txs: DashMap::new(),
                 txs_per_user: DashMap::new(),
                 capacity: cfg.capacity,
                 capacity_per_user: cfg.capacity_per_user,
+                block_capacity_per_user: cfg.block_capacity_per_user,
                 time_source: time_source.clone(),
                 tx_time_to_live: cfg.transaction_time_to_live,
                 future_threshold: cfg.future_threshold,
@@ -444,6 +758,35 @@ pub mod tests {
         AcceptedTransaction::accept(tx, &chain_id, &limits).expect("Failed to accept Transaction.")
     }
 
+    fn test_tx_limits() -> TransactionLimits {
+        TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        }
+    }
+
+    fn multisig_tx_by(
+        account_id: AccountId,
+        key_pair: &KeyPair,
+        time_source: &TimeSource,
+    ) -> SignedTransaction {
+        TransactionBuilder::new_with_time_source(ChainId::from("0"), account_id, time_source)
+            .with_instructions([Fail::new("multisig".to_owned())])
+            .sign(key_pair)
+    }
+
+    fn world_with_multisig_account(account_id: &AccountId, signatories: [&KeyPair; 2]) -> World {
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(account_id);
+        let mut account = Account::new(account_id.clone()).build(account_id);
+        account.signature_condition = Some(SignatureCondition::new(
+            signatories.map(|key_pair| key_pair.public_key().clone()),
+            nonzero!(2_u16),
+        ));
+        assert!(domain.add_account(account).is_none());
+        World::with([domain], PeersIds::new())
+    }
+
     pub fn world_with_test_domains() -> World {
         let domain_id = DomainId::from_str("wonderland").expect("Valid");
         let (account_id, _account_keypair) = gen_account_in("wonderland");
@@ -477,6 +820,358 @@ pub mod tests {
             .expect("Failed to push tx into queue");
     }
 
+    #[test]
+    async fn push_multisig_tx() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (_bob_id, bob_keypair) = gen_account_in("wonderland");
+        let (_carol_id, carol_keypair) = gen_account_in("wonderland");
+        let world = world_with_multisig_account(&alice_id, [&alice_keypair, &bob_keypair]);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world, kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let tx = multisig_tx_by(alice_id.clone(), &alice_keypair, &time_source);
+        let accept = |tx: SignedTransaction| {
+            AcceptedTransaction::accept(tx, &ChainId::from("0"), &test_tx_limits())
+                .expect("Failed to accept Transaction.")
+        };
+
+        assert!(matches!(
+            queue.push(accept(tx.clone().sign(&carol_keypair)), &state_view),
+            Err(Failure {
+                err: Error::SignatoryInconsistent,
+                ..
+            })
+        ));
+
+        queue
+            .push(accept(tx.clone()), &state_view)
+            .expect("Failed to push tx into queue");
+        assert_eq!(queue.tx_len(), 0);
+        assert_eq!(
+            queue.pending_multisig_transactions(Some(&alice_id)).len(),
+            1
+        );
+
+        queue
+            .push(accept(tx.sign(&bob_keypair)), &state_view)
+            .expect("Failed to push tx into queue");
+        assert_eq!(queue.tx_len(), 1);
+        assert!(queue.pending_multisig_transactions(None).is_empty());
+    }
+
+    #[test]
+    async fn push_tx_cosigned_by_outsider() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (_carol_id, carol_keypair) = gen_account_in("wonderland");
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], PeersIds::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world, kura, query_handle));
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let tx = accepted_tx_by(alice_id, &alice_keypair, &time_source);
+        let cosigned_tx = AcceptedTransaction::accept(
+            tx.as_ref().clone().cosign(&carol_keypair),
+            &ChainId::from("0"),
+            &test_tx_limits(),
+        )
+        .expect("Failed to accept Transaction.");
+        assert!(matches!(
+            queue.push(cosigned_tx, &state.view()),
+            Err(Failure {
+                err: Error::SignatoryInconsistent,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    async fn push_multisig_tx_signed_differently() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (_bob_id, bob_keypair) = gen_account_in("wonderland");
+        let (_carol_id, carol_keypair) = gen_account_in("wonderland");
+        let world = world_with_multisig_account(&alice_id, [&alice_keypair, &bob_keypair]);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world, kura, query_handle));
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let tx = multisig_tx_by(alice_id, &alice_keypair, &time_source);
+        // Cosignatures are kept in one order and only once per key
+        assert_eq!(
+            tx.clone()
+                .cosign(&carol_keypair)
+                .cosign(&bob_keypair)
+                .cosign(&alice_keypair)
+                .hash(),
+            tx.clone()
+                .cosign(&bob_keypair)
+                .cosign(&carol_keypair)
+                .cosign(&carol_keypair)
+                .hash()
+        );
+
+        let accept = |tx: SignedTransaction| {
+            AcceptedTransaction::accept(tx, &ChainId::from("0"), &test_tx_limits())
+                .expect("Failed to accept Transaction.")
+        };
+        let signed_by_alice = accept(tx.clone().cosign(&bob_keypair));
+        let signed_by_bob = accept(tx.sign(&bob_keypair).cosign(&alice_keypair));
+        assert_ne!(
+            signed_by_alice.as_ref().hash(),
+            signed_by_bob.as_ref().hash()
+        );
+
+        queue
+            .push(signed_by_alice.clone(), &state.view())
+            .expect("Failed to push tx into queue");
+        assert!(matches!(
+            queue.push(signed_by_bob.clone(), &state.view()),
+            Err(Failure {
+                err: Error::IsInQueue,
+                ..
+            })
+        ));
+
+        let mut state_block = state.block();
+        state_block
+            .transaction_payloads
+            .insert(signed_by_alice.as_ref().payload_hash(), 1);
+        state_block.commit();
+        let queue = Queue::test(config_factory(), &time_source);
+        assert!(matches!(
+            queue.push(signed_by_bob, &state.view()),
+            Err(Failure {
+                err: Error::InBlockchain,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    async fn push_tx_with_outdated_sequence() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let domain_id = DomainId::from_str("wonderland").expect("Valid");
+        let mut domain = Domain::new(domain_id).build(&alice_id);
+        let mut account = Account::new(alice_id.clone()).build(&alice_id);
+        account.strict_ordering = true;
+        account.sequence = 1;
+        assert!(domain.add_account(account).is_none());
+        let world = World::with([domain], PeersIds::new());
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world, kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let tx_with_sequence = |sequence: Option<NonZeroU64>| {
+            let mut builder = TransactionBuilder::new_with_time_source(
+                ChainId::from("0"),
+                alice_id.clone(),
+                &time_source,
+            )
+            .with_instructions([Fail::new("ordered".to_owned())]);
+            if let Some(sequence) = sequence {
+                builder.set_sequence(sequence);
+            }
+            AcceptedTransaction::accept(
+                builder.sign(&alice_keypair),
+                &ChainId::from("0"),
+                &test_tx_limits(),
+            )
+            .expect("Failed to accept Transaction.")
+        };
+
+        for sequence in [None, Some(nonzero!(1_u64))] {
+            assert!(matches!(
+                queue.push(tx_with_sequence(sequence), &state_view),
+                Err(Failure {
+                    err: Error::SequenceOutdated,
+                    ..
+                })
+            ));
+        }
+        queue
+            .push(tx_with_sequence(Some(nonzero!(2_u64))), &state_view)
+            .expect("Failed to push tx into queue");
+    }
+
+    #[test]
+    async fn scheduled_tx_waits_in_queue() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+        let state_view = state.view();
+
+        let (time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let mut builder =
+            TransactionBuilder::new_with_time_source(ChainId::from("0"), alice_id, &time_source)
+                .with_instructions([Fail::new("scheduled".to_owned())]);
+        builder.set_not_before(MetadataExpiry::TimestampMs(1000));
+        let tx = AcceptedTransaction::accept(
+            builder.sign(&alice_keypair),
+            &ChainId::from("0"),
+            &test_tx_limits(),
+        )
+        .expect("Failed to accept Transaction.");
+        queue
+            .push(tx, &state_view)
+            .expect("Failed to push tx into queue");
+
+        assert!(queue
+            .collect_transactions_for_block(&state_view, 10)
+            .is_empty());
+        assert_eq!(queue.tx_len(), 1);
+
+        time_handle.advance(Duration::from_millis(1000));
+        assert_eq!(
+            queue.collect_transactions_for_block(&state_view, 10).len(),
+            1
+        );
+    }
+
+    #[test]
+    async fn cancel_queued_tx() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (_bob_id, bob_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let tx = accepted_tx_by(alice_id, &alice_keypair, &time_source);
+        let hash = tx.as_ref().hash();
+        queue
+            .push(tx.clone(), &state_view)
+            .expect("Failed to push tx into queue");
+
+        assert!(matches!(
+            queue.cancel(CancelTransaction::new(hash, &bob_keypair)),
+            Err(Error::SignatoryInconsistent)
+        ));
+        queue
+            .cancel(CancelTransaction::new(hash, &alice_keypair))
+            .expect("Failed to cancel tx");
+        assert_eq!(queue.tx_len(), 0);
+        assert_eq!(queue.cancellations().len(), 1);
+        assert!(matches!(
+            queue.push(tx, &state_view),
+            Err(Failure {
+                err: Error::Cancelled,
+                ..
+            })
+        ));
+        assert!(queue
+            .collect_transactions_for_block(&state_view, 10)
+            .is_empty());
+    }
+
+    #[test]
+    async fn queue_status_counts_pending_txs() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, bob_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+        let state_view = state.view();
+
+        let (time_handle, time_source) = TimeSource::new_mock(Duration::from_secs(1));
+
+        let queue = Queue::test(config_factory(), &time_source);
+        assert_eq!(queue.status(&state_view).len, 0);
+        assert_eq!(queue.status(&state_view).oldest_creation_time, None);
+
+        for _ in 0..2 {
+            queue
+                .push(
+                    accepted_tx_by(alice_id.clone(), &alice_keypair, &time_source),
+                    &state_view,
+                )
+                .expect("Failed to push tx into queue");
+            time_handle.advance(Duration::from_millis(10));
+        }
+        queue
+            .push(
+                accepted_tx_by(bob_id.clone(), &bob_keypair, &time_source),
+                &state_view,
+            )
+            .expect("Failed to push tx into queue");
+
+        let status = queue.status(&state_view);
+        assert_eq!(status.len, 3);
+        assert_eq!(status.len_per_authority.get(&alice_id), Some(&2));
+        assert_eq!(status.len_per_authority.get(&bob_id), Some(&1));
+        assert_eq!(status.oldest_creation_time, Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    async fn attach_signature_to_pending_tx() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (_bob_id, bob_keypair) = gen_account_in("wonderland");
+        let (_carol_id, carol_keypair) = gen_account_in("wonderland");
+        let world = world_with_multisig_account(&alice_id, [&alice_keypair, &bob_keypair]);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world, kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(config_factory(), &time_source);
+
+        let tx = multisig_tx_by(alice_id.clone(), &alice_keypair, &time_source);
+        let payload_hash = tx.payload_hash();
+        assert!(matches!(
+            queue.attach_signature(payload_hash, tx.sign_payload(&bob_keypair), &state_view),
+            Err(Error::NotPending)
+        ));
+
+        let accepted =
+            AcceptedTransaction::accept(tx.clone(), &ChainId::from("0"), &test_tx_limits())
+                .expect("Failed to accept Transaction.");
+        queue
+            .push(accepted, &state_view)
+            .expect("Failed to push tx into queue");
+        assert!(matches!(
+            queue.attach_signature(payload_hash, tx.sign_payload(&carol_keypair), &state_view),
+            Err(Error::SignatoryInconsistent)
+        ));
+        assert_eq!(queue.tx_len(), 0);
+
+        queue
+            .attach_signature(payload_hash, tx.sign_payload(&bob_keypair), &state_view)
+            .expect("Failed to attach signature");
+        assert_eq!(queue.tx_len(), 1);
+        assert!(queue.pending_multisig_transactions(None).is_empty());
+    }
+
     #[test]
     async fn push_tx_overflow() {
         let capacity = nonzero!(10_usize);
@@ -541,6 +1236,71 @@ pub mod tests {
         assert_eq!(available.len(), max_txs_in_block);
     }
 
+    #[test]
+    async fn get_txs_by_tip_with_block_capacity_per_user() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+        let state_view = state.view();
+
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let queue = Queue::test(
+            Config {
+                block_capacity_per_user: nonzero!(2_usize),
+                ..config_factory()
+            },
+            &time_source,
+        );
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, carol_keypair) = gen_account_in("wonderland");
+        let tx_with_tip =
+            |account_id: &AccountId, key_pair: &KeyPair, tip: Option<u32>, index: usize| {
+                let mut builder = TransactionBuilder::new_with_time_source(
+                    ChainId::from("0"),
+                    account_id.clone(),
+                    &time_source,
+                )
+                .with_instructions([Fail::new(format!("tx {index}"))]);
+                if let Some(tip) = tip {
+                    builder.set_tip(tip);
+                }
+                AcceptedTransaction::accept(
+                    builder.sign(key_pair),
+                    &ChainId::from("0"),
+                    &test_tx_limits(),
+                )
+                .expect("Failed to accept Transaction.")
+            };
+        let alice_tx = tx_with_tip(&alice_id, &alice_keypair, None, 0);
+        let bob_txs = [Some(1), Some(10), Some(10)]
+            .into_iter()
+            .enumerate()
+            .map(|(index, tip)| tx_with_tip(&bob_id, &bob_keypair, tip, index))
+            .collect::<Vec<_>>();
+        let carol_tx = tx_with_tip(&carol_id, &carol_keypair, Some(5), 0);
+        for tx in core::iter::once(&alice_tx)
+            .chain(&bob_txs)
+            .chain(core::iter::once(&carol_tx))
+        {
+            queue
+                .push(tx.clone(), &state_view)
+                .expect("Failed to push tx into queue");
+        }
+
+        let hashes = queue
+            .collect_transactions_for_block(&state_view, 5)
+            .into_iter()
+            .map(|tx| tx.as_ref().hash())
+            .collect::<Vec<_>>();
+        let expected = [&carol_tx, &bob_txs[0], &bob_txs[1], &alice_tx]
+            .into_iter()
+            .map(|tx| tx.as_ref().hash())
+            .collect::<Vec<_>>();
+        assert_eq!(hashes, expected);
+    }
+
     #[test]
     async fn push_tx_already_in_blockchain() {
         let kura = Kura::blank_kura_for_testing();
@@ -549,7 +1309,9 @@ pub mod tests {
         let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
         let tx = accepted_tx_by_someone(&time_source);
         let mut state_block = state.block();
-        state_block.transactions.insert(tx.as_ref().hash(), 1);
+        state_block
+            .transaction_payloads
+            .insert(tx.as_ref().payload_hash(), 1);
         state_block.commit();
         let state_view = state.view();
         let queue = Queue::test(config_factory(), &time_source);
@@ -574,7 +1336,9 @@ pub mod tests {
         let queue = Queue::test(config_factory(), &time_source);
         queue.push(tx.clone(), &state.view()).unwrap();
         let mut state_block = state.block();
-        state_block.transactions.insert(tx.as_ref().hash(), 1);
+        state_block
+            .transaction_payloads
+            .insert(tx.as_ref().payload_hash(), 1);
         state_block.commit();
         assert_eq!(
             queue
@@ -777,7 +1541,9 @@ pub mod tests {
                     for tx in queue.collect_transactions_for_block(&state.view(), max_txs_in_block)
                     {
                         let mut state_block = state.block();
-                        state_block.transactions.insert(tx.as_ref().hash(), 1);
+                        state_block
+                            .transaction_payloads
+                            .insert(tx.as_ref().payload_hash(), 1);
                         state_block.commit();
                     }
                     // Simulate random small delays
@@ -24,6 +24,8 @@ impl Registrable for iroha_data_model::account::NewAccount {
 /// - grant permissions and roles
 /// - Revoke permissions or roles
 pub mod isi {
+    use std::{collections::BTreeSet, num::NonZeroU16};
+
     use iroha_data_model::{
         asset::{AssetValue, AssetValueType},
         isi::{
@@ -36,7 +38,10 @@ pub mod isi {
 
     use self::asset::isi::assert_numeric_spec;
     use super::*;
-    use crate::{role::RoleIdWithOwner, state::StateTransaction};
+    use crate::{
+        role::RoleIdWithOwner,
+        state::{PermissionGrantEntry, StateReadOnly, StateTransaction},
+    };
 
     impl Execute for Register<Asset> {
         #[metrics(+"register_asset")]
@@ -112,10 +117,20 @@ pub mod isi {
                         .world
                         .decrease_asset_total_amount(&asset.id.definition_id, increment)?;
                 }
-                AssetValue::Store(_) => {
+                AssetValue::Store(ref tokens) => {
+                    // Each token of an `Nft` asset counts towards the total quantity
+                    let decrement = match state_transaction
+                        .world
+                        .asset_definition(&asset.id.definition_id)?
+                        .value_type
+                    {
+                        AssetValueType::Nft => Numeric::from(tokens.len() as u64),
+                        _ => Numeric::ONE,
+                    };
                     state_transaction
                         .world
-                        .decrease_asset_total_amount(&asset.id.definition_id, Numeric::ONE)?;
+                        .decrease_asset_total_amount(&asset.id.definition_id, decrement)?;
+                    state_transaction.world.remove_nft_owners(&asset);
                 }
             }
 
@@ -176,7 +191,10 @@ pub mod isi {
         ) -> Result<(), Error> {
             let account_id = self.object_id;
 
-            let account_metadata_limits = state_transaction.config.account_metadata_limits;
+            let account_metadata_limits = state_transaction
+                .world
+                .domain_metadata_limits(&account_id.domain_id)?
+                .account_limits(state_transaction.config.account_metadata_limits);
 
             state_transaction
                 .world
@@ -197,7 +215,7 @@ pub mod isi {
                 .world
                 .emit_events(Some(AccountEvent::MetadataInserted(MetadataChanged {
                     target_id: account_id,
-                    key: self.key,
+                    path: vec![self.key],
                     value: self.value,
                 })));
 
@@ -228,7 +246,7 @@ pub mod isi {
                 .world
                 .emit_events(Some(AccountEvent::MetadataRemoved(MetadataChanged {
                     target_id: account_id,
-                    key: self.key,
+                    path: vec![self.key],
                     value,
                 })));
 
@@ -236,11 +254,81 @@ pub mod isi {
         }
     }
 
+    impl Execute for MergeKeyValues<Account> {
+        #[metrics(+"merge_account_key_values")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.object_id;
+
+            let account_metadata_limits = state_transaction
+                .world
+                .domain_metadata_limits(&account_id.domain_id)?
+                .account_limits(state_transaction.config.account_metadata_limits);
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            let written = merge_metadata(
+                &mut account.metadata,
+                self.metadata,
+                self.policy,
+                account_metadata_limits,
+            )?;
+
+            state_transaction
+                .world
+                .emit_events(written.into_iter().map(|(path, value)| {
+                    AccountEvent::MetadataInserted(MetadataChanged {
+                        target_id: account_id.clone(),
+                        path,
+                        value,
+                    })
+                }));
+
+            Ok(())
+        }
+    }
+
+    /// Record `change` of the permissions or roles of `account_id` in its grant history
+    /// and emit the corresponding audit event.
+    fn record_grant_audit(
+        granter: &AccountId,
+        account_id: AccountId,
+        change: AccountGrantChange,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) {
+        let audit = AccountGrantAudit {
+            account_id,
+            granter: granter.clone(),
+            change,
+            // Height of the block being executed
+            block_height: state_transaction.height() + 1,
+        };
+
+        let max_len = state_transaction.config.grant_history_max_len as usize;
+        let grant_history = &mut state_transaction.world.grant_history;
+        match grant_history.get_mut(&audit.account_id) {
+            None => {
+                grant_history.insert(audit.account_id.clone(), vec![audit.clone()]);
+            }
+            Some(history) => history.push(audit.clone()),
+        }
+        // Only the latest changes are kept in the world state, all of them are emitted as events
+        if let Some(history) = grant_history.get_mut(&audit.account_id) {
+            let excess = history.len().saturating_sub(max_len);
+            history.drain(..excess);
+        }
+        state_transaction
+            .world
+            .emit_events(Some(AccountEvent::GrantAudited(audit)));
+    }
+
     impl Execute for Grant<Permission, Account> {
         #[metrics(+"grant_account_permission")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account_id = self.destination_id;
@@ -272,16 +360,28 @@ pub mod isi {
 
             state_transaction
                 .world
-                .add_account_permission(&account_id, permission);
+                .remove_permission_grant_terms(&Revoke::permission(
+                    permission.clone(),
+                    account_id.clone(),
+                ));
+            state_transaction
+                .world
+                .add_account_permission(&account_id, permission.clone());
 
             state_transaction
                 .world
                 .emit_events(Some(AccountEvent::PermissionAdded(
                     AccountPermissionChanged {
-                        account_id,
+                        account_id: account_id.clone(),
                         permission_id,
                     },
                 )));
+            record_grant_audit(
+                authority,
+                account_id,
+                AccountGrantChange::PermissionGranted(permission),
+                state_transaction,
+            );
 
             Ok(())
         }
@@ -291,7 +391,7 @@ pub mod isi {
         #[metrics(+"revoke_account_permission")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account_id = self.destination_id;
@@ -306,15 +406,53 @@ pub mod isi {
             {
                 return Err(FindError::Permission(permission.id).into());
             }
+            state_transaction
+                .world
+                .remove_permission_grant_terms(&Revoke::permission(
+                    permission.clone(),
+                    account_id.clone(),
+                ));
 
             state_transaction
                 .world
                 .emit_events(Some(AccountEvent::PermissionRemoved(
                     AccountPermissionChanged {
-                        account_id,
-                        permission_id: permission.id,
+                        account_id: account_id.clone(),
+                        permission_id: permission.id.clone(),
                     },
                 )));
+            record_grant_audit(
+                authority,
+                account_id,
+                AccountGrantChange::PermissionRevoked(permission),
+                state_transaction,
+            );
+
+            Ok(())
+        }
+    }
+
+    impl Execute for GrantWithTerms {
+        #[metrics(+"grant_account_permission_with_terms")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self { grant, terms } = self;
+            let revoke = Revoke::permission(grant.object.clone(), grant.destination_id.clone());
+
+            grant.execute(authority, state_transaction)?;
+            // Granted without any terms is the default, which is kept implicit
+            if terms != PermissionGrantTerms::default() {
+                state_transaction.world.insert_permission_grant_terms(
+                    revoke,
+                    PermissionGrantEntry {
+                        granter: authority.clone(),
+                        terms,
+                    },
+                );
+            }
 
             Ok(())
         }
@@ -324,7 +462,7 @@ pub mod isi {
         #[metrics(+"grant_account_role")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account_id = self.destination_id;
@@ -358,6 +496,7 @@ pub mod isi {
                 .into());
             }
 
+            let audited_account_id = account_id.clone();
             state_transaction.world.emit_events({
                 let account_id_clone = account_id.clone();
                 permissions
@@ -370,10 +509,16 @@ pub mod isi {
                     .chain(std::iter::once(AccountEvent::RoleGranted(
                         AccountRoleChanged {
                             account_id: account_id_clone,
-                            role_id,
+                            role_id: role_id.clone(),
                         },
                     )))
             });
+            record_grant_audit(
+                authority,
+                audited_account_id,
+                AccountGrantChange::RoleGranted(role_id),
+                state_transaction,
+            );
 
             Ok(())
         }
@@ -383,7 +528,7 @@ pub mod isi {
         #[metrics(+"revoke_account_role")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account_id = self.destination_id;
@@ -411,6 +556,7 @@ pub mod isi {
                 return Err(FindError::Role(role_id).into());
             }
 
+            let audited_account_id = account_id.clone();
             state_transaction.world.emit_events({
                 let account_id_clone = account_id.clone();
                 permissions
@@ -423,15 +569,415 @@ pub mod isi {
                     .chain(std::iter::once(AccountEvent::RoleRevoked(
                         AccountRoleChanged {
                             account_id: account_id_clone,
-                            role_id,
+                            role_id: role_id.clone(),
                         },
                     )))
             });
+            record_grant_audit(
+                authority,
+                audited_account_id,
+                AccountGrantChange::RoleRevoked(role_id),
+                state_transaction,
+            );
+
+            Ok(())
+        }
+    }
+
+    impl Execute for AddSignatory {
+        #[metrics(+"add_signatory")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            let condition = account
+                .signature_condition
+                .get_or_insert_with(|| single_signatory_condition(&account_id));
+            if !condition.signatories.insert(self.public_key) {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::AddSignatory,
+                    id: account_id.into(),
+                }
+                .into());
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AuthenticationAdded(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RemoveSignatory {
+        #[metrics(+"remove_signatory")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            let condition = account
+                .signature_condition
+                .get_or_insert_with(|| single_signatory_condition(&account_id));
+            if !condition.signatories.contains(&self.public_key) {
+                return Err(FindError::PublicKey(self.public_key).into());
+            }
+            if condition.signatories.len() <= usize::from(condition.threshold.get()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Removing the signatory would leave fewer than {} signatories of `{account_id}`",
+                    condition.threshold
+                )));
+            }
+            condition.signatories.remove(&self.public_key);
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AuthenticationRemoved(account_id)));
 
             Ok(())
         }
     }
 
+    impl Execute for SetSignatureThreshold {
+        #[metrics(+"set_signature_threshold")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            let condition = account
+                .signature_condition
+                .get_or_insert_with(|| single_signatory_condition(&account_id));
+            if condition.signatories.len() < usize::from(self.threshold.get()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Signature threshold {} exceeds the {} signatories of `{account_id}`",
+                    self.threshold,
+                    condition.signatories.len()
+                )));
+            }
+            condition.threshold = self.threshold;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SignatureThresholdChanged(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RotateKey {
+        #[metrics(+"rotate_key")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            let condition = account
+                .signature_condition
+                .get_or_insert_with(|| single_signatory_condition(&account_id));
+            if !condition.signatories.contains(&self.old_public_key) {
+                return Err(FindError::PublicKey(self.old_public_key).into());
+            }
+            if condition.signatories.contains(&self.new_public_key) {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::RotateKey,
+                    id: account_id.into(),
+                }
+                .into());
+            }
+            condition.signatories.remove(&self.old_public_key);
+            condition.signatories.insert(self.new_public_key);
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::KeyRotated(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for SetRecoveryPolicy {
+        #[metrics(+"set_recovery_policy")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+            let policy = self.policy;
+
+            if policy.guardians.len() < usize::from(policy.quorum.get()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Recovery quorum {} exceeds the {} guardians",
+                    policy.quorum,
+                    policy.guardians.len()
+                )));
+            }
+            let account = state_transaction.world.account_mut(&account_id)?;
+            account.recovery_policy = Some(policy);
+            account.pending_recoveries.clear();
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::RecoveryPolicySet(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RecoverAccount {
+        #[metrics(+"recover_account")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+            let signature_condition = self.signature_condition;
+
+            if signature_condition.signatories.len()
+                < usize::from(signature_condition.threshold.get())
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Signature threshold {} exceeds the {} signatories",
+                    signature_condition.threshold,
+                    signature_condition.signatories.len()
+                )));
+            }
+            let now_ms = state_transaction
+                .latest_block_ref()
+                .map_or(0, |block| block.header().timestamp_ms);
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            let Some(policy) = &account.recovery_policy else {
+                return Err(Error::InvariantViolation(format!(
+                    "Account `{account_id}` has no recovery policy"
+                )));
+            };
+            if !policy.guardians.contains(authority) {
+                return Err(Error::InvariantViolation(format!(
+                    "`{authority}` is not a guardian of `{account_id}`"
+                )));
+            }
+            let quorum = usize::from(policy.quorum.get());
+
+            // A guardian approves one condition at a time, so one guardian proposing
+            // another condition doesn't discard the approvals of the others
+            for pending in &mut account.pending_recoveries {
+                if pending.signature_condition != signature_condition
+                    && pending.approvals.remove(authority)
+                    && pending.approvals.len() < quorum
+                {
+                    pending.quorum_reached_ms = None;
+                }
+            }
+            account
+                .pending_recoveries
+                .retain(|pending| !pending.approvals.is_empty());
+            let pending = match account
+                .pending_recoveries
+                .iter()
+                .position(|pending| pending.signature_condition == signature_condition)
+            {
+                Some(position) => &mut account.pending_recoveries[position],
+                None => {
+                    account.pending_recoveries.push(PendingRecovery {
+                        signature_condition,
+                        approvals: BTreeSet::new(),
+                        quorum_reached_ms: None,
+                    });
+                    account
+                        .pending_recoveries
+                        .last_mut()
+                        .expect("Pending recovery was inserted above")
+                }
+            };
+            pending.approvals.insert(authority.clone());
+            if pending.quorum_reached_ms.is_none() && pending.approvals.len() >= quorum {
+                pending.quorum_reached_ms = Some(now_ms);
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::RecoveryApproved(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for FinalizeRecovery {
+        #[metrics(+"finalize_recovery")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+            let signature_condition = self.signature_condition;
+            let now_ms = state_transaction
+                .latest_block_ref()
+                .map_or(0, |block| block.header().timestamp_ms);
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            let delay_ms = account
+                .recovery_policy
+                .as_ref()
+                .map_or(0, |policy| policy.delay_ms);
+            let is_due = account.pending_recoveries.iter().any(|pending| {
+                pending.signature_condition == signature_condition
+                    && pending
+                        .quorum_reached_ms
+                        .is_some_and(|reached_ms| now_ms >= reached_ms.saturating_add(delay_ms))
+            });
+            if !is_due {
+                return Err(Error::InvariantViolation(format!(
+                    "Recovery of `{account_id}` to {signature_condition} isn't approved by the quorum of guardians or its delay hasn't passed"
+                )));
+            }
+            account.signature_condition = Some(signature_condition);
+            account.pending_recoveries.clear();
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::Recovered(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for CancelRecovery {
+        #[metrics(+"cancel_recovery")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if account.pending_recoveries.is_empty() {
+                return Err(Error::InvariantViolation(format!(
+                    "No recovery of `{account_id}` is pending"
+                )));
+            }
+            account.pending_recoveries.clear();
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::RecoveryCancelled(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for DisableAccount {
+        #[metrics(+"disable_account")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if account.disabled {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::DisableAccount,
+                    id: IdBox::AccountId(account_id),
+                }
+                .into());
+            }
+            account.disabled = true;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::Disabled(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for EnableAccount {
+        #[metrics(+"enable_account")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if !account.disabled {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::EnableAccount,
+                    id: IdBox::AccountId(account_id),
+                }
+                .into());
+            }
+            account.disabled = false;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::Enabled(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for SetStrictOrdering {
+        #[metrics(+"set_strict_ordering")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account_id;
+
+            state_transaction
+                .world
+                .account_mut(&account_id)?
+                .strict_ordering = self.strict;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::StrictOrderingChanged(account_id)));
+
+            Ok(())
+        }
+    }
+
+    /// Assert that the account exists and isn't disabled, i.e. can receive transfers.
+    pub(crate) fn assert_account_enabled(
+        account_id: &AccountId,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        if state_transaction.world.account(account_id)?.disabled {
+            return Err(Error::AccountDisabled(account_id.clone()));
+        }
+        Ok(())
+    }
+
+    /// Condition equivalent to the default one of an account without [`SignatureCondition`].
+    fn single_signatory_condition(account_id: &AccountId) -> SignatureCondition {
+        SignatureCondition::new(
+            [account_id.signatory.clone()],
+            NonZeroU16::new(1).expect("One is non-zero"),
+        )
+    }
+
     /// Assert that this asset can be registered to an account.
     fn assert_can_register(
         definition_id: &AssetDefinitionId,
@@ -440,7 +986,9 @@ pub mod isi {
     ) -> Result<(), Error> {
         let expected_asset_value_type = match value.value_type() {
             AssetValueType::Numeric(_) => asset::isi::expected_asset_value_type_numeric,
-            AssetValueType::Store => asset::isi::expected_asset_value_type_store,
+            AssetValueType::Store | AssetValueType::Nft => {
+                asset::isi::expected_asset_value_type_store
+            }
         };
         let definition = asset::isi::assert_asset_type(
             definition_id,
@@ -542,6 +1090,46 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindPermissionGrantTerms {
+        #[metrics(+"find_permission_grant_terms")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<PermissionGrantTerms, Error> {
+            let account_id = &self.account_id;
+            let permission = &self.permission;
+            iroha_logger::trace!(%account_id, %permission);
+            let world = state_ro.world();
+            world.account(account_id)?;
+            if !world.account_contains_inherent_permission(account_id, permission) {
+                return Err(FindError::Permission(permission.id.clone()).into());
+            }
+            Ok(world
+                .permission_grant_terms()
+                .get(&Revoke::permission(permission.clone(), account_id.clone()))
+                .map(|entry| entry.terms)
+                .unwrap_or_default())
+        }
+    }
+
+    impl ValidQuery for FindGrantHistoryByAccountId {
+        #[metrics(+"find_grant_history_by_account_id")]
+        fn execute<'state>(
+            &self,
+            state_ro: &'state impl StateReadOnly,
+        ) -> Result<Box<dyn Iterator<Item = AccountGrantAudit> + 'state>, Error> {
+            let account_id = &self.account_id;
+            iroha_logger::trace!(%account_id);
+            // History outlives the account so that the trail stays auditable after unregistering
+            Ok(Box::new(
+                state_ro
+                    .world()
+                    .grant_history()
+                    .get(account_id)
+                    .into_iter()
+                    .flatten()
+                    .cloned(),
+            ))
+        }
+    }
+
     impl ValidQuery for FindAllAccounts {
         #[metrics(+"find_all_accounts")]
         fn execute<'state>(
@@ -570,6 +1158,22 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAccountByAlias {
+        #[metrics(+"find_account_by_alias")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Account, Error> {
+            let alias = &self.alias;
+            iroha_logger::trace!(%alias);
+            let world = state_ro.world();
+            let account_id = world
+                .domain(&alias.domain_id)?
+                .account_by_alias(&alias.name)
+                .ok_or_else(|| FindError::AccountAlias(alias.clone()))?;
+            world
+                .map_account(account_id, Clone::clone)
+                .map_err(Into::into)
+        }
+    }
+
     impl ValidQuery for FindAccountsByDomainId {
         #[metrics(+"find_accounts_by_domain_id")]
         fn execute<'state>(
@@ -585,6 +1189,19 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAccountCountByDomainId {
+        #[metrics(+"find_account_count_by_domain_id")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Numeric, Error> {
+            let id = &self.domain_id;
+
+            iroha_logger::trace!(%id);
+            let count = state_ro.world().domain(id)?.accounts.len();
+            Ok(u64::try_from(count)
+                .expect("`usize` should always fit into `u64`")
+                .into())
+        }
+    }
+
     impl ValidQuery for FindAccountKeyValueByIdAndKey {
         #[metrics(+"find_account_key_value_by_id_and_key")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<MetadataValueBox, Error> {
@@ -599,6 +1216,18 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAccountKeyValueByIdAndPath {
+        #[metrics(+"find_account_key_value_by_id_and_path")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<MetadataValueBox, Error> {
+            let id = &self.id;
+            let path = &self.path;
+            iroha_logger::trace!(%id, ?path);
+            state_ro
+                .world()
+                .map_account(id, |account| find_metadata_value(&account.metadata, path))?
+        }
+    }
+
     impl ValidQuery for FindAccountsWithAsset {
         #[metrics(+"find_accounts_with_asset")]
         fn execute<'state>(
@@ -622,4 +1251,64 @@ pub mod query {
             ))
         }
     }
+
+    impl ValidQuery for FindAccountsByMetadataKeyPrefix {
+        #[metrics(+"find_accounts_by_metadata_key_prefix")]
+        fn execute<'state>(
+            &self,
+            state_ro: &'state impl StateReadOnly,
+        ) -> Result<Box<dyn Iterator<Item = Account> + 'state>, Error> {
+            let prefix = self.prefix.clone();
+            iroha_logger::trace!(%prefix);
+            Ok(Box::new(
+                state_ro
+                    .world()
+                    .domains_iter()
+                    .flat_map(|domain| domain.accounts.values())
+                    .filter(move |account| {
+                        account.metadata.keys_with_prefix(&prefix).next().is_some()
+                    })
+                    .cloned(),
+            ))
+        }
+    }
+
+    impl ValidQuery for FindMetadataHistory {
+        #[metrics(+"find_metadata_history")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<MetadataValueBox, Error> {
+            let object_id = &self.object_id;
+            let key = &self.key;
+            let path = &self.path;
+            let height = self.height;
+            iroha_logger::trace!(%object_id, %key, ?path, %height);
+
+            let entry: RemoveKeyValueBox = match object_id.clone() {
+                IdBox::DomainId(id) => RemoveKeyValue::domain(id, key.clone()).into(),
+                IdBox::AccountId(id) => RemoveKeyValue::account(id, key.clone()).into(),
+                IdBox::AssetDefinitionId(id) => {
+                    RemoveKeyValue::asset_definition(id, key.clone()).into()
+                }
+                IdBox::AssetId(id) => RemoveKeyValue::asset(id, key.clone()).into(),
+                IdBox::TriggerId(id) => RemoveKeyValue::trigger(id, key.clone()).into(),
+                _ => {
+                    return Err(Error::Conversion(format!(
+                        "`{object_id}` doesn't have metadata"
+                    )))
+                }
+            };
+            let value = state_ro
+                .kura()
+                .metadata_value_at(&entry, height)
+                .ok_or(Error::MetadataHistoryNotIndexed)?
+                .ok_or_else(|| FindError::MetadataKey(key.clone()))?;
+
+            if path.is_empty() {
+                return Ok(value);
+            }
+            match value {
+                MetadataValueBox::LimitedMetadata(nested) => find_metadata_value(&nested, path),
+                _ => Err(FindError::MetadataKey(path[0].clone()).into()),
+            }
+        }
+    }
 }
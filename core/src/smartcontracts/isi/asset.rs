@@ -20,6 +20,8 @@ impl Registrable for NewAssetDefinition {
             id: self.id,
             value_type: self.value_type,
             mintable: self.mintable,
+            frozen: false,
+            max_supply: self.max_supply,
             logo: self.logo,
             metadata: self.metadata,
             owned_by: authority.clone(),
@@ -32,10 +34,20 @@ impl Registrable for NewAssetDefinition {
 /// - update metadata
 /// - transfer, etc.
 pub mod isi {
-    use iroha_data_model::{asset::AssetValueType, isi::error::MintabilityError};
+    use iroha_data_model::{
+        asset::AssetValueType,
+        isi::{
+            error::{MintabilityError, RepetitionError},
+            InstructionType,
+        },
+        metadata::MetadataValueBox,
+    };
 
     use super::*;
-    use crate::smartcontracts::account::isi::forbid_minting;
+    use crate::{
+        smartcontracts::account::isi::{assert_account_enabled, forbid_minting},
+        state::{NftTokenKey, StateReadOnly, WorldReadOnly},
+    };
 
     impl Execute for SetKeyValue<Asset> {
         #[metrics(+"set_asset_key_value")]
@@ -45,45 +57,24 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let asset_id = self.object_id;
-
-            assert_asset_type(
-                &asset_id.definition_id,
-                state_transaction,
-                expected_asset_value_type_store,
-            )?;
-
-            // Increase `Store` asset total quantity by 1 if asset was not present earlier
-            if matches!(
-                state_transaction.world.asset(&asset_id),
-                Err(QueryExecutionFail::Find(_))
-            ) {
-                state_transaction
-                    .world
-                    .increase_asset_total_amount(&asset_id.definition_id, Numeric::ONE)?;
-            }
-
-            let asset_metadata_limits = state_transaction.config.asset_metadata_limits;
-            let asset = state_transaction
+            let value_type = state_transaction
                 .world
-                .asset_or_insert(asset_id.clone(), Metadata::new())?;
+                .asset_definition(&asset_id.definition_id)?
+                .value_type;
 
-            {
-                let AssetValue::Store(store) = &mut asset.value else {
-                    return Err(Error::Conversion("Expected store asset type".to_owned()));
-                };
-
-                store.insert_with_limits(
-                    self.key.clone(),
-                    self.value.clone(),
-                    asset_metadata_limits,
-                )?;
-            }
+            let asset_metadata_limits = state_transaction.config.asset_metadata_limits;
+            let asset = key_values_holder(&asset_id, value_type, state_transaction)?;
+            asset.key_values_mut(value_type).insert_with_limits(
+                self.key.clone(),
+                self.value.clone(),
+                asset_metadata_limits,
+            )?;
 
             state_transaction
                 .world
                 .emit_events(Some(AssetEvent::MetadataInserted(MetadataChanged {
                     target_id: asset_id,
-                    key: self.key,
+                    path: vec![self.key],
                     value: self.value,
                 })));
 
@@ -99,30 +90,23 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let asset_id = self.object_id;
+            let value_type = state_transaction
+                .world
+                .asset_definition(&asset_id.definition_id)?
+                .value_type;
 
-            assert_asset_type(
-                &asset_id.definition_id,
-                state_transaction,
-                expected_asset_value_type_store,
-            )?;
-
-            let value = {
-                let asset = state_transaction.world.asset_mut(&asset_id)?;
-
-                let AssetValue::Store(store) = &mut asset.value else {
-                    return Err(Error::Conversion("Expected store asset type".to_owned()));
-                };
-
-                store
-                    .remove(&self.key)
-                    .ok_or_else(|| FindError::MetadataKey(self.key.clone()))?
-            };
+            let value = state_transaction
+                .world
+                .asset_mut(&asset_id)?
+                .key_values_mut(value_type)
+                .remove(&self.key)
+                .ok_or_else(|| FindError::MetadataKey(self.key.clone()))?;
 
             state_transaction
                 .world
                 .emit_events(Some(AssetEvent::MetadataRemoved(MetadataChanged {
                     target_id: asset_id,
-                    key: self.key,
+                    path: vec![self.key],
                     value,
                 })));
 
@@ -130,6 +114,42 @@ pub mod isi {
         }
     }
 
+    impl Execute for MergeKeyValues<Asset> {
+        #[metrics(+"merge_asset_key_values")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object_id;
+            let value_type = state_transaction
+                .world
+                .asset_definition(&asset_id.definition_id)?
+                .value_type;
+
+            let asset_metadata_limits = state_transaction.config.asset_metadata_limits;
+            let asset = key_values_holder(&asset_id, value_type, state_transaction)?;
+            let written = merge_metadata(
+                asset.key_values_mut(value_type),
+                self.metadata,
+                self.policy,
+                asset_metadata_limits,
+            )?;
+
+            state_transaction
+                .world
+                .emit_events(written.into_iter().map(|(path, value)| {
+                    AssetEvent::MetadataInserted(MetadataChanged {
+                        target_id: asset_id.clone(),
+                        path,
+                        value,
+                    })
+                }));
+
+            Ok(())
+        }
+    }
+
     impl Execute for Transfer<Asset, Metadata, Account> {
         #[metrics(+"transfer_store")]
         fn execute(
@@ -138,11 +158,13 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let asset_id = self.source_id;
-            assert_asset_type(
+            let asset_definition = assert_asset_type(
                 &asset_id.definition_id,
                 state_transaction,
                 expected_asset_value_type_store,
             )?;
+            assert_not_frozen(&asset_id, &asset_definition, state_transaction)?;
+            assert_account_enabled(&self.destination_id, state_transaction)?;
             let account_id = asset_id.account_id.clone();
 
             let asset = state_transaction
@@ -173,6 +195,138 @@ pub mod isi {
         }
     }
 
+    impl Execute for Mint<Name, Asset> {
+        #[metrics(+"mint_nft")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.destination_id;
+            let token_id = self.object;
+
+            let asset_definition = assert_asset_type(
+                &asset_id.definition_id,
+                state_transaction,
+                expected_asset_value_type_nft,
+            )?;
+            if let Some(owner) =
+                nft_owner(&state_transaction.world, &asset_id.definition_id, &token_id)
+            {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::Mint,
+                    id: IdBox::AssetId(AssetId::new(asset_id.definition_id.clone(), owner.clone())),
+                }
+                .into());
+            }
+
+            assert_can_mint(&asset_definition, state_transaction)?;
+            let asset_metadata_limits = state_transaction.config.asset_metadata_limits;
+            let asset = state_transaction
+                .world
+                .asset_or_insert(asset_id.clone(), Metadata::new())?;
+            let AssetValue::Store(tokens) = &mut asset.value else {
+                return Err(Error::Conversion("Expected store asset type".to_owned()));
+            };
+            let value = MetadataValueBox::from(Metadata::new());
+            tokens.insert_with_limits(token_id.clone(), value.clone(), asset_metadata_limits)?;
+            state_transaction.world.set_nft_owner(
+                asset_id.definition_id.clone(),
+                token_id.clone(),
+                asset_id.account_id.clone(),
+            );
+
+            state_transaction
+                .world
+                .increase_asset_total_amount(&asset_id.definition_id, Numeric::ONE)?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::MetadataInserted(MetadataChanged {
+                    target_id: asset_id,
+                    path: vec![token_id],
+                    value,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Transfer<Asset, Name, Account> {
+        #[metrics(+"transfer_nft")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let source_id = self.source_id;
+            let token_id = self.object;
+            let destination_id =
+                AssetId::new(source_id.definition_id.clone(), self.destination_id.clone());
+
+            let asset_definition = assert_asset_type(
+                &source_id.definition_id,
+                state_transaction,
+                expected_asset_value_type_nft,
+            )?;
+            assert_not_frozen(&source_id, &asset_definition, state_transaction)?;
+            assert_account_enabled(&self.destination_id, state_transaction)?;
+
+            let value = {
+                let account = state_transaction.world.account_mut(&source_id.account_id)?;
+                let asset = account
+                    .assets
+                    .get_mut(&source_id)
+                    .ok_or_else(|| FindError::Asset(source_id.clone()))?;
+                let AssetValue::Store(tokens) = &mut asset.value else {
+                    return Err(Error::Conversion("Expected store asset type".to_owned()));
+                };
+                let value = tokens
+                    .remove(&token_id)
+                    .ok_or_else(|| FindError::MetadataKey(token_id.clone()))?;
+                if tokens.is_empty() {
+                    assert!(account.remove_asset(&source_id).is_some());
+                }
+                value
+            };
+
+            let asset_metadata_limits = state_transaction.config.asset_metadata_limits;
+            let destination_asset = state_transaction
+                .world
+                .asset_or_insert(destination_id.clone(), Metadata::new())?;
+            {
+                let AssetValue::Store(tokens) = &mut destination_asset.value else {
+                    return Err(Error::Conversion("Expected store asset type".to_owned()));
+                };
+                tokens.insert_with_limits(
+                    token_id.clone(),
+                    value.clone(),
+                    asset_metadata_limits,
+                )?;
+            }
+            state_transaction.world.set_nft_owner(
+                destination_id.definition_id.clone(),
+                token_id.clone(),
+                destination_id.account_id.clone(),
+            );
+
+            state_transaction.world.emit_events([
+                AssetEvent::MetadataRemoved(MetadataChanged {
+                    target_id: source_id,
+                    path: vec![token_id.clone()],
+                    value: value.clone(),
+                }),
+                AssetEvent::MetadataInserted(MetadataChanged {
+                    target_id: destination_id,
+                    path: vec![token_id],
+                    value,
+                }),
+            ]);
+
+            Ok(())
+        }
+    }
+
     impl Execute for Mint<Numeric, Asset> {
         fn execute(
             self,
@@ -235,6 +389,7 @@ pub mod isi {
                 expected_asset_value_type_numeric,
             )?;
             assert_numeric_spec(&self.object, &asset_definition)?;
+            assert_not_frozen(&asset_id, &asset_definition, state_transaction)?;
 
             let account = state_transaction.world.account_mut(&asset_id.account_id)?;
             let asset = account
@@ -290,35 +445,11 @@ pub mod isi {
                 expected_asset_value_type_numeric,
             )?;
             assert_numeric_spec(&self.object, &asset_definition)?;
+            assert_not_frozen(&source_id, &asset_definition, state_transaction)?;
+            assert_account_enabled(&self.destination_id, state_transaction)?;
 
-            {
-                let account = state_transaction.world.account_mut(&source_id.account_id)?;
-                let asset = account
-                    .assets
-                    .get_mut(&source_id)
-                    .ok_or_else(|| FindError::Asset(source_id.clone()))?;
-                let AssetValue::Numeric(quantity) = &mut asset.value else {
-                    return Err(Error::Conversion("Expected numeric asset type".to_owned()));
-                };
-                *quantity = quantity
-                    .checked_sub(self.object)
-                    .ok_or(MathError::NotEnoughQuantity)?;
-                if asset.value.is_zero_value() {
-                    assert!(account.remove_asset(&source_id).is_some());
-                }
-            }
-
-            let destination_asset = state_transaction
-                .world
-                .asset_or_insert(destination_id.clone(), Numeric::ZERO)?;
-            {
-                let AssetValue::Numeric(quantity) = &mut destination_asset.value else {
-                    return Err(Error::Conversion("Expected numeric asset type".to_owned()));
-                };
-                *quantity = quantity
-                    .checked_add(self.object)
-                    .ok_or(MathError::Overflow)?;
-            }
+            withdraw_numeric(&source_id, self.object, state_transaction)?;
+            deposit_numeric(&destination_id, self.object, state_transaction)?;
 
             #[allow(clippy::float_arithmetic)]
             {
@@ -343,6 +474,338 @@ pub mod isi {
         }
     }
 
+    impl Execute for Burn<All, Asset> {
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let balance = numeric_balance(&self.destination_id, state_transaction)?;
+
+            Burn::asset_numeric(balance, self.destination_id).execute(authority, state_transaction)
+        }
+    }
+
+    impl Execute for Transfer<Asset, All, Account> {
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let balance = numeric_balance(&self.source_id, state_transaction)?;
+
+            Transfer::asset_numeric(self.source_id, balance, self.destination_id)
+                .execute(authority, state_transaction)
+        }
+    }
+
+    impl Execute for Freeze<Asset> {
+        #[metrics(+"freeze_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object_id;
+
+            let asset = state_transaction.world.asset_mut(&asset_id)?;
+            if asset.frozen {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::Freeze,
+                    id: IdBox::AssetId(asset_id),
+                }
+                .into());
+            }
+            asset.frozen = true;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Frozen(asset_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unfreeze<Asset> {
+        #[metrics(+"unfreeze_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object_id;
+
+            let asset = state_transaction.world.asset_mut(&asset_id)?;
+            if !asset.frozen {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::Unfreeze,
+                    id: IdBox::AssetId(asset_id),
+                }
+                .into());
+            }
+            asset.frozen = false;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Unfrozen(asset_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for OpenEscrow {
+        #[metrics(+"open_escrow")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let escrow_id = self.escrow_id;
+            let escrow = self.escrow;
+
+            if state_transaction.world.escrows.get(&escrow_id).is_some() {
+                return Err(Error::InvariantViolation(format!(
+                    "Escrow `{escrow_id}` already exists"
+                )));
+            }
+            let asset_definition = assert_asset_type(
+                &escrow.source_id.definition_id,
+                state_transaction,
+                expected_asset_value_type_numeric,
+            )?;
+            assert_numeric_spec(&escrow.amount, &asset_definition)?;
+            assert_not_frozen(&escrow.source_id, &asset_definition, state_transaction)?;
+            assert_account_enabled(&escrow.recipient, state_transaction)?;
+
+            withdraw_numeric(&escrow.source_id, escrow.amount, state_transaction)?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Removed(AssetChanged {
+                    asset_id: escrow.source_id.clone(),
+                    amount: escrow.amount.into(),
+                })));
+            state_transaction.world.escrows.insert(escrow_id, escrow);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for ReleaseEscrow {
+        #[metrics(+"release_escrow")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let escrow_id = self.escrow_id;
+            let escrow = state_transaction
+                .world
+                .escrows
+                .get(&escrow_id)
+                .ok_or_else(|| FindError::Escrow(escrow_id.clone()))?
+                .clone();
+
+            let releasable = match &escrow.condition {
+                EscrowCondition::After(expiry) => is_reached(*expiry, state_transaction),
+                EscrowCondition::CounterSignature(account_id) => account_id == authority,
+                EscrowCondition::AccountMetadata(condition) => state_transaction
+                    .world
+                    .account(&condition.account_id)?
+                    .metadata
+                    .get(&condition.key)
+                    .is_some_and(|value| *value == condition.value),
+            };
+            if !releasable {
+                return Err(Error::InvariantViolation(format!(
+                    "Condition of escrow `{escrow_id}` doesn't hold"
+                )));
+            }
+
+            assert_account_enabled(&escrow.recipient, state_transaction)?;
+
+            let destination_id = AssetId::new(
+                escrow.source_id.definition_id.clone(),
+                escrow.recipient.clone(),
+            );
+            settle_escrow(&escrow_id, destination_id, escrow.amount, state_transaction)
+        }
+    }
+
+    impl Execute for RefundEscrow {
+        #[metrics(+"refund_escrow")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let escrow_id = self.escrow_id;
+            let escrow = state_transaction
+                .world
+                .escrows
+                .get(&escrow_id)
+                .ok_or_else(|| FindError::Escrow(escrow_id.clone()))?
+                .clone();
+
+            let refundable = *authority == escrow.recipient
+                || (authority == escrow.depositor()
+                    && escrow
+                        .refund_after
+                        .is_some_and(|expiry| is_reached(expiry, state_transaction)));
+            if !refundable {
+                return Err(Error::InvariantViolation(format!(
+                    "Escrow `{escrow_id}` can't be refunded by `{authority}` yet"
+                )));
+            }
+
+            settle_escrow(
+                &escrow_id,
+                escrow.source_id,
+                escrow.amount,
+                state_transaction,
+            )
+        }
+    }
+
+    /// Close the escrow paying its `amount` out to `destination_id`.
+    fn settle_escrow(
+        escrow_id: &EscrowId,
+        destination_id: AssetId,
+        amount: Numeric,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        state_transaction.world.escrows.remove(escrow_id.clone());
+        deposit_numeric(&destination_id, amount, state_transaction)?;
+
+        #[allow(clippy::float_arithmetic)]
+        {
+            state_transaction
+                .new_tx_amounts
+                .lock()
+                .push(amount.to_f64());
+        }
+
+        state_transaction
+            .world
+            .emit_events(Some(AssetEvent::Added(AssetChanged {
+                asset_id: destination_id,
+                amount: amount.into(),
+            })));
+
+        Ok(())
+    }
+
+    /// Whether `expiry` is reached by the latest committed block.
+    fn is_reached(expiry: MetadataExpiry, state_transaction: &StateTransaction<'_, '_>) -> bool {
+        let latest_block_timestamp_ms = state_transaction
+            .latest_block_ref()
+            .map_or(0, |block| block.header().timestamp_ms);
+        expiry.is_reached(state_transaction.height(), latest_block_timestamp_ms)
+    }
+
+    /// Current quantity of the numeric asset.
+    fn numeric_balance(
+        asset_id: &AssetId,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<Numeric, Error> {
+        match state_transaction.world.asset(asset_id)?.value {
+            AssetValue::Numeric(quantity) => Ok(quantity),
+            _ => Err(Error::Conversion("Expected numeric asset type".to_owned())),
+        }
+    }
+
+    /// Take `amount` from the numeric asset, removing the asset once it's empty.
+    fn withdraw_numeric(
+        asset_id: &AssetId,
+        amount: Numeric,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let account = state_transaction.world.account_mut(&asset_id.account_id)?;
+        let asset = account
+            .assets
+            .get_mut(asset_id)
+            .ok_or_else(|| FindError::Asset(asset_id.clone()))?;
+        let AssetValue::Numeric(quantity) = &mut asset.value else {
+            return Err(Error::Conversion("Expected numeric asset type".to_owned()));
+        };
+        *quantity = quantity
+            .checked_sub(amount)
+            .ok_or(MathError::NotEnoughQuantity)?;
+        if asset.value.is_zero_value() {
+            assert!(account.remove_asset(asset_id).is_some());
+        }
+
+        Ok(())
+    }
+
+    /// Add `amount` to the numeric asset, creating the asset if it isn't held yet.
+    fn deposit_numeric(
+        asset_id: &AssetId,
+        amount: Numeric,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let asset = state_transaction
+            .world
+            .asset_or_insert(asset_id.clone(), Numeric::ZERO)?;
+        let AssetValue::Numeric(quantity) = &mut asset.value else {
+            return Err(Error::Conversion("Expected numeric asset type".to_owned()));
+        };
+        *quantity = quantity.checked_add(amount).ok_or(MathError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Asset whose key-value entries are to be written.
+    ///
+    /// [`AssetValueType::Store`] assets are created on the first write,
+    /// assets of other types must already be held.
+    fn key_values_holder<'tx>(
+        asset_id: &AssetId,
+        value_type: AssetValueType,
+        state_transaction: &'tx mut StateTransaction<'_, '_>,
+    ) -> Result<&'tx mut Asset, Error> {
+        if value_type != AssetValueType::Store {
+            return Ok(state_transaction.world.asset_mut(asset_id)?);
+        }
+
+        // Increase `Store` asset total quantity by 1 if asset was not present earlier
+        if matches!(
+            state_transaction.world.asset(asset_id),
+            Err(QueryExecutionFail::Find(_))
+        ) {
+            state_transaction
+                .world
+                .increase_asset_total_amount(&asset_id.definition_id, Numeric::ONE)?;
+        }
+
+        state_transaction
+            .world
+            .asset_or_insert(asset_id.clone(), Metadata::new())
+    }
+
+    /// Assert that neither the asset nor its definition is frozen.
+    fn assert_not_frozen(
+        asset_id: &AssetId,
+        asset_definition: &AssetDefinition,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        if asset_definition.frozen {
+            return Err(Error::Frozen(IdBox::AssetDefinitionId(
+                asset_definition.id.clone(),
+            )));
+        }
+        let asset_frozen = state_transaction
+            .world
+            .account(&asset_id.account_id)?
+            .assets
+            .get(asset_id)
+            .is_some_and(|asset| asset.frozen);
+        if asset_frozen {
+            return Err(Error::Frozen(IdBox::AssetId(asset_id.clone())));
+        }
+        Ok(())
+    }
+
     /// Assert that asset type is Numeric and that it satisfy asset definition spec
     pub(crate) fn assert_numeric_spec(
         object: &Numeric,
@@ -420,13 +883,33 @@ pub mod isi {
             other => Err(TypeError::NumericAssetValueTypeExpected(*other)),
         }
     }
+
+    pub(crate) fn expected_asset_value_type_nft(
+        asset_value_type: &AssetValueType,
+    ) -> Result<(), TypeError> {
+        match asset_value_type {
+            AssetValueType::Nft => Ok(()),
+            other => Err(TypeError::NftAssetValueTypeExpected(*other)),
+        }
+    }
+
+    /// Account holding the token `token_id` of the asset definition `definition_id`, if minted.
+    pub(crate) fn nft_owner<'world>(
+        world: &'world impl WorldReadOnly,
+        definition_id: &AssetDefinitionId,
+        token_id: &Name,
+    ) -> Option<&'world AccountId> {
+        world
+            .nft_owners()
+            .get(&NftTokenKey::new(definition_id.clone(), token_id.clone()))
+    }
 }
 
 /// Asset-related query implementations.
 pub mod query {
     use eyre::Result;
     use iroha_data_model::{
-        asset::{Asset, AssetDefinition, AssetValue},
+        asset::{Asset, AssetDefinition, AssetValue, AssetValueType},
         metadata::MetadataValueBox,
         query::{asset::FindAssetDefinitionById, error::QueryExecutionFail as Error},
     };
@@ -619,6 +1102,63 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAssetsByMetadataKeyPrefix {
+        #[metrics(+"find_assets_by_metadata_key_prefix")]
+        fn execute<'state>(
+            &self,
+            state_ro: &'state impl StateReadOnly,
+        ) -> Result<Box<dyn Iterator<Item = Asset> + 'state>, Error> {
+            let prefix = self.prefix.clone();
+            iroha_logger::trace!(%prefix);
+            Ok(Box::new(
+                state_ro
+                    .world()
+                    .domains_iter()
+                    .flat_map(|domain| {
+                        domain
+                            .accounts
+                            .values()
+                            .flat_map(|account| account.assets.values())
+                    })
+                    .filter(move |asset| {
+                        let in_store = match &asset.value {
+                            AssetValue::Store(store) => {
+                                store.keys_with_prefix(&prefix).next().is_some()
+                            }
+                            AssetValue::Numeric(_) => false,
+                        };
+                        in_store || asset.metadata.keys_with_prefix(&prefix).next().is_some()
+                    })
+                    .cloned(),
+            ))
+        }
+    }
+
+    impl ValidQuery for FindNftsByAccountId {
+        #[metrics(+"find_nfts_by_account_id")]
+        fn execute<'state>(
+            &self,
+            state_ro: &'state impl StateReadOnly,
+        ) -> Result<Box<dyn Iterator<Item = Asset> + 'state>, Error> {
+            let id = &self.account_id;
+            iroha_logger::trace!(%id);
+            let world = state_ro.world();
+            Ok(Box::new(
+                world
+                    .account_assets(id)?
+                    .filter(move |asset| {
+                        let definition_id = &asset.id.definition_id;
+                        world
+                            .domain(&definition_id.domain_id)
+                            .ok()
+                            .and_then(|domain| domain.asset_definitions.get(definition_id))
+                            .is_some_and(|definition| definition.value_type == AssetValueType::Nft)
+                    })
+                    .cloned(),
+            ))
+        }
+    }
+
     impl ValidQuery for FindAssetQuantityById {
         #[metrics(+"find_asset_quantity_by_id")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Numeric, Error> {
@@ -657,6 +1197,25 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAssetCountByAssetDefinitionId {
+        #[metrics(+"find_asset_count_by_asset_definition_id")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Numeric, Error> {
+            let id = &self.id;
+            iroha_logger::trace!(%id);
+            state_ro.world().asset_definition(id)?;
+            let count = state_ro
+                .world()
+                .domains_iter()
+                .flat_map(|domain| domain.accounts.values())
+                .flat_map(|account| account.assets.values())
+                .filter(|asset| asset.id().definition_id == *id)
+                .count();
+            Ok(u64::try_from(count)
+                .expect("`usize` should always fit into `u64`")
+                .into())
+        }
+    }
+
     impl ValidQuery for FindAssetKeyValueByIdAndKey {
         #[metrics(+"find_asset_key_value_by_id_and_key")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<MetadataValueBox, Error> {
@@ -670,15 +1229,39 @@ pub mod query {
                 }
             })?;
             iroha_logger::trace!(%id, %key);
-            let AssetValue::Store(store) = &asset.value else {
-                return Err(Error::Conversion("expected store, found other".to_owned()));
-            };
+            let value_type = state_ro
+                .world()
+                .asset_definition(&id.definition_id)?
+                .value_type;
 
-            store
+            asset
+                .key_values(value_type)
                 .get(key)
                 .ok_or_else(|| Error::Find(FindError::MetadataKey(key.clone())))
                 .cloned()
                 .map(Into::into)
         }
     }
+
+    impl ValidQuery for FindAssetKeyValueByIdAndPath {
+        #[metrics(+"find_asset_key_value_by_id_and_path")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<MetadataValueBox, Error> {
+            let id = &self.id;
+            let path = &self.path;
+            let asset = state_ro.world().asset(id).map_err(|asset_err| {
+                if let Err(definition_err) = state_ro.world().asset_definition(&id.definition_id) {
+                    Error::Find(definition_err)
+                } else {
+                    asset_err
+                }
+            })?;
+            iroha_logger::trace!(%id, ?path);
+            let value_type = state_ro
+                .world()
+                .asset_definition(&id.definition_id)?
+                .value_type;
+
+            find_metadata_value(asset.key_values(value_type), path)
+        }
+    }
 }
@@ -3,7 +3,7 @@ use eyre::Result;
 use iroha_data_model::{
     block::{BlockHeader, SignedBlock},
     query::{
-        block::FindBlockHeaderByHash,
+        block::{FindBlockByHash, FindBlockByHeight, FindBlockHeaderByHash, FindBlocksInRange},
         error::{FindError, QueryExecutionFail},
     },
 };
@@ -30,12 +30,7 @@ impl ValidQuery for FindAllBlockHeaders {
         &self,
         staete_snapshot: &'state impl StateReadOnly,
     ) -> Result<Box<dyn Iterator<Item = BlockHeader> + 'state>, QueryExecutionFail> {
-        Ok(Box::new(
-            staete_snapshot
-                .all_blocks()
-                .rev()
-                .map(|block| block.header().clone()),
-        ))
+        Ok(Box::new(staete_snapshot.all_block_headers().rev()))
     }
 }
 
@@ -44,11 +39,73 @@ impl ValidQuery for FindBlockHeaderByHash {
     fn execute(&self, state_ro: &impl StateReadOnly) -> Result<BlockHeader, QueryExecutionFail> {
         let hash = self.hash;
 
+        let height = state_ro
+            .block_hashes()
+            .iter()
+            .position(|block_hash| *block_hash == hash)
+            .ok_or_else(|| QueryExecutionFail::Find(FindError::Block(hash)))?;
+
+        Ok(state_ro
+            .kura()
+            .get_block_header_by_height(height as u64 + 1)
+            .expect("Failed to load block header."))
+    }
+}
+
+impl ValidQuery for FindBlockByHash {
+    #[metrics(+"find_block_by_hash")]
+    fn execute(&self, state_ro: &impl StateReadOnly) -> Result<SignedBlock, QueryExecutionFail> {
+        let hash = self.hash;
+
+        let height = state_ro
+            .block_hashes()
+            .iter()
+            .position(|block_hash| *block_hash == hash)
+            .ok_or_else(|| QueryExecutionFail::Find(FindError::Block(hash)))?;
+
+        // The block body might have been pruned on a non-archival peer
         let block = state_ro
-            .all_blocks()
-            .find(|block| block.hash() == hash)
+            .kura()
+            .get_block_by_height(height as u64 + 1)
             .ok_or_else(|| QueryExecutionFail::Find(FindError::Block(hash)))?;
 
-        Ok(block.header().clone())
+        Ok((*block).clone())
+    }
+}
+
+impl ValidQuery for FindBlockByHeight {
+    #[metrics(+"find_block_by_height")]
+    fn execute(&self, state_ro: &impl StateReadOnly) -> Result<SignedBlock, QueryExecutionFail> {
+        let height = self.height;
+
+        if height.get() > state_ro.height() {
+            return Err(QueryExecutionFail::Find(FindError::BlockHeight(height)));
+        }
+
+        // The block body might have been pruned on a non-archival peer
+        let block = state_ro
+            .kura()
+            .get_block_by_height(height.get())
+            .ok_or_else(|| QueryExecutionFail::Find(FindError::BlockHeight(height)))?;
+
+        Ok((*block).clone())
+    }
+}
+
+impl ValidQuery for FindBlocksInRange {
+    #[metrics(+"find_blocks_in_range")]
+    fn execute<'state>(
+        &self,
+        state_ro: &'state impl StateReadOnly,
+    ) -> Result<Box<dyn Iterator<Item = SignedBlock> + 'state>, QueryExecutionFail> {
+        let end = self.end.get().min(state_ro.height());
+
+        // Blocks whose bodies were pruned on a non-archival peer are skipped
+        Ok(Box::new((self.start.get()..=end).filter_map(|height| {
+            state_ro
+                .kura()
+                .get_block_by_height(height)
+                .map(|block| (*block).clone())
+        })))
     }
 }
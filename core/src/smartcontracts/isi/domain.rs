@@ -2,7 +2,7 @@
 
 use eyre::Result;
 use iroha_data_model::{
-    account::AccountsMap,
+    account::{AccountAliasesMap, AccountsMap},
     asset::{AssetDefinitionsMap, AssetTotalQuantityMap},
     prelude::*,
     query::error::FindError,
@@ -25,6 +25,8 @@ impl Registrable for iroha_data_model::domain::NewDomain {
             metadata: self.metadata,
             logo: self.logo,
             owned_by: authority.clone(),
+            metadata_limits: DomainMetadataLimits::default(),
+            account_aliases: AccountAliasesMap::default(),
         }
     }
 }
@@ -84,9 +86,19 @@ pub mod isi {
             let account_id = self.object_id;
 
             let domain = state_transaction.world.domain_mut(&account_id.domain_id)?;
-            if domain.remove_account(&account_id).is_none() {
+            let Some(account) = domain.remove_account(&account_id) else {
                 return Err(FindError::Account(account_id).into());
+            };
+            domain
+                .account_aliases
+                .retain(|_, aliased_id| *aliased_id != account_id);
+            for asset in account.assets.values() {
+                state_transaction.world.remove_nft_owners(asset);
             }
+            state_transaction
+                .world
+                .swap_offers
+                .remove(account_id.clone());
 
             state_transaction
                 .world
@@ -169,13 +181,13 @@ pub mod isi {
             let mut events = Vec::with_capacity(assets_to_remove.len() + 1);
             for asset_id in assets_to_remove {
                 let account_id = asset_id.account_id.clone();
-                if state_transaction
+                match state_transaction
                     .world
                     .account_mut(&account_id)?
                     .remove_asset(&asset_id)
-                    .is_none()
                 {
-                    error!(%asset_id, "asset not found. This is a bug");
+                    Some(asset) => state_transaction.world.remove_nft_owners(&asset),
+                    None => error!(%asset_id, "asset not found. This is a bug"),
                 }
 
                 events.push(AccountEvent::Asset(AssetEvent::Deleted(asset_id)).into());
@@ -203,6 +215,64 @@ pub mod isi {
         }
     }
 
+    impl Execute for Freeze<AssetDefinition> {
+        #[metrics(+"freeze_asset_definition")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.object_id;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            if asset_definition.frozen {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::Freeze,
+                    id: IdBox::AssetDefinitionId(asset_definition_id),
+                }
+                .into());
+            }
+            asset_definition.frozen = true;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetDefinitionEvent::Frozen(asset_definition_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unfreeze<AssetDefinition> {
+        #[metrics(+"unfreeze_asset_definition")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.object_id;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            if !asset_definition.frozen {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::Unfreeze,
+                    id: IdBox::AssetDefinitionId(asset_definition_id),
+                }
+                .into());
+            }
+            asset_definition.frozen = false;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetDefinitionEvent::Unfrozen(asset_definition_id)));
+
+            Ok(())
+        }
+    }
+
     impl Execute for SetKeyValue<AssetDefinition> {
         #[metrics(+"set_key_value_asset_definition")]
         fn execute(
@@ -212,7 +282,10 @@ pub mod isi {
         ) -> Result<(), Error> {
             let asset_definition_id = self.object_id;
 
-            let metadata_limits = state_transaction.config.asset_definition_metadata_limits;
+            let metadata_limits = state_transaction
+                .world
+                .domain_metadata_limits(&asset_definition_id.domain_id)?
+                .asset_definition_limits(state_transaction.config.asset_definition_metadata_limits);
             state_transaction
                 .world
                 .asset_definition_mut(&asset_definition_id)
@@ -229,7 +302,7 @@ pub mod isi {
                 .emit_events(Some(AssetDefinitionEvent::MetadataInserted(
                     MetadataChanged {
                         target_id: asset_definition_id,
-                        key: self.key,
+                        path: vec![self.key],
                         value: self.value,
                     },
                 )));
@@ -262,7 +335,7 @@ pub mod isi {
                 .emit_events(Some(AssetDefinitionEvent::MetadataRemoved(
                     MetadataChanged {
                         target_id: asset_definition_id,
-                        key: self.key,
+                        path: vec![self.key],
                         value,
                     },
                 )));
@@ -271,6 +344,43 @@ pub mod isi {
         }
     }
 
+    impl Execute for MergeKeyValues<AssetDefinition> {
+        #[metrics(+"merge_asset_definition_key_values")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.object_id;
+
+            let metadata_limits = state_transaction
+                .world
+                .domain_metadata_limits(&asset_definition_id.domain_id)?
+                .asset_definition_limits(state_transaction.config.asset_definition_metadata_limits);
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            let written = merge_metadata(
+                &mut asset_definition.metadata,
+                self.metadata,
+                self.policy,
+                metadata_limits,
+            )?;
+
+            state_transaction
+                .world
+                .emit_events(written.into_iter().map(|(path, value)| {
+                    AssetDefinitionEvent::MetadataInserted(MetadataChanged {
+                        target_id: asset_definition_id.clone(),
+                        path,
+                        value,
+                    })
+                }));
+
+            Ok(())
+        }
+    }
+
     impl Execute for SetKeyValue<Domain> {
         #[metrics(+"set_domain_key_value")]
         fn execute(
@@ -291,7 +401,7 @@ pub mod isi {
                 .world
                 .emit_events(Some(DomainEvent::MetadataInserted(MetadataChanged {
                     target_id: domain_id,
-                    key: self.key,
+                    path: vec![self.key],
                     value: self.value,
                 })));
 
@@ -318,7 +428,7 @@ pub mod isi {
                 .world
                 .emit_events(Some(DomainEvent::MetadataRemoved(MetadataChanged {
                     target_id: domain_id,
-                    key: self.key,
+                    path: vec![self.key],
                     value,
                 })));
 
@@ -326,6 +436,34 @@ pub mod isi {
         }
     }
 
+    impl Execute for MergeKeyValues<Domain> {
+        #[metrics(+"merge_domain_key_values")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let domain_id = self.object_id;
+
+            let limits = state_transaction.config.domain_metadata_limits;
+
+            let domain = state_transaction.world.domain_mut(&domain_id)?;
+            let written = merge_metadata(&mut domain.metadata, self.metadata, self.policy, limits)?;
+
+            state_transaction
+                .world
+                .emit_events(written.into_iter().map(|(path, value)| {
+                    DomainEvent::MetadataInserted(MetadataChanged {
+                        target_id: domain_id.clone(),
+                        path,
+                        value,
+                    })
+                }));
+
+            Ok(())
+        }
+    }
+
     impl Execute for Transfer<Account, DomainId, Account> {
         fn execute(
             self,
@@ -358,6 +496,97 @@ pub mod isi {
             Ok(())
         }
     }
+
+    impl Execute for SetDomainMetadataLimits {
+        #[metrics(+"set_domain_metadata_limits")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let SetDomainMetadataLimits { domain_id, limits } = self;
+
+            state_transaction
+                .world
+                .domain_mut(&domain_id)?
+                .metadata_limits = limits;
+            state_transaction
+                .world
+                .emit_events(Some(DomainEvent::MetadataLimitsChanged(
+                    DomainMetadataLimitsChanged { domain_id, limits },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RegisterAlias {
+        #[metrics(+"register_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let RegisterAlias { alias, account_id } = self;
+
+            if alias.domain_id != account_id.domain_id {
+                return Err(Error::InvariantViolation(format!(
+                    "Alias `{alias}` must be registered in the domain of `{account_id}`"
+                )));
+            }
+            alias
+                .name
+                .validate_len(state_transaction.config.ident_length_limits)
+                .map_err(Error::from)?;
+
+            let domain = state_transaction.world.domain_mut(&alias.domain_id)?;
+            if !domain.contains_account(&account_id) {
+                return Err(FindError::Account(account_id).into());
+            }
+            if domain.account_aliases.contains_key(&alias.name) {
+                return Err(Error::InvariantViolation(format!(
+                    "Alias `{alias}` is already registered"
+                )));
+            }
+            domain
+                .account_aliases
+                .insert(alias.name.clone(), account_id.clone());
+
+            state_transaction
+                .world
+                .emit_events(Some(DomainEvent::AccountAliasRegistered(
+                    AccountAliasChanged { alias, account_id },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for UnregisterAlias {
+        #[metrics(+"unregister_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let alias = self.alias;
+
+            let account_id = state_transaction
+                .world
+                .domain_mut(&alias.domain_id)?
+                .account_aliases
+                .remove(&alias.name)
+                .ok_or_else(|| FindError::AccountAlias(alias.clone()))?;
+
+            state_transaction
+                .world
+                .emit_events(Some(DomainEvent::AccountAliasUnregistered(
+                    AccountAliasChanged { alias, account_id },
+                )));
+
+            Ok(())
+        }
+    }
 }
 
 /// Query module provides [`Query`] Domain related implementations.
@@ -380,6 +609,25 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindDomainDescendants {
+        #[metrics(+"find_domain_descendants")]
+        fn execute<'state>(
+            &self,
+            state_ro: &'state impl StateReadOnly,
+        ) -> Result<Box<dyn Iterator<Item = Domain> + 'state>, Error> {
+            let id = self.id.clone();
+            iroha_logger::trace!(%id);
+            let world = state_ro.world();
+            world.domain(&id)?;
+            Ok(Box::new(
+                world
+                    .domains_iter()
+                    .filter(move |domain| domain.id().is_descendant_of(&id))
+                    .cloned(),
+            ))
+        }
+    }
+
     impl ValidQuery for FindDomainById {
         #[metrics(+"find_domain_by_id")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Domain, Error> {
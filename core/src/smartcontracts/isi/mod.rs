@@ -12,8 +12,13 @@ pub mod world;
 
 use eyre::Result;
 use iroha_data_model::{
-    isi::{error::InstructionExecutionError as Error, *},
+    isi::{
+        error::{InstructionExecutionError as Error, MathError},
+        *,
+    },
+    metadata::{MetadataError, MetadataValueBox},
     prelude::*,
+    query::error::FindError,
 };
 use iroha_logger::prelude::*;
 use storage::storage::StorageReadOnly;
@@ -22,7 +27,9 @@ use super::Execute;
 use crate::{
     prelude::*,
     smartcontracts::triggers::set::SetReadOnly,
-    state::{StateReadOnly, StateTransaction, WorldReadOnly},
+    state::{
+        metadata_entry_value, ExpiringMetadataEntry, StateReadOnly, StateTransaction, WorldReadOnly,
+    },
 };
 
 /// Trait for proxy objects used for registration.
@@ -51,9 +58,38 @@ impl Execute for InstructionBox {
             Self::Fail(isi) => isi.execute(authority, state_transaction),
             Self::SetKeyValue(isi) => isi.execute(authority, state_transaction),
             Self::RemoveKeyValue(isi) => isi.execute(authority, state_transaction),
+            Self::MergeKeyValues(isi) => isi.execute(authority, state_transaction),
+            Self::SetDomainMetadataLimits(isi) => isi.execute(authority, state_transaction),
+            Self::RegisterAlias(isi) => isi.execute(authority, state_transaction),
+            Self::UnregisterAlias(isi) => isi.execute(authority, state_transaction),
+            Self::SetKeyValueWithExpiry(isi) => isi.execute(authority, state_transaction),
+            Self::SetKeyValueIf(isi) => isi.execute(authority, state_transaction),
+            Self::IncrementKeyValue(isi) => isi.execute(authority, state_transaction),
+            Self::Swap(isi) => isi.execute(authority, state_transaction),
+            Self::TransferMany(isi) => isi.execute(authority, state_transaction),
+            Self::OpenEscrow(isi) => isi.execute(authority, state_transaction),
+            Self::ReleaseEscrow(isi) => isi.execute(authority, state_transaction),
+            Self::RefundEscrow(isi) => isi.execute(authority, state_transaction),
+            Self::AddSignatory(isi) => isi.execute(authority, state_transaction),
+            Self::RemoveSignatory(isi) => isi.execute(authority, state_transaction),
+            Self::SetSignatureThreshold(isi) => isi.execute(authority, state_transaction),
+            Self::RotateKey(isi) => isi.execute(authority, state_transaction),
+            Self::SetRecoveryPolicy(isi) => isi.execute(authority, state_transaction),
+            Self::RecoverAccount(isi) => isi.execute(authority, state_transaction),
+            Self::FinalizeRecovery(isi) => isi.execute(authority, state_transaction),
+            Self::CancelRecovery(isi) => isi.execute(authority, state_transaction),
+            Self::DisableAccount(isi) => isi.execute(authority, state_transaction),
+            Self::EnableAccount(isi) => isi.execute(authority, state_transaction),
+            Self::SetStrictOrdering(isi) => isi.execute(authority, state_transaction),
+            Self::Freeze(isi) => isi.execute(authority, state_transaction),
+            Self::Unfreeze(isi) => isi.execute(authority, state_transaction),
             Self::Grant(isi) => isi.execute(authority, state_transaction),
             Self::Revoke(isi) => isi.execute(authority, state_transaction),
+            Self::GrantWithTerms(isi) => isi.execute(authority, state_transaction),
             Self::ExecuteTrigger(isi) => isi.execute(authority, state_transaction),
+            Self::Pause(isi) => isi.execute(authority, state_transaction),
+            Self::Resume(isi) => isi.execute(authority, state_transaction),
+            Self::UpgradeTrigger(isi) => isi.execute(authority, state_transaction),
             Self::SetParameter(isi) => isi.execute(authority, state_transaction),
             Self::NewParameter(isi) => isi.execute(authority, state_transaction),
             Self::Upgrade(isi) => isi.execute(authority, state_transaction),
@@ -77,6 +113,7 @@ impl Execute for RegisterBox {
             Self::Asset(isi) => isi.execute(authority, state_transaction),
             Self::Role(isi) => isi.execute(authority, state_transaction),
             Self::Trigger(isi) => isi.execute(authority, state_transaction),
+            Self::WasmCode(isi) => isi.execute(authority, state_transaction),
         }
     }
 }
@@ -110,6 +147,7 @@ impl Execute for MintBox {
         match self {
             Self::Asset(isi) => isi.execute(authority, state_transaction),
             Self::TriggerRepetitions(isi) => isi.execute(authority, state_transaction),
+            Self::Nft(isi) => isi.execute(authority, state_transaction),
         }
     }
 }
@@ -123,6 +161,7 @@ impl Execute for BurnBox {
     ) -> Result<(), Error> {
         match self {
             Self::Asset(isi) => isi.execute(authority, state_transaction),
+            Self::AssetAll(isi) => isi.execute(authority, state_transaction),
             Self::TriggerRepetitions(isi) => isi.execute(authority, state_transaction),
         }
     }
@@ -151,7 +190,9 @@ impl Execute for AssetTransferBox {
     ) -> std::prelude::v1::Result<(), Error> {
         match self {
             Self::Numeric(isi) => isi.execute(authority, state_transaction),
+            Self::NumericAll(isi) => isi.execute(authority, state_transaction),
             Self::Store(isi) => isi.execute(authority, state_transaction),
+            Self::Nft(isi) => isi.execute(authority, state_transaction),
         }
     }
 }
@@ -188,6 +229,240 @@ impl Execute for RemoveKeyValueBox {
     }
 }
 
+impl Execute for MergeKeyValuesBox {
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Domain(isi) => isi.execute(authority, state_transaction),
+            Self::Account(isi) => isi.execute(authority, state_transaction),
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+            Self::Trigger(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+/// Split `set` into the instruction removing the entry it sets and the value it sets.
+fn split_set_key_value(set: &SetKeyValueBox) -> (RemoveKeyValueBox, MetadataValueBox) {
+    match set {
+        SetKeyValueBox::Domain(isi) => (
+            RemoveKeyValue::domain(isi.object_id.clone(), isi.key.clone()).into(),
+            isi.value.clone(),
+        ),
+        SetKeyValueBox::Account(isi) => (
+            RemoveKeyValue::account(isi.object_id.clone(), isi.key.clone()).into(),
+            isi.value.clone(),
+        ),
+        SetKeyValueBox::AssetDefinition(isi) => (
+            RemoveKeyValue::asset_definition(isi.object_id.clone(), isi.key.clone()).into(),
+            isi.value.clone(),
+        ),
+        SetKeyValueBox::Asset(isi) => (
+            RemoveKeyValue::asset(isi.object_id.clone(), isi.key.clone()).into(),
+            isi.value.clone(),
+        ),
+        SetKeyValueBox::Trigger(isi) => (
+            RemoveKeyValue::trigger(isi.object_id.clone(), isi.key.clone()).into(),
+            isi.value.clone(),
+        ),
+    }
+}
+
+impl Execute for SetKeyValueWithExpiry {
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let (removal, value) = split_set_key_value(&self.set);
+
+        self.set.execute(authority, state_transaction)?;
+        state_transaction.world.schedule_metadata_expiration(
+            removal,
+            ExpiringMetadataEntry {
+                authority: authority.clone(),
+                expiry: self.expiry,
+                value,
+            },
+        );
+
+        Ok(())
+    }
+}
+
+impl Execute for SetKeyValueIf {
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let (removal, _) = split_set_key_value(&self.set);
+        let current = metadata_entry_value(&state_transaction.world, &removal);
+
+        if current != self.expected {
+            let key = match removal {
+                RemoveKeyValueBox::Domain(isi) => isi.key,
+                RemoveKeyValueBox::Account(isi) => isi.key,
+                RemoveKeyValueBox::AssetDefinition(isi) => isi.key,
+                RemoveKeyValueBox::Asset(isi) => isi.key,
+                RemoveKeyValueBox::Trigger(isi) => isi.key,
+            };
+            return Err(MetadataError::ValueMismatch(key).into());
+        }
+
+        self.set.execute(authority, state_transaction)
+    }
+}
+
+impl Execute for IncrementKeyValueBox {
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let (removal, key, delta): (RemoveKeyValueBox, _, _) = match &self {
+            Self::Domain(isi) => (
+                RemoveKeyValue::domain(isi.object_id.clone(), isi.key.clone()).into(),
+                isi.key.clone(),
+                isi.delta,
+            ),
+            Self::Account(isi) => (
+                RemoveKeyValue::account(isi.object_id.clone(), isi.key.clone()).into(),
+                isi.key.clone(),
+                isi.delta,
+            ),
+            Self::AssetDefinition(isi) => (
+                RemoveKeyValue::asset_definition(isi.object_id.clone(), isi.key.clone()).into(),
+                isi.key.clone(),
+                isi.delta,
+            ),
+            Self::Asset(isi) => (
+                RemoveKeyValue::asset(isi.object_id.clone(), isi.key.clone()).into(),
+                isi.key.clone(),
+                isi.delta,
+            ),
+            Self::Trigger(isi) => (
+                RemoveKeyValue::trigger(isi.object_id.clone(), isi.key.clone()).into(),
+                isi.key.clone(),
+                isi.delta,
+            ),
+        };
+
+        let current = match metadata_entry_value(&state_transaction.world, &removal) {
+            Some(MetadataValueBox::Numeric(current)) => current,
+            Some(_) => return Err(MetadataError::NotNumeric(key).into()),
+            None => return Err(FindError::MetadataKey(key).into()),
+        };
+        let value = delta.apply(current).ok_or(match delta {
+            NumericDelta::Increase(_) => MathError::Overflow,
+            NumericDelta::Decrease(_) => MathError::NotEnoughQuantity,
+        })?;
+
+        let set: SetKeyValueBox = match self {
+            Self::Domain(isi) => SetKeyValue::domain(isi.object_id, isi.key, value).into(),
+            Self::Account(isi) => SetKeyValue::account(isi.object_id, isi.key, value).into(),
+            Self::AssetDefinition(isi) => {
+                SetKeyValue::asset_definition(isi.object_id, isi.key, value).into()
+            }
+            Self::Asset(isi) => SetKeyValue::asset(isi.object_id, isi.key, value).into(),
+            Self::Trigger(isi) => SetKeyValue::trigger(isi.object_id, isi.key, value).into(),
+        };
+        set.execute(authority, state_transaction)
+    }
+}
+
+impl Execute for FreezeBox {
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for UnfreezeBox {
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for Swap {
+    #[iroha_logger::log(name = "swap", skip_all)]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        if self.first.destination_id() != &self.second.source_id().account_id
+            || self.second.destination_id() != &self.first.source_id().account_id
+        {
+            return Err(Error::InvariantViolation(
+                "Swap legs must exchange assets between the same two accounts".to_owned(),
+            ));
+        }
+
+        let first_account_id = &self.first.source_id().account_id;
+        let second_account_id = &self.second.source_id().account_id;
+        let counterparty_id = if authority == first_account_id {
+            second_account_id
+        } else if authority == second_account_id {
+            first_account_id
+        } else {
+            return Err(Error::InvariantViolation(
+                "Only the accounts exchanging assets can swap them".to_owned(),
+            ));
+        };
+
+        // Both legs are executed only once the counterparty has offered the same swap
+        if counterparty_id != authority {
+            if state_transaction.world.swap_offers.get(counterparty_id) != Some(&self) {
+                state_transaction
+                    .world
+                    .swap_offers
+                    .insert(authority.clone(), self);
+                return Ok(());
+            }
+            state_transaction
+                .world
+                .swap_offers
+                .remove(counterparty_id.clone());
+        }
+
+        self.first.execute(authority, state_transaction)?;
+        self.second.execute(authority, state_transaction)
+    }
+}
+
+impl Execute for TransferMany {
+    #[iroha_logger::log(name = "transfer_many", skip_all)]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        for (destination_id, quantity) in self.recipients {
+            Transfer::asset_numeric(self.source_id.clone(), quantity, destination_id)
+                .execute(authority, state_transaction)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Execute for Fail {
     fn execute(
         self,
@@ -230,6 +505,56 @@ impl Execute for RevokeBox {
     }
 }
 
+/// Merge `metadata` into `target` and collect the entries that were written,
+/// so that a `MetadataInserted` event can be emitted for each of them.
+pub(crate) fn merge_metadata(
+    target: &mut Metadata,
+    metadata: Metadata,
+    policy: MergePolicy,
+    limits: MetadataLimits,
+) -> Result<Vec<(Vec<Name>, iroha_data_model::metadata::MetadataValueBox)>, Error> {
+    let written = target.merge_with_limits(metadata, policy, limits)?;
+
+    Ok(written
+        .into_iter()
+        .filter_map(|path| {
+            let value = target.nested_get(&path)?.clone();
+            Some((path, value))
+        })
+        .collect())
+}
+
+/// Find the value nested at `path` in `metadata`, an empty path addressing `metadata` itself.
+///
+/// # Errors
+/// Fails with [`FindError::MetadataKey`] naming the first segment of `path` that wasn't found.
+pub(crate) fn find_metadata_value(
+    metadata: &Metadata,
+    path: &[Name],
+) -> Result<
+    iroha_data_model::metadata::MetadataValueBox,
+    iroha_data_model::query::error::QueryExecutionFail,
+> {
+    use iroha_data_model::{metadata::MetadataValueBox, query::error::FindError};
+
+    let Some((key, parents)) = path.split_last() else {
+        return Ok(metadata.clone().into());
+    };
+
+    let mut layer = metadata;
+    for segment in parents {
+        match layer.get(segment) {
+            Some(MetadataValueBox::LimitedMetadata(nested)) => layer = nested,
+            _ => return Err(FindError::MetadataKey(segment.clone()).into()),
+        }
+    }
+
+    layer
+        .get(key)
+        .cloned()
+        .ok_or_else(|| FindError::MetadataKey(key.clone()).into())
+}
+
 pub mod prelude {
     //! Re-export important traits and types for glob import `(::*)`
     pub use super::*;
@@ -237,12 +562,20 @@ pub mod prelude {
 
 #[cfg(test)]
 mod tests {
-    use core::str::FromStr as _;
+    use core::{
+        num::{NonZeroU16, NonZeroU64},
+        str::FromStr as _,
+    };
     use std::sync::Arc;
 
-    use iroha_data_model::metadata::MetadataValueBox;
+    use iroha_crypto::{HashOf, KeyPair};
+    use iroha_data_model::{
+        isi::error::MintabilityError, metadata::MetadataValueBox,
+        parameter::default::TRANSACTION_FEES, transaction::TransactionFees,
+    };
     use test_samples::{
-        gen_account_in, ALICE_ID, SAMPLE_GENESIS_ACCOUNT_ID, SAMPLE_GENESIS_ACCOUNT_KEYPAIR,
+        gen_account_in, ALICE_ID, ALICE_KEYPAIR, SAMPLE_GENESIS_ACCOUNT_ID,
+        SAMPLE_GENESIS_ACCOUNT_KEYPAIR,
     };
     use tokio::test;
 
@@ -250,6 +583,7 @@ mod tests {
     use crate::{
         kura::Kura,
         query::store::LiveQueryStore,
+        smartcontracts::isi::asset::isi::nft_owner,
         state::{State, World},
         tx::AcceptTransactionFail,
         PeersIds,
@@ -337,139 +671,603 @@ mod tests {
     }
 
     #[test]
-    async fn asset_definition_metadata() -> Result<()> {
+    async fn set_account_key_value_if() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let mut state_block = state.block();
         let mut state_transaction = state_block.transaction();
-        let definition_id = AssetDefinitionId::from_str("rose#wonderland")?;
         let account_id = ALICE_ID.clone();
-        SetKeyValue::asset_definition(
-            definition_id.clone(),
-            Name::from_str("Bytes")?,
-            vec![1_u32, 2_u32, 3_u32],
-        )
-        .execute(&account_id, &mut state_transaction)?;
-        let bytes = state_transaction
+        let key = Name::from_str("counter")?;
+        let set = |value: u32| SetKeyValue::account(account_id.clone(), key.clone(), value);
+
+        SetKeyValueIf::new(set(1), None).execute(&account_id, &mut state_transaction)?;
+        let stale = SetKeyValueIf::new(set(2), None).execute(&account_id, &mut state_transaction);
+        assert!(matches!(
+            stale,
+            Err(Error::Metadata(MetadataError::ValueMismatch(_)))
+        ));
+        SetKeyValueIf::new(set(2), Some(1_u32.into()))
+            .execute(&account_id, &mut state_transaction)?;
+
+        let counter = state_transaction
             .world
-            .asset_definition(&definition_id)?
-            .metadata()
-            .get(&Name::from_str("Bytes")?)
-            .cloned();
-        assert_eq!(
-            bytes,
-            Some(MetadataValueBox::Vec(vec![
-                1_u32.into(),
-                2_u32.into(),
-                3_u32.into(),
-            ]))
-        );
+            .map_account(&account_id, |account| account.metadata().get(&key).cloned())?;
+        assert_eq!(counter, Some(2_u32.into()));
         Ok(())
     }
 
     #[test]
-    async fn domain_metadata() -> Result<()> {
+    async fn increment_account_key_value() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let mut state_block = state.block();
         let mut state_transaction = state_block.transaction();
-        let domain_id = DomainId::from_str("wonderland")?;
         let account_id = ALICE_ID.clone();
-        SetKeyValue::domain(
-            domain_id.clone(),
-            Name::from_str("Bytes")?,
-            vec![1_u32, 2_u32, 3_u32],
-        )
-        .execute(&account_id, &mut state_transaction)?;
-        let bytes = state_transaction
+        let key = Name::from_str("counter")?;
+        let increment = |delta: NumericDelta| {
+            IncrementKeyValue::account(account_id.clone(), key.clone(), delta)
+        };
+
+        let missing = increment(NumericDelta::Increase(1_u32.into()))
+            .execute(&account_id, &mut state_transaction);
+        assert!(matches!(
+            missing,
+            Err(Error::Find(FindError::MetadataKey(_)))
+        ));
+
+        SetKeyValue::account(account_id.clone(), key.clone(), 5_u32)
+            .execute(&account_id, &mut state_transaction)?;
+        increment(NumericDelta::Increase(3_u32.into()))
+            .execute(&account_id, &mut state_transaction)?;
+        increment(NumericDelta::Decrease(2_u32.into()))
+            .execute(&account_id, &mut state_transaction)?;
+        let negative = increment(NumericDelta::Decrease(7_u32.into()))
+            .execute(&account_id, &mut state_transaction);
+        assert!(matches!(
+            negative,
+            Err(Error::Math(MathError::NotEnoughQuantity))
+        ));
+
+        let counter = state_transaction
             .world
-            .domain(&domain_id)?
-            .metadata()
-            .get(&Name::from_str("Bytes")?)
-            .cloned();
-        assert_eq!(
-            bytes,
-            Some(MetadataValueBox::Vec(vec![
-                1_u32.into(),
-                2_u32.into(),
-                3_u32.into(),
-            ]))
-        );
+            .map_account(&account_id, |account| account.metadata().get(&key).cloned())?;
+        assert_eq!(counter, Some(6_u32.into()));
+
+        SetKeyValue::account(account_id.clone(), key.clone(), "six".to_owned())
+            .execute(&account_id, &mut state_transaction)?;
+        let not_numeric = increment(NumericDelta::Increase(1_u32.into()))
+            .execute(&account_id, &mut state_transaction);
+        assert!(matches!(
+            not_numeric,
+            Err(Error::Metadata(MetadataError::NotNumeric(_)))
+        ));
         Ok(())
     }
 
     #[test]
-    async fn executing_unregistered_trigger_should_return_error() -> Result<()> {
+    async fn mint_and_transfer_nft() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let mut state_block = state.block();
         let mut state_transaction = state_block.transaction();
-        let account_id = ALICE_ID.clone();
-        let trigger_id = TriggerId::from_str("test_trigger_id")?;
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let definition_id = AssetDefinitionId::from_str("kitty#wonderland")?;
+        let token_id = Name::from_str("kitty_1")?;
+        let alice_kitties = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+        let bob_kitties = AssetId::new(definition_id.clone(), bob_id.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::nft(definition_id.clone()))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+
+        Mint::nft(token_id.clone(), alice_kitties.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        let repeated = Mint::nft(token_id.clone(), bob_kitties.clone())
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(repeated, Err(Error::Repetition(_))));
+
+        Transfer::nft(alice_kitties.clone(), token_id.clone(), bob_id.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&alice_kitties).is_err());
+        let AssetValue::Store(tokens) = state_transaction.world.asset(&bob_kitties)?.value else {
+            panic!("expected store asset");
+        };
+        assert!(tokens.contains(&token_id));
+        assert_eq!(
+            state_transaction.world.asset_total_amount(&definition_id)?,
+            Numeric::ONE
+        );
 
+        let store_transfer = Transfer::asset_store(bob_kitties, ALICE_ID.clone())
+            .execute(&bob_id, &mut state_transaction);
         assert!(matches!(
-            ExecuteTrigger::new(trigger_id)
-                .execute(&account_id, &mut state_transaction)
-                .expect_err("Error expected"),
-            Error::Find(_)
+            store_transfer,
+            Err(Error::Evaluate(error::InstructionEvaluationError::Type(_)))
         ));
-
         Ok(())
     }
 
     #[test]
-    async fn unauthorized_trigger_execution_should_return_error() -> Result<()> {
+    async fn nft_owners_are_indexed() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let mut state_block = state.block();
         let mut state_transaction = state_block.transaction();
-        let account_id = ALICE_ID.clone();
-        let (fake_account_id, _fake_account_keypair) = gen_account_in("wonderland");
-        let trigger_id = TriggerId::from_str("test_trigger_id")?;
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let definition_id = AssetDefinitionId::from_str("kitty#wonderland")?;
+        let token_id = Name::from_str("kitty_1")?;
+        let alice_kitties = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+        let bob_kitties = AssetId::new(definition_id.clone(), bob_id.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::nft(definition_id.clone()))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        let owner = |state_transaction: &StateTransaction<'_, '_>| {
+            nft_owner(&state_transaction.world, &definition_id, &token_id).cloned()
+        };
 
-        // register fake account
-        let register_account = Register::account(Account::new(fake_account_id.clone()));
-        register_account.execute(&account_id, &mut state_transaction)?;
+        Mint::nft(token_id.clone(), alice_kitties.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert_eq!(owner(&state_transaction), Some(ALICE_ID.clone()));
+        Transfer::nft(alice_kitties.clone(), token_id.clone(), bob_id.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert_eq!(owner(&state_transaction), Some(bob_id.clone()));
 
-        // register the trigger
-        let register_trigger = Register::trigger(Trigger::new(
-            trigger_id.clone(),
-            Action::new(
-                Vec::<InstructionBox>::new(),
-                Repeats::Indefinitely,
-                account_id.clone(),
-                ExecuteTriggerEventFilter::new()
-                    .for_trigger(trigger_id.clone())
-                    .under_authority(account_id.clone()),
-            ),
-        ));
+        // Token can be minted again once its asset is unregistered
+        Unregister::asset(bob_kitties).execute(&ALICE_ID, &mut state_transaction)?;
+        assert_eq!(owner(&state_transaction), None);
+        Mint::nft(token_id.clone(), alice_kitties).execute(&ALICE_ID, &mut state_transaction)?;
+        assert_eq!(owner(&state_transaction), Some(ALICE_ID.clone()));
 
-        register_trigger.execute(&account_id, &mut state_transaction)?;
+        // Index is rebuilt from the assets when the world is loaded
+        state_transaction.apply();
+        state_block.commit();
+        let world = World::with(state.view().world.domains_iter().cloned(), PeersIds::new());
+        assert_eq!(
+            nft_owner(&world.view(), &definition_id, &token_id),
+            Some(&*ALICE_ID)
+        );
+        Ok(())
+    }
 
-        // execute with the valid account
-        ExecuteTrigger::new(trigger_id.clone()).execute(&account_id, &mut state_transaction)?;
+    #[test]
+    async fn swap_assets() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let gold_id = AssetDefinitionId::from_str("gold#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        let bob_gold = AssetId::new(gold_id.clone(), bob_id.clone());
+        for account_id in [&bob_id, &carol_id] {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        for definition_id in [&coin_id, &gold_id] {
+            Register::asset_definition(AssetDefinition::numeric(definition_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        Mint::asset_numeric(10_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(1_u32, bob_gold.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
 
-        // execute with the fake account
-        assert!(matches!(
-            ExecuteTrigger::new(trigger_id)
-                .execute(&fake_account_id, &mut state_transaction)
-                .expect_err("Error expected"),
-            Error::InvariantViolation(_)
-        ));
+        let mismatched = Swap::new(
+            Transfer::asset_numeric(alice_coin.clone(), 10_u32, bob_id.clone()),
+            Transfer::asset_numeric(bob_gold.clone(), 1_u32, carol_id.clone()),
+        )
+        .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(mismatched, Err(Error::InvariantViolation(_))));
 
+        let swap = Swap::new(
+            Transfer::asset_numeric(alice_coin, 10_u32, bob_id.clone()),
+            Transfer::asset_numeric(bob_gold, 1_u32, ALICE_ID.clone()),
+        );
+        let outsider = swap.clone().execute(&carol_id, &mut state_transaction);
+        assert!(matches!(outsider, Err(Error::InvariantViolation(_))));
+
+        swap.clone().execute(&ALICE_ID, &mut state_transaction)?;
+        let unmatched = state_transaction
+            .world
+            .asset(&AssetId::new(coin_id.clone(), bob_id.clone()));
+        assert!(unmatched.is_err());
+
+        swap.execute(&bob_id, &mut state_transaction)?;
+        assert_eq!(state_transaction.world.swap_offers.iter().count(), 0);
+
+        let bob_coin = state_transaction
+            .world
+            .asset(&AssetId::new(coin_id, bob_id))?;
+        assert_eq!(bob_coin.value, AssetValue::Numeric(10_u32.into()));
+        let alice_gold = state_transaction
+            .world
+            .asset(&AssetId::new(gold_id, ALICE_ID.clone()))?;
+        assert_eq!(alice_gold.value, AssetValue::Numeric(1_u32.into()));
         Ok(())
     }
 
     #[test]
-    async fn not_allowed_to_register_genesis_domain_or_account() -> Result<()> {
+    async fn frozen_asset_blocks_transfer_and_burn() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let mut state_block = state.block();
         let mut state_transaction = state_block.transaction();
-        let account_id = ALICE_ID.clone();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::numeric(coin_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        Freeze::asset(alice_coin.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        let transfer = Transfer::asset_numeric(alice_coin.clone(), 1_u32, bob_id.clone())
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(transfer, Err(Error::Frozen(IdBox::AssetId(_)))));
+        let burn = Burn::asset_numeric(1_u32, alice_coin.clone())
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(burn, Err(Error::Frozen(IdBox::AssetId(_)))));
+        Unfreeze::asset(alice_coin.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+
+        Freeze::asset_definition(coin_id.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        let transfer = Transfer::asset_numeric(alice_coin.clone(), 1_u32, bob_id.clone())
+            .execute(&ALICE_ID, &mut state_transaction);
         assert!(matches!(
-            Register::domain(Domain::new(DomainId::from_str("genesis")?))
-                .execute(&account_id, &mut state_transaction)
+            transfer,
+            Err(Error::Frozen(IdBox::AssetDefinitionId(_)))
+        ));
+        Unfreeze::asset_definition(coin_id).execute(&ALICE_ID, &mut state_transaction)?;
+
+        Transfer::asset_numeric(alice_coin, 1_u32, bob_id)
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn mint_respects_max_supply() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        Register::asset_definition(
+            AssetDefinition::numeric(coin_id.clone()).with_max_supply(10_u32.into()),
+        )
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        Mint::asset_numeric(10_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let exceeded = Mint::asset_numeric(1_u32, alice_coin)
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction);
+        assert!(matches!(
+            exceeded,
+            Err(Error::Mintability(MintabilityError::MaxSupplyExceeded))
+        ));
+        assert_eq!(
+            state_transaction.world.asset_total_amount(&coin_id)?,
+            Numeric::from(10_u32)
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn transfer_many() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        for account_id in [&bob_id, &carol_id] {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        Register::asset_definition(AssetDefinition::numeric(coin_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        TransferMany::new(
+            alice_coin.clone(),
+            [
+                (bob_id.clone(), Numeric::from(3_u32)),
+                (carol_id.clone(), Numeric::from(7_u32)),
+            ],
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+
+        assert!(state_transaction.world.asset(&alice_coin).is_err());
+        for (account_id, quantity) in [(bob_id, 3_u32), (carol_id, 7_u32)] {
+            let asset = state_transaction
+                .world
+                .asset(&AssetId::new(coin_id.clone(), account_id))?;
+            assert_eq!(asset.value, AssetValue::Numeric(quantity.into()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    async fn transfer_and_burn_all() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        let bob_coin = AssetId::new(coin_id.clone(), bob_id.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::numeric(coin_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(7_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(3_u32, bob_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        Transfer::asset_all(alice_coin.clone(), bob_id)
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&alice_coin).is_err());
+        assert_eq!(
+            state_transaction.world.asset(&bob_coin)?.value,
+            AssetValue::Numeric(10_u32.into())
+        );
+
+        Burn::asset_all(bob_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&bob_coin).is_err());
+        assert_eq!(
+            state_transaction.world.asset_total_amount(&coin_id)?,
+            Numeric::ZERO
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn escrow_release_and_refund() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        let bob_coin = AssetId::new(coin_id.clone(), bob_id.clone());
+        for account_id in [&bob_id, &carol_id] {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        Register::asset_definition(AssetDefinition::numeric(coin_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let signed_id = EscrowId::from_str("signed")?;
+        let flagged_id = EscrowId::from_str("flagged")?;
+        let signed = Escrow::new(
+            alice_coin.clone(),
+            4_u32,
+            bob_id.clone(),
+            EscrowCondition::CounterSignature(carol_id.clone()),
+        )
+        .with_refund_after(MetadataExpiry::BlockHeight(100));
+        let flagged = Escrow::new(
+            alice_coin.clone(),
+            6_u32,
+            bob_id.clone(),
+            EscrowCondition::AccountMetadata(AccountMetadataCondition::new(
+                carol_id.clone(),
+                "delivered".parse()?,
+                true.into(),
+            )),
+        );
+        OpenEscrow::new(signed_id.clone(), signed).execute(&ALICE_ID, &mut state_transaction)?;
+        OpenEscrow::new(flagged_id.clone(), flagged).execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&alice_coin).is_err());
+
+        for (escrow_id, authority) in [(&signed_id, &bob_id), (&flagged_id, &carol_id)] {
+            assert!(matches!(
+                ReleaseEscrow::new(escrow_id.clone()).execute(authority, &mut state_transaction),
+                Err(Error::InvariantViolation(_))
+            ));
+        }
+        assert!(matches!(
+            RefundEscrow::new(signed_id.clone()).execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+
+        ReleaseEscrow::new(signed_id.clone()).execute(&carol_id, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction.world.asset(&bob_coin)?.value,
+            AssetValue::Numeric(4_u32.into())
+        );
+        assert!(ReleaseEscrow::new(signed_id)
+            .execute(&carol_id, &mut state_transaction)
+            .is_err());
+
+        RefundEscrow::new(flagged_id).execute(&bob_id, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction.world.asset(&alice_coin)?.value,
+            AssetValue::Numeric(6_u32.into())
+        );
+        assert_eq!(state_transaction.world.escrows.iter().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    async fn numeric_asset_metadata() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        let batch = Name::from_str("batch")?;
+        Register::asset_definition(AssetDefinition::numeric(coin_id))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        let unheld = SetKeyValue::asset(alice_coin.clone(), batch.clone(), 42_u32)
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(unheld, Err(Error::Find(FindError::Asset(_)))));
+
+        Mint::asset_numeric(10_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        SetKeyValue::asset(alice_coin.clone(), batch.clone(), 42_u32)
+            .execute(&ALICE_ID, &mut state_transaction)?;
+
+        let asset = state_transaction.world.asset(&alice_coin)?;
+        assert_eq!(asset.value, AssetValue::Numeric(10_u32.into()));
+        assert_eq!(asset.metadata.get(&batch), Some(&42_u32.into()));
+
+        RemoveKeyValue::asset(alice_coin.clone(), batch.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        let asset = state_transaction.world.asset(&alice_coin)?;
+        assert!(asset.metadata.get(&batch).is_none());
+        Ok(())
+    }
+
+    #[test]
+    async fn asset_definition_metadata() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let definition_id = AssetDefinitionId::from_str("rose#wonderland")?;
+        let account_id = ALICE_ID.clone();
+        SetKeyValue::asset_definition(
+            definition_id.clone(),
+            Name::from_str("Bytes")?,
+            vec![1_u32, 2_u32, 3_u32],
+        )
+        .execute(&account_id, &mut state_transaction)?;
+        let bytes = state_transaction
+            .world
+            .asset_definition(&definition_id)?
+            .metadata()
+            .get(&Name::from_str("Bytes")?)
+            .cloned();
+        assert_eq!(
+            bytes,
+            Some(MetadataValueBox::Vec(vec![
+                1_u32.into(),
+                2_u32.into(),
+                3_u32.into(),
+            ]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn domain_metadata() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let domain_id = DomainId::from_str("wonderland")?;
+        let account_id = ALICE_ID.clone();
+        SetKeyValue::domain(
+            domain_id.clone(),
+            Name::from_str("Bytes")?,
+            vec![1_u32, 2_u32, 3_u32],
+        )
+        .execute(&account_id, &mut state_transaction)?;
+        let bytes = state_transaction
+            .world
+            .domain(&domain_id)?
+            .metadata()
+            .get(&Name::from_str("Bytes")?)
+            .cloned();
+        assert_eq!(
+            bytes,
+            Some(MetadataValueBox::Vec(vec![
+                1_u32.into(),
+                2_u32.into(),
+                3_u32.into(),
+            ]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn executing_unregistered_trigger_should_return_error() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let trigger_id = TriggerId::from_str("test_trigger_id")?;
+
+        assert!(matches!(
+            ExecuteTrigger::new(trigger_id)
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::Find(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    async fn unauthorized_trigger_execution_should_return_error() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let (fake_account_id, _fake_account_keypair) = gen_account_in("wonderland");
+        let trigger_id = TriggerId::from_str("test_trigger_id")?;
+
+        // register fake account
+        let register_account = Register::account(Account::new(fake_account_id.clone()));
+        register_account.execute(&account_id, &mut state_transaction)?;
+
+        // register the trigger
+        let register_trigger = Register::trigger(Trigger::new(
+            trigger_id.clone(),
+            Action::new(
+                Vec::<InstructionBox>::new(),
+                Repeats::Indefinitely,
+                account_id.clone(),
+                ExecuteTriggerEventFilter::new()
+                    .for_trigger(trigger_id.clone())
+                    .under_authority(account_id.clone()),
+            ),
+        ));
+
+        register_trigger.execute(&account_id, &mut state_transaction)?;
+
+        // execute with the valid account
+        ExecuteTrigger::new(trigger_id.clone()).execute(&account_id, &mut state_transaction)?;
+
+        // execute with the fake account
+        assert!(matches!(
+            ExecuteTrigger::new(trigger_id)
+                .execute(&fake_account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    async fn not_allowed_to_register_genesis_domain_or_account() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        assert!(matches!(
+            Register::domain(Domain::new(DomainId::from_str("genesis")?))
+                .execute(&account_id, &mut state_transaction)
                 .expect_err("Error expected"),
             Error::InvariantViolation(_)
         ));
@@ -483,6 +1281,686 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn signatories_and_threshold() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, bob_keypair) = gen_account_in("wonderland");
+        let two = NonZeroU16::new(2).expect("non-zero");
+
+        AddSignatory::new(ALICE_ID.clone(), bob_keypair.public_key().clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            AddSignatory::new(ALICE_ID.clone(), bob_id.signatory().clone())
+                .execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Repetition(_))
+        ));
+        SetSignatureThreshold::new(ALICE_ID.clone(), two)
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            SetSignatureThreshold::new(ALICE_ID.clone(), NonZeroU16::new(3).expect("non-zero"))
+                .execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+        assert!(matches!(
+            RemoveSignatory::new(ALICE_ID.clone(), bob_id.signatory().clone())
+                .execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+
+        let alice = state_transaction.world.account(&ALICE_ID)?;
+        let condition = alice
+            .signature_condition
+            .as_ref()
+            .expect("Signatory was added");
+        assert_eq!(*condition.threshold(), two);
+        assert!(alice.is_signed_by([ALICE_ID.signatory(), bob_id.signatory()]));
+        assert!(!alice.is_signed_by([ALICE_ID.signatory()]));
+        Ok(())
+    }
+
+    #[test]
+    async fn rotate_key() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (_, old_keypair) = gen_account_in("wonderland");
+        let (_, new_keypair) = gen_account_in("wonderland");
+
+        RotateKey::new(
+            ALICE_ID.clone(),
+            ALICE_ID.signatory().clone(),
+            new_keypair.public_key().clone(),
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            RotateKey::new(
+                ALICE_ID.clone(),
+                old_keypair.public_key().clone(),
+                new_keypair.public_key().clone(),
+            )
+            .execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Find(FindError::PublicKey(_)))
+        ));
+
+        let alice = state_transaction.world.account(&ALICE_ID)?;
+        assert!(alice.is_signed_by([new_keypair.public_key()]));
+        assert!(!alice.is_signed_by([ALICE_ID.signatory()]));
+        Ok(())
+    }
+
+    #[test]
+    async fn account_aliases() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let alias = AccountAlias::from_str("alice@wonderland")?;
+
+        RegisterAlias::new(alias.clone(), ALICE_ID.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            RegisterAlias::new(alias.clone(), ALICE_ID.clone())
+                .execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+        assert!(matches!(
+            RegisterAlias::new(
+                AccountAlias::from_str("alice@garden_of_live_flowers")?,
+                ALICE_ID.clone()
+            )
+            .execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+        let account = FindAccountByAlias::new(alias.clone()).execute(&state_transaction)?;
+        assert_eq!(account.id(), &*ALICE_ID);
+
+        UnregisterAlias::new(alias.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            UnregisterAlias::new(alias.clone()).execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Find(FindError::AccountAlias(_)))
+        ));
+        assert!(FindAccountByAlias::new(alias)
+            .execute(&state_transaction)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    async fn sub_domains() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let wonderland = DomainId::from_str("wonderland")?;
+        let garden = DomainId::from_str("garden.wonderland")?;
+        let limits = DomainMetadataLimits {
+            account: Some(MetadataLimits::new(4, 16)),
+            asset_definition: None,
+        };
+
+        assert!(matches!(
+            Register::domain(Domain::new(DomainId::from_str("garden.looking_glass")?))
+                .execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Find(FindError::Domain(_)))
+        ));
+        Register::domain(Domain::new(garden.clone())).execute(&ALICE_ID, &mut state_transaction)?;
+        SetDomainMetadataLimits::new(wonderland.clone(), limits)
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction.world.domain_metadata_limits(&garden)?,
+            limits
+        );
+
+        let descendants = FindDomainDescendants::new(wonderland.clone())
+            .execute(&state_transaction)?
+            .map(|domain| domain.id().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(descendants, vec![garden.clone()]);
+        assert!(matches!(
+            Unregister::domain(wonderland.clone()).execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+        Unregister::domain(garden).execute(&ALICE_ID, &mut state_transaction)?;
+        Unregister::domain(wonderland).execute(&ALICE_ID, &mut state_transaction)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn recover_account_by_guardians() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let (_, new_keypair) = gen_account_in("wonderland");
+        for account_id in [&bob_id, &carol_id] {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        let two = NonZeroU16::new(2).expect("non-zero");
+        let recovered = SignatureCondition::new(
+            [new_keypair.public_key().clone()],
+            NonZeroU16::new(1).expect("non-zero"),
+        );
+        let recover = RecoverAccount::new(ALICE_ID.clone(), recovered.clone());
+        let finalize = FinalizeRecovery::new(ALICE_ID.clone(), recovered.clone());
+
+        assert!(recover
+            .clone()
+            .execute(&bob_id, &mut state_transaction)
+            .is_err());
+        SetRecoveryPolicy::new(
+            ALICE_ID.clone(),
+            RecoveryPolicy::new([bob_id.clone(), carol_id.clone()], two, 1000),
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+        for guardian in [&bob_id, &carol_id] {
+            recover.clone().execute(guardian, &mut state_transaction)?;
+        }
+        let alice = state_transaction.world.account(&ALICE_ID)?;
+        assert!(alice.signature_condition.is_none());
+        assert!(matches!(
+            alice.pending_recoveries.as_slice(),
+            [pending] if pending.quorum_reached_ms.is_some()
+        ));
+        // Delay hasn't passed yet
+        assert!(matches!(
+            finalize.clone().execute(&bob_id, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+        CancelRecovery::new(ALICE_ID.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+
+        SetRecoveryPolicy::new(
+            ALICE_ID.clone(),
+            RecoveryPolicy::new([bob_id.clone(), carol_id.clone()], two, 0),
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            recover.clone().execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+        recover.clone().execute(&bob_id, &mut state_transaction)?;
+        // Quorum isn't reached yet
+        assert!(matches!(
+            finalize.clone().execute(&bob_id, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+        recover.execute(&carol_id, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .account(&ALICE_ID)?
+            .signature_condition
+            .is_none());
+        finalize.execute(&bob_id, &mut state_transaction)?;
+
+        let alice = state_transaction.world.account(&ALICE_ID)?;
+        assert_eq!(alice.signature_condition.as_ref(), Some(&recovered));
+        assert!(alice.pending_recoveries.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    async fn guardian_cannot_reset_recovery_approved_by_others() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let (carol_id, _carol_keypair) = gen_account_in("wonderland");
+        let (mallory_id, mallory_keypair) = gen_account_in("wonderland");
+        let (_, new_keypair) = gen_account_in("wonderland");
+        for account_id in [&bob_id, &carol_id, &mallory_id] {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        let one = NonZeroU16::new(1).expect("non-zero");
+        let recovered = SignatureCondition::new([new_keypair.public_key().clone()], one);
+        let hijacked = SignatureCondition::new([mallory_keypair.public_key().clone()], one);
+        SetRecoveryPolicy::new(
+            ALICE_ID.clone(),
+            RecoveryPolicy::new(
+                [bob_id.clone(), carol_id.clone(), mallory_id.clone()],
+                NonZeroU16::new(2).expect("non-zero"),
+                0,
+            ),
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+
+        let recover = RecoverAccount::new(ALICE_ID.clone(), recovered.clone());
+        recover.clone().execute(&bob_id, &mut state_transaction)?;
+        // Proposing another condition doesn't discard the approval of bob
+        RecoverAccount::new(ALICE_ID.clone(), hijacked.clone())
+            .execute(&mallory_id, &mut state_transaction)?;
+        recover.execute(&carol_id, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction
+                .world
+                .account(&ALICE_ID)?
+                .pending_recoveries
+                .len(),
+            2
+        );
+        assert!(matches!(
+            FinalizeRecovery::new(ALICE_ID.clone(), hijacked)
+                .execute(&mallory_id, &mut state_transaction),
+            Err(Error::InvariantViolation(_))
+        ));
+        FinalizeRecovery::new(ALICE_ID.clone(), recovered.clone())
+            .execute(&mallory_id, &mut state_transaction)?;
+
+        let alice = state_transaction.world.account(&ALICE_ID)?;
+        assert_eq!(alice.signature_condition.as_ref(), Some(&recovered));
+        assert!(alice.pending_recoveries.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    async fn disabled_account_cannot_receive_transfers() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::numeric(coin_id))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        DisableAccount::new(bob_id.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(state_transaction.world.account(&bob_id)?.disabled);
+        assert!(matches!(
+            DisableAccount::new(bob_id.clone()).execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Repetition(_))
+        ));
+        let transfer = Transfer::asset_numeric(alice_coin.clone(), 1_u32, bob_id.clone())
+            .execute(&ALICE_ID, &mut state_transaction);
+        assert!(matches!(transfer, Err(Error::AccountDisabled(_))));
+
+        EnableAccount::new(bob_id.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            EnableAccount::new(bob_id.clone()).execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Repetition(_))
+        ));
+        Transfer::asset_numeric(alice_coin, 1_u32, bob_id)
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        Ok(())
+    }
+
+    #[test]
+    async fn strict_ordering_requires_next_sequence() -> Result<()> {
+        let chain_id = ChainId::from("0");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        SetStrictOrdering::new(ALICE_ID.clone(), true)
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        state_transaction.apply();
+
+        let tx_limits = state_block.transaction_executor().transaction_limits;
+        let tx_with_sequence = |sequence: Option<NonZeroU64>| {
+            let mut builder =
+                TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone()).with_instructions([
+                    Log::new(iroha_data_model::Level::INFO, "ordered".to_owned()),
+                ]);
+            if let Some(sequence) = sequence {
+                builder.set_sequence(sequence);
+            }
+            AcceptedTransaction::accept(builder.sign(&ALICE_KEYPAIR), &chain_id, &tx_limits)
+        };
+
+        for sequence in [None, NonZeroU64::new(2)] {
+            let tx = tx_with_sequence(sequence)?;
+            assert!(matches!(
+                state_block
+                    .transaction_executor()
+                    .validate(tx, &mut state_block),
+                Err((_, TransactionRejectionReason::SequenceMismatch(0)))
+            ));
+        }
+        for sequence in [1, 2] {
+            let tx = tx_with_sequence(NonZeroU64::new(sequence))?;
+            assert!(state_block
+                .transaction_executor()
+                .validate(tx, &mut state_block)
+                .is_ok());
+        }
+        assert_eq!(state_block.world.account(&ALICE_ID)?.sequence, 2);
+        Ok(())
+    }
+
+    #[test]
+    async fn transaction_fees_are_charged() -> Result<()> {
+        let chain_id = ChainId::from("0");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, _bob_keypair) = gen_account_in("wonderland");
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        let bob_coin = AssetId::new(coin_id.clone(), bob_id.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::numeric(coin_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(1_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let fees = TransactionFees::new(coin_id, bob_id, 1_u32, 0_u32);
+        NewParameter::new(Parameter::new(
+            ParameterId::from_str(TRANSACTION_FEES)?,
+            fees.into(),
+        ))
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        state_transaction.apply();
+
+        let tx_limits = state_block.transaction_executor().transaction_limits;
+        let tx = || {
+            let tx = TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone())
+                .with_instructions([Log::new(iroha_data_model::Level::INFO, "paid".to_owned())])
+                .sign(&ALICE_KEYPAIR);
+            AcceptedTransaction::accept(tx, &chain_id, &tx_limits)
+        };
+
+        assert!(state_block
+            .transaction_executor()
+            .validate(tx()?, &mut state_block)
+            .is_ok());
+        assert!(state_block.world.asset(&alice_coin).is_err());
+        assert_eq!(
+            state_block.world.asset(&bob_coin)?.value,
+            AssetValue::Numeric(Numeric::ONE)
+        );
+        assert!(matches!(
+            state_block
+                .transaction_executor()
+                .validate(tx()?, &mut state_block),
+            Err((_, TransactionRejectionReason::FeeCharge(_)))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    async fn unauthorized_transactions_are_not_charged() -> Result<()> {
+        let chain_id = ChainId::from("0");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, bob_keypair) = gen_account_in("wonderland");
+        let coin_id = AssetDefinitionId::from_str("coin#wonderland")?;
+        let alice_coin = AssetId::new(coin_id.clone(), ALICE_ID.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::numeric(coin_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(100_u32, alice_coin.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let fees = TransactionFees::new(coin_id, bob_id, 1_u32, 1_u32);
+        NewParameter::new(Parameter::new(
+            ParameterId::from_str(TRANSACTION_FEES)?,
+            fees.clone().into(),
+        ))
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        state_transaction.apply();
+
+        let tx = TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone())
+            .with_instructions([Log::new(iroha_data_model::Level::INFO, "paid".to_owned())])
+            .sign(&ALICE_KEYPAIR);
+        // Signatures don't change the fee
+        assert_eq!(
+            fees.fee_of(&tx),
+            fees.fee_of(&tx.clone().cosign(&bob_keypair))
+        );
+
+        let tx_limits = state_block.transaction_executor().transaction_limits;
+        for tx in [tx.clone().sign(&bob_keypair), tx.cosign(&bob_keypair)] {
+            let tx = AcceptedTransaction::accept(tx, &chain_id, &tx_limits)?;
+            assert!(matches!(
+                state_block
+                    .transaction_executor()
+                    .validate(tx, &mut state_block),
+                Err((_, TransactionRejectionReason::SignatureConditionUnsatisfied))
+            ));
+        }
+        assert_eq!(
+            state_block.world.asset(&alice_coin)?.value,
+            AssetValue::Numeric(100_u32.into())
+        );
+        Ok(())
+    }
+
+    #[test]
+    async fn validation_trigger_vetoes_transaction() -> Result<()> {
+        let chain_id = ChainId::from("0");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let (bob_id, bob_keypair) = gen_account_in("wonderland");
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let trigger_id = TriggerId::from_str("forbid_bob$wonderland")?;
+        Register::trigger(Trigger::new(
+            trigger_id.clone(),
+            Action::new(
+                [Fail::new("Bob may not submit transactions".to_owned())],
+                Repeats::Indefinitely,
+                ALICE_ID.clone(),
+                TransactionValidationEventFilter::new().under_authority(bob_id.clone()),
+            ),
+        ))
+        .execute(&ALICE_ID, &mut state_transaction)?;
+        state_transaction.apply();
+
+        let tx_limits = state_block.transaction_executor().transaction_limits;
+        let tx = |authority: &AccountId, keypair: &KeyPair| {
+            let tx = TransactionBuilder::new(chain_id.clone(), authority.clone())
+                .with_instructions([Log::new(iroha_data_model::Level::INFO, "vetoed".to_owned())])
+                .sign(keypair);
+            AcceptedTransaction::accept(tx, &chain_id, &tx_limits)
+        };
+
+        assert!(state_block
+            .transaction_executor()
+            .validate(tx(&ALICE_ID, &ALICE_KEYPAIR)?, &mut state_block)
+            .is_ok());
+        assert!(matches!(
+            state_block
+                .transaction_executor()
+                .validate(tx(&bob_id, &bob_keypair)?, &mut state_block),
+            Err((_, TransactionRejectionReason::ValidationTrigger(fail)))
+                if fail.trigger_id == trigger_id
+        ));
+        Ok(())
+    }
+
+    #[test]
+    async fn paused_trigger_is_not_executed() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let trigger_id = TriggerId::from_str("call_me$wonderland")?;
+        let action = Action::new(
+            [Log::new(iroha_data_model::Level::INFO, "called".to_owned())],
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            ExecuteTriggerEventFilter::new().for_trigger(trigger_id.clone()),
+        );
+        let payload_hash = action.payload_hash();
+        Register::trigger(Trigger::new(trigger_id.clone(), action))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+
+        Pause::new(trigger_id.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            Pause::new(trigger_id.clone()).execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Repetition(_))
+        ));
+        assert!(ExecuteTrigger::new(trigger_id.clone())
+            .execute(&ALICE_ID, &mut state_transaction)
+            .is_err());
+        let trigger = FindTriggerById::new(trigger_id.clone()).execute(&state_transaction)?;
+        assert!(trigger.action().is_paused());
+        assert_eq!(trigger.action().payload_hash(), payload_hash);
+        assert_eq!(
+            FindAllActiveTriggers.execute(&state_transaction)?.count(),
+            0
+        );
+
+        Resume::new(trigger_id.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        ExecuteTrigger::new(trigger_id.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        let active: Vec<_> = FindAllActiveTriggers.execute(&state_transaction)?.collect();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id(), &trigger_id);
+        Ok(())
+    }
+
+    #[test]
+    async fn upgraded_trigger_keeps_its_action() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let trigger_id = TriggerId::from_str("call_me$wonderland")?;
+        let action = Action::new(
+            [Log::new(iroha_data_model::Level::INFO, "old".to_owned())],
+            Repeats::Exactly(3),
+            ALICE_ID.clone(),
+            ExecuteTriggerEventFilter::new().for_trigger(trigger_id.clone()),
+        );
+        Register::trigger(Trigger::new(trigger_id.clone(), action))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+
+        let executable =
+            Executable::from([Log::new(iroha_data_model::Level::INFO, "new".to_owned())]);
+        UpgradeTrigger::new(trigger_id.clone(), executable.clone())
+            .execute(&ALICE_ID, &mut state_transaction)?;
+        let trigger = FindTriggerById::new(trigger_id.clone()).execute(&state_transaction)?;
+        assert_eq!(trigger.action().executable(), &executable);
+        assert_eq!(trigger.action().repeats(), &Repeats::Exactly(3));
+
+        assert!(matches!(
+            UpgradeTrigger::new(TriggerId::from_str("missing$wonderland")?, executable)
+                .execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Find(FindError::Trigger(_)))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    async fn triggers_refer_to_registered_wasm_code() -> Result<()> {
+        let chain_id = ChainId::from("0");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        let code = WasmSmartContract::from_compiled(b"(module)".to_vec());
+        let trigger_id = TriggerId::from_str("call_me$wonderland")?;
+        let trigger = Trigger::new(
+            trigger_id.clone(),
+            Action::new(
+                Executable::WasmCode(HashOf::new(&code)),
+                Repeats::Indefinitely,
+                ALICE_ID.clone(),
+                ExecuteTriggerEventFilter::new().for_trigger(trigger_id.clone()),
+            ),
+        );
+
+        assert!(matches!(
+            Register::trigger(trigger.clone()).execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvalidParameter(_))
+        ));
+        Register::wasm_code(code.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        assert!(matches!(
+            Register::wasm_code(code.clone()).execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::Repetition(_))
+        ));
+        let mut forged = Register::wasm_code(code.clone());
+        forged.object.code = WasmSmartContract::from_compiled(b"(module (memory 1))".to_vec());
+        assert!(matches!(
+            forged.execute(&ALICE_ID, &mut state_transaction),
+            Err(Error::InvalidParameter(_))
+        ));
+
+        Register::trigger(trigger.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        let found = FindTriggerById::new(trigger_id.clone()).execute(&state_transaction)?;
+        assert_eq!(found.action().executable(), &Executable::Wasm(code.clone()));
+
+        let new_code = WasmSmartContract::from_compiled(b"(module (memory 1))".to_vec());
+        Register::wasm_code(new_code.clone()).execute(&ALICE_ID, &mut state_transaction)?;
+        UpgradeTrigger::new(
+            trigger_id.clone(),
+            Executable::WasmCode(HashOf::new(&new_code)),
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+        let found = FindTriggerById::new(trigger_id.clone()).execute(&state_transaction)?;
+        assert_eq!(found.action().executable(), &Executable::Wasm(new_code));
+
+        // Registered code outlives the triggers using it
+        Unregister::trigger(trigger_id).execute(&ALICE_ID, &mut state_transaction)?;
+        Register::trigger(trigger).execute(&ALICE_ID, &mut state_transaction)?;
+
+        let tx = TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone())
+            .with_executable(Executable::WasmCode(HashOf::new(&code)))
+            .sign(&ALICE_KEYPAIR);
+        let tx_limits = state_transaction.transaction_executor().transaction_limits;
+        assert!(matches!(
+            AcceptedTransaction::accept(tx, &chain_id, &tx_limits),
+            Err(AcceptTransactionFail::TransactionLimit(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    async fn simulated_transaction_is_not_committed() -> Result<()> {
+        let chain_id = ChainId::from("0");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let key = Name::from_str("simulated")?;
+
+        {
+            let mut state_block = state.block();
+            let tx = TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone())
+                .with_instructions([SetKeyValue::account(
+                    ALICE_ID.clone(),
+                    key.clone(),
+                    "value".to_owned(),
+                )])
+                .sign(&ALICE_KEYPAIR);
+            let tx_limits = state_block.transaction_executor().transaction_limits;
+            let tx = AcceptedTransaction::accept(tx, &chain_id, &tx_limits)?;
+            let simulation = state_block
+                .transaction_executor()
+                .simulate(tx, &mut state_block);
+            assert!(simulation.error.is_none());
+            assert!(simulation.events.iter().any(|event| matches!(
+                event,
+                EventBox::Data(DataEvent::Domain(DomainEvent::Account(
+                    AccountEvent::MetadataInserted(_)
+                )))
+            )));
+        }
+
+        assert!(state
+            .view()
+            .world
+            .account(&ALICE_ID)?
+            .metadata
+            .get(&key)
+            .is_none());
+        Ok(())
+    }
+
     #[test]
     async fn transaction_signed_by_genesis_account_should_be_rejected() -> Result<()> {
         let chain_id = ChainId::from("0");
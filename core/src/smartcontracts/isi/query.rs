@@ -66,50 +66,44 @@ impl LazyQueryOutput<'_> {
                 let iter = iter.filter(move |v| filter.applies(v));
 
                 // sort & paginate
-                let output = match &sorting.sort_by_metadata_key {
-                    Some(key) => {
-                        // if sorting was requested, we need to retrieve all the results first
-                        let mut pairs: Vec<(Option<QueryOutputBox>, QueryOutputBox)> = iter
-                            .map(|value| {
-                                let key = match &value {
-                                    QueryOutputBox::Identifiable(IdentifiableBox::Asset(asset)) => {
-                                        match asset.value() {
-                                            AssetValue::Store(store) => {
-                                                store.get(key).cloned().map(Into::into)
-                                            }
-                                            _ => None,
-                                        }
-                                    }
-                                    QueryOutputBox::Identifiable(v) => {
-                                        TryInto::<&dyn HasMetadata>::try_into(v)
-                                            .ok()
-                                            .and_then(|has_metadata| {
-                                                has_metadata.metadata().get(key)
-                                            })
-                                            .cloned()
-                                            .map(Into::into)
-                                    }
-                                    _ => None,
-                                };
-                                (key, value)
-                            })
-                            .collect();
-                        pairs.sort_by(|(left_key, _), (right_key, _)| {
-                            match (left_key, right_key) {
-                                (Some(l), Some(r)) => l.cmp(r),
-                                (Some(_), None) => Ordering::Less,
-                                (None, Some(_)) => Ordering::Greater,
-                                (None, None) => Ordering::Equal,
+                let output = if sorting.is_requested() {
+                    // if sorting was requested, we need to retrieve all the results first
+                    let mut pairs: Vec<(Option<QueryOutputBox>, QueryOutputBox)> = iter
+                        .map(|value| {
+                            let key = sorting
+                                .sort_by_metadata_key
+                                .as_ref()
+                                .and_then(|key| value.metadata_value(key))
+                                .cloned()
+                                .map(Into::into);
+                            (key, value)
+                        })
+                        .collect();
+                    pairs.sort_by(|(left_key, left), (right_key, right)| {
+                        // items without the metadata key go last regardless of the order
+                        let by_key = match (left_key, right_key) {
+                            (Some(l), Some(r)) => sorting.order.apply(l.cmp(r)),
+                            (Some(_), None) => Ordering::Less,
+                            (None, Some(_)) => Ordering::Greater,
+                            (None, None) => Ordering::Equal,
+                        };
+                        // identifiable items are ordered by their ids
+                        by_key.then_with(|| {
+                            if sorting.sort_by_id {
+                                sorting.order.apply(left.cmp(right))
+                            } else {
+                                Ordering::Equal
                             }
-                        });
-                        pairs
-                            .into_iter()
-                            .map(|(_, val)| val)
-                            .paginate(pagination)
-                            .collect::<Vec<_>>()
-                    }
+                        })
+                    });
+                    pairs
+                        .into_iter()
+                        .map(|(_, val)| val)
+                        .paginate(pagination)
+                        .collect::<Vec<_>>()
+                } else {
                     // no sorting, can just paginate the results without constructing the full output vec
-                    None => iter.paginate(pagination).collect::<Vec<_>>(),
+                    iter.paginate(pagination).collect::<Vec<_>>()
                 };
 
                 let fetch_size = fetch_size
@@ -199,7 +193,12 @@ impl ValidQueryRequest {
         Ok(Self(query))
     }
 
-    /// Execute contained query on the [`StateSnapshot`].
+    /// Account which submitted the query.
+    pub fn authority(&self) -> &AccountId {
+        self.0.authority()
+    }
+
+    /// Execute contained query on the [`StateSnapshot`] and postprocess its output.
     ///
     /// # Errors
     /// Forwards `self.query.execute` error.
@@ -207,9 +206,30 @@ impl ValidQueryRequest {
         &'state self,
         state_ro: &'state impl StateReadOnly,
     ) -> Result<ProcessedQueryOutput, Error> {
+        self.process(self.execute(state_ro)?)
+    }
+
+    /// Execute contained query on the [`StateSnapshot`].
+    ///
+    /// Iterable output is evaluated lazily, only once it is processed.
+    ///
+    /// # Errors
+    /// Forwards `self.query.execute` error.
+    pub fn execute<'state>(
+        &'state self,
+        state_ro: &'state impl StateReadOnly,
+    ) -> Result<LazyQueryOutput<'state>, Error> {
+        self.0.query().execute(state_ro)
+    }
+
+    /// Apply filtering, sorting, pagination and batching requested along with the query.
+    ///
+    /// # Errors
+    /// Fails if the requested parameters are not applicable to the output
+    pub fn process(&self, output: LazyQueryOutput<'_>) -> Result<ProcessedQueryOutput, Error> {
         let query = &self.0;
 
-        query.query().execute(state_ro)?.apply_postprocessing(
+        output.apply_postprocessing(
             query.filter(),
             query.sorting(),
             query.pagination(),
@@ -245,26 +265,37 @@ impl ValidQuery for QueryBox {
         match_all! {
             non_iter: {
                 FindAccountById,
+                FindAccountByAlias,
                 FindAssetById,
                 FindAssetDefinitionById,
                 FindAssetQuantityById,
                 FindTotalAssetQuantityByAssetDefinitionId,
+                FindAssetCountByAssetDefinitionId,
+                FindAccountCountByDomainId,
                 FindDomainById,
                 FindBlockHeaderByHash,
+                FindBlockByHash,
+                FindBlockByHeight,
                 FindTransactionByHash,
+                FindTransactionProofByHash,
                 FindTriggerById,
                 FindRoleByRoleId,
                 FindDomainKeyValueByIdAndKey,
                 FindAssetKeyValueByIdAndKey,
+                FindAssetKeyValueByIdAndPath,
                 FindAccountKeyValueByIdAndKey,
+                FindAccountKeyValueByIdAndPath,
+                FindMetadataHistory,
                 FindAssetDefinitionKeyValueByIdAndKey,
                 FindTriggerKeyValueByIdAndKey,
                 FindExecutorDataModel,
+                FindPermissionGrantTerms,
             }
 
             FindAllAccounts,
             FindAccountsByDomainId,
             FindAccountsWithAsset,
+            FindAccountsByMetadataKeyPrefix,
             FindAllAssets,
             FindAllAssetsDefinitions,
             FindAssetsByName,
@@ -272,14 +303,20 @@ impl ValidQuery for QueryBox {
             FindAssetsByAssetDefinitionId,
             FindAssetsByDomainId,
             FindAssetsByDomainIdAndAssetDefinitionId,
+            FindAssetsByMetadataKeyPrefix,
+            FindNftsByAccountId,
             FindAllDomains,
+            FindDomainDescendants,
             FindAllPeers,
             FindAllBlocks,
             FindAllBlockHeaders,
+            FindBlocksInRange,
             FindAllTransactions,
             FindTransactionsByAccountId,
             FindPermissionsByAccountId,
+            FindGrantHistoryByAccountId,
             FindAllActiveTriggerIds,
+            FindAllActiveTriggers,
             FindTriggersByDomainId,
             FindAllRoles,
             FindAllRoleIds,
@@ -291,7 +328,7 @@ impl ValidQuery for QueryBox {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr as _;
+    use std::{num::NonZeroU64, str::FromStr as _};
 
     use iroha_crypto::{Hash, HashOf};
     use iroha_data_model::{
@@ -460,6 +497,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn aggregates() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_asset_with_metadata(), kura, query_handle);
+
+        let accounts = FindAccountCountByDomainId::new(DomainId::from_str("wonderland")?)
+            .execute(&state.view())?;
+        assert_eq!(accounts, Numeric::from(1_u32));
+        let assets =
+            FindAssetCountByAssetDefinitionId::new(AssetDefinitionId::from_str("rose#wonderland")?)
+                .execute(&state.view())?;
+        assert_eq!(assets, Numeric::from(1_u32));
+        Ok(())
+    }
+
     #[test]
     async fn account_metadata() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
@@ -475,6 +528,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn account_metadata_by_path() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account_with_metadata()?, kura, query_handle);
+
+        let bytes_key = Name::from_str("Bytes")?;
+        let bytes = FindAccountKeyValueByIdAndPath::new(ALICE_ID.clone(), vec![bytes_key.clone()])
+            .execute(&state.view())?;
+        assert_eq!(
+            MetadataValueBox::Vec(vec![1_u32.into(), 2_u32.into(), 3_u32.into()]),
+            bytes,
+        );
+
+        let whole = FindAccountKeyValueByIdAndPath::new(ALICE_ID.clone(), Vec::new())
+            .execute(&state.view())?;
+        assert!(matches!(whole, MetadataValueBox::LimitedMetadata(_)));
+
+        // `Bytes` is not a nested metadata layer
+        let not_found = FindAccountKeyValueByIdAndPath::new(
+            ALICE_ID.clone(),
+            vec![bytes_key.clone(), Name::from_str("Inner")?],
+        )
+        .execute(&state.view());
+        assert_eq!(not_found, Err(FindError::MetadataKey(bytes_key).into()));
+        Ok(())
+    }
+
+    #[test]
+    async fn accounts_by_metadata_key_prefix() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account_with_metadata()?, kura, query_handle);
+
+        let found = FindAccountsByMetadataKeyPrefix::new("By".to_owned())
+            .execute(&state.view())?
+            .map(|account| account.id().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec![ALICE_ID.clone()]);
+
+        let missing = FindAccountsByMetadataKeyPrefix::new("bytes".to_owned())
+            .execute(&state.view())?
+            .count();
+        assert_eq!(missing, 0);
+        Ok(())
+    }
+
     #[test]
     async fn find_all_blocks() -> Result<()> {
         let num_blocks = 100;
@@ -523,6 +623,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn find_blocks_by_hash_height_and_range() -> Result<()> {
+        let num_blocks = 10;
+
+        let state = state_with_test_blocks_and_transactions(num_blocks, 1, 1)?;
+        let state_view = state.view();
+        let block = state_view.all_blocks().nth(4).expect("state has 10 blocks");
+        let height = NonZeroU64::new(5).expect("Valid");
+
+        assert_eq!(
+            FindBlockByHash::new(block.hash()).execute(&state_view)?,
+            *block
+        );
+        assert_eq!(FindBlockByHeight::new(height).execute(&state_view)?, *block);
+        assert!(
+            FindBlockByHeight::new(NonZeroU64::new(num_blocks + 1).expect("Valid"))
+                .execute(&state_view)
+                .is_err()
+        );
+
+        let heights = FindBlocksInRange::new(height, NonZeroU64::new(100).expect("Valid"))
+            .execute(&state_view)?
+            .map(|block| block.header().height().get())
+            .collect::<Vec<_>>();
+        assert_eq!(heights, (5..=num_blocks).collect::<Vec<_>>());
+
+        Ok(())
+    }
+
+    #[test]
+    async fn find_transaction_proof_by_hash() -> Result<()> {
+        let state = state_with_test_blocks_and_transactions(2, 3, 2)?;
+        let state_view = state.view();
+        let block = state_view.all_blocks().last().expect("state is empty");
+
+        for tx in block.transactions() {
+            let hash = tx.value.hash();
+            let proof = FindTransactionProofByHash::new(hash).execute(&state_view)?;
+            assert_eq!(proof.block_header(), block.header());
+            assert!(proof.verify(hash));
+            assert!(!proof.verify(HashOf::from_untyped_unchecked(Hash::new([42]))));
+        }
+
+        Ok(())
+    }
+
     #[test]
     async fn find_all_transactions() -> Result<()> {
         let num_blocks = 100;
@@ -637,4 +783,54 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    async fn sort_by_metadata_key_then_by_id_descending() -> Result<()> {
+        let key = Name::from_str("rank")?;
+        let domains = [
+            ("a", Some(1_u32)),
+            ("b", Some(2)),
+            ("c", Some(1)),
+            ("d", None),
+        ]
+        .into_iter()
+        .map(|(name, rank)| -> Result<QueryOutputBox> {
+            let mut metadata = Metadata::new();
+            if let Some(rank) = rank {
+                metadata.insert_with_limits(
+                    key.clone(),
+                    rank.into(),
+                    MetadataLimits::new(10, 100),
+                )?;
+            }
+            let domain = Domain::new(DomainId::from_str(name)?)
+                .with_metadata(metadata)
+                .build(&ALICE_ID);
+            Ok(QueryOutputBox::Identifiable(domain.into()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+        let output = LazyQueryOutput::Iter(Box::new(domains.into_iter())).apply_postprocessing(
+            &PredicateBox::default(),
+            &Sorting::by_metadata_key(key).then_by_id().descending(),
+            Pagination::default(),
+            FetchSize::default(),
+        )?;
+        let ProcessedQueryOutput::Iter(mut batches) = output else {
+            panic!("Expected iterable output");
+        };
+        let (sorted, _) = batches.next_batch(Some(0))?;
+        let sorted_ids = sorted
+            .into_iter()
+            .map(|value| match value {
+                QueryOutputBox::Identifiable(IdentifiableBox::Domain(domain)) => {
+                    domain.id().to_string()
+                }
+                _ => panic!("Expected domain"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(sorted_ids, ["b", "c", "a", "d"]);
+
+        Ok(())
+    }
 }
@@ -15,6 +15,7 @@ pub mod specialized;
 pub mod isi {
     use std::time::Duration;
 
+    use iroha_crypto::HashOf;
     use iroha_data_model::{
         events::EventFilter,
         isi::error::{InvalidParameterError, RepetitionError},
@@ -63,7 +64,15 @@ pub mod isi {
                         .map_err(|e: &str| Error::Conversion(e.to_owned()))?,
                 ),
                 TriggeringEventFilterBox::Time(time_filter) => {
-                    if let ExecutionTime::Schedule(schedule) = time_filter.0 {
+                    let earliest_start = match &time_filter.0 {
+                        ExecutionTime::PreCommit => None,
+                        ExecutionTime::Schedule(schedule) => Some(schedule.start),
+                        // Without timestamps the trigger would never be executed
+                        ExecutionTime::Timestamps(timestamps) => {
+                            Some(timestamps.iter().min().copied().unwrap_or_default())
+                        }
+                    };
+                    if let Some(earliest_start) = earliest_start {
                         match last_block_estimation {
                             // We're in genesis
                             None => {
@@ -72,7 +81,7 @@ pub mod isi {
                                 ));
                             }
                             Some(latest_block_estimation)
-                                if schedule.start < latest_block_estimation =>
+                                if earliest_start < latest_block_estimation =>
                             {
                                 return Err(Error::InvalidParameter(
                                     InvalidParameterError::TimeTriggerInThePast,
@@ -94,6 +103,13 @@ pub mod isi {
                         .try_into()
                         .map_err(|e: &str| Error::Conversion(e.to_owned()))?,
                 ),
+                TriggeringEventFilterBox::TransactionValidation(_) => triggers
+                    .add_transaction_validation_trigger(
+                        &engine,
+                        new_trigger
+                            .try_into()
+                            .map_err(|e: &str| Error::Conversion(e.to_owned()))?,
+                    ),
             }
             .map_err(|e| InvalidParameterError::Wasm(e.to_string()))?;
 
@@ -230,7 +246,7 @@ pub mod isi {
                 .world
                 .emit_events(Some(TriggerEvent::MetadataInserted(MetadataChanged {
                     target_id: trigger_id,
-                    key: self.key,
+                    path: vec![self.key],
                     value: self.value,
                 })));
 
@@ -262,7 +278,7 @@ pub mod isi {
                 .world
                 .emit_events(Some(TriggerEvent::MetadataRemoved(MetadataChanged {
                     target_id: trigger_id,
-                    key: self.key,
+                    path: vec![self.key],
                     value,
                 })));
 
@@ -270,6 +286,43 @@ pub mod isi {
         }
     }
 
+    impl Execute for MergeKeyValues<Trigger> {
+        #[metrics(+"merge_trigger_key_values")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let trigger_id = self.object_id;
+
+            let trigger_metadata_limits = state_transaction.config.trigger_metadata_limits;
+            let written = state_transaction
+                .world
+                .triggers
+                .inspect_by_id_mut(&trigger_id, |action| {
+                    merge_metadata(
+                        action.metadata_mut(),
+                        self.metadata.clone(),
+                        self.policy,
+                        trigger_metadata_limits,
+                    )
+                })
+                .ok_or(FindError::Trigger(trigger_id.clone()))??;
+
+            state_transaction
+                .world
+                .emit_events(written.into_iter().map(|(path, value)| {
+                    TriggerEvent::MetadataInserted(MetadataChanged {
+                        target_id: trigger_id.clone(),
+                        path,
+                        value,
+                    })
+                }));
+
+            Ok(())
+        }
+    }
+
     impl Execute for ExecuteTrigger {
         #[metrics(+"execute_trigger")]
         fn execute(
@@ -283,6 +336,11 @@ pub mod isi {
                 .world
                 .triggers
                 .inspect_by_id(id, |action| -> Result<(), Error> {
+                    if action.is_paused() {
+                        return Err(Error::InvariantViolation(String::from(
+                            "Paused trigger can't be executed",
+                        )));
+                    }
                     let allow_execute = if let TriggeringEventFilterBox::ExecuteTrigger(filter) =
                         action.clone_and_box().filter
                     {
@@ -315,6 +373,123 @@ pub mod isi {
             Ok(())
         }
     }
+
+    impl Execute for Pause {
+        #[metrics(+"pause_trigger")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            set_paused(self.trigger_id, true, state_transaction)
+        }
+    }
+
+    impl Execute for Resume {
+        #[metrics(+"resume_trigger")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            set_paused(self.trigger_id, false, state_transaction)
+        }
+    }
+
+    impl Execute for UpgradeTrigger {
+        #[metrics(+"upgrade_trigger")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let trigger_id = self.trigger_id;
+
+            let engine = state_transaction.engine.clone(); // Cloning engine is cheap
+            let found = state_transaction
+                .world
+                .triggers
+                .upgrade(&engine, &trigger_id, self.executable)
+                .map_err(|e| InvalidParameterError::Wasm(e.to_string()))?;
+            if !found {
+                return Err(Error::Find(FindError::Trigger(trigger_id)));
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(TriggerEvent::Upgraded(trigger_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Register<WasmCode> {
+        #[metrics(+"register_wasm_code")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let WasmCode { id, code } = self.object;
+            if HashOf::new(&code) != id {
+                return Err(InvalidParameterError::Wasm(format!(
+                    "Code doesn't match its hash `{id}`"
+                ))
+                .into());
+            }
+
+            let engine = state_transaction.engine.clone(); // Cloning engine is cheap
+            let success = state_transaction
+                .world
+                .triggers
+                .register_code(&engine, code)
+                .map_err(|e| InvalidParameterError::Wasm(e.to_string()))?;
+            if !success {
+                return Err(RepetitionError {
+                    instruction_type: InstructionType::Register,
+                    id: IdBox::WasmCodeId(id),
+                }
+                .into());
+            }
+
+            Ok(())
+        }
+    }
+
+    fn set_paused(
+        trigger_id: TriggerId,
+        paused: bool,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let changed = state_transaction
+            .world
+            .triggers
+            .inspect_by_id_mut(&trigger_id, |action| {
+                let changed = action.is_paused() != paused;
+                action.set_paused(paused);
+                changed
+            })
+            .ok_or_else(|| Error::Find(FindError::Trigger(trigger_id.clone())))?;
+        if !changed {
+            return Err(RepetitionError {
+                instruction_type: if paused {
+                    InstructionType::Pause
+                } else {
+                    InstructionType::Resume
+                },
+                id: trigger_id.into(),
+            }
+            .into());
+        }
+
+        state_transaction.world.emit_events(Some(if paused {
+            TriggerEvent::Paused(trigger_id)
+        } else {
+            TriggerEvent::Resumed(trigger_id)
+        }));
+
+        Ok(())
+    }
 }
 
 pub mod query {
@@ -329,15 +504,45 @@ pub mod query {
     use crate::{prelude::*, smartcontracts::triggers::set::SetReadOnly, state::StateReadOnly};
 
     impl ValidQuery for FindAllActiveTriggerIds {
-        #[metrics(+"find_all_active_triggers")]
+        #[metrics(+"find_all_active_trigger_ids")]
         fn execute<'state>(
             &self,
             state_ro: &'state impl StateReadOnly,
         ) -> Result<Box<dyn Iterator<Item = TriggerId> + 'state>, Error> {
-            Ok(Box::new(state_ro.world().triggers().ids_iter().cloned()))
+            Ok(Box::new(active_trigger_ids(state_ro).cloned()))
+        }
+    }
+
+    impl ValidQuery for FindAllActiveTriggers {
+        #[metrics(+"find_all_active_triggers")]
+        fn execute<'state>(
+            &self,
+            state_ro: &'state impl StateReadOnly,
+        ) -> Result<Box<dyn Iterator<Item = Trigger> + 'state>, Error> {
+            let triggers = state_ro.world().triggers();
+
+            Ok(Box::new(active_trigger_ids(state_ro).map(move |id| {
+                #[allow(clippy::redundant_closure_for_method_calls)]
+                let action = triggers
+                    .inspect_by_id(id, |action| action.clone_and_box())
+                    .expect("Trigger is taken from the set of ids");
+                Trigger::new(id.clone(), triggers.get_original_action(action).into())
+            })))
         }
     }
 
+    /// Ids of the triggers which aren't paused
+    fn active_trigger_ids<'state>(
+        state_ro: &'state impl StateReadOnly,
+    ) -> impl Iterator<Item = &'state TriggerId> + 'state {
+        let triggers = state_ro.world().triggers();
+        triggers.ids_iter().filter(move |id| {
+            triggers
+                .inspect_by_id(id, |action| !action.is_paused())
+                .unwrap_or(false)
+        })
+    }
+
     impl ValidQuery for FindTriggerById {
         #[metrics(+"find_trigger_by_id")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Trigger, Error> {
@@ -47,6 +47,8 @@ use crate::{
 pub enum Error {
     /// Failed to preload wasm trigger
     Preload(#[from] wasm::error::Error),
+    /// No WASM code is registered under `{0}`
+    UnknownCode(HashOf<WasmSmartContract>),
 }
 
 /// Result type for [`Set`] operations.
@@ -75,6 +77,8 @@ pub struct Set {
     time_triggers: Storage<TriggerId, LoadedAction<TimeEventFilter>>,
     /// Triggers using [`ExecuteTriggerEventFilter`]
     by_call_triggers: Storage<TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+    /// Triggers using [`TransactionValidationEventFilter`]
+    validation_triggers: Storage<TriggerId, LoadedAction<TransactionValidationEventFilter>>,
     /// Trigger ids with type of events they process
     ids: Storage<TriggerId, TriggeringEventType>,
     /// [`WasmSmartContract`]s map by wasm blob hash.
@@ -82,6 +86,7 @@ pub struct Set {
     /// 1. Querying original wasm blob of trigger
     /// 2. Getting compiled by wasmtime module for execution
     /// 3. Deduplicating triggers with the same wasm blob
+    /// 4. Storing code registered with [`Register<WasmCode>`] for triggers to refer to by hash
     contracts: WasmSmartContractMap,
     /// List of actions that should be triggered by events provided by `handle_*` methods.
     /// Vector is used to save the exact triggers order.
@@ -99,6 +104,9 @@ pub struct SetBlock<'set> {
     time_triggers: StorageBlock<'set, TriggerId, LoadedAction<TimeEventFilter>>,
     /// Triggers using [`ExecuteTriggerEventFilter`]
     by_call_triggers: StorageBlock<'set, TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+    /// Triggers using [`TransactionValidationEventFilter`]
+    validation_triggers:
+        StorageBlock<'set, TriggerId, LoadedAction<TransactionValidationEventFilter>>,
     /// Trigger ids with type of events they process
     ids: StorageBlock<'set, TriggerId, TriggeringEventType>,
     /// Original [`WasmSmartContract`]s by [`TriggerId`] for querying purposes.
@@ -120,6 +128,9 @@ pub struct SetTransaction<'block, 'set> {
     /// Triggers using [`ExecuteTriggerEventFilter`]
     by_call_triggers:
         StorageTransaction<'block, 'set, TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+    /// Triggers using [`TransactionValidationEventFilter`]
+    validation_triggers:
+        StorageTransaction<'block, 'set, TriggerId, LoadedAction<TransactionValidationEventFilter>>,
     /// Trigger ids with type of events they process
     ids: StorageTransaction<'block, 'set, TriggerId, TriggeringEventType>,
     /// Original [`WasmSmartContract`]s by [`TriggerId`] for querying purposes.
@@ -139,6 +150,9 @@ pub struct SetView<'set> {
     time_triggers: StorageView<'set, TriggerId, LoadedAction<TimeEventFilter>>,
     /// Triggers using [`ExecuteTriggerEventFilter`]
     by_call_triggers: StorageView<'set, TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+    /// Triggers using [`TransactionValidationEventFilter`]
+    validation_triggers:
+        StorageView<'set, TriggerId, LoadedAction<TransactionValidationEventFilter>>,
     /// Trigger ids with type of events they process
     ids: StorageView<'set, TriggerId, TriggeringEventType>,
     /// Original [`WasmSmartContract`]s by [`TriggerId`] for querying purposes.
@@ -156,8 +170,10 @@ pub struct WasmSmartContractEntry {
     /// Compiled with [`wasmtime`] smart-contract
     #[serde(skip)]
     compiled_contract: wasmtime::Module,
-    /// Number of times this contract is used
+    /// Number of times this contract is used, registration counts as one use
     count: NonZeroU64,
+    /// Whether this contract was registered as [`WasmCode`]
+    registered: bool,
 }
 
 impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Set> {
@@ -186,6 +202,7 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Set> {
                 let mut pipeline_triggers = None;
                 let mut time_triggers = None;
                 let mut by_call_triggers = None;
+                let mut validation_triggers = None;
                 let mut ids = None;
                 let mut contracts = None;
                 let mut matched_ids = None;
@@ -203,6 +220,9 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Set> {
                         "by_call_triggers" => {
                             by_call_triggers = Some(map.next_value()?);
                         }
+                        "validation_triggers" => {
+                            validation_triggers = Some(map.next_value()?);
+                        }
                         "ids" => {
                             ids = Some(map.next_value()?);
                         }
@@ -229,6 +249,8 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Set> {
                         .ok_or_else(|| serde::de::Error::missing_field("time_triggers"))?,
                     by_call_triggers: by_call_triggers
                         .ok_or_else(|| serde::de::Error::missing_field("by_call_triggers"))?,
+                    // Absent in the snapshots made before validation triggers were introduced
+                    validation_triggers: validation_triggers.unwrap_or_default(),
                     ids: ids.ok_or_else(|| serde::de::Error::missing_field("ids"))?,
                     contracts: contracts
                         .ok_or_else(|| serde::de::Error::missing_field("contracts"))?,
@@ -266,6 +288,7 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, WasmSmartContractEntry> {
             {
                 let mut original_contract = None;
                 let mut count = None;
+                let mut registered = None;
 
                 while let Some(key) = map.next_key::<String>()? {
                     match key.as_str() {
@@ -275,6 +298,9 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, WasmSmartContractEntry> {
                         "count" => {
                             count = Some(map.next_value()?);
                         }
+                        "registered" => {
+                            registered = Some(map.next_value()?);
+                        }
                         _ => { /* Ignore unknown fields */ }
                     }
                 }
@@ -289,6 +315,8 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, WasmSmartContractEntry> {
                     original_contract,
                     compiled_contract,
                     count,
+                    // Absent in the snapshots made before code could be registered
+                    registered: registered.unwrap_or_default(),
                 })
             }
         }
@@ -307,6 +335,9 @@ pub trait SetReadOnly {
     fn by_call_triggers(
         &self,
     ) -> &impl StorageReadOnly<TriggerId, LoadedAction<ExecuteTriggerEventFilter>>;
+    fn validation_triggers(
+        &self,
+    ) -> &impl StorageReadOnly<TriggerId, LoadedAction<TransactionValidationEventFilter>>;
     fn ids(&self) -> &impl StorageReadOnly<TriggerId, TriggeringEventType>;
     fn contracts(&self)
         -> &impl StorageReadOnly<HashOf<WasmSmartContract>, WasmSmartContractEntry>;
@@ -344,6 +375,8 @@ pub trait SetReadOnly {
             authority,
             filter,
             metadata,
+            limits,
+            paused,
         } = action;
 
         let original_executable = match executable {
@@ -363,6 +396,8 @@ pub trait SetReadOnly {
             authority,
             filter,
             metadata,
+            limits,
+            paused,
         }
     }
 
@@ -406,6 +441,13 @@ pub trait SetReadOnly {
                     .expect("`Set::by_call_triggers` doesn't contain required id. This is a bug")
                     .executable
             }
+            TriggeringEventType::TransactionValidation => {
+                &self
+                    .validation_triggers()
+                    .get(id)
+                    .expect("`Set::validation_triggers` doesn't contain required id. This is a bug")
+                    .executable
+            }
         })
     }
 
@@ -450,6 +492,13 @@ pub trait SetReadOnly {
                     .get(id)
                     .map(|trigger| f(id, trigger))
                     .expect("`Set::by_call_triggers` doesn't contain required id. This is a bug"),
+                TriggeringEventType::TransactionValidation => self
+                    .validation_triggers()
+                    .get(id)
+                    .map(|trigger| f(id, trigger))
+                    .expect(
+                        "`Set::validation_triggers` doesn't contain required id. This is a bug",
+                    ),
             };
 
             Some(result)
@@ -486,6 +535,11 @@ pub trait SetReadOnly {
                 .get(id)
                 .map(|entry| f(entry))
                 .expect("`Set::by_call_triggers` doesn't contain required id. This is a bug"),
+            TriggeringEventType::TransactionValidation => self
+                .validation_triggers()
+                .get(id)
+                .map(|entry| f(entry))
+                .expect("`Set::validation_triggers` doesn't contain required id. This is a bug"),
         };
         Some(result)
     }
@@ -506,6 +560,9 @@ macro_rules! impl_set_ro {
             fn by_call_triggers(&self) -> &impl StorageReadOnly<TriggerId, LoadedAction<ExecuteTriggerEventFilter>> {
                 &self.by_call_triggers
             }
+            fn validation_triggers(&self) -> &impl StorageReadOnly<TriggerId, LoadedAction<TransactionValidationEventFilter>> {
+                &self.validation_triggers
+            }
             fn ids(&self) -> &impl StorageReadOnly<TriggerId, TriggeringEventType> {
                 &self.ids
             }
@@ -531,6 +588,7 @@ impl Set {
             pipeline_triggers: self.pipeline_triggers.block(),
             time_triggers: self.time_triggers.block(),
             by_call_triggers: self.by_call_triggers.block(),
+            validation_triggers: self.validation_triggers.block(),
             ids: self.ids.block(),
             contracts: self.contracts.block(),
             matched_ids: self.matched_ids.block(),
@@ -544,6 +602,7 @@ impl Set {
             pipeline_triggers: self.pipeline_triggers.block_and_revert(),
             time_triggers: self.time_triggers.block_and_revert(),
             by_call_triggers: self.by_call_triggers.block_and_revert(),
+            validation_triggers: self.validation_triggers.block_and_revert(),
             ids: self.ids.block_and_revert(),
             contracts: self.contracts.block_and_revert(),
             matched_ids: self.matched_ids.block_and_revert(),
@@ -557,6 +616,7 @@ impl Set {
             pipeline_triggers: self.pipeline_triggers.view(),
             time_triggers: self.time_triggers.view(),
             by_call_triggers: self.by_call_triggers.view(),
+            validation_triggers: self.validation_triggers.view(),
             ids: self.ids.view(),
             contracts: self.contracts.view(),
             matched_ids: self.matched_ids.view(),
@@ -572,6 +632,7 @@ impl<'set> SetBlock<'set> {
             pipeline_triggers: self.pipeline_triggers.transaction(),
             time_triggers: self.time_triggers.transaction(),
             by_call_triggers: self.by_call_triggers.transaction(),
+            validation_triggers: self.validation_triggers.transaction(),
             ids: self.ids.transaction(),
             contracts: self.contracts.transaction(),
             matched_ids: self.matched_ids.transaction(),
@@ -584,6 +645,7 @@ impl<'set> SetBlock<'set> {
         self.matched_ids.commit();
         self.contracts.commit();
         self.ids.commit();
+        self.validation_triggers.commit();
         self.by_call_triggers.commit();
         self.time_triggers.commit();
         self.pipeline_triggers.commit();
@@ -596,6 +658,9 @@ impl<'set> SetBlock<'set> {
     /// These actions are inspected in the next [`Set::inspect_matched()`] call.
     pub fn handle_time_event(&mut self, event: TimeEvent) {
         for (id, action) in self.time_triggers.iter() {
+            if action.paused {
+                continue;
+            }
             let mut count = action.filter.count_matches(&event);
             if let Repeats::Exactly(repeats) = action.repeats {
                 count = min(repeats, count);
@@ -626,6 +691,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         self.matched_ids.apply();
         self.contracts.apply();
         self.ids.apply();
+        self.validation_triggers.apply();
         self.by_call_triggers.apply();
         self.time_triggers.apply();
         self.pipeline_triggers.apply();
@@ -704,6 +770,27 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         })
     }
 
+    /// Add trigger with [`TransactionValidationEventFilter`]
+    ///
+    /// Return `false` if a trigger with given id already exists
+    ///
+    /// # Errors
+    ///
+    /// Return [`Err`] if failed to preload wasm trigger
+    #[inline]
+    pub fn add_transaction_validation_trigger(
+        &mut self,
+        engine: &wasmtime::Engine,
+        trigger: SpecializedTrigger<TransactionValidationEventFilter>,
+    ) -> Result<bool> {
+        self.add_to(
+            engine,
+            trigger,
+            TriggeringEventType::TransactionValidation,
+            |me| &mut me.validation_triggers,
+        )
+    }
+
     /// Add generic trigger to generic collection
     ///
     /// Returns `false` if a trigger with given id already exists
@@ -727,6 +814,8 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
                     authority,
                     filter,
                     metadata,
+                    limits,
+                    paused,
                 },
         } = trigger;
 
@@ -734,7 +823,34 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
             return Ok(false);
         }
 
-        let loaded_executable = match executable {
+        let loaded_executable = self.load_executable(engine, executable)?;
+        map(self).insert(
+            trigger_id.clone(),
+            LoadedAction {
+                executable: loaded_executable,
+                repeats,
+                authority,
+                filter,
+                metadata,
+                limits,
+                paused,
+            },
+        );
+        self.ids.insert(trigger_id, event_type);
+        Ok(true)
+    }
+
+    /// Store original wasm of `executable` or increase its counter if it's already stored
+    ///
+    /// # Errors
+    ///
+    /// Return [`Err`] if failed to preload wasm trigger
+    fn load_executable(
+        &mut self,
+        engine: &wasmtime::Engine,
+        executable: Executable,
+    ) -> Result<ExecutableRef> {
+        Ok(match executable {
             Executable::Wasm(bytes) => {
                 let hash = HashOf::new(&bytes);
                 // Store original executable representation to respond to queries with.
@@ -753,24 +869,95 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
                             original_contract: bytes,
                             compiled_contract: module,
                             count: NonZeroU64::MIN,
+                            registered: false,
                         },
                     );
                 };
                 ExecutableRef::Wasm(hash)
             }
+            Executable::WasmCode(hash) => {
+                let Some(WasmSmartContractEntry {
+                    count,
+                    registered: true,
+                    ..
+                }) = self.contracts.get_mut(&hash)
+                else {
+                    return Err(Error::UnknownCode(hash));
+                };
+                *count = count
+                    .checked_add(1)
+                    .expect("There is no way someone could register 2^64 amount of same triggers");
+                ExecutableRef::Wasm(hash)
+            }
             Executable::Instructions(instructions) => ExecutableRef::Instructions(instructions),
-        };
-        map(self).insert(
-            trigger_id.clone(),
-            LoadedAction {
-                executable: loaded_executable,
-                repeats,
-                authority,
-                filter,
-                metadata,
-            },
-        );
-        self.ids.insert(trigger_id, event_type);
+        })
+    }
+
+    /// Store `code` for triggers to refer to by its hash.
+    /// The code stays stored after all the triggers using it are removed.
+    ///
+    /// Return `false` if the code is already registered.
+    ///
+    /// # Errors
+    ///
+    /// Return [`Err`] if failed to preload wasm
+    pub fn register_code(
+        &mut self,
+        engine: &wasmtime::Engine,
+        code: WasmSmartContract,
+    ) -> Result<bool> {
+        let hash = HashOf::new(&code);
+        if let Some(entry) = self.contracts.get_mut(&hash) {
+            if entry.registered {
+                return Ok(false);
+            }
+            entry.registered = true;
+            entry.count = entry
+                .count
+                .checked_add(1)
+                .expect("There is no way someone could register 2^64 amount of same triggers");
+        } else {
+            let module = wasm::load_module(engine, &code)?;
+            self.contracts.insert(
+                hash,
+                WasmSmartContractEntry {
+                    original_contract: code,
+                    compiled_contract: module,
+                    count: NonZeroU64::MIN,
+                    registered: true,
+                },
+            );
+        }
+        Ok(true)
+    }
+
+    /// Replace the executable of the trigger identified by `id` keeping the rest of its action.
+    ///
+    /// Return `false` if [`Set`] doesn't contain the trigger with the given `id`.
+    ///
+    /// # Errors
+    ///
+    /// Return [`Err`] if failed to preload wasm trigger
+    pub fn upgrade(
+        &mut self,
+        engine: &wasmtime::Engine,
+        id: &TriggerId,
+        executable: Executable,
+    ) -> Result<bool> {
+        if self.ids.get(id).is_none() {
+            return Ok(false);
+        }
+
+        let loaded_executable = self.load_executable(engine, executable)?;
+        let previous = self
+            .inspect_by_id_mut(id, |action| {
+                action.replace_executable(loaded_executable.clone())
+            })
+            .expect("Trigger existence is checked above");
+        if let ExecutableRef::Wasm(blob_hash) = previous {
+            Self::remove_original_trigger(&mut self.contracts, blob_hash);
+        }
+
         Ok(true)
     }
 
@@ -804,6 +991,11 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
                 .get_mut(id)
                 .map(|entry| f(entry))
                 .expect("`Set::by_call_triggers` doesn't contain required id. This is a bug"),
+            TriggeringEventType::TransactionValidation => self
+                .validation_triggers
+                .get_mut(id)
+                .map(|entry| f(entry))
+                .expect("`Set::validation_triggers` doesn't contain required id. This is a bug"),
         };
         Some(result)
     }
@@ -833,6 +1025,9 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
             TriggeringEventType::ExecuteTrigger => {
                 Self::remove_from(&mut self.contracts, &mut self.by_call_triggers, id)
             }
+            TriggeringEventType::TransactionValidation => {
+                Self::remove_from(&mut self.contracts, &mut self.validation_triggers, id)
+            }
         };
 
         assert!(
@@ -921,11 +1116,32 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
             let _mod_repeats_res = self.mod_repeats(id, |n| Ok(n.saturating_sub(1)));
         }
 
+        self.remove_exhausted();
+    }
+
+    /// Decrease instruction budgets of the triggers by the number of instructions they executed
+    /// and remove those whose budget is exhausted.
+    pub fn spend_instruction_budgets(&mut self, spent: &[(TriggerId, u64)]) {
+        for (id, instruction_count) in spent {
+            self.inspect_by_id_mut(id, |action| {
+                let limits = action.limits_mut();
+                limits.instruction_budget = limits
+                    .instruction_budget
+                    .map(|budget| budget.saturating_sub(*instruction_count));
+            });
+        }
+
+        self.remove_exhausted();
+    }
+
+    /// Remove actions which can't be executed anymore
+    fn remove_exhausted(&mut self) {
         let Self {
             data_triggers,
             pipeline_triggers,
             time_triggers,
             by_call_triggers,
+            validation_triggers,
             ids,
             contracts,
             ..
@@ -934,9 +1150,10 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         Self::remove_zeros(ids, contracts, pipeline_triggers);
         Self::remove_zeros(ids, contracts, time_triggers);
         Self::remove_zeros(ids, contracts, by_call_triggers);
+        Self::remove_zeros(ids, contracts, validation_triggers);
     }
 
-    /// Remove actions with zero execution count from `triggers`
+    /// Remove actions with zero execution count or exhausted instruction budget from `triggers`
     fn remove_zeros<F: storage::Value + EventFilter>(
         ids: &mut StorageTransaction<'block, 'set, TriggerId, TriggeringEventType>,
         contracts: &mut WasmSmartContractMapTransaction<'block, 'set>,
@@ -948,6 +1165,9 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
                 if let Repeats::Exactly(0) = action.repeats {
                     return Some(id.clone());
                 }
+                if action.limits.instruction_budget == Some(0) {
+                    return Some(id.clone());
+                }
                 None
             })
             .collect();
@@ -985,17 +1205,39 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         };
     }
 
+    /// Find the transaction validation triggers matched by `event`.
+    ///
+    /// Triggers of a domain match only the transactions of the accounts of this domain.
+    pub fn match_transaction_validation_event(
+        &self,
+        event: &TransactionValidationEvent,
+    ) -> Vec<TriggerId> {
+        let authority = event.transaction().authority();
+        self.validation_triggers
+            .iter()
+            .filter(|(id, _)| {
+                id.domain_id
+                    .as_ref()
+                    .map_or(true, |domain_id| *domain_id == authority.domain_id)
+            })
+            .filter(|(_, action)| action.repeats != Repeats::Exactly(0) && !action.paused)
+            .filter(|(_, action)| action.filter.matches(event))
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
     /// Match and insert a [`TriggerId`] into the set of matched ids.
     ///
     /// Skips insertion:
     /// - If the action's filter doesn't match an event
     /// - If the action's repeats count equals to 0
+    /// - If the action is paused
     fn match_and_insert_trigger<E: Into<EventBox>, F: EventFilter<Event = E>>(
         matched_ids: &mut Vec<(EventBox, TriggerId)>,
         event: E,
         (id, action): (&TriggerId, &LoadedAction<F>),
     ) {
-        if !action.filter.matches(&event) {
+        if action.paused || !action.filter.matches(&event) {
             return;
         }
 
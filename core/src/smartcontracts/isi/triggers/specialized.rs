@@ -7,6 +7,7 @@ use iroha_data_model::{
     events::{EventFilter, TriggeringEventFilterBox},
     metadata::Metadata,
     prelude::*,
+    trigger::action::ExecutionLimits,
 };
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +30,10 @@ pub struct SpecializedAction<F> {
     pub filter: F,
     /// Metadata used as persistent storage for trigger data.
     pub metadata: Metadata,
+    /// Limits of the work done by the executions of the action
+    pub limits: ExecutionLimits,
+    /// Whether the action is paused
+    pub paused: bool,
 }
 
 impl<F> SpecializedAction<F> {
@@ -46,6 +51,8 @@ impl<F> SpecializedAction<F> {
             authority,
             filter,
             metadata: Metadata::new(),
+            limits: ExecutionLimits::default(),
+            paused: false,
         }
     }
 }
@@ -61,6 +68,8 @@ where
             authority: value.authority,
             filter: value.filter.into(),
             metadata: value.metadata,
+            limits: value.limits,
+            paused: value.paused,
         }
     }
 }
@@ -82,12 +91,16 @@ macro_rules! impl_try_from_box {
 
                 fn try_from(boxed: Trigger) -> Result<Self, Self::Error> {
                     if let TriggeringEventFilterBox::$variant(concrete_filter) = boxed.action.filter {
-                        let action = SpecializedAction::new(
-                            boxed.action.executable,
-                            boxed.action.repeats,
-                            boxed.action.authority,
-                            concrete_filter,
-                        );
+                        let action = SpecializedAction {
+                            limits: boxed.action.limits,
+                            paused: boxed.action.paused,
+                            ..SpecializedAction::new(
+                                boxed.action.executable,
+                                boxed.action.repeats,
+                                boxed.action.authority,
+                                concrete_filter,
+                            )
+                        };
                         Ok(Self {
                             id: boxed.id,
                             action,
@@ -106,6 +119,7 @@ impl_try_from_box! {
     Pipeline => PipelineEventFilterBox,
     Time => TimeEventFilter,
     ExecuteTrigger => ExecuteTriggerEventFilter,
+    TransactionValidation => TransactionValidationEventFilter,
 }
 
 /// Same as [`iroha_data_model::trigger::action::Action`] but with
@@ -124,6 +138,12 @@ pub struct LoadedAction<F> {
     pub filter: F,
     /// Metadata used as persistent storage for trigger data.
     pub metadata: Metadata,
+    /// Limits of the work done by the executions of the action
+    #[serde(default)]
+    pub limits: ExecutionLimits,
+    /// Whether the action is paused
+    #[serde(default)]
+    pub paused: bool,
 }
 
 impl<F> LoadedAction<F> {
@@ -140,6 +160,9 @@ pub trait LoadedActionTrait {
     /// Get action executable
     fn executable(&self) -> &ExecutableRef;
 
+    /// Replace action executable returning the previous one
+    fn replace_executable(&mut self, executable: ExecutableRef) -> ExecutableRef;
+
     /// Get action repeats enum
     fn repeats(&self) -> &Repeats;
 
@@ -155,6 +178,18 @@ pub trait LoadedActionTrait {
     /// Get action metadata
     fn metadata_mut(&mut self) -> &mut Metadata;
 
+    /// Get action execution limits
+    fn limits(&self) -> &ExecutionLimits;
+
+    /// Get action execution limits
+    fn limits_mut(&mut self) -> &mut ExecutionLimits;
+
+    /// Check if action is paused
+    fn is_paused(&self) -> bool;
+
+    /// Pause or resume action
+    fn set_paused(&mut self, paused: bool);
+
     /// Check if action is mintable.
     fn mintable(&self) -> bool;
 
@@ -172,6 +207,10 @@ impl<F: EventFilter + Into<TriggeringEventFilterBox> + Clone> LoadedActionTrait
         &self.executable
     }
 
+    fn replace_executable(&mut self, executable: ExecutableRef) -> ExecutableRef {
+        core::mem::replace(&mut self.executable, executable)
+    }
+
     fn repeats(&self) -> &iroha_data_model::trigger::action::Repeats {
         &self.repeats
     }
@@ -192,6 +231,22 @@ impl<F: EventFilter + Into<TriggeringEventFilterBox> + Clone> LoadedActionTrait
         &mut self.metadata
     }
 
+    fn limits(&self) -> &ExecutionLimits {
+        &self.limits
+    }
+
+    fn limits_mut(&mut self) -> &mut ExecutionLimits {
+        &mut self.limits
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
     fn mintable(&self) -> bool {
         self.filter.mintable()
     }
@@ -203,6 +258,8 @@ impl<F: EventFilter + Into<TriggeringEventFilterBox> + Clone> LoadedActionTrait
             authority,
             filter,
             metadata,
+            limits,
+            paused,
         } = self;
 
         LoadedAction {
@@ -211,6 +268,8 @@ impl<F: EventFilter + Into<TriggeringEventFilterBox> + Clone> LoadedActionTrait
             authority,
             filter: filter.into(),
             metadata,
+            limits,
+            paused,
         }
     }
 
@@ -249,6 +308,11 @@ mod tests {
                         .map(|_| ())
                         .unwrap()
                 }
+                TriggeringEventFilterBox::TransactionValidation(_) => {
+                    SpecializedTrigger::<TransactionValidationEventFilter>::try_from(boxed)
+                        .map(|_| ())
+                        .unwrap()
+                }
             }
         }
     }
@@ -3,13 +3,13 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use iroha_crypto::HashOf;
+use iroha_crypto::{HashOf, MerkleTree};
 use iroha_data_model::{
     block::SignedBlock,
     prelude::*,
     query::{
         error::{FindError, QueryExecutionFail},
-        TransactionQueryOutput,
+        TransactionInclusionProof, TransactionQueryOutput,
     },
     transaction::CommittedTransaction,
 };
@@ -89,9 +89,20 @@ impl ValidQuery for FindTransactionsByAccountId {
     ) -> Result<Box<dyn Iterator<Item = TransactionQueryOutput> + 'state>, QueryExecutionFail> {
         let account_id = self.account_id.clone();
 
+        // Only the blocks with transactions of the account are loaded, if they are indexed
+        let blocks: Box<dyn Iterator<Item = Arc<SignedBlock>> + 'state> =
+            match state_ro
+                .kura()
+                .transaction_heights_by_authority(&account_id, state_ro.height())
+            {
+                Some(heights) => Box::new(heights.into_iter().filter_map(move |block_height| {
+                    state_ro.kura().get_block_by_height(block_height)
+                })),
+                None => Box::new(state_ro.all_blocks()),
+            };
+
         Ok(Box::new(
-            state_ro
-                .all_blocks()
+            blocks
                 .flat_map(BlockTransactionIter::new)
                 .filter(move |tx| *tx.authority() == account_id)
                 .map(|tx| TransactionQueryOutput {
@@ -131,3 +142,33 @@ impl ValidQuery for FindTransactionByHash {
             .ok_or_else(|| FindError::Transaction(tx_hash).into())
     }
 }
+
+impl ValidQuery for FindTransactionProofByHash {
+    #[metrics(+"find_transaction_proof_by_hash")]
+    fn execute(
+        &self,
+        state_ro: &impl StateReadOnly,
+    ) -> Result<TransactionInclusionProof, QueryExecutionFail> {
+        let tx_hash = self.hash;
+
+        iroha_logger::trace!(%tx_hash);
+        let block = state_ro
+            .block_with_tx(&tx_hash)
+            .ok_or_else(|| FindError::Transaction(tx_hash))?;
+
+        let tree = block
+            .transactions()
+            .map(|transaction| transaction.value.hash())
+            .collect::<MerkleTree<_>>();
+        let proof = block
+            .transactions()
+            .position(|transaction| transaction.value.hash() == tx_hash)
+            .and_then(|idx| tree.get_proof(idx))
+            .ok_or_else(|| FindError::Transaction(tx_hash))?;
+
+        Ok(TransactionInclusionProof {
+            block_header: block.header().clone(),
+            proof,
+        })
+    }
+}
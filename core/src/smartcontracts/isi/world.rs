@@ -105,6 +105,16 @@ pub mod isi {
                 }
                 .into());
             }
+            if domain_id.name.as_ref().contains(DomainId::SEPARATOR) {
+                let Some(parent_id) = domain_id.parent() else {
+                    return Err(InstructionExecutionError::InvariantViolation(format!(
+                        "`{domain_id}` is not a valid sub-domain id"
+                    )));
+                };
+                if world.domains.get(&parent_id).is_none() {
+                    return Err(FindError::Domain(parent_id).into());
+                }
+            }
 
             world.domains.insert(domain_id, domain.clone());
 
@@ -123,6 +133,16 @@ pub mod isi {
         ) -> Result<(), Error> {
             let domain_id = self.object_id;
 
+            if state_transaction
+                .world
+                .domains_iter()
+                .any(|domain| domain.id().is_descendant_of(&domain_id))
+            {
+                return Err(InstructionExecutionError::InvariantViolation(format!(
+                    "Domain `{domain_id}` has sub-domains which must be unregistered first"
+                )));
+            }
+
             let triggers_in_domain = state_transaction
                 .world()
                 .triggers()
@@ -133,8 +153,15 @@ pub mod isi {
             for trigger_id in &triggers_in_domain {
                 assert!(world.triggers.remove(trigger_id.clone()));
             }
-            if world.domains.remove(domain_id.clone()).is_none() {
+            let Some(domain) = world.domains.remove(domain_id.clone()) else {
                 return Err(FindError::Domain(domain_id).into());
+            };
+            for asset in domain
+                .accounts
+                .values()
+                .flat_map(|account| account.assets.values())
+            {
+                world.remove_nft_owners(asset);
             }
 
             world.emit_events(Some(DomainEvent::Deleted(domain_id)));
@@ -2,7 +2,7 @@
 //! `WebAssembly` VM Smartcontracts can be written in Rust, compiled
 //! to wasm format and submitted in a transaction
 
-use std::borrow::Borrow;
+use std::{borrow::Borrow, num::NonZeroU64};
 
 use error::*;
 use import::traits::{ExecuteOperations as _, GetExecutorPayloads as _, SetDataModel as _};
@@ -14,13 +14,15 @@ use iroha_data_model::{
     prelude::*,
     query::{QueryBox, QueryId, QueryOutputBox, QueryRequest, SmartContractQuery},
     smart_contract::payloads::{self, Validate},
+    trigger::action::ExecutionLimits,
     BatchedResponse, Level as LogLevel, ValidationFail,
 };
 use iroha_logger::debug;
 // NOTE: Using error_span so that span info is logged on every event
+use iroha_crypto::Hash;
 use iroha_logger::{error_span as wasm_log_span, prelude::tracing::Span};
 use iroha_wasm_codec::{self as codec, WasmUsize};
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Decode, Encode};
 use wasmtime::{
     Caller, Config as WasmtimeConfig, Engine, Linker, Module, Store, StoreLimits,
     StoreLimitsBuilder, TypedFunc,
@@ -28,7 +30,9 @@ use wasmtime::{
 
 use crate::{
     query::store::LiveQueryStoreHandle,
-    smartcontracts::{wasm::state::ValidateQueryOperation, Execute},
+    smartcontracts::{
+        triggers::set::SetReadOnly as _, wasm::state::ValidateQueryOperation, Execute,
+    },
     state::{StateReadOnly, StateTransaction, WorldReadOnly},
     ValidQuery as _,
 };
@@ -41,7 +45,14 @@ mod export {
     pub const EXECUTE_ISI: &str = "execute_instruction";
     pub const EXECUTE_QUERY: &str = "execute_query";
     pub const GET_SMART_CONTRACT_PAYLOAD: &str = "get_smart_contract_payload";
+    pub const GET_RANDOM_SEED: &str = "get_random_seed";
     pub const GET_TRIGGER_PAYLOAD: &str = "get_trigger_payload";
+    pub const GET_TRIGGER_STORAGE: &str = "get_trigger_storage";
+    pub const SET_TRIGGER_STORAGE: &str = "set_trigger_storage";
+    pub const REMOVE_TRIGGER_STORAGE: &str = "remove_trigger_storage";
+    pub const CALL_TRIGGER: &str = "call_trigger";
+    pub const GET_CALL_ARGUMENTS: &str = "get_call_arguments";
+    pub const SET_CALL_OUTPUT: &str = "set_call_output";
     pub const GET_MIGRATE_PAYLOAD: &str = "get_migrate_payload";
     pub const GET_VALIDATE_TRANSACTION_PAYLOAD: &str = "get_validate_transaction_payload";
     pub const GET_VALIDATE_INSTRUCTION_PAYLOAD: &str = "get_validate_instruction_payload";
@@ -215,6 +226,8 @@ pub mod error {
         HostExecution(#[source] wasmtime::Error),
         /// Execution limits exceeded
         ExecutionLimitsExceeded(#[source] wasmtime::Error),
+        /// Fuel limit exceeded
+        OutOfFuel(#[source] wasmtime::Error),
         /// Other kind of trap
         Other(#[source] wasmtime::Error),
     }
@@ -227,8 +240,8 @@ pub mod error {
                     | Trap::MemoryOutOfBounds
                     | Trap::TableOutOfBounds
                     | Trap::IndirectCallToNull
-                    | Trap::OutOfFuel
                     | Trap::Interrupt => Self::ExecutionLimitsExceeded(err),
+                    Trap::OutOfFuel => Self::OutOfFuel(err),
                     _ => Self::Other(err),
                 },
                 None => Self::HostExecution(err),
@@ -293,9 +306,9 @@ fn forget_all_executed_queries(
     Ok(())
 }
 
-/// Limits checker for smartcontracts.
+/// Limits checker for smartcontracts and triggers.
 #[derive(Copy, Clone)]
-struct LimitsExecutor {
+pub struct LimitsExecutor {
     /// Number of instructions in the smartcontract
     instruction_count: u64,
     /// Max allowed number of instructions in the smartcontract
@@ -311,6 +324,20 @@ impl LimitsExecutor {
         }
     }
 
+    /// Create new [`LimitsExecutor`] obeying both per-execution and overall instruction limits of a trigger
+    pub fn for_trigger(limits: &ExecutionLimits) -> Self {
+        let max_instruction_count = limits
+            .max_instructions
+            .map_or(u64::MAX, NonZeroU64::get)
+            .min(limits.instruction_budget.unwrap_or(u64::MAX));
+        Self::new(max_instruction_count)
+    }
+
+    /// Number of instructions executed so far
+    pub fn instruction_count(&self) -> u64 {
+        self.instruction_count
+    }
+
     /// Checks if number of instructions in wasm smartcontract exceeds maximum
     ///
     /// # Errors
@@ -437,6 +464,26 @@ pub mod state {
                 self.0
             }
         }
+
+        /// Trait to capture messages logged by WASM
+        ///
+        /// Exists to write generic code for [`WithMut`] and [`WithConst`].
+        pub trait CaptureLog {
+            /// Capture `log` if the chain state is set to capture logs
+            fn capture_log(&mut self, log: Log);
+        }
+
+        impl<S: StateReadOnly> CaptureLog for WithConst<'_, S> {
+            fn capture_log(&mut self, _log: Log) {}
+        }
+
+        impl CaptureLog for WithMut<'_, '_, '_> {
+            fn capture_log(&mut self, log: Log) {
+                if let Some(logs) = self.0.wasm_logs.as_mut() {
+                    logs.push(log);
+                }
+            }
+        }
     }
 
     pub mod specific {
@@ -464,6 +511,18 @@ pub mod state {
 
             /// Event which activated this trigger
             pub(in super::super) triggering_event: EventBox,
+
+            /// Limits of the trigger execution
+            pub(in super::super) limits_executor: LimitsExecutor,
+
+            /// Set if the trigger is called by another executable
+            pub(in super::super) call: Option<TriggerCall>,
+        }
+
+        /// Arguments and output of a synchronous trigger call
+        pub struct TriggerCall {
+            pub(in super::super) args: Vec<u8>,
+            pub(in super::super) output: Vec<u8>,
         }
 
         pub mod executor {
@@ -696,7 +755,7 @@ struct LogError(u8);
 /// It's required by `#[codec::wrap]` to parse well
 type WasmtimeError = wasmtime::Error;
 
-impl<W, S> Runtime<state::CommonState<W, S>> {
+impl<W: state::chain_state::CaptureLog, S> Runtime<state::CommonState<W, S>> {
     /// Log the given string at the given log level
     ///
     /// # Errors
@@ -705,15 +764,15 @@ impl<W, S> Runtime<state::CommonState<W, S>> {
     #[codec::wrap]
     pub fn log(
         (log_level, msg): (u8, String),
-        state: &state::CommonState<W, S>,
+        state: &mut state::CommonState<W, S>,
     ) -> Result<(), WasmtimeError> {
         const TARGET: &str = "WASM";
 
-        let _span = state.log_span.enter();
-        match LogLevel::from_repr(log_level)
+        let level = LogLevel::from_repr(log_level)
             .ok_or(LogError(log_level))
-            .map_err(wasmtime::Error::from)?
-        {
+            .map_err(wasmtime::Error::from)?;
+        let _span = state.log_span.enter();
+        match level {
             LogLevel::TRACE => {
                 iroha_logger::trace!(target: TARGET, msg);
             }
@@ -730,9 +789,51 @@ impl<W, S> Runtime<state::CommonState<W, S>> {
                 iroha_logger::error!(target: TARGET, msg);
             }
         }
+        state.state.capture_log(Log::new(level, msg));
         Ok(())
     }
+}
+
+impl<'wrld, 'block: 'wrld, 'state: 'block, S>
+    Runtime<state::CommonState<state::chain_state::WithMut<'wrld, 'block, 'state>, S>>
+{
+    /// Synchronously call the trigger `id` passing it SCALE-encoded `args`
+    /// and return the output it sets.
+    ///
+    /// The called trigger runs on the fuel the caller has left
+    /// and the fuel it consumes is taken from the caller.
+    /// Not wrapped with `codec::wrap`, because the caller's fuel is needed.
+    ///
+    /// # Errors
+    ///
+    /// If the call fails, see [`StateTransaction::call_trigger`].
+    /// The error traps the caller so that the changes made by the called trigger are discarded.
+    fn call_trigger(
+        mut caller: Caller<
+            state::CommonState<state::chain_state::WithMut<'wrld, 'block, 'state>, S>,
+        >,
+        offset: WasmUsize,
+        len: WasmUsize,
+    ) -> Result<WasmUsize, WasmtimeError> {
+        let memory = Self::get_memory(&mut caller).expect("Checked at instantiation step");
+        let alloc_fn = Self::get_alloc_fn(&mut caller).expect("Checked at instantiation step");
+        let (id, args): (TriggerId, Vec<u8>) =
+            codec::decode_from_memory(&memory, &caller, offset, len)?;
+
+        let fuel = caller.get_fuel()?;
+        let state = caller.data_mut();
+        let (output, consumed_fuel) = state
+            .state
+            .0
+            .call_trigger(&id, &state.authority, args, fuel)
+            .map_err(|error| WasmtimeError::msg(format!("Call of `{id}` failed: {error:?}")))?;
+        caller.set_fuel(fuel.saturating_sub(consumed_fuel))?;
+
+        codec::encode_into_memory(&output, &memory, &alloc_fn, &mut caller)
+    }
+}
 
+impl<W, S> Runtime<state::CommonState<W, S>> {
     fn create_store(&self, state: state::CommonState<W, S>) -> Store<state::CommonState<W, S>> {
         let mut store = Store::new(&self.engine, state);
 
@@ -939,6 +1040,21 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
             owner: state.authority.clone(),
         }
     }
+
+    /// Deterministic seed derived from the latest block hash and the hash of the executed
+    /// transaction. Same for every peer, so it can't be used to keep secrets.
+    #[codec::wrap]
+    fn get_random_seed(state: &state::SmartContract) -> [u8; Hash::LENGTH] {
+        let state_transaction = &state.state.0;
+        Hash::new(
+            (
+                state_transaction.latest_block_hash(),
+                state_transaction.transaction_hash,
+            )
+                .encode(),
+        )
+        .into()
+    }
 }
 
 impl<'wrld, 'block, 'state>
@@ -973,6 +1089,7 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
     ///
     /// - if unable to find expected main function export
     /// - if the execution of the smartcontract fails
+    /// - if instruction limits are not obeyed
     pub fn execute_trigger_module(
         &mut self,
         state_transaction: &'wrld mut StateTransaction<'block, 'state>,
@@ -980,6 +1097,7 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         authority: AccountId,
         module: &wasmtime::Module,
         event: EventBox,
+        limits_executor: &mut LimitsExecutor,
     ) -> Result<()> {
         let span = wasm_log_span!("Trigger execution", %id, %authority);
         let state = state::Trigger::new(
@@ -987,22 +1105,77 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
             self.config,
             span,
             state::chain_state::WithMut(state_transaction),
-            state::specific::Trigger::new(id.clone(), event),
+            state::specific::Trigger::new(id.clone(), event, *limits_executor, None),
         );
 
+        self.execute_trigger_module_with_state(module, state, limits_executor)
+            .map(drop)
+    }
+
+    /// Calls the given wasm trigger module synchronously
+    /// passing it `args` and returns the output it sets
+    /// together with the fuel consumed by the call
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::execute_trigger_module`]
+    pub fn call_trigger_module(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        authority: AccountId,
+        module: &wasmtime::Module,
+        event: ExecuteTriggerEvent,
+        limits_executor: &mut LimitsExecutor,
+        args: Vec<u8>,
+    ) -> Result<(Vec<u8>, u64)> {
+        let id = event.trigger_id.clone();
+        let span = wasm_log_span!("Trigger call", %id, %authority, caller = %event.authority);
+        let call = state::specific::TriggerCall {
+            args,
+            output: Vec::new(),
+        };
+        let state = state::Trigger::new(
+            authority,
+            self.config,
+            span,
+            state::chain_state::WithMut(state_transaction),
+            state::specific::Trigger::new(id, event.into(), *limits_executor, Some(call)),
+        );
+
+        let (specific_state, consumed_fuel) =
+            self.execute_trigger_module_with_state(module, state, limits_executor)?;
+        let output = specific_state
+            .call
+            .map(|call| call.output)
+            .unwrap_or_default();
+        Ok((output, consumed_fuel))
+    }
+
+    /// Returns the state of the executed trigger and the fuel it consumed
+    fn execute_trigger_module_with_state(
+        &mut self,
+        module: &wasmtime::Module,
+        state: state::Trigger<'wrld, 'block, 'state>,
+        limits_executor: &mut LimitsExecutor,
+    ) -> Result<(state::specific::Trigger, u64)> {
         let mut store = self.create_store(state);
         let instance = self.instantiate_module(module, &mut store)?;
 
         let main_fn = Self::get_typed_func(&instance, &mut store, import::TRIGGER_MAIN)?;
 
         // NOTE: This function takes ownership of the pointer
-        main_fn
-            .call(&mut store, ())
-            .map_err(ExportFnCallError::from)?;
+        let result = main_fn.call(&mut store, ());
+        *limits_executor = store.data().specific_state.limits_executor;
+        result.map_err(ExportFnCallError::from)?;
+        let consumed_fuel = self.config.fuel_limit
+            - store
+                .get_fuel()
+                .expect("Fuel consumption is enabled for the engine");
 
         let mut state = store.into_data();
         let executed_queries = state.take_executed_queries();
-        forget_all_executed_queries(state.state.0.query_handle, executed_queries)
+        forget_all_executed_queries(state.state.0.query_handle, executed_queries)?;
+        Ok((state.specific_state, consumed_fuel))
     }
 
     #[codec::wrap]
@@ -1013,6 +1186,85 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
             event: state.specific_state.triggering_event.clone(),
         }
     }
+
+    /// Get the arguments the executing trigger was called with, if it was called
+    #[codec::wrap]
+    fn get_call_arguments(state: &state::Trigger) -> Option<Vec<u8>> {
+        state
+            .specific_state
+            .call
+            .as_ref()
+            .map(|call| call.args.clone())
+    }
+
+    /// Set the output returned to the caller of the executing trigger
+    ///
+    /// # Errors
+    ///
+    /// If the trigger wasn't called by another executable
+    #[codec::wrap]
+    fn set_call_output(
+        output: Vec<u8>,
+        state: &mut state::Trigger<'wrld, 'block, 'state>,
+    ) -> Result<(), WasmtimeError> {
+        let call = state
+            .specific_state
+            .call
+            .as_mut()
+            .ok_or_else(|| WasmtimeError::msg("Trigger wasn't called by another executable"))?;
+        call.output = output;
+        Ok(())
+    }
+
+    /// Get the value of `key` in the storage of the executing trigger
+    #[codec::wrap]
+    fn get_trigger_storage(key: Name, state: &state::Trigger) -> Option<MetadataValueBox> {
+        state
+            .state
+            .0
+            .world
+            .triggers()
+            .inspect_by_id(&state.specific_state.id, |action| {
+                action.metadata().get(&key).cloned()
+            })
+            .flatten()
+    }
+
+    /// Set `key` to `value` in the storage of the executing trigger.
+    ///
+    /// The storage is the metadata of the trigger, so it's subject to the same limits
+    /// but it isn't validated by the executor since a trigger always owns its storage.
+    #[codec::wrap]
+    fn set_trigger_storage(
+        entry: (Name, MetadataValueBox),
+        state: &mut state::Trigger<'wrld, 'block, 'state>,
+    ) -> Result<(), ValidationFail> {
+        state
+            .specific_state
+            .limits_executor
+            .check_instruction_limits()?;
+
+        let (key, value) = entry;
+        SetKeyValue::trigger(state.specific_state.id.clone(), key, value)
+            .execute(&state.authority, state.state.0)
+            .map_err(ValidationFail::InstructionFailed)
+    }
+
+    /// Remove `key` from the storage of the executing trigger
+    #[codec::wrap]
+    fn remove_trigger_storage(
+        key: Name,
+        state: &mut state::Trigger<'wrld, 'block, 'state>,
+    ) -> Result<(), ValidationFail> {
+        state
+            .specific_state
+            .limits_executor
+            .check_instruction_limits()?;
+
+        RemoveKeyValue::trigger(state.specific_state.id.clone(), key)
+            .execute(&state.authority, state.state.0)
+            .map_err(ValidationFail::InstructionFailed)
+    }
 }
 
 impl<'wrld, 'block, 'state> import::traits::ExecuteOperations<state::Trigger<'wrld, 'block, 'state>>
@@ -1031,6 +1283,11 @@ impl<'wrld, 'block, 'state> import::traits::ExecuteOperations<state::Trigger<'wr
         instruction: InstructionBox,
         state: &mut state::Trigger<'wrld, 'block, 'state>,
     ) -> Result<(), ValidationFail> {
+        state
+            .specific_state
+            .limits_executor
+            .check_instruction_limits()?;
+
         Self::default_execute_instruction(instruction, state)
     }
 }
@@ -1554,6 +1811,8 @@ impl<'wrld, 'block, 'state> RuntimeBuilder<state::SmartContract<'wrld, 'block, '
                 export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
                 export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
                 export::GET_SMART_CONTRACT_PAYLOAD => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>| Runtime::get_smart_contract_payload(caller),
+                export::GET_RANDOM_SEED => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>| Runtime::get_random_seed(caller),
+                export::CALL_TRIGGER => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::call_trigger(caller, offset, len),
             )?;
             Ok(linker)
         })
@@ -1574,6 +1833,12 @@ impl<'wrld, 'block, 'state> RuntimeBuilder<state::Trigger<'wrld, 'block, 'state>
                 export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
                 export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
                 export::GET_TRIGGER_PAYLOAD => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>| Runtime::get_trigger_payload(caller),
+                export::GET_TRIGGER_STORAGE => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::get_trigger_storage(caller, offset, len),
+                export::SET_TRIGGER_STORAGE => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::set_trigger_storage(caller, offset, len),
+                export::REMOVE_TRIGGER_STORAGE => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::remove_trigger_storage(caller, offset, len),
+                export::CALL_TRIGGER => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::call_trigger(caller, offset, len),
+                export::GET_CALL_ARGUMENTS => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>| Runtime::get_call_arguments(caller),
+                export::SET_CALL_OUTPUT => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::set_call_output(caller, offset, len),
             )?;
             Ok(linker)
         })
@@ -1811,6 +2076,233 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn logs_are_captured() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+        let log_hex = encode_hex((LogLevel::INFO as u8, "captured".to_owned()));
+
+        let wat = format!(
+            r#"
+            (module
+                ;; Import host function to log
+                (import "iroha" "{log_fn_name}"
+                    (func $log_fn (param i32 i32)))
+
+                {memory_and_alloc}
+
+                ;; Function which starts the smartcontract execution
+                (func (export "{main_fn_name}") (param)
+                    (call $log_fn (i32.const 0) (i32.const {log_len}))))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            log_fn_name = export::LOG,
+            memory_and_alloc = memory_and_alloc(&log_hex),
+            log_len = log_hex.len() / 3,
+        );
+        let mut state_block = state.block();
+        state_block.wasm_logs = Some(Vec::new());
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new().build()?;
+        runtime
+            .execute(&mut state_block.transaction(), authority, wat)
+            .expect("Execution failed");
+
+        assert_eq!(
+            state_block.wasm_logs,
+            Some(vec![Log::new(LogLevel::INFO, "captured".to_owned())])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn call_of_missing_trigger_aborts_execution() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+        let trigger_id: TriggerId = "missing_trigger".parse().expect("Valid");
+        let call_hex = encode_hex((trigger_id, Vec::<u8>::new()));
+
+        let wat = format!(
+            r#"
+            (module
+                ;; Import host function to call a trigger
+                (import "iroha" "{call_fn_name}"
+                    (func $call_fn (param i32 i32) (result i32)))
+
+                {memory_and_alloc}
+
+                ;; Function which starts the smartcontract execution
+                (func (export "{main_fn_name}") (param)
+                    (call $call_fn (i32.const 0) (i32.const {call_len}))
+                    (drop)))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            call_fn_name = export::CALL_TRIGGER,
+            memory_and_alloc = memory_and_alloc(&call_hex),
+            call_len = call_hex.len() / 3,
+        );
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new().build()?;
+        let res = runtime.execute(&mut state.block().transaction(), authority, wat);
+
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    /// Register a trigger callable by `authority` which burns fuel for `iterations` loop iterations
+    fn register_callee(
+        state_transaction: &mut StateTransaction<'_, '_>,
+        authority: &AccountId,
+        id: &TriggerId,
+        repeats: Repeats,
+        iterations: u32,
+    ) {
+        let wat = format!(
+            r#"
+            (module
+                {memory_and_alloc}
+
+                ;; Function which starts the trigger execution
+                (func (export "{main_fn_name}") (param)
+                    (local $i i32)
+                    (local.set $i (i32.const {iterations}))
+                    (loop $busy
+                        (local.tee $i (i32.sub (local.get $i) (i32.const 1)))
+                        (br_if $busy))))
+            "#,
+            main_fn_name = import::TRIGGER_MAIN,
+            memory_and_alloc = memory_and_alloc(""),
+        );
+        Register::trigger(Trigger::new(
+            id.clone(),
+            Action::new(
+                WasmSmartContract::from_compiled(wat.into_bytes()),
+                repeats,
+                authority.clone(),
+                ExecuteTriggerEventFilter::new().for_trigger(id.clone()),
+            ),
+        ))
+        .execute(authority, state_transaction)
+        .expect("Trigger should be registered");
+    }
+
+    /// Smart contract calling the trigger `id` `calls` times
+    fn caller_wat(id: &TriggerId, calls: usize) -> String {
+        let call_hex = encode_hex((id.clone(), Vec::<u8>::new()));
+        let call = format!(
+            "(drop (call $call_fn (i32.const 0) (i32.const {call_len})))",
+            call_len = call_hex.len() / 3
+        );
+        format!(
+            r#"
+            (module
+                ;; Import host function to call a trigger
+                (import "iroha" "{call_fn_name}"
+                    (func $call_fn (param i32 i32) (result i32)))
+
+                {memory_and_alloc}
+
+                ;; Function which starts the smartcontract execution
+                (func (export "{main_fn_name}") (param)
+                    {calls}))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            call_fn_name = export::CALL_TRIGGER,
+            memory_and_alloc = memory_and_alloc(&call_hex),
+            calls = call.repeat(calls),
+        )
+    }
+
+    #[test]
+    async fn called_trigger_consumes_fuel_of_caller() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+        let trigger_id: TriggerId = "burn_fuel".parse().expect("Valid");
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        // Every call consumes more than half of the caller's fuel
+        register_callee(
+            &mut state_transaction,
+            &authority,
+            &trigger_id,
+            Repeats::Indefinitely,
+            1_000,
+        );
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new()
+            .with_config(Config {
+                fuel_limit: 8_000,
+                ..Config::default()
+            })
+            .build()?;
+
+        runtime
+            .execute(
+                &mut state_transaction,
+                authority.clone(),
+                caller_wat(&trigger_id, 1),
+            )
+            .expect("Single call should fit into the fuel of the caller");
+        let res = runtime.execute(
+            &mut state_transaction,
+            authority,
+            caller_wat(&trigger_id, 2),
+        );
+
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    async fn call_uses_up_repeats_of_trigger() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+        let trigger_id: TriggerId = "call_me_twice".parse().expect("Valid");
+        let mut state_block = state.block();
+        let mut state_transaction = state_block.transaction();
+        register_callee(
+            &mut state_transaction,
+            &authority,
+            &trigger_id,
+            Repeats::Exactly(2),
+            1,
+        );
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new().build()?;
+
+        runtime
+            .execute(
+                &mut state_transaction,
+                authority.clone(),
+                caller_wat(&trigger_id, 1),
+            )
+            .expect("Trigger has repeats left");
+        assert!(state_transaction
+            .world
+            .triggers
+            .inspect_by_id(&trigger_id, |action| matches!(
+                action.repeats(),
+                Repeats::Exactly(1)
+            ))
+            .expect("Trigger should still exist"));
+        let res = runtime.execute(
+            &mut state_transaction,
+            authority,
+            caller_wat(&trigger_id, 2),
+        );
+
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
     #[test]
     async fn execute_query_exported() -> Result<(), Error> {
         let (authority, _authority_keypair) = gen_account_in("wonderland");
@@ -1907,6 +2399,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn fuel_limit_reached() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+
+        let wat = format!(
+            r#"
+            (module
+                {memory_and_alloc}
+
+                ;; Function which never returns
+                (func (export "{main_fn_name}") (param)
+                    (loop $forever (br $forever))))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            memory_and_alloc = memory_and_alloc(""),
+        );
+
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new()
+            .with_config(Config {
+                fuel_limit: 1_000,
+                ..Config::default()
+            })
+            .build()?;
+        let res = runtime.validate(&mut state.block().transaction(), authority, wat, 1);
+
+        assert!(matches!(
+            res.expect_err("Execution should fail"),
+            Error::ExportFnCall(ExportFnCallError::OutOfFuel(_))
+        ));
+
+        Ok(())
+    }
+
     #[test]
     async fn instructions_not_allowed() -> Result<(), Error> {
         let (authority, _authority_keypair) = gen_account_in("wonderland");
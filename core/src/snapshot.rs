@@ -100,6 +100,7 @@ impl SnapshotMaker {
                 Ok(Ok(())) => {
                     iroha_logger::info!(at_height, "Successfully created a snapshot of state");
                     self.latest_block_hash = latest_block_hash;
+                    self.prune_blocks(at_height).await;
                 }
                 Ok(Err(error)) => {
                     iroha_logger::error!(%error, "Failed to create a snapshot of state");
@@ -111,6 +112,24 @@ impl SnapshotMaker {
         }
     }
 
+    /// Discard block bodies which are covered by the latest snapshot,
+    /// if the peer isn't archival
+    async fn prune_blocks(&self, at_height: u64) {
+        let state = self.state.clone();
+        let handle =
+            tokio::task::spawn_blocking(move || state.view().kura().prune_blocks(at_height));
+
+        match handle.await {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => {
+                iroha_logger::error!(%error, "Failed to prune block bodies");
+            }
+            Err(panic) => {
+                iroha_logger::error!(%panic, "Task panicked during pruning of block bodies");
+            }
+        }
+    }
+
     /// Create from [`Config`].
     ///
     /// Might return [`None`] if the configuration is not suitable for _making_ snapshots.
@@ -6,25 +6,27 @@ use iroha_config::parameters::actual::ChainWide as Config;
 use iroha_crypto::HashOf;
 use iroha_data_model::{
     account::AccountId,
-    block::SignedBlock,
+    block::{BlockHeader, SignedBlock},
     events::{
         pipeline::BlockEvent,
         time::TimeEvent,
         trigger_completed::{TriggerCompletedEvent, TriggerCompletedOutcome},
-        EventBox,
+        EventBox, EventFilter,
     },
     executor::ExecutorDataModel,
-    isi::error::{InstructionExecutionError as Error, MathError},
+    isi::error::{InstructionExecutionError as Error, MathError, MintabilityError},
+    metadata::MetadataValueBox,
     parameter::{Parameter, ParameterValueBox},
     permission::Permissions,
     prelude::*,
     query::error::{FindError, QueryExecutionFail},
     role::RoleId,
+    transaction::TransactionPayload,
 };
 use iroha_logger::prelude::*;
 use iroha_primitives::{must_use::MustUse, numeric::Numeric, small::SmallVec};
 use parking_lot::Mutex;
-use range_bounds::RoleIdByAccountBounds;
+use range_bounds::{ReachedExpiryBounds, RoleIdByAccountBounds};
 use serde::{
     de::{DeserializeSeed, MapAccess, Visitor},
     Deserializer, Serialize,
@@ -58,6 +60,9 @@ use crate::{
     Parameters, PeersIds,
 };
 
+/// Maximum number of nested trigger calls, see [`StateTransaction::call_trigger`].
+pub const MAX_TRIGGER_CALL_DEPTH: u8 = 8;
+
 /// The global entity consisting of `domains`, `triggers` and etc.
 /// For example registration of domain, will have this as an ISI target.
 #[derive(Default, Serialize)]
@@ -68,12 +73,32 @@ pub struct World {
     pub(crate) trusted_peers_ids: Cell<PeersIds>,
     /// Registered domains.
     pub(crate) domains: Storage<DomainId, Domain>,
+    /// Owners of the tokens of [`AssetValueType::Nft`] asset definitions, rebuilt when a snapshot is loaded.
+    #[serde(skip)]
+    pub(crate) nft_owners: Storage<NftTokenKey, AccountId>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: Storage<RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: Storage<AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: Storage<RoleIdWithOwner, ()>,
+    /// Metadata entries which are removed once their expiry is reached.
+    pub(crate) metadata_expirations: Storage<RemoveKeyValueBox, ExpiringMetadataEntry>,
+    /// [`Self::metadata_expirations`] ordered by expiry, rebuilt when a snapshot is loaded.
+    #[serde(skip)]
+    pub(crate) metadata_expiry_index: Storage<ExpiryIndexKey<RemoveKeyValueBox>, ()>,
+    /// Asset amounts locked until they are released or refunded.
+    pub(crate) escrows: Storage<EscrowId, Escrow>,
+    /// The latest [`Swap`] offered by an account and not yet matched by its counterparty.
+    pub(crate) swap_offers: Storage<AccountId, Swap>,
+    /// Terms of the account permissions granted with them.
+    pub(crate) permission_grant_terms: Storage<Revoke<Permission, Account>, PermissionGrantEntry>,
+    /// [`Self::permission_grant_terms`] with an expiry ordered by it, rebuilt when a snapshot is loaded.
+    #[serde(skip)]
+    pub(crate) permission_expiry_index: Storage<ExpiryIndexKey<Revoke<Permission, Account>>, ()>,
+    /// Permissions and roles granted to and revoked from an account.
+    /// Only the latest `grant_history_max_len` changes of each account are kept.
+    pub(crate) grant_history: Storage<AccountId, Vec<AccountGrantAudit>>,
     /// Triggers
     pub(crate) triggers: TriggerSet,
     /// Runtime Executor
@@ -90,12 +115,29 @@ pub struct WorldBlock<'world> {
     pub(crate) trusted_peers_ids: CellBlock<'world, PeersIds>,
     /// Registered domains.
     pub(crate) domains: StorageBlock<'world, DomainId, Domain>,
+    pub(crate) nft_owners: StorageBlock<'world, NftTokenKey, AccountId>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageBlock<'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageBlock<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageBlock<'world, RoleIdWithOwner, ()>,
+    /// Metadata entries which are removed once their expiry is reached.
+    pub(crate) metadata_expirations: StorageBlock<'world, RemoveKeyValueBox, ExpiringMetadataEntry>,
+    /// [`World::metadata_expirations`] ordered by expiry.
+    pub(crate) metadata_expiry_index: StorageBlock<'world, ExpiryIndexKey<RemoveKeyValueBox>, ()>,
+    /// Asset amounts locked until they are released or refunded.
+    pub(crate) escrows: StorageBlock<'world, EscrowId, Escrow>,
+    /// The latest [`Swap`] offered by an account and not yet matched by its counterparty.
+    pub(crate) swap_offers: StorageBlock<'world, AccountId, Swap>,
+    /// Terms of the account permissions granted with them.
+    pub(crate) permission_grant_terms:
+        StorageBlock<'world, Revoke<Permission, Account>, PermissionGrantEntry>,
+    /// [`World::permission_grant_terms`] with an expiry ordered by it.
+    pub(crate) permission_expiry_index:
+        StorageBlock<'world, ExpiryIndexKey<Revoke<Permission, Account>>, ()>,
+    /// Permissions and roles granted to and revoked from an account.
+    pub(crate) grant_history: StorageBlock<'world, AccountId, Vec<AccountGrantAudit>>,
     /// Triggers
     pub(crate) triggers: TriggerSetBlock<'world>,
     /// Runtime Executor
@@ -103,7 +145,7 @@ pub struct WorldBlock<'world> {
     /// Executor-defined data model
     pub(crate) executor_data_model: CellBlock<'world, ExecutorDataModel>,
     /// Events produced during execution of block
-    events_buffer: Vec<EventBox>,
+    pub(crate) events_buffer: Vec<EventBox>,
 }
 
 /// Struct for single transaction's aggregated changes
@@ -114,12 +156,31 @@ pub struct WorldTransaction<'block, 'world> {
     pub(crate) trusted_peers_ids: CellTransaction<'block, 'world, PeersIds>,
     /// Registered domains.
     pub(crate) domains: StorageTransaction<'block, 'world, DomainId, Domain>,
+    pub(crate) nft_owners: StorageTransaction<'block, 'world, NftTokenKey, AccountId>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageTransaction<'block, 'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageTransaction<'block, 'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageTransaction<'block, 'world, RoleIdWithOwner, ()>,
+    /// Metadata entries which are removed once their expiry is reached.
+    pub(crate) metadata_expirations:
+        StorageTransaction<'block, 'world, RemoveKeyValueBox, ExpiringMetadataEntry>,
+    /// [`World::metadata_expirations`] ordered by expiry.
+    pub(crate) metadata_expiry_index:
+        StorageTransaction<'block, 'world, ExpiryIndexKey<RemoveKeyValueBox>, ()>,
+    /// Asset amounts locked until they are released or refunded.
+    pub(crate) escrows: StorageTransaction<'block, 'world, EscrowId, Escrow>,
+    /// The latest [`Swap`] offered by an account and not yet matched by its counterparty.
+    pub(crate) swap_offers: StorageTransaction<'block, 'world, AccountId, Swap>,
+    /// Terms of the account permissions granted with them.
+    pub(crate) permission_grant_terms:
+        StorageTransaction<'block, 'world, Revoke<Permission, Account>, PermissionGrantEntry>,
+    /// [`World::permission_grant_terms`] with an expiry ordered by it.
+    pub(crate) permission_expiry_index:
+        StorageTransaction<'block, 'world, ExpiryIndexKey<Revoke<Permission, Account>>, ()>,
+    /// Permissions and roles granted to and revoked from an account.
+    pub(crate) grant_history: StorageTransaction<'block, 'world, AccountId, Vec<AccountGrantAudit>>,
     /// Triggers
     pub(crate) triggers: TriggerSetTransaction<'block, 'world>,
     /// Runtime Executor
@@ -146,12 +207,29 @@ pub struct WorldView<'world> {
     pub(crate) trusted_peers_ids: CellView<'world, PeersIds>,
     /// Registered domains.
     pub(crate) domains: StorageView<'world, DomainId, Domain>,
+    pub(crate) nft_owners: StorageView<'world, NftTokenKey, AccountId>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageView<'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageView<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageView<'world, RoleIdWithOwner, ()>,
+    /// Metadata entries which are removed once their expiry is reached.
+    pub(crate) metadata_expirations: StorageView<'world, RemoveKeyValueBox, ExpiringMetadataEntry>,
+    /// [`World::metadata_expirations`] ordered by expiry.
+    pub(crate) metadata_expiry_index: StorageView<'world, ExpiryIndexKey<RemoveKeyValueBox>, ()>,
+    /// Asset amounts locked until they are released or refunded.
+    pub(crate) escrows: StorageView<'world, EscrowId, Escrow>,
+    /// The latest [`Swap`] offered by an account and not yet matched by its counterparty.
+    pub(crate) swap_offers: StorageView<'world, AccountId, Swap>,
+    /// Terms of the account permissions granted with them.
+    pub(crate) permission_grant_terms:
+        StorageView<'world, Revoke<Permission, Account>, PermissionGrantEntry>,
+    /// [`World::permission_grant_terms`] with an expiry ordered by it.
+    pub(crate) permission_expiry_index:
+        StorageView<'world, ExpiryIndexKey<Revoke<Permission, Account>>, ()>,
+    /// Permissions and roles granted to and revoked from an account.
+    pub(crate) grant_history: StorageView<'world, AccountId, Vec<AccountGrantAudit>>,
     /// Triggers
     pub(crate) triggers: TriggerSetView<'world>,
     /// Runtime Executor
@@ -160,6 +238,86 @@ pub struct WorldView<'world> {
     pub(crate) executor_data_model: CellView<'world, ExecutorDataModel>,
 }
 
+/// Metadata entry set by [`SetKeyValueWithExpiry`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiringMetadataEntry {
+    /// Account which set the entry.
+    pub authority: AccountId,
+    /// When the entry is removed.
+    pub expiry: MetadataExpiry,
+    /// Value the entry was set to. Entries changed since then are not removed.
+    pub value: MetadataValueBox,
+}
+
+/// Terms an account permission is granted on with [`GrantWithTerms`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionGrantEntry {
+    /// Account which granted the permission. The permission is revoked on its behalf.
+    pub granter: AccountId,
+    /// Terms the permission is granted on.
+    pub terms: PermissionGrantTerms,
+}
+
+/// Key of an index ordered by the expiry at which `key` is removed.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpiryIndexKey<K> {
+    expiry: MetadataExpiry,
+    key: K,
+}
+
+impl<K> ExpiryIndexKey<K> {
+    fn new(expiry: MetadataExpiry, key: K) -> Self {
+        Self { expiry, key }
+    }
+}
+
+/// Token of an [`AssetValueType::Nft`] asset definition.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NftTokenKey {
+    definition_id: AssetDefinitionId,
+    token_id: Name,
+}
+
+impl NftTokenKey {
+    pub(crate) fn new(definition_id: AssetDefinitionId, token_id: Name) -> Self {
+        Self {
+            definition_id,
+            token_id,
+        }
+    }
+}
+
+/// Index of the owners of the tokens of [`AssetValueType::Nft`] assets held in `domains`.
+fn index_nft_owners(
+    domains: &impl StorageReadOnly<DomainId, Domain>,
+) -> Storage<NftTokenKey, AccountId> {
+    let is_nft = |definition_id: &AssetDefinitionId| {
+        domains
+            .get(&definition_id.domain_id)
+            .and_then(|domain| domain.asset_definitions.get(definition_id))
+            .is_some_and(|definition| definition.value_type == AssetValueType::Nft)
+    };
+
+    domains
+        .iter()
+        .flat_map(|(_, domain)| domain.accounts.values())
+        .flat_map(|account| account.assets.values())
+        .filter(|asset| is_nft(&asset.id.definition_id))
+        .filter_map(|asset| match &asset.value {
+            AssetValue::Store(tokens) => Some((&asset.id, tokens)),
+            AssetValue::Numeric(_) => None,
+        })
+        .flat_map(|(asset_id, tokens)| {
+            tokens.keys().map(move |token_id| {
+                (
+                    NftTokenKey::new(asset_id.definition_id.clone(), token_id.clone()),
+                    asset_id.account_id.clone(),
+                )
+            })
+        })
+        .collect()
+}
+
 /// Current state of the blockchain
 #[derive(Serialize)]
 pub struct State {
@@ -172,6 +330,9 @@ pub struct State {
     pub block_hashes: Cell<Vec<HashOf<SignedBlock>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: Storage<HashOf<SignedTransaction>, u64>,
+    /// Hashes of transaction payloads mapped onto block height where they stored.
+    /// Transactions are told apart by their payloads to reject replays carrying other signatures.
+    pub transaction_payloads: Storage<HashOf<TransactionPayload>, u64>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     #[serde(skip)]
     pub engine: wasmtime::Engine,
@@ -198,6 +359,9 @@ pub struct StateBlock<'state> {
     pub block_hashes: CellBlock<'state, Vec<HashOf<SignedBlock>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageBlock<'state, HashOf<SignedTransaction>, u64>,
+    /// Hashes of transaction payloads mapped onto block height where they stored.
+    /// Transactions are told apart by their payloads to reject replays carrying other signatures.
+    pub transaction_payloads: StorageBlock<'state, HashOf<TransactionPayload>, u64>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
 
@@ -208,6 +372,9 @@ pub struct StateBlock<'state> {
     /// Temporary metrics buffer of amounts of any asset that has been transacted.
     /// TODO: this should be done through events
     pub new_tx_amounts: &'state Mutex<Vec<f64>>,
+    /// Messages logged by WASM, captured only if set.
+    /// Unlike events, these are kept even if the transaction fails.
+    pub(crate) wasm_logs: Option<Vec<Log>>,
 }
 
 /// Struct for single transaction's aggregated changes
@@ -220,6 +387,9 @@ pub struct StateTransaction<'block, 'state> {
     pub block_hashes: CellTransaction<'block, 'state, Vec<HashOf<SignedBlock>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageTransaction<'block, 'state, HashOf<SignedTransaction>, u64>,
+    /// Hashes of transaction payloads mapped onto block height where they stored.
+    /// Transactions are told apart by their payloads to reject replays carrying other signatures.
+    pub transaction_payloads: StorageTransaction<'block, 'state, HashOf<TransactionPayload>, u64>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
 
@@ -230,6 +400,12 @@ pub struct StateTransaction<'block, 'state> {
     /// Temporary metrics buffer of amounts of any asset that has been transacted.
     /// TODO: this should be done through events
     pub new_tx_amounts: &'state Mutex<Vec<f64>>,
+    /// Messages logged by WASM, captured only if set.
+    pub(crate) wasm_logs: Option<&'block mut Vec<Log>>,
+    /// Hash of the transaction being executed, if any.
+    pub(crate) transaction_hash: Option<HashOf<SignedTransaction>>,
+    /// Number of trigger calls currently in progress, see [`StateTransaction::call_trigger`].
+    pub(crate) call_depth: u8,
 }
 
 /// Consistent point in time view of the [`State`]
@@ -242,6 +418,9 @@ pub struct StateView<'state> {
     pub block_hashes: CellView<'state, Vec<HashOf<SignedBlock>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageView<'state, HashOf<SignedTransaction>, u64>,
+    /// Hashes of transaction payloads mapped onto block height where they stored.
+    /// Transactions are told apart by their payloads to reject replays carrying other signatures.
+    pub transaction_payloads: StorageView<'state, HashOf<TransactionPayload>, u64>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
 
@@ -268,9 +447,10 @@ impl World {
         let domains = domains
             .into_iter()
             .map(|domain| (domain.id().clone(), domain))
-            .collect();
+            .collect::<Storage<_, _>>();
         World {
             trusted_peers_ids: Cell::new(trusted_peers_ids),
+            nft_owners: index_nft_owners(&domains.view()),
             domains,
             ..World::new()
         }
@@ -282,9 +462,17 @@ impl World {
             parameters: self.parameters.block(),
             trusted_peers_ids: self.trusted_peers_ids.block(),
             domains: self.domains.block(),
+            nft_owners: self.nft_owners.block(),
             roles: self.roles.block(),
             account_permissions: self.account_permissions.block(),
             account_roles: self.account_roles.block(),
+            metadata_expirations: self.metadata_expirations.block(),
+            metadata_expiry_index: self.metadata_expiry_index.block(),
+            escrows: self.escrows.block(),
+            swap_offers: self.swap_offers.block(),
+            permission_grant_terms: self.permission_grant_terms.block(),
+            permission_expiry_index: self.permission_expiry_index.block(),
+            grant_history: self.grant_history.block(),
             triggers: self.triggers.block(),
             executor: self.executor.block(),
             executor_data_model: self.executor_data_model.block(),
@@ -298,9 +486,17 @@ impl World {
             parameters: self.parameters.block_and_revert(),
             trusted_peers_ids: self.trusted_peers_ids.block_and_revert(),
             domains: self.domains.block_and_revert(),
+            nft_owners: self.nft_owners.block_and_revert(),
             roles: self.roles.block_and_revert(),
             account_permissions: self.account_permissions.block_and_revert(),
             account_roles: self.account_roles.block_and_revert(),
+            metadata_expirations: self.metadata_expirations.block_and_revert(),
+            metadata_expiry_index: self.metadata_expiry_index.block_and_revert(),
+            escrows: self.escrows.block_and_revert(),
+            swap_offers: self.swap_offers.block_and_revert(),
+            permission_grant_terms: self.permission_grant_terms.block_and_revert(),
+            permission_expiry_index: self.permission_expiry_index.block_and_revert(),
+            grant_history: self.grant_history.block_and_revert(),
             triggers: self.triggers.block_and_revert(),
             executor: self.executor.block_and_revert(),
             executor_data_model: self.executor_data_model.block_and_revert(),
@@ -314,9 +510,17 @@ impl World {
             parameters: self.parameters.view(),
             trusted_peers_ids: self.trusted_peers_ids.view(),
             domains: self.domains.view(),
+            nft_owners: self.nft_owners.view(),
             roles: self.roles.view(),
             account_permissions: self.account_permissions.view(),
             account_roles: self.account_roles.view(),
+            metadata_expirations: self.metadata_expirations.view(),
+            metadata_expiry_index: self.metadata_expiry_index.view(),
+            escrows: self.escrows.view(),
+            swap_offers: self.swap_offers.view(),
+            permission_grant_terms: self.permission_grant_terms.view(),
+            permission_expiry_index: self.permission_expiry_index.view(),
+            grant_history: self.grant_history.view(),
             triggers: self.triggers.view(),
             executor: self.executor.view(),
             executor_data_model: self.executor_data_model.view(),
@@ -330,9 +534,14 @@ pub trait WorldReadOnly {
     fn parameters(&self) -> &Parameters;
     fn trusted_peers_ids(&self) -> &PeersIds;
     fn domains(&self) -> &impl StorageReadOnly<DomainId, Domain>;
+    fn nft_owners(&self) -> &impl StorageReadOnly<NftTokenKey, AccountId>;
     fn roles(&self) -> &impl StorageReadOnly<RoleId, Role>;
     fn account_permissions(&self) -> &impl StorageReadOnly<AccountId, Permissions>;
     fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()>;
+    fn permission_grant_terms(
+        &self,
+    ) -> &impl StorageReadOnly<Revoke<Permission, Account>, PermissionGrantEntry>;
+    fn grant_history(&self) -> &impl StorageReadOnly<AccountId, Vec<AccountGrantAudit>>;
     fn triggers(&self) -> &impl TriggerSetReadOnly;
     fn executor(&self) -> &Executor;
     fn executor_data_model(&self) -> &ExecutorDataModel;
@@ -351,6 +560,20 @@ pub trait WorldReadOnly {
         Ok(domain)
     }
 
+    /// Get the metadata limits of the `Domain`, taking the ones which are not set from its ancestors.
+    ///
+    /// # Errors
+    /// Fails if there is no domain
+    fn domain_metadata_limits(&self, id: &DomainId) -> Result<DomainMetadataLimits, FindError> {
+        let limits = self.domain(id)?.metadata_limits;
+        Ok(id
+            .ancestors()
+            .filter_map(|ancestor_id| self.domains().get(&ancestor_id))
+            .fold(limits, |limits, ancestor| {
+                limits.inheriting(ancestor.metadata_limits)
+            }))
+    }
+
     /// Get `Domain` and pass it to closure.
     ///
     /// # Errors
@@ -570,6 +793,9 @@ macro_rules! impl_world_ro {
             fn domains(&self) -> &impl StorageReadOnly<DomainId, Domain> {
                 &self.domains
             }
+            fn nft_owners(&self) -> &impl StorageReadOnly<NftTokenKey, AccountId> {
+                &self.nft_owners
+            }
             fn roles(&self) -> &impl StorageReadOnly<RoleId, Role> {
                 &self.roles
             }
@@ -579,6 +805,14 @@ macro_rules! impl_world_ro {
             fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()> {
                 &self.account_roles
             }
+            fn permission_grant_terms(
+                &self,
+            ) -> &impl StorageReadOnly<Revoke<Permission, Account>, PermissionGrantEntry> {
+                &self.permission_grant_terms
+            }
+            fn grant_history(&self) -> &impl StorageReadOnly<AccountId, Vec<AccountGrantAudit>> {
+                &self.grant_history
+            }
             fn triggers(&self) -> &impl TriggerSetReadOnly {
                 &self.triggers
             }
@@ -603,9 +837,17 @@ impl<'world> WorldBlock<'world> {
             parameters: self.parameters.transaction(),
             trusted_peers_ids: self.trusted_peers_ids.transaction(),
             domains: self.domains.transaction(),
+            nft_owners: self.nft_owners.transaction(),
             roles: self.roles.transaction(),
             account_permissions: self.account_permissions.transaction(),
             account_roles: self.account_roles.transaction(),
+            metadata_expirations: self.metadata_expirations.transaction(),
+            metadata_expiry_index: self.metadata_expiry_index.transaction(),
+            escrows: self.escrows.transaction(),
+            swap_offers: self.swap_offers.transaction(),
+            permission_grant_terms: self.permission_grant_terms.transaction(),
+            permission_expiry_index: self.permission_expiry_index.transaction(),
+            grant_history: self.grant_history.transaction(),
             triggers: self.triggers.transaction(),
             executor: self.executor.transaction(),
             executor_data_model: self.executor_data_model.transaction(),
@@ -622,9 +864,17 @@ impl<'world> WorldBlock<'world> {
         self.executor_data_model.commit();
         self.executor.commit();
         self.triggers.commit();
+        self.grant_history.commit();
+        self.permission_expiry_index.commit();
+        self.permission_grant_terms.commit();
+        self.swap_offers.commit();
+        self.escrows.commit();
+        self.metadata_expiry_index.commit();
+        self.metadata_expirations.commit();
         self.account_roles.commit();
         self.account_permissions.commit();
         self.roles.commit();
+        self.nft_owners.commit();
         self.domains.commit();
         self.trusted_peers_ids.commit();
         self.parameters.commit();
@@ -637,9 +887,17 @@ impl WorldTransaction<'_, '_> {
         self.executor_data_model.apply();
         self.executor.apply();
         self.triggers.apply();
+        self.grant_history.apply();
+        self.permission_expiry_index.apply();
+        self.permission_grant_terms.apply();
+        self.swap_offers.apply();
+        self.escrows.apply();
+        self.metadata_expiry_index.apply();
+        self.metadata_expirations.apply();
         self.account_roles.apply();
         self.account_permissions.apply();
         self.roles.apply();
+        self.nft_owners.apply();
         self.domains.apply();
         self.trusted_peers_ids.apply();
         self.parameters.apply();
@@ -700,6 +958,83 @@ impl WorldTransaction<'_, '_> {
             .map_or(false, |permissions| permissions.remove(token))
     }
 
+    /// Record `owner` as the holder of the token `token_id` of an [`AssetValueType::Nft`] asset definition.
+    pub(crate) fn set_nft_owner(
+        &mut self,
+        definition_id: AssetDefinitionId,
+        token_id: Name,
+        owner: AccountId,
+    ) {
+        self.nft_owners
+            .insert(NftTokenKey::new(definition_id, token_id), owner);
+    }
+
+    /// Forget the owner of the tokens of the removed `asset`.
+    /// Tokens of store assets aren't indexed, so they are skipped.
+    pub(crate) fn remove_nft_owners(&mut self, asset: &Asset) {
+        let AssetValue::Store(tokens) = &asset.value else {
+            return;
+        };
+        for token_id in tokens.keys() {
+            let key = NftTokenKey::new(asset.id.definition_id.clone(), token_id.clone());
+            if self.nft_owners.get(&key) == Some(&asset.id.account_id) {
+                self.nft_owners.remove(key);
+            }
+        }
+    }
+
+    /// Schedule the removal of a metadata entry at its expiry, replacing the previously scheduled one.
+    pub(crate) fn schedule_metadata_expiration(
+        &mut self,
+        removal: RemoveKeyValueBox,
+        entry: ExpiringMetadataEntry,
+    ) {
+        self.take_metadata_expiration(&removal);
+        self.metadata_expiry_index
+            .insert(ExpiryIndexKey::new(entry.expiry, removal.clone()), ());
+        self.metadata_expirations.insert(removal, entry);
+    }
+
+    /// Cancel the scheduled removal of a metadata entry, returning it if it was scheduled.
+    pub(crate) fn take_metadata_expiration(
+        &mut self,
+        removal: &RemoveKeyValueBox,
+    ) -> Option<ExpiringMetadataEntry> {
+        let entry = self.metadata_expirations.get(removal).cloned()?;
+        self.metadata_expiry_index
+            .remove(ExpiryIndexKey::new(entry.expiry, removal.clone()));
+        self.metadata_expirations.remove(removal.clone());
+        Some(entry)
+    }
+
+    /// Set the terms a permission is granted to an account on, replacing the previous ones.
+    pub(crate) fn insert_permission_grant_terms(
+        &mut self,
+        revoke: Revoke<Permission, Account>,
+        entry: PermissionGrantEntry,
+    ) {
+        self.remove_permission_grant_terms(&revoke);
+        if let Some(expiry) = entry.terms.expiry {
+            self.permission_expiry_index
+                .insert(ExpiryIndexKey::new(expiry, revoke.clone()), ());
+        }
+        self.permission_grant_terms.insert(revoke, entry);
+    }
+
+    /// Remove the terms a permission is granted to an account on, returning them if there were any.
+    pub(crate) fn remove_permission_grant_terms(
+        &mut self,
+        revoke: &Revoke<Permission, Account>,
+    ) -> Option<PermissionGrantEntry> {
+        let entry = self.permission_grant_terms.get(revoke).cloned()?;
+        if let Some(expiry) = entry.terms.expiry {
+            self.permission_expiry_index
+                .remove(ExpiryIndexKey::new(expiry, revoke.clone()));
+        }
+        self.permission_grant_terms.remove(revoke.clone());
+        Some(entry)
+    }
+
     /// Get mutable reference to [`Asset`]
     ///
     /// # Errors
@@ -785,12 +1120,21 @@ impl WorldTransaction<'_, '_> {
         increment: Numeric,
     ) -> Result<(), Error> {
         let domain = self.domain_mut(&definition_id.domain_id)?;
+        let max_supply = domain
+            .asset_definitions
+            .get(definition_id)
+            .ok_or_else(|| FindError::AssetDefinition(definition_id.clone()))?
+            .max_supply;
         let asset_total_amount: &mut Numeric = domain
             .asset_total_quantities.get_mut(definition_id)
             .expect("Asset total amount not being found is a bug: check `Register<AssetDefinition>` to insert initial total amount");
-        *asset_total_amount = asset_total_amount
+        let new_total_amount = asset_total_amount
             .checked_add(increment)
             .ok_or(MathError::Overflow)?;
+        if max_supply.is_some_and(|max_supply| new_total_amount > max_supply) {
+            return Err(MintabilityError::MaxSupplyExceeded.into());
+        }
+        *asset_total_amount = new_total_amount;
         let asset_total_amount = *asset_total_amount;
 
         self.emit_events({
@@ -933,6 +1277,7 @@ impl State {
             world,
             config: Cell::new(config),
             transactions: Storage::new(),
+            transaction_payloads: Storage::new(),
             block_hashes: Cell::new(Vec::new()),
             new_tx_amounts: Arc::new(Mutex::new(Vec::new())),
             engine: wasm::create_engine(),
@@ -948,10 +1293,12 @@ impl State {
             config: self.config.block(),
             block_hashes: self.block_hashes.block(),
             transactions: self.transactions.block(),
+            transaction_payloads: self.transaction_payloads.block(),
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
             new_tx_amounts: &self.new_tx_amounts,
+            wasm_logs: None,
         }
     }
 
@@ -962,10 +1309,12 @@ impl State {
             config: self.config.block_and_revert(),
             block_hashes: self.block_hashes.block_and_revert(),
             transactions: self.transactions.block_and_revert(),
+            transaction_payloads: self.transaction_payloads.block_and_revert(),
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
             new_tx_amounts: &self.new_tx_amounts,
+            wasm_logs: None,
         }
     }
 
@@ -976,12 +1325,46 @@ impl State {
             config: self.config.view(),
             block_hashes: self.block_hashes.view(),
             transactions: self.transactions.view(),
+            transaction_payloads: self.transaction_payloads.view(),
             engine: &self.engine,
             kura: &self.kura,
             query_handle: &self.query_handle,
             new_tx_amounts: &self.new_tx_amounts,
         }
     }
+
+    /// Check whether an account is permitted to execute an instruction, as requested by `request`.
+    ///
+    /// # Errors
+    /// Fails if the request isn't signed by its requester or if the executor
+    /// doesn't allow the requester to read the permissions of the checked account
+    pub fn check_execution(
+        &self,
+        request: SignedCanAccountExecute,
+    ) -> Result<ExecutionVerdict, ValidationFail> {
+        let SignedCanAccountExecute {
+            check,
+            requester,
+            signature,
+        } = request;
+        if !requester.signatory_matches(signature.public_key()) || signature.verify(&check).is_err()
+        {
+            return Err(QueryExecutionFail::Signature(String::from(
+                "Signature doesn't correspond to the requester.",
+            ))
+            .into());
+        }
+        let state_view = self.view();
+        state_view.world.executor.validate_query(
+            &state_view,
+            &requester,
+            FindPermissionsByAccountId::new(check.account_id.clone()).into(),
+        )?;
+        drop(state_view);
+
+        // The block is dropped without being committed, so none of the changes persist
+        Ok(self.block().check_execution(check))
+    }
 }
 
 /// Trait to perform read-only operations on [`StateBlock`], [`StateTransaction`] and [`StateView`]
@@ -991,6 +1374,7 @@ pub trait StateReadOnly {
     fn config(&self) -> &Config;
     fn block_hashes(&self) -> &[HashOf<SignedBlock>];
     fn transactions(&self) -> &impl StorageReadOnly<HashOf<SignedTransaction>, u64>;
+    fn transaction_payloads(&self) -> &impl StorageReadOnly<HashOf<TransactionPayload>, u64>;
     fn engine(&self) -> &wasmtime::Engine;
     fn kura(&self) -> &Kura;
     fn query_handle(&self) -> &LiveQueryStoreHandle;
@@ -1022,13 +1406,19 @@ pub trait StateReadOnly {
         self.block_hashes().iter().nth_back(1).copied()
     }
 
-    /// Load all blocks in the block chain from disc
+    /// Load all blocks in the block chain from disc, skipping blocks whose bodies were pruned
     fn all_blocks(&self) -> impl DoubleEndedIterator<Item = Arc<SignedBlock>> + '_ {
+        let block_count = self.block_hashes().len() as u64;
+        (1..=block_count).filter_map(|height| self.kura().get_block_by_height(height))
+    }
+
+    /// Load headers of all blocks in the block chain from disc, including pruned blocks
+    fn all_block_headers(&self) -> impl DoubleEndedIterator<Item = BlockHeader> + '_ {
         let block_count = self.block_hashes().len() as u64;
         (1..=block_count).map(|height| {
             self.kura()
-                .get_block_by_height(height)
-                .expect("Failed to load block.")
+                .get_block_header_by_height(height)
+                .expect("Failed to load block header.")
         })
     }
 
@@ -1080,8 +1470,8 @@ pub trait StateReadOnly {
         } else {
             let opt = self
                 .kura()
-                .get_block_by_height(1)
-                .map(|genesis_block| genesis_block.header().timestamp());
+                .get_block_header_by_height(1)
+                .map(|genesis_header| genesis_header.timestamp());
 
             if opt.is_none() {
                 error!("Failed to get genesis block from Kura.");
@@ -1096,6 +1486,13 @@ pub trait StateReadOnly {
         self.transactions().get(&hash).is_some()
     }
 
+    /// Check if a [`SignedTransaction`] with this payload is already committed or rejected,
+    /// whatever signatures it carries.
+    #[inline]
+    fn has_transaction_payload(&self, hash: HashOf<TransactionPayload>) -> bool {
+        self.transaction_payloads().get(&hash).is_some()
+    }
+
     /// Get transaction executor
     fn transaction_executor(&self) -> TransactionExecutor {
         TransactionExecutor::new(self.config().transaction_limits)
@@ -1117,6 +1514,11 @@ macro_rules! impl_state_ro {
             fn transactions(&self) -> &impl StorageReadOnly<HashOf<SignedTransaction>, u64> {
                 &self.transactions
             }
+            fn transaction_payloads(
+                &self,
+            ) -> &impl StorageReadOnly<HashOf<TransactionPayload>, u64> {
+                &self.transaction_payloads
+            }
             fn engine(&self) -> &wasmtime::Engine {
                 &self.engine
             }
@@ -1145,21 +1547,54 @@ impl<'state> StateBlock<'state> {
             config: self.config.transaction(),
             block_hashes: self.block_hashes.transaction(),
             transactions: self.transactions.transaction(),
+            transaction_payloads: self.transaction_payloads.transaction(),
             engine: self.engine,
             kura: self.kura,
             query_handle: self.query_handle,
             new_tx_amounts: self.new_tx_amounts,
+            wasm_logs: self.wasm_logs.as_mut(),
+            transaction_hash: None,
+            call_depth: 0,
         }
     }
 
     /// Commit changes aggregated during application of block
     pub fn commit(self) {
         self.transactions.commit();
+        self.transaction_payloads.commit();
         self.block_hashes.commit();
         self.config.commit();
         self.world.commit();
     }
 
+    /// Check whether the account of `check` is permitted to execute its instruction.
+    ///
+    /// The instruction is executed if the executor allows it,
+    /// so `self` is meant to be dropped afterwards without being committed.
+    pub fn check_execution(&mut self, check: CanAccountExecute) -> ExecutionVerdict {
+        let CanAccountExecute {
+            account_id,
+            instruction,
+        } = check;
+        let permissions = self
+            .world
+            .account_permissions_iter(&account_id)
+            .map_or_else(|_| Vec::new(), |permissions| permissions.cloned().collect());
+
+        let mut transaction = self.transaction();
+        let denial = transaction
+            .world
+            .executor
+            .clone() // Cloning executor is a cheap operation
+            .validate_instruction(&mut transaction, &account_id, instruction)
+            .err();
+
+        ExecutionVerdict {
+            denial,
+            permissions,
+        }
+    }
+
     /// Commit `CommittedBlock` with changes in form of **Iroha Special
     /// Instructions** to `self`.
     ///
@@ -1221,9 +1656,10 @@ impl<'state> StateBlock<'state> {
             .as_ref()
             .transactions()
             .map(|tx| &tx.value)
-            .map(SignedTransaction::hash)
-            .for_each(|tx_hash| {
-                self.transactions.insert(tx_hash, block_height);
+            .for_each(|tx| {
+                self.transactions.insert(tx.hash(), block_height);
+                self.transaction_payloads
+                    .insert(tx.payload_hash(), block_height);
             });
 
         self.world.triggers.handle_time_event(time_event);
@@ -1237,9 +1673,22 @@ impl<'state> StateBlock<'state> {
             );
         }
 
+        self.remove_expired_metadata(block_height, block.as_ref().header().timestamp_ms);
+        self.revoke_expired_permissions(block_height, block.as_ref().header().timestamp_ms);
+
         self.block_hashes.push(block_hash);
 
         self.apply_parameters();
+        self.world
+            .events_buffer
+            .extend(block.as_ref().transactions().map(|tx| {
+                TransactionEvent {
+                    hash: tx.as_ref().hash(),
+                    block_height: Some(block_height),
+                    status: TransactionStatus::Committed,
+                }
+                .into()
+            }));
         self.world.events_buffer.push(
             BlockEvent {
                 header: block.as_ref().header().clone(),
@@ -1248,9 +1697,149 @@ impl<'state> StateBlock<'state> {
             }
             .into(),
         );
+        self.index_metadata_history(block_height);
+        self.kura.index_transaction_authorities(
+            block_height,
+            block
+                .as_ref()
+                .transactions()
+                .map(|tx| tx.as_ref().authority()),
+        );
         core::mem::take(&mut self.world.events_buffer)
     }
 
+    /// Remove the metadata entries whose expiry is reached by the block.
+    fn remove_expired_metadata(&mut self, block_height: u64, block_timestamp_ms: u64) {
+        let index = &self.world.metadata_expiry_index;
+        let expired: Vec<_> =
+            ReachedExpiryBounds::<RemoveKeyValueBox>::new(block_height, block_timestamp_ms)
+                .into_iter()
+                .flat_map(move |bounds| index.range(bounds))
+                .map(|(expiring, ())| expiring.key.clone())
+                .collect();
+        if expired.is_empty() {
+            return;
+        }
+
+        let mut transaction = self.transaction();
+        for removal in expired {
+            let Some(entry) = transaction.world.take_metadata_expiration(&removal) else {
+                continue;
+            };
+            if metadata_entry_value(&transaction.world, &removal).as_ref() != Some(&entry.value) {
+                // Changed or removed since it was set with the expiry
+                continue;
+            }
+            if let Err(error) = removal.execute(&entry.authority, &mut transaction) {
+                warn!(%error, "Failed to remove expired metadata entry");
+            }
+        }
+        transaction.apply();
+    }
+
+    /// Revoke the account permissions whose expiry is reached by the block.
+    fn revoke_expired_permissions(&mut self, block_height: u64, block_timestamp_ms: u64) {
+        let index = &self.world.permission_expiry_index;
+        let expired: Vec<_> = ReachedExpiryBounds::<Revoke<Permission, Account>>::new(
+            block_height,
+            block_timestamp_ms,
+        )
+        .into_iter()
+        .flat_map(move |bounds| index.range(bounds))
+        .map(|(expiring, ())| expiring.key.clone())
+        .collect();
+        if expired.is_empty() {
+            return;
+        }
+
+        let mut transaction = self.transaction();
+        for revoke in expired {
+            let Some(entry) = transaction.world.remove_permission_grant_terms(&revoke) else {
+                continue;
+            };
+            if !transaction
+                .world
+                .account_contains_inherent_permission(&revoke.destination_id, &revoke.object)
+            {
+                continue;
+            }
+            if let Err(error) = revoke.execute(&entry.granter, &mut transaction) {
+                warn!(%error, "Failed to revoke expired permission");
+            }
+        }
+        transaction.apply();
+    }
+
+    /// Record the metadata entries changed by the block in [`Kura`]'s metadata history.
+    ///
+    /// Changes of nested values are recorded as changes of the top-level entry containing them.
+    fn index_metadata_history(&self, block_height: u64) {
+        if !self.kura.indexes_metadata_history() {
+            return;
+        }
+
+        let changed_entries: BTreeSet<RemoveKeyValueBox> = self
+            .world
+            .events_buffer
+            .iter()
+            .filter_map(|event| {
+                let EventBox::Data(event) = event else {
+                    return None;
+                };
+                let entry: RemoveKeyValueBox = match event {
+                    DataEvent::Domain(DomainEvent::MetadataInserted(changed))
+                    | DataEvent::Domain(DomainEvent::MetadataRemoved(changed)) => {
+                        RemoveKeyValue::domain(
+                            changed.target_id.clone(),
+                            changed.path.first()?.clone(),
+                        )
+                        .into()
+                    }
+                    DataEvent::Domain(DomainEvent::Account(
+                        AccountEvent::MetadataInserted(changed)
+                        | AccountEvent::MetadataRemoved(changed),
+                    )) => RemoveKeyValue::account(
+                        changed.target_id.clone(),
+                        changed.path.first()?.clone(),
+                    )
+                    .into(),
+                    DataEvent::Domain(DomainEvent::AssetDefinition(
+                        AssetDefinitionEvent::MetadataInserted(changed)
+                        | AssetDefinitionEvent::MetadataRemoved(changed),
+                    )) => RemoveKeyValue::asset_definition(
+                        changed.target_id.clone(),
+                        changed.path.first()?.clone(),
+                    )
+                    .into(),
+                    DataEvent::Domain(DomainEvent::Account(AccountEvent::Asset(
+                        AssetEvent::MetadataInserted(changed)
+                        | AssetEvent::MetadataRemoved(changed),
+                    ))) => RemoveKeyValue::asset(
+                        changed.target_id.clone(),
+                        changed.path.first()?.clone(),
+                    )
+                    .into(),
+                    DataEvent::Trigger(
+                        TriggerEvent::MetadataInserted(changed)
+                        | TriggerEvent::MetadataRemoved(changed),
+                    ) => RemoveKeyValue::trigger(
+                        changed.target_id.clone(),
+                        changed.path.first()?.clone(),
+                    )
+                    .into(),
+                    _ => return None,
+                };
+                Some(entry)
+            })
+            .collect();
+
+        let changes = changed_entries.into_iter().map(|entry| {
+            let value = metadata_entry_value(&self.world, &entry);
+            (entry, value)
+        });
+        self.kura.index_metadata_changes(block_height, changes);
+    }
+
     /// Create time event using previous and current blocks
     fn create_time_event(&self, block: &CommittedBlock) -> TimeEvent {
         use iroha_config::parameters::defaults::chain_wide::CONSENSUS_ESTIMATION as DEFAULT_CONSENSUS_ESTIMATION;
@@ -1280,6 +1869,7 @@ impl<'state> StateBlock<'state> {
         // Cloning and clearing `self.matched_ids` so that `handle_` call won't deadlock
         let matched_ids = self.world.triggers.extract_matched_ids();
         let mut succeed = Vec::<TriggerId>::with_capacity(matched_ids.len());
+        let mut spent_instructions = Vec::new();
         let mut errors = Vec::new();
         for (event, id) in matched_ids {
             // Eliding the closure triggers a lifetime mismatch
@@ -1294,10 +1884,16 @@ impl<'state> StateBlock<'state> {
                         continue;
                     }
                 }
+                let mut limits_executor = wasm::LimitsExecutor::for_trigger(action.limits());
                 // Execute every trigger in it's own transaction
                 let event = {
                     let mut transaction = self.transaction();
-                    match transaction.process_trigger(&id, &action, event) {
+                    let result =
+                        transaction.process_trigger(&id, &action, event, &mut limits_executor);
+                    if action.limits().instruction_budget.is_some() {
+                        spent_instructions.push((id.clone(), limits_executor.instruction_count()));
+                    }
+                    match result {
                         Ok(()) => {
                             transaction.apply();
                             succeed.push(id.clone());
@@ -1318,6 +1914,10 @@ impl<'state> StateBlock<'state> {
         }
 
         let mut transaction = self.transaction();
+        transaction
+            .world
+            .triggers
+            .spend_instruction_budgets(&spent_instructions);
         transaction.world.triggers.decrease_repeats(&succeed);
         transaction.apply();
 
@@ -1347,14 +1947,61 @@ impl<'state> StateBlock<'state> {
             WASM_FUEL_LIMIT => self.config.wasm_runtime.fuel_limit,
             WASM_MAX_MEMORY => self.config.wasm_runtime.max_memory_bytes,
             TRANSACTION_LIMITS => self.config.transaction_limits,
+            GRANT_HISTORY_MAX_LEN => self.config.grant_history_max_len,
         }
     }
 }
 
+/// Current value of the metadata entry which `removal` would remove.
+pub(crate) fn metadata_entry_value(
+    world: &impl WorldReadOnly,
+    removal: &RemoveKeyValueBox,
+) -> Option<MetadataValueBox> {
+    match removal {
+        RemoveKeyValueBox::Domain(isi) => world
+            .domain(&isi.object_id)
+            .ok()?
+            .metadata
+            .get(&isi.key)
+            .cloned(),
+        RemoveKeyValueBox::Account(isi) => world
+            .account(&isi.object_id)
+            .ok()?
+            .metadata
+            .get(&isi.key)
+            .cloned(),
+        RemoveKeyValueBox::AssetDefinition(isi) => world
+            .asset_definition(&isi.object_id)
+            .ok()?
+            .metadata
+            .get(&isi.key)
+            .cloned(),
+        RemoveKeyValueBox::Asset(isi) => {
+            let value_type = world
+                .asset_definition(&isi.object_id.definition_id)
+                .ok()?
+                .value_type;
+            world
+                .asset(&isi.object_id)
+                .ok()?
+                .key_values(value_type)
+                .get(&isi.key)
+                .cloned()
+        }
+        RemoveKeyValueBox::Trigger(isi) => world
+            .triggers()
+            .inspect_by_id(&isi.object_id, |action| {
+                action.metadata().get(&isi.key).cloned()
+            })
+            .flatten(),
+    }
+}
+
 impl StateTransaction<'_, '_> {
     /// Apply transaction making it's changes visible
     pub fn apply(self) {
         self.transactions.apply();
+        self.transaction_payloads.apply();
         self.block_hashes.apply();
         self.config.apply();
         self.world.apply();
@@ -1374,6 +2021,9 @@ impl StateTransaction<'_, '_> {
                     .execute(self, authority, bytes)
                     .map_err(Into::into)
             }
+            Executable::WasmCode(_) => {
+                eyre::bail!("Only triggers can refer to registered WASM code")
+            }
         }
     }
 
@@ -1388,18 +2038,164 @@ impl StateTransaction<'_, '_> {
         })
     }
 
+    /// Execute the transaction validation triggers matched by `transaction`
+    /// before the transaction is applied.
+    ///
+    /// Changes made by the triggers, as well as their spent repetitions,
+    /// are applied only together with the transaction.
+    ///
+    /// # Errors
+    /// Fails if any of the triggers fails, vetoing the transaction
+    pub fn execute_validation_triggers(
+        &mut self,
+        transaction: &SignedTransaction,
+    ) -> Result<(), ValidationTriggerFail> {
+        let event = TransactionValidationEvent::new(transaction.clone());
+        for id in self
+            .world
+            .triggers
+            .match_transaction_validation_event(&event)
+        {
+            // Eliding the closure triggers a lifetime mismatch
+            #[allow(clippy::redundant_closure_for_method_calls)]
+            let action = self
+                .world
+                .triggers
+                .inspect_by_id(&id, |action| action.clone_and_box())
+                .expect("Trigger is matched above");
+
+            let mut limits_executor = wasm::LimitsExecutor::for_trigger(action.limits());
+            let result =
+                self.process_trigger(&id, &action, event.clone().into(), &mut limits_executor);
+            if action.limits().instruction_budget.is_some() {
+                self.world.triggers.spend_instruction_budgets(&[(
+                    id.clone(),
+                    limits_executor.instruction_count(),
+                )]);
+            }
+            result.map_err(|error| ValidationTriggerFail {
+                trigger_id: id.clone(),
+                reason: error.to_string(),
+            })?;
+            self.world
+                .triggers
+                .decrease_repeats(core::slice::from_ref(&id));
+        }
+
+        Ok(())
+    }
+
+    /// Synchronously call the WASM trigger `id` on behalf of `authority`,
+    /// passing it `args` and returning the output it sets.
+    ///
+    /// The trigger can be called by the same accounts that can execute it
+    /// with [`ExecuteTrigger`]. Every call uses up one of its repeats.
+    /// It runs on the `fuel` the caller has left, further restricted by the trigger's
+    /// own limits, and the fuel it consumed is returned along with its output
+    /// so that the caller can be charged for it.
+    ///
+    /// # Errors
+    /// Fails if calls are nested deeper than [`MAX_TRIGGER_CALL_DEPTH`],
+    /// if the trigger has no repeats left, if it can't be called by `authority`
+    /// or if its execution fails
+    pub fn call_trigger(
+        &mut self,
+        id: &TriggerId,
+        authority: &AccountId,
+        args: Vec<u8>,
+        fuel: u64,
+    ) -> Result<(Vec<u8>, u64)> {
+        if self.call_depth >= MAX_TRIGGER_CALL_DEPTH {
+            eyre::bail!("Trigger calls can't be nested deeper than {MAX_TRIGGER_CALL_DEPTH}");
+        }
+
+        // Eliding the closure triggers a lifetime mismatch
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        let action = self
+            .world
+            .triggers
+            .inspect_by_id(id, |action| action.clone_and_box())
+            .ok_or_else(|| Error::Find(FindError::Trigger(id.clone())))?;
+        if action.is_paused() {
+            eyre::bail!("Paused trigger can't be called");
+        }
+        if matches!(action.repeats(), Repeats::Exactly(0)) {
+            eyre::bail!("Trigger has no repeats left");
+        }
+        let event = ExecuteTriggerEvent {
+            trigger_id: id.clone(),
+            authority: authority.clone(),
+        };
+        let allow_call = match &action.filter {
+            TriggeringEventFilterBox::ExecuteTrigger(filter) => {
+                filter.matches(&event) || action.authority() == authority
+            }
+            _ => false,
+        };
+        if !allow_call {
+            eyre::bail!("Trigger can't be called by `{authority}`");
+        }
+        let triggers::set::ExecutableRef::Wasm(blob_hash) = action.executable() else {
+            eyre::bail!("Only WASM triggers can be called");
+        };
+
+        let module = self
+            .world
+            .triggers
+            .get_compiled_contract(blob_hash)
+            .expect("contract is not present it's a bug")
+            .clone();
+        let mut config = self.config.wasm_runtime;
+        config.fuel_limit = config.fuel_limit.min(fuel);
+        if let Some(max_fuel) = action.limits().max_fuel {
+            config.fuel_limit = config.fuel_limit.min(max_fuel.get());
+        }
+        let mut wasm_runtime = wasm::RuntimeBuilder::<wasm::state::Trigger>::new()
+            .with_config(config)
+            .with_engine(self.engine.clone()) // Cloning engine is cheap
+            .build()?;
+        let mut limits_executor = wasm::LimitsExecutor::for_trigger(action.limits());
+
+        self.call_depth += 1;
+        let output = wasm_runtime.call_trigger_module(
+            self,
+            action.authority().clone(),
+            &module,
+            event,
+            &mut limits_executor,
+            args,
+        );
+        self.call_depth -= 1;
+
+        if action.limits().instruction_budget.is_some() {
+            self.world
+                .triggers
+                .spend_instruction_budgets(&[(id.clone(), limits_executor.instruction_count())]);
+        }
+        let output = output?;
+        self.world
+            .triggers
+            .decrease_repeats(core::slice::from_ref(id));
+        Ok(output)
+    }
+
     fn process_trigger(
         &mut self,
         id: &TriggerId,
         action: &dyn LoadedActionTrait,
         event: EventBox,
+        limits_executor: &mut wasm::LimitsExecutor,
     ) -> Result<()> {
         use triggers::set::ExecutableRef::*;
         let authority = action.authority();
 
         match action.executable() {
             Instructions(instructions) => {
-                self.process_instructions(instructions.iter().cloned(), authority)
+                instructions.iter().cloned().try_for_each(|instruction| {
+                    limits_executor.check_instruction_limits()?;
+                    instruction.execute(authority, self)?;
+                    Ok::<_, eyre::Report>(())
+                })
             }
             Wasm(blob_hash) => {
                 let module = self
@@ -1408,12 +2204,23 @@ impl StateTransaction<'_, '_> {
                     .get_compiled_contract(blob_hash)
                     .expect("contract is not present it's a bug")
                     .clone();
+                let mut config = self.config.wasm_runtime;
+                if let Some(max_fuel) = action.limits().max_fuel {
+                    config.fuel_limit = config.fuel_limit.min(max_fuel.get());
+                }
                 let mut wasm_runtime = wasm::RuntimeBuilder::<wasm::state::Trigger>::new()
-                    .with_config(self.config.wasm_runtime)
+                    .with_config(config)
                     .with_engine(self.engine.clone()) // Cloning engine is cheap
                     .build()?;
                 wasm_runtime
-                    .execute_trigger_module(self, id, authority.clone(), &module, event)
+                    .execute_trigger_module(
+                        self,
+                        id,
+                        authority.clone(),
+                        &module,
+                        event,
+                        limits_executor,
+                    )
                     .map_err(Into::into)
             }
         }
@@ -1482,6 +2289,113 @@ mod range_bounds {
         key: RoleIdByAccount<'_>,
         trait: AsRoleIdByAccount
     }
+
+    /// Key for range queries over an index ordered by expiry
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    pub struct ByExpiry<'key, K> {
+        expiry: MetadataExpiry,
+        key: MinMaxExt<&'key K>,
+    }
+
+    impl<K> Clone for ByExpiry<'_, K> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<K> Copy for ByExpiry<'_, K> {}
+
+    /// Bounds for range queries over the index entries whose expiry is reached
+    pub struct ReachedExpiryBounds<K: 'static> {
+        start: ByExpiry<'static, K>,
+        end: ByExpiry<'static, K>,
+    }
+
+    impl<K> ReachedExpiryBounds<K> {
+        /// Create range bounds for the entries of each kind of expiry reached
+        /// by the block with the given height and creation time
+        pub fn new(block_height: u64, block_timestamp_ms: u64) -> [Self; 2] {
+            let bounds = |first, last| Self {
+                start: ByExpiry {
+                    expiry: first,
+                    key: MinMaxExt::Min,
+                },
+                end: ByExpiry {
+                    expiry: last,
+                    key: MinMaxExt::Max,
+                },
+            };
+
+            [
+                bounds(
+                    MetadataExpiry::BlockHeight(0),
+                    MetadataExpiry::BlockHeight(block_height),
+                ),
+                bounds(
+                    MetadataExpiry::TimestampMs(0),
+                    MetadataExpiry::TimestampMs(block_timestamp_ms),
+                ),
+            ]
+        }
+    }
+
+    impl<'key> RangeBounds<dyn AsMetadataRemovalByExpiry + 'key>
+        for ReachedExpiryBounds<RemoveKeyValueBox>
+    {
+        fn start_bound(&self) -> Bound<&(dyn AsMetadataRemovalByExpiry + 'key)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsMetadataRemovalByExpiry + 'key)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsMetadataRemovalByExpiry for MetadataRemovalByExpiry {
+        fn as_key(&self) -> ByExpiry<'_, RemoveKeyValueBox> {
+            ByExpiry {
+                expiry: self.expiry,
+                key: (&self.key).into(),
+            }
+        }
+    }
+
+    type MetadataRemovalByExpiry = ExpiryIndexKey<RemoveKeyValueBox>;
+
+    impl_as_dyn_key! {
+        target: MetadataRemovalByExpiry,
+        key: ByExpiry<'_, RemoveKeyValueBox>,
+        trait: AsMetadataRemovalByExpiry
+    }
+
+    impl<'key> RangeBounds<dyn AsPermissionRevokeByExpiry + 'key>
+        for ReachedExpiryBounds<Revoke<Permission, Account>>
+    {
+        fn start_bound(&self) -> Bound<&(dyn AsPermissionRevokeByExpiry + 'key)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsPermissionRevokeByExpiry + 'key)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsPermissionRevokeByExpiry for PermissionRevokeByExpiry {
+        fn as_key(&self) -> ByExpiry<'_, Revoke<Permission, Account>> {
+            ByExpiry {
+                expiry: self.expiry,
+                key: (&self.key).into(),
+            }
+        }
+    }
+
+    type PermissionRevokeByExpiry = ExpiryIndexKey<Revoke<Permission, Account>>;
+
+    impl_as_dyn_key! {
+        target: PermissionRevokeByExpiry,
+        key: ByExpiry<'_, Revoke<Permission, Account>>,
+        trait: AsPermissionRevokeByExpiry
+    }
 }
 
 pub(crate) mod deserialize {
@@ -1581,6 +2495,15 @@ pub(crate) mod deserialize {
                     let mut roles = None;
                     let mut account_permissions = None;
                     let mut account_roles = None;
+                    let mut metadata_expirations: Option<
+                        Storage<RemoveKeyValueBox, ExpiringMetadataEntry>,
+                    > = None;
+                    let mut escrows = None;
+                    let mut swap_offers = None;
+                    let mut permission_grant_terms: Option<
+                        Storage<Revoke<Permission, Account>, PermissionGrantEntry>,
+                    > = None;
+                    let mut grant_history = None;
                     let mut triggers = None;
                     let mut executor = None;
                     let mut executor_data_model = None;
@@ -1605,6 +2528,21 @@ pub(crate) mod deserialize {
                             "account_roles" => {
                                 account_roles = Some(map.next_value()?);
                             }
+                            "metadata_expirations" => {
+                                metadata_expirations = Some(map.next_value()?);
+                            }
+                            "escrows" => {
+                                escrows = Some(map.next_value()?);
+                            }
+                            "swap_offers" => {
+                                swap_offers = Some(map.next_value()?);
+                            }
+                            "permission_grant_terms" => {
+                                permission_grant_terms = Some(map.next_value()?);
+                            }
+                            "grant_history" => {
+                                grant_history = Some(map.next_value()?);
+                            }
                             "triggers" => {
                                 triggers =
                                     Some(map.next_value_seed(self.loader.cast::<TriggerSet>())?);
@@ -1622,19 +2560,49 @@ pub(crate) mod deserialize {
                         }
                     }
 
+                    let metadata_expirations = metadata_expirations.unwrap_or_default();
+                    let metadata_expiry_index = metadata_expirations
+                        .view()
+                        .iter()
+                        .map(|(removal, entry)| {
+                            (ExpiryIndexKey::new(entry.expiry, removal.clone()), ())
+                        })
+                        .collect();
+
+                    let permission_grant_terms = permission_grant_terms.unwrap_or_default();
+                    let permission_expiry_index = permission_grant_terms
+                        .view()
+                        .iter()
+                        .filter_map(|(revoke, entry)| {
+                            let expiry = entry.terms.expiry?;
+                            Some((ExpiryIndexKey::new(expiry, revoke.clone()), ()))
+                        })
+                        .collect();
+
+                    let domains: Storage<DomainId, Domain> =
+                        domains.ok_or_else(|| serde::de::Error::missing_field("domains"))?;
+                    let nft_owners = index_nft_owners(&domains.view());
+
                     Ok(World {
                         parameters: parameters
                             .ok_or_else(|| serde::de::Error::missing_field("parameters"))?,
                         trusted_peers_ids: trusted_peers_ids
                             .ok_or_else(|| serde::de::Error::missing_field("trusted_peers_ids"))?,
-                        domains: domains
-                            .ok_or_else(|| serde::de::Error::missing_field("domains"))?,
+                        domains,
+                        nft_owners,
                         roles: roles.ok_or_else(|| serde::de::Error::missing_field("roles"))?,
                         account_permissions: account_permissions.ok_or_else(|| {
                             serde::de::Error::missing_field("account_permissions")
                         })?,
                         account_roles: account_roles
                             .ok_or_else(|| serde::de::Error::missing_field("account_roles"))?,
+                        metadata_expirations,
+                        metadata_expiry_index,
+                        escrows: escrows.unwrap_or_default(),
+                        swap_offers: swap_offers.unwrap_or_default(),
+                        permission_grant_terms,
+                        permission_expiry_index,
+                        grant_history: grant_history.unwrap_or_default(),
                         triggers: triggers
                             .ok_or_else(|| serde::de::Error::missing_field("triggers"))?,
                         executor: executor
@@ -1655,6 +2623,11 @@ pub(crate) mod deserialize {
                     "roles",
                     "account_permissions",
                     "account_roles",
+                    "metadata_expirations",
+                    "escrows",
+                    "swap_offers",
+                    "permission_grant_terms",
+                    "grant_history",
                     "triggers",
                     "executor",
                     "executor_data_model",
@@ -1698,6 +2671,7 @@ pub(crate) mod deserialize {
                     let mut config = None;
                     let mut block_hashes = None;
                     let mut transactions = None;
+                    let mut transaction_payloads = None;
 
                     let engine = wasm::create_engine();
 
@@ -1720,6 +2694,9 @@ pub(crate) mod deserialize {
                             "transactions" => {
                                 transactions = Some(map.next_value()?);
                             }
+                            "transaction_payloads" => {
+                                transaction_payloads = Some(map.next_value()?);
+                            }
                             _ => { /* Skip unknown fields */ }
                         }
                     }
@@ -1731,6 +2708,9 @@ pub(crate) mod deserialize {
                             .ok_or_else(|| serde::de::Error::missing_field("block_hashes"))?,
                         transactions: transactions
                             .ok_or_else(|| serde::de::Error::missing_field("transactions"))?,
+                        transaction_payloads: transaction_payloads.ok_or_else(|| {
+                            serde::de::Error::missing_field("transaction_payloads")
+                        })?,
                         kura: self.loader.kura,
                         query_handle: self.loader.query_handle,
                         engine,
@@ -1741,7 +2721,13 @@ pub(crate) mod deserialize {
 
             deserializer.deserialize_struct(
                 "WorldState",
-                &["world", "config", "block_hashes", "transactions"],
+                &[
+                    "world",
+                    "config",
+                    "block_hashes",
+                    "transactions",
+                    "transaction_payloads",
+                ],
                 StateVisitor { loader: self },
             )
         }
@@ -1823,6 +2809,266 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn expired_metadata_is_removed() {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(World::default(), kura, query_handle);
+        let mut state_block = state.block();
+        let key = |name: &str| name.parse::<Name>().unwrap();
+        let set_account_key =
+            |name: &str, value: u32| SetKeyValue::account(account_id.clone(), key(name), value);
+
+        let mut transaction = state_block.transaction();
+        Register::domain(Domain::new("wonderland".parse().unwrap()))
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        Register::account(Account::new(account_id.clone()))
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        for (name, expiry) in [
+            ("expiring", MetadataExpiry::BlockHeight(2)),
+            ("changed", MetadataExpiry::BlockHeight(2)),
+            ("later", MetadataExpiry::TimestampMs(1_000)),
+        ] {
+            SetKeyValueWithExpiry::new(set_account_key(name, 1), expiry)
+                .execute(&account_id, &mut transaction)
+                .unwrap();
+        }
+        set_account_key("changed", 2)
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        // Rescheduled, so it is no longer removed at the earlier expiry
+        SetKeyValueWithExpiry::new(
+            set_account_key("rescheduled", 1),
+            MetadataExpiry::BlockHeight(2),
+        )
+        .execute(&account_id, &mut transaction)
+        .unwrap();
+        SetKeyValueWithExpiry::new(
+            set_account_key("rescheduled", 1),
+            MetadataExpiry::BlockHeight(3),
+        )
+        .execute(&account_id, &mut transaction)
+        .unwrap();
+        transaction.apply();
+
+        state_block.remove_expired_metadata(2, 999);
+
+        let metadata = &state_block.world.account(&account_id).unwrap().metadata;
+        assert_eq!(metadata.get("expiring"), None);
+        assert_eq!(metadata.get("changed"), Some(&2_u32.into()));
+        assert_eq!(metadata.get("later"), Some(&1_u32.into()));
+        assert_eq!(metadata.get("rescheduled"), Some(&1_u32.into()));
+        assert_eq!(state_block.world.metadata_expirations.iter().count(), 2);
+        assert_eq!(state_block.world.metadata_expiry_index.iter().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn execution_check_is_not_applied() {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(World::default(), kura, query_handle);
+        let mut state_block = state.block();
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+
+        let verdict = state_block.check_execution(CanAccountExecute::new(
+            account_id.clone(),
+            Register::domain(Domain::new(domain_id.clone())),
+        ));
+        assert_eq!(verdict.denial, None);
+        assert!(state_block.world.domain(&domain_id).is_err());
+
+        let verdict = state_block.check_execution(CanAccountExecute::new(
+            account_id.clone(),
+            Unregister::domain(domain_id),
+        ));
+        assert!(matches!(
+            verdict.denial,
+            Some(ValidationFail::InstructionFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn expired_permission_is_revoked() {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let (granter_id, _granter_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(World::default(), kura, query_handle);
+        let mut state_block = state.block();
+        let permission =
+            |name: &str| Permission::new(name.parse().unwrap(), &serde_json::Value::Null);
+
+        let mut transaction = state_block.transaction();
+        transaction
+            .world
+            .set_executor_data_model(ExecutorDataModel::new(
+                ["Expiring", "Revoked", "Later"]
+                    .into_iter()
+                    .map(|name| name.parse().unwrap())
+                    .collect(),
+                JsonString::from(&serde_json::Value::Null),
+            ));
+        Register::domain(Domain::new("wonderland".parse().unwrap()))
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        Register::account(Account::new(account_id.clone()))
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        for (name, expiry) in [
+            ("Expiring", MetadataExpiry::BlockHeight(2)),
+            ("Revoked", MetadataExpiry::BlockHeight(2)),
+            ("Later", MetadataExpiry::TimestampMs(1_000)),
+        ] {
+            GrantWithTerms::new(
+                Grant::permission(permission(name), account_id.clone()),
+                PermissionGrantTerms::default().with_expiry(expiry),
+            )
+            .execute(&granter_id, &mut transaction)
+            .unwrap();
+        }
+        Revoke::permission(permission("Revoked"), account_id.clone())
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        transaction.apply();
+
+        state_block.revoke_expired_permissions(2, 999);
+
+        let world = &state_block.world;
+        assert!(!world.account_contains_inherent_permission(&account_id, &permission("Expiring")));
+        assert!(world.account_contains_inherent_permission(&account_id, &permission("Later")));
+        assert_eq!(world.permission_grant_terms.iter().count(), 1);
+        assert_eq!(world.permission_expiry_index.iter().count(), 1);
+        // The expired permission is revoked on behalf of the account which granted it
+        let expired_revocation = world
+            .grant_history
+            .get(&account_id)
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(expired_revocation.granter(), &granter_id);
+        assert_eq!(
+            expired_revocation.change(),
+            &AccountGrantChange::PermissionRevoked(permission("Expiring"))
+        );
+    }
+
+    #[tokio::test]
+    async fn grant_history_records_changes() {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let (granter_id, _granter_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(World::default(), kura, query_handle);
+        let mut state_block = state.block();
+        let permission = Permission::new("Audited".parse().unwrap(), &serde_json::Value::Null);
+
+        let mut transaction = state_block.transaction();
+        transaction
+            .world
+            .set_executor_data_model(ExecutorDataModel::new(
+                [permission.id.clone()].into_iter().collect(),
+                JsonString::from(&serde_json::Value::Null),
+            ));
+        Register::domain(Domain::new("wonderland".parse().unwrap()))
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        Register::account(Account::new(account_id.clone()))
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        Grant::permission(permission.clone(), account_id.clone())
+            .execute(&granter_id, &mut transaction)
+            .unwrap();
+        Revoke::permission(permission.clone(), account_id.clone())
+            .execute(&granter_id, &mut transaction)
+            .unwrap();
+        transaction.apply();
+
+        let history = state_block.world.grant_history.get(&account_id).unwrap();
+        assert_eq!(
+            history
+                .iter()
+                .map(|audit| (audit.granter(), audit.change(), *audit.block_height()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    &granter_id,
+                    &AccountGrantChange::PermissionGranted(permission.clone()),
+                    1
+                ),
+                (
+                    &granter_id,
+                    &AccountGrantChange::PermissionRevoked(permission),
+                    1
+                ),
+            ]
+        );
+        let audit_events = state_block
+            .world
+            .events_buffer
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    EventBox::Data(DataEvent::Domain(DomainEvent::Account(
+                        AccountEvent::GrantAudited(_)
+                    )))
+                )
+            })
+            .count();
+        assert_eq!(audit_events, 2);
+    }
+
+    #[tokio::test]
+    async fn grant_history_is_bounded() {
+        let (account_id, _account_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::test().start();
+        let state = State::new(World::default(), kura, query_handle);
+        let mut state_block = state.block();
+        state_block.config.grant_history_max_len = 2;
+        let permission =
+            |name: &str| Permission::new(name.parse().unwrap(), &serde_json::Value::Null);
+
+        let mut transaction = state_block.transaction();
+        transaction
+            .world
+            .set_executor_data_model(ExecutorDataModel::new(
+                ["First", "Second", "Third"]
+                    .into_iter()
+                    .map(|name| name.parse().unwrap())
+                    .collect(),
+                JsonString::from(&serde_json::Value::Null),
+            ));
+        Register::domain(Domain::new("wonderland".parse().unwrap()))
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        Register::account(Account::new(account_id.clone()))
+            .execute(&account_id, &mut transaction)
+            .unwrap();
+        for name in ["First", "Second", "Third"] {
+            Grant::permission(permission(name), account_id.clone())
+                .execute(&account_id, &mut transaction)
+                .unwrap();
+        }
+        transaction.apply();
+
+        let history = state_block.world.grant_history.get(&account_id).unwrap();
+        assert_eq!(
+            history
+                .iter()
+                .map(AccountGrantAudit::change)
+                .collect::<Vec<_>>(),
+            [
+                &AccountGrantChange::PermissionGranted(permission("Second")),
+                &AccountGrantChange::PermissionGranted(permission("Third")),
+            ]
+        );
+    }
+
     #[test]
     fn role_account_range() {
         let (account_id, _account_keypair) = gen_account_in("wonderland");
@@ -40,6 +40,8 @@ pub struct Sumeragi {
     /// Only used in testing. Causes the genesis peer to withhold blocks when it
     /// is the proxy tail.
     pub debug_force_soft_fork: bool,
+    /// Interval after which the leader creates an empty block, if set
+    pub heartbeat_interval: Option<Duration>,
     /// The current network topology.
     pub current_topology: Topology,
     /// In order to *be fast*, we must minimize communication with
@@ -49,6 +51,14 @@ pub struct Sumeragi {
     pub transaction_cache: Vec<AcceptedTransaction>,
     /// Metrics for reporting number of view changes in current round
     pub view_changes_metric: iroha_telemetry::metrics::ViewChangesGauge,
+    /// Metrics for reporting number of view changes since the peer started
+    pub view_changes_total_metric: iroha_telemetry::metrics::ViewChangesCounter,
+    /// Metrics for reporting current leader
+    pub leader_metric: iroha_telemetry::metrics::LeaderGaugeVec,
+    /// Metrics for reporting time the proxy tail took to collect votes
+    pub proxy_tail_latency_metric: iroha_telemetry::metrics::ProxyTailLatencyHistogram,
+    /// Metrics for reporting proposals rejected by this peer
+    pub rejected_proposals_metric: iroha_telemetry::metrics::RejectedProposalsCounterVec,
 }
 
 #[allow(clippy::missing_fields_in_debug)]
@@ -141,7 +151,7 @@ impl Sumeragi {
                 should_sleep = false;
                 if let Err(error) = view_change_proof_chain.merge(
                     msg.view_change_proofs,
-                    &self.current_topology.ordered_peers,
+                    self.current_topology.validators(),
                     self.current_topology.max_faults(),
                     state_view.latest_block_hash(),
                 ) {
@@ -165,7 +175,7 @@ impl Sumeragi {
         view_change_proof_chain: &ProofChain,
     ) -> Option<BlockMessage> {
         let current_view_change_index = view_change_proof_chain.verify_with_state(
-            &self.current_topology.ordered_peers,
+            self.current_topology.validators(),
             self.current_topology.max_faults(),
             state_view.latest_block_hash(),
         ) as u64;
@@ -307,6 +317,25 @@ impl Sumeragi {
         self.broadcast_packet(genesis_msg);
     }
 
+    /// Update metrics describing the current round
+    fn update_round_metrics(&self, view_change_index: u64) {
+        let previous_view_change_index = self.view_changes_metric.get();
+        if view_change_index > previous_view_change_index {
+            self.view_changes_total_metric
+                .inc_by(view_change_index - previous_view_change_index);
+        }
+        self.view_changes_metric.set(view_change_index);
+
+        if let Some(topology) = self.current_topology.is_non_empty() {
+            let leader = topology.leader().public_key().to_string();
+            let leader_metric = self.leader_metric.with_label_values(&[&leader]);
+            if leader_metric.get() == 0 {
+                self.leader_metric.reset();
+                self.leader_metric.with_label_values(&[&leader]).set(1);
+            }
+        }
+    }
+
     fn commit_block(&mut self, block: CommittedBlock, state_block: StateBlock<'_>) {
         self.update_state::<NewBlockStrategy>(block, state_block);
     }
@@ -334,6 +363,7 @@ impl Sumeragi {
             block.as_ref(),
             0,
             state_block.world.peers().cloned().collect(),
+            max_faults_limit(&state_block.world),
         );
 
         // https://github.com/hyperledger/iroha/issues/3396
@@ -374,7 +404,8 @@ impl Sumeragi {
 
     fn cache_transaction(&mut self, state_block: &StateBlock<'_>) {
         self.transaction_cache.retain(|tx| {
-            !state_block.has_transaction(tx.as_ref().hash()) && !self.queue.is_expired(tx)
+            !state_block.has_transaction_payload(tx.as_ref().payload_hash())
+                && !self.queue.is_expired(tx)
         });
     }
 
@@ -401,7 +432,10 @@ impl Sumeragi {
         .unpack(|e| self.send_event(e))
         {
             Ok(block) => block,
-            Err(error) => {
+            Err((_, error)) => {
+                self.rejected_proposals_metric
+                    .with_label_values(&[error.reason()])
+                    .inc();
                 warn!(%addr, %role, ?error, "Block validation failed");
                 return None;
             }
@@ -418,7 +452,7 @@ impl Sumeragi {
     ) -> u64 {
         view_change_proof_chain.prune(state_view.latest_block_hash());
         view_change_proof_chain.verify_with_state(
-            &self.current_topology.ordered_peers,
+            self.current_topology.validators(),
             self.current_topology.max_faults(),
             state_view.latest_block_hash(),
         ) as u64
@@ -586,6 +620,9 @@ impl Sumeragi {
                             Some(VotingBlock::new(block, state_block))
                         }
                         Err((_, error)) => {
+                            self.rejected_proposals_metric
+                                .with_label_values(&[error.reason()])
+                                .inc();
                             warn!(%addr, %role, ?error, "Block validation failed");
                             None
                         }
@@ -667,8 +704,11 @@ impl Sumeragi {
                     let cache_full = self.transaction_cache.len() >= self.max_txs_in_block;
                     let deadline_reached = round_start_time.elapsed() > self.block_time;
                     let cache_non_empty = !self.transaction_cache.is_empty();
+                    let heartbeat_due = self
+                        .heartbeat_interval
+                        .is_some_and(|interval| round_start_time.elapsed() > interval);
 
-                    if cache_full || (deadline_reached && cache_non_empty) {
+                    if cache_full || (deadline_reached && cache_non_empty) || heartbeat_due {
                         let transactions = self.transaction_cache.clone();
                         info!(%addr, txns=%transactions.len(), "Creating block...");
                         let create_block_start_time = Instant::now();
@@ -723,6 +763,8 @@ impl Sumeragi {
                     {
                         Ok(committed_block) => {
                             info!(block=%committed_block.as_ref().hash(), "Block reached required number of votes");
+                            self.proxy_tail_latency_metric
+                                .observe(voted_at.elapsed().as_secs_f64() * 1000.0);
 
                             let msg = BlockCommitted::from(&committed_block);
 
@@ -793,6 +835,7 @@ fn reset_state(
             latest_block,
             current_view_change_index,
             current_topology.ordered_peers.iter().cloned().collect(),
+            current_topology.max_faults_limit,
         );
 
         *voting_block = None;
@@ -922,7 +965,7 @@ pub(crate) fn run(
             &mut last_view_change_time,
             &mut view_change_time,
         );
-        sumeragi.view_changes_metric.set(old_view_change_index);
+        sumeragi.update_round_metrics(old_view_change_index);
 
         if let Some(message) = {
             let (msg, sleep) =
@@ -971,7 +1014,7 @@ pub(crate) fn run(
 
                 view_change_proof_chain
                     .insert_proof(
-                        &sumeragi.current_topology.ordered_peers,
+                        sumeragi.current_topology.validators(),
                         sumeragi.current_topology.max_faults(),
                         state_view.latest_block_hash(),
                         suspect_proof,
@@ -1003,7 +1046,7 @@ pub(crate) fn run(
             &mut last_view_change_time,
             &mut view_change_time,
         );
-        sumeragi.view_changes_metric.set(old_view_change_index);
+        sumeragi.update_round_metrics(old_view_change_index);
 
         sumeragi.process_message_independent(
             &state,
@@ -1127,7 +1170,12 @@ fn handle_block_sync<'state, F: Fn(PipelineEventBox)>(
                 .expect("Not in genesis round so must have at least genesis block");
             let new_peers = state_block.world.peers().cloned().collect();
             let view_change_index = block.header().view_change_index;
-            Topology::recreate_topology(&last_committed_block, view_change_index, new_peers)
+            Topology::recreate_topology(
+                &last_committed_block,
+                view_change_index,
+                new_peers,
+                max_faults_limit(&state_block.world),
+            )
         };
         ValidBlock::validate(
             block,
@@ -1168,7 +1216,12 @@ fn handle_block_sync<'state, F: Fn(PipelineEventBox)>(
                 .expect("Not in genesis round so must have at least genesis block");
             let new_peers = state_block.world.peers().cloned().collect();
             let view_change_index = block.header().view_change_index;
-            Topology::recreate_topology(&last_committed_block, view_change_index, new_peers)
+            Topology::recreate_topology(
+                &last_committed_block,
+                view_change_index,
+                new_peers,
+                max_faults_limit(&state_block.world),
+            )
         };
         ValidBlock::validate(
             block,
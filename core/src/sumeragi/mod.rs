@@ -106,8 +106,9 @@ impl SumeragiHandle {
             });
 
         let peers = state_block.world.peers().cloned().collect();
+        let max_faults_limit = max_faults_limit(&state_block.world);
         Box::new(move |view_change_index| {
-            Topology::recreate_topology(block.as_ref(), view_change_index, peers)
+            Topology::recreate_topology(block.as_ref(), view_change_index, peers, max_faults_limit)
         })
     }
 
@@ -130,6 +131,10 @@ impl SumeragiHandle {
             sumeragi_metrics:
                 SumeragiMetrics {
                     view_changes,
+                    view_changes_total,
+                    leader,
+                    proxy_tail_latency_ms,
+                    rejected_proposals,
                     dropped_messages,
                 },
         }: SumeragiStartArgs,
@@ -166,8 +171,14 @@ impl SumeragiHandle {
                         Please check that the block storage was not disconnected.",
                     );
                     let peers = state_view.world.peers_ids().iter().cloned().collect();
+                    let max_faults_limit = max_faults_limit(&state_view.world);
                     Box::new(move |view_change_index| {
-                        Topology::recreate_topology(&block_ref, view_change_index, peers)
+                        Topology::recreate_topology(
+                            &block_ref,
+                            view_change_index,
+                            peers,
+                            max_faults_limit,
+                        )
                     })
                 }
             };
@@ -212,9 +223,14 @@ impl SumeragiHandle {
             control_message_receiver,
             message_receiver,
             debug_force_soft_fork,
+            heartbeat_interval: sumeragi_config.heartbeat_interval,
             current_topology,
             transaction_cache: Vec::new(),
             view_changes_metric: view_changes,
+            view_changes_total_metric: view_changes_total,
+            leader_metric: leader,
+            proxy_tail_latency_metric: proxy_tail_latency_ms,
+            rejected_proposals_metric: rejected_proposals,
         };
 
         // Oneshot channel to allow forcefully stopping the thread.
@@ -249,6 +265,15 @@ impl SumeragiHandle {
 /// Closure to get topology recreated at certain view change index
 type RecreateTopologyByViewChangeIndex = Box<dyn FnOnce(u64) -> Topology>;
 
+/// Limit of tolerated faults set on-chain, see [`Topology::with_max_faults_limit`]
+fn max_faults_limit(world: &impl WorldReadOnly) -> Option<usize> {
+    use iroha_data_model::parameter::default::MAX_FAULTS;
+
+    world
+        .query_param::<u32, _>(MAX_FAULTS)
+        .map(|max_faults| max_faults as usize)
+}
+
 /// The interval at which sumeragi checks if there are tx in the
 /// `queue`.  And will create a block if is leader and the voting is
 /// not already in progress.
@@ -316,6 +341,14 @@ pub struct SumeragiStartArgs {
 pub struct SumeragiMetrics {
     /// Number of view changes in current round
     pub view_changes: iroha_telemetry::metrics::ViewChangesGauge,
+    /// Number of view changes since the peer started
+    pub view_changes_total: iroha_telemetry::metrics::ViewChangesCounter,
+    /// Current leader
+    pub leader: iroha_telemetry::metrics::LeaderGaugeVec,
+    /// Time the proxy tail took to collect enough votes for a block
+    pub proxy_tail_latency_ms: iroha_telemetry::metrics::ProxyTailLatencyHistogram,
+    /// Proposals rejected by this peer by reason
+    pub rejected_proposals: iroha_telemetry::metrics::RejectedProposalsCounterVec,
     /// Amount of dropped messages by sumeragi
     pub dropped_messages: iroha_telemetry::metrics::DroppedMessagesCounter,
 }
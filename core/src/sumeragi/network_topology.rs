@@ -22,6 +22,8 @@ use iroha_primitives::unique_vec::UniqueVec;
 pub struct Topology {
     /// Current order of peers. The roles of peers are defined based on this order.
     pub(crate) ordered_peers: UniqueVec<PeerId>,
+    /// Limit of faulty peers to tolerate, set on-chain
+    pub(crate) max_faults_limit: Option<usize>,
 }
 
 /// Topology with at least one peer
@@ -41,9 +43,22 @@ impl Topology {
     pub fn new(peers: UniqueVec<PeerId>) -> Self {
         Topology {
             ordered_peers: peers,
+            max_faults_limit: None,
         }
     }
 
+    /// Tolerate at most `limit` faulty peers, if set.
+    ///
+    /// Only the first `3 * limit + 1` peers validate blocks, the rest observe them
+    /// without their signatures counting towards the commit. Fewer tolerated faults make
+    /// set A smaller and so consensus faster, the limit is ignored if it exceeds
+    /// what the number of peers can tolerate.
+    #[must_use]
+    pub fn with_max_faults_limit(mut self, limit: Option<usize>) -> Self {
+        self.max_faults_limit = limit;
+        self
+    }
+
     /// True, if the topology contains at least one peer and thus requires consensus
     pub fn is_non_empty(&self) -> Option<NonEmptyTopology> {
         (!self.ordered_peers.is_empty()).then_some(NonEmptyTopology { topology: self })
@@ -54,14 +69,27 @@ impl Topology {
         (self.ordered_peers.len() > 1).then_some(ConsensusTopology { topology: self })
     }
 
+    /// Number of peers, from the start of the order, which validate blocks.
+    fn validators_count(&self) -> usize {
+        let len = self.ordered_peers.len();
+        self.max_faults_limit.map_or(len, |limit| {
+            limit.saturating_mul(3).saturating_add(1).min(len)
+        })
+    }
+
+    /// Peers which validate blocks, only their signatures count towards the commit.
+    pub fn validators(&self) -> &[PeerId] {
+        &self.ordered_peers[..self.validators_count()]
+    }
+
     /// How many faulty peers can this topology tolerate.
     pub fn max_faults(&self) -> usize {
-        (self.ordered_peers.len().saturating_sub(1)) / 3
+        (self.validators_count().saturating_sub(1)) / 3
     }
 
     /// The required amount of votes to commit a block with this topology.
     pub fn min_votes_for_commit(&self) -> usize {
-        let len = self.ordered_peers.len();
+        let len = self.validators_count();
         if len > 3 {
             self.max_faults() * 2 + 1
         } else {
@@ -137,11 +165,13 @@ impl Topology {
         self.ordered_peers.extend(new_peers);
     }
 
-    /// Rotate peers n times where n is a number of failed attempt to create a block.
+    /// Rotate validators n times where n is a number of failed attempt to create a block.
+    ///
+    /// Peers outside of the validators stay in place, so that every view of a round
+    /// counts votes of the same peers.
     pub fn rotate_all_n(&mut self, n: u64) {
-        let len = self
-            .ordered_peers
-            .len()
+        let validators_count = self.validators_count();
+        let len = validators_count
             .try_into()
             .expect("`usize` should fit into `u64`");
         if let Some(rem) = n.checked_rem(len) {
@@ -149,7 +179,7 @@ impl Topology {
                 "`rem` is smaller than `usize::MAX`, because remainder is always smaller than divisor",
             );
 
-            self.modify_peers_directly(|peers| peers.rotate_left(rem));
+            self.modify_peers_directly(|peers| peers[..validators_count].rotate_left(rem));
         }
     }
 
@@ -180,8 +210,10 @@ impl Topology {
         block: &SignedBlock,
         view_change_index: u64,
         new_peers: UniqueVec<PeerId>,
+        max_faults_limit: Option<usize>,
     ) -> Self {
-        let mut topology = Topology::new(block.commit_topology().clone());
+        let mut topology =
+            Topology::new(block.commit_topology().clone()).with_max_faults_limit(max_faults_limit);
         let block_signees = block
             .signatures()
             .into_iter()
@@ -210,7 +242,8 @@ impl Topology {
                 peers.sort();
                 let peers_count = peers.len();
                 peers.rotate_right(view_change_limit % peers_count);
-                topology = Topology::new(peers.into_iter().collect());
+                topology = Topology::new(peers.into_iter().collect())
+                    .with_max_faults_limit(max_faults_limit);
             }
         }
 
@@ -251,9 +284,9 @@ impl<'topology> ConsensusTopology<'topology> {
         &self.ordered_peers[self.leader_index() + 1..self.proxy_tail_index()]
     }
 
-    /// Get observing [`PeerId`]s.
+    /// Get observing [`PeerId`]s among the validators.
     pub fn observing_peers(&self) -> &'topology [PeerId] {
-        &self.ordered_peers[self.proxy_tail_index() + 1..]
+        &self.topology.validators()[self.proxy_tail_index() + 1..]
     }
 
     /// Get voting [`PeerId`]s.
@@ -313,6 +346,54 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn max_faults_limit() {
+        assert_eq!(topology().max_faults(), 2);
+        assert_eq!(topology().with_max_faults_limit(Some(1)).max_faults(), 1);
+        assert_eq!(
+            topology()
+                .with_max_faults_limit(Some(1))
+                .min_votes_for_commit(),
+            3
+        );
+        // Limit can't raise the number of tolerated faults
+        assert_eq!(topology().with_max_faults_limit(Some(5)).max_faults(), 2);
+    }
+
+    #[test]
+    fn max_faults_limit_shrinks_validators() {
+        let key_pairs = core::iter::repeat_with(KeyPair::random)
+            .take(7)
+            .collect::<Vec<_>>();
+        let mut key_pairs_iter = key_pairs.iter();
+        let peers = test_peers![0, 1, 2, 3, 4, 5, 6: key_pairs_iter];
+        let topology = Topology::new(peers.clone()).with_max_faults_limit(Some(1));
+        assert_eq!(topology.validators().len(), 4);
+        assert_eq!(topology.min_votes_for_commit(), 3);
+
+        let dummy = "value to sign";
+        let signatures = key_pairs
+            .iter()
+            .map(|key_pair| SignatureOf::new(key_pair, &dummy))
+            .collect::<Vec<SignatureOf<_>>>();
+
+        // Peers after the validators don't count
+        let observing_peers_signatures =
+            topology.filter_signatures_by_roles(&[Role::ObservingPeer], signatures.iter());
+        assert_eq!(observing_peers_signatures.len(), 1);
+        assert_eq!(
+            observing_peers_signatures[0].public_key(),
+            peers[3].public_key()
+        );
+    }
+
+    #[test]
+    fn rotate_all_n_keeps_non_validators() {
+        let mut topology = topology().with_max_faults_limit(Some(1));
+        topology.rotate_all_n(1);
+        assert_eq!(extract_ports(&topology), vec![1, 2, 3, 0, 4, 5, 6]);
+    }
+
     #[test]
     fn rotate_set_a() {
         let mut topology = topology();
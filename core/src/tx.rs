@@ -8,20 +8,25 @@
 //! This is also where the actual execution of instructions, as well
 //! as various forms of validation are performed.
 
+use std::num::NonZeroU64;
+
 use eyre::Result;
 use iroha_crypto::SignatureVerificationFail;
 pub use iroha_data_model::prelude::*;
 use iroha_data_model::{
-    isi::error::Mismatch,
+    isi::error::{InstructionExecutionError, MathError, Mismatch},
+    parameter::default::TRANSACTION_FEES,
     query::error::FindError,
-    transaction::{error::TransactionLimitError, TransactionLimits, TransactionPayload},
+    transaction::{
+        error::TransactionLimitError, TransactionFees, TransactionLimits, TransactionPayload,
+    },
 };
-use iroha_genesis::GenesisTransaction;
+use iroha_genesis::{GenesisTransaction, GENESIS_DOMAIN_ID};
 use iroha_logger::{debug, error};
 use iroha_macro::FromVariant;
 
 use crate::{
-    smartcontracts::wasm,
+    smartcontracts::{wasm, Execute as _},
     state::{StateBlock, StateTransaction, WorldReadOnly},
 };
 
@@ -128,6 +133,13 @@ impl AcceptedTransaction {
                     ));
                 }
             }
+            Executable::WasmCode(_) => {
+                return Err(AcceptTransactionFail::TransactionLimit(
+                    TransactionLimitError {
+                        reason: "Only triggers can refer to registered WASM code".to_owned(),
+                    },
+                ));
+            }
         }
 
         Ok(Self(tx))
@@ -184,6 +196,14 @@ impl TransactionExecutor {
         tx: AcceptedTransaction,
         state_block: &mut StateBlock<'_>,
     ) -> Result<SignedTransaction, (SignedTransaction, TransactionRejectionReason)> {
+        let mut state_transaction = state_block.transaction();
+        if let Err(rejection_reason) = Self::check_authority(&tx, &state_transaction)
+            .and_then(|()| Self::charge_fee(&tx, &mut state_transaction))
+        {
+            return Err((tx.0, rejection_reason));
+        }
+        state_transaction.apply();
+
         let mut state_transaction = state_block.transaction();
         if let Err(rejection_reason) = self.validate_internal(tx.clone(), &mut state_transaction) {
             return Err((tx.0, rejection_reason));
@@ -193,14 +213,38 @@ impl TransactionExecutor {
         Ok(tx.0)
     }
 
-    fn validate_internal(
+    /// Execute the transaction against the [`StateBlock`] and report the events it
+    /// emits and the messages its WASM logs along with its rejection reason, if any.
+    ///
+    /// Meant to be used on a [`StateBlock`] which is dropped afterwards without being committed.
+    pub fn simulate(
         &self,
         tx: AcceptedTransaction,
-        state_transaction: &mut StateTransaction<'_, '_>,
+        state_block: &mut StateBlock<'_>,
+    ) -> TransactionSimulation {
+        state_block.wasm_logs = Some(Vec::new());
+        let error = self
+            .validate(tx, state_block)
+            .err()
+            .map(|(_, rejection_reason)| rejection_reason);
+
+        TransactionSimulation {
+            events: core::mem::take(&mut state_block.world.events_buffer),
+            error,
+            logs: state_block.wasm_logs.take().unwrap_or_default(),
+        }
+    }
+
+    /// Check that the authority of the transaction exists, signed it and may submit it now.
+    ///
+    /// Done before the fee is charged, so that nobody can make an account pay
+    /// for transactions it didn't authorize, e.g. by replaying them.
+    fn check_authority(
+        tx: &AcceptedTransaction,
+        state_transaction: &StateTransaction<'_, '_>,
     ) -> Result<(), TransactionRejectionReason> {
         let authority = tx.as_ref().authority();
-
-        if !state_transaction
+        let account = state_transaction
             .world
             .domain(&authority.domain_id)
             .map_err(|_e| {
@@ -209,14 +253,85 @@ impl TransactionExecutor {
                 ))
             })?
             .accounts
-            .contains_key(authority)
-        {
-            return Err(TransactionRejectionReason::AccountDoesNotExist(
-                FindError::Account(authority.clone()),
+            .get(authority)
+            .ok_or_else(|| {
+                TransactionRejectionReason::AccountDoesNotExist(FindError::Account(
+                    authority.clone(),
+                ))
+            })?;
+        if !account.is_signed_by(tx.as_ref().signatories()) {
+            return Err(TransactionRejectionReason::SignatureConditionUnsatisfied);
+        }
+        if account.disabled {
+            return Err(TransactionRejectionReason::AccountDisabled);
+        }
+        if account.strict_ordering {
+            let last_sequence = account.sequence;
+            if tx.as_ref().sequence().map(NonZeroU64::get) != last_sequence.checked_add(1) {
+                return Err(TransactionRejectionReason::SequenceMismatch(last_sequence));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfer the fee of the transaction from its authority to the fee recipient,
+    /// if [`TransactionFees`] are set. The fee is kept even if the transaction is rejected.
+    fn charge_fee(
+        tx: &AcceptedTransaction,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), TransactionRejectionReason> {
+        let authority = tx.as_ref().authority();
+        if authority.domain_id == *GENESIS_DOMAIN_ID {
+            return Ok(());
+        }
+        let Some(fees) = state_transaction
+            .world
+            .query_param::<TransactionFees, _>(TRANSACTION_FEES)
+        else {
+            return Ok(());
+        };
+
+        let Some(fee) = fees.fee_of(tx.as_ref()) else {
+            return Err(TransactionRejectionReason::FeeCharge(
+                InstructionExecutionError::Math(MathError::Overflow),
             ));
+        };
+        if fee.is_zero() {
+            return Ok(());
+        }
+        let source_id = AssetId::new(fees.asset_definition_id, authority.clone());
+        Transfer::asset_numeric(source_id, fee, fees.recipient)
+            .execute(authority, state_transaction)
+            .map_err(TransactionRejectionReason::FeeCharge)
+    }
+
+    fn validate_internal(
+        &self,
+        tx: AcceptedTransaction,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), TransactionRejectionReason> {
+        let authority = tx.as_ref().authority();
+        state_transaction.transaction_hash = Some(tx.as_ref().hash());
+
+        // The sequence number was checked by `check_authority`
+        if state_transaction
+            .world
+            .account(authority)
+            .map_err(TransactionRejectionReason::AccountDoesNotExist)?
+            .strict_ordering
+        {
+            state_transaction
+                .world
+                .account_mut(authority)
+                .map_err(TransactionRejectionReason::AccountDoesNotExist)?
+                .sequence += 1;
         }
 
         debug!("Validating transaction: {:?}", tx);
+        state_transaction
+            .execute_validation_triggers(tx.as_ref())
+            .map_err(TransactionRejectionReason::ValidationTrigger)?;
         Self::validate_with_runtime_executor(tx.clone(), state_transaction)?;
 
         if let (authority, Executable::Wasm(bytes)) = tx.into() {
@@ -235,7 +350,10 @@ impl TransactionExecutor {
     ) -> Result<(), TransactionRejectionReason> {
         debug!("Validating wasm");
 
+        let config = state_transaction.config.wasm_runtime;
         wasm::RuntimeBuilder::<wasm::state::SmartContract>::new()
+            .with_config(config)
+            .with_engine(state_transaction.engine.clone()) // Cloning engine is cheap
             .build()
             .and_then(|mut wasm_runtime| {
                 wasm_runtime.validate(
@@ -245,10 +363,14 @@ impl TransactionExecutor {
                     self.transaction_limits.max_instruction_number,
                 )
             })
-            .map_err(|error| WasmExecutionFail {
-                reason: format!("{:?}", eyre::Report::from(error)),
+            .map_err(|error| match error {
+                wasm::error::Error::ExportFnCall(wasm::error::ExportFnCallError::OutOfFuel(_)) => {
+                    TransactionRejectionReason::OutOfFuel(config.fuel_limit)
+                }
+                error => TransactionRejectionReason::WasmExecution(WasmExecutionFail {
+                    reason: format!("{:?}", eyre::Report::from(error)),
+                }),
             })
-            .map_err(TransactionRejectionReason::WasmExecution)
     }
 
     /// Validate transaction with runtime executors.
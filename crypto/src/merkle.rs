@@ -15,6 +15,18 @@ use crate::{Hash, HashOf};
 #[repr(transparent)]
 pub struct MerkleTree<T>(Vec<Option<HashOf<T>>>);
 
+/// Proof that a leaf is included in a [`MerkleTree`] with the given root hash
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, TypeId, Decode, Encode, Deserialize, Serialize,
+)]
+pub struct MerkleProof<T> {
+    /// Index of the leaf in the order of insertion
+    leaf_index: u32,
+    /// Hashes of the siblings on the path from the leaf to the root.
+    /// `None` stands for a missing right sibling
+    audit_path: Vec<Option<HashOf<T>>>,
+}
+
 /// Iterator over leaves of [`MerkleTree`]
 pub struct LeafHashIterator<T> {
     tree: MerkleTree<T>,
@@ -134,6 +146,33 @@ impl<T: IntoSchema> IntoSchema for MerkleTree<T> {
     }
 }
 
+impl<T: IntoSchema> IntoSchema for MerkleProof<T> {
+    fn type_name() -> String {
+        format!("MerkleProof<{}>", T::type_name())
+    }
+    fn update_schema_map(map: &mut iroha_schema::MetaMap) {
+        if !map.contains_key::<Self>() {
+            u32::update_schema_map(map);
+            Vec::<Option<HashOf<T>>>::update_schema_map(map);
+
+            map.insert::<Self>(iroha_schema::Metadata::Struct(
+                iroha_schema::NamedFieldsMeta {
+                    declarations: vec![
+                        iroha_schema::Declaration {
+                            name: String::from("leaf_index"),
+                            ty: core::any::TypeId::of::<u32>(),
+                        },
+                        iroha_schema::Declaration {
+                            name: String::from("audit_path"),
+                            ty: core::any::TypeId::of::<Vec<Option<HashOf<T>>>>(),
+                        },
+                    ],
+                },
+            ));
+        }
+    }
+}
+
 impl<T> Default for MerkleTree<T> {
     fn default() -> Self {
         Self::new()
@@ -159,6 +198,28 @@ impl<T> MerkleTree<T> {
         None
     }
 
+    /// Get the proof of inclusion of the `idx`-th leaf.
+    pub fn get_proof(&self, idx: usize) -> Option<MerkleProof<T>> {
+        self.get_leaf_hash(idx)?;
+
+        let mut audit_path = Vec::new();
+        let mut node_idx = 2_usize.pow(self.height()) - 1 + idx;
+        while let Some(parent_idx) = self.parent(node_idx) {
+            let sibling = if node_idx % 2 == 1 {
+                self.get_r_child(parent_idx)
+            } else {
+                self.get_l_child(parent_idx)
+            };
+            audit_path.push(sibling.copied().flatten());
+            node_idx = parent_idx;
+        }
+
+        Some(MerkleProof {
+            leaf_index: idx.try_into().ok()?,
+            audit_path,
+        })
+    }
+
     /// Add `hash` to the tail of the tree.
     pub fn add(&mut self, hash: HashOf<T>) {
         // If the tree is perfect, increment its height to double the leaf capacity.
@@ -225,6 +286,34 @@ impl<T> MerkleTree<T> {
     }
 }
 
+impl<T> MerkleProof<T> {
+    /// Index of the proven leaf in the order of insertion
+    pub fn leaf_index(&self) -> u32 {
+        self.leaf_index
+    }
+
+    /// Check that `leaf` is included in the tree with the given `root` hash.
+    pub fn verify(&self, leaf: HashOf<T>, root: HashOf<MerkleTree<T>>) -> bool {
+        let mut node = leaf;
+        let mut idx = self.leaf_index;
+        for sibling in &self.audit_path {
+            let parent = if idx % 2 == 0 {
+                MerkleTree::<T>::nodes_pair_hash(Some(&node), sibling.as_ref())
+            } else {
+                match sibling {
+                    Some(sibling) => MerkleTree::<T>::nodes_pair_hash(Some(sibling), Some(&node)),
+                    // Right child can't exist without its left sibling
+                    None => return false,
+                }
+            };
+            node = parent.expect("Left node is present");
+            idx /= 2;
+        }
+
+        idx == 0 && node.transmute::<MerkleTree<T>>() == root
+    }
+}
+
 impl<T> Iterator for LeafHashIterator<T> {
     type Item = HashOf<T>;
 
@@ -312,4 +401,21 @@ mod tests {
             assert_eq!(testee_leaf, tester_leaf);
         }
     }
+
+    #[test]
+    fn proof() {
+        for n_leaves in 1..=9 {
+            let hashes = test_hashes(n_leaves);
+            let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+            let root = tree.hash().expect("Tree is not empty");
+
+            for (idx, hash) in hashes.iter().enumerate() {
+                let proof = tree.get_proof(idx).expect("Leaf exists");
+                assert!(proof.verify(*hash, root));
+                let other = hashes[(idx + 1) % hashes.len()];
+                assert_eq!(proof.verify(other, root), other == *hash);
+            }
+            assert!(tree.get_proof(hashes.len()).is_none());
+        }
+    }
 }
@@ -1,9 +1,14 @@
 //! Structures, traits and impls related to `Account`s.
 #[cfg(not(feature = "std"))]
-use alloc::{collections::btree_map, format, string::String, vec::Vec};
-use core::str::FromStr;
+use alloc::{
+    collections::{btree_map, btree_set::BTreeSet},
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::{num::NonZeroU16, str::FromStr};
 #[cfg(feature = "std")]
-use std::collections::btree_map;
+use std::collections::{btree_map, BTreeSet};
 
 use derive_more::{Constructor, DebugCustom, Display};
 use getset::Getters;
@@ -21,12 +26,15 @@ use crate::{
     },
     domain::prelude::*,
     metadata::Metadata,
-    HasMetadata, Identifiable, ParseError, PublicKey, Registered,
+    HasMetadata, Identifiable, Name, ParseError, PublicKey, Registered,
 };
 
 /// API to work with collections of [`Id`] : [`Account`] mappings.
 pub type AccountsMap = btree_map::BTreeMap<AccountId, Account>;
 
+/// API to work with collections of alias [`Name`] : [`AccountId`] mappings.
+pub type AccountAliasesMap = btree_map::BTreeMap<Name, AccountId>;
+
 #[model]
 mod model {
     use super::*;
@@ -72,6 +80,45 @@ mod model {
         pub signatory: PublicKey,
     }
 
+    /// Human-readable handle of an [`Account`], registered in the [`Domain`](crate::domain::Domain) it belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iroha_data_model::account::AccountAlias;
+    ///
+    /// let alias: AccountAlias = "alice@wonderland"
+    ///     .parse()
+    ///     .expect("name@domain should be valid format");
+    /// ```
+    #[derive(
+        DebugCustom,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        DeserializeFromStr,
+        SerializeDisplay,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name}@{domain_id}")]
+    #[debug(fmt = "{name}@{domain_id}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AccountAlias {
+        /// [`Domain`](crate::domain::Domain) in which the alias is registered.
+        pub domain_id: DomainId,
+        /// Name of the alias, unique within the domain.
+        pub name: Name,
+    }
+
     /// Account entity is an authority which is used to execute `Iroha Special Instructions`.
     #[derive(
         Debug,
@@ -95,6 +142,115 @@ mod model {
         pub assets: AssetsMap,
         /// Metadata of this account as a key-value store.
         pub metadata: Metadata,
+        /// Condition which the signatures of the account transactions must satisfy.
+        ///
+        /// Without it, transactions are signed by the signatory of [`AccountId`] alone.
+        #[serde(default)]
+        pub signature_condition: Option<SignatureCondition>,
+        /// Guardians which may replace the signatories of the account if its keys are lost.
+        #[serde(default)]
+        pub recovery_policy: Option<RecoveryPolicy>,
+        /// Replacements of the signatories proposed by the guardians, at most one per guardian.
+        #[serde(default)]
+        pub pending_recoveries: Vec<PendingRecovery>,
+        /// Are transactions of the account and transfers to it blocked.
+        #[serde(default)]
+        pub disabled: bool,
+        /// Do transactions of the account have to carry consecutive sequence numbers.
+        #[serde(default)]
+        pub strict_ordering: bool,
+        /// Sequence number of the last executed transaction of the account.
+        #[serde(default)]
+        pub sequence: u64,
+    }
+
+    /// M-of-N condition on the signatures of the transactions of a multisignature [`Account`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{threshold} OF {} SIGNATORIES", "signatories.len()")]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct SignatureCondition {
+        /// Public keys whose signatures count towards the threshold.
+        pub signatories: BTreeSet<PublicKey>,
+        /// Number of distinct signatories which must sign a transaction.
+        pub threshold: NonZeroU16,
+    }
+
+    /// Guardian accounts which together may recover an [`Account`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(
+        fmt = "{quorum} OF {} GUARDIANS AFTER {delay_ms} MS",
+        "guardians.len()"
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct RecoveryPolicy {
+        /// Accounts which may approve a recovery.
+        pub guardians: BTreeSet<AccountId>,
+        /// Number of distinct guardians which must approve a recovery.
+        pub quorum: NonZeroU16,
+        /// Time between reaching the quorum and the recovery taking effect,
+        /// during which the account may cancel it.
+        pub delay_ms: u64,
+    }
+
+    /// Recovery of an [`Account`] approved by some of its guardians.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(
+        fmt = "RECOVERY TO {signature_condition} APPROVED BY {} GUARDIANS",
+        "approvals.len()"
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct PendingRecovery {
+        /// Signature condition which replaces the one of the account.
+        pub signature_condition: SignatureCondition,
+        /// Guardians which approved the recovery.
+        pub approvals: BTreeSet<AccountId>,
+        /// Timestamp of the latest block when the quorum was reached.
+        pub quorum_reached_ms: Option<u64>,
     }
 
     /// Builder which should be submitted in a transaction to create a new [`Account`]
@@ -135,6 +291,12 @@ impl Account {
         &self.id.signatory
     }
 
+    /// Return the condition the signatures of the `Account` transactions must satisfy, if any.
+    #[inline]
+    pub fn signature_condition(&self) -> Option<&SignatureCondition> {
+        self.signature_condition.as_ref()
+    }
+
     /// Return a reference to the [`Asset`] corresponding to the asset id.
     #[inline]
     pub fn asset(&self, asset_id: &AssetId) -> Option<&Asset> {
@@ -146,6 +308,68 @@ impl Account {
     pub fn assets(&self) -> impl ExactSizeIterator<Item = &Asset> {
         self.assets.values()
     }
+
+    /// Return `true` if signatures of the given public keys authorize a transaction of the `Account`.
+    ///
+    /// Signatures of keys which can't sign for the `Account` are not allowed,
+    /// so that nobody can change a signed transaction by adding their own.
+    pub fn is_signed_by<'key>(
+        &self,
+        public_keys: impl IntoIterator<Item = &'key PublicKey>,
+    ) -> bool {
+        let mut public_keys = public_keys.into_iter().peekable();
+        match &self.signature_condition {
+            Some(condition) => {
+                let public_keys: Vec<_> = public_keys.collect();
+                public_keys
+                    .iter()
+                    .all(|public_key| condition.signatories.contains(*public_key))
+                    && condition.is_satisfied_by(public_keys)
+            }
+            None => {
+                public_keys.peek().is_some()
+                    && public_keys.all(|public_key| *public_key == self.id.signatory)
+            }
+        }
+    }
+}
+
+impl RecoveryPolicy {
+    /// Construct a [`RecoveryPolicy`] requiring approvals of `quorum` of the `guardians`,
+    /// which takes effect `delay_ms` after the quorum is reached.
+    pub fn new(
+        guardians: impl IntoIterator<Item = AccountId>,
+        quorum: NonZeroU16,
+        delay_ms: u64,
+    ) -> Self {
+        Self {
+            guardians: guardians.into_iter().collect(),
+            quorum,
+            delay_ms,
+        }
+    }
+}
+
+impl SignatureCondition {
+    /// Construct a [`SignatureCondition`] requiring signatures of `threshold` of the `signatories`.
+    pub fn new(signatories: impl IntoIterator<Item = PublicKey>, threshold: NonZeroU16) -> Self {
+        Self {
+            signatories: signatories.into_iter().collect(),
+            threshold,
+        }
+    }
+
+    /// Return `true` if signatures of the given public keys reach the threshold.
+    pub fn is_satisfied_by<'key>(
+        &self,
+        public_keys: impl IntoIterator<Item = &'key PublicKey>,
+    ) -> bool {
+        let signed: BTreeSet<_> = public_keys
+            .into_iter()
+            .filter(|public_key| self.signatories.contains(public_key))
+            .collect();
+        signed.len() >= usize::from(self.threshold.get())
+    }
 }
 
 #[cfg(feature = "transparent_api")]
@@ -188,6 +412,12 @@ impl NewAccount {
             id: self.id,
             assets: AssetsMap::default(),
             metadata: self.metadata,
+            signature_condition: None,
+            recovery_policy: None,
+            pending_recoveries: Vec::new(),
+            disabled: false,
+            strict_ordering: false,
+            sequence: 0,
         }
     }
 }
@@ -235,9 +465,38 @@ impl FromStr for AccountId {
     }
 }
 
+impl FromStr for AccountAlias {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rsplit_once('@') {
+            None => Err(ParseError {
+                reason: "Account alias should have format `name@domain`",
+            }),
+            Some(("", _)) => Err(ParseError {
+                reason: "Empty `name` part in `name@domain`",
+            }),
+            Some((_, "")) => Err(ParseError {
+                reason: "Empty `domain` part in `name@domain`",
+            }),
+            Some((name_candidate, domain_id_candidate)) => {
+                let name = name_candidate.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse `name` part in `name@domain`",
+                })?;
+                let domain_id = domain_id_candidate.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse `domain` part in `name@domain`",
+                })?;
+                Ok(Self::new(domain_id, name))
+            }
+        }
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{Account, AccountId};
+    pub use super::{
+        Account, AccountAlias, AccountId, PendingRecovery, RecoveryPolicy, SignatureCondition,
+    };
 }
 
 #[cfg(test)]
@@ -262,4 +521,17 @@ mod tests {
             .parse::<AccountId>()
             .expect_err("signatory#domain should not be valid");
     }
+
+    #[test]
+    fn signature_condition_threshold() {
+        let [alice, bob, carol] = [(); 3].map(|()| iroha_crypto::KeyPair::random().into_parts().0);
+        let condition = SignatureCondition::new(
+            [alice.clone(), bob.clone()],
+            NonZeroU16::new(2).expect("non-zero"),
+        );
+
+        assert!(condition.is_satisfied_by([&alice, &bob]));
+        assert!(!condition.is_satisfied_by([&alice, &alice]));
+        assert!(!condition.is_satisfied_by([&alice, &carol]));
+    }
 }
@@ -125,6 +125,13 @@ mod model {
         pub value_type: AssetValueType,
         /// Is the asset mintable
         pub mintable: Mintable,
+        /// Are transfers and burns of assets of this definition blocked
+        #[serde(default)]
+        pub frozen: bool,
+        /// Upper bound of the total minted quantity, unbounded if [`None`]
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub max_supply: Option<Numeric>,
         /// IPFS link to the [`AssetDefinition`] logo
         #[getset(get = "pub")]
         pub logo: Option<IpfsPath>,
@@ -157,6 +164,12 @@ mod model {
         /// Asset's Quantity.
         #[getset(get = "pub")]
         pub value: AssetValue,
+        /// Are transfers and burns of this asset blocked
+        #[serde(default)]
+        pub frozen: bool,
+        /// Metadata of this particular asset, e.g. provenance of its units.
+        #[serde(default)]
+        pub metadata: Metadata,
     }
 
     /// Builder which can be submitted in a transaction to create a new [`AssetDefinition`]
@@ -172,6 +185,9 @@ mod model {
         pub value_type: AssetValueType,
         /// The mintablility associated with the asset definition builder.
         pub mintable: Mintable,
+        /// Upper bound of the total minted quantity, unbounded if [`None`]
+        #[serde(default)]
+        pub max_supply: Option<Numeric>,
         /// IPFS link to the [`AssetDefinition`] logo
         pub logo: Option<IpfsPath>,
         /// Metadata associated with the asset definition builder.
@@ -202,6 +218,9 @@ mod model {
         /// Asset's key-value structured data.
         #[display(fmt = "Store")]
         Store,
+        /// Unique tokens, kept as the keys of an [`AssetValue::Store`] of their owner's asset.
+        #[display(fmt = "Nft")]
+        Nft,
     }
 
     /// Asset's inner value.
@@ -272,7 +291,7 @@ mod model {
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
 #[repr(u8)]
 pub enum AssetValueTypeParseError {
-    /// `AssetValueType` should be either `Store` or `Numeric`
+    /// `AssetValueType` should be either `Store`, `Nft` or `Numeric`
     WrongVariant,
     /// Error occurred while parsing `Numeric` variant: {_0}
     Numeric(#[cfg_attr(feature = "std", source)] NumericSpecParseError),
@@ -299,6 +318,13 @@ impl AssetDefinition {
     pub fn store(id: AssetDefinitionId) -> <Self as Registered>::With {
         <Self as Registered>::With::new(id, AssetValueType::Store)
     }
+
+    /// Construct builder for [`AssetDefinition`] of non-fungible tokens identifiable by [`Id`].
+    #[must_use]
+    #[inline]
+    pub fn nft(id: AssetDefinitionId) -> <Self as Registered>::With {
+        <Self as Registered>::With::new(id, AssetValueType::Nft)
+    }
 }
 
 impl Asset {
@@ -307,6 +333,27 @@ impl Asset {
         Self {
             id,
             value: value.into(),
+            frozen: false,
+            metadata: Metadata::default(),
+        }
+    }
+
+    /// Key-value entries of this asset.
+    ///
+    /// These are the value itself for assets of [`AssetValueType::Store`]
+    /// and the attached [`Asset::metadata`] for assets of other types.
+    pub fn key_values(&self, value_type: AssetValueType) -> &Metadata {
+        match &self.value {
+            AssetValue::Store(store) if value_type == AssetValueType::Store => store,
+            _ => &self.metadata,
+        }
+    }
+
+    /// Mutable version of [`Self::key_values`].
+    pub fn key_values_mut(&mut self, value_type: AssetValueType) -> &mut Metadata {
+        match &mut self.value {
+            AssetValue::Store(store) if value_type == AssetValueType::Store => store,
+            _ => &mut self.metadata,
         }
     }
 }
@@ -318,6 +365,7 @@ impl NewAssetDefinition {
             id,
             value_type,
             mintable: Mintable::Infinitely,
+            max_supply: None,
             logo: None,
             metadata: Metadata::default(),
         }
@@ -331,6 +379,14 @@ impl NewAssetDefinition {
         self
     }
 
+    /// Cap the total quantity that can be minted
+    #[inline]
+    #[must_use]
+    pub fn with_max_supply(mut self, max_supply: Numeric) -> Self {
+        self.max_supply = Some(max_supply);
+        self
+    }
+
     /// Add [`logo`](IpfsPath) to the asset definition replacing previously defined value
     #[must_use]
     pub fn with_logo(mut self, logo: IpfsPath) -> Self {
@@ -451,6 +507,7 @@ impl FromStr for AssetValueType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "Store" => Ok(Self::Store),
+            "Nft" => Ok(Self::Nft),
             s if s.starts_with("Numeric") => s
                 .parse::<NumericSpec>()
                 .map(Self::Numeric)
@@ -340,6 +340,7 @@ pub mod stream {
     //! Blocks for streaming API.
 
     use derive_more::Constructor;
+    use getset::Getters;
     use iroha_schema::IntoSchema;
     use parity_scale_codec::{Decode, Encode};
 
@@ -356,13 +357,37 @@ pub mod stream {
         #[derive(
             Debug, Clone, Copy, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema,
         )]
-        #[repr(transparent)]
-        pub struct BlockSubscriptionRequest(pub NonZeroU64);
+        pub struct BlockSubscriptionRequest {
+            /// Height of the first block to send
+            pub from_height: NonZeroU64,
+            /// If `true`, blocks are sent as [`BlockMessage`]s,
+            /// otherwise only their summaries are sent as [`BlockSummaryMessage`]s
+            pub full_blocks: bool,
+        }
 
         /// Message sent by the stream producer containing block.
         #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
         #[repr(transparent)]
         pub struct BlockMessage(pub SignedBlock);
+
+        /// Message sent by the stream producer containing the summary of a block.
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        #[repr(transparent)]
+        pub struct BlockSummaryMessage(pub BlockSummary);
+
+        /// Header and transaction hashes of a committed block.
+        #[derive(
+            Debug, Clone, PartialEq, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        pub struct BlockSummary {
+            /// Header of the block
+            pub header: BlockHeader,
+            /// Hash of the block
+            pub hash: HashOf<SignedBlock>,
+            /// Hashes of the transactions of the block, in the order they were committed
+            pub transactions: Vec<HashOf<SignedTransaction>>,
+        }
     }
 
     impl From<BlockMessage> for SignedBlock {
@@ -371,9 +396,30 @@ pub mod stream {
         }
     }
 
+    impl From<BlockSummaryMessage> for BlockSummary {
+        fn from(source: BlockSummaryMessage) -> Self {
+            source.0
+        }
+    }
+
+    impl From<&SignedBlock> for BlockSummary {
+        fn from(block: &SignedBlock) -> Self {
+            Self {
+                header: block.header().clone(),
+                hash: block.hash(),
+                transactions: block
+                    .transactions()
+                    .map(|transaction| transaction.as_ref().hash())
+                    .collect(),
+            }
+        }
+    }
+
     /// Exports common structs and enums from this module.
     pub mod prelude {
-        pub use super::{BlockMessage, BlockSubscriptionRequest};
+        pub use super::{
+            BlockMessage, BlockSubscriptionRequest, BlockSummary, BlockSummaryMessage,
+        };
     }
 }
 
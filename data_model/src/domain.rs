@@ -14,12 +14,12 @@ use serde_with::{DeserializeFromStr, SerializeDisplay};
 
 pub use self::model::*;
 use crate::{
-    account::{Account, AccountsMap},
+    account::{Account, AccountAliasesMap, AccountsMap},
     asset::{AssetDefinition, AssetDefinitionsMap, AssetTotalQuantityMap},
     ipfs::IpfsPath,
-    metadata::Metadata,
+    metadata::{Limits as MetadataLimits, Metadata},
     prelude::*,
-    HasMetadata, Name, Registered,
+    HasMetadata, Name, ParseError, Registered,
 };
 
 #[model]
@@ -27,6 +27,8 @@ mod model {
     use super::*;
 
     /// Identification of a [`Domain`].
+    ///
+    /// A domain named `sub.parent` is a sub-domain of the domain `parent`.
     #[derive(
         Debug,
         Display,
@@ -87,6 +89,40 @@ mod model {
         /// The account that owns this domain. Usually the [`Account`] that registered it.
         #[getset(get = "pub")]
         pub owned_by: AccountId,
+        /// Metadata limits of the entities registered in this domain.
+        #[getset(get = "pub")]
+        #[serde(default)]
+        pub metadata_limits: DomainMetadataLimits,
+        /// Aliases of the [`Account`]s of this `Domain`.
+        #[serde(default)]
+        pub account_aliases: AccountAliasesMap,
+    }
+
+    /// Metadata limits set by the owner of a [`Domain`].
+    ///
+    /// Limits which are not set are inherited from the parent domain, falling back
+    /// to the global configuration, which also bounds the ones that are set.
+    #[derive(
+        Debug,
+        Default,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct DomainMetadataLimits {
+        /// Limits for the metadata of [`Account`]s.
+        pub account: Option<MetadataLimits>,
+        /// Limits for the metadata of [`AssetDefinition`]s.
+        pub asset_definition: Option<MetadataLimits>,
     }
 
     /// Builder which can be submitted in a transaction to create a new [`Domain`]
@@ -112,6 +148,65 @@ impl HasMetadata for NewDomain {
     }
 }
 
+impl DomainId {
+    /// Separator between the name of a sub-domain and the id of its parent.
+    pub const SEPARATOR: char = '.';
+
+    /// Id of the sub-domain named `name` of this domain.
+    ///
+    /// # Errors
+    /// Fails if the resulting name is not a valid [`Name`].
+    pub fn child(&self, name: &Name) -> Result<Self, ParseError> {
+        format!("{name}{}{self}", Self::SEPARATOR).parse()
+    }
+
+    /// Id of the parent domain if this is a sub-domain.
+    pub fn parent(&self) -> Option<Self> {
+        self.name
+            .as_ref()
+            .split_once(Self::SEPARATOR)
+            .and_then(|(_, parent)| parent.parse().ok())
+    }
+
+    /// Iterate over the ids of the ancestors of this domain, from its parent up.
+    pub fn ancestors(&self) -> impl Iterator<Item = Self> {
+        core::iter::successors(self.parent(), Self::parent)
+    }
+
+    /// Return `true` if this domain is a sub-domain of `ancestor`, directly or not.
+    pub fn is_descendant_of(&self, ancestor: &DomainId) -> bool {
+        self.name
+            .as_ref()
+            .strip_suffix(ancestor.name.as_ref())
+            .is_some_and(|name| name.ends_with(Self::SEPARATOR))
+    }
+}
+
+impl DomainMetadataLimits {
+    /// Take the limits which are not set from the ones of the `parent` domain.
+    #[must_use]
+    pub fn inheriting(self, parent: Self) -> Self {
+        Self {
+            account: self.account.or(parent.account),
+            asset_definition: self.asset_definition.or(parent.asset_definition),
+        }
+    }
+
+    /// Limits for the metadata of [`Account`]s, bounded by the `global` ones.
+    #[inline]
+    pub fn account_limits(&self, global: MetadataLimits) -> MetadataLimits {
+        self.account
+            .map_or(global, |limits| limits.bounded_by(global))
+    }
+
+    /// Limits for the metadata of [`AssetDefinition`]s, bounded by the `global` ones.
+    #[inline]
+    pub fn asset_definition_limits(&self, global: MetadataLimits) -> MetadataLimits {
+        self.asset_definition
+            .map_or(global, |limits| limits.bounded_by(global))
+    }
+}
+
 impl NewDomain {
     /// Create a [`NewDomain`], reserved for internal use.
     #[must_use]
@@ -194,6 +289,12 @@ impl Domain {
         self.accounts.contains_key(account_id)
     }
 
+    /// Return the id of the [`Account`] registered under the alias name.
+    #[inline]
+    pub fn account_by_alias(&self, name: &Name) -> Option<&AccountId> {
+        self.account_aliases.get(name)
+    }
+
     /// Get an iterator over asset definitions of the `Domain`
     #[inline]
     pub fn asset_definitions(&self) -> impl ExactSizeIterator<Item = &AssetDefinition> {
@@ -259,5 +360,31 @@ impl Domain {
 
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{Domain, DomainId};
+    pub use super::{Domain, DomainId, DomainMetadataLimits};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sub_domain_ancestors() {
+        let id: DomainId = "team.dept.org".parse().expect("valid");
+        let org: DomainId = "org".parse().expect("valid");
+        let dept = org.child(&"dept".parse().expect("valid")).expect("valid");
+
+        assert_eq!(dept, "dept.org".parse().expect("valid"));
+        assert_eq!(
+            id.ancestors().collect::<Vec<_>>(),
+            vec![dept.clone(), org.clone()]
+        );
+        assert!(org.parent().is_none());
+        assert!(id.is_descendant_of(&dept));
+        assert!(id.is_descendant_of(&org));
+        assert!(!dept.is_descendant_of(&id));
+        assert!(!"borg"
+            .parse::<DomainId>()
+            .expect("valid")
+            .is_descendant_of(&org));
+    }
 }
@@ -0,0 +1,177 @@
+//! Structures, traits and impls related to `Escrow`s.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use derive_more::{Constructor, Display, FromStr};
+use getset::Getters;
+use iroha_data_model_derive::model;
+use iroha_primitives::numeric::Numeric;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::{
+    account::AccountId,
+    asset::AssetId,
+    metadata::{Expiry, MetadataValueBox},
+    Name,
+};
+
+#[model]
+mod model {
+    use super::*;
+
+    /// Identification of an escrow.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        FromStr,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    #[ffi_type(opaque)]
+    pub struct EscrowId {
+        /// Escrow name, should be unique.
+        pub name: Name,
+    }
+
+    /// Numeric asset amount locked until it is released to the recipient or refunded to the depositor.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{amount} OF {source_id} TO {recipient} WHEN {condition}")]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct Escrow {
+        /// Asset of the depositor the amount is taken from and refunded to.
+        pub source_id: AssetId,
+        /// Locked amount.
+        pub amount: Numeric,
+        /// Account the amount is released to.
+        pub recipient: AccountId,
+        /// Condition which has to hold for the amount to be released.
+        pub condition: EscrowCondition,
+        /// When the depositor may take the amount back. Without it only the recipient can refund.
+        pub refund_after: Option<Expiry>,
+    }
+
+    /// Condition under which an [`Escrow`] is released.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    pub enum EscrowCondition {
+        /// Released once the expiry is reached, by any account.
+        #[display(fmt = "AFTER {_0}")]
+        After(Expiry),
+        /// Released only by the given account.
+        #[display(fmt = "SIGNED BY {_0}")]
+        CounterSignature(AccountId),
+        /// Released, by any account, while the metadata entry of an account has the given value.
+        #[display(fmt = "{_0}")]
+        AccountMetadata(AccountMetadataCondition),
+    }
+
+    /// Metadata entry of an account which must have the given value.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{account_id}[{key}] = {value}")]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct AccountMetadataCondition {
+        /// Account whose metadata is checked.
+        pub account_id: AccountId,
+        /// Key of the metadata entry.
+        pub key: Name,
+        /// Value the metadata entry must have.
+        pub value: MetadataValueBox,
+    }
+}
+
+impl Escrow {
+    /// Construct an [`Escrow`] of `amount` taken from `source_id` and released to `recipient` when `condition` holds.
+    pub fn new(
+        source_id: AssetId,
+        amount: impl Into<Numeric>,
+        recipient: AccountId,
+        condition: EscrowCondition,
+    ) -> Self {
+        Self {
+            source_id,
+            amount: amount.into(),
+            recipient,
+            condition,
+            refund_after: None,
+        }
+    }
+
+    /// Allow the depositor to refund the escrow once `expiry` is reached.
+    #[must_use]
+    pub fn with_refund_after(mut self, expiry: Expiry) -> Self {
+        self.refund_after = Some(expiry);
+        self
+    }
+
+    /// Account which deposited the amount.
+    pub fn depositor(&self) -> &AccountId {
+        &self.source_id.account_id
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{AccountMetadataCondition, Escrow, EscrowCondition, EscrowId};
+}
@@ -41,7 +41,8 @@ mod model {
     use crate::metadata::MetadataValueBox;
 
     /// Generic [`MetadataChanged`] struct.
-    /// Contains the changed metadata (`(key, value)` pair), either inserted or removed, which is determined by the wrapping event.
+    /// Contains the changed metadata (`(path, value)` pair), either inserted or removed, which is determined by the wrapping event.
+    /// The `path` starts from the top-level key of the target's metadata and leads to the changed entry.
     #[derive(
         Debug,
         Clone,
@@ -60,7 +61,7 @@ mod model {
     #[ffi_type]
     pub struct MetadataChanged<ID> {
         pub target_id: ID,
-        pub key: Name,
+        pub path: Vec<Name>,
         pub value: MetadataValueBox,
     }
 
@@ -121,6 +122,8 @@ mod asset {
             MetadataInserted(AssetMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target_id)]
             MetadataRemoved(AssetMetadataChanged),
+            Frozen(AssetId),
+            Unfrozen(AssetId),
         }
     }
 
@@ -139,6 +142,8 @@ mod asset {
             MetadataRemoved(AssetDefinitionMetadataChanged),
             #[has_origin(total_quantity_changed => &total_quantity_changed.asset_definition_id)]
             TotalQuantityChanged(AssetDefinitionTotalQuantityChanged),
+            Frozen(AssetDefinitionId),
+            Unfrozen(AssetDefinitionId),
         }
     }
 
@@ -317,6 +322,17 @@ mod account {
             MetadataInserted(AccountMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target_id)]
             MetadataRemoved(AccountMetadataChanged),
+            SignatureThresholdChanged(AccountId),
+            KeyRotated(AccountId),
+            RecoveryPolicySet(AccountId),
+            RecoveryApproved(AccountId),
+            RecoveryCancelled(AccountId),
+            Recovered(AccountId),
+            Disabled(AccountId),
+            Enabled(AccountId),
+            StrictOrderingChanged(AccountId),
+            #[has_origin(grant_audit => &grant_audit.account_id)]
+            GrantAudited(AccountGrantAudit),
         }
     }
 
@@ -369,6 +385,60 @@ mod account {
             pub account_id: AccountId,
             pub role_id: RoleId,
         }
+
+        /// Permission or role granted to or revoked from an account, as recorded for audit
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountGrantAudit {
+            /// Account the permission or role is granted to or revoked from
+            pub account_id: AccountId,
+            /// Account which granted or revoked the permission or role
+            pub granter: AccountId,
+            /// Granted or revoked permission or role
+            pub change: AccountGrantChange,
+            /// Height of the block in which the permission or role was granted or revoked
+            pub block_height: u64,
+        }
+
+        /// Change of the permissions or roles of an account recorded in [`AccountGrantAudit`]
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[ffi_type]
+        pub enum AccountGrantChange {
+            /// Permission granted to the account
+            PermissionGranted(Permission),
+            /// Permission revoked from the account
+            PermissionRevoked(Permission),
+            /// Role granted to the account
+            RoleGranted(RoleId),
+            /// Role revoked from the account
+            RoleRevoked(RoleId),
+        }
     }
 
     impl AccountPermissionChanged {
@@ -403,6 +473,12 @@ mod domain {
             MetadataRemoved(DomainMetadataChanged),
             #[has_origin(owner_changed => &owner_changed.domain_id)]
             OwnerChanged(DomainOwnerChanged),
+            #[has_origin(limits_changed => &limits_changed.domain_id)]
+            MetadataLimitsChanged(DomainMetadataLimitsChanged),
+            #[has_origin(alias_changed => &alias_changed.alias.domain_id)]
+            AccountAliasRegistered(AccountAliasChanged),
+            #[has_origin(alias_changed => &alias_changed.alias.domain_id)]
+            AccountAliasUnregistered(AccountAliasChanged),
         }
     }
 
@@ -431,6 +507,50 @@ mod domain {
             pub domain_id: DomainId,
             pub new_owner: AccountId,
         }
+
+        /// Event indicate that metadata limits of the [`Domain`] are changed
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct DomainMetadataLimitsChanged {
+            pub domain_id: DomainId,
+            pub limits: DomainMetadataLimits,
+        }
+
+        /// Event indicate that an alias of an [`Account`] in the [`Domain`] is (un)registered
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountAliasChanged {
+            pub alias: AccountAlias,
+            pub account_id: AccountId,
+        }
     }
 }
 
@@ -457,6 +577,9 @@ mod trigger {
             MetadataInserted(TriggerMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target_id)]
             MetadataRemoved(TriggerMetadataChanged),
+            Paused(TriggerId),
+            Resumed(TriggerId),
+            Upgraded(TriggerId),
         }
     }
 
@@ -605,14 +728,20 @@ impl DataEvent {
 
 pub mod prelude {
     pub use super::{
-        account::{AccountEvent, AccountEventSet, AccountPermissionChanged, AccountRoleChanged},
+        account::{
+            AccountEvent, AccountEventSet, AccountGrantAudit, AccountGrantChange,
+            AccountPermissionChanged, AccountRoleChanged,
+        },
         asset::{
             AssetChanged, AssetDefinitionEvent, AssetDefinitionEventSet,
             AssetDefinitionOwnerChanged, AssetDefinitionTotalQuantityChanged, AssetEvent,
             AssetEventSet,
         },
         config::{ConfigurationEvent, ConfigurationEventSet},
-        domain::{DomainEvent, DomainEventSet, DomainOwnerChanged},
+        domain::{
+            AccountAliasChanged, DomainEvent, DomainEventSet, DomainMetadataLimitsChanged,
+            DomainOwnerChanged,
+        },
         executor::{ExecutorEvent, ExecutorEventSet, ExecutorUpgrade},
         peer::{PeerEvent, PeerEventSet},
         role::{RoleEvent, RoleEventSet, RolePermissionChanged},
@@ -4,6 +4,8 @@
 //!
 //! Event types are filtered with an `EventSet` type, allowing to filter for multiple event types at once.
 
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::ToString as _, vec, vec::Vec};
 use core::fmt::Debug;
 
 use getset::Getters;
@@ -51,6 +53,26 @@ mod model {
         Configuration(ConfigurationEventFilter),
         /// Matches [`ExecutorEvent`]s
         Executor(ExecutorEventFilter),
+        /// Matches data events whose origin id matches the pattern
+        OriginId(OriginIdPattern),
+        /// Matches data events matched by every filter
+        And(
+            #[skip_from]
+            #[skip_try_from]
+            Vec<DataEventFilter>,
+        ),
+        /// Matches data events matched by any of the filters
+        Or(
+            #[skip_from]
+            #[skip_try_from]
+            Vec<DataEventFilter>,
+        ),
+        /// Matches data events not matched by the filter
+        Not(
+            #[skip_from]
+            #[skip_try_from]
+            Box<DataEventFilter>,
+        ),
     }
 
     /// An event filter for [`PeerEvent`]s
@@ -137,6 +159,10 @@ mod model {
     pub struct AssetEventFilter {
         /// If specified matches only events originating from this asset
         pub(super) id_matcher: Option<super::AssetId>,
+        /// If specified matches only events originating from assets of this definition
+        pub(super) definition_matcher: Option<super::AssetDefinitionId>,
+        /// If specified matches only events originating from assets of this account
+        pub(super) account_matcher: Option<super::AccountId>,
         /// Matches only event from this set
         pub(super) event_set: AssetEventSet,
     }
@@ -249,6 +275,33 @@ mod model {
         /// Matches only event from this set
         pub(super) event_set: ExecutorEventSet,
     }
+
+    /// Pattern of the id of the entity a data event originates from.
+    ///
+    /// `*` in the pattern matches any sequence of characters, e.g. `*@wonderland`
+    /// matches every account of the `wonderland` domain and `rose#*` matches `rose`
+    /// asset definitions of every domain.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct OriginIdPattern {
+        /// Pattern the origin id is matched against
+        pub(super) pattern: String,
+    }
 }
 
 impl PeerEventFilter {
@@ -403,6 +456,8 @@ impl AssetEventFilter {
     pub const fn new() -> Self {
         Self {
             id_matcher: None,
+            definition_matcher: None,
+            account_matcher: None,
             event_set: AssetEventSet::all(),
         }
     }
@@ -414,6 +469,20 @@ impl AssetEventFilter {
         self
     }
 
+    /// Modifies a [`AssetEventFilter`] to accept only [`AssetEvent`]s originating from assets of `definition_id`.
+    #[must_use]
+    pub fn for_asset_definition(mut self, definition_id: AssetDefinitionId) -> Self {
+        self.definition_matcher = Some(definition_id);
+        self
+    }
+
+    /// Modifies a [`AssetEventFilter`] to accept only [`AssetEvent`]s originating from assets of `account_id`.
+    #[must_use]
+    pub fn for_account(mut self, account_id: AccountId) -> Self {
+        self.account_matcher = Some(account_id);
+        self
+    }
+
     /// Modifies a [`AssetEventFilter`] to accept only [`AssetEvent`]s of types contained in `event_set`.
     #[must_use]
     pub const fn for_events(mut self, event_set: AssetEventSet) -> Self {
@@ -439,6 +508,18 @@ impl super::EventFilter for AssetEventFilter {
             }
         }
 
+        if let Some(definition_matcher) = &self.definition_matcher {
+            if *definition_matcher != event.origin_id().definition_id {
+                return false;
+            }
+        }
+
+        if let Some(account_matcher) = &self.account_matcher {
+            if *account_matcher != event.origin_id().account_id {
+                return false;
+            }
+        }
+
         if !self.event_set.matches(event) {
             return false;
         }
@@ -678,6 +759,61 @@ impl super::EventFilter for ExecutorEventFilter {
     }
 }
 
+impl OriginIdPattern {
+    /// Creates a new [`OriginIdPattern`] from a pattern where `*` matches any sequence of characters.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+        }
+    }
+
+    /// Check if `id` matches the pattern
+    pub fn matches_id(&self, id: &str) -> bool {
+        let mut parts = self.pattern.split('*');
+        let first = parts.next().unwrap_or_default();
+        let Some(mut rest) = id.strip_prefix(first) else {
+            return false;
+        };
+        let mut parts: Vec<&str> = parts.collect();
+        let Some(last) = parts.pop() else {
+            // no wildcards in the pattern
+            return rest.is_empty();
+        };
+        for part in parts {
+            let Some(start) = rest.find(part) else {
+                return false;
+            };
+            rest = &rest[start + part.len()..];
+        }
+
+        rest.ends_with(last)
+    }
+}
+
+#[cfg(feature = "transparent_api")]
+impl EventFilter for OriginIdPattern {
+    type Event = DataEvent;
+
+    fn matches(&self, event: &DataEvent) -> bool {
+        let origin_id = match event {
+            DataEvent::Domain(DomainEvent::Account(AccountEvent::Asset(event))) => {
+                event.origin_id().to_string()
+            }
+            DataEvent::Domain(DomainEvent::Account(event)) => event.origin_id().to_string(),
+            DataEvent::Domain(DomainEvent::AssetDefinition(event)) => event.origin_id().to_string(),
+            DataEvent::Domain(event) => event.origin_id().to_string(),
+            DataEvent::Peer(event) => event.origin_id().to_string(),
+            DataEvent::Trigger(event) => event.origin_id().to_string(),
+            DataEvent::Role(event) => event.origin_id().to_string(),
+            DataEvent::Configuration(event) => event.origin_id().to_string(),
+            // executor is a global entity, so there is no id to match
+            DataEvent::Executor(_) => return false,
+        };
+
+        self.matches_id(&origin_id)
+    }
+}
+
 #[cfg(feature = "transparent_api")]
 impl EventFilter for DataEventFilter {
     type Event = DataEvent;
@@ -687,6 +823,9 @@ impl EventFilter for DataEventFilter {
 
         #[allow(clippy::match_same_arms)]
         match (event, self) {
+            (event, And(filters)) => filters.iter().all(|filter| filter.matches(event)),
+            (event, Or(filters)) => filters.iter().any(|filter| filter.matches(event)),
+            (event, Not(filter)) => !filter.matches(event),
             (
                 DataEvent::Domain(DomainEvent::Account(AccountEvent::Asset(event))),
                 Asset(filter),
@@ -704,6 +843,7 @@ impl EventFilter for DataEventFilter {
             (DataEvent::Role(event), Role(filter)) => filter.matches(event),
             (DataEvent::Configuration(event), Configuration(filter)) => filter.matches(event),
             (DataEvent::Executor(event), Executor(filter)) => filter.matches(event),
+            (event, OriginId(pattern)) => pattern.matches(event),
 
             (
                 DataEvent::Peer(_)
@@ -727,11 +867,48 @@ impl EventFilter for DataEventFilter {
     }
 }
 
+impl DataEventFilter {
+    /// Combine filters into one matching events matched by both of them
+    #[must_use]
+    pub fn and(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::And(mut filters) => {
+                filters.push(other.into());
+                Self::And(filters)
+            }
+            filter => Self::And(vec![filter, other.into()]),
+        }
+    }
+
+    /// Combine filters into one matching events matched by any of them
+    #[must_use]
+    pub fn or(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::Or(mut filters) => {
+                filters.push(other.into());
+                Self::Or(filters)
+            }
+            filter => Self::Or(vec![filter, other.into()]),
+        }
+    }
+}
+
+impl core::ops::Not for DataEventFilter {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Self::Not(filter) => *filter,
+            filter => Self::Not(Box::new(filter)),
+        }
+    }
+}
+
 pub mod prelude {
     pub use super::{
         AccountEventFilter, AssetDefinitionEventFilter, AssetEventFilter, ConfigurationEventFilter,
-        DataEventFilter, DomainEventFilter, ExecutorEventFilter, PeerEventFilter, RoleEventFilter,
-        TriggerEventFilter,
+        DataEventFilter, DomainEventFilter, ExecutorEventFilter, OriginIdPattern, PeerEventFilter,
+        RoleEventFilter, TriggerEventFilter,
     };
 }
 #[cfg(test)]
@@ -741,7 +918,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        account::AccountsMap,
+        account::{AccountAliasesMap, AccountsMap},
         asset::{AssetDefinitionsMap, AssetTotalQuantityMap},
     };
 
@@ -761,6 +938,8 @@ mod tests {
             logo: None,
             metadata: Metadata::default(),
             owned_by: domain_owner_id,
+            metadata_limits: DomainMetadataLimits::default(),
+            account_aliases: AccountAliasesMap::default(),
         };
         let account = Account::new(account_id.clone()).into_account();
         let asset = Asset::new(asset_id.clone(), 0_u32);
@@ -795,4 +974,74 @@ mod tests {
         assert!(!asset_filter.matches(&account_created));
         assert!(asset_filter.matches(&asset_created));
     }
+
+    #[test]
+    fn origin_id_pattern() {
+        let pattern = OriginIdPattern::new("rose#*");
+        assert!(pattern.matches_id("rose#wonderland"));
+        assert!(!pattern.matches_id("tulip#wonderland"));
+
+        let pattern = OriginIdPattern::new("*@wonderland");
+        assert!(pattern.matches_id("alice@wonderland"));
+        assert!(!pattern.matches_id("alice@wonderland2"));
+
+        let pattern = OriginIdPattern::new("rose#*#*@wonderland");
+        assert!(pattern.matches_id("rose#garden#alice@wonderland"));
+        assert!(!pattern.matches_id("rose#alice@wonderland"));
+
+        let pattern = OriginIdPattern::new("wonderland");
+        assert!(pattern.matches_id("wonderland"));
+        assert!(!pattern.matches_id("wonderland2"));
+
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let account_id = AccountId::new(domain_id.clone(), KeyPair::random().into_parts().0);
+        let account_created: DataEvent =
+            AccountEvent::Created(Account::new(account_id).into_account()).into();
+        let domain_deleted: DataEvent = DomainEvent::Deleted(domain_id).into();
+
+        let filter = DataEventFilter::OriginId(OriginIdPattern::new("*@wonderland"));
+        assert!(filter.matches(&account_created));
+        assert!(!filter.matches(&domain_deleted));
+    }
+
+    #[test]
+    fn asset_entity_matchers() {
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let alice_id = AccountId::new(domain_id.clone(), KeyPair::random().into_parts().0);
+        let bob_id = AccountId::new(domain_id, KeyPair::random().into_parts().0);
+        let rose_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let tulip_id: AssetDefinitionId = "tulip#wonderland".parse().unwrap();
+
+        let added = |definition_id: &AssetDefinitionId, account_id: &AccountId| -> DataEvent {
+            AssetEvent::Added(AssetChanged {
+                asset_id: AssetId::new(definition_id.clone(), account_id.clone()),
+                amount: Numeric::ONE.into(),
+            })
+            .into()
+        };
+        let bob_rose_added = added(&rose_id, &bob_id);
+        let bob_tulip_added = added(&tulip_id, &bob_id);
+        let alice_rose_added = added(&rose_id, &alice_id);
+
+        let roses_of_bob = DataEventFilter::Asset(
+            AssetEventFilter::new()
+                .for_asset_definition(rose_id.clone())
+                .for_account(bob_id.clone())
+                .for_events(AssetEventSet::Added),
+        );
+        assert!(roses_of_bob.matches(&bob_rose_added));
+        assert!(!roses_of_bob.matches(&bob_tulip_added));
+        assert!(!roses_of_bob.matches(&alice_rose_added));
+
+        let roses = DataEventFilter::Asset(AssetEventFilter::new().for_asset_definition(rose_id));
+        let of_bob = DataEventFilter::Asset(AssetEventFilter::new().for_account(bob_id));
+        let filter = roses.clone().and(!of_bob.clone());
+        assert!(filter.matches(&alice_rose_added));
+        assert!(!filter.matches(&bob_rose_added));
+
+        let filter = roses.or(of_bob);
+        assert!(filter.matches(&alice_rose_added));
+        assert!(filter.matches(&bob_tulip_added));
+        assert!(!(!filter).matches(&bob_tulip_added));
+    }
 }
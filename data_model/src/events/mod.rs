@@ -1,7 +1,7 @@
 //! Events for streaming API.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
 
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
@@ -16,6 +16,7 @@ pub mod data;
 pub mod execute_trigger;
 pub mod pipeline;
 pub mod time;
+pub mod transaction_validation;
 pub mod trigger_completed;
 
 #[model]
@@ -49,6 +50,8 @@ mod model {
         ExecuteTrigger(execute_trigger::ExecuteTriggerEvent),
         /// Trigger completion event.
         TriggerCompleted(trigger_completed::TriggerCompletedEvent),
+        /// Transaction validation event.
+        TransactionValidation(transaction_validation::TransactionValidationEvent),
     }
 
     /// Event type which could invoke trigger execution.
@@ -64,6 +67,8 @@ mod model {
         Time,
         /// Trigger execution event.
         ExecuteTrigger,
+        /// Transaction validation event.
+        TransactionValidation,
     }
 
     /// Event filter.
@@ -95,6 +100,24 @@ mod model {
         ExecuteTrigger(execute_trigger::ExecuteTriggerEventFilter),
         /// Listen to trigger completion event with filter.
         TriggerCompleted(trigger_completed::TriggerCompletedEventFilter),
+        /// Listen to events matched by every filter.
+        And(
+            #[skip_from]
+            #[skip_try_from]
+            Vec<EventFilterBox>,
+        ),
+        /// Listen to events matched by any of the filters.
+        Or(
+            #[skip_from]
+            #[skip_try_from]
+            Vec<EventFilterBox>,
+        ),
+        /// Listen to events not matched by the filter.
+        Not(
+            #[skip_from]
+            #[skip_try_from]
+            Box<EventFilterBox>,
+        ),
     }
 
     /// Event filter which could be attached to trigger.
@@ -124,6 +147,8 @@ mod model {
         Time(time::TimeEventFilter),
         /// Listen to trigger execution event with filter.
         ExecuteTrigger(execute_trigger::ExecuteTriggerEventFilter),
+        /// Validate transactions before they are applied.
+        TransactionValidation(transaction_validation::TransactionValidationEventFilter),
     }
 }
 
@@ -171,6 +196,43 @@ impl TryFrom<EventBox> for BlockEvent {
     }
 }
 
+impl EventFilterBox {
+    /// Combine filters into one matching events matched by both of them
+    #[must_use]
+    pub fn and(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::And(mut filters) => {
+                filters.push(other.into());
+                Self::And(filters)
+            }
+            filter => Self::And(vec![filter, other.into()]),
+        }
+    }
+
+    /// Combine filters into one matching events matched by any of them
+    #[must_use]
+    pub fn or(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::Or(mut filters) => {
+                filters.push(other.into());
+                Self::Or(filters)
+            }
+            filter => Self::Or(vec![filter, other.into()]),
+        }
+    }
+}
+
+impl core::ops::Not for EventFilterBox {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Self::Not(filter) => *filter,
+            filter => Self::Not(Box::new(filter)),
+        }
+    }
+}
+
 /// Trait for filters
 #[cfg(feature = "transparent_api")]
 pub trait EventFilter {
@@ -215,13 +277,17 @@ impl EventFilter for EventFilterBox {
             (EventBox::TriggerCompleted(event), Self::TriggerCompleted(filter)) => {
                 filter.matches(event)
             }
+            (event, Self::And(filters)) => filters.iter().all(|filter| filter.matches(event)),
+            (event, Self::Or(filters)) => filters.iter().any(|filter| filter.matches(event)),
+            (event, Self::Not(filter)) => !filter.matches(event),
             // Fail to compile in case when new variant to event or filter is added
             (
                 EventBox::Pipeline(_)
                 | EventBox::Data(_)
                 | EventBox::Time(_)
                 | EventBox::ExecuteTrigger(_)
-                | EventBox::TriggerCompleted(_),
+                | EventBox::TriggerCompleted(_)
+                | EventBox::TransactionValidation(_),
                 Self::Pipeline(_)
                 | Self::Data(_)
                 | Self::Time(_)
@@ -245,14 +311,22 @@ impl EventFilter for TriggeringEventFilterBox {
             (EventBox::ExecuteTrigger(event), Self::ExecuteTrigger(filter)) => {
                 filter.matches(event)
             }
+            (EventBox::TransactionValidation(event), Self::TransactionValidation(filter)) => {
+                filter.matches(event)
+            }
             // Fail to compile in case when new variant to event or filter is added
             (
                 EventBox::Pipeline(_)
                 | EventBox::Data(_)
                 | EventBox::Time(_)
                 | EventBox::ExecuteTrigger(_)
-                | EventBox::TriggerCompleted(_),
-                Self::Pipeline(_) | Self::Data(_) | Self::Time(_) | Self::ExecuteTrigger(_),
+                | EventBox::TriggerCompleted(_)
+                | EventBox::TransactionValidation(_),
+                Self::Pipeline(_)
+                | Self::Data(_)
+                | Self::Time(_)
+                | Self::ExecuteTrigger(_)
+                | Self::TransactionValidation(_),
             ) => false,
         }
     }
@@ -322,12 +396,16 @@ mod conversions {
 pub mod stream {
     //! Structures related to event streaming over HTTP
 
+    use core::num::NonZeroU64;
+
     use derive_more::Constructor;
+    use iroha_crypto::SignatureOf;
     use iroha_data_model_derive::model;
     use iroha_version::prelude::*;
 
     pub use self::model::*;
     use super::*;
+    use crate::account::AccountId;
 
     #[model]
     mod model {
@@ -339,11 +417,101 @@ pub mod stream {
         #[repr(transparent)]
         pub struct EventMessage(pub EventBox);
 
+        /// Message sent by the stream producer.
+        /// Event sent by the peer over a subscription with acknowledged delivery.
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct AcknowledgedEventMessage {
+            /// Number to acknowledge the event with, increasing within the subscription
+            pub sequence_number: u64,
+            /// Event matching the filters of the subscription
+            pub event: EventBox,
+        }
+
         /// Message sent by the stream consumer.
         /// Request sent by the client to subscribe to events.
         #[derive(Debug, Clone, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema)]
-        #[repr(transparent)]
-        pub struct EventSubscriptionRequest(pub Vec<EventFilterBox>);
+        pub struct EventSubscriptionRequest {
+            /// Events matching any of the filters are sent
+            pub filters: Vec<EventFilterBox>,
+            /// If specified, pipeline events of the blocks committed starting from this height
+            /// are replayed before the events emitted after the subscription
+            pub from_block_height: Option<NonZeroU64>,
+            /// If specified, events are sent as [`AcknowledgedEventMessage`]s and
+            /// the unacknowledged ones are sent again on resubscription
+            pub acknowledged_delivery: Option<SignedAcknowledgedDelivery>,
+        }
+
+        /// Acknowledged delivery of the events of a subscription.
+        ///
+        /// The peer keeps the events until they are acknowledged, including the ones
+        /// emitted while the subscriber is disconnected, and sends them again once
+        /// it subscribes with the same id.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            Constructor,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        pub struct AcknowledgedDelivery {
+            /// Id chosen by the subscriber to resume the subscription with
+            pub subscription_id: String,
+            /// What happens when the peer keeps too many unacknowledged events
+            pub overflow_policy: EventOverflowPolicy,
+        }
+
+        /// [`AcknowledgedDelivery`] requested by an account.
+        ///
+        /// Subscriptions are kept separately for every account
+        /// and the peer limits how many subscriptions an account may have.
+        #[derive(
+            Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+        )]
+        pub struct SignedAcknowledgedDelivery {
+            /// Account the subscription belongs to
+            pub authority: AccountId,
+            /// Requested delivery
+            pub delivery: AcknowledgedDelivery,
+            /// Signature of the delivery by the signatory of `authority`
+            pub signature: SignatureOf<AcknowledgedDelivery>,
+        }
+
+        /// What happens when the unacknowledged events of a subscription exceed the capacity of the peer.
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Default,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        pub enum EventOverflowPolicy {
+            /// The oldest unacknowledged event is discarded
+            #[default]
+            DropOldest,
+            /// The subscription is terminated along with its events
+            Terminate,
+        }
+
+        /// Message sent by the stream consumer.
+        /// Acknowledgement of all the events up to the one with the given sequence number.
+        #[derive(
+            Debug, Clone, Copy, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema,
+        )]
+        pub struct EventAcknowledgement {
+            /// Sequence number of the last processed event
+            pub sequence_number: u64,
+        }
     }
 
     impl From<EventMessage> for EventBox {
@@ -351,17 +519,71 @@ pub mod stream {
             source.0
         }
     }
+
+    impl SignedAcknowledgedDelivery {
+        /// Sign `delivery` on behalf of `authority` with its `key_pair`
+        pub fn new(
+            authority: AccountId,
+            delivery: AcknowledgedDelivery,
+            key_pair: &iroha_crypto::KeyPair,
+        ) -> Self {
+            let signature = SignatureOf::new(key_pair, &delivery);
+            Self {
+                authority,
+                delivery,
+                signature,
+            }
+        }
+
+        /// Check that the delivery is signed by the signatory of its authority
+        pub fn is_signed_by_authority(&self) -> bool {
+            self.signature.public_key() == &self.authority.signatory
+                && self.signature.verify(&self.delivery).is_ok()
+        }
+    }
 }
 
 /// Exports common structs and enums from this module.
 pub mod prelude {
     #[cfg(feature = "http")]
-    pub use super::stream::{EventMessage, EventSubscriptionRequest};
+    pub use super::stream::{
+        AcknowledgedDelivery, AcknowledgedEventMessage, EventAcknowledgement, EventMessage,
+        EventOverflowPolicy, EventSubscriptionRequest, SignedAcknowledgedDelivery,
+    };
     #[cfg(feature = "transparent_api")]
     pub use super::EventFilter;
     pub use super::{
         data::prelude::*, execute_trigger::prelude::*, pipeline::prelude::*, time::prelude::*,
-        trigger_completed::prelude::*, EventBox, EventFilterBox, TriggeringEventFilterBox,
-        TriggeringEventType,
+        transaction_validation::prelude::*, trigger_completed::prelude::*, EventBox,
+        EventFilterBox, TriggeringEventFilterBox, TriggeringEventType,
     };
 }
+
+#[cfg(test)]
+#[cfg(feature = "transparent_api")]
+mod tests {
+    use super::{prelude::*, *};
+    use crate::prelude::*;
+
+    #[test]
+    fn boolean_combinations() {
+        let wonderland_id: DomainId = "wonderland".parse().unwrap();
+        let garden_id: DomainId = "garden".parse().unwrap();
+        let wonderland_deleted: EventBox =
+            DataEvent::from(DomainEvent::Deleted(wonderland_id.clone())).into();
+        let garden_deleted: EventBox = DataEvent::from(DomainEvent::Deleted(garden_id)).into();
+
+        let any_domain = EventFilterBox::from(DomainEventFilter::new());
+        let wonderland = EventFilterBox::from(DomainEventFilter::new().for_domain(wonderland_id));
+
+        let not_wonderland = any_domain.clone().and(!wonderland.clone());
+        assert!(!not_wonderland.matches(&wonderland_deleted));
+        assert!(not_wonderland.matches(&garden_deleted));
+
+        let wonderland_or_pipeline = wonderland.or(BlockEventFilter::default());
+        assert!(wonderland_or_pipeline.matches(&wonderland_deleted));
+        assert!(!wonderland_or_pipeline.matches(&garden_deleted));
+
+        assert_eq!(!!any_domain.clone(), any_domain);
+    }
+}
@@ -140,6 +140,8 @@ mod model {
         Approved,
         /// Transaction was stored in the block as invalid
         Rejected(Box<crate::transaction::error::TransactionRejectionReason>),
+        /// Block with the transaction was committed and applied, so its approval or rejection is final
+        Committed,
     }
 
     #[derive(
@@ -44,7 +44,6 @@ mod model {
     #[derive(
         Debug,
         Clone,
-        Copy,
         PartialEq,
         Eq,
         PartialOrd,
@@ -64,7 +63,6 @@ mod model {
     #[derive(
         Debug,
         Clone,
-        Copy,
         PartialEq,
         Eq,
         PartialOrd,
@@ -80,6 +78,8 @@ mod model {
         PreCommit,
         /// Execute with some schedule
         Schedule(Schedule),
+        /// Execute once at each of the given timestamps
+        Timestamps(Vec<Duration>),
     }
 
     /// Schedule of the trigger
@@ -143,62 +143,70 @@ impl EventFilter for TimeEventFilter {
     fn count_matches(&self, event: &TimeEvent) -> u32 {
         match &self.0 {
             ExecutionTime::PreCommit => 1,
-            ExecutionTime::Schedule(schedule) => {
-                // Prevent matching in the future it will be handled by the next block
-                if schedule.start > event.interval.since {
-                    return 0;
-                }
-
-                let current_interval = event.prev_interval.map_or(event.interval, |prev| {
-                    // Case 1:
-                    // ----|-----[--[--)--)-----
-                    //     s    p1 c1  p2 c2
-                    //
-                    // Schedule start was before previous block (p1).
-                    // In this case we only care about interval [p2, c2)
-                    // Because everything up to p2 (excluding) was processed in the previous blocks.
-                    //
-                    // Case 2:
-                    // ---------[-|-[--)--)-----
-                    //         p1 s c1 p2 c2
-                    //
-                    // ---------[--)--|--[--)---
-                    //          p1 p2 s  c1 c2
-                    //
-                    // Schedule start is between previous block (p1) and current block (c1).
-                    // In this case we care about either interval [s, c2) if (s) is in [p1, p2) or [p2, c2) if (s) is after (p2).
-                    // Because in the previous block [p1, p2) event won't match since (s) was in the future.
-                    //
-                    // Case 3:
-                    // ---------[--[-|-)--)-----
-                    //         p1  c1 s p2 c2
-                    //
-                    // Schedule start is after current block (c1).
-                    // In this case event won't match and it will be handled in the next block.
-                    let since = if Range::from(prev).contains(&schedule.start) {
-                        schedule.start
-                    } else {
-                        prev.since + prev.length
-                    };
-                    let estimation = event.interval.since + event.interval.length;
-                    let length = estimation - since;
-
-                    TimeInterval { since, length }
-                });
-
-                count_matches_in_interval(schedule, &current_interval)
-            }
+            ExecutionTime::Schedule(schedule) => count_schedule_matches(schedule, event),
+            ExecutionTime::Timestamps(timestamps) => timestamps
+                .iter()
+                .map(|timestamp| count_schedule_matches(&Schedule::starting_at(*timestamp), event))
+                .sum(),
         }
     }
 
     fn mintable(&self) -> bool {
         !matches!(
             self.0,
-            ExecutionTime::Schedule(Schedule { period: None, .. })
+            ExecutionTime::Schedule(Schedule { period: None, .. }) | ExecutionTime::Timestamps(_)
         )
     }
 }
 
+/// Count executions of the `schedule` which should be handled in the block of the time `event`
+#[cfg(feature = "transparent_api")]
+fn count_schedule_matches(schedule: &Schedule, event: &TimeEvent) -> u32 {
+    // Prevent matching in the future it will be handled by the next block
+    if schedule.start > event.interval.since {
+        return 0;
+    }
+
+    let current_interval = event.prev_interval.map_or(event.interval, |prev| {
+        // Case 1:
+        // ----|-----[--[--)--)-----
+        //     s    p1 c1  p2 c2
+        //
+        // Schedule start was before previous block (p1).
+        // In this case we only care about interval [p2, c2)
+        // Because everything up to p2 (excluding) was processed in the previous blocks.
+        //
+        // Case 2:
+        // ---------[-|-[--)--)-----
+        //         p1 s c1 p2 c2
+        //
+        // ---------[--)--|--[--)---
+        //          p1 p2 s  c1 c2
+        //
+        // Schedule start is between previous block (p1) and current block (c1).
+        // In this case we care about either interval [s, c2) if (s) is in [p1, p2) or [p2, c2) if (s) is after (p2).
+        // Because in the previous block [p1, p2) event won't match since (s) was in the future.
+        //
+        // Case 3:
+        // ---------[--[-|-)--)-----
+        //         p1  c1 s p2 c2
+        //
+        // Schedule start is after current block (c1).
+        // In this case event won't match and it will be handled in the next block.
+        let since = if Range::from(prev).contains(&schedule.start) {
+            schedule.start
+        } else {
+            prev.since + prev.length
+        };
+        let estimation = event.interval.since + event.interval.length;
+        let length = estimation - since;
+
+        TimeInterval { since, length }
+    });
+
+    count_matches_in_interval(schedule, &current_interval)
+}
+
 /// Count something with the `schedule` within the `interval`
 #[cfg(feature = "transparent_api")]
 fn count_matches_in_interval(schedule: &Schedule, interval: &TimeInterval) -> u32 {
@@ -595,5 +603,37 @@ mod tests {
 
             assert_eq!(filter.count_matches(&event), 0);
         }
+
+        #[test]
+        fn test_timestamps_are_matched_once() {
+            //
+            // -------[-*--)--*--[----)--*--
+            //        p1 t1 p2 t2 c1   c2 t3
+
+            let filter = TimeEventFilter(ExecutionTime::Timestamps(vec![
+                Duration::from_secs(TIMESTAMP + 5),
+                Duration::from_secs(TIMESTAMP + 15),
+                Duration::from_secs(TIMESTAMP + 40),
+            ]));
+
+            let since = Duration::from_secs(TIMESTAMP);
+            let length = Duration::from_secs(10);
+            let prev_interval = TimeInterval { since, length };
+            let prev_event = TimeEvent {
+                prev_interval: None,
+                interval: prev_interval,
+            };
+            assert_eq!(filter.count_matches(&prev_event), 0);
+
+            let since = Duration::from_secs(TIMESTAMP + 20);
+            let length = Duration::from_secs(10);
+            let interval = TimeInterval { since, length };
+            let event = TimeEvent {
+                prev_interval: Some(prev_interval),
+                interval,
+            };
+            assert_eq!(filter.count_matches(&event), 2);
+            assert!(!filter.mintable());
+        }
     }
 }
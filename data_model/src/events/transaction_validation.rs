@@ -0,0 +1,108 @@
+//! Transaction validation event and filter
+
+use getset::Getters;
+use iroha_data_model_derive::model;
+
+pub use self::model::*;
+use super::*;
+use crate::{prelude::*, transaction::SignedTransaction};
+
+#[model]
+mod model {
+    use super::*;
+
+    /// Transaction validation event. Produced before a transaction is applied,
+    /// only to execute the triggers which can veto it by failing.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct TransactionValidationEvent {
+        /// Transaction to be applied
+        pub transaction: SignedTransaction,
+    }
+
+    /// Filter for transaction validation [`Event`].
+    ///
+    /// Triggers registered in a domain validate only the transactions of the accounts of this domain.
+    #[derive(
+        Debug,
+        Clone,
+        PartialOrd,
+        Ord,
+        PartialEq,
+        Eq,
+        Default,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct TransactionValidationEventFilter {
+        /// Account whose transactions are validated
+        #[getset(get = "pub")]
+        pub(super) authority: Option<AccountId>,
+    }
+}
+
+impl TransactionValidationEvent {
+    /// Construct [`Self`]
+    #[inline]
+    pub const fn new(transaction: SignedTransaction) -> Self {
+        Self { transaction }
+    }
+}
+
+impl TransactionValidationEventFilter {
+    /// Creates a new [`TransactionValidationEventFilter`] accepting all [`TransactionValidationEvent`]s
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { authority: None }
+    }
+
+    /// Modifies a [`TransactionValidationEventFilter`] to accept only [`TransactionValidationEvent`]s of the transactions submitted by a specific account
+    #[must_use]
+    #[inline]
+    pub fn under_authority(mut self, authority: AccountId) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+}
+
+#[cfg(feature = "transparent_api")]
+impl EventFilter for TransactionValidationEventFilter {
+    type Event = TransactionValidationEvent;
+
+    /// Check if `event` matches filter
+    ///
+    /// Event considered as matched if the transaction authority matches
+    fn matches(&self, event: &TransactionValidationEvent) -> bool {
+        if let Some(authority) = &self.authority {
+            if authority != event.transaction.authority() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Exports common structs and enums from this module.
+pub mod prelude {
+    pub use super::{TransactionValidationEvent, TransactionValidationEventFilter};
+}
@@ -1,8 +1,13 @@
 //! This library contains basic Iroha Special Instructions.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
-use core::fmt::{Debug, Display};
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+use core::{
+    fmt::{Debug, Display},
+    num::NonZeroU16,
+};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
 
 use derive_more::{Constructor, DebugCustom, Display};
 use iroha_data_model_derive::{model, EnumRef};
@@ -10,7 +15,7 @@ use iroha_primitives::numeric::Numeric;
 use iroha_schema::IntoSchema;
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
-use strum::EnumDiscriminants;
+use strum::{EnumDiscriminants, EnumString};
 
 pub use self::{model::*, transparent::*};
 use super::{metadata::MetadataValueBox, prelude::*};
@@ -62,6 +67,7 @@ mod model {
         name(InstructionType),
         derive(
             Display,
+            EnumString,
             PartialOrd,
             Ord,
             Deserialize,
@@ -119,7 +125,90 @@ mod model {
 
         #[debug(fmt = "{_0:?}")]
         Fail(Fail),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        MergeKeyValues(MergeKeyValuesBox),
+        #[debug(fmt = "{_0:?}")]
+        SetDomainMetadataLimits(SetDomainMetadataLimits),
+        #[debug(fmt = "{_0:?}")]
+        SetKeyValueWithExpiry(SetKeyValueWithExpiry),
+        #[debug(fmt = "{_0:?}")]
+        SetKeyValueIf(SetKeyValueIf),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        IncrementKeyValue(IncrementKeyValueBox),
+        #[debug(fmt = "{_0:?}")]
+        Swap(Swap),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Freeze(FreezeBox),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Unfreeze(UnfreezeBox),
+        #[debug(fmt = "{_0:?}")]
+        TransferMany(TransferMany),
+        #[debug(fmt = "{_0:?}")]
+        OpenEscrow(OpenEscrow),
+        #[debug(fmt = "{_0:?}")]
+        ReleaseEscrow(ReleaseEscrow),
+        #[debug(fmt = "{_0:?}")]
+        RefundEscrow(RefundEscrow),
+        #[debug(fmt = "{_0:?}")]
+        AddSignatory(AddSignatory),
+        #[debug(fmt = "{_0:?}")]
+        RemoveSignatory(RemoveSignatory),
+        #[debug(fmt = "{_0:?}")]
+        SetSignatureThreshold(SetSignatureThreshold),
+        #[debug(fmt = "{_0:?}")]
+        RotateKey(RotateKey),
+        #[debug(fmt = "{_0:?}")]
+        SetRecoveryPolicy(SetRecoveryPolicy),
+        #[debug(fmt = "{_0:?}")]
+        RecoverAccount(RecoverAccount),
+        #[debug(fmt = "{_0:?}")]
+        CancelRecovery(CancelRecovery),
+        #[debug(fmt = "{_0:?}")]
+        RegisterAlias(RegisterAlias),
+        #[debug(fmt = "{_0:?}")]
+        UnregisterAlias(UnregisterAlias),
+        #[debug(fmt = "{_0:?}")]
+        DisableAccount(DisableAccount),
+        #[debug(fmt = "{_0:?}")]
+        EnableAccount(EnableAccount),
+        #[debug(fmt = "{_0:?}")]
+        SetStrictOrdering(SetStrictOrdering),
+        #[debug(fmt = "{_0:?}")]
+        Pause(Pause),
+        #[debug(fmt = "{_0:?}")]
+        Resume(Resume),
+        #[debug(fmt = "{_0:?}")]
+        GrantWithTerms(GrantWithTerms),
+        #[debug(fmt = "{_0:?}")]
+        UpgradeTrigger(UpgradeTrigger),
+        #[debug(fmt = "{_0:?}")]
+        FinalizeRecovery(FinalizeRecovery),
     }
+
+    /// Amount standing for the whole balance of a numeric [`Asset`], resolved when the instruction is executed.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Default,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "ALL")]
+    #[ffi_type]
+    pub struct All;
 }
 
 macro_rules! impl_instruction {
@@ -143,6 +232,41 @@ impl_instruction! {
     RemoveKeyValue<Account>,
     RemoveKeyValue<Asset>,
     RemoveKeyValue<Trigger>,
+    MergeKeyValues<Domain>,
+    MergeKeyValues<AssetDefinition>,
+    MergeKeyValues<Account>,
+    MergeKeyValues<Asset>,
+    MergeKeyValues<Trigger>,
+    SetDomainMetadataLimits,
+    RegisterAlias,
+    UnregisterAlias,
+    SetKeyValueWithExpiry,
+    SetKeyValueIf,
+    IncrementKeyValue<Domain>,
+    IncrementKeyValue<AssetDefinition>,
+    IncrementKeyValue<Account>,
+    IncrementKeyValue<Asset>,
+    IncrementKeyValue<Trigger>,
+    Swap,
+    TransferMany,
+    OpenEscrow,
+    ReleaseEscrow,
+    RefundEscrow,
+    AddSignatory,
+    RemoveSignatory,
+    SetSignatureThreshold,
+    RotateKey,
+    SetRecoveryPolicy,
+    RecoverAccount,
+    FinalizeRecovery,
+    CancelRecovery,
+    DisableAccount,
+    EnableAccount,
+    SetStrictOrdering,
+    Freeze<AssetDefinition>,
+    Freeze<Asset>,
+    Unfreeze<AssetDefinition>,
+    Unfreeze<Asset>,
     Register<Peer>,
     Register<Domain>,
     Register<Account>,
@@ -150,6 +274,7 @@ impl_instruction! {
     Register<Asset>,
     Register<Role>,
     Register<Trigger>,
+    Register<WasmCode>,
     Unregister<Peer>,
     Unregister<Domain>,
     Unregister<Account>,
@@ -159,22 +284,30 @@ impl_instruction! {
     Unregister<Trigger>,
     Mint<Numeric, Asset>,
     Mint<u32, Trigger>,
+    Mint<Name, Asset>,
     Burn<Numeric, Asset>,
+    Burn<All, Asset>,
     Burn<u32, Trigger>,
     Transfer<Account, DomainId, Account>,
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Asset, Numeric, Account>,
+    Transfer<Asset, All, Account>,
     Transfer<Asset, Metadata, Account>,
+    Transfer<Asset, Name, Account>,
     Grant<Permission, Account>,
     Grant<RoleId, Account>,
     Grant<Permission, Role>,
     Revoke<Permission, Account>,
     Revoke<RoleId, Account>,
     Revoke<Permission, Role>,
+    GrantWithTerms,
     SetParameter,
     NewParameter,
     Upgrade,
     ExecuteTrigger,
+    Pause,
+    Resume,
+    UpgradeTrigger,
     Log,
     Fail,
 }
@@ -453,6 +586,619 @@ mod transparent {
     => RemoveKeyValueBoxRef<'a> => InstructionBoxRef<'a>[RemoveKeyValue]
     }
 
+    isi! {
+        /// Generic instruction to merge a whole [`Metadata`] tree into the object's metadata.
+        #[schema(bounds = "O: Identifiable, O::Id: IntoSchema")]
+        pub struct MergeKeyValues<O: Identifiable> {
+            /// Where to merge key values.
+            pub object_id: O::Id,
+            /// Key-value pairs to merge.
+            pub metadata: Metadata,
+            /// How to resolve keys that are already present.
+            pub policy: MergePolicy,
+        }
+    }
+
+    impl MergeKeyValues<Domain> {
+        /// Constructs a new [`MergeKeyValues`] for a [`Domain`] with the given `metadata` and `policy`.
+        pub fn domain(domain_id: DomainId, metadata: Metadata, policy: MergePolicy) -> Self {
+            Self {
+                object_id: domain_id,
+                metadata,
+                policy,
+            }
+        }
+    }
+
+    impl MergeKeyValues<Account> {
+        /// Constructs a new [`MergeKeyValues`] for an [`Account`] with the given `metadata` and `policy`.
+        pub fn account(account_id: AccountId, metadata: Metadata, policy: MergePolicy) -> Self {
+            Self {
+                object_id: account_id,
+                metadata,
+                policy,
+            }
+        }
+    }
+
+    impl MergeKeyValues<AssetDefinition> {
+        /// Constructs a new [`MergeKeyValues`] for an [`AssetDefinition`] with the given `metadata` and `policy`.
+        pub fn asset_definition(
+            asset_definition_id: AssetDefinitionId,
+            metadata: Metadata,
+            policy: MergePolicy,
+        ) -> Self {
+            Self {
+                object_id: asset_definition_id,
+                metadata,
+                policy,
+            }
+        }
+    }
+
+    impl MergeKeyValues<Asset> {
+        /// Constructs a new [`MergeKeyValues`] for an [`Asset`] with the given `metadata` and `policy`.
+        pub fn asset(asset_id: AssetId, metadata: Metadata, policy: MergePolicy) -> Self {
+            Self {
+                object_id: asset_id,
+                metadata,
+                policy,
+            }
+        }
+    }
+
+    impl MergeKeyValues<Trigger> {
+        /// Constructs a new [`MergeKeyValues`] for a [`Trigger`] with the given `metadata` and `policy`.
+        pub fn trigger(trigger_id: TriggerId, metadata: Metadata, policy: MergePolicy) -> Self {
+            Self {
+                object_id: trigger_id,
+                metadata,
+                policy,
+            }
+        }
+    }
+
+    impl_display! {
+        MergeKeyValues<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "MERGE `{}` INTO `{}` ({})",
+        metadata, object_id, policy,
+    }
+
+    impl_into_box! {
+        MergeKeyValues<Domain> |
+        MergeKeyValues<Account> |
+        MergeKeyValues<AssetDefinition> |
+        MergeKeyValues<Asset> |
+        MergeKeyValues<Trigger>
+    => MergeKeyValuesBox => InstructionBox[MergeKeyValues],
+    => MergeKeyValuesBoxRef<'a> => InstructionBoxRef<'a>[MergeKeyValues]
+    }
+
+    isi! {
+        /// Instruction to set the metadata limits of the entities registered in a [`Domain`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SET METADATA LIMITS OF `{domain_id}`")]
+        pub struct SetDomainMetadataLimits {
+            /// Domain whose limits are set.
+            pub domain_id: DomainId,
+            /// New limits, replacing the previous ones.
+            pub limits: DomainMetadataLimits,
+        }
+    }
+
+    isi! {
+        /// Instruction to register an alias by which an [`Account`] can be found in its [`Domain`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REGISTER ALIAS `{alias}` FOR `{account_id}`")]
+        pub struct RegisterAlias {
+            /// Alias to register, in the domain of the account.
+            pub alias: AccountAlias,
+            /// Account the alias refers to.
+            pub account_id: AccountId,
+        }
+    }
+
+    isi! {
+        /// Instruction to unregister an alias of an [`Account`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "UNREGISTER ALIAS `{alias}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct UnregisterAlias {
+            /// Alias to unregister.
+            pub alias: AccountAlias,
+        }
+    }
+
+    isi! {
+        /// Instruction to set key value at the object and remove it once `expiry` is reached.
+        ///
+        /// The entry is left intact if its value was changed in the meantime.
+        #[derive(Display)]
+        #[display(fmt = "{set} UNTIL {expiry}")]
+        pub struct SetKeyValueWithExpiry {
+            /// Key value to set.
+            pub set: SetKeyValueBox,
+            /// When the key value is removed.
+            pub expiry: MetadataExpiry,
+        }
+    }
+
+    impl SetKeyValueWithExpiry {
+        /// Constructs a new [`SetKeyValueWithExpiry`] from the given [`SetKeyValue`] and `expiry`.
+        pub fn new(set: impl Into<SetKeyValueBox>, expiry: MetadataExpiry) -> Self {
+            Self {
+                set: set.into(),
+                expiry,
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to set key value at the object only if the current value is `expected`.
+        #[derive(Display)]
+        #[display(fmt = "{set} IF CURRENT VALUE IS `{expected:?}`")]
+        pub struct SetKeyValueIf {
+            /// Key value to set.
+            pub set: SetKeyValueBox,
+            /// Value the key must currently have, `None` if the key must be absent.
+            pub expected: Option<MetadataValueBox>,
+        }
+    }
+
+    impl SetKeyValueIf {
+        /// Constructs a new [`SetKeyValueIf`] from the given [`SetKeyValue`] and `expected` value.
+        pub fn new(set: impl Into<SetKeyValueBox>, expected: Option<MetadataValueBox>) -> Self {
+            Self {
+                set: set.into(),
+                expected,
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to exchange assets between two accounts in both directions at once.
+        ///
+        /// Each leg must move an asset to the account which is the source of the other leg.
+        /// Submitted by one of these accounts, the swap is only offered. Both legs are executed
+        /// once the other account submits the same swap, and an account has at most one offer.
+        #[derive(Display)]
+        #[display(fmt = "SWAP {first} AND {second}")]
+        pub struct Swap {
+            /// Transfer from the first account to the second one.
+            pub first: AssetTransferBox,
+            /// Transfer from the second account back to the first one.
+            pub second: AssetTransferBox,
+        }
+    }
+
+    impl Swap {
+        /// Constructs a new [`Swap`] from the given transfers.
+        pub fn new(
+            first: impl Into<AssetTransferBox>,
+            second: impl Into<AssetTransferBox>,
+        ) -> Self {
+            Self {
+                first: first.into(),
+                second: second.into(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to transfer a numeric asset from one account to many recipients at once.
+        ///
+        /// Either every recipient receives its quantity or none does.
+        #[derive(Display)]
+        #[display(fmt = "TRANSFER `{source_id}` TO {} RECIPIENTS", "recipients.len()")]
+        pub struct TransferMany {
+            /// Asset to transfer from.
+            pub source_id: AssetId,
+            /// Quantity transferred to each of the receiving accounts.
+            pub recipients: BTreeMap<AccountId, Numeric>,
+        }
+    }
+
+    impl TransferMany {
+        /// Constructs a new [`TransferMany`] of the asset `source_id` to the given recipients.
+        pub fn new(
+            source_id: AssetId,
+            recipients: impl IntoIterator<Item = (AccountId, Numeric)>,
+        ) -> Self {
+            Self {
+                source_id,
+                recipients: recipients.into_iter().collect(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to lock an amount of a numeric asset in a new [`Escrow`].
+        ///
+        /// The amount is taken from the depositor right away and is paid out by [`ReleaseEscrow`] or [`RefundEscrow`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "OPEN ESCROW `{escrow_id}`: {escrow}")]
+        pub struct OpenEscrow {
+            /// Id of the escrow to open.
+            pub escrow_id: EscrowId,
+            /// Escrowed amount and the conditions of its settlement.
+            pub escrow: Escrow,
+        }
+    }
+
+    isi! {
+        /// Instruction to pay the amount locked in an [`Escrow`] out to its recipient.
+        ///
+        /// Fails unless the [`EscrowCondition`] of the escrow holds.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "RELEASE ESCROW `{escrow_id}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct ReleaseEscrow {
+            /// Id of the escrow to release.
+            pub escrow_id: EscrowId,
+        }
+    }
+
+    isi! {
+        /// Instruction to return the amount locked in an [`Escrow`] to its depositor.
+        ///
+        /// The recipient can refund at any time, the depositor only once the refund expiry of the escrow is reached.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REFUND ESCROW `{escrow_id}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct RefundEscrow {
+            /// Id of the escrow to refund.
+            pub escrow_id: EscrowId,
+        }
+    }
+
+    isi! {
+        /// Instruction to add a signatory to the [`SignatureCondition`] of an [`Account`].
+        ///
+        /// An account without a condition gets one requiring a signature of either its own signatory or the added one.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ADD SIGNATORY `{public_key}` TO `{account_id}`")]
+        pub struct AddSignatory {
+            /// Account whose signatories are extended.
+            pub account_id: AccountId,
+            /// Public key of the new signatory.
+            pub public_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to remove a signatory from the [`SignatureCondition`] of an [`Account`].
+        ///
+        /// Fails if fewer signatories than the threshold would remain.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REMOVE SIGNATORY `{public_key}` FROM `{account_id}`")]
+        pub struct RemoveSignatory {
+            /// Account whose signatory is removed.
+            pub account_id: AccountId,
+            /// Public key of the removed signatory.
+            pub public_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to change the number of signatories which must sign transactions of an [`Account`].
+        ///
+        /// An account without a [`SignatureCondition`] gets one with its own signatory as the only one.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SET SIGNATURE THRESHOLD OF `{account_id}` TO {threshold}")]
+        pub struct SetSignatureThreshold {
+            /// Account whose threshold is changed.
+            pub account_id: AccountId,
+            /// New number of required signatories.
+            pub threshold: NonZeroU16,
+        }
+    }
+
+    isi! {
+        /// Instruction to replace a signatory of an [`Account`] with another one in a single step.
+        ///
+        /// An account without a [`SignatureCondition`] gets one with its own signatory as the only one,
+        /// so rotating that key doesn't change the [`AccountId`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ROTATE KEY `{old_public_key}` OF `{account_id}` TO `{new_public_key}`")]
+        pub struct RotateKey {
+            /// Account whose signatory is replaced.
+            pub account_id: AccountId,
+            /// Public key of the replaced signatory.
+            pub old_public_key: PublicKey,
+            /// Public key of the new signatory.
+            pub new_public_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to set the guardians which may recover an [`Account`].
+        ///
+        /// Discards the recovery pending under the previous policy.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SET RECOVERY POLICY OF `{account_id}` TO {policy}")]
+        pub struct SetRecoveryPolicy {
+            /// Account whose recovery policy is set.
+            pub account_id: AccountId,
+            /// Guardians of the account.
+            pub policy: RecoveryPolicy,
+        }
+    }
+
+    isi! {
+        /// Instruction by which a guardian approves replacing the [`SignatureCondition`] of an [`Account`].
+        ///
+        /// Approvals are counted separately for every proposed condition, and a guardian
+        /// approves one condition at a time: approving another one withdraws its previous approval.
+        /// Once the quorum of guardians approved a condition, it can be applied with
+        /// [`FinalizeRecovery`] after the delay of the [`RecoveryPolicy`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "RECOVER `{account_id}` TO {signature_condition}")]
+        pub struct RecoverAccount {
+            /// Account to recover.
+            pub account_id: AccountId,
+            /// Signature condition replacing the one of the account.
+            pub signature_condition: SignatureCondition,
+        }
+    }
+
+    isi! {
+        /// Instruction to replace the [`SignatureCondition`] of an [`Account`] with the one
+        /// approved by the quorum of its guardians once the delay of the [`RecoveryPolicy`] passed.
+        ///
+        /// Anyone may submit it, the conditions are checked on execution.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "FINALIZE RECOVERY OF `{account_id}` TO {signature_condition}")]
+        pub struct FinalizeRecovery {
+            /// Account to recover.
+            pub account_id: AccountId,
+            /// Approved signature condition replacing the one of the account.
+            pub signature_condition: SignatureCondition,
+        }
+    }
+
+    isi! {
+        /// Instruction to cancel the pending recoveries of an [`Account`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "CANCEL RECOVERY OF `{account_id}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct CancelRecovery {
+            /// Account whose recoveries are cancelled.
+            pub account_id: AccountId,
+        }
+    }
+
+    isi! {
+        /// Instruction to disable an [`Account`].
+        ///
+        /// A disabled account keeps its assets and history, but can't submit
+        /// transactions or receive transfers until it is enabled again.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "DISABLE `{account_id}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct DisableAccount {
+            /// Account to disable.
+            pub account_id: AccountId,
+        }
+    }
+
+    isi! {
+        /// Instruction to enable an [`Account`] disabled by [`DisableAccount`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ENABLE `{account_id}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct EnableAccount {
+            /// Account to enable.
+            pub account_id: AccountId,
+        }
+    }
+
+    isi! {
+        /// Instruction to turn the strict ordering of the transactions of an [`Account`] on or off.
+        ///
+        /// In strict ordering mode every transaction of the account has to carry the sequence
+        /// number following the one of the last executed transaction, otherwise it is rejected.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SET STRICT ORDERING OF `{account_id}` TO {strict}")]
+        pub struct SetStrictOrdering {
+            /// Account whose ordering mode is set.
+            pub account_id: AccountId,
+            /// Whether the transactions of the account are strictly ordered.
+            pub strict: bool,
+        }
+    }
+
+    isi! {
+        /// Generic instruction to change a numeric metadata entry of the object by `delta`.
+        ///
+        /// Fails if the entry is missing or isn't numeric.
+        #[schema(bounds = "O: Identifiable, O::Id: IntoSchema")]
+        pub struct IncrementKeyValue<O: Identifiable> {
+            /// Where to change the key value.
+            pub object_id: O::Id,
+            /// Key of the numeric entry.
+            pub key: Name,
+            /// Change applied to the entry.
+            pub delta: NumericDelta,
+        }
+    }
+
+    impl IncrementKeyValue<Domain> {
+        /// Constructs a new [`IncrementKeyValue`] for a [`Domain`] with the given `key` and `delta`.
+        pub fn domain(domain_id: DomainId, key: Name, delta: NumericDelta) -> Self {
+            Self {
+                object_id: domain_id,
+                key,
+                delta,
+            }
+        }
+    }
+
+    impl IncrementKeyValue<Account> {
+        /// Constructs a new [`IncrementKeyValue`] for an [`Account`] with the given `key` and `delta`.
+        pub fn account(account_id: AccountId, key: Name, delta: NumericDelta) -> Self {
+            Self {
+                object_id: account_id,
+                key,
+                delta,
+            }
+        }
+    }
+
+    impl IncrementKeyValue<AssetDefinition> {
+        /// Constructs a new [`IncrementKeyValue`] for an [`AssetDefinition`] with the given `key` and `delta`.
+        pub fn asset_definition(
+            asset_definition_id: AssetDefinitionId,
+            key: Name,
+            delta: NumericDelta,
+        ) -> Self {
+            Self {
+                object_id: asset_definition_id,
+                key,
+                delta,
+            }
+        }
+    }
+
+    impl IncrementKeyValue<Asset> {
+        /// Constructs a new [`IncrementKeyValue`] for an [`Asset`] with the given `key` and `delta`.
+        pub fn asset(asset_id: AssetId, key: Name, delta: NumericDelta) -> Self {
+            Self {
+                object_id: asset_id,
+                key,
+                delta,
+            }
+        }
+    }
+
+    impl IncrementKeyValue<Trigger> {
+        /// Constructs a new [`IncrementKeyValue`] for a [`Trigger`] with the given `key` and `delta`.
+        pub fn trigger(trigger_id: TriggerId, key: Name, delta: NumericDelta) -> Self {
+            Self {
+                object_id: trigger_id,
+                key,
+                delta,
+            }
+        }
+    }
+
+    impl_display! {
+        IncrementKeyValue<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "INCREMENT `{}` BY `{}` IN `{}`",
+        key, delta, object_id,
+    }
+
+    impl_into_box! {
+        IncrementKeyValue<Domain> |
+        IncrementKeyValue<Account> |
+        IncrementKeyValue<AssetDefinition> |
+        IncrementKeyValue<Asset> |
+        IncrementKeyValue<Trigger>
+    => IncrementKeyValueBox => InstructionBox[IncrementKeyValue],
+    => IncrementKeyValueBoxRef<'a> => InstructionBoxRef<'a>[IncrementKeyValue]
+    }
+
+    isi! {
+        /// Generic instruction to block transfers and burns of the object's assets.
+        #[schema(bounds = "O: Identifiable, O::Id: IntoSchema")]
+        pub struct Freeze<O: Identifiable> {
+            /// [`Identifiable::Id`] of the object which should be frozen.
+            pub object_id: O::Id,
+        }
+    }
+
+    impl Freeze<AssetDefinition> {
+        /// Constructs a new [`Freeze`] for all assets of an [`AssetDefinition`].
+        pub fn asset_definition(asset_definition_id: AssetDefinitionId) -> Self {
+            Self {
+                object_id: asset_definition_id,
+            }
+        }
+    }
+
+    impl Freeze<Asset> {
+        /// Constructs a new [`Freeze`] for an [`Asset`].
+        pub fn asset(asset_id: AssetId) -> Self {
+            Self {
+                object_id: asset_id,
+            }
+        }
+    }
+
+    impl_display! {
+        Freeze<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "FREEZE `{}`",
+        object_id,
+    }
+
+    impl_into_box! {
+        Freeze<AssetDefinition> |
+        Freeze<Asset>
+    => FreezeBox => InstructionBox[Freeze],
+    => FreezeBoxRef<'a> => InstructionBoxRef<'a>[Freeze]
+    }
+
+    isi! {
+        /// Generic instruction to lift a [`Freeze`] of the object.
+        #[schema(bounds = "O: Identifiable, O::Id: IntoSchema")]
+        pub struct Unfreeze<O: Identifiable> {
+            /// [`Identifiable::Id`] of the object which should be unfrozen.
+            pub object_id: O::Id,
+        }
+    }
+
+    impl Unfreeze<AssetDefinition> {
+        /// Constructs a new [`Unfreeze`] for all assets of an [`AssetDefinition`].
+        pub fn asset_definition(asset_definition_id: AssetDefinitionId) -> Self {
+            Self {
+                object_id: asset_definition_id,
+            }
+        }
+    }
+
+    impl Unfreeze<Asset> {
+        /// Constructs a new [`Unfreeze`] for an [`Asset`].
+        pub fn asset(asset_id: AssetId) -> Self {
+            Self {
+                object_id: asset_id,
+            }
+        }
+    }
+
+    impl_display! {
+        Unfreeze<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "UNFREEZE `{}`",
+        object_id,
+    }
+
+    impl_into_box! {
+        Unfreeze<AssetDefinition> |
+        Unfreeze<Asset>
+    => UnfreezeBox => InstructionBox[Unfreeze],
+    => UnfreezeBoxRef<'a> => InstructionBoxRef<'a>[Unfreeze]
+    }
+
     isi! {
         /// Generic instruction for a registration of an object to the identifiable destination.
         #[schema(bounds = "O: Registered, O::With: IntoSchema")]
@@ -518,6 +1264,15 @@ mod transparent {
         }
     }
 
+    impl Register<WasmCode> {
+        /// Constructs a new [`Register`] for a [`WasmCode`].
+        pub fn wasm_code(code: WasmSmartContract) -> Self {
+            Self {
+                object: WasmCode::new(code),
+            }
+        }
+    }
+
     impl_display! {
         Register<O>
         where
@@ -535,7 +1290,8 @@ mod transparent {
         Register<AssetDefinition> |
         Register<Asset> |
         Register<Role> |
-        Register<Trigger>
+        Register<Trigger> |
+        Register<WasmCode>
     => RegisterBox => InstructionBox[Register],
     => RegisterBoxRef<'a> => InstructionBoxRef<'a>[Register]
     }
@@ -661,6 +1417,16 @@ mod transparent {
         }
     }
 
+    impl Mint<Name, Asset> {
+        /// Constructs a new [`Mint`] for a token of an [`Asset`] of [`Nft`](AssetValueType::Nft) type.
+        pub fn nft(token_id: Name, asset_id: AssetId) -> Self {
+            Self {
+                object: token_id,
+                destination_id: asset_id,
+            }
+        }
+    }
+
     impl_display! {
         Mint<O, D>
         where
@@ -675,7 +1441,8 @@ mod transparent {
 
     impl_into_box! {
         Mint<Numeric, Asset> |
-        Mint<u32, Trigger>
+        Mint<u32, Trigger> |
+        Mint<Name, Asset>
     => MintBox => InstructionBox[Mint],
     => MintBoxRef<'a> => InstructionBoxRef<'a>[Mint]
     }
@@ -701,6 +1468,16 @@ mod transparent {
         }
     }
 
+    impl Burn<All, Asset> {
+        /// Constructs a new [`Burn`] of the whole balance of an [`Asset`] of [`Numeric`] type.
+        pub fn asset_all(asset_id: AssetId) -> Self {
+            Self {
+                object: All,
+                destination_id: asset_id,
+            }
+        }
+    }
+
     impl Burn<u32, Trigger> {
         /// Constructs a new [`Burn`] for repetition count of [`Trigger`].
         pub fn trigger_repetitions(repetitions: u32, trigger_id: TriggerId) -> Self {
@@ -725,6 +1502,7 @@ mod transparent {
 
     impl_into_box! {
         Burn<Numeric, Asset> |
+        Burn<All, Asset> |
         Burn<u32, Trigger>
     => BurnBox => InstructionBox[Burn],
     => BurnBoxRef<'a> => InstructionBoxRef<'a>[Burn]
@@ -786,6 +1564,17 @@ mod transparent {
         }
     }
 
+    impl Transfer<Asset, All, Account> {
+        /// Constructs a new [`Transfer`] of the whole balance of an [`Asset`] of [`Numeric`] type.
+        pub fn asset_all(asset_id: AssetId, to: AccountId) -> Self {
+            Self {
+                source_id: asset_id,
+                object: All,
+                destination_id: to,
+            }
+        }
+    }
+
     impl Transfer<Asset, Metadata, Account> {
         /// Constructs a new [`Transfer`] for an [`Asset`] of [`Store`] type.
         pub fn asset_store(asset_id: AssetId, to: AccountId) -> Self {
@@ -797,6 +1586,17 @@ mod transparent {
         }
     }
 
+    impl Transfer<Asset, Name, Account> {
+        /// Constructs a new [`Transfer`] for a token of an [`Asset`] of [`Nft`](AssetValueType::Nft) type.
+        pub fn nft(asset_id: AssetId, token_id: Name, to: AccountId) -> Self {
+            Self {
+                source_id: asset_id,
+                object: token_id,
+                destination_id: to,
+            }
+        }
+    }
+
     impl_display! {
         Transfer<S, O, D>
         where
@@ -813,7 +1613,10 @@ mod transparent {
     }
 
     impl_into_box! {
-        Transfer<Asset, Numeric, Account> | Transfer<Asset, Metadata, Account>
+        Transfer<Asset, Numeric, Account> |
+        Transfer<Asset, All, Account> |
+        Transfer<Asset, Metadata, Account> |
+        Transfer<Asset, Name, Account>
     => AssetTransferBox => TransferBox[Asset],
     => AssetTransferBoxRef<'a> => TransferBoxRef<'a>[Asset]
     }
@@ -821,7 +1624,10 @@ mod transparent {
     impl_into_box! {
         Transfer<Account, DomainId, Account> |
         Transfer<Account, AssetDefinitionId, Account> |
-        Transfer<Asset, Numeric, Account> | Transfer<Asset, Metadata, Account>
+        Transfer<Asset, Numeric, Account> |
+        Transfer<Asset, All, Account> |
+        Transfer<Asset, Metadata, Account> |
+        Transfer<Asset, Name, Account>
     => TransferBox => InstructionBox[Transfer],
     => TransferBoxRef<'a> => InstructionBoxRef<'a>[Transfer]
     }
@@ -899,6 +1705,20 @@ mod transparent {
     => GrantBoxRef<'a> => InstructionBoxRef<'a>[Grant]
     }
 
+    isi! {
+        /// Instruction to grant a [`Permission`] to an [`Account`] on the given terms.
+        ///
+        /// The permission is revoked once the expiry of the terms is reached.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "{grant} {terms}")]
+        pub struct GrantWithTerms {
+            /// Permission grant.
+            pub grant: Grant<Permission, Account>,
+            /// Terms of the grant.
+            pub terms: PermissionGrantTerms,
+        }
+    }
+
     isi! {
         /// Generic instruction for revoking permission from an entity.
         #[schema(bounds = "O: IntoSchema, D: Identifiable, D::Id: IntoSchema")]
@@ -972,6 +1792,42 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Instruction to pause specified trigger, so that it isn't executed until resumed
+        #[derive(Constructor, Display)]
+        #[display(fmt = "PAUSE `{trigger_id}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct Pause {
+            /// Id of a trigger to pause
+            pub trigger_id: TriggerId,
+        }
+    }
+
+    isi! {
+        /// Instruction to resume specified trigger paused by [`Pause`]
+        #[derive(Constructor, Display)]
+        #[display(fmt = "RESUME `{trigger_id}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct Resume {
+            /// Id of a trigger to resume
+            pub trigger_id: TriggerId,
+        }
+    }
+
+    isi! {
+        /// Instruction to replace the executable of specified trigger keeping the rest of its action
+        #[derive(Constructor, Display)]
+        #[display(fmt = "UPGRADE `{trigger_id}`")]
+        pub struct UpgradeTrigger {
+            /// Id of a trigger to upgrade
+            pub trigger_id: TriggerId,
+            /// New executable of the trigger
+            pub executable: Executable,
+        }
+    }
+
     isi! {
         /// Generic instruction for upgrading runtime objects.
         #[derive(Constructor, Display)]
@@ -1066,6 +1922,78 @@ isi_box! {
     }
 }
 
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(MergeKeyValuesType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`MergeKeyValues`] instructions.
+    pub enum MergeKeyValuesBox {
+        /// Merge key values into [`Domain`].
+        Domain(MergeKeyValues<Domain>),
+        /// Merge key values into [`Account`].
+        Account(MergeKeyValues<Account>),
+        /// Merge key values into [`AssetDefinition`].
+        AssetDefinition(MergeKeyValues<AssetDefinition>),
+        /// Merge key values into [`Asset`].
+        Asset(MergeKeyValues<Asset>),
+        /// Merge key values into [`Trigger`].
+        Trigger(MergeKeyValues<Trigger>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(IncrementKeyValueType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`IncrementKeyValue`] instructions.
+    pub enum IncrementKeyValueBox {
+        /// Increment key value of [`Domain`].
+        Domain(IncrementKeyValue<Domain>),
+        /// Increment key value of [`Account`].
+        Account(IncrementKeyValue<Account>),
+        /// Increment key value of [`AssetDefinition`].
+        AssetDefinition(IncrementKeyValue<AssetDefinition>),
+        /// Increment key value of [`Asset`].
+        Asset(IncrementKeyValue<Asset>),
+        /// Increment key value of [`Trigger`].
+        Trigger(IncrementKeyValue<Trigger>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(FreezeType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Freeze`] instructions.
+    pub enum FreezeBox {
+        /// Freeze [`AssetDefinition`].
+        AssetDefinition(Freeze<AssetDefinition>),
+        /// Freeze [`Asset`].
+        Asset(Freeze<Asset>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(UnfreezeType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Unfreeze`] instructions.
+    pub enum UnfreezeBox {
+        /// Unfreeze [`AssetDefinition`].
+        AssetDefinition(Unfreeze<AssetDefinition>),
+        /// Unfreeze [`Asset`].
+        Asset(Unfreeze<Asset>),
+    }
+}
+
 isi_box! {
     #[strum_discriminants(
         vis(pub(crate)),
@@ -1087,7 +2015,9 @@ isi_box! {
         /// Register [`Role`].
         Role(Register<Role>),
         /// Register [`Trigger`].
-        Trigger(Register<Trigger>)
+        Trigger(Register<Trigger>),
+        /// Register [`WasmCode`].
+        WasmCode(Register<WasmCode>),
     }
 }
 
@@ -1128,6 +2058,8 @@ isi_box! {
         Asset(Mint<Numeric, Asset>),
         /// Mint [`Trigger`] repetitions.
         TriggerRepetitions(Mint<u32, Trigger>),
+        /// Mint a token of [`Asset`] of [`Nft`](AssetValueType::Nft) type.
+        Nft(Mint<Name, Asset>),
     }
 }
 
@@ -1143,6 +2075,8 @@ isi_box! {
         Asset(Burn<Numeric, Asset>),
         /// Burn [`Trigger`] repetitions.
         TriggerRepetitions(Burn<u32, Trigger>),
+        /// Burn the whole balance of [`Asset`].
+        AssetAll(Burn<All, Asset>),
     }
 }
 
@@ -1176,6 +2110,32 @@ isi_box! {
         Numeric(Transfer<Asset, Numeric, Account>),
         /// Transfer [`Asset`] of [`Store`] type.
         Store(Transfer<Asset, Metadata, Account>),
+        /// Transfer a token of [`Asset`] of [`Nft`](AssetValueType::Nft) type.
+        Nft(Transfer<Asset, Name, Account>),
+        /// Transfer the whole balance of [`Asset`] of [`Numeric`] type.
+        NumericAll(Transfer<Asset, All, Account>),
+    }
+}
+
+impl AssetTransferBox {
+    /// Id of the transferred [`Asset`].
+    pub fn source_id(&self) -> &AssetId {
+        match self {
+            Self::Numeric(isi) => &isi.source_id,
+            Self::NumericAll(isi) => &isi.source_id,
+            Self::Store(isi) => &isi.source_id,
+            Self::Nft(isi) => &isi.source_id,
+        }
+    }
+
+    /// Id of the [`Account`] receiving the transferred [`Asset`].
+    pub fn destination_id(&self) -> &AccountId {
+        match self {
+            Self::Numeric(isi) => &isi.destination_id,
+            Self::NumericAll(isi) => &isi.destination_id,
+            Self::Store(isi) => &isi.destination_id,
+            Self::Nft(isi) => &isi.destination_id,
+        }
     }
 }
 
@@ -1229,6 +2189,7 @@ pub mod error {
     pub use self::model::*;
     use super::InstructionType;
     use crate::{
+        account::AccountId,
         asset::AssetValueType,
         metadata,
         query::error::{FindError, QueryExecutionFail},
@@ -1242,6 +2203,8 @@ pub mod error {
         use super::*;
 
         /// Instruction execution error type
+        ///
+        /// Variant indices are stable error codes: new variants get new indices.
         #[derive(
             Debug,
             displaydoc::Display,
@@ -1263,41 +2226,66 @@ pub mod error {
         #[ffi_type(opaque)]
         pub enum InstructionExecutionError {
             /// Instruction does not adhere to Iroha DSL specification
+            #[codec(index = 0)]
             Evaluate(#[cfg_attr(feature = "std", source)] InstructionEvaluationError),
             /// Query failed
+            #[codec(index = 1)]
             Query(#[cfg_attr(feature = "std", source)] QueryExecutionFail),
             /// Conversion Error: {0}
+            #[codec(index = 2)]
             Conversion(
                 #[skip_from]
                 #[skip_try_from]
                 String,
             ),
             /// Entity missing
+            #[codec(index = 3)]
             Find(#[cfg_attr(feature = "std", source)] FindError),
             /// Repeated instruction
+            #[codec(index = 4)]
             Repetition(#[cfg_attr(feature = "std", source)] RepetitionError),
             /// Mintability assertion failed
+            #[codec(index = 5)]
             Mintability(#[cfg_attr(feature = "std", source)] MintabilityError),
             /// Illegal math operation
+            #[codec(index = 6)]
             Math(#[cfg_attr(feature = "std", source)] MathError),
             /// Metadata error
+            #[codec(index = 7)]
             Metadata(#[cfg_attr(feature = "std", source)] metadata::MetadataError),
             /// Execution failed: {0}
+            #[codec(index = 8)]
             Fail(
                 #[skip_from]
                 #[skip_try_from]
                 String,
             ),
             /// Invalid instruction parameter
+            #[codec(index = 9)]
             InvalidParameter(#[cfg_attr(feature = "std", source)] InvalidParameterError),
             /// Iroha invariant violation: {0}
             ///
             /// i.e. you can't burn last key
+            #[codec(index = 10)]
             InvariantViolation(
                 #[skip_from]
                 #[skip_try_from]
                 String,
             ),
+            /// Transfers and burns of `{0}` are frozen
+            #[codec(index = 11)]
+            Frozen(
+                #[skip_from]
+                #[skip_try_from]
+                IdBox,
+            ),
+            /// Account `{0}` is disabled
+            #[codec(index = 12)]
+            AccountDisabled(
+                #[skip_from]
+                #[skip_try_from]
+                AccountId,
+            ),
         }
 
         /// Evaluation error. This error indicates instruction is not a valid Iroha DSL
@@ -1386,6 +2374,12 @@ pub mod error {
                 #[skip_try_from]
                 AssetValueType,
             ),
+            /// Nft asset value type was expected, received: {0}
+            NftAssetValueTypeExpected(
+                #[skip_from]
+                #[skip_try_from]
+                AssetValueType,
+            ),
         }
 
         /// Math error, which occurs during instruction execution
@@ -1451,6 +2445,8 @@ pub mod error {
             MintUnmintable,
             /// This asset was set as infinitely mintable. You cannot forbid its minting
             ForbidMintOnMintable,
+            /// Minting would exceed the maximum supply of this asset
+            MaxSupplyExceeded,
         }
 
         /// Invalid instruction parameter error
@@ -1518,9 +2514,15 @@ pub mod error {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        AssetTransferBox, Burn, BurnBox, ExecuteTrigger, Fail, Grant, GrantBox, InstructionBox,
-        Log, Mint, MintBox, NewParameter, Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox,
-        Revoke, RevokeBox, SetKeyValue, SetKeyValueBox, SetParameter, Transfer, TransferBox,
-        Unregister, UnregisterBox, Upgrade,
+        AddSignatory, All, AssetTransferBox, Burn, BurnBox, CancelRecovery, DisableAccount,
+        EnableAccount, ExecuteTrigger, Fail, FinalizeRecovery, Freeze, FreezeBox, Grant, GrantBox,
+        GrantWithTerms, IncrementKeyValue, IncrementKeyValueBox, InstructionBox, Log,
+        MergeKeyValues, MergeKeyValuesBox, Mint, MintBox, NewParameter, OpenEscrow, Pause,
+        RecoverAccount, RefundEscrow, Register, RegisterAlias, RegisterBox, ReleaseEscrow,
+        RemoveKeyValue, RemoveKeyValueBox, RemoveSignatory, Resume, Revoke, RevokeBox, RotateKey,
+        SetDomainMetadataLimits, SetKeyValue, SetKeyValueBox, SetKeyValueIf, SetKeyValueWithExpiry,
+        SetParameter, SetRecoveryPolicy, SetSignatureThreshold, SetStrictOrdering, Swap, Transfer,
+        TransferBox, TransferMany, Unfreeze, UnfreezeBox, Unregister, UnregisterAlias,
+        UnregisterBox, Upgrade, UpgradeTrigger,
     };
 }
@@ -37,6 +37,7 @@ pub mod account;
 pub mod asset;
 pub mod block;
 pub mod domain;
+pub mod escrow;
 pub mod events;
 pub mod executor;
 pub mod ipfs;
@@ -81,6 +82,12 @@ mod seal {
         RemoveKeyValue<Asset>,
         RemoveKeyValue<Trigger>,
 
+        MergeKeyValues<Domain>,
+        MergeKeyValues<AssetDefinition>,
+        MergeKeyValues<Account>,
+        MergeKeyValues<Asset>,
+        MergeKeyValues<Trigger>,
+
         Register<Peer>,
         Register<Domain>,
         Register<Account>,
@@ -88,6 +95,7 @@ mod seal {
         Register<Asset>,
         Register<Role>,
         Register<Trigger>,
+        Register<WasmCode>,
 
         Unregister<Peer>,
         Unregister<Domain>,
@@ -99,14 +107,18 @@ mod seal {
 
         Mint<Numeric, Asset>,
         Mint<u32, Trigger>,
+        Mint<Name, Asset>,
 
         Burn<Numeric, Asset>,
+        Burn<All, Asset>,
         Burn<u32, Trigger>,
 
         Transfer<Account, DomainId, Account>,
         Transfer<Account, AssetDefinitionId, Account>,
         Transfer<Asset, Numeric, Account>,
+        Transfer<Asset, All, Account>,
         Transfer<Asset, Metadata, Account>,
+        Transfer<Asset, Name, Account>,
 
         Grant<Permission, Account>,
         Grant<RoleId, Account>,
@@ -115,11 +127,50 @@ mod seal {
         Revoke<Permission, Account>,
         Revoke<RoleId, Account>,
         Revoke<Permission, Role>,
+        GrantWithTerms,
+
+        SetDomainMetadataLimits,
+        RegisterAlias,
+        UnregisterAlias,
+        SetKeyValueWithExpiry,
+        SetKeyValueIf,
+
+        IncrementKeyValue<Domain>,
+        IncrementKeyValue<AssetDefinition>,
+        IncrementKeyValue<Account>,
+        IncrementKeyValue<Asset>,
+        IncrementKeyValue<Trigger>,
+
+        Swap,
+        TransferMany,
+
+        OpenEscrow,
+        ReleaseEscrow,
+        RefundEscrow,
+        AddSignatory,
+        RemoveSignatory,
+        SetSignatureThreshold,
+        RotateKey,
+        SetRecoveryPolicy,
+        RecoverAccount,
+        FinalizeRecovery,
+        CancelRecovery,
+        DisableAccount,
+        EnableAccount,
+        SetStrictOrdering,
+
+        Freeze<AssetDefinition>,
+        Freeze<Asset>,
+        Unfreeze<AssetDefinition>,
+        Unfreeze<Asset>,
 
         SetParameter,
         NewParameter,
         Upgrade,
         ExecuteTrigger,
+        Pause,
+        Resume,
+        UpgradeTrigger,
         Log,
         Fail,
 
@@ -127,9 +178,14 @@ mod seal {
         QueryBox,
         FindAllAccounts,
         FindAccountById,
+        FindAccountByAlias,
         FindAccountKeyValueByIdAndKey,
+        FindAccountKeyValueByIdAndPath,
         FindAccountsByDomainId,
+        FindAccountCountByDomainId,
         FindAccountsWithAsset,
+        FindAccountsByMetadataKeyPrefix,
+        FindMetadataHistory,
         FindAllAssets,
         FindAllAssetsDefinitions,
         FindAssetById,
@@ -139,23 +195,35 @@ mod seal {
         FindAssetsByAssetDefinitionId,
         FindAssetsByDomainId,
         FindAssetsByDomainIdAndAssetDefinitionId,
+        FindAssetsByMetadataKeyPrefix,
+        FindNftsByAccountId,
         FindAssetQuantityById,
         FindTotalAssetQuantityByAssetDefinitionId,
+        FindAssetCountByAssetDefinitionId,
         FindAssetKeyValueByIdAndKey,
+        FindAssetKeyValueByIdAndPath,
         FindAssetDefinitionKeyValueByIdAndKey,
         FindAllDomains,
         FindDomainById,
+        FindDomainDescendants,
         FindDomainKeyValueByIdAndKey,
         FindAllPeers,
         FindAllBlocks,
         FindAllBlockHeaders,
         FindBlockHeaderByHash,
+        FindBlockByHash,
+        FindBlockByHeight,
+        FindBlocksInRange,
         FindAllTransactions,
         FindTransactionsByAccountId,
         FindTransactionByHash,
+        FindTransactionProofByHash,
         FindPermissionsByAccountId,
+        FindPermissionGrantTerms,
+        FindGrantHistoryByAccountId,
         FindExecutorDataModel,
         FindAllActiveTriggerIds,
+        FindAllActiveTriggers,
         FindTriggerById,
         FindTriggerKeyValueByIdAndKey,
         FindTriggersByDomainId,
@@ -228,7 +296,9 @@ pub mod parameter {
         pub const MAX_TRANSACTIONS_IN_BLOCK: &str = "MaxTransactionsInBlock";
         pub const BLOCK_TIME: &str = "BlockTime";
         pub const COMMIT_TIME_LIMIT: &str = "CommitTimeLimit";
+        pub const MAX_FAULTS: &str = "MaxFaults";
         pub const TRANSACTION_LIMITS: &str = "TransactionLimits";
+        pub const TRANSACTION_FEES: &str = "TransactionFees";
         pub const WSV_DOMAIN_METADATA_LIMITS: &str = "WSVDomainMetadataLimits";
         pub const WSV_ASSET_DEFINITION_METADATA_LIMITS: &str = "WSVAssetDefinitionMetadataLimits";
         pub const WSV_ACCOUNT_METADATA_LIMITS: &str = "WSVAccountMetadataLimits";
@@ -239,6 +309,7 @@ pub mod parameter {
         pub const EXECUTOR_MAX_MEMORY: &str = "ExecutorMaxMemory";
         pub const WASM_FUEL_LIMIT: &str = "WASMFuelLimit";
         pub const WASM_MAX_MEMORY: &str = "WASMMaxMemory";
+        pub const GRANT_HISTORY_MAX_LEN: &str = "GrantHistoryMaxLen";
     }
 
     #[model]
@@ -269,6 +340,7 @@ pub mod parameter {
                 #[skip_try_from]
                 Numeric,
             ),
+            TransactionFees(transaction::TransactionFees),
         }
 
         /// Identification of a [`Parameter`].
@@ -328,6 +400,7 @@ pub mod parameter {
             match self {
                 Self::MetadataLimits(v) => core::fmt::Display::fmt(&v, f),
                 Self::TransactionLimits(v) => core::fmt::Display::fmt(&v, f),
+                Self::TransactionFees(v) => core::fmt::Display::fmt(&v, f),
                 Self::LengthLimits(v) => core::fmt::Display::fmt(&v, f),
                 Self::Numeric(v) => core::fmt::Display::fmt(&v, f),
             }
@@ -434,12 +507,19 @@ pub mod parameter {
                                     max_wasm_size,
                                 ).into()
                             }
+                            // Shorthand for `TransactionFees`
+                            "TF" => val.parse::<transaction::TransactionFees>()?.into(),
                             // Shorthand for `MetadataLimits`
                             "ML" => {
-                                let (lower, upper) = val.rsplit_once(',').ok_or( ParseError {
+                                let mut parts = val.split(',');
+                                let (Some(lower), Some(upper), total, None) =
+                                    (parts.next(), parts.next(), parts.next(), parts.next())
+                                else {
+                                    return Err(ParseError {
                                         reason:
-                                            "Failed to parse the `val` part of the `Parameter` as `MetadataLimits`. Two comma-separated values are expected.",
-                                    })?;
+                                            "Failed to parse the `val` part of the `Parameter` as `MetadataLimits`. Two or three comma-separated values are expected.",
+                                    });
+                                };
                                 let lower = lower.parse::<u32>().map_err(|_| ParseError {
                                     reason:
                                         "Failed to parse the `val` part of the `Parameter` as `MetadataLimits`. Invalid `u32` in `capacity` field.",
@@ -448,7 +528,15 @@ pub mod parameter {
                                     reason:
                                         "Failed to parse the `val` part of the `Parameter` as `MetadataLimits`. Invalid `u32` in `max_entry_len` field.",
                                 })?;
-                                metadata::Limits::new(lower, upper).into()
+                                let limits = metadata::Limits::new(lower, upper);
+                                match total {
+                                    Some(total) => limits.with_max_total_byte_size(total.parse::<u64>().map_err(|_| ParseError {
+                                        reason:
+                                            "Failed to parse the `val` part of the `Parameter` as `MetadataLimits`. Invalid `u64` in `max_total_byte_size` field.",
+                                    })?),
+                                    None => limits,
+                                }
+                                .into()
                             }
                             _ => return Err(ParseError {
                                 reason:
@@ -546,8 +634,9 @@ pub mod parameter {
     mod tests {
         use super::*;
         use crate::{
+            isi::InstructionType,
             prelude::{numeric, MetadataLimits},
-            transaction::TransactionLimits,
+            transaction::{TransactionFees, TransactionLimits},
         };
 
         const INVALID_PARAM: [&str; 4] = [
@@ -611,6 +700,27 @@ pub mod parameter {
                 );
             }
         }
+
+        #[test]
+        fn transaction_fees_parameter_roundtrip() {
+            let fees = TransactionFees::new(
+                "xor#wonderland".parse().expect("Valid"),
+                "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03@wonderland"
+                    .parse()
+                    .expect("Valid"),
+                numeric!(1),
+                numeric!(0.01),
+            )
+            .with_instruction_fee(InstructionType::Register, numeric!(10));
+            let parameter = Parameter::new(
+                ParameterId::from_str("TransactionFees").expect("Failed to parse `ParameterId`"),
+                fees.clone().into(),
+            );
+
+            let parsed = Parameter::from_str(&parameter.to_string())
+                .expect("Failed to parse `TransactionFees` parameter");
+            assert_eq!(parsed.val, ParameterValueBox::TransactionFees(fees));
+        }
     }
 }
 
@@ -679,6 +789,8 @@ mod model {
         PermissionId(permission::PermissionId),
         /// [`ParameterId`](`parameter::ParameterId`) variant.
         ParameterId(parameter::ParameterId),
+        /// [`WasmCode`](`transaction::WasmCode`) id variant.
+        WasmCodeId(iroha_crypto::HashOf<transaction::WasmSmartContract>),
     }
 
     /// Sized container for all possible entities.
@@ -756,6 +868,8 @@ mod model {
 
     /// Operation validation failed.
     ///
+    /// Variant indices are stable error codes and are kept when variants are added.
+    ///
     /// # Note
     ///
     /// Keep in mind that *Validation* is not the right term
@@ -783,16 +897,19 @@ mod model {
     #[cfg_attr(feature = "std", derive(thiserror::Error))]
     pub enum ValidationFail {
         /// Operation is not permitted: {0}
+        #[codec(index = 0)]
         NotPermitted(
             #[skip_from]
             #[skip_try_from]
             String,
         ),
         /// Instruction execution failed
+        #[codec(index = 1)]
         InstructionFailed(
             #[cfg_attr(feature = "std", source)] isi::error::InstructionExecutionError,
         ),
         /// Query execution failed
+        #[codec(index = 2)]
         QueryFailed(#[cfg_attr(feature = "std", source)] query::error::QueryExecutionFail),
         /// Operation is too complex, perhaps `WASM_RUNTIME_CONFIG` blockchain parameters should be increased
         ///
@@ -800,10 +917,12 @@ mod model {
         ///
         /// It's different from [`TransactionRejectionReason::LimitCheck`] because it depends on
         /// executor.
+        #[codec(index = 3)]
         TooComplex,
         /// Internal error occurred, please contact the support or check the logs if you are the node owner
         ///
         /// Usually means a bug inside **Runtime Executor** or **Iroha** implementation.
+        #[codec(index = 4)]
         InternalError(
             /// Contained error message if its used internally. Empty for external users.
             /// Never serialized to not to expose internal errors to the end user.
@@ -1150,10 +1269,11 @@ pub mod prelude {
     pub use iroha_primitives::numeric::{numeric, Numeric, NumericSpec};
 
     pub use super::{
-        account::prelude::*, asset::prelude::*, domain::prelude::*, events::prelude::*,
-        executor::prelude::*, isi::prelude::*, metadata::prelude::*, name::prelude::*,
-        parameter::prelude::*, peer::prelude::*, permission::prelude::*, query::prelude::*,
-        role::prelude::*, transaction::prelude::*, trigger::prelude::*, ChainId, EnumTryAsError,
-        HasMetadata, IdBox, Identifiable, IdentifiableBox, LengthLimits, ValidationFail,
+        account::prelude::*, asset::prelude::*, domain::prelude::*, escrow::prelude::*,
+        events::prelude::*, executor::prelude::*, isi::prelude::*, metadata::prelude::*,
+        name::prelude::*, parameter::prelude::*, peer::prelude::*, permission::prelude::*,
+        query::prelude::*, role::prelude::*, transaction::prelude::*, trigger::prelude::*, ChainId,
+        EnumTryAsError, HasMetadata, IdBox, Identifiable, IdentifiableBox, LengthLimits,
+        ValidationFail,
     };
 }
@@ -5,18 +5,20 @@ use alloc::{
     collections::btree_map,
     format,
     string::{String, ToString},
+    sync::Arc,
+    vec,
     vec::Vec,
 };
-use core::borrow::Borrow;
+use core::{borrow::Borrow, str::FromStr};
 #[cfg(feature = "std")]
-use std::collections::btree_map;
+use std::{collections::btree_map, sync::Arc};
 
 use derive_more::Display;
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_primitives::numeric::Numeric;
 use iroha_schema::IntoSchema;
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Compact, CompactLen, Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
@@ -25,17 +27,37 @@ use crate::Name;
 /// A path slice, composed of [`Name`]s.
 pub type Path = [Name];
 
-/// Collection of parameters by their names.
-pub type UnlimitedMetadata = btree_map::BTreeMap<Name, MetadataValueBox>;
-
 #[model]
 mod model {
     use super::*;
 
     /// Collection of parameters by their names with checked insertion.
+    ///
+    /// The map is shared between clones and only copied on the first write
+    /// to a shared layer, so cloning is cheap even for big metadata trees.
+    ///
+    /// Every layer keeps the encoded size of its entries up to date, so
+    /// size limits are checked without re-encoding the map.
+    #[derive(
+        Display, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Encode, IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    #[serde(transparent)]
+    #[display(fmt = "Metadata")]
+    #[allow(clippy::multiple_inherent_impl)]
+    pub struct Metadata(
+        pub(super) Arc<btree_map::BTreeMap<Name, MetadataValueBox>>,
+        /// Encoded size of the entries, without the length prefix of the map
+        #[codec(skip)]
+        #[serde(skip)]
+        pub(super) usize,
+    );
+
+    /// Collection of parameters by their names, not subject to any [`Limits`].
+    ///
+    /// Used where no limits are configured, e.g. for transaction metadata.
     #[derive(
         Debug,
-        Display,
         Clone,
         Default,
         PartialEq,
@@ -51,9 +73,7 @@ mod model {
     #[ffi_type(opaque)]
     #[repr(transparent)]
     #[serde(transparent)]
-    #[display(fmt = "Metadata")]
-    #[allow(clippy::multiple_inherent_impl)]
-    pub struct Metadata(pub(super) btree_map::BTreeMap<Name, MetadataValueBox>);
+    pub struct UnlimitedMetadata(pub(super) btree_map::BTreeMap<Name, MetadataValueBox>);
 
     /// Metadata value
     #[derive(
@@ -90,7 +110,6 @@ mod model {
     /// Limits for [`Metadata`].
     #[derive(
         Debug,
-        Display,
         Clone,
         Copy,
         PartialEq,
@@ -104,12 +123,94 @@ mod model {
         IntoSchema,
     )]
     #[ffi_type]
-    #[display(fmt = "{capacity},{max_entry_len}_ML")]
     pub struct Limits {
         /// Maximum number of entries
         pub capacity: u32,
         /// Maximum length of entry
         pub max_entry_len: u32,
+        /// Maximum cumulative encoded size of all entries, in bytes
+        #[serde(default = "Limits::unbounded_total_byte_size")]
+        pub max_total_byte_size: u64,
+    }
+
+    /// How [`Metadata::merge_with_limits`] resolves a key present in both layers,
+    /// unless both values are [`Metadata`] themselves, in which case they are merged recursively.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    pub enum MergePolicy {
+        /// Replace the existing value with the incoming one.
+        Overwrite,
+        /// Keep the existing value and discard the incoming one.
+        KeepExisting,
+        /// Abort the whole merge.
+        Reject,
+    }
+
+    /// Point at which an expiring metadata entry is removed.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    pub enum Expiry {
+        /// Commit of the block with this height.
+        #[display(fmt = "BLOCK {_0}")]
+        BlockHeight(u64),
+        /// Commit of the first block created at or after this time, in milliseconds since the Unix epoch.
+        #[display(fmt = "{_0} MS")]
+        TimestampMs(u64),
+    }
+
+    /// Signed change applied to a numeric metadata entry.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    pub enum NumericDelta {
+        /// Add the given amount.
+        #[display(fmt = "+{_0}")]
+        Increase(Numeric),
+        /// Subtract the given amount.
+        #[display(fmt = "-{_0}")]
+        Decrease(Numeric),
     }
 
     /// Metadata related errors.
@@ -140,6 +241,14 @@ mod model {
         MissingSegment(Name),
         /// `{0}`: path segment not an instance of metadata
         InvalidSegment(Name),
+        /// Metadata exceeds overall byte size limit
+        MaxTotalByteSize(#[cfg_attr(feature = "std", source)] SizeError),
+        /// `{0}`: key is already present and the merge policy forbids overwriting it
+        KeyConflict(Name),
+        /// `{0}`: current value differs from the expected one
+        ValueMismatch(Name),
+        /// `{0}`: value is not numeric
+        NotNumeric(Name),
     }
 
     /// Size limits exhaustion error
@@ -164,18 +273,158 @@ mod model {
     pub struct SizeError {
         /// The limits that were set for this entry
         pub limits: Limits,
-        /// The actual *entry* size in bytes
+        /// The actual size (entry size, number of entries or total byte size)
         pub actual: u64,
     }
 }
 
 impl Limits {
     /// Constructor.
+    ///
+    /// The cumulative byte size is left unbounded, see [`Self::with_max_total_byte_size`].
     pub const fn new(capacity: u32, max_entry_len: u32) -> Limits {
         Limits {
             capacity,
             max_entry_len,
+            max_total_byte_size: Self::unbounded_total_byte_size(),
+        }
+    }
+
+    /// Set the maximum cumulative encoded size of all entries.
+    #[must_use]
+    pub const fn with_max_total_byte_size(mut self, max_total_byte_size: u64) -> Limits {
+        self.max_total_byte_size = max_total_byte_size;
+        self
+    }
+
+    /// Tighten each limit to be no greater than the corresponding one in `upper`.
+    #[must_use]
+    pub fn bounded_by(self, upper: Limits) -> Limits {
+        Limits {
+            capacity: self.capacity.min(upper.capacity),
+            max_entry_len: self.max_entry_len.min(upper.max_entry_len),
+            max_total_byte_size: self.max_total_byte_size.min(upper.max_total_byte_size),
+        }
+    }
+
+    /// Value of `max_total_byte_size` for limits defined before it was introduced.
+    const fn unbounded_total_byte_size() -> u64 {
+        u64::MAX
+    }
+}
+
+impl UnlimitedMetadata {
+    /// Constructor.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if there are no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the entries, ordered by key.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&Name, &MetadataValueBox)> {
+        self.0.iter()
+    }
+
+    /// Get the value under `key`.
+    pub fn get<K: Ord + ?Sized>(&self, key: &K) -> Option<&MetadataValueBox>
+    where
+        Name: Borrow<K>,
+    {
+        self.0.get(key)
+    }
+
+    /// Insert `value` under `key`, returning the previous value.
+    pub fn insert(
+        &mut self,
+        key: Name,
+        value: impl Into<MetadataValueBox>,
+    ) -> Option<MetadataValueBox> {
+        self.0.insert(key, value.into())
+    }
+
+    /// Remove the value under `key`, returning it.
+    pub fn remove<K: Ord + ?Sized>(&mut self, key: &K) -> Option<MetadataValueBox>
+    where
+        Name: Borrow<K>,
+    {
+        self.0.remove(key)
+    }
+
+    /// Convert into [`Metadata`], checking the entries against `limits`.
+    ///
+    /// # Errors
+    /// Fails if `max_entry_len`, `capacity` or `max_total_byte_size` from `limits` are exceeded.
+    pub fn try_into_metadata(self, limits: Limits) -> Result<Metadata, MetadataError> {
+        let mut metadata = Metadata::new();
+        for (key, value) in self.0 {
+            metadata.insert_with_limits(key, value, limits)?;
+        }
+        Ok(metadata)
+    }
+}
+
+impl From<Metadata> for UnlimitedMetadata {
+    fn from(metadata: Metadata) -> Self {
+        Self(Arc::try_unwrap(metadata.0).unwrap_or_else(|shared| (*shared).clone()))
+    }
+}
+
+impl FromIterator<(Name, MetadataValueBox)> for UnlimitedMetadata {
+    fn from_iter<T: IntoIterator<Item = (Name, MetadataValueBox)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for UnlimitedMetadata {
+    type Item = (Name, MetadataValueBox);
+    type IntoIter = btree_map::IntoIter<Name, MetadataValueBox>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Expiry {
+    /// Whether the committed block with the given height and creation time reaches this expiry.
+    pub const fn is_reached(self, block_height: u64, block_timestamp_ms: u64) -> bool {
+        match self {
+            Self::BlockHeight(height) => block_height >= height,
+            Self::TimestampMs(timestamp_ms) => block_timestamp_ms >= timestamp_ms,
+        }
+    }
+}
+
+impl NumericDelta {
+    /// Apply this delta to `value`.
+    ///
+    /// Returns `None` if the result overflows or would be negative.
+    pub fn apply(self, value: Numeric) -> Option<Numeric> {
+        match self {
+            Self::Increase(delta) => value.checked_add(delta),
+            Self::Decrease(delta) => value.checked_sub(delta),
+        }
+    }
+}
+
+impl core::fmt::Display for Limits {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{},{}", self.capacity, self.max_entry_len)?;
+        if self.max_total_byte_size != Self::unbounded_total_byte_size() {
+            write!(f, ",{}", self.max_total_byte_size)?;
         }
+        write!(f, "_ML")
     }
 }
 
@@ -278,13 +527,100 @@ impl MetadataValueBox {
             LimitedMetadata(data) => data.nested_len() + 1,
         }
     }
+
+    /// Human-readable name of the contained type.
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Bool(_) => "bool",
+            Self::String(_) => "string",
+            Self::Name(_) => "name",
+            Self::Bytes(_) => "bytes",
+            Self::Numeric(_) => "numeric",
+            Self::LimitedMetadata(_) => "metadata",
+            Self::Vec(_) => "vec",
+        }
+    }
+}
+
+impl core::fmt::Debug for Metadata {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Metadata").field(&self.0).finish()
+    }
+}
+
+impl Decode for Metadata {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        btree_map::BTreeMap::decode(input).map(Self::from_map)
+    }
+}
+
+impl<'de> Deserialize<'de> for Metadata {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        btree_map::BTreeMap::deserialize(deserializer).map(Self::from_map)
+    }
 }
 
 impl Metadata {
     /// Constructor.
     #[inline]
     pub fn new() -> Self {
-        Self(UnlimitedMetadata::new())
+        Self::default()
+    }
+
+    /// Wrap `map`, computing the encoded size of its entries.
+    fn from_map(map: btree_map::BTreeMap<Name, MetadataValueBox>) -> Self {
+        let entries_size = map
+            .iter()
+            .map(|(key, value)| entry_size_of(key, value))
+            .sum();
+        Self(Arc::new(map), entries_size)
+    }
+
+    /// Mutable access to the map of this layer, copying it first if it is shared.
+    ///
+    /// Callers are responsible for keeping the size of the entries up to date.
+    fn map_mut(&mut self) -> &mut btree_map::BTreeMap<Name, MetadataValueBox> {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Insert an entry of `entry_size` bytes without checking any limits.
+    fn insert_entry(
+        &mut self,
+        key: Name,
+        value: MetadataValueBox,
+        entry_size: usize,
+    ) -> Option<MetadataValueBox> {
+        let key_size = key.encoded_size();
+        let old_value = self.map_mut().insert(key, value);
+        let old_entry_size = old_value
+            .as_ref()
+            .map_or(0, |old_value| key_size + value_size(old_value));
+        self.1 = self.1 - old_entry_size + entry_size;
+        old_value
+    }
+
+    /// Apply `f` to the layer found at `parents`, updating the sizes of the
+    /// enclosing layers by however much the layer grew or shrank.
+    ///
+    /// Returns `None` if the path doesn't lead to a layer.
+    fn with_layer_mut<R>(
+        &mut self,
+        parents: &Path,
+        f: impl FnOnce(&mut Self) -> Option<R>,
+    ) -> Option<R> {
+        let Some((key, rest)) = parents.split_first() else {
+            return f(self);
+        };
+        let MetadataValueBox::LimitedMetadata(layer) = self.map_mut().get_mut(key)? else {
+            return None;
+        };
+        let old_size = layer.byte_size();
+        let result = layer.with_layer_mut(rest, f);
+        let new_size = layer.byte_size();
+        self.1 = self.1 - old_size + new_size;
+        result
     }
 
     /// Get the (expensive) cumulative length of all [`Value`]s housed
@@ -311,14 +647,58 @@ impl Metadata {
 
     /// Check if the internal map contains the given key.
     pub fn contains(&self, key: &Name) -> bool {
+        self.contains_key(key)
+    }
+
+    /// Check if the internal map contains the given key.
+    #[inline]
+    pub fn contains_key<K: Ord + ?Sized>(&self, key: &K) -> bool
+    where
+        Name: Borrow<K>,
+    {
         self.0.contains_key(key)
     }
 
+    /// Number of top-level entries in the internal map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return `true` if there are no entries in the internal map.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Iterate over key/value pairs stored in the internal map.
     pub fn iter(&self) -> impl ExactSizeIterator<Item = (&Name, &MetadataValueBox)> {
         self.0.iter()
     }
 
+    /// Iterate over keys stored in the internal map in sorted order.
+    #[inline]
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &Name> {
+        self.0.keys()
+    }
+
+    /// Iterate over top-level keys starting with `prefix` in sorted order.
+    pub fn keys_with_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a Name> {
+        self.0
+            .range::<str, _>((
+                core::ops::Bound::Included(prefix),
+                core::ops::Bound::Unbounded,
+            ))
+            .map(|(key, _)| key)
+            .take_while(move |key| key.as_ref().starts_with(prefix))
+    }
+
+    /// Iterate over values stored in the internal map, ordered by their keys.
+    #[inline]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &MetadataValueBox> {
+        self.0.values()
+    }
+
     /// Get the `Some(&Value)` associated to `key`. Return `None` if not found.
     #[inline]
     pub fn get<K: Ord + ?Sized>(&self, key: &K) -> Option<&MetadataValueBox>
@@ -328,69 +708,498 @@ impl Metadata {
         self.0.get(key)
     }
 
-    fn len_u64(&self) -> u64 {
-        self.0
-            .len()
-            .try_into()
-            .expect("`usize` should always fit into `u64`")
+    /// Get the value under `key` as a string.
+    ///
+    /// # Errors
+    /// If the key is absent or its value is not a [`MetadataValueBox::String`].
+    pub fn get_str<K: Ord + ?Sized>(&self, key: &K) -> Result<&str, TypeMismatch>
+    where
+        Name: Borrow<K>,
+    {
+        self.get_typed(key, "string", |value| match value {
+            MetadataValueBox::String(value) => Some(value.as_str()),
+            _ => None,
+        })
     }
 
-    /// Insert the given [`Value`] into the given path. If the path is
-    /// complete, check the limits and only then insert. The creation
-    /// of the path is the responsibility of the user.
+    /// Get the value under `key` as a boolean.
     ///
     /// # Errors
-    /// - If the path is empty.
-    /// - If one of the intermediate keys is absent.
-    /// - If some intermediate key is a leaf node.
-    pub fn nested_insert_with_limits(
-        &mut self,
-        path: &Path,
-        value: impl Into<MetadataValueBox>,
-        limits: Limits,
-    ) -> Result<Option<MetadataValueBox>, MetadataError> {
-        if self.0.len() >= limits.capacity as usize {
-            return Err(MetadataError::MaxCapacity(SizeError {
-                limits,
-                actual: self.len_u64(),
-            }));
-        }
-        let key = path.last().ok_or(MetadataError::EmptyPath)?;
-        let mut layer = self;
-        for k in path.iter().take(path.len() - 1) {
-            layer = match layer
-                .0
-                .get_mut(k)
-                .ok_or_else(|| MetadataError::MissingSegment(k.clone()))?
-            {
-                MetadataValueBox::LimitedMetadata(data) => data,
-                _ => return Err(MetadataError::InvalidSegment(k.clone())),
-            };
-        }
-        layer.insert_with_limits(key.clone(), value, limits)
+    /// If the key is absent or its value is not a [`MetadataValueBox::Bool`].
+    pub fn get_bool<K: Ord + ?Sized>(&self, key: &K) -> Result<bool, TypeMismatch>
+    where
+        Name: Borrow<K>,
+    {
+        self.get_typed(key, "bool", |value| match value {
+            MetadataValueBox::Bool(value) => Some(*value),
+            _ => None,
+        })
     }
 
-    /// Insert [`Value`] under the given key.  Returns `Some(value)`
-    /// if the value was already present, `None` otherwise.
+    /// Get the value under `key` as a [`u32`].
     ///
     /// # Errors
-    /// Fails if `max_entry_len` or `capacity` from `limits` are exceeded.
-    pub fn insert_with_limits(
-        &mut self,
-        key: Name,
-        value: impl Into<MetadataValueBox>,
-        limits: Limits,
-    ) -> Result<Option<MetadataValueBox>, MetadataError> {
-        let value = value.into();
-
-        if self.0.len() >= limits.capacity as usize && !self.0.contains_key(&key) {
-            return Err(MetadataError::MaxCapacity(SizeError {
-                limits,
-                actual: self.len_u64(),
-            }));
-        }
-        check_size_limits(&key, value.clone(), limits)?;
-        Ok(self.0.insert(key, value))
+    /// If the key is absent or its value is not a [`MetadataValueBox::Numeric`]
+    /// representable as [`u32`].
+    pub fn get_u32<K: Ord + ?Sized>(&self, key: &K) -> Result<u32, TypeMismatch>
+    where
+        Name: Borrow<K>,
+    {
+        self.get_typed(key, "u32", |value| match value {
+            MetadataValueBox::Numeric(value) => (*value).try_into().ok(),
+            _ => None,
+        })
+    }
+
+    /// Get the value under `key` as [`Numeric`].
+    ///
+    /// # Errors
+    /// If the key is absent or its value is not a [`MetadataValueBox::Numeric`].
+    pub fn get_numeric<K: Ord + ?Sized>(&self, key: &K) -> Result<Numeric, TypeMismatch>
+    where
+        Name: Borrow<K>,
+    {
+        self.get_typed(key, "numeric", |value| match value {
+            MetadataValueBox::Numeric(value) => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// Get the value under `key` as a nested [`Metadata`] layer.
+    ///
+    /// # Errors
+    /// If the key is absent or its value is not a [`MetadataValueBox::LimitedMetadata`].
+    pub fn get_metadata<K: Ord + ?Sized>(&self, key: &K) -> Result<&Metadata, TypeMismatch>
+    where
+        Name: Borrow<K>,
+    {
+        self.get_typed(key, "metadata", |value| match value {
+            MetadataValueBox::LimitedMetadata(value) => Some(value),
+            _ => None,
+        })
+    }
+
+    /// Get the value under `key` converted into any type `T` that
+    /// [`MetadataValueBox`] can be converted into.
+    ///
+    /// # Errors
+    /// If the key is absent or the conversion fails.
+    pub fn get_as<T, K: Ord + ?Sized>(&self, key: &K) -> Result<T, TypeMismatch>
+    where
+        Name: Borrow<K>,
+        MetadataValueBox: TryInto<T>,
+    {
+        self.get_typed(key, core::any::type_name::<T>(), |value| {
+            value.clone().try_into().ok()
+        })
+    }
+
+    fn get_typed<'a, K: Ord + ?Sized, T>(
+        &'a self,
+        key: &K,
+        expected: &'static str,
+        convert: impl FnOnce(&'a MetadataValueBox) -> Option<T>,
+    ) -> Result<T, TypeMismatch>
+    where
+        Name: Borrow<K>,
+    {
+        let value = self.get(key).ok_or(TypeMismatch {
+            expected,
+            found: None,
+        })?;
+
+        convert(value).ok_or(TypeMismatch {
+            expected,
+            found: Some(value.type_name()),
+        })
+    }
+
+    fn len_u64(&self) -> u64 {
+        self.0
+            .len()
+            .try_into()
+            .expect("`usize` should always fit into `u64`")
+    }
+
+    /// Encoded size of this layer in bytes.
+    fn byte_size(&self) -> usize {
+        compact_len(self.0.len()) + self.1
+    }
+
+    /// Encoded size of this layer in bytes after the entry under `key`
+    /// is replaced with (or becomes) an entry of `entry_size` bytes.
+    fn byte_size_with(&self, key: &Name, entry_size: usize) -> usize {
+        let old_entry_size = self
+            .0
+            .get_key_value(key)
+            .map_or(0, |(key, value)| entry_size_of(key, value));
+        let len_after = self.0.len() + usize::from(!self.0.contains_key(key));
+
+        compact_len(len_after) + self.1 - old_entry_size + entry_size
+    }
+
+    /// Check that the layer found at `parents` exists and that making its
+    /// entry under `key` `entry_size` bytes long doesn't push any of the
+    /// enclosing layers over `max_total_byte_size`.
+    fn check_nested_entry(
+        &self,
+        parents: &Path,
+        key: &Name,
+        entry_size: usize,
+        limits: Limits,
+    ) -> Result<(), MetadataError> {
+        let mut ancestor_sizes = Vec::with_capacity(parents.len());
+        let mut layer = self;
+        for k in parents {
+            ancestor_sizes.push(layer.byte_size());
+            layer = match layer
+                .0
+                .get(k)
+                .ok_or_else(|| MetadataError::MissingSegment(k.clone()))?
+            {
+                MetadataValueBox::LimitedMetadata(data) => data,
+                _ => return Err(MetadataError::InvalidSegment(k.clone())),
+            };
+        }
+
+        // Every enclosing layer grows by exactly as much as the innermost one
+        let old_size = layer.byte_size();
+        let new_size = layer.byte_size_with(key, entry_size);
+        for ancestor_size in ancestor_sizes {
+            check_total_size_limits((ancestor_size + new_size).saturating_sub(old_size), limits)?;
+        }
+
+        Ok(())
+    }
+
+    /// Insert the given [`Value`] into the given path. If the path is
+    /// complete, check the limits and only then insert. The creation
+    /// of the path is the responsibility of the user, see
+    /// [`Self::nested_insert_create_path_with_limits`] otherwise.
+    ///
+    /// # Errors
+    /// - If the path is empty.
+    /// - If one of the intermediate keys is absent.
+    /// - If some intermediate key is a leaf node.
+    pub fn nested_insert_with_limits(
+        &mut self,
+        path: &Path,
+        value: impl Into<MetadataValueBox>,
+        limits: Limits,
+    ) -> Result<Option<MetadataValueBox>, MetadataError> {
+        if self.0.len() >= limits.capacity as usize {
+            return Err(MetadataError::MaxCapacity(SizeError {
+                limits,
+                actual: self.len_u64(),
+            }));
+        }
+        let (key, parents) = path.split_last().ok_or(MetadataError::EmptyPath)?;
+        let value = value.into();
+        self.check_nested_entry(parents, key, entry_size_of(key, &value), limits)?;
+        self.with_layer_mut(parents, |layer| {
+            Some(layer.insert_with_limits(key.clone(), value, limits))
+        })
+        .expect("Path to the layer was checked above")
+    }
+
+    /// Insert the given [`Value`] into the given path, creating any
+    /// missing intermediate [`Metadata`] layers on the way. Limits are
+    /// checked at every level before anything is inserted, so on
+    /// error `self` is left untouched.
+    ///
+    /// # Errors
+    /// - If the path is empty.
+    /// - If some intermediate key is a leaf node.
+    /// - If `max_entry_len` or `capacity` from `limits` are exceeded at any level.
+    pub fn nested_insert_create_path_with_limits(
+        &mut self,
+        path: &Path,
+        value: impl Into<MetadataValueBox>,
+        limits: Limits,
+    ) -> Result<Option<MetadataValueBox>, MetadataError> {
+        let key = path.last().ok_or(MetadataError::EmptyPath)?;
+        let parents = &path[..path.len() - 1];
+
+        let mut existing = 0;
+        let mut map = &self.0;
+        for k in parents {
+            match map.get(k) {
+                Some(MetadataValueBox::LimitedMetadata(data)) => {
+                    map = &data.0;
+                    existing += 1;
+                }
+                Some(_) => return Err(MetadataError::InvalidSegment(k.clone())),
+                None => break,
+            }
+        }
+        let (existing_path, missing_path) = parents.split_at(existing);
+
+        // Build the missing branch bottom-up so that it is attached in a single insert
+        let mut branch_key = key.clone();
+        let mut branch_value = value.into();
+        for k in missing_path.iter().rev() {
+            let mut layer = Metadata::new();
+            layer.insert_with_limits(branch_key, branch_value, limits)?;
+            branch_key = k.clone();
+            branch_value = layer.into();
+        }
+
+        let entry_size = entry_size_of(&branch_key, &branch_value);
+        self.check_nested_entry(existing_path, &branch_key, entry_size, limits)?;
+        self.with_layer_mut(existing_path, |layer| {
+            Some(layer.insert_with_limits(branch_key, branch_value, limits))
+        })
+        .expect("Path to the layer was checked above")
+    }
+
+    /// Insert [`Value`] under the given key.  Returns `Some(value)`
+    /// if the value was already present, `None` otherwise.
+    ///
+    /// # Errors
+    /// Fails if `max_entry_len`, `capacity` or `max_total_byte_size` from `limits` are exceeded.
+    pub fn insert_with_limits(
+        &mut self,
+        key: Name,
+        value: impl Into<MetadataValueBox>,
+        limits: Limits,
+    ) -> Result<Option<MetadataValueBox>, MetadataError> {
+        let value = value.into();
+
+        if self.0.len() >= limits.capacity as usize && !self.0.contains_key(&key) {
+            return Err(MetadataError::MaxCapacity(SizeError {
+                limits,
+                actual: self.len_u64(),
+            }));
+        }
+        let entry_size = entry_size_of(&key, &value);
+        check_size_limits(entry_size, limits)?;
+        check_total_size_limits(self.byte_size_with(&key, entry_size), limits)?;
+        Ok(self.insert_entry(key, value, entry_size))
+    }
+
+    /// Insert `value` under `key` only if the current value is `expected`,
+    /// `None` meaning that the key must be absent.
+    ///
+    /// Returns the previous value, same as [`Self::insert_with_limits`].
+    ///
+    /// # Errors
+    /// - If the current value differs from `expected`
+    /// - If `max_entry_len`, `capacity` or `max_total_byte_size` from `limits` are exceeded
+    pub fn compare_and_swap(
+        &mut self,
+        key: Name,
+        expected: Option<&MetadataValueBox>,
+        value: impl Into<MetadataValueBox>,
+        limits: Limits,
+    ) -> Result<Option<MetadataValueBox>, MetadataError> {
+        if self.0.get(&key) != expected {
+            return Err(MetadataError::ValueMismatch(key));
+        }
+        self.insert_with_limits(key, value, limits)
+    }
+
+    /// Merge `other` into `self`, layer by layer.
+    ///
+    /// Keys present only in `other` are inserted. When a key is present in both
+    /// and both values are [`Metadata`], they are merged recursively, otherwise
+    /// the conflict is resolved according to `policy`. The merge is atomic: on
+    /// error `self` is left unchanged.
+    ///
+    /// Returns the paths of the entries that were written. Nested layers that
+    /// were merged recursively aren't reported themselves, only their entries are.
+    ///
+    /// # Errors
+    /// - If `limits` are exceeded in any of the layers.
+    /// - If a conflicting key is found and `policy` is [`MergePolicy::Reject`].
+    pub fn merge_with_limits(
+        &mut self,
+        other: Metadata,
+        policy: MergePolicy,
+        limits: Limits,
+    ) -> Result<Vec<Vec<Name>>, MetadataError> {
+        let mut merged = self.clone();
+        let written = merged.merge_layer(other, policy, limits)?;
+        *self = merged;
+        Ok(written)
+    }
+
+    fn merge_layer(
+        &mut self,
+        other: Metadata,
+        policy: MergePolicy,
+        limits: Limits,
+    ) -> Result<Vec<Vec<Name>>, MetadataError> {
+        let mut written = Vec::new();
+
+        let other = Arc::try_unwrap(other.0).unwrap_or_else(|shared| (*shared).clone());
+        for (key, value) in other {
+            let (value, paths) = match (self.map_mut().get_mut(&key), value) {
+                (
+                    Some(MetadataValueBox::LimitedMetadata(existing)),
+                    MetadataValueBox::LimitedMetadata(patch),
+                ) => {
+                    // Cloning only shares the map, and the existing entry has to
+                    // stay in place for its size to be accounted for on insert
+                    let mut layer = existing.clone();
+                    let paths = layer.merge_layer(patch, policy, limits)?;
+                    (MetadataValueBox::LimitedMetadata(layer), paths)
+                }
+                (Some(_), value) => match policy {
+                    MergePolicy::Overwrite => (value, vec![Vec::new()]),
+                    MergePolicy::KeepExisting => continue,
+                    MergePolicy::Reject => return Err(MetadataError::KeyConflict(key)),
+                },
+                (None, value) => (value, vec![Vec::new()]),
+            };
+
+            self.insert_with_limits(key.clone(), value, limits)?;
+            written.extend(paths.into_iter().map(|mut path| {
+                path.insert(0, key.clone());
+                path
+            }));
+        }
+
+        Ok(written)
+    }
+}
+
+/// Error returned by the typed getters of [`Metadata`], e.g. [`Metadata::get_str`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeMismatch {
+    /// Type that was requested
+    pub expected: &'static str,
+    /// Type that was found, or `None` if the key is absent
+    pub found: Option<&'static str>,
+}
+
+impl core::fmt::Display for TypeMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.found {
+            Some(found) => write!(f, "Expected `{}`, found `{found}`", self.expected),
+            None => write!(f, "Expected `{}`, but the key is absent", self.expected),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TypeMismatch {}
+
+/// Errors that can occur when importing [`Metadata`] from JSON.
+#[derive(Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum MetadataJsonError {
+    /// Only a JSON object can be imported as metadata
+    NotAnObject,
+    /// JSON `null` has no metadata counterpart
+    Null,
+    /// `{0}`: not a valid metadata key
+    InvalidKey(String),
+    /// `{0}`: only non-negative decimal numbers are supported
+    InvalidNumber(String),
+    /// Imported metadata doesn't fit into the limits
+    Limits(#[cfg_attr(feature = "std", source)] MetadataError),
+}
+
+impl From<MetadataError> for MetadataJsonError {
+    fn from(err: MetadataError) -> Self {
+        Self::Limits(err)
+    }
+}
+
+impl Metadata {
+    /// Import metadata from a JSON object.
+    ///
+    /// Nested objects become nested [`Metadata`] layers, arrays become
+    /// [`MetadataValueBox::Vec`], and strings, booleans and numbers map to the
+    /// corresponding scalar variants. Every layer is checked against `limits`.
+    ///
+    /// # Errors
+    /// - If `value` (or any of its nested values) can't be represented as metadata.
+    /// - If `limits` are exceeded in any of the layers.
+    pub fn from_json_value(
+        value: serde_json::Value,
+        limits: Limits,
+    ) -> Result<Self, MetadataJsonError> {
+        let serde_json::Value::Object(object) = value else {
+            return Err(MetadataJsonError::NotAnObject);
+        };
+
+        let mut metadata = Self::new();
+        for (key, value) in object {
+            let key = Name::from_str(&key).map_err(|_| MetadataJsonError::InvalidKey(key))?;
+            let value = MetadataValueBox::from_json_value(value, limits)?;
+            metadata.insert_with_limits(key, value, limits)?;
+        }
+
+        Ok(metadata)
+    }
+
+    /// Export metadata as a JSON object.
+    ///
+    /// This is the inverse of [`Self::from_json_value`], except that
+    /// [`MetadataValueBox::Name`] is exported as a string and
+    /// [`MetadataValueBox::Bytes`] as an array of numbers.
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::Value::Object(
+            self.0
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_json_value()))
+                .collect(),
+        )
+    }
+}
+
+impl MetadataValueBox {
+    /// Import a single value from JSON, see [`Metadata::from_json_value`].
+    ///
+    /// # Errors
+    /// Same as [`Metadata::from_json_value`].
+    pub fn from_json_value(
+        value: serde_json::Value,
+        limits: Limits,
+    ) -> Result<Self, MetadataJsonError> {
+        use serde_json::Value;
+
+        Ok(match value {
+            Value::Null => return Err(MetadataJsonError::Null),
+            Value::Bool(value) => Self::Bool(value),
+            Value::String(value) => Self::String(value),
+            Value::Number(value) => {
+                let value = value.to_string();
+                Self::Numeric(
+                    Numeric::from_str(&value)
+                        .map_err(|_| MetadataJsonError::InvalidNumber(value))?,
+                )
+            }
+            Value::Array(values) => Self::Vec(
+                values
+                    .into_iter()
+                    .map(|value| Self::from_json_value(value, limits))
+                    .collect::<Result<_, _>>()?,
+            ),
+            object @ Value::Object(_) => {
+                Self::LimitedMetadata(Metadata::from_json_value(object, limits)?)
+            }
+        })
+    }
+
+    /// Export a single value as JSON, see [`Metadata::to_json_value`].
+    pub fn to_json_value(&self) -> serde_json::Value {
+        use serde_json::Value;
+
+        match self {
+            Self::Bool(value) => Value::Bool(*value),
+            Self::String(value) => Value::String(value.clone()),
+            Self::Name(value) => Value::String(value.to_string()),
+            Self::Bytes(value) => Value::Array(value.iter().copied().map(Value::from).collect()),
+            Self::Numeric(value) => {
+                let value = value.to_string();
+                serde_json::Number::from_str(&value)
+                    .map_or_else(|_| Value::String(value), Value::Number)
+            }
+            Self::LimitedMetadata(value) => value.to_json_value(),
+            Self::Vec(values) => Value::Array(values.iter().map(Self::to_json_value).collect()),
+        }
     }
 }
 
@@ -404,7 +1213,17 @@ impl Metadata {
     where
         Name: Borrow<K>,
     {
-        self.0.remove(key)
+        self.remove_entry(key)
+    }
+
+    /// Remove the entry under `key`, keeping the size of the entries up to date.
+    fn remove_entry<K: Ord + ?Sized>(&mut self, key: &K) -> Option<MetadataValueBox>
+    where
+        Name: Borrow<K>,
+    {
+        let (key, value) = self.map_mut().remove_entry(key)?;
+        self.1 -= entry_size_of(&key, &value);
+        Some(value)
     }
 
     /// Remove leaf node in metadata, given path. If the path is
@@ -412,25 +1231,52 @@ impl Metadata {
     /// are not [`Metadata`] instances) return `None`. Else return the
     /// owned value corresponding to that path.
     pub fn nested_remove(&mut self, path: &Path) -> Option<MetadataValueBox> {
-        let key = path.last()?;
-        let mut map = &mut self.0;
-        for k in path.iter().take(path.len() - 1) {
-            map = match map.get_mut(k)? {
-                MetadataValueBox::LimitedMetadata(data) => &mut data.0,
-                _ => return None,
-            };
+        let (key, parents) = path.split_last()?;
+        self.with_layer_mut(parents, |layer| layer.remove_entry(key))
+    }
+
+    /// Same as [`Self::nested_remove`], but also removes the interior
+    /// layers along the `path` which are left empty by the removal.
+    pub fn nested_remove_prune(&mut self, path: &Path) -> Option<MetadataValueBox> {
+        let (key, rest) = path.split_first()?;
+        if rest.is_empty() {
+            return self.remove_entry(key);
         }
-        map.remove(key)
+        let MetadataValueBox::LimitedMetadata(layer) = self.map_mut().get_mut(key)? else {
+            return None;
+        };
+        let old_size = layer.byte_size();
+        let removed = layer.nested_remove_prune(rest);
+        let new_size = layer.byte_size();
+        let is_empty = layer.is_empty();
+        self.1 = self.1 - old_size + new_size;
+
+        let removed = removed?;
+        if is_empty {
+            self.remove_entry(key);
+        }
+        Some(removed)
+    }
+
+    /// Recursively remove all empty nested [`Metadata`] layers,
+    /// including the ones which only contained empty layers.
+    pub fn compact(&mut self) {
+        self.map_mut().retain(|_, value| match value {
+            MetadataValueBox::LimitedMetadata(layer) => {
+                layer.compact();
+                !layer.is_empty()
+            }
+            _ => true,
+        });
+        self.1 = self
+            .0
+            .iter()
+            .map(|(key, value)| entry_size_of(key, value))
+            .sum();
     }
 }
 
-fn check_size_limits(
-    key: &Name,
-    value: MetadataValueBox,
-    limits: Limits,
-) -> Result<(), MetadataError> {
-    let entry_bytes: Vec<u8> = (key, value).encode();
-    let byte_size = entry_bytes.len();
+fn check_size_limits(byte_size: usize, limits: Limits) -> Result<(), MetadataError> {
     if byte_size > limits.max_entry_len as usize {
         return Err(MetadataError::EntryTooBig(SizeError {
             limits,
@@ -442,9 +1288,53 @@ fn check_size_limits(
     Ok(())
 }
 
+fn check_total_size_limits(byte_size: usize, limits: Limits) -> Result<(), MetadataError> {
+    let byte_size: u64 = byte_size
+        .try_into()
+        .expect("`usize` should always fit into `u64`");
+    if byte_size > limits.max_total_byte_size {
+        return Err(MetadataError::MaxTotalByteSize(SizeError {
+            limits,
+            actual: byte_size,
+        }));
+    }
+    Ok(())
+}
+
+/// Encoded size of the `(key, value)` entry of a [`Metadata`] layer.
+fn entry_size_of(key: &Name, value: &MetadataValueBox) -> usize {
+    key.encoded_size() + value_size(value)
+}
+
+/// Encoded size of `value`, using the size kept by nested layers instead of encoding them.
+fn value_size(value: &MetadataValueBox) -> usize {
+    // Variant index takes one byte
+    match value {
+        MetadataValueBox::LimitedMetadata(layer) => 1 + layer.byte_size(),
+        MetadataValueBox::Vec(values) => {
+            1 + compact_len(values.len()) + values.iter().map(value_size).sum::<usize>()
+        }
+        MetadataValueBox::Bool(_)
+        | MetadataValueBox::String(_)
+        | MetadataValueBox::Name(_)
+        | MetadataValueBox::Bytes(_)
+        | MetadataValueBox::Numeric(_) => value.encoded_size(),
+    }
+}
+
+fn compact_len(len: usize) -> usize {
+    let len: u32 = len
+        .try_into()
+        .expect("Metadata length should fit into `u32`");
+    Compact::<u32>::compact_len(&len)
+}
+
 pub mod prelude {
     //! Prelude: re-export most commonly used traits, structs and macros from this module.
-    pub use super::{Limits as MetadataLimits, Metadata, UnlimitedMetadata};
+    pub use super::{
+        Expiry as MetadataExpiry, Limits as MetadataLimits, MergePolicy, Metadata, NumericDelta,
+        UnlimitedMetadata,
+    };
 }
 
 #[cfg(test)]
@@ -564,6 +1454,453 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn bulk_read_api() -> Result<(), TestError> {
+        let mut metadata = Metadata::new();
+        assert!(metadata.is_empty());
+        let limits = Limits::new(10, 15);
+        metadata.insert_with_limits(Name::from_str("b")?, "2".to_owned(), limits)?;
+        metadata.insert_with_limits(Name::from_str("a")?, "1".to_owned(), limits)?;
+
+        assert_eq!(metadata.len(), 2);
+        assert!(!metadata.is_empty());
+        assert!(metadata.contains_key("a"));
+        assert!(!metadata.contains_key("c"));
+        assert_eq!(
+            metadata.keys().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        assert_eq!(
+            metadata.values().cloned().collect::<Vec<_>>(),
+            vec![
+                MetadataValueBox::from("1".to_owned()),
+                MetadataValueBox::from("2".to_owned())
+            ]
+        );
+        assert_eq!(metadata.iter().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn nested_insert_creates_missing_path() -> Result<(), TestError> {
+        let mut metadata = Metadata::new();
+        let limits = Limits::new(1024, 1024);
+        metadata.insert_with_limits(Name::from_str("0")?, Metadata::new(), limits)?;
+        let path = [
+            Name::from_str("0")?,
+            Name::from_str("1")?,
+            Name::from_str("2")?,
+            Name::from_str("3")?,
+        ];
+        let previous = metadata.nested_insert_create_path_with_limits(
+            &path,
+            "Hello World".to_owned(),
+            limits,
+        )?;
+        assert!(previous.is_none());
+        assert_eq!(
+            *metadata.nested_get(&path).expect("Valid"),
+            MetadataValueBox::from("Hello World".to_owned())
+        );
+        assert!(matches!(
+            metadata.nested_get(&path[..2]),
+            Some(MetadataValueBox::LimitedMetadata(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn nested_insert_create_path_is_atomic() -> Result<(), TestError> {
+        let mut metadata = Metadata::new();
+        let limits = Limits::new(10, 14);
+        let path = [
+            Name::from_str("0")?,
+            Name::from_str("1")?,
+            Name::from_str("2")?,
+        ];
+        assert!(metadata
+            .nested_insert_create_path_with_limits(&path, "Hello World".to_owned(), limits)
+            .is_err());
+        assert_eq!(metadata, Metadata::new());
+
+        metadata.insert_with_limits(Name::from_str("0")?, "leaf".to_owned(), limits)?;
+        assert_eq!(
+            metadata.nested_insert_create_path_with_limits(&path, "1".to_owned(), limits),
+            Err(MetadataError::InvalidSegment(Name::from_str("0")?))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn merge_resolves_conflicts_by_policy() -> Result<(), TestError> {
+        let limits = Limits::new(1024, 1024);
+        let mut inner = Metadata::new();
+        inner.insert_with_limits(Name::from_str("a")?, 1_u32, limits)?;
+        let mut metadata = Metadata::new();
+        metadata.insert_with_limits(Name::from_str("x")?, "old".to_owned(), limits)?;
+        metadata.insert_with_limits(Name::from_str("nested")?, inner, limits)?;
+
+        let mut patch_inner = Metadata::new();
+        patch_inner.insert_with_limits(Name::from_str("b")?, 2_u32, limits)?;
+        let mut patch = Metadata::new();
+        patch.insert_with_limits(Name::from_str("x")?, "new".to_owned(), limits)?;
+        patch.insert_with_limits(Name::from_str("nested")?, patch_inner, limits)?;
+
+        let mut kept = metadata.clone();
+        kept.merge_with_limits(patch.clone(), MergePolicy::KeepExisting, limits)?;
+        assert_eq!(
+            kept.get("x"),
+            Some(&MetadataValueBox::from("old".to_owned()))
+        );
+        // Nested layers are merged regardless of the policy
+        let a = [Name::from_str("nested")?, Name::from_str("a")?];
+        let b = [Name::from_str("nested")?, Name::from_str("b")?];
+        assert!(kept.nested_get(&a).is_some() && kept.nested_get(&b).is_some());
+
+        let mut overwritten = metadata.clone();
+        let written =
+            overwritten.merge_with_limits(patch.clone(), MergePolicy::Overwrite, limits)?;
+        assert_eq!(written, vec![b.to_vec(), vec![Name::from_str("x")?]]);
+        assert_eq!(
+            overwritten.get("x"),
+            Some(&MetadataValueBox::from("new".to_owned()))
+        );
+
+        let mut rejected = metadata.clone();
+        assert_eq!(
+            rejected.merge_with_limits(patch, MergePolicy::Reject, limits),
+            Err(MetadataError::KeyConflict(Name::from_str("x")?))
+        );
+        assert_eq!(rejected, metadata);
+        Ok(())
+    }
+
+    #[test]
+    fn typed_getters() -> Result<(), TestError> {
+        let limits = Limits::new(1024, 1024);
+        let mut metadata = Metadata::new();
+        metadata.insert_with_limits(Name::from_str("name")?, "Alice".to_owned(), limits)?;
+        metadata.insert_with_limits(Name::from_str("age")?, 42_u32, limits)?;
+        metadata.insert_with_limits(Name::from_str("verified")?, true, limits)?;
+        metadata.insert_with_limits(Name::from_str("nested")?, Metadata::new(), limits)?;
+
+        assert_eq!(metadata.get_str("name"), Ok("Alice"));
+        assert_eq!(metadata.get_u32("age"), Ok(42));
+        assert_eq!(metadata.get_as::<u64, _>("age"), Ok(42));
+        assert_eq!(metadata.get_bool("verified"), Ok(true));
+        assert_eq!(metadata.get_metadata("nested"), Ok(&Metadata::new()));
+        assert_eq!(
+            metadata.get_bool("name"),
+            Err(TypeMismatch {
+                expected: "bool",
+                found: Some("string")
+            })
+        );
+        assert_eq!(
+            metadata.get_str("missing"),
+            Err(TypeMismatch {
+                expected: "string",
+                found: None
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nested_remove_prune() -> Result<(), TestError> {
+        let limits = Limits::new(1024, 1024);
+        let mut metadata = Metadata::new();
+        let path = [
+            Name::from_str("a")?,
+            Name::from_str("b")?,
+            Name::from_str("c")?,
+        ];
+        let sibling = [Name::from_str("a")?, Name::from_str("d")?];
+        metadata.nested_insert_create_path_with_limits(&path, 1_u32, limits)?;
+        metadata.nested_insert_create_path_with_limits(&sibling, 2_u32, limits)?;
+
+        assert_eq!(metadata.nested_remove_prune(&path), Some(1_u32.into()));
+        // `b` is empty now, but `a` still holds `d`
+        assert!(metadata.nested_get(&path[..2]).is_none());
+        assert!(metadata.nested_get(&sibling).is_some());
+
+        assert_eq!(metadata.nested_remove_prune(&sibling), Some(2_u32.into()));
+        assert!(metadata.is_empty());
+        assert_eq!(metadata.nested_remove_prune(&sibling), None);
+        Ok(())
+    }
+
+    #[test]
+    fn compact_removes_empty_layers() -> Result<(), TestError> {
+        let limits = Limits::new(1024, 1024);
+        let mut inner = Metadata::new();
+        inner.insert_with_limits(Name::from_str("empty")?, Metadata::new(), limits)?;
+        let mut metadata = Metadata::new();
+        metadata.insert_with_limits(Name::from_str("nested")?, inner, limits)?;
+        metadata.insert_with_limits(Name::from_str("value")?, true, limits)?;
+
+        metadata.compact();
+        assert_eq!(metadata.len(), 1);
+        assert!(metadata.contains_key("value"));
+        Ok(())
+    }
+
+    #[test]
+    fn clones_share_layers_until_written() -> Result<(), TestError> {
+        let limits = Limits::new(1024, 1024);
+        let path = [Name::from_str("nested")?, Name::from_str("a")?];
+        let mut metadata = Metadata::new();
+        metadata.nested_insert_create_path_with_limits(&path, 1_u32, limits)?;
+
+        let mut clone = metadata.clone();
+        assert!(Arc::ptr_eq(&metadata.0, &clone.0));
+
+        clone.insert_with_limits(Name::from_str("b")?, 2_u32, limits)?;
+        assert!(!Arc::ptr_eq(&metadata.0, &clone.0));
+        // The untouched nested layer is still shared
+        let (Ok(original), Ok(cloned)) = (
+            metadata.get_metadata("nested"),
+            clone.get_metadata("nested"),
+        ) else {
+            panic!("`nested` should be a metadata layer");
+        };
+        assert!(Arc::ptr_eq(&original.0, &cloned.0));
+        assert_eq!(metadata.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn keys_with_prefix() -> Result<(), TestError> {
+        let limits = Limits::new(1024, 1024);
+        let mut metadata = Metadata::new();
+        for key in ["kyc", "kyc_level", "kyc_provider", "kz", "name"] {
+            metadata.insert_with_limits(Name::from_str(key)?, true, limits)?;
+        }
+
+        let keys: Vec<_> = metadata
+            .keys_with_prefix("kyc")
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(keys, ["kyc", "kyc_level", "kyc_provider"]);
+        assert_eq!(metadata.keys_with_prefix("").count(), metadata.len());
+        assert_eq!(metadata.keys_with_prefix("missing").count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn json_roundtrip() -> Result<(), TestError> {
+        let json = serde_json::json!({
+            "name": "Alice",
+            "verified": true,
+            "age": 42,
+            "balance": "1.5",
+            "tags": ["a", "b"],
+            "address": { "city": "Tokyo", "zip": 1000001 },
+        });
+        let limits = Limits::new(1024, 1024);
+        let metadata = Metadata::from_json_value(json.clone(), limits).expect("Valid metadata");
+
+        let path = [Name::from_str("address")?, Name::from_str("city")?];
+        assert_eq!(
+            metadata.nested_get(&path),
+            Some(&MetadataValueBox::from("Tokyo".to_owned()))
+        );
+        assert_eq!(metadata.to_json_value(), json);
+
+        assert_eq!(
+            Metadata::from_json_value(serde_json::json!({ "key": null }), limits),
+            Err(MetadataJsonError::Null)
+        );
+        assert!(matches!(
+            Metadata::from_json_value(json, Limits::new(2, 1024)),
+            Err(MetadataJsonError::Limits(MetadataError::MaxCapacity(_)))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn insert_exceeds_total_byte_size() -> Result<(), TestError> {
+        let mut metadata = Metadata::new();
+        // Each entry takes 5 bytes and the map length prefix takes 1 more
+        let limits = Limits::new(10, 5).with_max_total_byte_size(11);
+        metadata.insert_with_limits(Name::from_str("1")?, "0".to_owned(), limits)?;
+        metadata.insert_with_limits(Name::from_str("2")?, "0".to_owned(), limits)?;
+        // Replacing an existing entry with one of equal size is fine
+        metadata.insert_with_limits(Name::from_str("2")?, "1".to_owned(), limits)?;
+        assert_eq!(
+            metadata.insert_with_limits(Name::from_str("3")?, "0".to_owned(), limits),
+            Err(MetadataError::MaxTotalByteSize(SizeError {
+                limits,
+                actual: 16
+            }))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nested_insert_respects_total_byte_size_of_every_layer() -> Result<(), TestError> {
+        let mut metadata = Metadata::new();
+        let limits = Limits::new(10, 64).with_max_total_byte_size(16);
+        metadata.insert_with_limits(Name::from_str("0")?, Metadata::new(), limits)?;
+        metadata.insert_with_limits(Name::from_str("1")?, "ab".to_owned(), limits)?;
+        // Fits into the inner layer, but not into the outer one
+        let path = [Name::from_str("0")?, Name::from_str("1")?];
+        assert!(matches!(
+            metadata.nested_insert_with_limits(&path, "abcdef".to_owned(), limits),
+            Err(MetadataError::MaxTotalByteSize(_))
+        ));
+        assert!(metadata.nested_get(&path).is_none());
+        metadata.nested_insert_with_limits(&path, "a".to_owned(), limits)?;
+        Ok(())
+    }
+
+    #[test]
+    fn byte_size_is_tracked_through_inserts() -> Result<(), TestError> {
+        let limits = Limits::new(1024, 1024);
+        let mut metadata = Metadata::new();
+        let path = [
+            Name::from_str("a")?,
+            Name::from_str("b")?,
+            Name::from_str("c")?,
+        ];
+        metadata.nested_insert_create_path_with_limits(&path, "value".to_owned(), limits)?;
+        metadata.insert_with_limits(
+            Name::from_str("list")?,
+            vec![MetadataValueBox::from(Metadata::new()), 1_u32.into()],
+            limits,
+        )?;
+        metadata.nested_insert_with_limits(&path, "longer value".to_owned(), limits)?;
+        assert_eq!(metadata.byte_size(), metadata.encoded_size());
+
+        let mut patch = Metadata::new();
+        patch.nested_insert_create_path_with_limits(
+            &[Name::from_str("a")?, Name::from_str("d")?],
+            true,
+            limits,
+        )?;
+        metadata.merge_with_limits(patch, MergePolicy::Overwrite, limits)?;
+        assert_eq!(metadata.byte_size(), metadata.encoded_size());
+
+        let decoded = Metadata::decode(&mut metadata.encode().as_slice()).expect("Valid");
+        assert_eq!(decoded.byte_size(), metadata.encoded_size());
+        let deserialized: Metadata =
+            serde_json::from_value(serde_json::to_value(&metadata).expect("Valid")).expect("Valid");
+        assert_eq!(deserialized.byte_size(), metadata.encoded_size());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "transparent_api")]
+    fn byte_size_is_tracked_through_removals() -> Result<(), TestError> {
+        let limits = Limits::new(1024, 1024);
+        let mut metadata = Metadata::new();
+        let path = [
+            Name::from_str("a")?,
+            Name::from_str("b")?,
+            Name::from_str("c")?,
+        ];
+        let sibling = [Name::from_str("a")?, Name::from_str("d")?];
+        metadata.nested_insert_create_path_with_limits(&path, "value".to_owned(), limits)?;
+        metadata.nested_insert_create_path_with_limits(&sibling, true, limits)?;
+        metadata.insert_with_limits(Name::from_str("e")?, Metadata::new(), limits)?;
+
+        metadata.nested_remove(&sibling);
+        assert_eq!(metadata.byte_size(), metadata.encoded_size());
+        metadata.nested_remove_prune(&path);
+        assert_eq!(metadata.byte_size(), metadata.encoded_size());
+        metadata.compact();
+        assert_eq!(metadata.byte_size(), metadata.encoded_size());
+        metadata.remove("a");
+        assert_eq!(metadata.byte_size(), metadata.encoded_size());
+        assert!(metadata.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn limits_display_roundtrip() {
+        assert_eq!(Limits::new(10, 5).to_string(), "10,5_ML");
+        assert_eq!(
+            Limits::new(10, 5).with_max_total_byte_size(42).to_string(),
+            "10,5,42_ML"
+        );
+    }
+
+    #[test]
+    fn limits_bounded_by() {
+        let global = Limits::new(10, 5).with_max_total_byte_size(42);
+        assert_eq!(
+            Limits::new(20, 3).bounded_by(global),
+            Limits::new(10, 3).with_max_total_byte_size(42)
+        );
+        assert_eq!(
+            Limits::new(4, 4).bounded_by(global),
+            Limits::new(4, 4).with_max_total_byte_size(42)
+        );
+    }
+
+    #[test]
+    fn expiry_is_reached() {
+        assert!(!Expiry::BlockHeight(5).is_reached(4, 1_000));
+        assert!(Expiry::BlockHeight(5).is_reached(5, 0));
+        assert!(!Expiry::TimestampMs(1_000).is_reached(u64::MAX, 999));
+        assert!(Expiry::TimestampMs(1_000).is_reached(1, 1_000));
+    }
+
+    #[test]
+    fn numeric_delta_apply() {
+        let value = Numeric::from(5_u32);
+
+        assert_eq!(
+            NumericDelta::Increase(3_u32.into()).apply(value),
+            Some(8_u32.into())
+        );
+        assert_eq!(
+            NumericDelta::Decrease(5_u32.into()).apply(value),
+            Some(0_u32.into())
+        );
+        assert_eq!(NumericDelta::Decrease(6_u32.into()).apply(value), None);
+        assert_eq!(NumericDelta::Increase(Numeric::MAX).apply(value), None);
+    }
+
+    #[test]
+    fn unlimited_metadata_conversions() -> Result<(), TestError> {
+        let mut unlimited = UnlimitedMetadata::new();
+        unlimited.insert(Name::from_str("a")?, 1_u32);
+        unlimited.insert(Name::from_str("b")?, "22".to_owned());
+
+        assert!(matches!(
+            unlimited.clone().try_into_metadata(Limits::new(1, 100)),
+            Err(MetadataError::MaxCapacity(_))
+        ));
+        assert!(matches!(
+            unlimited.clone().try_into_metadata(Limits::new(10, 3)),
+            Err(MetadataError::EntryTooBig(_))
+        ));
+        let metadata = unlimited.clone().try_into_metadata(Limits::new(10, 100))?;
+        assert_eq!(metadata.get("a"), Some(&1_u32.into()));
+        assert_eq!(UnlimitedMetadata::from(metadata), unlimited);
+        Ok(())
+    }
+
+    #[test]
+    fn compare_and_swap() -> Result<(), TestError> {
+        let mut metadata = Metadata::new();
+        let limits = Limits::new(10, 100);
+        let key = Name::from_str("counter")?;
+
+        metadata.compare_and_swap(key.clone(), None, 1_u32, limits)?;
+        assert_eq!(
+            metadata.compare_and_swap(key.clone(), None, 2_u32, limits),
+            Err(MetadataError::ValueMismatch(key.clone()))
+        );
+        assert_eq!(
+            metadata.compare_and_swap(key.clone(), Some(&1_u32.into()), 2_u32, limits)?,
+            Some(1_u32.into())
+        );
+        assert_eq!(metadata.get(&key), Some(&2_u32.into()));
+        Ok(())
+    }
+
     #[test]
     fn insert_exceeds_entry_size() -> Result<(), TestError> {
         let mut metadata = Metadata::new();
@@ -10,7 +10,7 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
-use crate::name::Name;
+use crate::{metadata::Expiry, name::Name};
 
 /// Collection of [`Token`]s
 pub type Permissions = BTreeSet<Permission>;
@@ -79,6 +79,32 @@ mod model {
         #[getset(skip)]
         pub payload: JsonString,
     }
+
+    /// Terms on which a [`Permission`] is granted to an account.
+    #[derive(
+        Debug,
+        Default,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        Getters,
+    )]
+    #[ffi_type(opaque)]
+    #[getset(get_copy = "pub")]
+    pub struct PermissionGrantTerms {
+        /// When the permission is revoked, `None` if it never expires.
+        pub expiry: Option<Expiry>,
+        /// Whether the grantee may grant the permission to other accounts.
+        pub delegatable: bool,
+    }
 }
 
 impl Permission {
@@ -97,7 +123,54 @@ impl Permission {
     }
 }
 
+impl PermissionGrantTerms {
+    /// Revoke the permission once `expiry` is reached.
+    #[must_use]
+    pub const fn with_expiry(mut self, expiry: Expiry) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    /// Allow the grantee to grant the permission to other accounts.
+    #[must_use]
+    pub const fn with_delegation(mut self) -> Self {
+        self.delegatable = true;
+        self
+    }
+
+    /// Whether an account holding a permission on these terms may grant it on `terms`.
+    ///
+    /// The permission has to be delegatable, and the grant can't outlast it.
+    pub fn permits_delegation(&self, terms: &Self) -> bool {
+        if !self.delegatable {
+            return false;
+        }
+
+        match (self.expiry, terms.expiry) {
+            (None, _) => true,
+            (Some(Expiry::BlockHeight(own)), Some(Expiry::BlockHeight(granted)))
+            | (Some(Expiry::TimestampMs(own)), Some(Expiry::TimestampMs(granted))) => {
+                granted <= own
+            }
+            _ => false,
+        }
+    }
+}
+
+impl core::fmt::Display for PermissionGrantTerms {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.expiry {
+            Some(expiry) => write!(f, "UNTIL {expiry}")?,
+            None => write!(f, "FOREVER")?,
+        }
+        if self.delegatable {
+            write!(f, ", DELEGATABLE")?;
+        }
+        Ok(())
+    }
+}
+
 pub mod prelude {
     //! The prelude re-exports most commonly used traits, structs and macros from this crate.
-    pub use super::{Permission, PermissionId};
+    pub use super::{Permission, PermissionGrantTerms, PermissionId};
 }
@@ -13,7 +13,7 @@ use core::{cmp::Ordering, num::NonZeroU32, time::Duration};
 
 pub use cursor::ForwardCursor;
 use derive_more::{Constructor, Display};
-use iroha_crypto::{PublicKey, SignatureOf};
+use iroha_crypto::{HashOf, MerkleProof, PublicKey, SignatureOf};
 use iroha_data_model_derive::{model, EnumRef};
 use iroha_primitives::{numeric::Numeric, small::SmallVec};
 use iroha_schema::IntoSchema;
@@ -22,7 +22,7 @@ use nonzero_ext::nonzero;
 pub use pagination::Pagination;
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
-pub use sorting::Sorting;
+pub use sorting::{SortOrder, Sorting};
 
 pub use self::model::*;
 use self::{
@@ -32,17 +32,19 @@ use self::{
 use crate::{
     account::{Account, AccountId},
     block::{BlockHeader, SignedBlock},
+    escrow::EscrowId,
     events::TriggeringEventFilterBox,
     metadata::MetadataValueBox,
     seal,
     transaction::{CommittedTransaction, SignedTransaction, TransactionPayload},
-    IdBox, Identifiable, IdentifiableBox,
+    HasMetadata, IdBox, Identifiable, IdentifiableBox,
 };
 
 pub mod cursor;
 pub mod pagination;
 pub mod predicate;
 pub mod sorting;
+pub mod stream;
 
 const FETCH_SIZE: &str = "fetch_size";
 
@@ -207,6 +209,23 @@ mod model {
         FindRoleByRoleId(FindRoleByRoleId),
         FindRolesByAccountId(FindRolesByAccountId),
         FindAllParameters(FindAllParameters),
+        FindAccountKeyValueByIdAndPath(FindAccountKeyValueByIdAndPath),
+        FindAssetKeyValueByIdAndPath(FindAssetKeyValueByIdAndPath),
+        FindAccountsByMetadataKeyPrefix(FindAccountsByMetadataKeyPrefix),
+        FindAssetsByMetadataKeyPrefix(FindAssetsByMetadataKeyPrefix),
+        FindMetadataHistory(FindMetadataHistory),
+        FindNftsByAccountId(FindNftsByAccountId),
+        FindAccountByAlias(FindAccountByAlias),
+        FindDomainDescendants(FindDomainDescendants),
+        FindAccountCountByDomainId(FindAccountCountByDomainId),
+        FindAssetCountByAssetDefinitionId(FindAssetCountByAssetDefinitionId),
+        FindBlockByHash(FindBlockByHash),
+        FindBlockByHeight(FindBlockByHeight),
+        FindBlocksInRange(FindBlocksInRange),
+        FindTransactionProofByHash(FindTransactionProofByHash),
+        FindAllActiveTriggers(FindAllActiveTriggers),
+        FindPermissionGrantTerms(FindPermissionGrantTerms),
+        FindGrantHistoryByAccountId(FindGrantHistoryByAccountId),
     }
 
     /// Sized container for all possible [`Query::Output`]s
@@ -241,6 +260,9 @@ mod model {
             #[skip_try_from]
             Vec<QueryOutputBox>,
         ),
+        TransactionInclusionProof(TransactionInclusionProof),
+        PermissionGrantTerms(crate::permission::PermissionGrantTerms),
+        AccountGrantAudit(crate::events::data::prelude::AccountGrantAudit),
     }
 
     /// Output of [`FindAllTransactions`] query
@@ -268,6 +290,30 @@ mod model {
         pub transaction: CommittedTransaction,
     }
 
+    /// Output of [`FindTransactionProofByHash`] query
+    #[derive(
+        Debug,
+        Clone,
+        PartialOrd,
+        Ord,
+        PartialEq,
+        Eq,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct TransactionInclusionProof {
+        /// Header of the block to which the transaction belongs to
+        pub block_header: BlockHeader,
+        /// Path from the transaction hash to the `transactions_hash` of the block
+        pub proof: MerkleProof<SignedTransaction>,
+    }
+
     /// Request type clients (like http clients or wasm) can send to a query endpoint.
     ///
     /// `Q` should be either [`http::SignedQuery`] for client or [`SmartContractQuery`] for wasm smart contract.
@@ -311,6 +357,25 @@ mod model {
     }
 }
 
+impl QueryOutputBox {
+    /// Value of the metadata `key` of the item, if it has metadata.
+    ///
+    /// Values in the store of an [`Asset`](crate::asset::Asset) take precedence over its metadata.
+    pub fn metadata_value(&self, key: &crate::Name) -> Option<&MetadataValueBox> {
+        match self {
+            Self::Identifiable(IdentifiableBox::Asset(asset)) => match asset.value() {
+                crate::asset::AssetValue::Store(store) => store.get(key),
+                crate::asset::AssetValue::Numeric(_) => None,
+            }
+            .or_else(|| asset.metadata.get(key)),
+            Self::Identifiable(v) => TryInto::<&dyn HasMetadata>::try_into(v)
+                .ok()
+                .and_then(|has_metadata| has_metadata.metadata().get(key)),
+            _ => None,
+        }
+    }
+}
+
 impl From<u32> for QueryOutputBox {
     fn from(value: u32) -> Self {
         Self::Numeric(value.into())
@@ -398,11 +463,18 @@ impl_queries! {
     FindRolesByAccountId => Vec<crate::role::RoleId>,
     FindRoleByRoleId => crate::role::Role,
     FindPermissionsByAccountId => Vec<crate::permission::Permission>,
+    FindPermissionGrantTerms => crate::permission::PermissionGrantTerms,
+    FindGrantHistoryByAccountId => Vec<crate::events::data::prelude::AccountGrantAudit>,
     FindAllAccounts => Vec<crate::account::Account>,
     FindAccountById => crate::account::Account,
+    FindAccountByAlias => crate::account::Account,
     FindAccountKeyValueByIdAndKey => MetadataValueBox,
+    FindAccountKeyValueByIdAndPath => MetadataValueBox,
     FindAccountsByDomainId => Vec<crate::account::Account>,
+    FindAccountCountByDomainId => Numeric,
     FindAccountsWithAsset => Vec<crate::account::Account>,
+    FindAccountsByMetadataKeyPrefix => Vec<crate::account::Account>,
+    FindMetadataHistory => MetadataValueBox,
     FindAllAssets => Vec<crate::asset::Asset>,
     FindAllAssetsDefinitions => Vec<crate::asset::AssetDefinition>,
     FindAssetById => crate::asset::Asset,
@@ -412,25 +484,35 @@ impl_queries! {
     FindAssetsByAssetDefinitionId => Vec<crate::asset::Asset>,
     FindAssetsByDomainId => Vec<crate::asset::Asset>,
     FindAssetsByDomainIdAndAssetDefinitionId => Vec<crate::asset::Asset>,
+    FindAssetsByMetadataKeyPrefix => Vec<crate::asset::Asset>,
+    FindNftsByAccountId => Vec<crate::asset::Asset>,
     FindAssetQuantityById => Numeric,
     FindTotalAssetQuantityByAssetDefinitionId => Numeric,
+    FindAssetCountByAssetDefinitionId => Numeric,
     FindAssetKeyValueByIdAndKey => MetadataValueBox,
+    FindAssetKeyValueByIdAndPath => MetadataValueBox,
     FindAssetDefinitionKeyValueByIdAndKey => MetadataValueBox,
     FindAllDomains => Vec<crate::domain::Domain>,
     FindDomainById => crate::domain::Domain,
+    FindDomainDescendants => Vec<crate::domain::Domain>,
     FindDomainKeyValueByIdAndKey => MetadataValueBox,
     FindAllPeers => Vec<crate::peer::Peer>,
     FindAllParameters => Vec<crate::parameter::Parameter>,
     FindAllActiveTriggerIds => Vec<crate::trigger::TriggerId>,
+    FindAllActiveTriggers => Vec<crate::trigger::Trigger>,
     FindTriggerById => crate::trigger::Trigger,
     FindTriggerKeyValueByIdAndKey => MetadataValueBox,
     FindTriggersByDomainId => Vec<crate::trigger::Trigger>,
     FindAllTransactions => Vec<TransactionQueryOutput>,
     FindTransactionsByAccountId => Vec<TransactionQueryOutput>,
     FindTransactionByHash => TransactionQueryOutput,
+    FindTransactionProofByHash => TransactionInclusionProof,
     FindAllBlocks => Vec<SignedBlock>,
     FindAllBlockHeaders => Vec<crate::block::BlockHeader>,
     FindBlockHeaderByHash => crate::block::BlockHeader,
+    FindBlockByHash => SignedBlock,
+    FindBlockByHeight => SignedBlock,
+    FindBlocksInRange => Vec<SignedBlock>,
     FindExecutorDataModel => crate::executor::ExecutorDataModel
 }
 
@@ -449,7 +531,10 @@ impl core::fmt::Display for QueryOutputBox {
             QueryOutputBox::Id(v) => core::fmt::Display::fmt(&v, f),
             QueryOutputBox::Identifiable(v) => core::fmt::Display::fmt(&v, f),
             QueryOutputBox::Transaction(_) => write!(f, "TransactionQueryOutput"),
+            QueryOutputBox::TransactionInclusionProof(_) => write!(f, "TransactionInclusionProof"),
             QueryOutputBox::Permission(v) => core::fmt::Display::fmt(&v, f),
+            QueryOutputBox::PermissionGrantTerms(v) => core::fmt::Display::fmt(&v, f),
+            QueryOutputBox::AccountGrantAudit(_) => write!(f, "AccountGrantAudit"),
             QueryOutputBox::Block(v) => core::fmt::Display::fmt(&v, f),
             QueryOutputBox::BlockHeader(v) => core::fmt::Display::fmt(&v, f),
             QueryOutputBox::Numeric(v) => core::fmt::Display::fmt(&v, f),
@@ -572,6 +657,18 @@ impl AsRef<CommittedTransaction> for TransactionQueryOutput {
     }
 }
 
+impl TransactionInclusionProof {
+    /// Check that the transaction with the given hash is included in the block with [`Self::block_header`].
+    ///
+    /// The block header itself has to be checked separately, e.g. against the signatures of the block.
+    pub fn verify(&self, hash: HashOf<SignedTransaction>) -> bool {
+        self.block_header
+            .transactions_hash()
+            .as_ref()
+            .map_or(false, |root| self.proof.verify(hash, *root))
+    }
+}
+
 impl<Q: core::fmt::Display> core::fmt::Display for QueryRequest<Q> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -600,6 +697,7 @@ pub mod role {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use getset::Getters;
     use parity_scale_codec::Encode;
 
     use super::{Query, QueryType};
@@ -632,8 +730,9 @@ pub mod role {
         }
 
         /// [`FindRolesByAccountId`] Iroha Query finds all [`Role`]s for a specified account.
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find all roles for `{id}` account")]
+        #[getset(get = "pub")]
         #[repr(transparent)]
         // SAFETY: `FindRolesByAccountId` has no trap representation in `EvaluatesTo<AccountId>`
         #[ffi_type(unsafe {robust})]
@@ -656,6 +755,7 @@ pub mod permission {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use getset::Getters;
     use parity_scale_codec::Encode;
 
     use super::{Query, QueryType};
@@ -664,8 +764,9 @@ pub mod permission {
     queries! {
         /// [`FindPermissionsByAccountId`] Iroha Query finds all [`Permission`]s
         /// for a specified account.
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find permission tokens specified for `{id}` account")]
+        #[getset(get = "pub")]
         #[repr(transparent)]
         // SAFETY: `FindPermissionsByAccountId` has no trap representation in `EvaluatesTo<AccountId>`
         #[ffi_type(unsafe {robust})]
@@ -673,11 +774,39 @@ pub mod permission {
             /// `Id` of an account to find.
             pub id: AccountId,
         }
+
+        /// [`FindPermissionGrantTerms`] Iroha Query finds the terms under which
+        /// a [`Permission`] was granted to a specified account.
+        #[derive(Display, Getters)]
+        #[display(fmt = "Find grant terms of `{permission}` permission of `{account_id}` account")]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct FindPermissionGrantTerms {
+            /// `Id` of an account the permission is granted to.
+            pub account_id: AccountId,
+            /// Granted permission.
+            pub permission: Permission,
+        }
+
+        /// [`FindGrantHistoryByAccountId`] Iroha Query finds all permissions and roles
+        /// ever granted to or revoked from a specified account.
+        #[derive(Display, Getters)]
+        #[display(fmt = "Find grant history of `{account_id}` account")]
+        #[getset(get = "pub")]
+        #[repr(transparent)]
+        // SAFETY: `FindGrantHistoryByAccountId` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindGrantHistoryByAccountId {
+            /// `Id` of an account to find.
+            pub account_id: AccountId,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this module.
     pub mod prelude {
-        pub use super::FindPermissionsByAccountId;
+        pub use super::{
+            FindGrantHistoryByAccountId, FindPermissionGrantTerms, FindPermissionsByAccountId,
+        };
     }
 }
 
@@ -688,6 +817,7 @@ pub mod account {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use getset::Getters;
     use parity_scale_codec::Encode;
 
     use super::{MetadataValueBox, Query, QueryType};
@@ -702,8 +832,9 @@ pub mod account {
         pub struct FindAllAccounts;
 
         /// [`FindAccountById`] Iroha Query finds an [`Account`] by it's identification.
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find `{id}` account")]
+        #[getset(get = "pub")]
         #[repr(transparent)]
         // SAFETY: `FindAccountById` has no trap representation in `EvaluatesTo<AccountId>`
         #[ffi_type(unsafe {robust})]
@@ -712,10 +843,22 @@ pub mod account {
             pub id: AccountId,
         }
 
+        /// [`FindAccountByAlias`] Iroha Query finds an [`Account`] by an alias registered in its domain.
+        #[derive(Display)]
+        #[display(fmt = "Find account aliased `{alias}`")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountByAlias` has no trap representation in `AccountAlias`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountByAlias {
+            /// Alias of an account to find.
+            pub alias: AccountAlias,
+        }
+
         /// [`FindAccountKeyValueByIdAndKey`] Iroha Query finds an [`MetadataValue`]
         /// of the key-value metadata pair in the specified account.
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find metadata value with `{key}` key in `{id}` account")]
+        #[getset(get = "pub")]
         #[ffi_type]
         pub struct FindAccountKeyValueByIdAndKey {
             /// `Id` of an account to find.
@@ -724,6 +867,20 @@ pub mod account {
             pub key: Name,
         }
 
+        /// [`FindAccountKeyValueByIdAndPath`] Iroha Query finds an [`MetadataValue`]
+        /// nested at the given path in the specified account's metadata.
+        /// An empty path addresses the whole metadata.
+        #[derive(Display, Getters)]
+        #[display(fmt = "Find metadata value at `{path:?}` path in `{id}` account")]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct FindAccountKeyValueByIdAndPath {
+            /// `Id` of an account to find.
+            pub id: AccountId,
+            /// Path to the specific value in the Account's metadata.
+            pub path: Vec<Name>,
+        }
+
         /// [`FindAccountsByDomainId`] Iroha Query gets [`Domain`]s id as input and
         /// finds all [`Account`]s under this [`Domain`].
         #[derive(Display)]
@@ -736,6 +893,18 @@ pub mod account {
             pub domain_id: DomainId,
         }
 
+        /// [`FindAccountCountByDomainId`] Iroha Query gets [`Domain`]s id as input and
+        /// finds the number of [`Account`]s under this [`Domain`].
+        #[derive(Display)]
+        #[display(fmt = "Find number of accounts under `{domain_id}` domain")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountCountByDomainId` has no trap representation in `DomainId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountCountByDomainId {
+            /// `Id` of the domain under which accounts should be counted.
+            pub domain_id: DomainId,
+        }
+
         /// [`FindAccountsWithAsset`] Iroha Query gets [`AssetDefinition`]s id as input and
         /// finds all [`Account`]s storing [`Asset`] with such definition.
         #[derive(Display)]
@@ -747,13 +916,44 @@ pub mod account {
             /// `Id` of the definition of the asset which should be stored in founded accounts.
             pub asset_definition_id: AssetDefinitionId,
         }
+
+        /// [`FindAccountsByMetadataKeyPrefix`] Iroha Query finds all [`Account`]s
+        /// whose metadata has a top-level key starting with the given prefix.
+        #[derive(Display)]
+        #[display(fmt = "Find accounts with metadata keys starting with `{prefix}`")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountsByMetadataKeyPrefix` has no trap representation in `String`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountsByMetadataKeyPrefix {
+            /// Prefix of the metadata keys to look for. An empty prefix matches any non-empty metadata.
+            pub prefix: String,
+        }
+
+        /// [`FindMetadataHistory`] Iroha Query finds the [`MetadataValue`]
+        /// of the key in the specified object's metadata as of the given block.
+        /// Only answered by peers which index metadata history.
+        #[derive(Display)]
+        #[display(fmt = "Find metadata value with `{key}` key in `{object_id}` at height {height}")]
+        #[ffi_type]
+        pub struct FindMetadataHistory {
+            /// `Id` of a domain, account, asset definition, asset or trigger to find.
+            pub object_id: IdBox,
+            /// Top-level key of the specific key-value in the object's metadata.
+            pub key: Name,
+            /// Path to the value nested under `key`, empty for the value of `key` itself.
+            pub path: Vec<Name>,
+            /// Height of the block after which the value is looked up.
+            pub height: u64,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
         pub use super::{
-            FindAccountById, FindAccountKeyValueByIdAndKey, FindAccountsByDomainId,
-            FindAccountsWithAsset, FindAllAccounts,
+            FindAccountByAlias, FindAccountById, FindAccountCountByDomainId,
+            FindAccountKeyValueByIdAndKey, FindAccountKeyValueByIdAndPath, FindAccountsByDomainId,
+            FindAccountsByMetadataKeyPrefix, FindAccountsWithAsset, FindAllAccounts,
+            FindMetadataHistory,
         };
     }
 }
@@ -767,6 +967,7 @@ pub mod asset {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use getset::Getters;
     use iroha_primitives::numeric::Numeric;
     use parity_scale_codec::Encode;
 
@@ -788,8 +989,9 @@ pub mod asset {
         pub struct FindAllAssetsDefinitions; // TODO: Should it be renamed to [`FindAllAssetDefinitions`?
 
         /// [`FindAssetById`] Iroha Query finds an [`Asset`] by it's identification in Iroha [`Peer`].
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find `{id}` asset")]
+        #[getset(get = "pub")]
         #[repr(transparent)]
         // SAFETY: `FindAssetById` has no trap representation in `EvaluatesTo<AssetId>`
         #[ffi_type(unsafe {robust})]
@@ -823,8 +1025,9 @@ pub mod asset {
 
         /// [`FindAssetsByAccountId`] Iroha Query gets [`AccountId`] as input and find all [`Asset`]s
         /// owned by the [`Account`] in Iroha Peer.
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find assets owned by the `{account_id}` account")]
+        #[getset(get = "pub")]
         #[repr(transparent)]
         // SAFETY: `FindAssetsByAccountId` has no trap representation in `EvaluatesTo<AccountId>`
         #[ffi_type(unsafe {robust})]
@@ -847,8 +1050,9 @@ pub mod asset {
 
         /// [`FindAssetsByDomainId`] Iroha Query gets [`Domain`]s id as input and
         /// finds all [`Asset`]s under this [`Domain`] in Iroha [`Peer`].
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find assets under the `{domain_id}` domain")]
+        #[getset(get = "pub")]
         #[repr(transparent)]
         // SAFETY: `FindAssetsByDomainId` has no trap representation in `EvaluatesTo<DomainId>`
         #[ffi_type(unsafe {robust})]
@@ -860,8 +1064,9 @@ pub mod asset {
         /// [`FindAssetsByDomainIdAndAssetDefinitionId`] Iroha Query gets [`DomainId`] and
         /// [`AssetDefinitionId`] as inputs and finds [`Asset`]s under the [`Domain`]
         /// with this [`AssetDefinition`] in Iroha [`Peer`].
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find assets under the `{domain_id}` domain with `{asset_definition_id}` asset definition")]
+        #[getset(get = "pub")]
         #[ffi_type]
         pub struct FindAssetsByDomainIdAndAssetDefinitionId {
             /// `Id` of the domain under which assets should be found.
@@ -870,10 +1075,37 @@ pub mod asset {
             pub asset_definition_id: AssetDefinitionId,
         }
 
+        /// [`FindAssetsByMetadataKeyPrefix`] Iroha Query finds all [`Store`](crate::asset::AssetValue::Store)
+        /// [`Asset`]s with a top-level key starting with the given prefix.
+        #[derive(Display)]
+        #[display(fmt = "Find store assets with keys starting with `{prefix}`")]
+        #[repr(transparent)]
+        // SAFETY: `FindAssetsByMetadataKeyPrefix` has no trap representation in `String`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAssetsByMetadataKeyPrefix {
+            /// Prefix of the keys to look for. An empty prefix matches any non-empty store.
+            pub prefix: String,
+        }
+
+        /// [`FindNftsByAccountId`] Iroha Query gets [`AccountId`] as input and finds all [`Asset`]s
+        /// of [`Nft`](crate::asset::AssetValueType::Nft) type owned by the [`Account`].
+        /// The keys of each asset's store are the tokens held by the account.
+        #[derive(Display, Getters)]
+        #[display(fmt = "Find non-fungible tokens owned by the `{account_id}` account")]
+        #[getset(get = "pub")]
+        #[repr(transparent)]
+        // SAFETY: `FindNftsByAccountId` has no trap representation in `EvaluatesTo<AccountId>`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindNftsByAccountId {
+            /// [`AccountId`] under which tokens should be found.
+            pub account_id: AccountId,
+        }
+
         /// [`FindAssetQuantityById`] Iroha Query gets [`AssetId`] as input and finds [`Asset::quantity`]
         /// parameter's value if [`Asset`] is presented in Iroha Peer.
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find quantity of the `{id}` asset")]
+        #[getset(get = "pub")]
         #[repr(transparent)]
         // SAFETY: `FindAssetQuantityById` has no trap representation in `EvaluatesTo<AssetId>`
         #[ffi_type(unsafe {robust})]
@@ -895,10 +1127,23 @@ pub mod asset {
             pub id: AssetDefinitionId,
         }
 
+        /// [`FindAssetCountByAssetDefinitionId`] Iroha Query gets [`AssetDefinitionId`] as input and
+        /// finds the number of [`Asset`]s with this definition, i.e. the number of their holders.
+        #[derive(Display)]
+        #[display(fmt = "Find number of `{id}` assets")]
+        #[repr(transparent)]
+        // SAFETY: `FindAssetCountByAssetDefinitionId` has no trap representation in `AssetDefinitionId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAssetCountByAssetDefinitionId {
+            /// `Id` of the definition of the assets to count.
+            pub id: AssetDefinitionId,
+        }
+
         /// [`FindAssetKeyValueByIdAndKey`] Iroha Query gets [`AssetId`] and key as input and finds [`MetadataValue`]
         /// of the key-value pair stored in this asset.
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find metadata value with `{key}` key in `{id}` asset")]
+        #[getset(get = "pub")]
         #[ffi_type]
         pub struct FindAssetKeyValueByIdAndKey {
             /// `Id` of an [`Asset`] acting as [`Store`](crate::asset::AssetValue::Store).
@@ -907,6 +1152,19 @@ pub mod asset {
             pub key: Name,
         }
 
+        /// [`FindAssetKeyValueByIdAndPath`] Iroha Query gets [`AssetId`] and path as input and finds [`MetadataValue`]
+        /// nested at this path in the asset. An empty path addresses the whole store.
+        #[derive(Display, Getters)]
+        #[display(fmt = "Find metadata value at `{path:?}` path in `{id}` asset")]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct FindAssetKeyValueByIdAndPath {
+            /// `Id` of an [`Asset`] acting as [`Store`](crate::asset::AssetValue::Store).
+            pub id: AssetId,
+            /// Path to the value stored in the asset.
+            pub path: Vec<Name>,
+        }
+
         /// [`FindAssetDefinitionKeyValueByIdAndKey`] Iroha Query gets [`AssetDefinitionId`] and key as input and finds [`MetadataValue`]
         /// of the key-value pair stored in this asset definition.
         #[derive(Display)]
@@ -923,11 +1181,13 @@ pub mod asset {
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
         pub use super::{
-            FindAllAssets, FindAllAssetsDefinitions, FindAssetById, FindAssetDefinitionById,
+            FindAllAssets, FindAllAssetsDefinitions, FindAssetById,
+            FindAssetCountByAssetDefinitionId, FindAssetDefinitionById,
             FindAssetDefinitionKeyValueByIdAndKey, FindAssetKeyValueByIdAndKey,
-            FindAssetQuantityById, FindAssetsByAccountId, FindAssetsByAssetDefinitionId,
-            FindAssetsByDomainId, FindAssetsByDomainIdAndAssetDefinitionId, FindAssetsByName,
-            FindTotalAssetQuantityByAssetDefinitionId,
+            FindAssetKeyValueByIdAndPath, FindAssetQuantityById, FindAssetsByAccountId,
+            FindAssetsByAssetDefinitionId, FindAssetsByDomainId,
+            FindAssetsByDomainIdAndAssetDefinitionId, FindAssetsByMetadataKeyPrefix,
+            FindAssetsByName, FindNftsByAccountId, FindTotalAssetQuantityByAssetDefinitionId,
         };
     }
 }
@@ -964,6 +1224,17 @@ pub mod domain {
             pub id: DomainId,
         }
 
+        /// [`FindDomainDescendants`] Iroha Query finds all sub-domains of a [`Domain`], direct or not.
+        #[derive(Display)]
+        #[display(fmt = "Find sub-domains of `{id}` domain")]
+        #[repr(transparent)]
+        // SAFETY: `FindDomainDescendants` has no trap representation in `DomainId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindDomainDescendants {
+            /// `Id` of the domain whose sub-domains to find.
+            pub id: DomainId,
+        }
+
         /// [`FindDomainKeyValueByIdAndKey`] Iroha Query finds a [`MetadataValue`] of the key-value metadata pair
         /// in the specified domain.
         #[derive(Display)]
@@ -979,7 +1250,9 @@ pub mod domain {
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAllDomains, FindDomainById, FindDomainKeyValueByIdAndKey};
+        pub use super::{
+            FindAllDomains, FindDomainById, FindDomainDescendants, FindDomainKeyValueByIdAndKey,
+        };
     }
 }
 
@@ -1054,13 +1327,20 @@ pub mod trigger {
     };
 
     queries! {
-        /// Find all currently active (as in not disabled and/or expired)
+        /// Find all currently active (as in not paused and/or expired)
         /// trigger IDs.
         #[derive(Copy, Display)]
         #[display(fmt = "Find all trigger ids")]
         #[ffi_type]
         pub struct FindAllActiveTriggerIds;
 
+        /// Find all currently active (as in not paused and/or expired)
+        /// triggers.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all active triggers")]
+        #[ffi_type]
+        pub struct FindAllActiveTriggers;
+
         /// Find Trigger given its ID.
         #[derive(Display)]
         #[display(fmt = "Find `{id}` trigger")]
@@ -1100,8 +1380,8 @@ pub mod trigger {
     pub mod prelude {
         //! Prelude Re-exports most commonly used traits, structs and macros from this crate.
         pub use super::{
-            FindAllActiveTriggerIds, FindTriggerById, FindTriggerKeyValueByIdAndKey,
-            FindTriggersByDomainId,
+            FindAllActiveTriggerIds, FindAllActiveTriggers, FindTriggerById,
+            FindTriggerKeyValueByIdAndKey, FindTriggersByDomainId,
         };
     }
 }
@@ -1115,10 +1395,11 @@ pub mod transaction {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use getset::Getters;
     use iroha_crypto::HashOf;
     use parity_scale_codec::Encode;
 
-    use super::{Query, QueryType, TransactionQueryOutput};
+    use super::{Query, QueryType, TransactionInclusionProof, TransactionQueryOutput};
     use crate::{account::AccountId, prelude::Account, transaction::SignedTransaction};
 
     queries! {
@@ -1130,8 +1411,9 @@ pub mod transaction {
 
         /// [`FindTransactionsByAccountId`] Iroha Query finds all transactions included in a blockchain
         /// for the account
-        #[derive(Display)]
+        #[derive(Display, Getters)]
         #[display(fmt = "Find all transactions for `{account_id}` account")]
+        #[getset(get = "pub")]
         #[repr(transparent)]
         // SAFETY: `FindTransactionsByAccountId` has no trap representation in `EvaluatesTo<AccountId>`
         #[ffi_type(unsafe {robust})]
@@ -1151,11 +1433,26 @@ pub mod transaction {
             /// Transaction hash.
             pub hash: HashOf<SignedTransaction>,
         }
+
+        /// [`FindTransactionProofByHash`] Iroha Query finds the proof of inclusion
+        /// of the transaction with corresponding hash value into its block
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find inclusion proof of transaction with `{hash}` hash")]
+        #[repr(transparent)]
+        // SAFETY: `FindTransactionProofByHash` has no trap representation in `HashOf<SignedTransaction>`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindTransactionProofByHash {
+            /// Transaction hash.
+            pub hash: HashOf<SignedTransaction>,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAllTransactions, FindTransactionByHash, FindTransactionsByAccountId};
+        pub use super::{
+            FindAllTransactions, FindTransactionByHash, FindTransactionProofByHash,
+            FindTransactionsByAccountId,
+        };
     }
 }
 
@@ -1167,6 +1464,8 @@ pub mod block {
     #[cfg(not(feature = "std"))]
     use alloc::{format, string::String, vec::Vec};
 
+    use core::num::NonZeroU64;
+
     use derive_more::Display;
     use iroha_crypto::HashOf;
     use parity_scale_codec::{Decode, Encode};
@@ -1198,11 +1497,48 @@ pub mod block {
             /// Block hash.
             pub hash: HashOf<SignedBlock>,
         }
+
+        /// [`FindBlockByHash`] Iroha Query finds block by its hash
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find block with `{hash}` hash")]
+        #[repr(transparent)]
+        // SAFETY: `FindBlockByHash` has no trap representation in `HashOf<SignedBlock>`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindBlockByHash {
+            /// Block hash.
+            pub hash: HashOf<SignedBlock>,
+        }
+
+        /// [`FindBlockByHeight`] Iroha Query finds block by its height
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find block at height {height}")]
+        #[repr(transparent)]
+        // SAFETY: `FindBlockByHeight` has no trap representation in `NonZeroU64`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindBlockByHeight {
+            /// Block height.
+            pub height: NonZeroU64,
+        }
+
+        /// [`FindBlocksInRange`] Iroha Query lists blocks from `start` to `end` height inclusive
+        /// sorted by height in ascending order. Heights above the latest block are ignored.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find blocks from height {start} to {end}")]
+        #[ffi_type]
+        pub struct FindBlocksInRange {
+            /// Height of the first block.
+            pub start: NonZeroU64,
+            /// Height of the last block.
+            pub end: NonZeroU64,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAllBlockHeaders, FindAllBlocks, FindBlockHeaderByHash};
+        pub use super::{
+            FindAllBlockHeaders, FindAllBlocks, FindBlockByHash, FindBlockByHeight,
+            FindBlockHeaderByHash, FindBlocksInRange,
+        };
     }
 }
 
@@ -1216,7 +1552,7 @@ pub mod http {
 
     pub use self::model::*;
     use super::*;
-    use crate::account::AccountId;
+    use crate::{account::AccountId, BatchedResponse};
 
     declare_versioned!(SignedQuery 1..2, Debug, Clone, iroha_macro::FromVariant, IntoSchema);
 
@@ -1267,6 +1603,75 @@ pub mod http {
         /// End type of a query http clients can send to an endpoint.
         #[derive(Debug, Clone, Decode, Encode)]
         pub struct ClientQueryRequest(pub QueryRequest<SignedQuery>);
+
+        /// Statement of a peer about the query response it produced.
+        #[derive(
+            Debug, Clone, PartialEq, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        pub struct QueryResponseAttestation {
+            /// Hash of the response.
+            pub response_hash: HashOf<BatchedResponse<QueryOutputBox>>,
+            /// Height of the latest block at the moment the query was executed.
+            pub block_height: u64,
+            /// Time the response was produced at, in milliseconds since the Unix epoch.
+            pub timestamp_ms: u64,
+        }
+
+        /// Query response signed by the peer which produced it.
+        #[derive(Debug, Clone, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        #[getset(get = "pub")]
+        pub struct SignedQueryResponse {
+            /// Response to the query.
+            pub response: BatchedResponse<QueryOutputBox>,
+            /// Statement of the peer about the response.
+            pub attestation: QueryResponseAttestation,
+            /// Signature of the peer over the attestation.
+            pub signature: SignatureOf<QueryResponseAttestation>,
+        }
+    }
+
+    impl SignedQueryResponse {
+        /// Construct [`Self`] by signing the response at the given block height and time with the peer key.
+        pub fn new(
+            response: BatchedResponse<QueryOutputBox>,
+            block_height: u64,
+            timestamp: Duration,
+            key_pair: &iroha_crypto::KeyPair,
+        ) -> Self {
+            let attestation = QueryResponseAttestation {
+                response_hash: HashOf::new(&response),
+                block_height,
+                timestamp_ms: timestamp
+                    .as_millis()
+                    .try_into()
+                    .expect("Time should fit into u64"),
+            };
+            let signature = SignatureOf::new(key_pair, &attestation);
+
+            Self {
+                response,
+                attestation,
+                signature,
+            }
+        }
+
+        /// Check that the attestation is signed and that it matches the response.
+        ///
+        /// # Errors
+        /// Fails if the signature is invalid or the response has a different hash
+        pub fn verify(&self) -> Result<(), iroha_crypto::Error> {
+            self.signature.verify(&self.attestation)?;
+            if HashOf::new(&self.response) != self.attestation.response_hash {
+                return Err(iroha_crypto::Error::BadSignature);
+            }
+            Ok(())
+        }
+
+        /// Time the response was produced at
+        pub fn timestamp(&self) -> Duration {
+            Duration::from_millis(self.attestation.timestamp_ms)
+        }
     }
 
     impl ClientQueryRequest {
@@ -1488,6 +1893,14 @@ pub mod error {
             FetchSizeTooBig,
             /// Some of the specified parameters (filter/pagination/fetch_size/sorting) are not applicable to singular queries
             InvalidSingularParameters,
+            /// Metadata history is not indexed by this peer or doesn't cover the requested height
+            MetadataHistoryNotIndexed,
+            /// Query budget of the account is exhausted, retry in {0} ms
+            RateLimited(
+                #[skip_from]
+                #[skip_try_from]
+                u64,
+            ),
         }
 
         /// Type assertion error
@@ -1535,6 +1948,12 @@ pub mod error {
             Parameter(ParameterId),
             /// Failed to find public key: `{0}`
             PublicKey(PublicKey),
+            /// Escrow with id `{0}` not found
+            Escrow(EscrowId),
+            /// Failed to find account alias: `{0}`
+            AccountAlias(AccountAlias),
+            /// Block at height `{0}` not found
+            BlockHeight(core::num::NonZeroU64),
         }
     }
 }
@@ -1548,6 +1967,6 @@ pub mod prelude {
         account::prelude::*, asset::prelude::*, block::prelude::*, domain::prelude::*,
         executor::prelude::*, peer::prelude::*, permission::prelude::*, predicate::PredicateTrait,
         role::prelude::*, transaction::prelude::*, trigger::prelude::*, FetchSize, QueryBox,
-        QueryId, TransactionQueryOutput,
+        QueryId, TransactionInclusionProof, TransactionQueryOutput,
     };
 }
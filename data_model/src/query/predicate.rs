@@ -603,6 +603,7 @@ pub mod string {
                 IdBox::RoleId(id) => self.applies(&id.to_string()),
                 IdBox::PermissionId(id) => self.applies(&id.to_string()),
                 IdBox::ParameterId(id) => self.applies(&id.to_string()),
+                IdBox::WasmCodeId(id) => self.applies(&id.to_string()),
             }
         }
     }
@@ -1109,7 +1110,7 @@ pub mod numerical {
 pub mod value {
     //!  raw predicates applied to `QueryOutputBox`.
     use super::*;
-    use crate::query::QueryOutputBox;
+    use crate::{metadata::MetadataValueBox, query::QueryOutputBox};
 
     /// A predicate designed for general processing of `QueryOutputBox`.
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
@@ -1126,6 +1127,8 @@ pub mod value {
         TimeStamp(numerical::SemiInterval<u128>),
         /// Always return true.
         Pass,
+        /// Apply predicate to the value of the metadata key.
+        ValueOfKey(ValueOfKey),
     }
 
     impl PredicateTrait<&QueryOutputBox> for QueryOutputPredicate {
@@ -1167,6 +1170,15 @@ pub mod value {
                     _ => false,
                 },
                 QueryOutputPredicate::Pass => true,
+                QueryOutputPredicate::ValueOfKey(ValueOfKey { key, predicate }) => input
+                    .metadata_value(key)
+                    .map_or(false, |value| match value {
+                        // so that numerical predicates apply to numeric metadata values
+                        MetadataValueBox::Numeric(numeric) => {
+                            predicate.applies(&QueryOutputBox::Numeric(*numeric))
+                        }
+                        _ => predicate.applies(&QueryOutputBox::LimitedMetadata(value.clone())),
+                    }),
             }
         }
     }
@@ -1186,6 +1198,16 @@ pub mod value {
             Self::Container(Container::All(Box::new(pred.into())))
         }
 
+        /// Construct [`Predicate::ValueOfKey`] variant.
+        #[inline]
+        #[must_use]
+        pub fn value_of_key(key: Name, pred: impl Into<QueryOutputPredicate>) -> Self {
+            Self::ValueOfKey(ValueOfKey {
+                key,
+                predicate: Box::new(pred.into()),
+            })
+        }
+
         /// Construct [`Predicate::Container`] variant.
         #[inline]
         #[must_use]
@@ -1204,6 +1226,13 @@ pub mod value {
         predicate: Box<QueryOutputPredicate>,
     }
 
+    /// A predicate that targets the value of the metadata `key` of an item.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct ValueOfKey {
+        key: Name,
+        predicate: Box<QueryOutputPredicate>,
+    }
+
     /// Predicate that targets specific elements or groups; useful for
     /// working with containers. Currently only [`Vec<Value>`] is supported.
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
@@ -1329,5 +1358,42 @@ pub mod value {
             println!("{idx_pred:?}");
             assert!(!idx_pred.applies(&list));
         }
+
+        #[test]
+        fn value_of_key() {
+            let key: Name = "rank".parse().expect("Valid");
+            let mut metadata = Metadata::new();
+            metadata
+                .insert_with_limits(
+                    key.clone(),
+                    numeric!(7).into(),
+                    crate::metadata::Limits::new(10, 100),
+                )
+                .expect("Valid");
+            let wonderland = QueryOutputBox::Identifiable(
+                Domain::new("wonderland".parse().expect("Valid"))
+                    .with_metadata(metadata)
+                    .into(),
+            );
+
+            let in_range = QueryOutputPredicate::value_of_key(
+                key.clone(),
+                QueryOutputPredicate::Numerical(numerical::SemiRange::Numeric(
+                    (numeric!(5), numeric!(10)).into(),
+                )),
+            );
+            assert!(in_range.applies(&wonderland));
+            let equal = QueryOutputPredicate::value_of_key(
+                key,
+                QueryOutputPredicate::Display(string::StringPredicate::is("7")),
+            );
+            assert!(equal.applies(&wonderland));
+
+            let missing = QueryOutputPredicate::value_of_key(
+                "missing".parse().expect("Valid"),
+                QueryOutputPredicate::Pass,
+            );
+            assert!(!missing.applies(&wonderland));
+        }
     }
 }
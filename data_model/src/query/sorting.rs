@@ -29,6 +29,33 @@ mod model {
     pub struct Sorting {
         /// Sort query result using [`Name`] of the key in [`Asset`]'s metadata.
         pub sort_by_metadata_key: Option<Name>,
+        /// Sort query result by id. Combined with [`Self::sort_by_metadata_key`],
+        /// orders the results with equal metadata values.
+        pub sort_by_id: bool,
+        /// Direction of sorting
+        pub order: SortOrder,
+    }
+
+    /// Direction of sorting
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum SortOrder {
+        /// From the smallest to the largest
+        #[default]
+        Ascending,
+        /// From the largest to the smallest
+        Descending,
     }
 }
 
@@ -37,6 +64,44 @@ impl Sorting {
     pub fn by_metadata_key(key: Name) -> Self {
         Self {
             sort_by_metadata_key: Some(key),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a sorting by id.
+    pub fn by_id() -> Self {
+        Self {
+            sort_by_id: true,
+            ..Self::default()
+        }
+    }
+
+    /// Order the results with equal metadata values by id.
+    #[must_use]
+    pub fn then_by_id(mut self) -> Self {
+        self.sort_by_id = true;
+        self
+    }
+
+    /// Sort from the largest to the smallest.
+    #[must_use]
+    pub fn descending(mut self) -> Self {
+        self.order = SortOrder::Descending;
+        self
+    }
+
+    /// Whether any sorting is requested
+    pub fn is_requested(&self) -> bool {
+        self.sort_by_metadata_key.is_some() || self.sort_by_id
+    }
+}
+
+impl SortOrder {
+    /// Apply the direction to the ascending `ordering`
+    pub fn apply(self, ordering: core::cmp::Ordering) -> core::cmp::Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
         }
     }
 }
@@ -0,0 +1,34 @@
+//! Query results for streaming API.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use iroha_data_model_derive::model;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use super::QueryOutputBox;
+use crate::{BatchedResponse, ValidationFail};
+
+#[model]
+mod model {
+    use super::*;
+
+    /// Message sent by the stream producer containing the next batch of query output.
+    ///
+    /// The stream is closed by the producer after the last batch or after the error.
+    #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub enum QueryStreamMessage {
+        /// Batch of query output
+        Batch(BatchedResponse<QueryOutputBox>),
+        /// Query failed
+        Error(ValidationFail),
+    }
+}
+
+/// Exports common structs and enums from this module.
+pub mod prelude {
+    pub use super::QueryStreamMessage;
+}
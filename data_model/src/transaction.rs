@@ -1,28 +1,36 @@
 //! [`Transaction`] structures and related implementations.
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, format, string::String, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, format, string::String, vec::Vec};
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
     iter::IntoIterator,
     num::{NonZeroU32, NonZeroU64},
+    str::FromStr,
     time::Duration,
 };
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
 
 use derive_more::{DebugCustom, Display};
-use iroha_crypto::SignatureOf;
-use iroha_data_model_derive::model;
+use iroha_crypto::{HashOf, SignatureOf};
+use iroha_data_model_derive::{model, IdEqOrdHash};
 use iroha_macro::FromVariant;
+use iroha_primitives::numeric::Numeric;
 use iroha_schema::IntoSchema;
-use iroha_version::{declare_versioned, version};
+use iroha_version::{declare_versioned, scale::EncodeVersioned, version};
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
 use crate::{
     account::AccountId,
-    isi::{Instruction, InstructionBox},
-    metadata::UnlimitedMetadata,
-    ChainId,
+    asset::AssetDefinitionId,
+    events::EventBox,
+    isi::{Instruction, InstructionBox, InstructionType},
+    metadata::{Expiry, UnlimitedMetadata},
+    permission::Permission,
+    prelude::PublicKey,
+    ChainId, Identifiable, ParseError, Registered, ValidationFail,
 };
 
 #[model]
@@ -53,6 +61,9 @@ mod model {
         Instructions(Vec<InstructionBox>),
         /// WebAssembly smartcontract
         Wasm(WasmSmartContract),
+        /// Hash of a WebAssembly smartcontract registered as [`WasmCode`].
+        /// Only triggers can refer to code this way.
+        WasmCode(HashOf<WasmSmartContract>),
     }
 
     /// Wrapper for byte representation of [`Executable::Wasm`].
@@ -82,6 +93,19 @@ mod model {
         pub(super) Vec<u8>,
     );
 
+    /// [`WasmSmartContract`] stored on chain under the hash of its binary.
+    #[derive(
+        Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    #[display(fmt = "{id}")]
+    #[ffi_type]
+    pub struct WasmCode {
+        /// Hash of the binary.
+        pub id: HashOf<WasmSmartContract>,
+        /// Raw wasm blob.
+        pub code: WasmSmartContract,
+    }
+
     /// Iroha [`Transaction`] payload.
     #[derive(
         Debug,
@@ -110,6 +134,14 @@ mod model {
         pub time_to_live_ms: Option<NonZeroU64>,
         /// Random value to make different hashes for transactions which occur repeatedly and simultaneously.
         pub nonce: Option<NonZeroU32>,
+        /// Position of the transaction among the transactions of the authority.
+        /// Checked only if the authority account is in strict ordering mode.
+        pub sequence: Option<NonZeroU64>,
+        /// Amount of the fee asset paid on top of the fee to get the transaction into a block sooner.
+        pub tip: Option<Numeric>,
+        /// Earliest block the transaction may be put into. Until then it waits in the queue,
+        /// so its time-to-live has to cover the wait.
+        pub not_before: Option<Expiry>,
         /// Store for additional information.
         pub metadata: UnlimitedMetadata,
     }
@@ -141,22 +173,64 @@ mod model {
         pub max_wasm_size_bytes: u64,
     }
 
+    /// Fees charged from the authority of every transaction put into a block.
+    ///
+    /// A transaction pays the fee of each of its instructions, the fee of each
+    /// byte of its encoded payload and its tip. `WebAssembly` smart contracts pay for
+    /// their size and tip only.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct TransactionFees {
+        /// Definition of the asset fees are paid in.
+        pub asset_definition_id: AssetDefinitionId,
+        /// Account fees are paid to.
+        pub recipient: AccountId,
+        /// Fee of an instruction without a fee of its own in `instruction_fees`.
+        pub instruction_fee: Numeric,
+        /// Fee of a byte of the encoded transaction payload. Signatures are not charged,
+        /// so that adding them doesn't change the fee.
+        pub byte_fee: Numeric,
+        /// Fees of particular kinds of instructions.
+        pub instruction_fees: BTreeMap<InstructionType, Numeric>,
+    }
+
     /// Transaction that contains a signature
     ///
     /// `Iroha` and its clients use [`Self`] to send transactions over the network.
     /// After a transaction is signed and before it can be processed any further,
     /// the transaction must be accepted by the `Iroha` peer.
     /// The peer verifies the signature and checks the limits.
-    #[version(version = 1, versioned_alias = "SignedTransaction")]
+    // NOTE: Version 2 added the cosignatures, and the sequence, tip and not-before
+    // to the payload. Version 1 transactions are rejected as unsupported.
+    #[version(version = 2, versioned_alias = "SignedTransaction")]
     #[derive(
         Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Serialize, IntoSchema,
     )]
     #[cfg_attr(not(feature = "std"), display(fmt = "Signed transaction"))]
     #[cfg_attr(feature = "std", display(fmt = "{}", "self.hash()"))]
     #[ffi_type]
-    pub struct SignedTransactionV1 {
+    pub struct SignedTransactionV2 {
         /// Signature of [`Self::payload`].
         pub(super) signature: SignatureOf<TransactionPayload>,
+        /// Additional signatures of [`Self::payload`] made by the signatories of a multisignature account.
+        ///
+        /// Kept sorted by public key, with one signature per key, so that the hash
+        /// of the transaction doesn't depend on the order the signatures were added in.
+        pub(super) cosignatures: Vec<SignatureOf<TransactionPayload>>,
         /// Payload of the transaction.
         pub(super) payload: TransactionPayload,
     }
@@ -185,6 +259,242 @@ mod model {
         /// Reason of rejection
         pub error: Option<error::TransactionRejectionReason>,
     }
+
+    /// Outcome of executing a transaction against the current state without committing it.
+    #[derive(
+        Debug,
+        PartialOrd,
+        Ord,
+        Getters,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[getset(get = "pub")]
+    pub struct TransactionSimulation {
+        /// Events the transaction would emit, including the data events describing its state changes
+        pub events: Vec<EventBox>,
+        /// Reason of rejection
+        pub error: Option<error::TransactionRejectionReason>,
+        /// Messages logged by WASM the transaction executed, kept even if it's rejected
+        pub logs: Vec<crate::isi::Log>,
+    }
+
+    /// Request to check whether an account is permitted to execute an instruction.
+    #[derive(
+        Debug,
+        PartialOrd,
+        Ord,
+        Getters,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    #[getset(get = "pub")]
+    pub struct CanAccountExecute {
+        /// Account on behalf of which the instruction is checked
+        pub account_id: AccountId,
+        /// Checked instruction
+        pub instruction: InstructionBox,
+    }
+
+    /// [`CanAccountExecute`] signed by the account requesting the check.
+    #[derive(
+        Debug,
+        PartialOrd,
+        Ord,
+        Getters,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    #[getset(get = "pub")]
+    pub struct SignedCanAccountExecute {
+        /// Requested check
+        pub check: CanAccountExecute,
+        /// Account which requests the check, it has to be allowed to read the permissions of the checked account
+        pub requester: AccountId,
+        /// Signature of [`Self::check`] made by [`Self::requester`]
+        pub signature: SignatureOf<CanAccountExecute>,
+    }
+
+    /// Outcome of [`CanAccountExecute`].
+    #[derive(
+        Debug,
+        PartialOrd,
+        Ord,
+        Getters,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[getset(get = "pub")]
+    pub struct ExecutionVerdict {
+        /// Why the instruction is rejected, `None` if it is allowed.
+        ///
+        /// [`ValidationFail::NotPermitted`] carries the reason given by the executor,
+        /// [`ValidationFail::InstructionFailed`] means the executor allowed the instruction but it failed.
+        pub denial: Option<ValidationFail>,
+        /// Permissions the executor judged the account by, including those of its roles
+        pub permissions: Vec<Permission>,
+    }
+
+    /// Request of the authority of a queued transaction to remove it from the queue.
+    #[derive(
+        Debug,
+        PartialOrd,
+        Ord,
+        Getters,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    #[getset(get = "pub")]
+    pub struct CancelTransaction {
+        /// Hash of the cancelled transaction
+        pub hash: HashOf<SignedTransaction>,
+        /// Signature of [`Self::hash`] made by the authority of the cancelled transaction
+        pub signature: SignatureOf<HashOf<SignedTransaction>>,
+    }
+
+    /// Snapshot of the transactions waiting in the queue of a peer.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    #[getset(get = "pub")]
+    pub struct QueueStatus {
+        /// Number of transactions waiting in the queue
+        pub len: u64,
+        /// Number of transactions waiting in the queue per authority
+        pub len_per_authority: BTreeMap<AccountId, u64>,
+        /// Creation time of the oldest transaction waiting in the queue
+        pub oldest_creation_time: Option<Duration>,
+    }
+
+    /// Part of a transaction too large to be submitted in one request.
+    #[derive(
+        Debug,
+        PartialOrd,
+        Ord,
+        Getters,
+        CopyGetters,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    pub struct TransactionChunk {
+        /// Hash of the whole transaction
+        #[getset(get = "pub")]
+        pub hash: HashOf<SignedTransaction>,
+        /// Position of the chunk among the chunks of the transaction
+        #[getset(get_copy = "pub")]
+        pub index: u32,
+        /// Number of chunks of the transaction
+        #[getset(get_copy = "pub")]
+        pub count: NonZeroU32,
+        /// Part of the versioned encoding of the transaction
+        #[getset(get = "pub")]
+        pub bytes: Vec<u8>,
+    }
+}
+
+impl TransactionChunk {
+    /// Split the versioned encoding of `transaction` into chunks of at most `chunk_len` bytes
+    ///
+    /// # Panics
+    /// If the transaction is split into more than [`u32::MAX`] chunks
+    pub fn split(transaction: &SignedTransaction, chunk_len: core::num::NonZeroUsize) -> Vec<Self> {
+        let hash = transaction.hash();
+        let bytes = transaction.encode_versioned();
+        let count = bytes
+            .chunks(chunk_len.get())
+            .len()
+            .try_into()
+            .ok()
+            .and_then(NonZeroU32::new)
+            .expect("Transaction should be split into 1..=u32::MAX chunks");
+
+        bytes
+            .chunks(chunk_len.get())
+            .zip(0..)
+            .map(|(bytes, index)| Self {
+                hash,
+                index,
+                count,
+                bytes: bytes.to_vec(),
+            })
+            .collect()
+    }
+}
+
+impl CancelTransaction {
+    /// Construct [`Self`] signed with the key pair of the transaction authority
+    pub fn new(hash: HashOf<SignedTransaction>, key_pair: &iroha_crypto::KeyPair) -> Self {
+        Self {
+            hash,
+            signature: SignatureOf::new(key_pair, &hash),
+        }
+    }
+}
+
+impl CanAccountExecute {
+    /// Construct [`Self`]
+    pub fn new(account_id: AccountId, instruction: impl Into<InstructionBox>) -> Self {
+        Self {
+            account_id,
+            instruction: instruction.into(),
+        }
+    }
+
+    /// Sign the check on behalf of `requester`
+    pub fn sign(
+        self,
+        requester: AccountId,
+        key_pair: &iroha_crypto::KeyPair,
+    ) -> SignedCanAccountExecute {
+        SignedCanAccountExecute {
+            signature: SignatureOf::new(key_pair, &self),
+            check: self,
+            requester,
+        }
+    }
 }
 
 impl TransactionLimits {
@@ -197,6 +507,113 @@ impl TransactionLimits {
     }
 }
 
+impl TransactionFees {
+    /// Construct [`Self`] charging `instruction_fee` for every instruction.
+    pub fn new(
+        asset_definition_id: AssetDefinitionId,
+        recipient: AccountId,
+        instruction_fee: impl Into<Numeric>,
+        byte_fee: impl Into<Numeric>,
+    ) -> Self {
+        Self {
+            asset_definition_id,
+            recipient,
+            instruction_fee: instruction_fee.into(),
+            byte_fee: byte_fee.into(),
+            instruction_fees: BTreeMap::new(),
+        }
+    }
+
+    /// Charge `fee` for every instruction of the given kind instead of the default instruction fee.
+    #[must_use]
+    pub fn with_instruction_fee(
+        mut self,
+        instruction_type: InstructionType,
+        fee: impl Into<Numeric>,
+    ) -> Self {
+        self.instruction_fees.insert(instruction_type, fee.into());
+        self
+    }
+
+    /// Fee of the given transaction including its tip, or `None` if it overflows.
+    pub fn fee_of(&self, transaction: &SignedTransaction) -> Option<Numeric> {
+        let SignedTransaction::V2(tx) = transaction;
+        let size = u64::try_from(tx.payload.encoded_size()).ok()?;
+        let mut fee = self.byte_fee.checked_mul(size.into())?;
+        if let Some(tip) = transaction.tip() {
+            fee = fee.checked_add(tip)?;
+        }
+        if let Executable::Instructions(instructions) = transaction.instructions() {
+            for instruction in instructions {
+                let instruction_fee = self
+                    .instruction_fees
+                    .get(&InstructionType::from(instruction))
+                    .unwrap_or(&self.instruction_fee);
+                fee = fee.checked_add(*instruction_fee)?;
+            }
+        }
+        Some(fee)
+    }
+}
+
+impl Display for TransactionFees {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.asset_definition_id, self.recipient, self.instruction_fee, self.byte_fee
+        )?;
+        for (instruction_type, fee) in &self.instruction_fees {
+            write!(f, ",{instruction_type}:{fee}")?;
+        }
+        write!(f, "_TF")
+    }
+}
+
+impl FromStr for TransactionFees {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.strip_suffix("_TF").unwrap_or(string);
+        let mut parts = string.split(',');
+        let (Some(asset_definition_id), Some(recipient), Some(instruction_fee), Some(byte_fee)) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ParseError {
+                reason: "Transaction fees should have at least four comma-separated parts",
+            });
+        };
+        let mut fees = Self::new(
+            asset_definition_id.parse().map_err(|_| ParseError {
+                reason: "Failed to parse the fee asset definition id",
+            })?,
+            recipient.parse().map_err(|_| ParseError {
+                reason: "Failed to parse the fee recipient account id",
+            })?,
+            instruction_fee.parse::<Numeric>().map_err(|_| ParseError {
+                reason: "Failed to parse the instruction fee as `Numeric`",
+            })?,
+            byte_fee.parse::<Numeric>().map_err(|_| ParseError {
+                reason: "Failed to parse the byte fee as `Numeric`",
+            })?,
+        );
+        for part in parts {
+            let (instruction_type, fee) = part.split_once(':').ok_or(ParseError {
+                reason: "Fee of an instruction kind should be in the `<kind>:<fee>` form",
+            })?;
+            fees = fees.with_instruction_fee(
+                instruction_type.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse the instruction kind",
+                })?,
+                fee.parse::<Numeric>().map_err(|_| ParseError {
+                    reason: "Failed to parse the instruction fee as `Numeric`",
+                })?,
+            );
+        }
+        Ok(fees)
+    }
+}
+
 impl<A: Instruction> FromIterator<A> for Executable {
     fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
         Self::Instructions(iter.into_iter().map(Into::into).collect())
@@ -215,6 +632,21 @@ impl From<WasmSmartContract> for Executable {
     }
 }
 
+impl WasmCode {
+    /// Constructor.
+    #[inline]
+    pub fn new(code: WasmSmartContract) -> <Self as Registered>::With {
+        Self {
+            id: HashOf::new(&code),
+            code,
+        }
+    }
+}
+
+impl Registered for WasmCode {
+    type With = Self;
+}
+
 impl AsRef<[u8]> for WasmSmartContract {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
@@ -235,43 +667,43 @@ impl WasmSmartContract {
 }
 
 #[cfg(any(feature = "ffi_export", feature = "ffi_import"))]
-declare_versioned!(SignedTransaction 1..2, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, iroha_ffi::FfiType, IntoSchema);
+declare_versioned!(SignedTransaction 2..3, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, iroha_ffi::FfiType, IntoSchema);
 #[cfg(all(not(feature = "ffi_export"), not(feature = "ffi_import")))]
-declare_versioned!(SignedTransaction 1..2, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, IntoSchema);
+declare_versioned!(SignedTransaction 2..3, Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, FromVariant, IntoSchema);
 
 impl SignedTransaction {
     /// Return transaction instructions
     #[inline]
     pub fn instructions(&self) -> &Executable {
-        let SignedTransaction::V1(tx) = self;
+        let SignedTransaction::V2(tx) = self;
         &tx.payload.instructions
     }
 
     /// Return transaction authority
     #[inline]
     pub fn authority(&self) -> &AccountId {
-        let SignedTransaction::V1(tx) = self;
+        let SignedTransaction::V2(tx) = self;
         &tx.payload.authority
     }
 
     /// Return transaction metadata.
     #[inline]
     pub fn metadata(&self) -> &UnlimitedMetadata {
-        let SignedTransaction::V1(tx) = self;
+        let SignedTransaction::V2(tx) = self;
         &tx.payload.metadata
     }
 
     /// Creation timestamp as [`core::time::Duration`]
     #[inline]
     pub fn creation_time(&self) -> Duration {
-        let SignedTransaction::V1(tx) = self;
+        let SignedTransaction::V2(tx) = self;
         Duration::from_millis(tx.payload.creation_time_ms)
     }
 
     /// If transaction is not committed by this time it will be dropped.
     #[inline]
     pub fn time_to_live(&self) -> Option<Duration> {
-        let SignedTransaction::V1(tx) = self;
+        let SignedTransaction::V2(tx) = self;
         tx.payload
             .time_to_live_ms
             .map(|ttl| Duration::from_millis(ttl.into()))
@@ -280,14 +712,35 @@ impl SignedTransaction {
     /// Transaction nonce
     #[inline]
     pub fn nonce(&self) -> Option<NonZeroU32> {
-        let SignedTransaction::V1(tx) = self;
+        let SignedTransaction::V2(tx) = self;
         tx.payload.nonce
     }
 
+    /// Transaction sequence number
+    #[inline]
+    pub fn sequence(&self) -> Option<NonZeroU64> {
+        let SignedTransaction::V2(tx) = self;
+        tx.payload.sequence
+    }
+
+    /// Transaction tip
+    #[inline]
+    pub fn tip(&self) -> Option<Numeric> {
+        let SignedTransaction::V2(tx) = self;
+        tx.payload.tip
+    }
+
+    /// Earliest block the transaction may be put into
+    #[inline]
+    pub fn not_before(&self) -> Option<Expiry> {
+        let SignedTransaction::V2(tx) = self;
+        tx.payload.not_before
+    }
+
     /// Transaction chain id
     #[inline]
     pub fn chain_id(&self) -> &ChainId {
-        let SignedTransaction::V1(tx) = self;
+        let SignedTransaction::V2(tx) = self;
         &tx.payload.chain_id
     }
 
@@ -295,40 +748,145 @@ impl SignedTransaction {
     #[inline]
     #[allow(private_interfaces)]
     pub fn signature(&self) -> &SignatureOf<TransactionPayload> {
-        let SignedTransaction::V1(tx) = self;
+        let SignedTransaction::V2(tx) = self;
         &tx.signature
     }
 
+    /// Return the additional signatures of the signatories of a multisignature account
+    #[inline]
+    #[allow(private_interfaces)]
+    pub fn cosignatures(&self) -> &[SignatureOf<TransactionPayload>] {
+        let SignedTransaction::V2(tx) = self;
+        &tx.cosignatures
+    }
+
+    /// Return public keys of all the signatures of the transaction
+    pub fn signatories(&self) -> impl Iterator<Item = &PublicKey> {
+        let SignedTransaction::V2(tx) = self;
+        core::iter::once(&tx.signature)
+            .chain(&tx.cosignatures)
+            .map(|signature| signature.public_key())
+    }
+
     /// Calculate transaction [`Hash`](`iroha_crypto::HashOf`).
     #[inline]
     pub fn hash(&self) -> iroha_crypto::HashOf<Self> {
         iroha_crypto::HashOf::new(self)
     }
 
+    /// Calculate hash of the transaction payload, which is the same for all of the signatures.
+    #[inline]
+    #[allow(private_interfaces)]
+    pub fn payload_hash(&self) -> iroha_crypto::HashOf<TransactionPayload> {
+        let SignedTransaction::V2(tx) = self;
+        iroha_crypto::HashOf::new(&tx.payload)
+    }
+
     /// Sign transaction with provided key pair.
     #[must_use]
     pub fn sign(self, key_pair: &iroha_crypto::KeyPair) -> SignedTransaction {
-        let SignedTransaction::V1(mut tx) = self;
+        let SignedTransaction::V2(mut tx) = self;
         let signature = iroha_crypto::SignatureOf::new(key_pair, &tx.payload);
         tx.signature = signature;
+        tx.canonicalize_cosignatures();
 
-        SignedTransactionV1 {
-            payload: tx.payload,
-            signature: tx.signature,
+        tx.into()
+    }
+
+    /// Add signature of the provided key pair to the signatures of the transaction.
+    #[must_use]
+    pub fn cosign(self, key_pair: &iroha_crypto::KeyPair) -> SignedTransaction {
+        let signature = self.sign_payload(key_pair);
+        let SignedTransaction::V2(mut tx) = self;
+        tx.cosignatures.push(signature);
+        tx.canonicalize_cosignatures();
+
+        tx.into()
+    }
+
+    /// Sign the transaction payload with provided key pair without changing the transaction.
+    #[allow(private_interfaces)]
+    pub fn sign_payload(
+        &self,
+        key_pair: &iroha_crypto::KeyPair,
+    ) -> SignatureOf<TransactionPayload> {
+        let SignedTransaction::V2(tx) = self;
+        iroha_crypto::SignatureOf::new(key_pair, &tx.payload)
+    }
+
+    /// Add the signatures of `other` which this transaction doesn't have yet.
+    ///
+    /// Returns `false` without changing the transaction if `other` has a different payload.
+    pub fn merge_signatures(&mut self, other: &SignedTransaction) -> bool {
+        let (SignedTransaction::V2(tx), SignedTransaction::V2(other)) = (&*self, other);
+        if tx.payload != other.payload {
+            return false;
         }
-        .into()
+
+        for signature in core::iter::once(&other.signature).chain(&other.cosignatures) {
+            if !self.is_signed_by(signature.public_key()) {
+                let SignedTransaction::V2(tx) = &mut *self;
+                tx.cosignatures.push(signature.clone());
+            }
+        }
+        let SignedTransaction::V2(tx) = self;
+        tx.canonicalize_cosignatures();
+
+        true
+    }
+
+    /// Add a signature made separately from the transaction, e.g. by a signatory
+    /// which only received the payload hash.
+    ///
+    /// A signature of a public key which already signed the transaction is ignored.
+    ///
+    /// # Errors
+    /// Fails if `signature` is not a valid signature of the transaction payload
+    #[allow(private_interfaces)]
+    pub fn add_signature(
+        &mut self,
+        signature: SignatureOf<TransactionPayload>,
+    ) -> Result<(), iroha_crypto::Error> {
+        let SignedTransaction::V2(tx) = &*self;
+        signature.verify(&tx.payload)?;
+        if !self.is_signed_by(signature.public_key()) {
+            let SignedTransaction::V2(tx) = self;
+            tx.cosignatures.push(signature);
+            tx.canonicalize_cosignatures();
+        }
+
+        Ok(())
+    }
+
+    /// Return `true` if the transaction has a signature of `public_key`.
+    pub fn is_signed_by(&self, public_key: &PublicKey) -> bool {
+        self.signatories().any(|signatory| signatory == public_key)
     }
 }
 
 #[cfg(feature = "transparent_api")]
 impl From<SignedTransaction> for (AccountId, Executable) {
     fn from(source: SignedTransaction) -> Self {
-        let SignedTransaction::V1(tx) = source;
+        let SignedTransaction::V2(tx) = source;
         (tx.payload.authority, tx.payload.instructions)
     }
 }
 
-impl SignedTransactionV1 {
+impl SignedTransactionV2 {
+    /// Sort the cosignatures by public key and drop the ones of keys which already signed.
+    fn canonicalize_cosignatures(&mut self) {
+        let signatory = self.signature.public_key().clone();
+        self.cosignatures
+            .retain(|cosignature| *cosignature.public_key() != signatory);
+        self.cosignatures.sort_by(|lhs, rhs| {
+            lhs.public_key()
+                .cmp(rhs.public_key())
+                .then_with(|| lhs.cmp(rhs))
+        });
+        self.cosignatures
+            .dedup_by(|lhs, rhs| lhs.public_key() == rhs.public_key());
+    }
+
     #[cfg(feature = "std")]
     fn hash(&self) -> iroha_crypto::HashOf<SignedTransaction> {
         iroha_crypto::HashOf::from_untyped_unchecked(iroha_crypto::HashOf::new(self).into())
@@ -349,43 +907,49 @@ mod candidate {
     #[derive(Decode, Deserialize)]
     struct SignedTransactionCandidate {
         signature: SignatureOf<TransactionPayload>,
+        #[serde(default)]
+        cosignatures: Vec<SignatureOf<TransactionPayload>>,
         payload: TransactionPayload,
     }
 
     impl SignedTransactionCandidate {
-        fn validate(self) -> Result<SignedTransactionV1, &'static str> {
+        fn validate(self) -> Result<SignedTransactionV2, &'static str> {
             self.validate_signature()?;
             self.validate_instructions()
         }
 
-        fn validate_instructions(self) -> Result<SignedTransactionV1, &'static str> {
+        fn validate_instructions(self) -> Result<SignedTransactionV2, &'static str> {
             if let Executable::Instructions(instructions) = &self.payload.instructions {
                 if instructions.is_empty() {
                     return Err("Transaction is empty");
                 }
             }
 
-            Ok(SignedTransactionV1 {
+            let mut tx = SignedTransactionV2 {
                 payload: self.payload,
                 signature: self.signature,
-            })
+                cosignatures: self.cosignatures,
+            };
+            tx.canonicalize_cosignatures();
+            Ok(tx)
         }
 
         fn validate_signature(&self) -> Result<(), &'static str> {
-            self.signature
-                .verify(&self.payload)
+            core::iter::once(&self.signature)
+                .chain(&self.cosignatures)
+                .try_for_each(|signature| signature.verify(&self.payload))
                 .map_err(|_| "Transaction contains invalid signatures")
         }
     }
 
-    impl Decode for SignedTransactionV1 {
+    impl Decode for SignedTransactionV2 {
         fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
             SignedTransactionCandidate::decode(input)?
                 .validate()
                 .map_err(Into::into)
         }
     }
-    impl<'de> Deserialize<'de> for SignedTransactionV1 {
+    impl<'de> Deserialize<'de> for SignedTransactionV2 {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: serde::Deserializer<'de>,
@@ -520,7 +1084,35 @@ pub mod error {
             pub reason: String,
         }
 
+        /// Transaction was rejected because a transaction validation trigger failed
+        #[derive(
+            Debug,
+            Display,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[display(fmt = "Transaction is vetoed by trigger `{trigger_id}`: {reason}")]
+        #[ffi_type]
+        pub struct ValidationTriggerFail {
+            /// Trigger which vetoed the transaction
+            #[getset(get = "pub")]
+            pub trigger_id: crate::trigger::TriggerId,
+            /// Error which happened during execution of the trigger
+            pub reason: String,
+        }
+
         /// The reason for rejecting transaction which happened because of transaction.
+        ///
+        /// Variant indices are error codes clients match on, so they must never change or be reused.
         #[derive(
             Debug,
             displaydoc::Display,
@@ -542,6 +1134,7 @@ pub mod error {
         #[ffi_type(opaque)]
         pub enum TransactionRejectionReason {
             /// Account does not exist
+            #[codec(index = 0)]
             AccountDoesNotExist(
                 #[skip_from] // NOTE: Such implicit conversions would be too unreadable
                 #[skip_try_from]
@@ -551,16 +1144,52 @@ pub mod error {
             /// Failed to validate transaction limits
             ///
             /// e.g. number of instructions
+            #[codec(index = 1)]
             LimitCheck(#[cfg_attr(feature = "std", source)] error::TransactionLimitError),
             /// Validation failed
+            #[codec(index = 2)]
             Validation(#[cfg_attr(feature = "std", source)] crate::ValidationFail),
             /// Failure in instruction execution
             ///
             /// In practice should be fully replaced by [`ValidationFail::Execution`]
             /// and will be removed soon.
+            #[codec(index = 3)]
             InstructionExecution(#[cfg_attr(feature = "std", source)] InstructionExecutionFail),
             /// Failure in WebAssembly execution
+            #[codec(index = 4)]
             WasmExecution(#[cfg_attr(feature = "std", source)] WasmExecutionFail),
+            /// Transaction signatures do not satisfy the signature condition of the authority account,
+            /// or include signatures of keys which can't sign for it
+            #[codec(index = 5)]
+            SignatureConditionUnsatisfied,
+            /// Authority account is disabled
+            #[codec(index = 6)]
+            AccountDisabled,
+            /// Sequence number of the transaction doesn't follow `{0}`, the one of the last executed transaction of the authority account
+            #[codec(index = 7)]
+            SequenceMismatch(
+                #[skip_from]
+                #[skip_try_from]
+                u64,
+            ),
+            /// Failed to charge the transaction fee
+            #[codec(index = 8)]
+            FeeCharge(
+                #[skip_from]
+                #[skip_try_from]
+                #[cfg_attr(feature = "std", source)]
+                crate::isi::error::InstructionExecutionError,
+            ),
+            /// Transaction validation trigger failed
+            #[codec(index = 9)]
+            ValidationTrigger(#[cfg_attr(feature = "std", source)] ValidationTriggerFail),
+            /// WebAssembly smart contract consumed more than `{0}` units of fuel
+            #[codec(index = 10)]
+            OutOfFuel(
+                #[skip_from]
+                #[skip_try_from]
+                u64,
+            ),
         }
     }
 
@@ -576,9 +1205,38 @@ pub mod error {
                 Unregister(_) => "un-register",
                 SetKeyValue(_) => "set key-value pair",
                 RemoveKeyValue(_) => "remove key-value pair",
+                MergeKeyValues(_) => "merge key-value pairs",
+                SetDomainMetadataLimits(_) => "set domain metadata limits",
+                RegisterAlias(_) => "register alias",
+                UnregisterAlias(_) => "unregister alias",
+                SetKeyValueWithExpiry(_) => "set key-value pair with expiry",
+                SetKeyValueIf(_) => "set key-value pair conditionally",
+                IncrementKeyValue(_) => "increment key-value pair",
+                Swap(_) => "swap",
+                TransferMany(_) => "transfer to many",
+                OpenEscrow(_) => "open escrow",
+                ReleaseEscrow(_) => "release escrow",
+                RefundEscrow(_) => "refund escrow",
+                AddSignatory(_) => "add signatory",
+                RemoveSignatory(_) => "remove signatory",
+                SetSignatureThreshold(_) => "set signature threshold",
+                RotateKey(_) => "rotate key",
+                SetRecoveryPolicy(_) => "set recovery policy",
+                RecoverAccount(_) => "recover account",
+                CancelRecovery(_) => "cancel recovery",
+                FinalizeRecovery(_) => "finalize recovery",
+                DisableAccount(_) => "disable account",
+                EnableAccount(_) => "enable account",
+                SetStrictOrdering(_) => "set strict ordering",
+                Freeze(_) => "freeze",
+                Unfreeze(_) => "unfreeze",
                 Grant(_) => "grant",
                 Revoke(_) => "revoke",
+                GrantWithTerms(_) => "grant with terms",
                 ExecuteTrigger(_) => "execute trigger",
+                Pause(_) => "pause trigger",
+                Resume(_) => "resume trigger",
+                UpgradeTrigger(_) => "upgrade trigger",
                 SetParameter(_) => "set parameter",
                 NewParameter(_) => "new parameter",
                 Upgrade(_) => "upgrade",
@@ -601,10 +1259,16 @@ pub mod error {
     #[cfg(feature = "std")]
     impl std::error::Error for WasmExecutionFail {}
 
+    #[cfg(feature = "std")]
+    impl std::error::Error for ValidationTriggerFail {}
+
     pub mod prelude {
         //! The prelude re-exports most commonly used traits, structs and macros from this module.
 
-        pub use super::{InstructionExecutionFail, TransactionRejectionReason, WasmExecutionFail};
+        pub use super::{
+            InstructionExecutionFail, TransactionRejectionReason, ValidationTriggerFail,
+            WasmExecutionFail,
+        };
     }
 }
 
@@ -636,6 +1300,9 @@ mod http {
                     authority,
                     creation_time_ms,
                     nonce: None,
+                    sequence: None,
+                    tip: None,
+                    not_before: None,
                     time_to_live_ms: None,
                     instructions: Vec::<InstructionBox>::new().into(),
                     metadata: UnlimitedMetadata::new(),
@@ -716,6 +1383,24 @@ mod http {
             self
         }
 
+        /// Set sequence number for [`Transaction`]
+        pub fn set_sequence(&mut self, sequence: NonZeroU64) -> &mut Self {
+            self.payload.sequence = Some(sequence);
+            self
+        }
+
+        /// Set tip for [`Transaction`]
+        pub fn set_tip(&mut self, tip: impl Into<Numeric>) -> &mut Self {
+            self.payload.tip = Some(tip.into());
+            self
+        }
+
+        /// Schedule [`Transaction`] to be put into a block no earlier than `not_before`
+        pub fn set_not_before(&mut self, not_before: Expiry) -> &mut Self {
+            self.payload.not_before = Some(not_before);
+            self
+        }
+
         /// Set time-to-live for [`Transaction`]
         pub fn set_ttl(&mut self, time_to_live: Duration) -> &mut Self {
             let ttl: u64 = time_to_live
@@ -745,9 +1430,10 @@ mod http {
         pub fn sign(self, key_pair: &iroha_crypto::KeyPair) -> SignedTransaction {
             let signature = SignatureOf::new(key_pair, &self.payload);
 
-            SignedTransactionV1 {
+            SignedTransactionV2 {
                 payload: self.payload,
                 signature,
+                cosignatures: Vec::new(),
             }
             .into()
         }
@@ -759,7 +1445,9 @@ pub mod prelude {
     #[cfg(feature = "http")]
     pub use super::http::TransactionBuilder;
     pub use super::{
-        error::prelude::*, CommittedTransaction, Executable, SignedTransaction, WasmSmartContract,
+        error::prelude::*, CanAccountExecute, CancelTransaction, CommittedTransaction, Executable,
+        ExecutionVerdict, QueueStatus, SignedCanAccountExecute, SignedTransaction,
+        TransactionChunk, TransactionSimulation, WasmCode, WasmSmartContract,
     };
 }
 
@@ -775,4 +1463,21 @@ mod tests {
         let contract = WasmSmartContract::from_compiled(vec![0, 1, 2, 3, 4]);
         assert_eq!(format!("{contract:?}"), "WASM binary(len = 5)");
     }
+
+    #[test]
+    fn rejection_reason_codes_are_stable() {
+        use crate::{isi::error::InstructionExecutionError, ValidationFail};
+
+        assert_eq!(
+            TransactionRejectionReason::SequenceMismatch(1).encode()[0],
+            7
+        );
+        assert_eq!(
+            TransactionRejectionReason::Validation(ValidationFail::InstructionFailed(
+                InstructionExecutionError::Fail(String::new())
+            ))
+            .encode()[..3],
+            [2, 1, 8]
+        );
+    }
 }
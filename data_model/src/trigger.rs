@@ -5,10 +5,11 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{format, string::String, vec::Vec};
-use core::{cmp, str::FromStr};
+use core::{cmp, num::NonZeroU64, str::FromStr};
 
 use derive_more::{Constructor, Display};
 use getset::Getters;
+use iroha_crypto::HashOf;
 use iroha_data_model_derive::{model, IdEqOrdHash};
 use iroha_macro::ffi_impl_opaque;
 use iroha_schema::IntoSchema;
@@ -165,6 +166,44 @@ pub mod action {
             pub filter: TriggeringEventFilterBox,
             /// Metadata used as persistent storage for trigger data.
             pub metadata: Metadata,
+            /// Limits of the work done by the executions of the action
+            #[serde(default)]
+            pub limits: ExecutionLimits,
+            /// Whether the action is paused. Paused actions aren't executed
+            /// until they are resumed.
+            #[serde(default)]
+            pub paused: bool,
+        }
+
+        /// Limits of the work done by the executions of a trigger, so that
+        /// a runaway trigger can't take up whole blocks.
+        #[derive(
+            Debug,
+            Default,
+            Copy,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get_copy = "pub")]
+        #[ffi_type]
+        pub struct ExecutionLimits {
+            /// Maximum number of instructions executed by a single execution
+            pub max_instructions: Option<NonZeroU64>,
+            /// Maximum WASM fuel consumed by a single execution.
+            /// The chain-wide WASM fuel limit applies anyway.
+            pub max_fuel: Option<NonZeroU64>,
+            /// Number of instructions left for all the following executions.
+            /// The trigger is unregistered once it is exhausted.
+            pub instruction_budget: Option<u64>,
         }
 
         /// Enumeration of possible repetitions schemes.
@@ -206,6 +245,14 @@ pub mod action {
         pub fn filter(&self) -> &TriggeringEventFilterBox {
             &self.filter
         }
+        /// Limits of the work done by the executions of the action
+        pub fn limits(&self) -> &ExecutionLimits {
+            &self.limits
+        }
+        /// Whether the action is paused
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
     }
 
     impl Action {
@@ -223,15 +270,53 @@ pub mod action {
                 authority,
                 filter: filter.into(),
                 metadata: Metadata::new(),
+                limits: ExecutionLimits::default(),
+                paused: false,
             }
         }
 
+        /// Hash of the executable, identifying the payload of the action
+        /// without transferring it.
+        pub fn payload_hash(&self) -> HashOf<Executable> {
+            HashOf::new(&self.executable)
+        }
+
         /// Add [`Metadata`] to the trigger replacing previously defined
         #[must_use]
         pub fn with_metadata(mut self, metadata: Metadata) -> Self {
             self.metadata = metadata;
             self
         }
+
+        /// Set [`ExecutionLimits`] of the trigger replacing previously defined
+        #[must_use]
+        pub fn with_limits(mut self, limits: ExecutionLimits) -> Self {
+            self.limits = limits;
+            self
+        }
+    }
+
+    impl ExecutionLimits {
+        /// Limit the number of instructions executed by a single execution
+        #[must_use]
+        pub const fn with_max_instructions(mut self, max_instructions: NonZeroU64) -> Self {
+            self.max_instructions = Some(max_instructions);
+            self
+        }
+
+        /// Limit the WASM fuel consumed by a single execution
+        #[must_use]
+        pub const fn with_max_fuel(mut self, max_fuel: NonZeroU64) -> Self {
+            self.max_fuel = Some(max_fuel);
+            self
+        }
+
+        /// Limit the number of instructions executed by all the executions
+        #[must_use]
+        pub const fn with_instruction_budget(mut self, instruction_budget: u64) -> Self {
+            self.instruction_budget = Some(instruction_budget);
+            self
+        }
     }
 
     impl PartialOrd for Action {
@@ -279,7 +364,7 @@ pub mod action {
 
     pub mod prelude {
         //! Re-exports of commonly used types.
-        pub use super::{Action, Repeats};
+        pub use super::{Action, ExecutionLimits, Repeats};
     }
 }
 
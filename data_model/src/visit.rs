@@ -29,28 +29,64 @@ pub trait Visit {
         // Visit InstructionBox
         visit_burn(&BurnBox),
         visit_fail(&Fail),
+        visit_freeze(&FreezeBox),
         visit_grant(&GrantBox),
+        visit_increment_key_value(&IncrementKeyValueBox),
+        visit_merge_key_values(&MergeKeyValuesBox),
         visit_mint(&MintBox),
         visit_register(&RegisterBox),
         visit_remove_key_value(&RemoveKeyValueBox),
         visit_revoke(&RevokeBox),
         visit_set_key_value(&SetKeyValueBox),
         visit_transfer(&TransferBox),
+        visit_unfreeze(&UnfreezeBox),
         visit_unregister(&UnregisterBox),
         visit_upgrade(&Upgrade),
 
+        visit_grant_with_terms(&GrantWithTerms),
         visit_execute_trigger(&ExecuteTrigger),
+        visit_pause_trigger(&Pause),
+        visit_resume_trigger(&Resume),
+        visit_upgrade_trigger(&UpgradeTrigger),
         visit_new_parameter(&NewParameter),
         visit_set_parameter(&SetParameter),
+        visit_set_domain_metadata_limits(&SetDomainMetadataLimits),
+        visit_register_alias(&RegisterAlias),
+        visit_unregister_alias(&UnregisterAlias),
+        visit_set_key_value_with_expiry(&SetKeyValueWithExpiry),
+        visit_set_key_value_if(&SetKeyValueIf),
+        visit_swap(&Swap),
+        visit_transfer_many(&TransferMany),
+        visit_open_escrow(&OpenEscrow),
+        visit_release_escrow(&ReleaseEscrow),
+        visit_refund_escrow(&RefundEscrow),
+        visit_add_signatory(&AddSignatory),
+        visit_remove_signatory(&RemoveSignatory),
+        visit_set_signature_threshold(&SetSignatureThreshold),
+        visit_rotate_key(&RotateKey),
+        visit_set_recovery_policy(&SetRecoveryPolicy),
+        visit_recover_account(&RecoverAccount),
+        visit_finalize_recovery(&FinalizeRecovery),
+        visit_finalize_recovery(&FinalizeRecovery),
+    visit_cancel_recovery(&CancelRecovery),
+        visit_disable_account(&DisableAccount),
+        visit_enable_account(&EnableAccount),
+        visit_set_strict_ordering(&SetStrictOrdering),
         visit_log(&Log),
 
         // Visit QueryBox
         visit_find_account_by_id(&FindAccountById),
+        visit_find_account_by_alias(&FindAccountByAlias),
         visit_find_account_key_value_by_id_and_key(&FindAccountKeyValueByIdAndKey),
+        visit_find_account_key_value_by_id_and_path(&FindAccountKeyValueByIdAndPath),
         visit_find_accounts_by_domain_id(&FindAccountsByDomainId),
+        visit_find_account_count_by_domain_id(&FindAccountCountByDomainId),
         visit_find_accounts_with_asset(&FindAccountsWithAsset),
+        visit_find_accounts_by_metadata_key_prefix(&FindAccountsByMetadataKeyPrefix),
+        visit_find_metadata_history(&FindMetadataHistory),
         visit_find_all_accounts(&FindAllAccounts),
         visit_find_all_active_trigger_ids(&FindAllActiveTriggerIds),
+        visit_find_all_active_triggers(&FindAllActiveTriggers),
         visit_find_all_assets(&FindAllAssets),
         visit_find_all_assets_definitions(&FindAllAssetsDefinitions),
         visit_find_all_block_headers(&FindAllBlockHeaders),
@@ -66,20 +102,31 @@ pub trait Visit {
         visit_find_asset_definition_by_id(&FindAssetDefinitionById),
         visit_find_asset_definition_key_value_by_id_and_key(&FindAssetDefinitionKeyValueByIdAndKey),
         visit_find_asset_key_value_by_id_and_key(&FindAssetKeyValueByIdAndKey),
+        visit_find_asset_key_value_by_id_and_path(&FindAssetKeyValueByIdAndPath),
         visit_find_asset_quantity_by_id(&FindAssetQuantityById),
         visit_find_assets_by_account_id(&FindAssetsByAccountId),
         visit_find_assets_by_asset_definition_id(&FindAssetsByAssetDefinitionId),
         visit_find_assets_by_domain_id(&FindAssetsByDomainId),
         visit_find_assets_by_domain_id_and_asset_definition_id(&FindAssetsByDomainIdAndAssetDefinitionId),
+        visit_find_assets_by_metadata_key_prefix(&FindAssetsByMetadataKeyPrefix),
+        visit_find_nfts_by_account_id(&FindNftsByAccountId),
         visit_find_assets_by_name(&FindAssetsByName),
         visit_find_block_header_by_hash(&FindBlockHeaderByHash),
+        visit_find_block_by_hash(&FindBlockByHash),
+        visit_find_block_by_height(&FindBlockByHeight),
+        visit_find_blocks_in_range(&FindBlocksInRange),
         visit_find_domain_by_id(&FindDomainById),
+        visit_find_domain_descendants(&FindDomainDescendants),
         visit_find_domain_key_value_by_id_and_key(&FindDomainKeyValueByIdAndKey),
         visit_find_permissions_by_account_id(&FindPermissionsByAccountId),
+        visit_find_permission_grant_terms(&FindPermissionGrantTerms),
+        visit_find_grant_history_by_account_id(&FindGrantHistoryByAccountId),
         visit_find_role_by_role_id(&FindRoleByRoleId),
         visit_find_roles_by_account_id(&FindRolesByAccountId),
         visit_find_total_asset_quantity_by_asset_definition_id(&FindTotalAssetQuantityByAssetDefinitionId),
+        visit_find_asset_count_by_asset_definition_id(&FindAssetCountByAssetDefinitionId),
         visit_find_transaction_by_hash(&FindTransactionByHash),
+        visit_find_transaction_proof_by_hash(&FindTransactionProofByHash),
         visit_find_transactions_by_account_id(&FindTransactionsByAccountId),
         visit_find_trigger_by_id(&FindTriggerById),
         visit_find_trigger_key_value_by_id_and_key(&FindTriggerKeyValueByIdAndKey),
@@ -93,6 +140,7 @@ pub trait Visit {
         visit_register_asset(&Register<Asset>),
         visit_register_role(&Register<Role>),
         visit_register_trigger(&Register<Trigger>),
+        visit_register_wasm_code(&Register<WasmCode>),
 
         // Visit UnregisterBox
         visit_unregister_peer(&Unregister<Peer>),
@@ -107,15 +155,19 @@ pub trait Visit {
         // Visit MintBox
         visit_mint_asset_numeric(&Mint<Numeric, Asset>),
         visit_mint_trigger_repetitions(&Mint<u32, Trigger>),
+        visit_mint_nft(&Mint<Name, Asset>),
 
         // Visit BurnBox
         visit_burn_asset_numeric(&Burn<Numeric, Asset>),
+        visit_burn_asset_all(&Burn<All, Asset>),
         visit_burn_trigger_repetitions(&Burn<u32, Trigger>),
 
         // Visit TransferBox
         visit_transfer_asset_definition(&Transfer<Account, AssetDefinitionId, Account>),
         visit_transfer_asset_numeric(&Transfer<Asset, Numeric, Account>),
+        visit_transfer_asset_all(&Transfer<Asset, All, Account>),
         visit_transfer_asset_store(&Transfer<Asset, Metadata, Account>),
+        visit_transfer_nft(&Transfer<Asset, Name, Account>),
         visit_transfer_domain(&Transfer<Account, DomainId, Account>),
 
         // Visit SetKeyValueBox
@@ -132,6 +184,28 @@ pub trait Visit {
         visit_remove_asset_key_value(&RemoveKeyValue<Asset>),
         visit_remove_trigger_key_value(&RemoveKeyValue<Trigger>),
 
+        // Visit MergeKeyValuesBox
+        visit_merge_domain_key_values(&MergeKeyValues<Domain>),
+        visit_merge_account_key_values(&MergeKeyValues<Account>),
+        visit_merge_asset_definition_key_values(&MergeKeyValues<AssetDefinition>),
+        visit_merge_asset_key_values(&MergeKeyValues<Asset>),
+        visit_merge_trigger_key_values(&MergeKeyValues<Trigger>),
+
+        // Visit IncrementKeyValueBox
+        visit_increment_domain_key_value(&IncrementKeyValue<Domain>),
+        visit_increment_account_key_value(&IncrementKeyValue<Account>),
+        visit_increment_asset_definition_key_value(&IncrementKeyValue<AssetDefinition>),
+        visit_increment_asset_key_value(&IncrementKeyValue<Asset>),
+        visit_increment_trigger_key_value(&IncrementKeyValue<Trigger>),
+
+        // Visit FreezeBox
+        visit_freeze_asset_definition(&Freeze<AssetDefinition>),
+        visit_freeze_asset(&Freeze<Asset>),
+
+        // Visit UnfreezeBox
+        visit_unfreeze_asset_definition(&Unfreeze<AssetDefinition>),
+        visit_unfreeze_asset(&Unfreeze<Asset>),
+
         // Visit GrantBox
         visit_grant_account_permission(&Grant<Permission, Account>),
         visit_grant_account_role(&Grant<RoleId, Account>),
@@ -156,6 +230,8 @@ pub fn visit_transaction<V: Visit + ?Sized>(
                 visitor.visit_instruction(authority, isi);
             }
         }
+        // Transactions can't refer to registered code, they are rejected before validation
+        Executable::WasmCode(_) => {}
     }
 }
 
@@ -171,11 +247,17 @@ pub fn visit_query<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, qu
 
     query_visitors! {
         visit_find_account_by_id(FindAccountById),
+        visit_find_account_by_alias(FindAccountByAlias),
         visit_find_account_key_value_by_id_and_key(FindAccountKeyValueByIdAndKey),
+        visit_find_account_key_value_by_id_and_path(FindAccountKeyValueByIdAndPath),
         visit_find_accounts_by_domain_id(FindAccountsByDomainId),
+        visit_find_account_count_by_domain_id(FindAccountCountByDomainId),
         visit_find_accounts_with_asset(FindAccountsWithAsset),
+        visit_find_accounts_by_metadata_key_prefix(FindAccountsByMetadataKeyPrefix),
+        visit_find_metadata_history(FindMetadataHistory),
         visit_find_all_accounts(FindAllAccounts),
         visit_find_all_active_trigger_ids(FindAllActiveTriggerIds),
+        visit_find_all_active_triggers(FindAllActiveTriggers),
         visit_find_all_assets(FindAllAssets),
         visit_find_all_assets_definitions(FindAllAssetsDefinitions),
         visit_find_all_block_headers(FindAllBlockHeaders),
@@ -191,20 +273,31 @@ pub fn visit_query<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, qu
         visit_find_asset_definition_by_id(FindAssetDefinitionById),
         visit_find_asset_definition_key_value_by_id_and_key(FindAssetDefinitionKeyValueByIdAndKey),
         visit_find_asset_key_value_by_id_and_key(FindAssetKeyValueByIdAndKey),
+        visit_find_asset_key_value_by_id_and_path(FindAssetKeyValueByIdAndPath),
         visit_find_asset_quantity_by_id(FindAssetQuantityById),
         visit_find_assets_by_account_id(FindAssetsByAccountId),
         visit_find_assets_by_asset_definition_id(FindAssetsByAssetDefinitionId),
         visit_find_assets_by_domain_id(FindAssetsByDomainId),
         visit_find_assets_by_domain_id_and_asset_definition_id(FindAssetsByDomainIdAndAssetDefinitionId),
+        visit_find_assets_by_metadata_key_prefix(FindAssetsByMetadataKeyPrefix),
+        visit_find_nfts_by_account_id(FindNftsByAccountId),
         visit_find_assets_by_name(FindAssetsByName),
         visit_find_block_header_by_hash(FindBlockHeaderByHash),
+        visit_find_block_by_hash(FindBlockByHash),
+        visit_find_block_by_height(FindBlockByHeight),
+        visit_find_blocks_in_range(FindBlocksInRange),
         visit_find_domain_by_id(FindDomainById),
+        visit_find_domain_descendants(FindDomainDescendants),
         visit_find_domain_key_value_by_id_and_key(FindDomainKeyValueByIdAndKey),
         visit_find_permissions_by_account_id(FindPermissionsByAccountId),
+        visit_find_permission_grant_terms(FindPermissionGrantTerms),
+        visit_find_grant_history_by_account_id(FindGrantHistoryByAccountId),
         visit_find_role_by_role_id(FindRoleByRoleId),
         visit_find_roles_by_account_id(FindRolesByAccountId),
         visit_find_total_asset_quantity_by_asset_definition_id(FindTotalAssetQuantityByAssetDefinitionId),
+        visit_find_asset_count_by_asset_definition_id(FindAssetCountByAssetDefinitionId),
         visit_find_transaction_by_hash(FindTransactionByHash),
+        visit_find_transaction_proof_by_hash(FindTransactionProofByHash),
         visit_find_transactions_by_account_id(FindTransactionsByAccountId),
         visit_find_trigger_by_id(FindTriggerById),
         visit_find_trigger_key_value_by_id_and_key(FindTriggerKeyValueByIdAndKey),
@@ -239,16 +332,97 @@ pub fn visit_instruction<V: Visit + ?Sized>(
         InstructionBox::ExecuteTrigger(variant_value) => {
             visitor.visit_execute_trigger(authority, variant_value)
         }
+        InstructionBox::Pause(variant_value) => {
+            visitor.visit_pause_trigger(authority, variant_value)
+        }
+        InstructionBox::Resume(variant_value) => {
+            visitor.visit_resume_trigger(authority, variant_value)
+        }
+        InstructionBox::UpgradeTrigger(variant_value) => {
+            visitor.visit_upgrade_trigger(authority, variant_value)
+        }
+        InstructionBox::SetDomainMetadataLimits(variant_value) => {
+            visitor.visit_set_domain_metadata_limits(authority, variant_value)
+        }
+        InstructionBox::RegisterAlias(variant_value) => {
+            visitor.visit_register_alias(authority, variant_value)
+        }
+        InstructionBox::UnregisterAlias(variant_value) => {
+            visitor.visit_unregister_alias(authority, variant_value)
+        }
+        InstructionBox::SetKeyValueWithExpiry(variant_value) => {
+            visitor.visit_set_key_value_with_expiry(authority, variant_value)
+        }
+        InstructionBox::SetKeyValueIf(variant_value) => {
+            visitor.visit_set_key_value_if(authority, variant_value)
+        }
+        InstructionBox::Swap(variant_value) => visitor.visit_swap(authority, variant_value),
+        InstructionBox::TransferMany(variant_value) => {
+            visitor.visit_transfer_many(authority, variant_value)
+        }
+        InstructionBox::OpenEscrow(variant_value) => {
+            visitor.visit_open_escrow(authority, variant_value)
+        }
+        InstructionBox::ReleaseEscrow(variant_value) => {
+            visitor.visit_release_escrow(authority, variant_value)
+        }
+        InstructionBox::RefundEscrow(variant_value) => {
+            visitor.visit_refund_escrow(authority, variant_value)
+        }
+        InstructionBox::AddSignatory(variant_value) => {
+            visitor.visit_add_signatory(authority, variant_value)
+        }
+        InstructionBox::RemoveSignatory(variant_value) => {
+            visitor.visit_remove_signatory(authority, variant_value)
+        }
+        InstructionBox::SetSignatureThreshold(variant_value) => {
+            visitor.visit_set_signature_threshold(authority, variant_value)
+        }
+        InstructionBox::RotateKey(variant_value) => {
+            visitor.visit_rotate_key(authority, variant_value)
+        }
+        InstructionBox::SetRecoveryPolicy(variant_value) => {
+            visitor.visit_set_recovery_policy(authority, variant_value)
+        }
+        InstructionBox::RecoverAccount(variant_value) => {
+            visitor.visit_recover_account(authority, variant_value)
+        }
+        InstructionBox::FinalizeRecovery(variant_value) => {
+            visitor.visit_finalize_recovery(authority, variant_value)
+        }
+        InstructionBox::CancelRecovery(variant_value) => {
+            visitor.visit_cancel_recovery(authority, variant_value)
+        }
+        InstructionBox::DisableAccount(variant_value) => {
+            visitor.visit_disable_account(authority, variant_value)
+        }
+        InstructionBox::EnableAccount(variant_value) => {
+            visitor.visit_enable_account(authority, variant_value)
+        }
+        InstructionBox::SetStrictOrdering(variant_value) => {
+            visitor.visit_set_strict_ordering(authority, variant_value)
+        }
+        InstructionBox::Freeze(variant_value) => visitor.visit_freeze(authority, variant_value),
+        InstructionBox::Unfreeze(variant_value) => visitor.visit_unfreeze(authority, variant_value),
         InstructionBox::Log(variant_value) => visitor.visit_log(authority, variant_value),
         InstructionBox::Burn(variant_value) => visitor.visit_burn(authority, variant_value),
         InstructionBox::Fail(variant_value) => visitor.visit_fail(authority, variant_value),
         InstructionBox::Grant(variant_value) => visitor.visit_grant(authority, variant_value),
+        InstructionBox::IncrementKeyValue(variant_value) => {
+            visitor.visit_increment_key_value(authority, variant_value)
+        }
+        InstructionBox::MergeKeyValues(variant_value) => {
+            visitor.visit_merge_key_values(authority, variant_value)
+        }
         InstructionBox::Mint(variant_value) => visitor.visit_mint(authority, variant_value),
         InstructionBox::Register(variant_value) => visitor.visit_register(authority, variant_value),
         InstructionBox::RemoveKeyValue(variant_value) => {
             visitor.visit_remove_key_value(authority, variant_value)
         }
         InstructionBox::Revoke(variant_value) => visitor.visit_revoke(authority, variant_value),
+        InstructionBox::GrantWithTerms(variant_value) => {
+            visitor.visit_grant_with_terms(authority, variant_value)
+        }
         InstructionBox::SetKeyValue(variant_value) => {
             visitor.visit_set_key_value(authority, variant_value)
         }
@@ -275,6 +449,7 @@ pub fn visit_register<V: Visit + ?Sized>(
         RegisterBox::Asset(obj) => visitor.visit_register_asset(authority, obj),
         RegisterBox::Role(obj) => visitor.visit_register_role(authority, obj),
         RegisterBox::Trigger(obj) => visitor.visit_register_trigger(authority, obj),
+        RegisterBox::WasmCode(obj) => visitor.visit_register_wasm_code(authority, obj),
     }
 }
 
@@ -300,12 +475,14 @@ pub fn visit_mint<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi
     match isi {
         MintBox::Asset(obj) => visitor.visit_mint_asset_numeric(authority, obj),
         MintBox::TriggerRepetitions(obj) => visitor.visit_mint_trigger_repetitions(authority, obj),
+        MintBox::Nft(obj) => visitor.visit_mint_nft(authority, obj),
     }
 }
 
 pub fn visit_burn<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi: &BurnBox) {
     match isi {
         BurnBox::Asset(obj) => visitor.visit_burn_asset_numeric(authority, obj),
+        BurnBox::AssetAll(obj) => visitor.visit_burn_asset_all(authority, obj),
         BurnBox::TriggerRepetitions(obj) => visitor.visit_burn_trigger_repetitions(authority, obj),
     }
 }
@@ -322,7 +499,9 @@ pub fn visit_transfer<V: Visit + ?Sized>(
         }
         TransferBox::Asset(transfer_asset) => match transfer_asset {
             AssetTransferBox::Numeric(obj) => visitor.visit_transfer_asset_numeric(authority, obj),
+            AssetTransferBox::NumericAll(obj) => visitor.visit_transfer_asset_all(authority, obj),
             AssetTransferBox::Store(obj) => visitor.visit_transfer_asset_store(authority, obj),
+            AssetTransferBox::Nft(obj) => visitor.visit_transfer_nft(authority, obj),
         },
     }
 }
@@ -359,6 +538,64 @@ pub fn visit_remove_key_value<V: Visit + ?Sized>(
     }
 }
 
+pub fn visit_merge_key_values<V: Visit + ?Sized>(
+    visitor: &mut V,
+    authority: &AccountId,
+    isi: &MergeKeyValuesBox,
+) {
+    match isi {
+        MergeKeyValuesBox::Domain(obj) => visitor.visit_merge_domain_key_values(authority, obj),
+        MergeKeyValuesBox::Account(obj) => visitor.visit_merge_account_key_values(authority, obj),
+        MergeKeyValuesBox::AssetDefinition(obj) => {
+            visitor.visit_merge_asset_definition_key_values(authority, obj)
+        }
+        MergeKeyValuesBox::Asset(obj) => visitor.visit_merge_asset_key_values(authority, obj),
+        MergeKeyValuesBox::Trigger(obj) => visitor.visit_merge_trigger_key_values(authority, obj),
+    }
+}
+
+pub fn visit_increment_key_value<V: Visit + ?Sized>(
+    visitor: &mut V,
+    authority: &AccountId,
+    isi: &IncrementKeyValueBox,
+) {
+    match isi {
+        IncrementKeyValueBox::Domain(obj) => {
+            visitor.visit_increment_domain_key_value(authority, obj)
+        }
+        IncrementKeyValueBox::Account(obj) => {
+            visitor.visit_increment_account_key_value(authority, obj)
+        }
+        IncrementKeyValueBox::AssetDefinition(obj) => {
+            visitor.visit_increment_asset_definition_key_value(authority, obj)
+        }
+        IncrementKeyValueBox::Asset(obj) => visitor.visit_increment_asset_key_value(authority, obj),
+        IncrementKeyValueBox::Trigger(obj) => {
+            visitor.visit_increment_trigger_key_value(authority, obj)
+        }
+    }
+}
+
+pub fn visit_freeze<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi: &FreezeBox) {
+    match isi {
+        FreezeBox::AssetDefinition(obj) => visitor.visit_freeze_asset_definition(authority, obj),
+        FreezeBox::Asset(obj) => visitor.visit_freeze_asset(authority, obj),
+    }
+}
+
+pub fn visit_unfreeze<V: Visit + ?Sized>(
+    visitor: &mut V,
+    authority: &AccountId,
+    isi: &UnfreezeBox,
+) {
+    match isi {
+        UnfreezeBox::AssetDefinition(obj) => {
+            visitor.visit_unfreeze_asset_definition(authority, obj)
+        }
+        UnfreezeBox::Asset(obj) => visitor.visit_unfreeze_asset(authority, obj),
+    }
+}
+
 pub fn visit_grant<V: Visit + ?Sized>(visitor: &mut V, authority: &AccountId, isi: &GrantBox) {
     match isi {
         GrantBox::Permission(obj) => visitor.visit_grant_account_permission(authority, obj),
@@ -389,30 +626,70 @@ leaf_visitors! {
     visit_unregister_account(&Unregister<Account>),
     visit_set_account_key_value(&SetKeyValue<Account>),
     visit_remove_account_key_value(&RemoveKeyValue<Account>),
+    visit_merge_account_key_values(&MergeKeyValues<Account>),
+    visit_increment_account_key_value(&IncrementKeyValue<Account>),
     visit_register_asset(&Register<Asset>),
     visit_unregister_asset(&Unregister<Asset>),
     visit_mint_asset_numeric(&Mint<Numeric, Asset>),
     visit_burn_asset_numeric(&Burn<Numeric, Asset>),
+    visit_burn_asset_all(&Burn<All, Asset>),
     visit_transfer_asset_numeric(&Transfer<Asset, Numeric, Account>),
+    visit_transfer_asset_all(&Transfer<Asset, All, Account>),
     visit_transfer_asset_store(&Transfer<Asset, Metadata, Account>),
+    visit_mint_nft(&Mint<Name, Asset>),
+    visit_transfer_nft(&Transfer<Asset, Name, Account>),
     visit_set_asset_key_value(&SetKeyValue<Asset>),
     visit_remove_asset_key_value(&RemoveKeyValue<Asset>),
+    visit_merge_asset_key_values(&MergeKeyValues<Asset>),
+    visit_increment_asset_key_value(&IncrementKeyValue<Asset>),
+    visit_freeze_asset(&Freeze<Asset>),
+    visit_unfreeze_asset(&Unfreeze<Asset>),
     visit_set_trigger_key_value(&SetKeyValue<Trigger>),
     visit_remove_trigger_key_value(&RemoveKeyValue<Trigger>),
+    visit_merge_trigger_key_values(&MergeKeyValues<Trigger>),
+    visit_increment_trigger_key_value(&IncrementKeyValue<Trigger>),
     visit_register_asset_definition(&Register<AssetDefinition>),
     visit_unregister_asset_definition(&Unregister<AssetDefinition>),
     visit_transfer_asset_definition(&Transfer<Account, AssetDefinitionId, Account>),
     visit_set_asset_definition_key_value(&SetKeyValue<AssetDefinition>),
     visit_remove_asset_definition_key_value(&RemoveKeyValue<AssetDefinition>),
+    visit_merge_asset_definition_key_values(&MergeKeyValues<AssetDefinition>),
+    visit_increment_asset_definition_key_value(&IncrementKeyValue<AssetDefinition>),
+    visit_freeze_asset_definition(&Freeze<AssetDefinition>),
+    visit_unfreeze_asset_definition(&Unfreeze<AssetDefinition>),
     visit_register_domain(&Register<Domain>),
     visit_unregister_domain(&Unregister<Domain>),
     visit_transfer_domain(&Transfer<Account, DomainId, Account>),
     visit_set_domain_key_value(&SetKeyValue<Domain>),
     visit_remove_domain_key_value(&RemoveKeyValue<Domain>),
+    visit_merge_domain_key_values(&MergeKeyValues<Domain>),
+    visit_increment_domain_key_value(&IncrementKeyValue<Domain>),
+    visit_set_domain_metadata_limits(&SetDomainMetadataLimits),
+    visit_register_alias(&RegisterAlias),
+    visit_unregister_alias(&UnregisterAlias),
+    visit_set_key_value_with_expiry(&SetKeyValueWithExpiry),
+    visit_set_key_value_if(&SetKeyValueIf),
+    visit_swap(&Swap),
+    visit_transfer_many(&TransferMany),
+    visit_open_escrow(&OpenEscrow),
+    visit_release_escrow(&ReleaseEscrow),
+    visit_refund_escrow(&RefundEscrow),
+    visit_add_signatory(&AddSignatory),
+    visit_remove_signatory(&RemoveSignatory),
+    visit_set_signature_threshold(&SetSignatureThreshold),
+    visit_rotate_key(&RotateKey),
+    visit_set_recovery_policy(&SetRecoveryPolicy),
+    visit_recover_account(&RecoverAccount),
+    visit_finalize_recovery(&FinalizeRecovery),
+    visit_cancel_recovery(&CancelRecovery),
+    visit_disable_account(&DisableAccount),
+    visit_enable_account(&EnableAccount),
+    visit_set_strict_ordering(&SetStrictOrdering),
     visit_register_peer(&Register<Peer>),
     visit_unregister_peer(&Unregister<Peer>),
     visit_grant_account_permission(&Grant<Permission, Account>),
     visit_revoke_account_permission(&Revoke<Permission, Account>),
+    visit_grant_with_terms(&GrantWithTerms),
     visit_register_role(&Register<Role>),
     visit_unregister_role(&Unregister<Role>),
     visit_grant_account_role(&Grant<RoleId, Account>),
@@ -427,16 +704,26 @@ leaf_visitors! {
     visit_new_parameter(&NewParameter),
     visit_set_parameter(&SetParameter),
     visit_execute_trigger(&ExecuteTrigger),
+    visit_pause_trigger(&Pause),
+    visit_resume_trigger(&Resume),
+    visit_upgrade_trigger(&UpgradeTrigger),
+    visit_register_wasm_code(&Register<WasmCode>),
     visit_fail(&Fail),
     visit_log(&Log),
 
     // Query visitors
     visit_find_account_by_id(&FindAccountById),
+    visit_find_account_by_alias(&FindAccountByAlias),
     visit_find_account_key_value_by_id_and_key(&FindAccountKeyValueByIdAndKey),
+    visit_find_account_key_value_by_id_and_path(&FindAccountKeyValueByIdAndPath),
     visit_find_accounts_by_domain_id(&FindAccountsByDomainId),
+    visit_find_account_count_by_domain_id(&FindAccountCountByDomainId),
     visit_find_accounts_with_asset(&FindAccountsWithAsset),
+    visit_find_accounts_by_metadata_key_prefix(&FindAccountsByMetadataKeyPrefix),
+    visit_find_metadata_history(&FindMetadataHistory),
     visit_find_all_accounts(&FindAllAccounts),
     visit_find_all_active_trigger_ids(&FindAllActiveTriggerIds),
+    visit_find_all_active_triggers(&FindAllActiveTriggers),
     visit_find_all_assets(&FindAllAssets),
     visit_find_all_assets_definitions(&FindAllAssetsDefinitions),
     visit_find_all_block_headers(&FindAllBlockHeaders),
@@ -452,20 +739,31 @@ leaf_visitors! {
     visit_find_asset_definition_by_id(&FindAssetDefinitionById),
     visit_find_asset_definition_key_value_by_id_and_key(&FindAssetDefinitionKeyValueByIdAndKey),
     visit_find_asset_key_value_by_id_and_key(&FindAssetKeyValueByIdAndKey),
+    visit_find_asset_key_value_by_id_and_path(&FindAssetKeyValueByIdAndPath),
     visit_find_asset_quantity_by_id(&FindAssetQuantityById),
     visit_find_assets_by_account_id(&FindAssetsByAccountId),
     visit_find_assets_by_asset_definition_id(&FindAssetsByAssetDefinitionId),
     visit_find_assets_by_domain_id(&FindAssetsByDomainId),
     visit_find_assets_by_domain_id_and_asset_definition_id(&FindAssetsByDomainIdAndAssetDefinitionId),
+    visit_find_assets_by_metadata_key_prefix(&FindAssetsByMetadataKeyPrefix),
+    visit_find_nfts_by_account_id(&FindNftsByAccountId),
     visit_find_assets_by_name(&FindAssetsByName),
     visit_find_block_header_by_hash(&FindBlockHeaderByHash),
+    visit_find_block_by_hash(&FindBlockByHash),
+    visit_find_block_by_height(&FindBlockByHeight),
+    visit_find_blocks_in_range(&FindBlocksInRange),
     visit_find_domain_by_id(&FindDomainById),
+    visit_find_domain_descendants(&FindDomainDescendants),
     visit_find_domain_key_value_by_id_and_key(&FindDomainKeyValueByIdAndKey),
     visit_find_permissions_by_account_id(&FindPermissionsByAccountId),
+    visit_find_permission_grant_terms(&FindPermissionGrantTerms),
+    visit_find_grant_history_by_account_id(&FindGrantHistoryByAccountId),
     visit_find_role_by_role_id(&FindRoleByRoleId),
     visit_find_roles_by_account_id(&FindRolesByAccountId),
     visit_find_total_asset_quantity_by_asset_definition_id(&FindTotalAssetQuantityByAssetDefinitionId),
+    visit_find_asset_count_by_asset_definition_id(&FindAssetCountByAssetDefinitionId),
     visit_find_transaction_by_hash(&FindTransactionByHash),
+    visit_find_transaction_proof_by_hash(&FindTransactionProofByHash),
     visit_find_transactions_by_account_id(&FindTransactionsByAccountId),
     visit_find_trigger_by_id(&FindTriggerById),
     visit_find_trigger_key_value_by_id_and_key(&FindTriggerKeyValueByIdAndKey),
@@ -16,6 +16,7 @@ use parity_scale_codec::Encode as _;
 use tokio::{
     net::{TcpListener, TcpStream},
     sync::{mpsc, watch},
+    time::Instant,
 };
 
 use crate::{
@@ -29,6 +30,13 @@ use crate::{
     unbounded_with_len, Broadcast, Error, NetworkMessage, OnlinePeers, Post, UpdateTopology,
 };
 
+/// Delay before reconnecting to a peer after the first failed attempt
+const MIN_RECONNECT_DELAY: Duration = Duration::from_millis(100);
+/// Upper bound of the delay between attempts to connect to a peer
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+/// How long a connection has to stay up for the delay of reconnecting to the peer to be reset
+const MIN_STABLE_CONNECTION: Duration = Duration::from_secs(10);
+
 /// [`NetworkBase`] actor handle.
 // NOTE: channels are unbounded in order to break communication cycle deadlock.
 // Unbounded channels are ok here because messages frequency is either configurable (and relatively low)
@@ -40,6 +48,8 @@ pub struct NetworkBaseHandle<T: Pload, K: Kex, E: Enc> {
     subscribe_to_peers_messages_sender: mpsc::UnboundedSender<mpsc::Sender<T>>,
     /// Receiver of `OnlinePeer` message
     online_peers_receiver: watch::Receiver<OnlinePeers>,
+    /// Public key of this peer
+    public_key: PublicKey,
     /// [`UpdateTopology`] message sender
     update_topology_sender: mpsc::UnboundedSender<UpdateTopology>,
     /// Sender of [`NetworkMessage`] message
@@ -55,6 +65,7 @@ impl<T: Pload, K: Kex, E: Enc> Clone for NetworkBaseHandle<T, K, E> {
         Self {
             subscribe_to_peers_messages_sender: self.subscribe_to_peers_messages_sender.clone(),
             online_peers_receiver: self.online_peers_receiver.clone(),
+            public_key: self.public_key.clone(),
             update_topology_sender: self.update_topology_sender.clone(),
             network_message_sender: self.network_message_sender.clone(),
             _key_exchange: core::marker::PhantomData::<K>,
@@ -87,11 +98,13 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             unbounded_with_len::unbounded_channel();
         let (peer_message_sender, peer_message_receiver) = mpsc::channel(1);
         let (service_message_sender, service_message_receiver) = mpsc::channel(1);
+        let public_key = key_pair.public_key().clone();
         let network = NetworkBase {
             listen_addr: listen_addr.into_value(),
             listener,
             peers: HashMap::new(),
             connecting_peers: HashMap::new(),
+            reconnect_backoff: HashMap::new(),
             key_pair,
             subscribers_to_peers_messages: Vec::new(),
             subscribe_to_peers_messages_receiver,
@@ -112,6 +125,7 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
         Ok(Self {
             subscribe_to_peers_messages_sender,
             online_peers_receiver,
+            public_key,
             update_topology_sender,
             network_message_sender,
             _key_exchange: core::marker::PhantomData,
@@ -149,6 +163,11 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             .expect("NetworkBase must accept messages until there is at least one handle to it")
     }
 
+    /// Public key identifying this peer in the network
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
     /// Receive latest update of [`OnlinePeers`]
     pub fn online_peers<P>(&self, f: impl FnOnce(&OnlinePeers) -> P) -> P {
         f(&self.online_peers_receiver.borrow())
@@ -175,6 +194,8 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     peers: HashMap<PublicKey, RefPeer<T>>,
     /// [`Peer`]s in process of being connected.
     connecting_peers: HashMap<ConnectionId, PublicKey>,
+    /// Delays of reconnection to [`Peer`]s which couldn't be connected or didn't stay connected
+    reconnect_backoff: HashMap<PublicKey, ReconnectBackoff>,
     /// [`TcpListener`] that is accepting [`Peer`]s' connections
     listener: TcpListener,
     /// Our app-level key pair
@@ -321,8 +342,12 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                         .connecting_peers
                         .values()
                         .any(|public_key| peer.public_key() == public_key)
-                    && *is_active)
-                    .then_some(peer)
+                    && *is_active
+                    && self
+                        .reconnect_backoff
+                        .get(peer.public_key())
+                        .map_or(true, ReconnectBackoff::is_elapsed))
+                .then_some(peer)
             })
             .cloned()
             .collect::<Vec<_>>();
@@ -342,6 +367,12 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         for public_key in to_disconnect {
             self.disconnect_peer(&public_key)
         }
+
+        self.reconnect_backoff.retain(|public_key, _| {
+            self.current_topology
+                .keys()
+                .any(|peer_id| peer_id.public_key() == public_key)
+        });
     }
 
     fn connect_peer(&mut self, peer: &PeerId) {
@@ -416,17 +447,35 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             disambiguator,
         };
         let _ = peer_message_sender.send(self.peer_message_sender.clone());
+        self.reconnect_backoff
+            .entry(peer_id.public_key().clone())
+            .or_default()
+            .connected();
         self.peers.insert(peer_id.public_key().clone(), ref_peer);
         Self::add_online_peer(&self.online_peers_sender, peer_id);
     }
 
     fn peer_terminated(&mut self, Terminated { peer_id, conn_id }: Terminated) {
-        self.connecting_peers.remove(&conn_id);
+        if let Some(public_key) = self.connecting_peers.remove(&conn_id) {
+            // Connection attempt failed, so the next one is delayed
+            let backoff = self.reconnect_backoff.entry(public_key).or_default();
+            backoff.fail();
+            iroha_logger::debug!(
+                conn_id,
+                attempts = backoff.failed_attempts,
+                "Failed to connect to peer"
+            );
+        }
         if let Some(peer_id) = peer_id {
             if let Some(peer) = self.peers.get(&peer_id.public_key) {
                 if peer.conn_id == conn_id {
                     iroha_logger::debug!(conn_id, peer=%peer_id, "Peer terminated");
                     self.peers.remove(&peer_id.public_key);
+                    // Peers dropping the connection right after the handshake are redialed with a delay
+                    self.reconnect_backoff
+                        .entry(peer_id.public_key().clone())
+                        .or_default()
+                        .disconnected();
                     Self::remove_online_peer(&self.online_peers_sender, &peer_id);
                 }
             }
@@ -516,6 +565,58 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
     }
 }
 
+/// Exponentially growing delay between attempts to connect to a peer.
+#[derive(Debug, Clone, Copy)]
+struct ReconnectBackoff {
+    /// Number of consecutive failed attempts
+    failed_attempts: u32,
+    /// Earliest time of the next attempt
+    next_attempt_at: Instant,
+    /// Time the current connection was established, if the peer is connected
+    connected_at: Option<Instant>,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self {
+            failed_attempts: 0,
+            next_attempt_at: Instant::now(),
+            connected_at: None,
+        }
+    }
+}
+
+impl ReconnectBackoff {
+    fn connected(&mut self) {
+        self.connected_at = Some(Instant::now());
+    }
+
+    /// Reset the delay if the connection was stable, otherwise count it as a failed attempt.
+    fn disconnected(&mut self) {
+        match self.connected_at.take() {
+            Some(connected_at) if connected_at.elapsed() >= MIN_STABLE_CONNECTION => {
+                *self = Self::default();
+            }
+            _ => self.fail(),
+        }
+    }
+
+    fn fail(&mut self) {
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+        self.next_attempt_at = Instant::now() + self.delay();
+    }
+
+    fn delay(&self) -> Duration {
+        MIN_RECONNECT_DELAY
+            .saturating_mul(2_u32.saturating_pow(self.failed_attempts.saturating_sub(1)))
+            .min(MAX_RECONNECT_DELAY)
+    }
+
+    fn is_elapsed(&self) -> bool {
+        self.next_attempt_at <= Instant::now()
+    }
+}
+
 pub mod message {
     //! Module for network messages
 
@@ -575,3 +676,50 @@ struct RefPeer<T: Pload> {
     /// It's equal for both peers and when peer receive connection for peer already present in peers set it just select connection with higher value.
     disambiguator: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backoff(failed_attempts: u32) -> ReconnectBackoff {
+        ReconnectBackoff {
+            failed_attempts,
+            ..ReconnectBackoff::default()
+        }
+    }
+
+    #[test]
+    fn reconnect_delay_doubles() {
+        assert_eq!(backoff(1).delay(), Duration::from_millis(100));
+        assert_eq!(backoff(2).delay(), Duration::from_millis(200));
+        assert_eq!(backoff(3).delay(), Duration::from_millis(400));
+        assert_eq!(backoff(6).delay(), Duration::from_millis(3200));
+    }
+
+    #[test]
+    fn reconnect_delay_is_capped() {
+        for failed_attempts in [7, 32, 33, 64, u32::MAX] {
+            assert_eq!(backoff(failed_attempts).delay(), MAX_RECONNECT_DELAY);
+        }
+    }
+
+    #[test]
+    fn reconnect_delay_is_reset_only_after_stable_connection() {
+        let mut backoff = backoff(0);
+        for _ in 0..5 {
+            backoff.fail();
+        }
+        assert_eq!(backoff.delay(), Duration::from_millis(1600));
+
+        // Connection dropped right after the handshake
+        backoff.connected();
+        backoff.disconnected();
+        assert_eq!(backoff.failed_attempts, 6);
+        assert!(!backoff.is_elapsed());
+
+        backoff.connected_at = Instant::now().checked_sub(MIN_STABLE_CONNECTION);
+        backoff.disconnected();
+        assert_eq!(backoff.failed_attempts, 0);
+        assert!(backoff.is_elapsed());
+    }
+}
@@ -195,6 +195,16 @@ impl Numeric {
             .and_then(|inner| inner.is_sign_positive().then_some(Self { inner }))
     }
 
+    /// Checked multiplication
+    ///
+    /// # Errors
+    /// In case of overflow
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.inner
+            .checked_mul(other.inner)
+            .map(|inner| Self { inner })
+    }
+
     /// Convert [`Numeric`] to [`f64`] with possible loss in precision
     pub fn to_f64(self) -> f64 {
         self.inner.to_f64().expect("never fails")
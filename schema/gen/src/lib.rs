@@ -3,8 +3,8 @@
 //! types are included in the schema.
 use iroha_crypto::MerkleTree;
 use iroha_data_model::{
-    block::stream::{BlockMessage, BlockSubscriptionRequest},
-    query::QueryOutputBox,
+    block::stream::{BlockMessage, BlockSubscriptionRequest, BlockSummaryMessage},
+    query::{stream::QueryStreamMessage, QueryOutputBox},
     BatchedResponse,
 };
 use iroha_schema::prelude::*;
@@ -43,14 +43,21 @@ pub fn build_schemas() -> MetaMap {
         // Query + response
         SignedQuery,
         BatchedResponse<QueryOutputBox>,
+        SignedQueryResponse,
 
         // Event stream
         EventMessage,
         EventSubscriptionRequest,
+        AcknowledgedEventMessage,
+        EventAcknowledgement,
 
         // Block stream
         BlockMessage,
         BlockSubscriptionRequest,
+        BlockSummaryMessage,
+
+        // Query stream
+        QueryStreamMessage,
 
         // Never referenced, but present in type signature. Like `PhantomData<X>`
         MerkleTree<SignedTransaction>,
@@ -59,14 +66,24 @@ pub fn build_schemas() -> MetaMap {
 
 types!(
     Account,
+    AccountAlias,
+    AccountAliasChanged,
     AccountEvent,
     AccountEventFilter,
     AccountEventSet,
+    AccountGrantAudit,
+    AccountGrantChange,
     AccountId,
+    AccountMetadataCondition,
     AccountPermissionChanged,
     AccountRoleChanged,
+    AcknowledgedDelivery,
+    AcknowledgedEventMessage,
     Action,
+    AddSignatory,
     Algorithm,
+    All,
+    Arc<BTreeMap<Name, MetadataValueBox>>,
     Asset,
     AssetChanged,
     AssetDefinition,
@@ -85,12 +102,18 @@ types!(
     AssetValueType,
     AtIndex,
     BTreeMap<AccountId, Account>,
+    BTreeMap<AccountId, Numeric>,
+    BTreeMap<AccountId, u64>,
     BTreeMap<AssetDefinitionId, AssetDefinition>,
     BTreeMap<AssetDefinitionId, Numeric>,
     BTreeMap<AssetId, Asset>,
+    BTreeMap<InstructionType, Numeric>,
+    BTreeMap<Name, AccountId>,
     BTreeMap<Name, MetadataValueBox>,
     BTreeSet<Permission>,
+    BTreeSet<AccountId>,
     BTreeSet<PermissionId>,
+    BTreeSet<PublicKey>,
     BTreeSet<SignatureWrapperOf<BlockPayload>>,
     BatchedResponse<QueryOutputBox>,
     BatchedResponseV1<QueryOutputBox>,
@@ -102,12 +125,20 @@ types!(
     BlockRejectionReason,
     BlockStatus,
     BlockSubscriptionRequest,
+    BlockSummary,
+    BlockSummaryMessage,
+    Box<DataEventFilter>,
+    Box<EventFilterBox>,
     Box<GenericPredicateBox<QueryOutputPredicate>>,
     Box<QueryOutputPredicate>,
     Box<TransactionRejectionReason>,
     Burn<u32, Trigger>,
+    Burn<All, Asset>,
     Burn<Numeric, Asset>,
     BurnBox,
+    CanAccountExecute,
+    CancelRecovery,
+    CancelTransaction,
     ChainId,
     CommittedTransaction,
     ConfigurationEvent,
@@ -119,21 +150,32 @@ types!(
     ClientQueryPayload,
     DataEvent,
     DataEventFilter,
+    DisableAccount,
     Domain,
     DomainEvent,
     DomainEventFilter,
     DomainEventSet,
     DomainId,
+    DomainMetadataLimits,
+    DomainMetadataLimitsChanged,
     DomainOwnerChanged,
     Duration,
+    EnableAccount,
+    Escrow,
+    EscrowCondition,
+    EscrowId,
+    EventAcknowledgement,
     EventBox,
     EventMessage,
+    EventOverflowPolicy,
     EventSubscriptionRequest,
     Executable,
     ExecuteTrigger,
     ExecuteTriggerEvent,
     ExecuteTriggerEventFilter,
+    ExecutionLimits,
     ExecutionTime,
+    ExecutionVerdict,
     Executor,
     ExecutorEvent,
     ExecutorEventFilter,
@@ -141,14 +183,20 @@ types!(
     ExecutorUpgrade,
     ExecutorDataModel,
     Fail,
+    FinalizeRecovery,
     EventFilterBox,
     FetchSize,
+    FindAccountByAlias,
     FindAccountById,
+    FindAccountCountByDomainId,
     FindAccountKeyValueByIdAndKey,
+    FindAccountKeyValueByIdAndPath,
     FindAccountsByDomainId,
+    FindAccountsByMetadataKeyPrefix,
     FindAccountsWithAsset,
     FindAllAccounts,
     FindAllActiveTriggerIds,
+    FindAllActiveTriggers,
     FindAllAssets,
     FindAllAssetsDefinitions,
     FindAllBlockHeaders,
@@ -160,40 +208,64 @@ types!(
     FindAllRoles,
     FindAllTransactions,
     FindAssetById,
+    FindAssetCountByAssetDefinitionId,
     FindAssetDefinitionById,
     FindAssetDefinitionKeyValueByIdAndKey,
     FindAssetKeyValueByIdAndKey,
+    FindAssetKeyValueByIdAndPath,
     FindAssetQuantityById,
     FindAssetsByAccountId,
     FindAssetsByAssetDefinitionId,
     FindAssetsByDomainId,
     FindAssetsByDomainIdAndAssetDefinitionId,
+    FindAssetsByMetadataKeyPrefix,
     FindAssetsByName,
+    FindBlockByHash,
+    FindBlockByHeight,
     FindBlockHeaderByHash,
+    FindBlocksInRange,
     FindDomainById,
+    FindDomainDescendants,
     FindDomainKeyValueByIdAndKey,
     FindError,
     FindExecutorDataModel,
+    FindGrantHistoryByAccountId,
+    FindMetadataHistory,
+    FindNftsByAccountId,
+    FindPermissionGrantTerms,
     FindPermissionsByAccountId,
     FindRoleByRoleId,
     FindRolesByAccountId,
     FindTotalAssetQuantityByAssetDefinitionId,
     FindTransactionByHash,
+    FindTransactionProofByHash,
     FindTransactionsByAccountId,
     FindTriggerById,
     FindTriggerKeyValueByIdAndKey,
     FindTriggersByDomainId,
     ForwardCursor,
+    Freeze<Asset>,
+    Freeze<AssetDefinition>,
+    FreezeBox,
     Grant<Permission, Account>,
     Grant<Permission, Role>,
     Grant<RoleId, Account>,
     GrantBox,
+    GrantWithTerms,
     Hash,
+    HashOf<BatchedResponse<QueryOutputBox>>,
     HashOf<MerkleTree<SignedTransaction>>,
     HashOf<SignedBlock>,
     HashOf<SignedTransaction>,
+    HashOf<WasmSmartContract>,
     IdBox,
     IdentifiableBox,
+    IncrementKeyValue<Account>,
+    IncrementKeyValue<Asset>,
+    IncrementKeyValue<AssetDefinition>,
+    IncrementKeyValue<Domain>,
+    IncrementKeyValue<Trigger>,
+    IncrementKeyValueBox,
     InstructionBox,
     InstructionEvaluationError,
     InstructionExecutionError,
@@ -207,17 +279,27 @@ types!(
     Level,
     Log,
     MathError,
+    MerkleProof<SignedTransaction>,
     MerkleTree<SignedTransaction>,
     Metadata,
+    MergeKeyValues<Account>,
+    MergeKeyValues<Asset>,
+    MergeKeyValues<AssetDefinition>,
+    MergeKeyValues<Domain>,
+    MergeKeyValues<Trigger>,
+    MergeKeyValuesBox,
+    MergePolicy,
     MetadataChanged<AccountId>,
     MetadataChanged<AssetDefinitionId>,
     MetadataChanged<AssetId>,
     MetadataChanged<DomainId>,
     MetadataChanged<TriggerId>,
     MetadataError,
+    MetadataExpiry,
     MetadataLimits,
     MetadataValueBox,
     Mint<u32, Trigger>,
+    Mint<Name, Asset>,
     Mint<Numeric, Asset>,
     MintBox,
     MintabilityError,
@@ -230,10 +312,13 @@ types!(
     NewParameter,
     NewRole,
     NonTrivial<PredicateBox>,
+    NonZeroU16,
     NonZeroU32,
     NonZeroU64,
     Numeric,
+    NumericDelta,
     NumericSpec,
+    OpenEscrow,
     Option<u32>,
     Option<u64>,
     Option<AccountId>,
@@ -246,31 +331,43 @@ types!(
     Option<HashOf<SignedBlock>>,
     Option<HashOf<SignedTransaction>>,
     Option<IpfsPath>,
+    Option<MetadataExpiry>,
+    Option<MetadataLimits>,
+    Option<MetadataValueBox>,
     Option<Name>,
+    Option<Numeric>,
     Option<NonZeroU32>,
     Option<NonZeroU64>,
     Option<Option<u64>>,
     Option<ParameterId>,
     Option<PeerId>,
+    Option<RecoveryPolicy>,
     Option<RoleId>,
+    Option<SignatureCondition>,
+    Option<SignedAcknowledgedDelivery>,
     Option<String>,
     Option<TimeInterval>,
     Option<TransactionRejectionReason>,
     Option<TransactionStatus>,
+    Option<ValidationFail>,
     Option<TriggerCompletedOutcomeType>,
     Option<TriggerId>,
+    OriginIdPattern,
     Pagination,
     Parameter,
     ParameterId,
     ParameterValueBox,
+    Pause,
     Peer,
     PeerEvent,
     PeerEventFilter,
     PeerEventSet,
     PeerId,
+    PendingRecovery,
     PermissionId,
     RolePermissionChanged,
     Permission,
+    PermissionGrantTerms,
     PipelineEventBox,
     PipelineEventFilterBox,
     PredicateBox,
@@ -279,6 +376,11 @@ types!(
     QueryExecutionFail,
     QueryOutputBox,
     QueryOutputPredicate,
+    QueryResponseAttestation,
+    QueryStreamMessage,
+    QueueStatus,
+    RecoverAccount,
+    RecoveryPolicy,
     Register<Account>,
     Register<Asset>,
     Register<AssetDefinition>,
@@ -286,15 +388,21 @@ types!(
     Register<Peer>,
     Register<Role>,
     Register<Trigger>,
+    Register<WasmCode>,
+    RefundEscrow,
+    RegisterAlias,
     RegisterBox,
+    ReleaseEscrow,
     RemoveKeyValue<Account>,
     RemoveKeyValue<Asset>,
     RemoveKeyValue<AssetDefinition>,
     RemoveKeyValue<Domain>,
     RemoveKeyValue<Trigger>,
     RemoveKeyValueBox,
+    RemoveSignatory,
     Repeats,
     RepetitionError,
+    Resume,
     Revoke<Permission, Account>,
     Revoke<Permission, Role>,
     Revoke<RoleId, Account>,
@@ -304,6 +412,7 @@ types!(
     RoleEventFilter,
     RoleEventSet,
     RoleId,
+    RotateKey,
     SemiInterval<Numeric>,
     SemiInterval<u128>,
     SemiRange,
@@ -312,46 +421,71 @@ types!(
     SetKeyValue<AssetDefinition>,
     SetKeyValue<Domain>,
     SetKeyValue<Trigger>,
+    SetDomainMetadataLimits,
     SetKeyValueBox,
+    SetKeyValueIf,
+    SetKeyValueWithExpiry,
     SetParameter,
+    SetRecoveryPolicy,
+    SetSignatureThreshold,
+    SetStrictOrdering,
     Signature,
+    SignatureCondition,
+    SignatureOf<AcknowledgedDelivery>,
     SignatureOf<BlockPayload>,
+    SignatureOf<CanAccountExecute>,
     SignatureOf<ClientQueryPayload>,
+    SignatureOf<HashOf<SignedTransaction>>,
+    SignatureOf<QueryResponseAttestation>,
     SignatureOf<TransactionPayload>,
     SignatureWrapperOf<BlockPayload>,
     SignaturesOf<BlockPayload>,
+    SignedAcknowledgedDelivery,
     SignedBlock,
     SignedBlockV1,
+    SignedCanAccountExecute,
     SignedQuery,
+    SignedQueryResponse,
     SignedQueryV1,
     SignedTransaction,
-    SignedTransactionV1,
+    SignedTransactionV2,
     SizeError,
     SocketAddr,
     SocketAddrHost,
     SocketAddrV4,
     SocketAddrV6,
+    SortOrder,
     Sorting,
     String,
     StringPredicate,
+    Swap,
     JsonString,
     TimeEvent,
     TimeEventFilter,
     TimeInterval,
     TimeSchedule,
+    TransactionChunk,
     TransactionEvent,
     TransactionEventFilter,
+    TransactionFees,
+    TransactionInclusionProof,
     TransactionLimitError,
     TransactionLimits,
     TransactionPayload,
     TransactionQueryOutput,
     TransactionRejectionReason,
+    TransactionSimulation,
     TransactionStatus,
+    TransactionValidationEvent,
+    TransactionValidationEventFilter,
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Account, DomainId, Account>,
+    Transfer<Asset, All, Account>,
     Transfer<Asset, Metadata, Account>,
+    Transfer<Asset, Name, Account>,
     Transfer<Asset, Numeric, Account>,
     TransferBox,
+    TransferMany,
     Trigger,
     TriggerCompletedEvent,
     TriggerCompletedEventFilter,
@@ -365,6 +499,10 @@ types!(
     TriggeringEventFilterBox,
     TypeError,
     UniqueVec<PeerId>,
+    Unfreeze<Asset>,
+    Unfreeze<AssetDefinition>,
+    UnfreezeBox,
+    UnlimitedMetadata,
     Unregister<Account>,
     Unregister<Asset>,
     Unregister<AssetDefinition>,
@@ -372,18 +510,32 @@ types!(
     Unregister<Peer>,
     Unregister<Role>,
     Unregister<Trigger>,
+    UnregisterAlias,
     UnregisterBox,
     Upgrade,
+    UpgradeTrigger,
     ValidationFail,
+    ValidationTriggerFail,
+    ValueOfKey,
     Vec<CommittedTransaction>,
+    Vec<DataEventFilter>,
+    Vec<Duration>,
     Vec<EventBox>,
     Vec<EventFilterBox>,
+    Vec<HashOf<SignedTransaction>>,
     Vec<InstructionBox>,
+    Vec<Log>,
     Vec<MetadataValueBox>,
+    Vec<Name>,
+    Vec<Option<HashOf<SignedTransaction>>>,
     Vec<PeerId>,
+    Vec<PendingRecovery>,
+    Vec<Permission>,
     Vec<PredicateBox>,
     Vec<QueryOutputBox>,
+    Vec<SignatureOf<TransactionPayload>>,
     Vec<u8>,
+    WasmCode,
     WasmExecutionFail,
     WasmSmartContract,
     [u16; 8],
@@ -400,9 +552,10 @@ types!(
 pub mod complete_data_model {
     //! Complete set of types participating in the schema
 
-    pub use core::num::{NonZeroU32, NonZeroU64};
+    pub use core::num::{NonZeroU16, NonZeroU32, NonZeroU64};
     pub use std::{
         collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+        sync::Arc,
         time::Duration,
     };
 
@@ -412,7 +565,7 @@ pub mod complete_data_model {
         asset::NewAssetDefinition,
         block::{
             error::BlockRejectionReason,
-            stream::{BlockMessage, BlockSubscriptionRequest},
+            stream::{BlockMessage, BlockSubscriptionRequest, BlockSummary, BlockSummaryMessage},
             BlockHeader, BlockPayload, SignedBlock, SignedBlockV1,
         },
         domain::NewDomain,
@@ -434,13 +587,14 @@ pub mod complete_data_model {
             predicate::{
                 numerical::{SemiInterval, SemiRange},
                 string::StringPredicate,
-                value::{AtIndex, Container, QueryOutputPredicate},
+                value::{AtIndex, Container, QueryOutputPredicate, ValueOfKey},
                 GenericPredicateBox, NonTrivial, PredicateBox,
             },
-            ForwardCursor, Pagination, QueryOutputBox, Sorting,
+            stream::QueryStreamMessage,
+            ForwardCursor, Pagination, QueryOutputBox, SortOrder, Sorting,
         },
         transaction::{
-            error::TransactionLimitError, SignedTransactionV1, TransactionLimits,
+            error::TransactionLimitError, SignedTransactionV2, TransactionFees, TransactionLimits,
             TransactionPayload,
         },
         BatchedResponse, BatchedResponseV1, JsonString, Level,
@@ -565,6 +719,6 @@ mod tests {
     fn no_schema_type_overlap() {
         let mut schemas = super::build_schemas();
         <Vec<PublicKey>>::update_schema_map(&mut schemas);
-        <BTreeSet<SignedTransactionV1>>::update_schema_map(&mut schemas);
+        <BTreeSet<SignedTransactionV2>>::update_schema_map(&mut schemas);
     }
 }
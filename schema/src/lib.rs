@@ -12,6 +12,7 @@ use alloc::{
     collections::{btree_map, btree_set},
     format,
     string::String,
+    sync::Arc,
     vec,
     vec::Vec,
 };
@@ -421,6 +422,28 @@ impl<T: IntoSchema> IntoSchema for Box<T> {
     }
 }
 
+impl<T: TypeId> TypeId for Arc<T> {
+    fn id() -> String {
+        format!("Arc<{}>", T::id())
+    }
+}
+impl<T: IntoSchema> IntoSchema for Arc<T> {
+    fn type_name() -> String {
+        T::type_name()
+    }
+    fn update_schema_map(map: &mut MetaMap) {
+        if !map.contains_key::<Self>() {
+            if !map.contains_key::<T>() {
+                T::update_schema_map(map);
+            }
+
+            if let Some(schema) = map.get::<T>() {
+                map.insert::<Self>(schema.clone());
+            }
+        }
+    }
+}
+
 impl TypeId for Box<str> {
     fn id() -> String {
         "String".to_owned()
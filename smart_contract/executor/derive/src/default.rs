@@ -123,27 +123,67 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_transfer_domain(operation: &Transfer<Account, DomainId, Account>)",
         "fn visit_set_domain_key_value(operation: &SetKeyValue<Domain>)",
         "fn visit_remove_domain_key_value(operation: &RemoveKeyValue<Domain>)",
+        "fn visit_merge_domain_key_values(operation: &MergeKeyValues<Domain>)",
+        "fn visit_increment_domain_key_value(operation: &IncrementKeyValue<Domain>)",
+        "fn visit_set_domain_metadata_limits(operation: &SetDomainMetadataLimits)",
+        "fn visit_register_alias(operation: &RegisterAlias)",
+        "fn visit_unregister_alias(operation: &UnregisterAlias)",
+        "fn visit_set_key_value_with_expiry(operation: &SetKeyValueWithExpiry)",
+        "fn visit_set_key_value_if(operation: &SetKeyValueIf)",
+        "fn visit_swap(operation: &Swap)",
+        "fn visit_transfer_many(operation: &TransferMany)",
+        "fn visit_open_escrow(operation: &OpenEscrow)",
+        "fn visit_release_escrow(operation: &ReleaseEscrow)",
+        "fn visit_refund_escrow(operation: &RefundEscrow)",
+        "fn visit_add_signatory(operation: &AddSignatory)",
+        "fn visit_remove_signatory(operation: &RemoveSignatory)",
+        "fn visit_set_signature_threshold(operation: &SetSignatureThreshold)",
+        "fn visit_rotate_key(operation: &RotateKey)",
+        "fn visit_set_recovery_policy(operation: &SetRecoveryPolicy)",
+        "fn visit_recover_account(operation: &RecoverAccount)",
+        "fn visit_finalize_recovery(operation: &FinalizeRecovery)",
+        "fn visit_cancel_recovery(operation: &CancelRecovery)",
+        "fn visit_disable_account(operation: &DisableAccount)",
+        "fn visit_enable_account(operation: &EnableAccount)",
+        "fn visit_set_strict_ordering(operation: &SetStrictOrdering)",
         "fn visit_register_account(operation: &Register<Account>)",
         "fn visit_unregister_account(operation: &Unregister<Account>)",
         "fn visit_set_account_key_value(operation: &SetKeyValue<Account>)",
         "fn visit_remove_account_key_value(operation: &RemoveKeyValue<Account>)",
+        "fn visit_merge_account_key_values(operation: &MergeKeyValues<Account>)",
+        "fn visit_increment_account_key_value(operation: &IncrementKeyValue<Account>)",
         "fn visit_register_asset(operation: &Register<Asset>)",
         "fn visit_unregister_asset(operation: &Unregister<Asset>)",
         "fn visit_mint_asset_numeric(operation: &Mint<Numeric, Asset>)",
         "fn visit_burn_asset_numeric(operation: &Burn<Numeric, Asset>)",
+        "fn visit_burn_asset_all(operation: &Burn<All, Asset>)",
         "fn visit_transfer_asset_numeric(operation: &Transfer<Asset, Numeric, Account>)",
+        "fn visit_transfer_asset_all(operation: &Transfer<Asset, All, Account>)",
         "fn visit_transfer_asset_store(operation: &Transfer<Asset, Metadata, Account>)",
+        "fn visit_mint_nft(operation: &Mint<Name, Asset>)",
+        "fn visit_transfer_nft(operation: &Transfer<Asset, Name, Account>)",
         "fn visit_set_asset_key_value(operation: &SetKeyValue<Asset>)",
         "fn visit_remove_asset_key_value(operation: &RemoveKeyValue<Asset>)",
+        "fn visit_merge_asset_key_values(operation: &MergeKeyValues<Asset>)",
+        "fn visit_increment_asset_key_value(operation: &IncrementKeyValue<Asset>)",
+        "fn visit_freeze_asset(operation: &Freeze<Asset>)",
+        "fn visit_unfreeze_asset(operation: &Unfreeze<Asset>)",
         "fn visit_set_trigger_key_value(operation: &SetKeyValue<Trigger>)",
         "fn visit_remove_trigger_key_value(operation: &RemoveKeyValue<Trigger>)",
+        "fn visit_merge_trigger_key_values(operation: &MergeKeyValues<Trigger>)",
+        "fn visit_increment_trigger_key_value(operation: &IncrementKeyValue<Trigger>)",
         "fn visit_register_asset_definition(operation: &Register<AssetDefinition>)",
         "fn visit_unregister_asset_definition(operation: &Unregister<AssetDefinition>)",
         "fn visit_transfer_asset_definition(operation: &Transfer<Account, AssetDefinitionId, Account>)",
         "fn visit_set_asset_definition_key_value(operation: &SetKeyValue<AssetDefinition>)",
         "fn visit_remove_asset_definition_key_value(operation: &RemoveKeyValue<AssetDefinition>)",
+        "fn visit_merge_asset_definition_key_values(operation: &MergeKeyValues<AssetDefinition>)",
+        "fn visit_increment_asset_definition_key_value(operation: &IncrementKeyValue<AssetDefinition>)",
+        "fn visit_freeze_asset_definition(operation: &Freeze<AssetDefinition>)",
+        "fn visit_unfreeze_asset_definition(operation: &Unfreeze<AssetDefinition>)",
         "fn visit_grant_account_permission(operation: &Grant<Permission, Account>)",
         "fn visit_revoke_account_permission(operation: &Revoke<Permission, Account>)",
+        "fn visit_grant_with_terms(operation: &GrantWithTerms)",
         "fn visit_register_role(operation: &Register<Role>)",
         "fn visit_unregister_role(operation: &Unregister<Role>)",
         "fn visit_grant_account_role(operation: &Grant<RoleId, Account>)",
@@ -155,11 +195,47 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_mint_trigger_repetitions(operation: &Mint<u32, Trigger>)",
         "fn visit_burn_trigger_repetitions(operation: &Burn<u32, Trigger>)",
         "fn visit_execute_trigger(operation: &ExecuteTrigger)",
+        "fn visit_pause_trigger(operation: &Pause)",
+        "fn visit_resume_trigger(operation: &Resume)",
+        "fn visit_upgrade_trigger(operation: &UpgradeTrigger)",
+        "fn visit_register_wasm_code(operation: &Register<WasmCode>)",
         "fn visit_set_parameter(operation: &SetParameter)",
         "fn visit_new_parameter(operation: &NewParameter)",
         "fn visit_upgrade(operation: &Upgrade)",
         "fn visit_log(operation: &Log)",
         "fn visit_fail(operation: &Fail)",
+        "fn visit_find_account_by_id(operation: &FindAccountById)",
+        "fn visit_find_account_key_value_by_id_and_key(operation: &FindAccountKeyValueByIdAndKey)",
+        "fn visit_find_account_key_value_by_id_and_path(operation: &FindAccountKeyValueByIdAndPath)",
+        "fn visit_find_assets_by_account_id(operation: &FindAssetsByAccountId)",
+        "fn visit_find_permissions_by_account_id(operation: &FindPermissionsByAccountId)",
+        "fn visit_find_permission_grant_terms(operation: &FindPermissionGrantTerms)",
+        "fn visit_find_grant_history_by_account_id(operation: &FindGrantHistoryByAccountId)",
+        "fn visit_find_roles_by_account_id(operation: &FindRolesByAccountId)",
+        "fn visit_find_transactions_by_account_id(operation: &FindTransactionsByAccountId)",
+        "fn visit_find_asset_by_id(operation: &FindAssetById)",
+        "fn visit_find_asset_quantity_by_id(operation: &FindAssetQuantityById)",
+        "fn visit_find_asset_key_value_by_id_and_key(operation: &FindAssetKeyValueByIdAndKey)",
+        "fn visit_find_asset_key_value_by_id_and_path(operation: &FindAssetKeyValueByIdAndPath)",
+        "fn visit_find_assets_by_domain_id(operation: &FindAssetsByDomainId)",
+        "fn visit_find_assets_by_domain_id_and_asset_definition_id(operation: &FindAssetsByDomainIdAndAssetDefinitionId)",
+        "fn visit_find_nfts_by_account_id(operation: &FindNftsByAccountId)",
+        "fn visit_find_account_by_alias(operation: &FindAccountByAlias)",
+        "fn visit_find_accounts_by_domain_id(operation: &FindAccountsByDomainId)",
+        "fn visit_find_transaction_by_hash(operation: &FindTransactionByHash)",
+        "fn visit_find_metadata_history(operation: &FindMetadataHistory)",
+        "fn visit_find_all_accounts(operation: &FindAllAccounts)",
+        "fn visit_find_accounts_by_metadata_key_prefix(operation: &FindAccountsByMetadataKeyPrefix)",
+        "fn visit_find_all_assets(operation: &FindAllAssets)",
+        "fn visit_find_assets_by_name(operation: &FindAssetsByName)",
+        "fn visit_find_assets_by_metadata_key_prefix(operation: &FindAssetsByMetadataKeyPrefix)",
+        "fn visit_find_all_transactions(operation: &FindAllTransactions)",
+        "fn visit_find_all_blocks(operation: &FindAllBlocks)",
+        "fn visit_find_block_by_hash(operation: &FindBlockByHash)",
+        "fn visit_find_block_by_height(operation: &FindBlockByHeight)",
+        "fn visit_find_blocks_in_range(operation: &FindBlocksInRange)",
+        "fn visit_find_accounts_with_asset(operation: &FindAccountsWithAsset)",
+        "fn visit_find_assets_by_asset_definition_id(operation: &FindAssetsByAssetDefinitionId)",
     ]
     .into_iter()
     .map(|item| {
@@ -6,39 +6,71 @@ pub mod permissions;
 use alloc::format;
 
 pub use account::{
-    visit_register_account, visit_remove_account_key_value, visit_set_account_key_value,
+    visit_add_signatory, visit_cancel_recovery, visit_disable_account, visit_enable_account,
+    visit_finalize_recovery, visit_increment_account_key_value, visit_merge_account_key_values,
+    visit_recover_account, visit_register_account, visit_remove_account_key_value,
+    visit_remove_signatory, visit_rotate_key, visit_set_account_key_value,
+    visit_set_recovery_policy, visit_set_signature_threshold, visit_set_strict_ordering,
     visit_unregister_account,
 };
 pub use asset::{
-    visit_burn_asset_numeric, visit_mint_asset_numeric, visit_register_asset,
-    visit_remove_asset_key_value, visit_set_asset_key_value, visit_transfer_asset_numeric,
-    visit_transfer_asset_store, visit_unregister_asset,
+    visit_burn_asset_all, visit_burn_asset_numeric, visit_freeze_asset,
+    visit_increment_asset_key_value, visit_merge_asset_key_values, visit_mint_asset_numeric,
+    visit_mint_nft, visit_open_escrow, visit_refund_escrow, visit_register_asset,
+    visit_release_escrow, visit_remove_asset_key_value, visit_set_asset_key_value, visit_swap,
+    visit_transfer_asset_all, visit_transfer_asset_numeric, visit_transfer_asset_store,
+    visit_transfer_many, visit_transfer_nft, visit_unfreeze_asset, visit_unregister_asset,
 };
 pub use asset_definition::{
-    visit_register_asset_definition, visit_remove_asset_definition_key_value,
-    visit_set_asset_definition_key_value, visit_transfer_asset_definition,
+    visit_freeze_asset_definition, visit_increment_asset_definition_key_value,
+    visit_merge_asset_definition_key_values, visit_register_asset_definition,
+    visit_remove_asset_definition_key_value, visit_set_asset_definition_key_value,
+    visit_transfer_asset_definition, visit_unfreeze_asset_definition,
     visit_unregister_asset_definition,
 };
 pub use domain::{
+    visit_increment_domain_key_value, visit_merge_domain_key_values, visit_register_alias,
     visit_register_domain, visit_remove_domain_key_value, visit_set_domain_key_value,
-    visit_transfer_domain, visit_unregister_domain,
+    visit_set_domain_metadata_limits, visit_transfer_domain, visit_unregister_alias,
+    visit_unregister_domain,
 };
 pub use executor::visit_upgrade;
 pub use fail::visit_fail;
 use iroha_smart_contract::data_model::isi::InstructionBox;
 pub use log::visit_log;
+pub use metadata::{visit_set_key_value_if, visit_set_key_value_with_expiry};
 pub use parameter::{visit_new_parameter, visit_set_parameter};
 pub use peer::{visit_register_peer, visit_unregister_peer};
-pub use permission::{visit_grant_account_permission, visit_revoke_account_permission};
+pub use permission::{
+    visit_grant_account_permission, visit_grant_with_terms, visit_revoke_account_permission,
+};
 use permissions::AnyPermission;
+pub use query::{
+    visit_find_account_by_alias, visit_find_account_by_id,
+    visit_find_account_key_value_by_id_and_key, visit_find_account_key_value_by_id_and_path,
+    visit_find_accounts_by_domain_id, visit_find_accounts_by_metadata_key_prefix,
+    visit_find_accounts_with_asset, visit_find_all_accounts, visit_find_all_assets,
+    visit_find_all_blocks, visit_find_all_transactions, visit_find_asset_by_id,
+    visit_find_asset_key_value_by_id_and_key, visit_find_asset_key_value_by_id_and_path,
+    visit_find_asset_quantity_by_id, visit_find_assets_by_account_id,
+    visit_find_assets_by_asset_definition_id, visit_find_assets_by_domain_id,
+    visit_find_assets_by_domain_id_and_asset_definition_id,
+    visit_find_assets_by_metadata_key_prefix, visit_find_assets_by_name, visit_find_block_by_hash,
+    visit_find_block_by_height, visit_find_blocks_in_range, visit_find_grant_history_by_account_id,
+    visit_find_metadata_history, visit_find_nfts_by_account_id, visit_find_permission_grant_terms,
+    visit_find_permissions_by_account_id, visit_find_roles_by_account_id,
+    visit_find_transaction_by_hash, visit_find_transactions_by_account_id,
+};
 pub use role::{
     visit_grant_account_role, visit_grant_role_permission, visit_register_role,
     visit_revoke_account_role, visit_revoke_role_permission, visit_unregister_role,
 };
 pub use trigger::{
-    visit_burn_trigger_repetitions, visit_execute_trigger, visit_mint_trigger_repetitions,
-    visit_register_trigger, visit_remove_trigger_key_value, visit_set_trigger_key_value,
-    visit_unregister_trigger,
+    visit_burn_trigger_repetitions, visit_execute_trigger, visit_increment_trigger_key_value,
+    visit_merge_trigger_key_values, visit_mint_trigger_repetitions, visit_pause_trigger,
+    visit_register_trigger, visit_register_wasm_code, visit_remove_trigger_key_value,
+    visit_resume_trigger, visit_set_trigger_key_value, visit_unregister_trigger,
+    visit_upgrade_trigger,
 };
 
 use crate::{
@@ -71,6 +103,8 @@ pub fn visit_transaction<V: Validate + Visit + ?Sized>(
                 }
             }
         }
+        // Transactions can't refer to registered code, they are rejected before validation
+        Executable::WasmCode(_) => {}
     }
 }
 
@@ -97,6 +131,15 @@ pub fn visit_instruction<V: Validate + Visit + ?Sized>(
         InstructionBox::ExecuteTrigger(isi) => {
             executor.visit_execute_trigger(authority, isi);
         }
+        InstructionBox::Pause(isi) => {
+            executor.visit_pause_trigger(authority, isi);
+        }
+        InstructionBox::Resume(isi) => {
+            executor.visit_resume_trigger(authority, isi);
+        }
+        InstructionBox::UpgradeTrigger(isi) => {
+            executor.visit_upgrade_trigger(authority, isi);
+        }
         InstructionBox::Burn(isi) => {
             executor.visit_burn(authority, isi);
         }
@@ -106,6 +149,66 @@ pub fn visit_instruction<V: Validate + Visit + ?Sized>(
         InstructionBox::Grant(isi) => {
             executor.visit_grant(authority, isi);
         }
+        InstructionBox::IncrementKeyValue(isi) => {
+            executor.visit_increment_key_value(authority, isi);
+        }
+        InstructionBox::Swap(isi) => {
+            executor.visit_swap(authority, isi);
+        }
+        InstructionBox::TransferMany(isi) => {
+            executor.visit_transfer_many(authority, isi);
+        }
+        InstructionBox::OpenEscrow(isi) => {
+            executor.visit_open_escrow(authority, isi);
+        }
+        InstructionBox::ReleaseEscrow(isi) => {
+            executor.visit_release_escrow(authority, isi);
+        }
+        InstructionBox::RefundEscrow(isi) => {
+            executor.visit_refund_escrow(authority, isi);
+        }
+        InstructionBox::AddSignatory(isi) => {
+            executor.visit_add_signatory(authority, isi);
+        }
+        InstructionBox::RemoveSignatory(isi) => {
+            executor.visit_remove_signatory(authority, isi);
+        }
+        InstructionBox::SetSignatureThreshold(isi) => {
+            executor.visit_set_signature_threshold(authority, isi);
+        }
+        InstructionBox::RotateKey(isi) => {
+            executor.visit_rotate_key(authority, isi);
+        }
+        InstructionBox::SetRecoveryPolicy(isi) => {
+            executor.visit_set_recovery_policy(authority, isi);
+        }
+        InstructionBox::RecoverAccount(isi) => {
+            executor.visit_recover_account(authority, isi);
+        }
+        InstructionBox::FinalizeRecovery(isi) => {
+            executor.visit_finalize_recovery(authority, isi);
+        }
+        InstructionBox::CancelRecovery(isi) => {
+            executor.visit_cancel_recovery(authority, isi);
+        }
+        InstructionBox::DisableAccount(isi) => {
+            executor.visit_disable_account(authority, isi);
+        }
+        InstructionBox::EnableAccount(isi) => {
+            executor.visit_enable_account(authority, isi);
+        }
+        InstructionBox::SetStrictOrdering(isi) => {
+            executor.visit_set_strict_ordering(authority, isi);
+        }
+        InstructionBox::Freeze(isi) => {
+            executor.visit_freeze(authority, isi);
+        }
+        InstructionBox::Unfreeze(isi) => {
+            executor.visit_unfreeze(authority, isi);
+        }
+        InstructionBox::MergeKeyValues(isi) => {
+            executor.visit_merge_key_values(authority, isi);
+        }
         InstructionBox::Mint(isi) => {
             executor.visit_mint(authority, isi);
         }
@@ -118,9 +221,27 @@ pub fn visit_instruction<V: Validate + Visit + ?Sized>(
         InstructionBox::Revoke(isi) => {
             executor.visit_revoke(authority, isi);
         }
+        InstructionBox::GrantWithTerms(isi) => {
+            executor.visit_grant_with_terms(authority, isi);
+        }
         InstructionBox::SetKeyValue(isi) => {
             executor.visit_set_key_value(authority, isi);
         }
+        InstructionBox::SetDomainMetadataLimits(isi) => {
+            executor.visit_set_domain_metadata_limits(authority, isi);
+        }
+        InstructionBox::RegisterAlias(isi) => {
+            executor.visit_register_alias(authority, isi);
+        }
+        InstructionBox::UnregisterAlias(isi) => {
+            executor.visit_unregister_alias(authority, isi);
+        }
+        InstructionBox::SetKeyValueWithExpiry(isi) => {
+            executor.visit_set_key_value_with_expiry(authority, isi);
+        }
+        InstructionBox::SetKeyValueIf(isi) => {
+            executor.visit_set_key_value_if(authority, isi);
+        }
         InstructionBox::Transfer(isi) => {
             executor.visit_transfer(authority, isi);
         }
@@ -171,10 +292,26 @@ pub mod domain {
 
     pub fn visit_register_domain<V: Validate + Visit + ?Sized>(
         executor: &mut V,
-        _authority: &AccountId,
+        authority: &AccountId,
         isi: &Register<Domain>,
     ) {
-        execute!(executor, isi)
+        let Some(parent_id) = isi.object().id().parent() else {
+            execute!(executor, isi)
+        };
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_domain_owner(&parent_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't register a sub-domain of domain owned by another account"
+        );
     }
 
     pub fn visit_unregister_domain<V: Validate + Visit + ?Sized>(
@@ -233,7 +370,7 @@ pub mod domain {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        match is_domain_owner(domain_id, source_id) {
+        match is_domain_owner(domain_id, authority) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
@@ -267,6 +404,119 @@ pub mod domain {
         deny!(executor, "Can't set key value in domain metadata");
     }
 
+    pub fn visit_merge_domain_key_values<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &MergeKeyValues<Domain>,
+    ) {
+        let domain_id = isi.object_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_domain_owner(domain_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        // Merging only ever sets values, so it is covered by the same permission
+        let can_set_key_value_token = permissions::domain::CanSetKeyValueInDomain {
+            domain_id: domain_id.clone(),
+        };
+        if can_set_key_value_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't merge key values into domain metadata");
+    }
+
+    pub fn visit_increment_domain_key_value<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &IncrementKeyValue<Domain>,
+    ) {
+        let domain_id = isi.object_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_domain_owner(domain_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_set_key_value_token = permissions::domain::CanSetKeyValueInDomain {
+            domain_id: domain_id.clone(),
+        };
+        if can_set_key_value_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't increment key value in domain metadata");
+    }
+
+    pub fn visit_set_domain_metadata_limits<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetDomainMetadataLimits,
+    ) {
+        let domain_id = isi.domain_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_domain_owner(domain_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't set metadata limits of domain owned by another account"
+        );
+    }
+
+    pub fn visit_register_alias<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &RegisterAlias,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't register an alias for another account");
+    }
+
+    pub fn visit_unregister_alias<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &UnregisterAlias,
+    ) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        let account = match FindAccountByAlias::new(isi.alias().clone()).execute() {
+            Err(err) => deny!(executor, err),
+            Ok(account) => account.into_inner(),
+        };
+        match is_account_owner(account.id(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't unregister an alias of another account");
+    }
+
     pub fn visit_remove_domain_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
@@ -309,6 +559,7 @@ pub mod domain {
             AnyPermission::CanRegisterAssetDefinitionInDomain(permission) => {
                 &permission.domain_id == domain_id
             }
+            AnyPermission::CanReadDomainAssets(permission) => &permission.domain_id == domain_id,
             AnyPermission::CanUnregisterAssetDefinition(permission) => {
                 permission.asset_definition_id.domain_id() == domain_id
             }
@@ -333,6 +584,12 @@ pub mod domain {
             AnyPermission::CanTransferAssetWithDefinition(permission) => {
                 permission.asset_definition_id.domain_id() == domain_id
             }
+            AnyPermission::CanFreezeAssetDefinition(permission) => {
+                permission.asset_definition_id.domain_id() == domain_id
+            }
+            AnyPermission::CanFreezeAssetWithDefinition(permission) => {
+                permission.asset_definition_id.domain_id() == domain_id
+            }
             AnyPermission::CanBurnUserAsset(permission) => {
                 permission.asset_id.definition_id().domain_id() == domain_id
                     || permission.asset_id.account_id().domain_id() == domain_id
@@ -375,6 +632,9 @@ pub mod domain {
             AnyPermission::CanRemoveKeyValueInAccount(permission) => {
                 permission.account_id.domain_id() == domain_id
             }
+            AnyPermission::CanReadAccount(permission) => {
+                permission.account_id.domain_id() == domain_id
+            }
             AnyPermission::CanRegisterUserTrigger(permission) => {
                 permission.account_id.domain_id() == domain_id
             }
@@ -404,6 +664,8 @@ pub mod domain {
             | AnyPermission::CanRevokePermissionToSetParameters(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanUnregisterAnyRole(_)
+            | AnyPermission::CanReadAllAccounts(_)
+            | AnyPermission::CanRegisterAnyValidationTrigger(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
     }
@@ -508,10 +770,10 @@ pub mod account {
         );
     }
 
-    pub fn visit_remove_account_key_value<V: Validate + Visit + ?Sized>(
+    pub fn visit_merge_account_key_values<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &RemoveKeyValue<Account>,
+        isi: &MergeKeyValues<Account>,
     ) {
         let account_id = isi.object_id();
 
@@ -523,136 +785,426 @@ pub mod account {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_remove_key_value_in_user_account_token =
-            permissions::account::CanRemoveKeyValueInAccount {
-                account_id: account_id.clone(),
-            };
-        if can_remove_key_value_in_user_account_token.is_owned_by(authority) {
+        let can_set_key_value_token = permissions::account::CanSetKeyValueInAccount {
+            account_id: account_id.clone(),
+        };
+        if can_set_key_value_token.is_owned_by(authority) {
             execute!(executor, isi);
         }
 
         deny!(
             executor,
-            "Can't remove value from the metadata of another account"
+            "Can't merge values into the metadata of another account"
         );
     }
 
-    fn is_token_account_associated(permission: &Permission, account_id: &AccountId) -> bool {
-        let Ok(permission) = AnyPermission::try_from(permission) else {
-            return false;
-        };
-        match permission {
-            AnyPermission::CanUnregisterAccount(permission) => &permission.account_id == account_id,
-            AnyPermission::CanMintUserPublicKeys(permission) => {
-                &permission.account_id == account_id
-            }
-            AnyPermission::CanBurnUserPublicKeys(permission) => {
-                &permission.account_id == account_id
-            }
-            AnyPermission::CanMintUserSignatureCheckConditions(permission) => {
-                &permission.account_id == account_id
-            }
-            AnyPermission::CanSetKeyValueInAccount(permission) => {
-                &permission.account_id == account_id
-            }
-            AnyPermission::CanRemoveKeyValueInAccount(permission) => {
-                &permission.account_id == account_id
-            }
-            AnyPermission::CanBurnUserAsset(permission) => {
-                permission.asset_id.account_id() == account_id
-            }
-            AnyPermission::CanTransferUserAsset(permission) => {
-                permission.asset_id.account_id() == account_id
-            }
-            AnyPermission::CanUnregisterUserAsset(permission) => {
-                permission.asset_id.account_id() == account_id
-            }
-            AnyPermission::CanSetKeyValueInUserAsset(permission) => {
-                permission.asset_id.account_id() == account_id
-            }
-            AnyPermission::CanRemoveKeyValueInUserAsset(permission) => {
-                permission.asset_id.account_id() == account_id
-            }
-            AnyPermission::CanMintUserAsset(permission) => {
-                permission.asset_id.account_id() == account_id
-            }
-            AnyPermission::CanRegisterUserTrigger(permission) => {
-                &permission.account_id == account_id
-            }
-            AnyPermission::CanUnregisterUserTrigger(permission) => {
-                &permission.account_id == account_id
-            }
-            AnyPermission::CanExecuteUserTrigger(_)
-            | AnyPermission::CanBurnUserTrigger(_)
-            | AnyPermission::CanMintUserTrigger(_)
-            | AnyPermission::CanSetKeyValueInTrigger(_)
-            | AnyPermission::CanRemoveKeyValueInTrigger(_)
-            | AnyPermission::CanUnregisterAnyPeer(_)
-            | AnyPermission::CanUnregisterDomain(_)
-            | AnyPermission::CanSetKeyValueInDomain(_)
-            | AnyPermission::CanRemoveKeyValueInDomain(_)
-            | AnyPermission::CanRegisterAccountInDomain(_)
-            | AnyPermission::CanRegisterAssetDefinitionInDomain(_)
-            | AnyPermission::CanUnregisterAssetDefinition(_)
-            | AnyPermission::CanSetKeyValueInAssetDefinition(_)
-            | AnyPermission::CanRemoveKeyValueInAssetDefinition(_)
-            | AnyPermission::CanRegisterAssetWithDefinition(_)
-            | AnyPermission::CanUnregisterAssetWithDefinition(_)
-            | AnyPermission::CanBurnAssetWithDefinition(_)
-            | AnyPermission::CanMintAssetWithDefinition(_)
-            | AnyPermission::CanTransferAssetWithDefinition(_)
-            | AnyPermission::CanGrantPermissionToCreateParameters(_)
-            | AnyPermission::CanRevokePermissionToCreateParameters(_)
-            | AnyPermission::CanCreateParameters(_)
-            | AnyPermission::CanGrantPermissionToSetParameters(_)
-            | AnyPermission::CanRevokePermissionToSetParameters(_)
-            | AnyPermission::CanSetParameters(_)
-            | AnyPermission::CanUnregisterAnyRole(_)
-            | AnyPermission::CanUpgradeExecutor(_) => false,
-        }
-    }
-}
-
-pub mod asset_definition {
-    use iroha_smart_contract::data_model::asset::AssetDefinitionId;
-
-    use super::*;
-    use crate::permission::{
-        account::is_account_owner, accounts_permissions,
-        asset_definition::is_asset_definition_owner, roles_permissions,
-    };
-
-    pub fn visit_register_asset_definition<V: Validate + Visit + ?Sized>(
+    pub fn visit_increment_account_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &Register<AssetDefinition>,
+        isi: &IncrementKeyValue<Account>,
     ) {
-        let domain_id = isi.object().id().domain_id();
+        let account_id = isi.object_id();
 
-        match crate::permission::domain::is_domain_owner(domain_id, authority) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-
-        let can_register_asset_definition_in_domain_token =
-            permissions::domain::CanRegisterAssetDefinitionInDomain {
-                domain_id: domain_id.clone(),
-            };
-        if can_register_asset_definition_in_domain_token.is_owned_by(authority) {
+        let can_set_key_value_token = permissions::account::CanSetKeyValueInAccount {
+            account_id: account_id.clone(),
+        };
+        if can_set_key_value_token.is_owned_by(authority) {
             execute!(executor, isi);
         }
 
         deny!(
             executor,
-            "Can't register asset definition in a domain owned by another account"
+            "Can't increment values in the metadata of another account"
         );
     }
 
-    pub fn visit_unregister_asset_definition<V: Validate + Visit + ?Sized>(
+    pub fn visit_remove_account_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &Unregister<AssetDefinition>,
+        isi: &RemoveKeyValue<Account>,
+    ) {
+        let account_id = isi.object_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_remove_key_value_in_user_account_token =
+            permissions::account::CanRemoveKeyValueInAccount {
+                account_id: account_id.clone(),
+            };
+        if can_remove_key_value_in_user_account_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't remove value from the metadata of another account"
+        );
+    }
+
+    pub fn visit_add_signatory<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &AddSignatory,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_mint_user_public_keys_token = permissions::account::CanMintUserPublicKeys {
+            account_id: account_id.clone(),
+        };
+        if can_mint_user_public_keys_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't add signatories to another account");
+    }
+
+    pub fn visit_remove_signatory<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &RemoveSignatory,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_burn_user_public_keys_token = permissions::account::CanBurnUserPublicKeys {
+            account_id: account_id.clone(),
+        };
+        if can_burn_user_public_keys_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't remove signatories of another account");
+    }
+
+    pub fn visit_set_signature_threshold<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetSignatureThreshold,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_mint_user_signature_check_conditions_token =
+            permissions::account::CanMintUserSignatureCheckConditions {
+                account_id: account_id.clone(),
+            };
+        if can_mint_user_signature_check_conditions_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't change the signature threshold of another account"
+        );
+    }
+
+    pub fn visit_rotate_key<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &RotateKey,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_mint_user_public_keys_token = permissions::account::CanMintUserPublicKeys {
+            account_id: account_id.clone(),
+        };
+        let can_burn_user_public_keys_token = permissions::account::CanBurnUserPublicKeys {
+            account_id: account_id.clone(),
+        };
+        if can_mint_user_public_keys_token.is_owned_by(authority)
+            && can_burn_user_public_keys_token.is_owned_by(authority)
+        {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't rotate keys of another account");
+    }
+
+    pub fn visit_set_recovery_policy<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetRecoveryPolicy,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't set the recovery policy of another account");
+    }
+
+    pub fn visit_recover_account<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        _authority: &AccountId,
+        isi: &RecoverAccount,
+    ) {
+        // Whether the authority is a guardian of the account is checked on execution
+        execute!(executor, isi);
+    }
+
+    pub fn visit_finalize_recovery<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        _authority: &AccountId,
+        isi: &FinalizeRecovery,
+    ) {
+        // Whether the guardians approved the recovery and its delay passed is checked on execution
+        execute!(executor, isi);
+    }
+
+    pub fn visit_cancel_recovery<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &CancelRecovery,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't cancel the recovery of another account");
+    }
+
+    pub fn visit_disable_account<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &DisableAccount,
+    ) {
+        validate_disable_account(executor, authority, isi.account_id(), isi);
+    }
+
+    pub fn visit_enable_account<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &EnableAccount,
+    ) {
+        validate_disable_account(executor, authority, isi.account_id(), isi);
+    }
+
+    pub fn visit_set_strict_ordering<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetStrictOrdering,
+    ) {
+        let account_id = isi.account_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't set the transaction ordering of another account"
+        );
+    }
+
+    fn validate_disable_account<V, I>(
+        executor: &mut V,
+        authority: &AccountId,
+        account_id: &AccountId,
+        isi: &I,
+    ) where
+        V: Validate + Visit + ?Sized,
+        I: Instruction + Encode,
+    {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match crate::permission::domain::is_domain_owner(account_id.domain_id(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't disable or enable an account outside of an owned domain"
+        );
+    }
+
+    fn is_token_account_associated(permission: &Permission, account_id: &AccountId) -> bool {
+        let Ok(permission) = AnyPermission::try_from(permission) else {
+            return false;
+        };
+        match permission {
+            AnyPermission::CanUnregisterAccount(permission) => &permission.account_id == account_id,
+            AnyPermission::CanMintUserPublicKeys(permission) => {
+                &permission.account_id == account_id
+            }
+            AnyPermission::CanBurnUserPublicKeys(permission) => {
+                &permission.account_id == account_id
+            }
+            AnyPermission::CanMintUserSignatureCheckConditions(permission) => {
+                &permission.account_id == account_id
+            }
+            AnyPermission::CanSetKeyValueInAccount(permission) => {
+                &permission.account_id == account_id
+            }
+            AnyPermission::CanRemoveKeyValueInAccount(permission) => {
+                &permission.account_id == account_id
+            }
+            AnyPermission::CanReadAccount(permission) => &permission.account_id == account_id,
+            AnyPermission::CanBurnUserAsset(permission) => {
+                permission.asset_id.account_id() == account_id
+            }
+            AnyPermission::CanTransferUserAsset(permission) => {
+                permission.asset_id.account_id() == account_id
+            }
+            AnyPermission::CanUnregisterUserAsset(permission) => {
+                permission.asset_id.account_id() == account_id
+            }
+            AnyPermission::CanSetKeyValueInUserAsset(permission) => {
+                permission.asset_id.account_id() == account_id
+            }
+            AnyPermission::CanRemoveKeyValueInUserAsset(permission) => {
+                permission.asset_id.account_id() == account_id
+            }
+            AnyPermission::CanMintUserAsset(permission) => {
+                permission.asset_id.account_id() == account_id
+            }
+            AnyPermission::CanRegisterUserTrigger(permission) => {
+                &permission.account_id == account_id
+            }
+            AnyPermission::CanUnregisterUserTrigger(permission) => {
+                &permission.account_id == account_id
+            }
+            AnyPermission::CanExecuteUserTrigger(_)
+            | AnyPermission::CanBurnUserTrigger(_)
+            | AnyPermission::CanMintUserTrigger(_)
+            | AnyPermission::CanSetKeyValueInTrigger(_)
+            | AnyPermission::CanRemoveKeyValueInTrigger(_)
+            | AnyPermission::CanUnregisterAnyPeer(_)
+            | AnyPermission::CanUnregisterDomain(_)
+            | AnyPermission::CanSetKeyValueInDomain(_)
+            | AnyPermission::CanRemoveKeyValueInDomain(_)
+            | AnyPermission::CanRegisterAccountInDomain(_)
+            | AnyPermission::CanRegisterAssetDefinitionInDomain(_)
+            | AnyPermission::CanReadDomainAssets(_)
+            | AnyPermission::CanUnregisterAssetDefinition(_)
+            | AnyPermission::CanSetKeyValueInAssetDefinition(_)
+            | AnyPermission::CanRemoveKeyValueInAssetDefinition(_)
+            | AnyPermission::CanRegisterAssetWithDefinition(_)
+            | AnyPermission::CanUnregisterAssetWithDefinition(_)
+            | AnyPermission::CanBurnAssetWithDefinition(_)
+            | AnyPermission::CanMintAssetWithDefinition(_)
+            | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CanFreezeAssetDefinition(_)
+            | AnyPermission::CanFreezeAssetWithDefinition(_)
+            | AnyPermission::CanGrantPermissionToCreateParameters(_)
+            | AnyPermission::CanRevokePermissionToCreateParameters(_)
+            | AnyPermission::CanCreateParameters(_)
+            | AnyPermission::CanGrantPermissionToSetParameters(_)
+            | AnyPermission::CanRevokePermissionToSetParameters(_)
+            | AnyPermission::CanSetParameters(_)
+            | AnyPermission::CanUnregisterAnyRole(_)
+            | AnyPermission::CanReadAllAccounts(_)
+            | AnyPermission::CanRegisterAnyValidationTrigger(_)
+            | AnyPermission::CanUpgradeExecutor(_) => false,
+        }
+    }
+}
+
+pub mod asset_definition {
+    use iroha_smart_contract::data_model::{asset::AssetDefinitionId, isi::Instruction};
+    use iroha_smart_contract_utils::Encode;
+
+    use super::*;
+    use crate::permission::{
+        account::is_account_owner, accounts_permissions,
+        asset_definition::is_asset_definition_owner, roles_permissions,
+    };
+
+    pub fn visit_register_asset_definition<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Register<AssetDefinition>,
+    ) {
+        let domain_id = isi.object().id().domain_id();
+
+        match crate::permission::domain::is_domain_owner(domain_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        let can_register_asset_definition_in_domain_token =
+            permissions::domain::CanRegisterAssetDefinitionInDomain {
+                domain_id: domain_id.clone(),
+            };
+        if can_register_asset_definition_in_domain_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't register asset definition in a domain owned by another account"
+        );
+    }
+
+    pub fn visit_unregister_asset_definition<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Unregister<AssetDefinition>,
     ) {
         let asset_definition_id = isi.object_id();
 
@@ -750,6 +1302,64 @@ pub mod asset_definition {
         );
     }
 
+    pub fn visit_merge_asset_definition_key_values<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &MergeKeyValues<AssetDefinition>,
+    ) {
+        let asset_definition_id = isi.object_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_asset_definition_owner(asset_definition_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_set_key_value_token =
+            permissions::asset_definition::CanSetKeyValueInAssetDefinition {
+                asset_definition_id: asset_definition_id.clone(),
+            };
+        if can_set_key_value_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't merge values into the asset definition metadata created by another account"
+        );
+    }
+
+    pub fn visit_increment_asset_definition_key_value<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &IncrementKeyValue<AssetDefinition>,
+    ) {
+        let asset_definition_id = isi.object_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_asset_definition_owner(asset_definition_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_set_key_value_token =
+            permissions::asset_definition::CanSetKeyValueInAssetDefinition {
+                asset_definition_id: asset_definition_id.clone(),
+            };
+        if can_set_key_value_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't increment values in the asset definition metadata created by another account"
+        );
+    }
+
     pub fn visit_remove_asset_definition_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
@@ -779,6 +1389,53 @@ pub mod asset_definition {
         );
     }
 
+    pub fn visit_freeze_asset_definition<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Freeze<AssetDefinition>,
+    ) {
+        validate_freeze_asset_definition(executor, authority, isi.object_id(), isi);
+    }
+
+    pub fn visit_unfreeze_asset_definition<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Unfreeze<AssetDefinition>,
+    ) {
+        validate_freeze_asset_definition(executor, authority, isi.object_id(), isi);
+    }
+
+    fn validate_freeze_asset_definition<V, I>(
+        executor: &mut V,
+        authority: &AccountId,
+        asset_definition_id: &AssetDefinitionId,
+        isi: &I,
+    ) where
+        V: Validate + Visit + ?Sized,
+        I: Instruction + Encode,
+    {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_asset_definition_owner(asset_definition_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_freeze_asset_definition_token =
+            permissions::asset_definition::CanFreezeAssetDefinition {
+                asset_definition_id: asset_definition_id.clone(),
+            };
+        if can_freeze_asset_definition_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't freeze or unfreeze asset definition created by another account"
+        );
+    }
+
     fn is_token_asset_definition_associated(
         permission: &Permission,
         asset_definition_id: &AssetDefinitionId,
@@ -811,6 +1468,12 @@ pub mod asset_definition {
             AnyPermission::CanTransferAssetWithDefinition(permission) => {
                 &permission.asset_definition_id == asset_definition_id
             }
+            AnyPermission::CanFreezeAssetDefinition(permission) => {
+                &permission.asset_definition_id == asset_definition_id
+            }
+            AnyPermission::CanFreezeAssetWithDefinition(permission) => {
+                &permission.asset_definition_id == asset_definition_id
+            }
             AnyPermission::CanBurnUserAsset(permission) => {
                 permission.asset_id.definition_id() == asset_definition_id
             }
@@ -835,6 +1498,7 @@ pub mod asset_definition {
             | AnyPermission::CanMintUserSignatureCheckConditions(_)
             | AnyPermission::CanSetKeyValueInAccount(_)
             | AnyPermission::CanRemoveKeyValueInAccount(_)
+            | AnyPermission::CanReadAccount(_)
             | AnyPermission::CanRegisterUserTrigger(_)
             | AnyPermission::CanUnregisterUserTrigger(_)
             | AnyPermission::CanExecuteUserTrigger(_)
@@ -848,6 +1512,7 @@ pub mod asset_definition {
             | AnyPermission::CanRemoveKeyValueInDomain(_)
             | AnyPermission::CanRegisterAccountInDomain(_)
             | AnyPermission::CanRegisterAssetDefinitionInDomain(_)
+            | AnyPermission::CanReadDomainAssets(_)
             | AnyPermission::CanGrantPermissionToCreateParameters(_)
             | AnyPermission::CanRevokePermissionToCreateParameters(_)
             | AnyPermission::CanCreateParameters(_)
@@ -855,15 +1520,15 @@ pub mod asset_definition {
             | AnyPermission::CanRevokePermissionToSetParameters(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanUnregisterAnyRole(_)
+            | AnyPermission::CanReadAllAccounts(_)
+            | AnyPermission::CanRegisterAnyValidationTrigger(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
     }
 }
 
 pub mod asset {
-    use iroha_smart_contract::data_model::{
-        asset::AssetValue, isi::Instruction, metadata::Metadata,
-    };
+    use iroha_smart_contract::data_model::{isi::Instruction, metadata::Metadata};
     use iroha_smart_contract_utils::Encode;
 
     use super::*;
@@ -938,53 +1603,306 @@ pub mod asset {
     fn validate_mint_asset<V, Q>(executor: &mut V, authority: &AccountId, isi: &Mint<Q, Asset>)
     where
         V: Validate + Visit + ?Sized,
-        Q: Into<AssetValue>,
         Mint<Q, Asset>: Instruction + Encode,
+    {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match can_mint_asset(isi.destination_id(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't mint assets with definitions registered by other accounts"
+        );
+    }
+
+    pub(super) fn can_mint_asset(asset_id: &AssetId, authority: &AccountId) -> Result<bool> {
+        if is_asset_definition_owner(asset_id.definition_id(), authority)? {
+            return Ok(true);
+        }
+        let can_mint_assets_with_definition_token =
+            permissions::asset::CanMintAssetWithDefinition {
+                asset_definition_id: asset_id.definition_id().clone(),
+            };
+        let can_mint_user_asset_token = permissions::asset::CanMintUserAsset {
+            asset_id: asset_id.clone(),
+        };
+
+        Ok(can_mint_assets_with_definition_token.is_owned_by(authority)
+            || can_mint_user_asset_token.is_owned_by(authority))
+    }
+
+    pub fn visit_mint_asset_numeric<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Mint<Numeric, Asset>,
+    ) {
+        validate_mint_asset(executor, authority, isi);
+    }
+
+    pub fn visit_mint_nft<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Mint<Name, Asset>,
+    ) {
+        validate_mint_asset(executor, authority, isi);
+    }
+
+    fn validate_burn_asset<V, Q>(executor: &mut V, authority: &AccountId, isi: &Burn<Q, Asset>)
+    where
+        V: Validate + Visit + ?Sized,
+        Burn<Q, Asset>: Instruction + Encode,
     {
         let asset_id = isi.destination_id();
         if is_genesis(executor) {
             execute!(executor, isi);
         }
-        match is_asset_definition_owner(asset_id.definition_id(), authority) {
+        match is_asset_owner(asset_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        match is_asset_definition_owner(asset_id.definition_id(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_burn_assets_with_definition_token =
+            permissions::asset::CanBurnAssetWithDefinition {
+                asset_definition_id: asset_id.definition_id().clone(),
+            };
+        if can_burn_assets_with_definition_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+        let can_burn_user_asset_token = permissions::asset::CanBurnUserAsset {
+            asset_id: asset_id.clone(),
+        };
+        if can_burn_user_asset_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't burn assets from another account");
+    }
+
+    pub fn visit_burn_asset_numeric<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Burn<Numeric, Asset>,
+    ) {
+        validate_burn_asset(executor, authority, isi);
+    }
+
+    pub fn visit_burn_asset_all<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Burn<All, Asset>,
+    ) {
+        validate_burn_asset(executor, authority, isi);
+    }
+
+    fn validate_transfer_asset<V, Q>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Transfer<Asset, Q, Account>,
+    ) where
+        V: Validate + Visit + ?Sized,
+        Transfer<Asset, Q, Account>: Instruction + Encode,
+    {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(isi.source_id(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't transfer assets of another account");
+    }
+
+    fn can_transfer_asset(asset_id: &AssetId, authority: &AccountId) -> Result<bool> {
+        if is_asset_owner(asset_id, authority)?
+            || is_asset_definition_owner(asset_id.definition_id(), authority)?
+        {
+            return Ok(true);
+        }
+        let can_transfer_assets_with_definition_token =
+            permissions::asset::CanTransferAssetWithDefinition {
+                asset_definition_id: asset_id.definition_id().clone(),
+            };
+        let can_transfer_user_asset_token = permissions::asset::CanTransferUserAsset {
+            asset_id: asset_id.clone(),
+        };
+
+        Ok(
+            can_transfer_assets_with_definition_token.is_owned_by(authority)
+                || can_transfer_user_asset_token.is_owned_by(authority),
+        )
+    }
+
+    pub fn visit_freeze_asset<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Freeze<Asset>,
+    ) {
+        validate_freeze_asset(executor, authority, isi.object_id(), isi);
+    }
+
+    pub fn visit_unfreeze_asset<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Unfreeze<Asset>,
+    ) {
+        validate_freeze_asset(executor, authority, isi.object_id(), isi);
+    }
+
+    fn validate_freeze_asset<V, I>(
+        executor: &mut V,
+        authority: &AccountId,
+        asset_id: &AssetId,
+        isi: &I,
+    ) where
+        V: Validate + Visit + ?Sized,
+        I: Instruction + Encode,
+    {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_asset_definition_owner(asset_id.definition_id(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_freeze_assets_with_definition_token =
+            permissions::asset::CanFreezeAssetWithDefinition {
+                asset_definition_id: asset_id.definition_id().clone(),
+            };
+        if can_freeze_assets_with_definition_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't freeze or unfreeze assets with definition owned by another account"
+        );
+    }
+
+    pub fn visit_transfer_many<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &TransferMany,
+    ) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(&isi.source_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't transfer assets of another account");
+    }
+
+    pub fn visit_open_escrow<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &OpenEscrow,
+    ) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(isi.escrow().source_id(), authority) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_mint_assets_with_definition_token =
-            permissions::asset::CanMintAssetWithDefinition {
-                asset_definition_id: asset_id.definition_id().clone(),
-            };
-        if can_mint_assets_with_definition_token.is_owned_by(authority) {
+
+        deny!(executor, "Can't escrow assets of another account");
+    }
+
+    pub fn visit_release_escrow<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        _authority: &AccountId,
+        isi: &ReleaseEscrow,
+    ) {
+        // Whether the escrow condition holds is checked on execution
+        execute!(executor, isi);
+    }
+
+    pub fn visit_refund_escrow<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        _authority: &AccountId,
+        isi: &RefundEscrow,
+    ) {
+        // Only the recipient or, after the refund expiry, the depositor can refund on execution
+        execute!(executor, isi);
+    }
+
+    pub fn visit_swap<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Swap,
+    ) {
+        if is_genesis(executor) {
             execute!(executor, isi);
         }
-        let can_mint_user_asset_token = permissions::asset::CanMintUserAsset {
-            asset_id: asset_id.clone(),
-        };
-        if can_mint_user_asset_token.is_owned_by(authority) {
-            execute!(executor, isi);
+        // The counterparty authorizes its leg by offering the same swap, checked on execution
+        if isi.first.source_id().account_id() != authority
+            && isi.second.source_id().account_id() != authority
+        {
+            deny!(
+                executor,
+                "Only the accounts exchanging assets can swap them"
+            );
         }
 
-        deny!(
-            executor,
-            "Can't mint assets with definitions registered by other accounts"
-        );
+        execute!(executor, isi);
     }
 
-    pub fn visit_mint_asset_numeric<V: Validate + Visit + ?Sized>(
+    pub fn visit_transfer_asset_numeric<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &Mint<Numeric, Asset>,
+        isi: &Transfer<Asset, Numeric, Account>,
     ) {
-        validate_mint_asset(executor, authority, isi);
+        validate_transfer_asset(executor, authority, isi);
     }
 
-    fn validate_burn_asset<V, Q>(executor: &mut V, authority: &AccountId, isi: &Burn<Q, Asset>)
-    where
-        V: Validate + Visit + ?Sized,
-        Q: Into<AssetValue>,
-        Burn<Q, Asset>: Instruction + Encode,
-    {
-        let asset_id = isi.destination_id();
+    pub fn visit_transfer_asset_all<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Transfer<Asset, All, Account>,
+    ) {
+        validate_transfer_asset(executor, authority, isi);
+    }
+
+    pub fn visit_transfer_asset_store<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Transfer<Asset, Metadata, Account>,
+    ) {
+        validate_transfer_asset(executor, authority, isi);
+    }
+
+    pub fn visit_transfer_nft<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Transfer<Asset, Name, Account>,
+    ) {
+        validate_transfer_asset(executor, authority, isi);
+    }
+
+    pub fn visit_set_asset_key_value<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetKeyValue<Asset>,
+    ) {
+        let asset_id = isi.object_id();
+
         if is_genesis(executor) {
             execute!(executor, isi);
         }
@@ -993,46 +1911,32 @@ pub mod asset {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        match is_asset_definition_owner(asset_id.definition_id(), authority) {
+        match can_mint_asset(asset_id, authority) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_burn_assets_with_definition_token =
-            permissions::asset::CanBurnAssetWithDefinition {
-                asset_definition_id: asset_id.definition_id().clone(),
-            };
-        if can_burn_assets_with_definition_token.is_owned_by(authority) {
-            execute!(executor, isi);
-        }
-        let can_burn_user_asset_token = permissions::asset::CanBurnUserAsset {
+
+        let can_set_key_value_in_user_asset_token = permissions::asset::CanSetKeyValueInUserAsset {
             asset_id: asset_id.clone(),
         };
-        if can_burn_user_asset_token.is_owned_by(authority) {
+        if can_set_key_value_in_user_asset_token.is_owned_by(authority) {
             execute!(executor, isi);
         }
 
-        deny!(executor, "Can't burn assets from another account");
+        deny!(
+            executor,
+            "Can't set value to the asset metadata of another account"
+        );
     }
 
-    pub fn visit_burn_asset_numeric<V: Validate + Visit + ?Sized>(
+    pub fn visit_merge_asset_key_values<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &Burn<Numeric, Asset>,
+        isi: &MergeKeyValues<Asset>,
     ) {
-        validate_burn_asset(executor, authority, isi);
-    }
+        let asset_id = isi.object_id();
 
-    fn validate_transfer_asset<V, Q>(
-        executor: &mut V,
-        authority: &AccountId,
-        isi: &Transfer<Asset, Q, Account>,
-    ) where
-        V: Validate + Visit + ?Sized,
-        Q: Into<AssetValue>,
-        Transfer<Asset, Q, Account>: Instruction + Encode,
-    {
-        let asset_id = isi.source_id();
         if is_genesis(executor) {
             execute!(executor, isi);
         }
@@ -1041,48 +1945,28 @@ pub mod asset {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        match is_asset_definition_owner(asset_id.definition_id(), authority) {
+        match can_mint_asset(asset_id, authority) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_transfer_assets_with_definition_token =
-            permissions::asset::CanTransferAssetWithDefinition {
-                asset_definition_id: asset_id.definition_id().clone(),
-            };
-        if can_transfer_assets_with_definition_token.is_owned_by(authority) {
-            execute!(executor, isi);
-        }
-        let can_transfer_user_asset_token = permissions::asset::CanTransferUserAsset {
+        let can_set_key_value_token = permissions::asset::CanSetKeyValueInUserAsset {
             asset_id: asset_id.clone(),
         };
-        if can_transfer_user_asset_token.is_owned_by(authority) {
+        if can_set_key_value_token.is_owned_by(authority) {
             execute!(executor, isi);
         }
 
-        deny!(executor, "Can't transfer assets of another account");
-    }
-
-    pub fn visit_transfer_asset_numeric<V: Validate + Visit + ?Sized>(
-        executor: &mut V,
-        authority: &AccountId,
-        isi: &Transfer<Asset, Numeric, Account>,
-    ) {
-        validate_transfer_asset(executor, authority, isi);
-    }
-
-    pub fn visit_transfer_asset_store<V: Validate + Visit + ?Sized>(
-        executor: &mut V,
-        authority: &AccountId,
-        isi: &Transfer<Asset, Metadata, Account>,
-    ) {
-        validate_transfer_asset(executor, authority, isi);
+        deny!(
+            executor,
+            "Can't merge values into the asset metadata of another account"
+        );
     }
 
-    pub fn visit_set_asset_key_value<V: Validate + Visit + ?Sized>(
+    pub fn visit_increment_asset_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &SetKeyValue<Asset>,
+        isi: &IncrementKeyValue<Asset>,
     ) {
         let asset_id = isi.object_id();
 
@@ -1094,17 +1978,21 @@ pub mod asset {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-
-        let can_set_key_value_in_user_asset_token = permissions::asset::CanSetKeyValueInUserAsset {
+        match can_mint_asset(asset_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_set_key_value_token = permissions::asset::CanSetKeyValueInUserAsset {
             asset_id: asset_id.clone(),
         };
-        if can_set_key_value_in_user_asset_token.is_owned_by(authority) {
+        if can_set_key_value_token.is_owned_by(authority) {
             execute!(executor, isi);
         }
 
         deny!(
             executor,
-            "Can't set value to the asset metadata of another account"
+            "Can't increment values in the asset metadata of another account"
         );
     }
 
@@ -1123,6 +2011,11 @@ pub mod asset {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
+        match can_mint_asset(asset_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
         let can_remove_key_value_in_user_asset_token =
             permissions::asset::CanRemoveKeyValueInUserAsset {
                 asset_id: asset_id.clone(),
@@ -1139,6 +2032,10 @@ pub mod asset {
 }
 
 pub mod parameter {
+    use alloc::collections::BTreeSet;
+
+    use iroha_smart_contract::data_model::parameter::default::MAX_FAULTS;
+
     use super::*;
 
     #[allow(clippy::needless_pass_by_value)]
@@ -1169,6 +2066,14 @@ pub mod parameter {
         if is_genesis(executor) {
             execute!(executor, isi);
         }
+        if isi.parameter().id().name().as_ref() == MAX_FAULTS
+            && !is_signed_by_peer_supermajority(authority)
+        {
+            deny!(
+                executor,
+                "Tolerated faults can only be changed by an account whose transactions a supermajority of peers must sign"
+            );
+        }
         if permissions::parameter::CanSetParameters.is_owned_by(authority) {
             execute!(executor, isi);
         }
@@ -1178,6 +2083,40 @@ pub mod parameter {
             "Can't set executor configuration parameters without permission"
         );
     }
+
+    /// Whether every transaction of `authority` has to be signed by more than two thirds of the peers,
+    /// no matter which of its signatories sign it.
+    fn is_signed_by_peer_supermajority(authority: &AccountId) -> bool {
+        let Ok(account) = FindAccountById::new(authority.clone()).execute() else {
+            return false;
+        };
+        let account = account.into_inner();
+        let Some(condition) = account.signature_condition() else {
+            return false;
+        };
+        let Ok(peers) = FindAllPeers.execute() else {
+            return false;
+        };
+        let Ok(peer_keys) = peers
+            .into_iter()
+            .map(|peer| peer.map(|peer| peer.id().public_key().clone()))
+            .collect::<Result<BTreeSet<_>, _>>()
+        else {
+            return false;
+        };
+
+        let supermajority = peer_keys.len() - peer_keys.len().saturating_sub(1) / 3;
+        let non_peer_signatories = condition
+            .signatories()
+            .iter()
+            .filter(|signatory| !peer_keys.contains(*signatory))
+            .count();
+        // Non-peer signatories can only make up the part of the threshold above the supermajority
+        let min_peer_signatures =
+            usize::from(condition.threshold().get()).saturating_sub(non_peer_signatories);
+
+        min_peer_signatures >= supermajority
+    }
 }
 
 pub mod role {
@@ -1343,6 +2282,7 @@ pub mod trigger {
 
     use super::*;
     use crate::permission::{
+        account::is_account_owner,
         accounts_permissions,
         domain::is_domain_owner,
         roles_permissions,
@@ -1356,6 +2296,27 @@ pub mod trigger {
     ) {
         let trigger = isi.object();
 
+        // Validation triggers can veto the transactions in their scope, so only the owner
+        // of the scope may register them, and a trigger validating every transaction needs
+        // a dedicated permission
+        if let TriggeringEventFilterBox::TransactionValidation(filter) = trigger.action().filter() {
+            let can_register = if let Some(account_id) = filter.authority() {
+                is_account_owner(account_id, authority)
+            } else if let Some(domain_id) = trigger.id().domain_id() {
+                is_domain_owner(domain_id, authority)
+            } else {
+                Ok(permissions::trigger::CanRegisterAnyValidationTrigger.is_owned_by(authority))
+            };
+            match can_register {
+                Err(err) => deny!(executor, err),
+                Ok(false) if !is_genesis(executor) => deny!(
+                    executor,
+                    "Can't register validation trigger for transactions of other accounts"
+                ),
+                Ok(_) => {}
+            }
+        }
+
         if is_genesis(executor)
             || {
                 match is_domain_owner(trigger.action().authority().domain_id(), authority) {
@@ -1418,46 +2379,166 @@ pub mod trigger {
             }
             execute!(executor, isi);
         }
-        deny!(
-            executor,
-            "Can't unregister trigger owned by another account"
-        );
+        deny!(
+            executor,
+            "Can't unregister trigger owned by another account"
+        );
+    }
+
+    pub fn visit_mint_trigger_repetitions<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Mint<u32, Trigger>,
+    ) {
+        let trigger_id = isi.destination_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_trigger_owner(trigger_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_mint_user_trigger_token = permissions::trigger::CanMintUserTrigger {
+            trigger_id: trigger_id.clone(),
+        };
+        if can_mint_user_trigger_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't mint execution count for trigger owned by another account"
+        );
+    }
+
+    pub fn visit_burn_trigger_repetitions<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Burn<u32, Trigger>,
+    ) {
+        let trigger_id = isi.destination_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_trigger_owner(trigger_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_mint_user_trigger_token = permissions::trigger::CanBurnUserTrigger {
+            trigger_id: trigger_id.clone(),
+        };
+        if can_mint_user_trigger_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't burn execution count for trigger owned by another account"
+        );
+    }
+
+    pub fn visit_execute_trigger<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &ExecuteTrigger,
+    ) {
+        let trigger_id = isi.trigger_id();
+
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match is_trigger_owner(trigger_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_execute_trigger_token = permissions::trigger::CanExecuteUserTrigger {
+            trigger_id: trigger_id.clone(),
+        };
+        if can_execute_trigger_token.is_owned_by(authority) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't execute trigger owned by another account");
     }
 
-    pub fn visit_mint_trigger_repetitions<V: Validate + Visit + ?Sized>(
+    pub fn visit_pause_trigger<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &Mint<u32, Trigger>,
+        isi: &Pause,
     ) {
-        let trigger_id = isi.destination_id();
+        validate_pause_trigger(executor, authority, isi.trigger_id(), isi);
+    }
+
+    pub fn visit_resume_trigger<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &Resume,
+    ) {
+        validate_pause_trigger(executor, authority, isi.trigger_id(), isi);
+    }
 
+    pub fn visit_upgrade_trigger<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &UpgradeTrigger,
+    ) {
         if is_genesis(executor) {
             execute!(executor, isi);
         }
-        match is_trigger_owner(trigger_id, authority) {
+        // Upgraded executable runs on behalf of the trigger owner, so no one else can upgrade it
+        match is_trigger_owner(isi.trigger_id(), authority) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_mint_user_trigger_token = permissions::trigger::CanMintUserTrigger {
-            trigger_id: trigger_id.clone(),
-        };
-        if can_mint_user_trigger_token.is_owned_by(authority) {
+
+        deny!(executor, "Can't upgrade trigger owned by another account");
+    }
+
+    pub fn visit_register_wasm_code<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        _authority: &AccountId,
+        isi: &Register<WasmCode>,
+    ) {
+        // Registered code runs only as a trigger, whose registration and upgrade are validated
+        execute!(executor, isi);
+    }
+
+    fn validate_pause_trigger<V, I>(
+        executor: &mut V,
+        authority: &AccountId,
+        trigger_id: &TriggerId,
+        isi: &I,
+    ) where
+        V: Validate + Visit + ?Sized,
+        I: Instruction + Encode,
+    {
+        if is_genesis(executor) {
             execute!(executor, isi);
         }
+        match is_trigger_owner(trigger_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
 
         deny!(
             executor,
-            "Can't mint execution count for trigger owned by another account"
+            "Can't pause or resume trigger owned by another account"
         );
     }
 
-    pub fn visit_burn_trigger_repetitions<V: Validate + Visit + ?Sized>(
+    pub fn visit_set_trigger_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &Burn<u32, Trigger>,
+        isi: &SetKeyValue<Trigger>,
     ) {
-        let trigger_id = isi.destination_id();
+        let trigger_id = isi.object_id();
 
         if is_genesis(executor) {
             execute!(executor, isi);
@@ -1467,25 +2548,26 @@ pub mod trigger {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_mint_user_trigger_token = permissions::trigger::CanBurnUserTrigger {
-            trigger_id: trigger_id.clone(),
-        };
-        if can_mint_user_trigger_token.is_owned_by(authority) {
+        let can_set_key_value_in_user_trigger_token =
+            permissions::trigger::CanSetKeyValueInTrigger {
+                trigger_id: trigger_id.clone(),
+            };
+        if can_set_key_value_in_user_trigger_token.is_owned_by(authority) {
             execute!(executor, isi);
         }
 
         deny!(
             executor,
-            "Can't burn execution count for trigger owned by another account"
+            "Can't set value to the metadata of another trigger"
         );
     }
 
-    pub fn visit_execute_trigger<V: Validate + Visit + ?Sized>(
+    pub fn visit_merge_trigger_key_values<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &ExecuteTrigger,
+        isi: &MergeKeyValues<Trigger>,
     ) {
-        let trigger_id = isi.trigger_id();
+        let trigger_id = isi.object_id();
 
         if is_genesis(executor) {
             execute!(executor, isi);
@@ -1495,20 +2577,23 @@ pub mod trigger {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_execute_trigger_token = permissions::trigger::CanExecuteUserTrigger {
+        let can_set_key_value_token = permissions::trigger::CanSetKeyValueInTrigger {
             trigger_id: trigger_id.clone(),
         };
-        if can_execute_trigger_token.is_owned_by(authority) {
+        if can_set_key_value_token.is_owned_by(authority) {
             execute!(executor, isi);
         }
 
-        deny!(executor, "Can't execute trigger owned by another account");
+        deny!(
+            executor,
+            "Can't merge values into the metadata of another trigger"
+        );
     }
 
-    pub fn visit_set_trigger_key_value<V: Validate + Visit + ?Sized>(
+    pub fn visit_increment_trigger_key_value<V: Validate + Visit + ?Sized>(
         executor: &mut V,
         authority: &AccountId,
-        isi: &SetKeyValue<Trigger>,
+        isi: &IncrementKeyValue<Trigger>,
     ) {
         let trigger_id = isi.object_id();
 
@@ -1520,17 +2605,16 @@ pub mod trigger {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_set_key_value_in_user_trigger_token =
-            permissions::trigger::CanSetKeyValueInTrigger {
-                trigger_id: trigger_id.clone(),
-            };
-        if can_set_key_value_in_user_trigger_token.is_owned_by(authority) {
+        let can_set_key_value_token = permissions::trigger::CanSetKeyValueInTrigger {
+            trigger_id: trigger_id.clone(),
+        };
+        if can_set_key_value_token.is_owned_by(authority) {
             execute!(executor, isi);
         }
 
         deny!(
             executor,
-            "Can't set value to the metadata of another trigger"
+            "Can't increment values in the metadata of another trigger"
         );
     }
 
@@ -1587,12 +2671,14 @@ pub mod trigger {
             | AnyPermission::CanRemoveKeyValueInDomain(_)
             | AnyPermission::CanRegisterAccountInDomain(_)
             | AnyPermission::CanRegisterAssetDefinitionInDomain(_)
+            | AnyPermission::CanReadDomainAssets(_)
             | AnyPermission::CanUnregisterAccount(_)
             | AnyPermission::CanMintUserPublicKeys(_)
             | AnyPermission::CanBurnUserPublicKeys(_)
             | AnyPermission::CanMintUserSignatureCheckConditions(_)
             | AnyPermission::CanSetKeyValueInAccount(_)
             | AnyPermission::CanRemoveKeyValueInAccount(_)
+            | AnyPermission::CanReadAccount(_)
             | AnyPermission::CanUnregisterAssetDefinition(_)
             | AnyPermission::CanSetKeyValueInAssetDefinition(_)
             | AnyPermission::CanRemoveKeyValueInAssetDefinition(_)
@@ -1603,6 +2689,8 @@ pub mod trigger {
             | AnyPermission::CanBurnUserAsset(_)
             | AnyPermission::CanMintAssetWithDefinition(_)
             | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CanFreezeAssetDefinition(_)
+            | AnyPermission::CanFreezeAssetWithDefinition(_)
             | AnyPermission::CanTransferUserAsset(_)
             | AnyPermission::CanSetKeyValueInUserAsset(_)
             | AnyPermission::CanRemoveKeyValueInUserAsset(_)
@@ -1614,6 +2702,8 @@ pub mod trigger {
             | AnyPermission::CanRevokePermissionToSetParameters(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanUnregisterAnyRole(_)
+            | AnyPermission::CanReadAllAccounts(_)
+            | AnyPermission::CanRegisterAnyValidationTrigger(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
     }
@@ -1638,7 +2728,9 @@ pub mod permission {
                     $authority,
                     $executor.block_height(),
                 ) {
-                    deny!($executor, error);
+                    if !isi.is_delegated_by($authority) {
+                        deny!($executor, error);
+                    }
                 }
 
                 execute!($executor, isi);
@@ -1678,6 +2770,68 @@ pub mod permission {
             Revoke<Permission, Account>
         );
     }
+
+    pub fn visit_grant_with_terms<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &GrantWithTerms,
+    ) {
+        let account_id = isi.grant().destination_id().clone();
+        let token = isi.grant().object();
+        let terms = *isi.terms();
+
+        if let Ok(any_token) = AnyPermission::try_from(token) {
+            let token = Permission::from(any_token.clone());
+            let isi = GrantWithTerms::new(Grant::permission(token, account_id), terms);
+            if is_genesis(executor) {
+                execute!(executor, isi);
+            }
+            if let Err(error) = crate::permission::ValidateGrantRevoke::validate_grant(
+                &any_token,
+                authority,
+                executor.block_height(),
+            ) {
+                if !is_delegated(authority, isi.grant().object(), terms) {
+                    deny!(executor, error);
+                }
+            }
+
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            ValidationFail::NotPermitted(format!("{token:?}: Unknown permission"))
+        );
+    }
+
+    /// Instruction which `authority` may execute by delegation of the permission it holds.
+    trait DelegatedBy {
+        fn is_delegated_by(&self, authority: &AccountId) -> bool;
+    }
+
+    impl DelegatedBy for Grant<Permission, Account> {
+        fn is_delegated_by(&self, authority: &AccountId) -> bool {
+            is_delegated(authority, self.object(), PermissionGrantTerms::default())
+        }
+    }
+
+    impl DelegatedBy for Revoke<Permission, Account> {
+        fn is_delegated_by(&self, _authority: &AccountId) -> bool {
+            false
+        }
+    }
+
+    /// Whether `authority` holds `permission` on terms which allow granting it on `terms`.
+    fn is_delegated(
+        authority: &AccountId,
+        permission: &Permission,
+        terms: PermissionGrantTerms,
+    ) -> bool {
+        FindPermissionGrantTerms::new(authority.clone(), permission.clone())
+            .execute()
+            .is_ok_and(|own_terms| own_terms.into_inner().permits_delegation(&terms))
+    }
 }
 
 pub mod executor {
@@ -1700,6 +2854,106 @@ pub mod executor {
     }
 }
 
+pub mod metadata {
+    use super::*;
+    use crate::permission::{
+        account::is_account_owner, asset::is_asset_owner,
+        asset_definition::is_asset_definition_owner, domain::is_domain_owner,
+        trigger::is_trigger_owner,
+    };
+
+    pub fn visit_set_key_value_with_expiry<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetKeyValueWithExpiry,
+    ) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match can_set_key_value(isi.set(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't set expiring value to the metadata of an object owned by another account"
+        );
+    }
+
+    pub fn visit_set_key_value_if<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        isi: &SetKeyValueIf,
+    ) {
+        if is_genesis(executor) {
+            execute!(executor, isi);
+        }
+        match can_set_key_value(isi.set(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't set value to the metadata of an object owned by another account"
+        );
+    }
+
+    fn can_set_key_value(set: &SetKeyValueBox, authority: &AccountId) -> Result<bool> {
+        match set {
+            SetKeyValueBox::Domain(set) => {
+                let domain_id = set.object_id();
+                let can_set_key_value_in_domain_token =
+                    permissions::domain::CanSetKeyValueInDomain {
+                        domain_id: domain_id.clone(),
+                    };
+                Ok(is_domain_owner(domain_id, authority)?
+                    || can_set_key_value_in_domain_token.is_owned_by(authority))
+            }
+            SetKeyValueBox::Account(set) => {
+                let account_id = set.object_id();
+                let can_set_key_value_in_user_account_token =
+                    permissions::account::CanSetKeyValueInAccount {
+                        account_id: account_id.clone(),
+                    };
+                Ok(is_account_owner(account_id, authority)?
+                    || can_set_key_value_in_user_account_token.is_owned_by(authority))
+            }
+            SetKeyValueBox::AssetDefinition(set) => {
+                let asset_definition_id = set.object_id();
+                let can_set_key_value_in_asset_definition_token =
+                    permissions::asset_definition::CanSetKeyValueInAssetDefinition {
+                        asset_definition_id: asset_definition_id.clone(),
+                    };
+                Ok(is_asset_definition_owner(asset_definition_id, authority)?
+                    || can_set_key_value_in_asset_definition_token.is_owned_by(authority))
+            }
+            SetKeyValueBox::Asset(set) => {
+                let asset_id = set.object_id();
+                let can_set_key_value_in_user_asset_token =
+                    permissions::asset::CanSetKeyValueInUserAsset {
+                        asset_id: asset_id.clone(),
+                    };
+                Ok(is_asset_owner(asset_id, authority)?
+                    || super::asset::can_mint_asset(asset_id, authority)?
+                    || can_set_key_value_in_user_asset_token.is_owned_by(authority))
+            }
+            SetKeyValueBox::Trigger(set) => {
+                let trigger_id = set.object_id();
+                let can_set_key_value_in_user_trigger_token =
+                    permissions::trigger::CanSetKeyValueInTrigger {
+                        trigger_id: trigger_id.clone(),
+                    };
+                Ok(is_trigger_owner(trigger_id, authority)?
+                    || can_set_key_value_in_user_trigger_token.is_owned_by(authority))
+            }
+        }
+    }
+}
+
 pub mod log {
     use super::*;
 
@@ -1724,6 +2978,252 @@ pub mod fail {
     }
 }
 
+pub mod query {
+    //! Queries of the data, assets or transactions of a particular account are only allowed
+    //! to the owner of the account or to the holder of a read permission. Queries of the assets
+    //! or accounts of a domain are only allowed to the domain owner or to the holder of a read
+    //! permission, and queries of the holders of an asset to the asset definition owner.
+    //!
+    //! Queries scanning the accounts, assets, transactions or blocks of the whole world state
+    //! (`FindAll*` and searches by name or metadata) require `CanReadAllAccounts`, which
+    //! also allows reading every account. Domains, asset definitions, roles, triggers,
+    //! peers, parameters, block headers and aggregated counts are public.
+
+    use iroha_smart_contract::QueryOutputCursor;
+
+    use super::*;
+    use crate::permission::{
+        account::is_account_owner, asset_definition::is_asset_definition_owner,
+        domain::is_domain_owner,
+    };
+
+    macro_rules! impl_visit_account_query {
+        ($($visitor:ident($query:ty), $account_id:ident;)+) => { $(
+            pub fn $visitor<V: Validate + Visit + ?Sized>(
+                executor: &mut V,
+                authority: &AccountId,
+                query: &$query,
+            ) {
+                match can_read_account(query.$account_id(), authority) {
+                    Err(err) => deny!(executor, err),
+                    Ok(true) => {}
+                    Ok(false) => deny!(executor, "Can't read data of another account"),
+                }
+            } )+
+        };
+    }
+
+    macro_rules! impl_visit_asset_query {
+        ($($visitor:ident($query:ty);)+) => { $(
+            pub fn $visitor<V: Validate + Visit + ?Sized>(
+                executor: &mut V,
+                authority: &AccountId,
+                query: &$query,
+            ) {
+                match can_read_asset(query.id(), authority) {
+                    Err(err) => deny!(executor, err),
+                    Ok(true) => {}
+                    Ok(false) => deny!(executor, "Can't read asset of another account"),
+                }
+            } )+
+        };
+    }
+
+    macro_rules! impl_visit_domain_assets_query {
+        ($($visitor:ident($query:ty);)+) => { $(
+            pub fn $visitor<V: Validate + Visit + ?Sized>(
+                executor: &mut V,
+                authority: &AccountId,
+                query: &$query,
+            ) {
+                match can_read_domain_assets(query.domain_id(), authority) {
+                    Err(err) => deny!(executor, err),
+                    Ok(true) => {}
+                    Ok(false) => deny!(executor, "Can't read assets of another domain"),
+                }
+            } )+
+        };
+    }
+
+    macro_rules! impl_visit_world_query {
+        ($($visitor:ident($query:ty);)+) => { $(
+            pub fn $visitor<V: Validate + Visit + ?Sized>(
+                executor: &mut V,
+                authority: &AccountId,
+                _query: &$query,
+            ) {
+                if !can_read_all_accounts(authority) {
+                    deny!(executor, "Can't read data of all accounts");
+                }
+            } )+
+        };
+    }
+
+    macro_rules! impl_visit_asset_holders_query {
+        ($($visitor:ident($query:ty);)+) => { $(
+            pub fn $visitor<V: Validate + Visit + ?Sized>(
+                executor: &mut V,
+                authority: &AccountId,
+                query: &$query,
+            ) {
+                match can_read_asset_holders(&query.asset_definition_id, authority) {
+                    Err(err) => deny!(executor, err),
+                    Ok(true) => {}
+                    Ok(false) => deny!(executor, "Can't read holders of another asset"),
+                }
+            } )+
+        };
+    }
+
+    impl_visit_account_query! {
+        visit_find_account_by_id(FindAccountById), id;
+        visit_find_account_key_value_by_id_and_key(FindAccountKeyValueByIdAndKey), id;
+        visit_find_account_key_value_by_id_and_path(FindAccountKeyValueByIdAndPath), id;
+        visit_find_assets_by_account_id(FindAssetsByAccountId), account_id;
+        visit_find_permissions_by_account_id(FindPermissionsByAccountId), id;
+        visit_find_permission_grant_terms(FindPermissionGrantTerms), account_id;
+        visit_find_grant_history_by_account_id(FindGrantHistoryByAccountId), account_id;
+        visit_find_roles_by_account_id(FindRolesByAccountId), id;
+        visit_find_transactions_by_account_id(FindTransactionsByAccountId), account_id;
+        visit_find_nfts_by_account_id(FindNftsByAccountId), account_id;
+    }
+
+    impl_visit_asset_query! {
+        visit_find_asset_by_id(FindAssetById);
+        visit_find_asset_quantity_by_id(FindAssetQuantityById);
+        visit_find_asset_key_value_by_id_and_key(FindAssetKeyValueByIdAndKey);
+        visit_find_asset_key_value_by_id_and_path(FindAssetKeyValueByIdAndPath);
+    }
+
+    impl_visit_domain_assets_query! {
+        visit_find_assets_by_domain_id(FindAssetsByDomainId);
+        visit_find_assets_by_domain_id_and_asset_definition_id(FindAssetsByDomainIdAndAssetDefinitionId);
+    }
+
+    impl_visit_world_query! {
+        visit_find_all_accounts(FindAllAccounts);
+        visit_find_accounts_by_metadata_key_prefix(FindAccountsByMetadataKeyPrefix);
+        visit_find_all_assets(FindAllAssets);
+        visit_find_assets_by_name(FindAssetsByName);
+        visit_find_assets_by_metadata_key_prefix(FindAssetsByMetadataKeyPrefix);
+        visit_find_all_transactions(FindAllTransactions);
+        visit_find_all_blocks(FindAllBlocks);
+        visit_find_block_by_hash(FindBlockByHash);
+        visit_find_block_by_height(FindBlockByHeight);
+        visit_find_blocks_in_range(FindBlocksInRange);
+    }
+
+    impl_visit_asset_holders_query! {
+        visit_find_accounts_with_asset(FindAccountsWithAsset);
+        visit_find_assets_by_asset_definition_id(FindAssetsByAssetDefinitionId);
+    }
+
+    pub fn visit_find_account_by_alias<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        query: &FindAccountByAlias,
+    ) {
+        let account = match FindAccountByAlias::new(query.alias.clone())
+            .execute()
+            .map(QueryOutputCursor::into_inner)
+        {
+            Err(err) => deny!(executor, err),
+            Ok(account) => account,
+        };
+        match can_read_account(account.id(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => {}
+            Ok(false) => deny!(executor, "Can't read data of another account"),
+        }
+    }
+
+    pub fn visit_find_accounts_by_domain_id<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        query: &FindAccountsByDomainId,
+    ) {
+        match is_domain_owner(&query.domain_id, authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => {}
+            Ok(false) if can_read_all_accounts(authority) => {}
+            Ok(false) => deny!(executor, "Can't read accounts of another domain"),
+        }
+    }
+
+    pub fn visit_find_transaction_by_hash<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        query: &FindTransactionByHash,
+    ) {
+        let transaction = match FindTransactionByHash::new(query.hash)
+            .execute()
+            .map(QueryOutputCursor::into_inner)
+        {
+            Err(err) => deny!(executor, err),
+            Ok(output) => output.transaction.value,
+        };
+        match can_read_account(transaction.authority(), authority) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => {}
+            Ok(false) => deny!(executor, "Can't read transactions of another account"),
+        }
+    }
+
+    pub fn visit_find_metadata_history<V: Validate + Visit + ?Sized>(
+        executor: &mut V,
+        authority: &AccountId,
+        query: &FindMetadataHistory,
+    ) {
+        let can_read = match &query.object_id {
+            IdBox::AccountId(account_id) => can_read_account(account_id, authority),
+            IdBox::AssetId(asset_id) => can_read_asset(asset_id, authority),
+            _ => Ok(true),
+        };
+        match can_read {
+            Err(err) => deny!(executor, err),
+            Ok(true) => {}
+            Ok(false) => deny!(executor, "Can't read metadata of another account"),
+        }
+    }
+
+    fn can_read_all_accounts(authority: &AccountId) -> bool {
+        permissions::account::CanReadAllAccounts.is_owned_by(authority)
+    }
+
+    fn can_read_account(account_id: &AccountId, authority: &AccountId) -> Result<bool> {
+        if is_account_owner(account_id, authority)? || can_read_all_accounts(authority) {
+            return Ok(true);
+        }
+        let can_read_account_token = permissions::account::CanReadAccount {
+            account_id: account_id.clone(),
+        };
+        Ok(can_read_account_token.is_owned_by(authority))
+    }
+
+    fn can_read_domain_assets(domain_id: &DomainId, authority: &AccountId) -> Result<bool> {
+        if is_domain_owner(domain_id, authority)? || can_read_all_accounts(authority) {
+            return Ok(true);
+        }
+        let can_read_domain_assets_token = permissions::domain::CanReadDomainAssets {
+            domain_id: domain_id.clone(),
+        };
+        Ok(can_read_domain_assets_token.is_owned_by(authority))
+    }
+
+    fn can_read_asset(asset_id: &AssetId, authority: &AccountId) -> Result<bool> {
+        Ok(can_read_account(asset_id.account_id(), authority)?
+            || can_read_domain_assets(asset_id.definition_id().domain_id(), authority)?)
+    }
+
+    fn can_read_asset_holders(
+        asset_definition_id: &AssetDefinitionId,
+        authority: &AccountId,
+    ) -> Result<bool> {
+        Ok(can_read_all_accounts(authority)
+            || is_asset_definition_owner(asset_definition_id, authority)?)
+    }
+}
+
 fn is_genesis<V: Validate + Visit + ?Sized>(executor: &V) -> bool {
     executor.block_height() == 0
 }
@@ -100,6 +100,7 @@ declare_permissions! {
     crate::default::permissions::domain::{CanRemoveKeyValueInDomain},
     crate::default::permissions::domain::{CanRegisterAccountInDomain},
     crate::default::permissions::domain::{CanRegisterAssetDefinitionInDomain},
+    crate::default::permissions::domain::{CanReadDomainAssets},
 
     crate::default::permissions::account::{CanUnregisterAccount},
     crate::default::permissions::account::{CanMintUserPublicKeys},
@@ -107,10 +108,13 @@ declare_permissions! {
     crate::default::permissions::account::{CanMintUserSignatureCheckConditions},
     crate::default::permissions::account::{CanSetKeyValueInAccount},
     crate::default::permissions::account::{CanRemoveKeyValueInAccount},
+    crate::default::permissions::account::{CanReadAccount},
+    crate::default::permissions::account::{CanReadAllAccounts},
 
     crate::default::permissions::asset_definition::{CanUnregisterAssetDefinition},
     crate::default::permissions::asset_definition::{CanSetKeyValueInAssetDefinition},
     crate::default::permissions::asset_definition::{CanRemoveKeyValueInAssetDefinition},
+    crate::default::permissions::asset_definition::{CanFreezeAssetDefinition},
 
     crate::default::permissions::asset::{CanRegisterAssetWithDefinition},
     crate::default::permissions::asset::{CanUnregisterAssetWithDefinition},
@@ -118,6 +122,7 @@ declare_permissions! {
     crate::default::permissions::asset::{CanBurnAssetWithDefinition},
     crate::default::permissions::asset::{CanMintAssetWithDefinition},
     crate::default::permissions::asset::{CanMintUserAsset},
+    crate::default::permissions::asset::{CanFreezeAssetWithDefinition},
     crate::default::permissions::asset::{CanBurnUserAsset},
     crate::default::permissions::asset::{CanTransferAssetWithDefinition},
     crate::default::permissions::asset::{CanTransferUserAsset},
@@ -134,6 +139,7 @@ declare_permissions! {
     crate::default::permissions::role::{CanUnregisterAnyRole},
 
     crate::default::permissions::trigger::{CanRegisterUserTrigger},
+    crate::default::permissions::trigger::{CanRegisterAnyValidationTrigger},
     crate::default::permissions::trigger::{CanExecuteUserTrigger},
     crate::default::permissions::trigger::{CanUnregisterUserTrigger},
     crate::default::permissions::trigger::{CanMintUserTrigger},
@@ -196,6 +202,14 @@ pub mod domain {
             pub domain_id: DomainId,
         }
     }
+
+    permission! {
+        #[derive(ValidateGrantRevoke, permission::derive_conversions::domain::Owner)]
+        #[validate(permission::domain::Owner)]
+        pub struct CanReadDomainAssets {
+            pub domain_id: DomainId,
+        }
+    }
 }
 
 pub mod account {
@@ -243,6 +257,18 @@ pub mod account {
             pub account_id: AccountId,
         }
     }
+    permission! {
+        #[derive(ValidateGrantRevoke, permission::derive_conversions::account::Owner)]
+        #[validate(permission::account::Owner)]
+        pub struct CanReadAccount {
+            pub account_id: AccountId,
+        }
+    }
+    permission! {
+        #[derive(Copy, ValidateGrantRevoke)]
+        #[validate(permission::OnlyGenesis)]
+        pub struct CanReadAllAccounts;
+    }
 }
 
 pub mod asset_definition {
@@ -271,6 +297,14 @@ pub mod asset_definition {
             pub asset_definition_id: AssetDefinitionId,
         }
     }
+
+    permission! {
+        #[derive(ValidateGrantRevoke, permission::derive_conversions::asset_definition::Owner)]
+        #[validate(permission::asset_definition::Owner)]
+        pub struct CanFreezeAssetDefinition {
+            pub asset_definition_id: AssetDefinitionId,
+        }
+    }
 }
 
 pub mod asset {
@@ -348,6 +382,14 @@ pub mod asset {
         }
     }
 
+    permission! {
+        #[derive(ValidateGrantRevoke, permission::derive_conversions::asset_definition::Owner)]
+        #[validate(permission::asset_definition::Owner)]
+        pub struct CanFreezeAssetWithDefinition {
+            pub asset_definition_id: AssetDefinitionId,
+        }
+    }
+
     permission! {
         #[derive(ValidateGrantRevoke, permission::derive_conversions::asset::Owner)]
         #[validate(permission::asset::Owner)]
@@ -486,6 +528,12 @@ pub mod trigger {
         }
     }
 
+    permission! {
+        #[derive(Copy, ValidateGrantRevoke)]
+        #[validate(permission::OnlyGenesis)]
+        pub struct CanRegisterAnyValidationTrigger;
+    }
+
     permission! {
         #[derive(ValidateGrantRevoke)]
         #[validate(permission::trigger::Owner)]
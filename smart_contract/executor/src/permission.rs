@@ -257,15 +257,21 @@ pub mod domain {
     //! Module with pass conditions for domain related tokens
     use super::*;
 
-    /// Check if `authority` is owner of `domain_id`
+    /// Check if `authority` is owner of `domain_id` or of one of its ancestors
     ///
     /// # Errors
     /// Fails if query fails
     pub fn is_domain_owner(domain_id: &DomainId, authority: &AccountId) -> Result<bool> {
-        FindDomainById::new(domain_id.clone())
-            .execute()
-            .map(QueryOutputCursor::into_inner)
-            .map(|domain| domain.owned_by() == authority)
+        for domain_id in core::iter::once(domain_id.clone()).chain(domain_id.ancestors()) {
+            let domain = FindDomainById::new(domain_id)
+                .execute()
+                .map(QueryOutputCursor::into_inner)?;
+            if domain.owned_by() == authority {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
     }
 
     /// Pass condition that checks if `authority` is the owner of `domain_id`.
@@ -476,6 +476,31 @@ pub fn get_smart_contract_payload() -> payloads::SmartContract {
     unsafe { decode_with_length_prefix_from_raw(host::get_smart_contract_payload()) }
 }
 
+/// Get a pseudo-random seed derived from the latest block hash and the hash of
+/// the executed transaction.
+///
+/// The seed is the same on every peer, so it must not be used to keep secrets.
+#[cfg(not(test))]
+pub fn get_random_seed() -> [u8; 32] {
+    // Safety: ownership of the returned result is transferred into `_decode_from_raw`
+    unsafe { decode_with_length_prefix_from_raw(host::get_random_seed()) }
+}
+
+/// Synchronously call the WASM trigger `id` passing it SCALE-encoded `args`
+/// and return the SCALE-encoded output it sets.
+///
+/// The trigger can be called by accounts which are allowed to execute it with [`ExecuteTrigger`].
+/// Every call uses up one of the trigger's repeats. Calls can be nested up to a limit
+/// and the called trigger runs on the fuel the caller has left, so the caller pays for it.
+/// If the call fails, the execution of the caller is aborted.
+#[cfg(not(test))]
+pub fn call_trigger(id: &TriggerId, args: &[u8]) -> Vec<u8> {
+    // Safety: `host::call_trigger` doesn't take ownership of it's pointer parameter
+    unsafe {
+        decode_with_length_prefix_from_raw(encode_and_execute(&(id, args), host::call_trigger))
+    }
+}
+
 #[cfg(not(test))]
 mod host {
     #[link(wasm_import_module = "iroha")]
@@ -503,6 +528,21 @@ mod host {
         ///
         /// This function does transfer ownership of the result to the caller
         pub(super) fn get_smart_contract_payload() -> *const u8;
+
+        /// Get pseudo-random seed derived from the latest block and transaction hashes.
+        /// # Warning
+        ///
+        /// This function does transfer ownership of the result to the caller
+        pub(super) fn get_random_seed() -> *const u8;
+
+        /// Call the trigger with encoded id and arguments by providing offset and length
+        /// into WebAssembly's linear memory where they are stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn call_trigger(ptr: *const u8, len: usize) -> *const u8;
     }
 }
 
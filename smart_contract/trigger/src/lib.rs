@@ -3,11 +3,16 @@
 #![allow(unsafe_code)]
 
 #[cfg(not(test))]
-use data_model::smart_contract::payloads;
+extern crate alloc;
+
+#[cfg(not(test))]
+use data_model::{
+    metadata::MetadataValueBox, prelude::Name, smart_contract::payloads, ValidationFail,
+};
 pub use iroha_smart_contract as smart_contract;
 pub use iroha_smart_contract_utils::debug;
 #[cfg(not(test))]
-use iroha_smart_contract_utils::decode_with_length_prefix_from_raw;
+use iroha_smart_contract_utils::{decode_with_length_prefix_from_raw, encode_and_execute};
 pub use iroha_trigger_derive::main;
 pub use smart_contract::{data_model, stub_getrandom};
 
@@ -26,6 +31,44 @@ mod host {
         ///
         /// This function does transfer ownership of the result to the caller
         pub(super) fn get_trigger_payload() -> *const u8;
+
+        /// Get the value of an encoded key in the storage of the executing trigger
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn get_trigger_storage(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Set an encoded key-value pair in the storage of the executing trigger
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn set_trigger_storage(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Remove an encoded key from the storage of the executing trigger
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn remove_trigger_storage(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Get the encoded arguments the executing trigger was called with
+        ///
+        /// # Warning
+        ///
+        /// This function does transfer ownership of the result to the caller
+        pub(super) fn get_call_arguments() -> *const u8;
+
+        /// Set the encoded output returned to the caller of the executing trigger
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        pub(super) fn set_call_output(ptr: *const u8, len: usize);
     }
 }
 
@@ -36,6 +79,77 @@ pub fn get_trigger_payload() -> payloads::Trigger {
     unsafe { decode_with_length_prefix_from_raw(host::get_trigger_payload()) }
 }
 
+/// Synchronous calls of the executing trigger by other executables,
+/// see [`iroha_smart_contract::call_trigger`].
+#[cfg(not(test))]
+pub mod call {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    /// Get the SCALE-encoded arguments the trigger was called with,
+    /// or `None` if it's executed because of an event
+    pub fn arguments() -> Option<Vec<u8>> {
+        // Safety: ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe { decode_with_length_prefix_from_raw(host::get_call_arguments()) }
+    }
+
+    /// Set the SCALE-encoded output returned to the caller.
+    /// Aborts the execution if the trigger wasn't called.
+    pub fn set_output(output: &[u8]) {
+        // Safety: `host::set_call_output` doesn't take ownership of it's pointer parameter
+        unsafe { encode_and_execute(&output, host::set_call_output) }
+    }
+}
+
+/// Storage of the executing trigger which is kept across its executions.
+///
+/// Backed by the metadata of the trigger, so it's subject to the metadata limits.
+#[cfg(not(test))]
+pub mod storage {
+    use super::*;
+
+    /// Get the value of `key`
+    pub fn get(key: &Name) -> Option<MetadataValueBox> {
+        // Safety: `host::get_trigger_storage` doesn't take ownership of it's pointer parameter
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(key, host::get_trigger_storage))
+        }
+    }
+
+    /// Set `key` to `value`
+    ///
+    /// # Errors
+    ///
+    /// - If the metadata limits are exceeded
+    /// - If the instruction limits of the execution are exceeded
+    pub fn set(key: &Name, value: &MetadataValueBox) -> Result<(), ValidationFail> {
+        // Safety: `host::set_trigger_storage` doesn't take ownership of it's pointer parameter
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(
+                &(key, value),
+                host::set_trigger_storage,
+            ))
+        }
+    }
+
+    /// Remove `key`
+    ///
+    /// # Errors
+    ///
+    /// - If there is no such key
+    /// - If the instruction limits of the execution are exceeded
+    pub fn remove(key: &Name) -> Result<(), ValidationFail> {
+        // Safety: `host::remove_trigger_storage` doesn't take ownership of it's pointer parameter
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(
+                key,
+                host::remove_trigger_storage,
+            ))
+        }
+    }
+}
+
 pub mod prelude {
     //! Common imports used by triggers
 
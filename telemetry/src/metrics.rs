@@ -13,6 +13,14 @@ use serde::{Deserialize, Serialize};
 pub type DroppedMessagesCounter = IntCounter;
 /// Type for reporting view change index of current round
 pub type ViewChangesGauge = GenericGauge<AtomicU64>;
+/// Type for reporting total amount of view changes
+pub type ViewChangesCounter = IntCounter;
+/// Type for reporting current leader, labeled by peer public key
+pub type LeaderGaugeVec = GenericGaugeVec<AtomicU64>;
+/// Type for reporting time the proxy tail took to collect enough votes for a block
+pub type ProxyTailLatencyHistogram = Histogram;
+/// Type for reporting proposals rejected by this peer, labeled by reason
+pub type RejectedProposalsCounterVec = IntCounterVec;
 
 /// Thin wrapper around duration that `impl`s [`Default`]
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -68,6 +76,11 @@ pub struct Status {
     /// Number of the transactions in the queue
     #[codec(compact)]
     pub queue_size: u64,
+    /// Number of view changes since the peer started
+    #[codec(compact)]
+    pub view_changes_total: u64,
+    /// Whether the peer stores bodies of all blocks
+    pub archival: bool,
 }
 
 impl<T: Deref<Target = Metrics>> From<&T> for Status {
@@ -81,6 +94,8 @@ impl<T: Deref<Target = Metrics>> From<&T> for Status {
             uptime: Uptime(Duration::from_millis(val.uptime_since_genesis_ms.get())),
             view_changes: val.view_changes.get(),
             queue_size: val.queue_size.get(),
+            view_changes_total: val.view_changes_total.get(),
+            archival: val.archival.get() != 0,
         }
     }
 }
@@ -94,6 +109,8 @@ pub struct Metrics {
     pub block_height: IntCounter,
     /// Number of currently connected peers excluding the reporting peer
     pub connected_peers: GenericGauge<AtomicU64>,
+    /// Connection state of each other peer in the network, set to 1 if connected
+    pub peer_connected: GenericGaugeVec<AtomicU64>,
     /// Uptime of the network, starting from commit of the genesis block
     pub uptime_since_genesis_ms: GenericGauge<AtomicU64>,
     /// Number of domains.
@@ -108,10 +125,20 @@ pub struct Metrics {
     pub isi_times: HistogramVec,
     /// Number of view changes in the current round
     pub view_changes: ViewChangesGauge,
+    /// Number of view changes since the peer started
+    pub view_changes_total: ViewChangesCounter,
+    /// Current leader, set to 1 for the public key of the leader
+    pub leader: LeaderGaugeVec,
+    /// Time the proxy tail took to collect enough votes for a block
+    pub proxy_tail_latency_ms: ProxyTailLatencyHistogram,
+    /// Proposals rejected by this peer by reason
+    pub rejected_proposals: RejectedProposalsCounterVec,
     /// Number of transactions in the queue
     pub queue_size: GenericGauge<AtomicU64>,
     /// Number of sumeragi dropped messages
     pub dropped_messages: DroppedMessagesCounter,
+    /// Set to 1 if the peer stores bodies of all blocks
+    pub archival: GenericGauge<AtomicU64>,
     /// Internal use only. Needed for generating the response.
     registry: Registry,
 }
@@ -142,6 +169,14 @@ impl Default for Metrics {
             "Total number of currently connected peers",
         )
         .expect("Infallible");
+        let peer_connected = GenericGaugeVec::new(
+            Opts::new(
+                "peer_connected",
+                "Whether the peer is connected to this peer",
+            ),
+            &["public_key"],
+        )
+        .expect("Infallible");
         let uptime_since_genesis_ms = GenericGauge::new(
             "uptime_since_genesis_ms",
             "Network up-time, from creation of the genesis block",
@@ -158,10 +193,33 @@ impl Default for Metrics {
             "Number of view changes in the current round",
         )
         .expect("Infallible");
+        let view_changes_total = IntCounter::new(
+            "view_changes_total",
+            "Number of view changes since the peer started",
+        )
+        .expect("Infallible");
+        let leader = GenericGaugeVec::new(
+            Opts::new("leader", "Current leader of consensus"),
+            &["public_key"],
+        )
+        .expect("Infallible");
+        let proxy_tail_latency_ms = Histogram::with_opts(HistogramOpts::new(
+            "proxy_tail_latency_ms",
+            "Time the proxy tail took to collect enough votes for a block",
+        ))
+        .expect("Infallible");
+        let rejected_proposals = IntCounterVec::new(
+            Opts::new("rejected_proposals", "Proposals rejected by this peer"),
+            &["reason"],
+        )
+        .expect("Infallible");
         let queue_size = GenericGauge::new("queue_size", "Number of the transactions in the queue")
             .expect("Infallible");
         let dropped_messages =
             IntCounter::new("dropped_messages", "Sumeragi dropped messages").expect("Infallible");
+        let archival =
+            GenericGauge::new("archival", "Whether the peer stores bodies of all blocks")
+                .expect("Infallible");
         let registry = Registry::new();
 
         macro_rules! register {
@@ -179,20 +237,27 @@ impl Default for Metrics {
             tx_amounts,
             block_height,
             connected_peers,
+            peer_connected,
             uptime_since_genesis_ms,
             domains,
             accounts,
             isi,
             isi_times,
             view_changes,
+            view_changes_total,
+            leader,
+            proxy_tail_latency_ms,
+            rejected_proposals,
             queue_size,
-            dropped_messages
+            dropped_messages,
+            archival
         );
 
         Self {
             txs,
             block_height,
             connected_peers,
+            peer_connected,
             uptime_since_genesis_ms,
             domains,
             accounts,
@@ -200,8 +265,13 @@ impl Default for Metrics {
             isi,
             isi_times,
             view_changes,
+            view_changes_total,
+            leader,
+            proxy_tail_latency_ms,
+            rejected_proposals,
             queue_size,
             dropped_messages,
+            archival,
             registry,
         }
     }
@@ -250,6 +320,8 @@ mod test {
             uptime: Uptime(Duration::new(5, 937_000_000)),
             view_changes: 2,
             queue_size: 18,
+            view_changes_total: 7,
+            archival: true,
         }
     }
 
@@ -271,7 +343,9 @@ mod test {
                 "nanos": 937000000
               },
               "view_changes": 2,
-              "queue_size": 18
+              "queue_size": 18,
+              "view_changes_total": 7,
+              "archival": true
             }"#]];
         expected.assert_eq(&actual);
     }
@@ -284,7 +358,7 @@ mod test {
         let actual = hex::encode_upper(bytes);
         // CAUTION: if this is outdated, make sure to update the documentation:
         // https://hyperledger.github.io/iroha-2-docs/api/torii-endpoints#status
-        let expected = expect_test::expect!["10147C0C14407CD9370848"];
+        let expected = expect_test::expect!["10147C0C14407CD93708481C01"];
         expected.assert_eq(&actual);
     }
 }
@@ -119,6 +119,10 @@ pub fn generate_default(
         Permission::new("CanSetParameters".parse()?, json!(null)),
         ALICE_ID.clone(),
     );
+    let grant_permission_to_read_all_accounts = Grant::permission(
+        Permission::new("CanReadAllAccounts".parse()?, json!(null)),
+        ALICE_ID.clone(),
+    );
     let transfer_rose_ownership = Transfer::asset_definition(
         genesis_account_id.clone(),
         "rose#wonderland".parse()?,
@@ -196,6 +200,10 @@ pub fn generate_default(
             WASM_MAX_MEMORY,
             Numeric::new(chain_wide_defaults::WASM_MAX_MEMORY_BYTES.into(), 0),
         )?
+        .add_parameter(
+            GRANT_HISTORY_MAX_LEN,
+            Numeric::new(chain_wide_defaults::GRANT_HISTORY_MAX_LEN.into(), 0),
+        )?
         .into_create_parameters();
 
     let first_tx = genesis
@@ -207,6 +215,7 @@ pub fn generate_default(
         transfer_rose_ownership.into(),
         transfer_wonderland_ownership.into(),
         grant_permission_to_set_parameters.into(),
+        grant_permission_to_read_all_accounts.into(),
     ]
     .into_iter()
     .chain(parameter_defaults.into_iter())
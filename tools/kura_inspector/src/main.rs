@@ -3,8 +3,6 @@ use std::path::{Path, PathBuf};
 
 use clap::{Parser, Subcommand};
 use iroha_core::kura::{BlockIndex, BlockStore, LockStatus};
-use iroha_data_model::block::SignedBlock;
-use iroha_version::scale::DecodeVersioned;
 
 /// Kura inspector
 #[derive(Parser)]
@@ -119,13 +117,9 @@ fn print_blockchain(block_store_path: &Path, from_height: u64, block_count: u64)
             idx.start,
             idx.length
         );
-        let mut block_buf =
-            vec![0_u8; usize::try_from(idx.length).expect("index_len didn't fit in 32-bits")];
-        block_store
-            .read_block_data(idx.start, &mut block_buf)
-            .unwrap_or_else(|_| panic!("Failed to read block № {} data.", meta_index + 1));
-        let block = SignedBlock::decode_all_versioned(&block_buf)
-            .unwrap_or_else(|_| panic!("Failed to decode block № {}", meta_index + 1));
+        let block = block_store
+            .read_block(meta_index)
+            .unwrap_or_else(|_| panic!("Failed to read block № {}", meta_index + 1));
         println!("Block#{} :", meta_index + 1);
         println!("{block:#?}");
     }
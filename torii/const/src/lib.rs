@@ -8,8 +8,24 @@ pub mod uri {
         iroha_primitives::addr::socket_addr!(127.0.0.1:8080);
     /// Query URI is used to handle incoming Query requests.
     pub const QUERY: &str = "query";
+    /// Signed query URI is used to handle Query requests whose responses are signed by the peer.
+    pub const SIGNED_QUERY: &str = "signed_query";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "transaction";
+    /// Simulate transaction URI is used to execute a transaction without committing its changes.
+    pub const SIMULATE_TRANSACTION: &str = "simulate_transaction";
+    /// Can account execute URI is used to check whether an account is permitted to execute an instruction.
+    pub const CAN_ACCOUNT_EXECUTE: &str = "can_account_execute";
+    /// Transaction chunk URI is used to submit transactions too large for a single request piece by piece.
+    pub const TRANSACTION_CHUNK: &str = "transaction_chunk";
+    /// Pending transactions URI is used to inspect transactions of multisignature accounts waiting for signatures.
+    pub const PENDING_TRANSACTIONS: &str = "pending_transactions";
+    /// Queued transactions URI is used to inspect the transactions waiting in the queue.
+    pub const QUEUED_TRANSACTIONS: &str = "queued_transactions";
+    /// Cancel transaction URI is used to remove a queued transaction on request of its authority.
+    pub const CANCEL_TRANSACTION: &str = "cancel_transaction";
+    /// Queue status URI is used to inspect the number and age of transactions waiting in the queue.
+    pub const QUEUE_STATUS: &str = "queue_status";
     /// Block URI is used to handle incoming Block requests.
     pub const CONSENSUS: &str = "consensus";
     /// Health URI is used to handle incoming Healthcheck requests.
@@ -20,6 +36,8 @@ pub mod uri {
     pub const SUBSCRIPTION: &str = "events";
     /// The web socket uri used to subscribe to blocks stream.
     pub const BLOCKS_STREAM: &str = "block/stream";
+    /// The web socket uri used to receive the output of a query batch by batch.
+    pub const QUERY_STREAM: &str = "query/stream";
     /// The URI for local config changing inspecting
     pub const CONFIGURATION: &str = "configuration";
     /// URI to report status for administration
@@ -34,3 +52,10 @@ pub mod uri {
     /// URI for getting cpu profile
     pub const PROFILE: &str = "debug/pprof/profile";
 }
+
+pub mod header {
+    //! HTTP headers that Torii recognises in incoming requests.
+
+    /// Key under which Torii deduplicates resubmissions of the same transaction
+    pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+}
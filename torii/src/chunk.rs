@@ -0,0 +1,114 @@
+//! Reassembly of transactions too large to be submitted in one request.
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use iroha_crypto::HashOf;
+use iroha_data_model::transaction::{SignedTransaction, TransactionChunk};
+use iroha_version::scale::DecodeVersioned as _;
+
+/// Errors of transaction reassembly
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum Error {
+    /// Chunk {index} of {count} doesn't match the chunks of the transaction received before
+    Inconsistent {
+        /// Index of the chunk
+        index: u32,
+        /// Number of chunks of the transaction
+        count: u32,
+    },
+    /// Chunks waiting for reassembly exceed the maximum length of {0} bytes
+    TooLarge(u64),
+    /// Failed to decode the reassembled transaction
+    Decode(#[source] iroha_version::error::Error),
+    /// Reassembled transaction has a different hash
+    HashMismatch,
+}
+
+/// Chunks of transactions waiting for the rest of their chunks
+#[derive(Debug)]
+pub struct TransactionChunks {
+    /// Maximum total length of the chunks waiting for reassembly
+    max_len_bytes: u64,
+    /// How long the chunks of a transaction wait for the missing ones
+    time_to_live: Duration,
+    pending: Mutex<HashMap<HashOf<SignedTransaction>, PendingChunks>>,
+}
+
+#[derive(Debug)]
+struct PendingChunks {
+    first_received_at: Instant,
+    chunks: Vec<Option<Vec<u8>>>,
+}
+
+impl PendingChunks {
+    fn len_bytes(&self) -> u64 {
+        self.chunks
+            .iter()
+            .flatten()
+            .map(|bytes| bytes.len() as u64)
+            .sum()
+    }
+}
+
+impl TransactionChunks {
+    /// Construct [`Self`]
+    pub fn new(max_len_bytes: u64, time_to_live: Duration) -> Self {
+        Self {
+            max_len_bytes,
+            time_to_live,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Store the chunk and return the transaction once all of its chunks are received.
+    ///
+    /// # Errors
+    /// Fails if the chunk doesn't match the chunks received before, if there is no room
+    /// for it or if the reassembled transaction is invalid
+    pub fn insert(&self, chunk: TransactionChunk) -> Result<Option<SignedTransaction>, Error> {
+        let TransactionChunk {
+            hash,
+            index,
+            count,
+            bytes,
+        } = chunk;
+        let inconsistent = Error::Inconsistent {
+            index,
+            count: count.get(),
+        };
+
+        let mut pending = self.pending.lock().expect("Lock shouldn't be poisoned");
+        pending.retain(|_, chunks| chunks.first_received_at.elapsed() < self.time_to_live);
+
+        let pending_len_bytes: u64 = pending.values().map(PendingChunks::len_bytes).sum();
+        if pending_len_bytes.saturating_add(bytes.len() as u64) > self.max_len_bytes {
+            return Err(Error::TooLarge(self.max_len_bytes));
+        }
+
+        let chunks = pending.entry(hash).or_insert_with(|| PendingChunks {
+            first_received_at: Instant::now(),
+            chunks: vec![None; count.get() as usize],
+        });
+        let slot = (chunks.chunks.len() == count.get() as usize)
+            .then(|| chunks.chunks.get_mut(index as usize))
+            .flatten()
+            .ok_or(inconsistent)?;
+        *slot = Some(bytes);
+        if chunks.chunks.iter().any(Option::is_none) {
+            return Ok(None);
+        }
+
+        let chunks = pending.remove(&hash).expect("Inserted above");
+        let bytes: Vec<u8> = chunks.chunks.into_iter().flatten().flatten().collect();
+        let transaction = SignedTransaction::decode_all_versioned(&bytes).map_err(Error::Decode)?;
+        if transaction.hash() != hash {
+            return Err(Error::HashMismatch);
+        }
+
+        Ok(Some(transaction))
+    }
+}
@@ -1,12 +1,20 @@
 //! Iroha is a quite dynamic system so many events can happen.
 //! This module contains descriptions of such an events and
 //! utility Iroha Special Instructions to work with them.
+use std::{num::NonZeroU64, sync::Arc};
+
 use futures::TryStreamExt;
+use iroha_core::EventsSender;
 use iroha_data_model::events::prelude::*;
 use iroha_macro::error::ErrorTryFromEnum;
+use parity_scale_codec::DecodeAll as _;
+use tokio::sync::broadcast;
 use warp::ws::WebSocket;
 
-use crate::stream::{self, Sink, Stream};
+use crate::{
+    event_delivery::{self, EventDeliveries},
+    stream::{self, Sink, Stream},
+};
 
 /// Type of Stream error
 pub type StreamError = stream::Error<<WebSocket as Stream<EventSubscriptionRequest>>::Err>;
@@ -28,6 +36,12 @@ pub enum Error {
     /// Error that occurs than `WebSocket::next()` call returns `None`
     #[error("Can't receive message from stream")]
     CantReceiveMessage,
+    /// Error that occurs when events aren't received in time
+    #[error("Skipped {0} events which weren't received in time")]
+    Lagged(u64),
+    /// Error from acknowledged delivery of events
+    #[error("Acknowledged delivery error: {0}")]
+    Delivery(#[from] event_delivery::Error),
 }
 
 impl From<StreamError> for Error {
@@ -45,6 +59,9 @@ pub type Result<T> = core::result::Result<T, Error>;
 pub struct Consumer {
     stream: WebSocket,
     filters: Vec<EventFilterBox>,
+    from_block_height: Option<NonZeroU64>,
+    acknowledged_delivery: Option<SignedAcknowledgedDelivery>,
+    deliveries: Arc<EventDeliveries>,
 }
 
 impl Consumer {
@@ -53,9 +70,78 @@ impl Consumer {
     /// # Errors
     /// Can fail due to timeout or without message at websocket or during decoding request
     #[iroha_futures::telemetry_future]
-    pub async fn new(mut stream: WebSocket) -> Result<Self> {
-        let EventSubscriptionRequest(filters) = stream.recv().await?;
-        Ok(Consumer { stream, filters })
+    pub async fn new(mut stream: WebSocket, deliveries: Arc<EventDeliveries>) -> Result<Self> {
+        let EventSubscriptionRequest {
+            filters,
+            from_block_height,
+            acknowledged_delivery,
+        } = stream.recv().await?;
+        Ok(Consumer {
+            stream,
+            filters,
+            from_block_height,
+            acknowledged_delivery,
+            deliveries,
+        })
+    }
+
+    /// Subscribe for `events`. With acknowledged delivery, the subscription is resumed
+    /// and the events which weren't acknowledged before are sent again.
+    ///
+    /// # Errors
+    /// Fails if the subscription is already connected or can't be made, or if sending an event fails
+    pub async fn subscribe(
+        &mut self,
+        events: &EventsSender,
+    ) -> Result<broadcast::Receiver<EventBox>> {
+        let Some(delivery) = &self.acknowledged_delivery else {
+            return Ok(events.subscribe());
+        };
+
+        let (receiver, unacknowledged) = self
+            .deliveries
+            .connect(delivery, &self.filters, events)
+            .await?;
+        for message in unacknowledged {
+            if let Err(error) = self.stream.send(message).await {
+                self.unsubscribe(receiver);
+                return Err(error.into());
+            }
+        }
+        Ok(receiver)
+    }
+
+    /// Unsubscribe `receiver`. With acknowledged delivery, it keeps collecting the events
+    /// until the subscription is resumed.
+    pub fn unsubscribe(&self, receiver: broadcast::Receiver<EventBox>) {
+        if let Some(delivery) = &self.acknowledged_delivery {
+            self.deliveries.disconnect(
+                &delivery.authority,
+                &delivery.delivery.subscription_id,
+                receiver,
+            );
+        }
+    }
+
+    /// Handle `skipped` events lost because they weren't received in time.
+    ///
+    /// # Errors
+    /// Fails unless the overflow policy of acknowledged delivery allows dropping events
+    pub fn lagged(&self, skipped: u64) -> Result<()> {
+        match &self.acknowledged_delivery {
+            Some(delivery) => {
+                iroha_logger::warn!(skipped, "Events were not received in time");
+                Ok(self
+                    .deliveries
+                    .lagged(&delivery.authority, &delivery.delivery.subscription_id)?)
+            }
+            None => Err(Error::Lagged(skipped)),
+        }
+    }
+
+    /// Height of the block starting from which the events of committed blocks should be replayed
+    pub fn from_block_height(&self) -> Option<NonZeroU64> {
+        self.from_block_height
     }
 
     /// Forwards the `event` over the `stream` if it matches the `filter`.
@@ -68,13 +154,21 @@ impl Consumer {
             return Ok(());
         }
 
-        self.stream
-            .send(EventMessage(event))
-            .await
-            .map_err(Into::into)
+        let sent = match &self.acknowledged_delivery {
+            Some(delivery) => {
+                let message = self.deliveries.push(
+                    &delivery.authority,
+                    &delivery.delivery.subscription_id,
+                    event,
+                )?;
+                self.stream.send(message).await
+            }
+            None => self.stream.send(EventMessage(event)).await,
+        };
+        sent.map_err(Into::into)
     }
 
-    /// Listen for `Close` message in loop
+    /// Listen for `Close` message in loop, handling acknowledgements of the events
     ///
     /// # Errors
     /// Can fail if can't receive message from stream for some reason
@@ -83,6 +177,18 @@ impl Consumer {
             if message.is_close() {
                 return Ok(());
             }
+            if let (Some(delivery), true) = (&self.acknowledged_delivery, message.is_binary()) {
+                if let Ok(acknowledgement) =
+                    EventAcknowledgement::decode_all(&mut message.as_bytes())
+                {
+                    self.deliveries.acknowledge(
+                        &delivery.authority,
+                        &delivery.delivery.subscription_id,
+                        acknowledgement.sequence_number,
+                    );
+                    continue;
+                }
+            }
             iroha_logger::warn!("Unexpected message received: {:?}", message);
         }
         Err(Error::CantReceiveMessage)
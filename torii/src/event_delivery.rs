@@ -0,0 +1,617 @@
+//! Buffers of the events sent over subscriptions with acknowledged delivery.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use iroha_core::EventsSender;
+use iroha_data_model::{account::AccountId, events::prelude::*};
+use tokio::{
+    sync::{
+        broadcast::{self, error::RecvError},
+        oneshot,
+    },
+    task::JoinHandle,
+};
+
+/// Errors of acknowledged delivery
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum Error {
+    /// Subscription `{0}` is already connected
+    AlreadyConnected(String),
+    /// Unacknowledged events of subscription `{0}` exceed the capacity, the subscription is terminated
+    Overflow(String),
+    /// Acknowledged delivery of subscription `{0}` isn't signed by its authority
+    InvalidSignature(String),
+    /// Account `{0}` has too many subscriptions with acknowledged delivery
+    TooManyAccountSubscriptions(AccountId),
+    /// Peer has too many subscriptions with acknowledged delivery
+    TooManySubscriptions,
+}
+
+/// Limits of the subscriptions with acknowledged delivery
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Maximum number of unacknowledged events of a subscription
+    pub capacity: NonZeroUsize,
+    /// How long the events of a disconnected subscription are kept
+    pub idle_time: Duration,
+    /// Maximum number of subscriptions of an account
+    pub max_account_subscriptions: NonZeroUsize,
+    /// Maximum number of subscriptions of all accounts
+    pub max_subscriptions: NonZeroUsize,
+}
+
+/// Events of the subscriptions with acknowledged delivery which weren't acknowledged yet
+#[derive(Debug)]
+pub struct EventDeliveries {
+    limits: Limits,
+    /// Subscriptions of every account by their ids
+    subscriptions: Mutex<HashMap<AccountId, HashMap<String, Subscription>>>,
+}
+
+#[derive(Debug)]
+struct Subscription {
+    overflow_policy: EventOverflowPolicy,
+    filters: Vec<EventFilterBox>,
+    next_sequence_number: u64,
+    unacknowledged: VecDeque<AcknowledgedEventMessage>,
+    /// Whether the subscription overflowed while its subscriber was disconnected
+    terminated: bool,
+    /// Task collecting the events emitted while the subscriber is disconnected
+    disconnected: Option<Drain>,
+}
+
+/// Task moving the events of a disconnected subscription from the events channel
+/// into its unacknowledged events, so that they are kept within the capacity and
+/// the overflow policy applies to them
+#[derive(Debug)]
+struct Drain {
+    stop: oneshot::Sender<()>,
+    task: JoinHandle<broadcast::Receiver<EventBox>>,
+}
+
+impl Drain {
+    /// Stop the task and take back the receiver of the events channel
+    async fn stop(self) -> broadcast::Receiver<EventBox> {
+        // The task may have already finished, returning the receiver
+        let _ = self.stop.send(());
+        self.task.await.expect("Draining events doesn't panic")
+    }
+}
+
+impl EventDeliveries {
+    /// Construct [`Self`]
+    pub fn new(limits: Limits) -> Self {
+        Self {
+            limits,
+            subscriptions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Connect the subscriber of `delivery`, resuming its subscription if there is one.
+    /// The subscription keeps only the events matching `filters`.
+    ///
+    /// Returns the receiver of the events to deliver and the events to send again.
+    ///
+    /// # Errors
+    /// Fails if the delivery isn't signed by its authority, if another subscriber is connected
+    /// to the subscription or if a new subscription would exceed the limits
+    pub async fn connect(
+        &self,
+        delivery: &SignedAcknowledgedDelivery,
+        filters: &[EventFilterBox],
+        events: &EventsSender,
+    ) -> Result<(broadcast::Receiver<EventBox>, Vec<AcknowledgedEventMessage>), Error> {
+        let (authority, subscription_id) =
+            (&delivery.authority, &delivery.delivery.subscription_id);
+        if !delivery.is_signed_by_authority() {
+            return Err(Error::InvalidSignature(subscription_id.clone()));
+        }
+
+        let drain = {
+            let mut subscriptions = self.lock();
+            match subscriptions
+                .get_mut(authority)
+                .and_then(|account_subscriptions| account_subscriptions.get_mut(subscription_id))
+            {
+                Some(subscription) if subscription.terminated => {
+                    Self::remove(&mut subscriptions, authority, subscription_id);
+                    return Err(Error::Overflow(subscription_id.clone()));
+                }
+                Some(subscription) => {
+                    let drain = subscription
+                        .disconnected
+                        .take()
+                        .ok_or_else(|| Error::AlreadyConnected(subscription_id.clone()))?;
+                    subscription.overflow_policy = delivery.delivery.overflow_policy;
+                    subscription.filters = filters.to_vec();
+                    drain
+                }
+                None => {
+                    let total = subscriptions.values().map(HashMap::len).sum::<usize>();
+                    if total >= self.limits.max_subscriptions.get() {
+                        return Err(Error::TooManySubscriptions);
+                    }
+                    let account_subscriptions = subscriptions.entry(authority.clone()).or_default();
+                    if account_subscriptions.len() >= self.limits.max_account_subscriptions.get() {
+                        return Err(Error::TooManyAccountSubscriptions(authority.clone()));
+                    }
+                    account_subscriptions.insert(
+                        subscription_id.clone(),
+                        Subscription {
+                            overflow_policy: delivery.delivery.overflow_policy,
+                            filters: filters.to_vec(),
+                            next_sequence_number: 0,
+                            unacknowledged: VecDeque::new(),
+                            terminated: false,
+                            disconnected: None,
+                        },
+                    );
+                    return Ok((events.subscribe(), Vec::new()));
+                }
+            }
+        };
+
+        let receiver = drain.stop().await;
+        // The subscription is gone if it overflowed while being drained
+        let subscriptions = self.lock();
+        let subscription = subscriptions
+            .get(authority)
+            .and_then(|account_subscriptions| account_subscriptions.get(subscription_id))
+            .ok_or_else(|| Error::Overflow(subscription_id.clone()))?;
+        Ok((
+            receiver,
+            subscription.unacknowledged.iter().cloned().collect(),
+        ))
+    }
+
+    /// Keep collecting the events of the subscription from `receiver` until the subscriber
+    /// connects again. The subscription is forgotten if it stays disconnected for the idle time.
+    pub fn disconnect(
+        self: &Arc<Self>,
+        authority: &AccountId,
+        subscription_id: &str,
+        receiver: broadcast::Receiver<EventBox>,
+    ) {
+        let mut subscriptions = self.lock();
+        let Some(subscription) = subscriptions
+            .get_mut(authority)
+            .and_then(|account_subscriptions| account_subscriptions.get_mut(subscription_id))
+        else {
+            return;
+        };
+
+        let (stop, stopped) = oneshot::channel();
+        let task = tokio::spawn(Arc::clone(self).drain(
+            authority.clone(),
+            subscription_id.to_owned(),
+            receiver,
+            stopped,
+        ));
+        subscription.disconnected = Some(Drain { stop, task });
+    }
+
+    async fn drain(
+        self: Arc<Self>,
+        authority: AccountId,
+        subscription_id: String,
+        mut receiver: broadcast::Receiver<EventBox>,
+        mut stopped: oneshot::Receiver<()>,
+    ) -> broadcast::Receiver<EventBox> {
+        let idle_timeout = tokio::time::sleep(self.limits.idle_time);
+        tokio::pin!(idle_timeout);
+
+        loop {
+            tokio::select! {
+                // Also resolves once the subscription is dropped
+                _ = &mut stopped => break,
+                () = &mut idle_timeout => {
+                    self.forget_disconnected(&authority, &subscription_id);
+                    break;
+                }
+                event = receiver.recv() => {
+                    let kept = match event {
+                        Ok(event) => self.push_matching(&authority, &subscription_id, event),
+                        Err(RecvError::Lagged(_)) => self.lagged(&authority, &subscription_id),
+                        Err(RecvError::Closed) => break,
+                    };
+                    if kept.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        receiver
+    }
+
+    /// Remove the subscription unless its subscriber is connecting again
+    fn forget_disconnected(&self, authority: &AccountId, subscription_id: &str) {
+        let mut subscriptions = self.lock();
+        let Some(account_subscriptions) = subscriptions.get_mut(authority) else {
+            return;
+        };
+        if account_subscriptions
+            .get(subscription_id)
+            .is_some_and(|subscription| subscription.disconnected.is_some())
+        {
+            account_subscriptions.remove(subscription_id);
+        }
+        if account_subscriptions.is_empty() {
+            subscriptions.remove(authority);
+        }
+    }
+
+    fn push_matching(
+        &self,
+        authority: &AccountId,
+        subscription_id: &str,
+        event: EventBox,
+    ) -> Result<(), Error> {
+        let matches = self
+            .lock()
+            .get(authority)
+            .and_then(|account_subscriptions| account_subscriptions.get(subscription_id))
+            .is_some_and(|subscription| {
+                subscription
+                    .filters
+                    .iter()
+                    .any(|filter| filter.matches(&event))
+            });
+        if matches {
+            self.push(authority, subscription_id, event)?;
+        }
+        Ok(())
+    }
+
+    /// Assign a sequence number to `event` and keep it until it is acknowledged.
+    ///
+    /// # Errors
+    /// Fails if the subscription is terminated due to overflow
+    pub fn push(
+        &self,
+        authority: &AccountId,
+        subscription_id: &str,
+        event: EventBox,
+    ) -> Result<AcknowledgedEventMessage, Error> {
+        let mut subscriptions = self.lock();
+        let subscription = subscriptions
+            .get_mut(authority)
+            .and_then(|account_subscriptions| account_subscriptions.get_mut(subscription_id))
+            .ok_or_else(|| Error::Overflow(subscription_id.to_owned()))?;
+
+        let message = AcknowledgedEventMessage {
+            sequence_number: subscription.next_sequence_number,
+            event,
+        };
+        subscription.next_sequence_number += 1;
+        subscription.unacknowledged.push_back(message.clone());
+
+        if subscription.unacknowledged.len() > self.limits.capacity.get() {
+            match subscription.overflow_policy {
+                EventOverflowPolicy::DropOldest => {
+                    subscription.unacknowledged.pop_front();
+                }
+                EventOverflowPolicy::Terminate => {
+                    Self::terminate(&mut subscriptions, authority, subscription_id);
+                    return Err(Error::Overflow(subscription_id.to_owned()));
+                }
+            }
+        }
+
+        Ok(message)
+    }
+
+    /// Handle the loss of events not received from the events channel in time.
+    ///
+    /// # Errors
+    /// Fails if the subscription is terminated due to overflow
+    pub fn lagged(&self, authority: &AccountId, subscription_id: &str) -> Result<(), Error> {
+        let mut subscriptions = self.lock();
+        let overflow_policy = subscriptions
+            .get(authority)
+            .and_then(|account_subscriptions| account_subscriptions.get(subscription_id))
+            .map(|subscription| subscription.overflow_policy);
+
+        if overflow_policy == Some(EventOverflowPolicy::DropOldest) {
+            return Ok(());
+        }
+        Self::terminate(&mut subscriptions, authority, subscription_id);
+        Err(Error::Overflow(subscription_id.to_owned()))
+    }
+
+    /// Forget the events of the subscription up to the one with `sequence_number`
+    pub fn acknowledge(&self, authority: &AccountId, subscription_id: &str, sequence_number: u64) {
+        if let Some(subscription) = self
+            .lock()
+            .get_mut(authority)
+            .and_then(|account_subscriptions| account_subscriptions.get_mut(subscription_id))
+        {
+            while subscription
+                .unacknowledged
+                .front()
+                .is_some_and(|message| message.sequence_number <= sequence_number)
+            {
+                subscription.unacknowledged.pop_front();
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<AccountId, HashMap<String, Subscription>>> {
+        self.subscriptions
+            .lock()
+            .expect("Lock shouldn't be poisoned")
+    }
+
+    /// Terminate the overflowed subscription. The subscription of a disconnected subscriber
+    /// is kept without events until it connects again, so that it learns about the overflow.
+    fn terminate(
+        subscriptions: &mut HashMap<AccountId, HashMap<String, Subscription>>,
+        authority: &AccountId,
+        subscription_id: &str,
+    ) {
+        if let Some(subscription) = subscriptions
+            .get_mut(authority)
+            .and_then(|account_subscriptions| account_subscriptions.get_mut(subscription_id))
+            .filter(|subscription| subscription.disconnected.is_some())
+        {
+            subscription.unacknowledged.clear();
+            subscription.terminated = true;
+        } else {
+            Self::remove(subscriptions, authority, subscription_id);
+        }
+    }
+
+    fn remove(
+        subscriptions: &mut HashMap<AccountId, HashMap<String, Subscription>>,
+        authority: &AccountId,
+        subscription_id: &str,
+    ) {
+        if let Some(account_subscriptions) = subscriptions.get_mut(authority) {
+            account_subscriptions.remove(subscription_id);
+            if account_subscriptions.is_empty() {
+                subscriptions.remove(authority);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::KeyPair;
+    use iroha_data_model::prelude::*;
+    use nonzero_ext::nonzero;
+
+    use super::*;
+
+    fn event(domain: &str) -> EventBox {
+        DataEvent::from(DomainEvent::Deleted(domain.parse().expect("Valid"))).into()
+    }
+
+    fn filters() -> Vec<EventFilterBox> {
+        vec![DataEventFilter::Any.into()]
+    }
+
+    fn deliveries(capacity: NonZeroUsize) -> Arc<EventDeliveries> {
+        Arc::new(EventDeliveries::new(Limits {
+            capacity,
+            idle_time: Duration::from_secs(60),
+            max_account_subscriptions: nonzero!(2_usize),
+            max_subscriptions: nonzero!(3_usize),
+        }))
+    }
+
+    fn acknowledged_delivery(
+        key_pair: &KeyPair,
+        subscription_id: &str,
+        overflow_policy: EventOverflowPolicy,
+    ) -> SignedAcknowledgedDelivery {
+        let authority = AccountId::new(
+            "wonderland".parse().expect("Valid"),
+            key_pair.public_key().clone(),
+        );
+        let delivery = AcknowledgedDelivery::new(subscription_id.to_owned(), overflow_policy);
+        SignedAcknowledgedDelivery::new(authority, delivery, key_pair)
+    }
+
+    fn sequence_numbers(messages: &[AcknowledgedEventMessage]) -> Vec<u64> {
+        messages
+            .iter()
+            .map(|message| message.sequence_number)
+            .collect()
+    }
+
+    /// Let the tasks draining the events of disconnected subscriptions run
+    async fn drain_events() {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    #[tokio::test]
+    async fn unacknowledged_events_are_sent_again() {
+        let (events, _) = broadcast::channel(10);
+        let deliveries = deliveries(nonzero!(10_usize));
+        let delivery = acknowledged_delivery(
+            &KeyPair::random(),
+            "subscription",
+            EventOverflowPolicy::DropOldest,
+        );
+        let (authority, id) = (&delivery.authority, &delivery.delivery.subscription_id);
+
+        let (receiver, resent) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("New");
+        assert!(resent.is_empty());
+        for domain in ["a", "b", "c"] {
+            deliveries
+                .push(authority, id, event(domain))
+                .expect("Not full");
+        }
+        deliveries.acknowledge(authority, id, 0);
+        assert!(matches!(
+            deliveries.connect(&delivery, &filters(), &events).await,
+            Err(Error::AlreadyConnected(_))
+        ));
+        deliveries.disconnect(authority, id, receiver);
+
+        // Events emitted while disconnected are collected until resubscription
+        events.send(event("d")).expect("Receiver is kept");
+        drain_events().await;
+        let (mut receiver, resent) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("Resumed");
+        assert_eq!(sequence_numbers(&resent), [1, 2, 3]);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn overflow_policy_is_applied() {
+        let (events, _) = broadcast::channel(10);
+        let deliveries = deliveries(nonzero!(2_usize));
+        let key_pair = KeyPair::random();
+
+        let delivery =
+            acknowledged_delivery(&key_pair, "subscription", EventOverflowPolicy::DropOldest);
+        let (authority, id) = (&delivery.authority, &delivery.delivery.subscription_id);
+        let (receiver, _) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("New");
+        for domain in ["a", "b", "c"] {
+            deliveries
+                .push(authority, id, event(domain))
+                .expect("Oldest is dropped");
+        }
+        deliveries.disconnect(authority, id, receiver);
+        let (receiver, resent) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("Resumed");
+        assert_eq!(sequence_numbers(&resent), [1, 2]);
+
+        // Policy of the resumed subscription is updated
+        deliveries.disconnect(authority, id, receiver);
+        let delivery =
+            acknowledged_delivery(&key_pair, "subscription", EventOverflowPolicy::Terminate);
+        let (_receiver, _) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("Resumed");
+        assert!(matches!(
+            deliveries.push(authority, id, event("d")),
+            Err(Error::Overflow(_))
+        ));
+        let (_receiver, resent) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("New");
+        assert!(resent.is_empty());
+    }
+
+    #[tokio::test]
+    async fn overflow_policy_is_applied_while_disconnected() {
+        let (events, _) = broadcast::channel(10);
+        let deliveries = deliveries(nonzero!(2_usize));
+        let key_pair = KeyPair::random();
+
+        let delivery =
+            acknowledged_delivery(&key_pair, "subscription", EventOverflowPolicy::DropOldest);
+        let (authority, id) = (&delivery.authority, &delivery.delivery.subscription_id);
+        let (receiver, _) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("New");
+        deliveries.disconnect(authority, id, receiver);
+        for domain in ["a", "b", "c", "d", "e"] {
+            events.send(event(domain)).expect("Receiver is kept");
+        }
+        drain_events().await;
+        let (receiver, resent) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("Resumed");
+        assert_eq!(sequence_numbers(&resent), [3, 4]);
+        deliveries.disconnect(authority, id, receiver);
+
+        // Subscriber learns about the overflow when it connects again
+        let delivery =
+            acknowledged_delivery(&key_pair, "subscription", EventOverflowPolicy::Terminate);
+        let (receiver, _) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("Resumed");
+        deliveries.disconnect(authority, id, receiver);
+        events.send(event("f")).expect("Receiver is kept");
+        drain_events().await;
+        assert!(matches!(
+            deliveries.connect(&delivery, &filters(), &events).await,
+            Err(Error::Overflow(_))
+        ));
+        let (_receiver, resent) = deliveries
+            .connect(&delivery, &filters(), &events)
+            .await
+            .expect("New");
+        assert!(resent.is_empty());
+    }
+
+    #[tokio::test]
+    async fn subscriptions_are_limited() {
+        let (events, _) = broadcast::channel(10);
+        let deliveries = deliveries(nonzero!(2_usize));
+        let (alice, bob, carol) = (KeyPair::random(), KeyPair::random(), KeyPair::random());
+        let policy = EventOverflowPolicy::DropOldest;
+
+        let mut forged = acknowledged_delivery(&alice, "a", policy);
+        forged.authority = acknowledged_delivery(&bob, "a", policy).authority;
+        assert!(matches!(
+            deliveries.connect(&forged, &filters(), &events).await,
+            Err(Error::InvalidSignature(_))
+        ));
+
+        let mut receivers = Vec::new();
+        for delivery in [
+            acknowledged_delivery(&alice, "a", policy),
+            acknowledged_delivery(&alice, "b", policy),
+        ] {
+            receivers.push(
+                deliveries
+                    .connect(&delivery, &filters(), &events)
+                    .await
+                    .expect("Within limits"),
+            );
+        }
+        assert!(matches!(
+            deliveries
+                .connect(
+                    &acknowledged_delivery(&alice, "c", policy),
+                    &filters(),
+                    &events
+                )
+                .await,
+            Err(Error::TooManyAccountSubscriptions(_))
+        ));
+        deliveries
+            .connect(
+                &acknowledged_delivery(&bob, "a", policy),
+                &filters(),
+                &events,
+            )
+            .await
+            .expect("Within limits");
+        assert!(matches!(
+            deliveries
+                .connect(
+                    &acknowledged_delivery(&carol, "a", policy),
+                    &filters(),
+                    &events
+                )
+                .await,
+            Err(Error::TooManySubscriptions)
+        ));
+    }
+}
@@ -0,0 +1,58 @@
+//! Deduplication of transactions resubmitted under the same idempotency key.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use iroha_crypto::HashOf;
+use iroha_data_model::{account::AccountId, transaction::SignedTransaction};
+
+/// Hashes of the transactions submitted under idempotency keys within the last window
+#[derive(Debug)]
+pub struct IdempotencyKeys {
+    /// How long a key refers to the transaction first submitted under it
+    window: Duration,
+    keys: Mutex<HashMap<(AccountId, String), (HashOf<SignedTransaction>, Instant)>>,
+}
+
+impl IdempotencyKeys {
+    /// Construct [`Self`]
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserve `key` of `authority` for the transaction with the given `hash`.
+    ///
+    /// # Errors
+    /// Returns the hash of the transaction the key is already reserved for
+    pub fn reserve(
+        &self,
+        authority: &AccountId,
+        key: String,
+        hash: HashOf<SignedTransaction>,
+    ) -> Result<(), HashOf<SignedTransaction>> {
+        let mut keys = self.keys.lock().expect("Lock shouldn't be poisoned");
+        keys.retain(|_, (_, reserved_at)| reserved_at.elapsed() < self.window);
+
+        match keys.entry((authority.clone(), key)) {
+            Entry::Occupied(entry) => Err(entry.get().0),
+            Entry::Vacant(entry) => {
+                entry.insert((hash, Instant::now()));
+                Ok(())
+            }
+        }
+    }
+
+    /// Release `key` of `authority` if the transaction reserved it for wasn't accepted
+    pub fn release(&self, authority: &AccountId, key: String) {
+        self.keys
+            .lock()
+            .expect("Lock shouldn't be poisoned")
+            .remove(&(authority.clone(), key));
+    }
+}
@@ -25,9 +25,10 @@ use iroha_core::{
     state::State,
     EventsSender,
 };
+use iroha_crypto::KeyPair;
 use iroha_data_model::ChainId;
 use iroha_primitives::addr::SocketAddr;
-use iroha_torii_const::uri;
+use iroha_torii_const::{header, uri};
 use tokio::{sync::Notify, task};
 use utils::*;
 use warp::{
@@ -39,13 +40,18 @@ use warp::{
 
 #[macro_use]
 pub(crate) mod utils;
+mod chunk;
 mod event;
+mod event_delivery;
+mod idempotency;
+mod query_budget;
 mod routing;
 mod stream;
 
 /// Main network handler and the only entrypoint of the Iroha.
 pub struct Torii {
     chain_id: Arc<ChainId>,
+    key_pair: KeyPair,
     kiso: KisoHandle,
     queue: Arc<Queue>,
     events: EventsSender,
@@ -53,6 +59,10 @@ pub struct Torii {
     query_service: LiveQueryStoreHandle,
     kura: Arc<Kura>,
     transaction_max_content_length: u64,
+    chunks: Arc<chunk::TransactionChunks>,
+    idempotency_keys: Arc<idempotency::IdempotencyKeys>,
+    query_budgets: Arc<query_budget::QueryBudgets>,
+    event_deliveries: Arc<event_delivery::EventDeliveries>,
     address: SocketAddr,
     state: Arc<State>,
     #[cfg(feature = "telemetry")]
@@ -64,6 +74,7 @@ impl Torii {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         chain_id: ChainId,
+        key_pair: KeyPair,
         kiso: KisoHandle,
         config: Config,
         queue: Arc<Queue>,
@@ -74,8 +85,14 @@ impl Torii {
         state: Arc<State>,
         #[cfg(feature = "telemetry")] metrics_reporter: MetricsReporter,
     ) -> Self {
+        let chunks = Arc::new(chunk::TransactionChunks::new(
+            config.max_chunked_transactions_len_bytes,
+            queue.tx_time_to_live,
+        ));
+
         Self {
             chain_id: Arc::new(chain_id),
+            key_pair,
             kiso,
             queue,
             events,
@@ -87,6 +104,23 @@ impl Torii {
             metrics_reporter,
             address: config.address.into_value(),
             transaction_max_content_length: config.max_content_len_bytes,
+            chunks,
+            idempotency_keys: Arc::new(idempotency::IdempotencyKeys::new(
+                config.idempotency_window,
+            )),
+            query_budgets: Arc::new(query_budget::QueryBudgets::new(
+                config.query_cost_per_second,
+                config.singular_query_cost,
+                config.iterable_query_cost,
+            )),
+            event_deliveries: Arc::new(event_delivery::EventDeliveries::new(
+                event_delivery::Limits {
+                    capacity: config.event_buffer_capacity,
+                    idle_time: config.event_buffer_idle_time,
+                    max_account_subscriptions: config.event_subscriptions_per_account_max,
+                    max_subscriptions: config.event_subscriptions_max,
+                },
+            )),
         }
     }
 
@@ -102,7 +136,28 @@ impl Torii {
                 .and(add_state!(self.kiso))
                 .and_then(|kiso| async move {
                     Ok::<_, Infallible>(WarpResult(routing::handle_get_configuration(kiso).await))
-                }),
+                })
+                .or(warp::path(uri::PENDING_TRANSACTIONS)
+                    .and(add_state!(self.queue))
+                    .and(warp::query::<routing::PendingTransactionsParams>())
+                    .and_then(|queue, params| async move {
+                        Ok::<_, Infallible>(
+                            routing::handle_pending_transactions(queue, params).await,
+                        )
+                    }))
+                .or(warp::path(uri::QUEUED_TRANSACTIONS)
+                    .and(add_state!(self.queue, self.state.clone()))
+                    .and(warp::query::<routing::QueuedTransactionsParams>())
+                    .and_then(|queue, state, params| async move {
+                        Ok::<_, Infallible>(
+                            routing::handle_queued_transactions(queue, state, params).await,
+                        )
+                    }))
+                .or(warp::path(uri::QUEUE_STATUS)
+                    .and(add_state!(self.queue, self.state.clone()))
+                    .and_then(|queue, state| async move {
+                        Ok::<_, Infallible>(routing::handle_queue_status(queue, state).await)
+                    })),
         );
 
         #[cfg(feature = "telemetry")]
@@ -163,21 +218,87 @@ impl Torii {
 
         let post_router = warp::post()
             .and(
-                endpoint4(
+                endpoint6(
                     routing::handle_transaction,
                     warp::path(uri::TRANSACTION)
-                        .and(add_state!(self.chain_id, self.queue, self.state.clone()))
+                        .and(add_state!(
+                            self.chain_id,
+                            self.queue,
+                            self.state.clone(),
+                            self.idempotency_keys
+                        ))
+                        .and(warp::header::optional::<String>(header::IDEMPOTENCY_KEY))
                         .and(warp::body::content_length_limit(
                             self.transaction_max_content_length,
                         ))
                         .and(body::versioned()),
                 )
                 .or(endpoint3(
+                    routing::handle_simulate_transaction,
+                    warp::path(uri::SIMULATE_TRANSACTION)
+                        .and(add_state!(self.chain_id, self.state.clone()))
+                        .and(warp::body::content_length_limit(
+                            self.transaction_max_content_length,
+                        ))
+                        .and(body::versioned()),
+                ))
+                .or(endpoint2(
+                    routing::handle_can_account_execute,
+                    warp::path(uri::CAN_ACCOUNT_EXECUTE)
+                        .and(add_state!(self.state.clone()))
+                        .and(warp::body::content_length_limit(
+                            self.transaction_max_content_length,
+                        ))
+                        .and(warp::body::bytes()),
+                ))
+                .or(endpoint5(
+                    routing::handle_transaction_chunk,
+                    warp::path(uri::TRANSACTION_CHUNK)
+                        .and(add_state!(
+                            self.chain_id,
+                            self.queue,
+                            self.state.clone(),
+                            self.chunks
+                        ))
+                        .and(warp::body::content_length_limit(
+                            self.transaction_max_content_length,
+                        ))
+                        .and(warp::body::bytes()),
+                ))
+                .or(endpoint4(
                     routing::handle_queries,
                     warp::path(uri::QUERY)
-                        .and(add_state!(self.query_service, self.state.clone(),))
+                        .and(add_state!(
+                            self.query_service,
+                            self.query_budgets,
+                            self.state.clone(),
+                        ))
+                        .and(routing::client_query_request()),
+                ))
+                .or(endpoint5(
+                    routing::handle_signed_queries,
+                    warp::path(uri::SIGNED_QUERY)
+                        .and(add_state!(
+                            self.key_pair,
+                            self.query_service,
+                            self.query_budgets,
+                            self.state.clone(),
+                        ))
                         .and(routing::client_query_request()),
                 ))
+                .or(endpoint4(
+                    routing::handle_pending_transaction_signature,
+                    warp::path(uri::PENDING_TRANSACTIONS)
+                        .and(add_state!(self.queue, self.state.clone()))
+                        .and(warp::query::<routing::PendingSignatureParams>())
+                        .and(warp::body::bytes()),
+                ))
+                .or(endpoint2(
+                    routing::handle_cancel_transaction,
+                    warp::path(uri::CANCEL_TRANSACTION)
+                        .and(add_state!(self.queue))
+                        .and(warp::body::bytes()),
+                ))
                 .or(endpoint2(
                     routing::handle_post_configuration,
                     warp::path(uri::CONFIGURATION)
@@ -188,12 +309,14 @@ impl Torii {
             .recover(|rejection| async move { body::recover_versioned(rejection) });
 
         let events_ws_router = warp::path(uri::SUBSCRIPTION)
-            .and(add_state!(self.events))
+            .and(add_state!(self.events, self.kura, self.event_deliveries))
             .and(warp::ws())
-            .map(|events, ws: Ws| {
+            .map(|events, kura, deliveries, ws: Ws| {
                 ws.on_upgrade(|this_ws| async move {
-                    if let Err(error) =
-                        routing::subscription::handle_subscription(events, this_ws).await
+                    if let Err(error) = routing::subscription::handle_subscription(
+                        events, kura, deliveries, this_ws,
+                    )
+                    .await
                     {
                         iroha_logger::error!(%error, "Failure during subscription");
                     }
@@ -220,7 +343,32 @@ impl Torii {
                 })
             });
 
-        let ws_router = events_ws_router.or(blocks_ws_router);
+        let query_ws_router_path = uri::QUERY_STREAM
+            .split('/')
+            .skip_while(|p| p.is_empty())
+            .fold(warp::any().boxed(), |path_filter, path| {
+                path_filter.and(warp::path(path)).boxed()
+            });
+
+        let queries_ws_router = query_ws_router_path
+            .and(add_state!(
+                self.query_service,
+                self.query_budgets,
+                self.state.clone()
+            ))
+            .and(warp::ws())
+            .map(|query_service, query_budgets, state, ws: Ws| {
+                ws.on_upgrade(|this_ws| async move {
+                    if let Err(error) =
+                        routing::handle_queries_stream(query_service, query_budgets, state, this_ws)
+                            .await
+                    {
+                        iroha_logger::error!(%error, "Failed to stream query output");
+                    }
+                })
+            });
+
+        let ws_router = events_ws_router.or(blocks_ws_router).or(queries_ws_router);
 
         warp::any()
             .and(
@@ -301,6 +449,10 @@ pub enum Error {
     Config(#[source] eyre::Report),
     /// Failed to push into queue
     PushIntoQueue(#[from] Box<queue::Error>),
+    /// Failed to decode request body
+    DecodeBody(#[source] parity_scale_codec::Error),
+    /// Failed to reassemble transaction from chunks
+    TransactionChunk(#[from] chunk::Error),
     #[cfg(feature = "telemetry")]
     /// Failed to get Prometheus metrics
     Prometheus(#[source] eyre::Report),
@@ -334,11 +486,12 @@ impl Error {
 
         match self {
             Query(e) => Self::query_status_code(e),
-            AcceptTransaction(_) => StatusCode::BAD_REQUEST,
+            AcceptTransaction(_) | DecodeBody(_) | TransactionChunk(_) => StatusCode::BAD_REQUEST,
             Config(_) | StatusSegmentNotFound(_) => StatusCode::NOT_FOUND,
             PushIntoQueue(err) => match **err {
                 queue::Error::Full => StatusCode::INTERNAL_SERVER_ERROR,
                 queue::Error::SignatoryInconsistent => StatusCode::UNAUTHORIZED,
+                queue::Error::NotPending | queue::Error::NotInQueue => StatusCode::NOT_FOUND,
                 _ => StatusCode::BAD_REQUEST,
             },
             #[cfg(feature = "telemetry")]
@@ -365,6 +518,8 @@ impl Error {
                 }
                 Signature(_) => StatusCode::UNAUTHORIZED,
                 Find(_) => StatusCode::NOT_FOUND,
+                MetadataHistoryNotIndexed => StatusCode::NOT_IMPLEMENTED,
+                RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
             },
             TooComplex => StatusCode::UNPROCESSABLE_ENTITY,
             InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -0,0 +1,168 @@
+//! Per-account budget limiting how much query work a single client can request.
+
+use std::{collections::HashMap, sync::Mutex, time::Instant};
+
+use iroha_core::smartcontracts::query::LazyQueryOutput;
+use iroha_data_model::{account::AccountId, query::error::QueryExecutionFail};
+
+/// Budgets of the accounts which submitted queries recently.
+///
+/// Every account may spend up to `cost_per_second` cost units at once,
+/// and the spent units are restored at the same rate.
+#[derive(Debug)]
+pub struct QueryBudgets {
+    /// Cost units restored per second. `0` disables the limit
+    cost_per_second: u32,
+    /// Cost of a query returning a single value
+    singular_query_cost: u32,
+    /// Cost of a query returning a collection of values
+    iterable_query_cost: u32,
+    budgets: Mutex<HashMap<AccountId, Budget>>,
+}
+
+/// Budget of a single account, in thousandths of cost units.
+#[derive(Debug)]
+struct Budget {
+    available: u64,
+    updated_at: Instant,
+}
+
+impl QueryBudgets {
+    /// Construct [`Self`]
+    pub fn new(cost_per_second: u32, singular_query_cost: u32, iterable_query_cost: u32) -> Self {
+        Self {
+            cost_per_second,
+            singular_query_cost,
+            iterable_query_cost,
+            budgets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Charge `authority` for the query which produced `output`.
+    ///
+    /// # Errors
+    /// Fails with [`QueryExecutionFail::RateLimited`] if the budget of `authority` is exhausted
+    pub fn charge(
+        &self,
+        authority: &AccountId,
+        output: &LazyQueryOutput<'_>,
+    ) -> Result<(), QueryExecutionFail> {
+        let cost = match output {
+            LazyQueryOutput::QueryOutput(_) => self.singular_query_cost,
+            LazyQueryOutput::Iter(_) => self.iterable_query_cost,
+        };
+        self.charge_cost(authority, cost)
+    }
+
+    fn charge_cost(&self, authority: &AccountId, cost: u32) -> Result<(), QueryExecutionFail> {
+        if self.cost_per_second == 0 {
+            return Ok(());
+        }
+        let capacity = u64::from(self.cost_per_second) * 1000;
+        let cost = u64::from(cost) * 1000;
+
+        let mut budgets = self.budgets.lock().expect("Lock shouldn't be poisoned");
+        let now = Instant::now();
+        for budget in budgets.values_mut() {
+            budget.restore(now, self.cost_per_second, capacity);
+        }
+        budgets.retain(|_, budget| budget.available < capacity);
+
+        let budget = budgets.entry(authority.clone()).or_insert(Budget {
+            available: capacity,
+            updated_at: now,
+        });
+        if budget.available < cost {
+            let retry_after_ms =
+                (cost - budget.available).div_ceil(u64::from(self.cost_per_second));
+            return Err(QueryExecutionFail::RateLimited(retry_after_ms));
+        }
+        budget.available -= cost;
+
+        Ok(())
+    }
+}
+
+impl Budget {
+    fn restore(&mut self, now: Instant, cost_per_second: u32, capacity: u64) {
+        let elapsed_ms =
+            u64::try_from(now.duration_since(self.updated_at).as_millis()).unwrap_or(u64::MAX);
+        // `cost_per_second` cost units per second is as many thousandths of units per millisecond
+        let restored = elapsed_ms.saturating_mul(u64::from(cost_per_second));
+        if restored > 0 {
+            self.available = self.available.saturating_add(restored).min(capacity);
+            self.updated_at = now;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use iroha_crypto::KeyPair;
+
+    use super::*;
+
+    fn account_id() -> AccountId {
+        AccountId::new(
+            "wonderland".parse().expect("Valid"),
+            KeyPair::random().into_parts().0,
+        )
+    }
+
+    #[test]
+    fn exhausted_budget_is_rejected() {
+        let budgets = QueryBudgets::new(10, 1, 5);
+        let (alice_id, bob_id) = (account_id(), account_id());
+
+        budgets
+            .charge_cost(&alice_id, 5)
+            .expect("Budget isn't exhausted");
+        budgets
+            .charge_cost(&alice_id, 5)
+            .expect("Budget isn't exhausted");
+        let error = budgets
+            .charge_cost(&alice_id, 1)
+            .expect_err("Budget is exhausted");
+        assert!(matches!(
+            error,
+            QueryExecutionFail::RateLimited(retry_after_ms) if (1..=100).contains(&retry_after_ms)
+        ));
+
+        // Budgets of different accounts are independent
+        budgets
+            .charge_cost(&bob_id, 10)
+            .expect("Budget isn't exhausted");
+    }
+
+    #[test]
+    fn budget_is_restored_over_time() {
+        let budgets = QueryBudgets::new(100, 1, 10);
+        let alice_id = account_id();
+
+        budgets
+            .charge_cost(&alice_id, 100)
+            .expect("Budget isn't exhausted");
+        budgets
+            .charge_cost(&alice_id, 10)
+            .expect_err("Budget is exhausted");
+
+        std::thread::sleep(Duration::from_millis(200));
+        budgets
+            .charge_cost(&alice_id, 10)
+            .expect("Budget is restored");
+    }
+
+    #[test]
+    fn zero_rate_disables_limit() {
+        let budgets = QueryBudgets::new(0, 1, 10);
+        let alice_id = account_id();
+
+        for _ in 0..100 {
+            budgets
+                .charge_cost(&alice_id, 10)
+                .expect("Limit is disabled");
+        }
+    }
+}
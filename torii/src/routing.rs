@@ -9,22 +9,35 @@
 use eyre::{eyre, WrapErr};
 use futures::TryStreamExt;
 use iroha_config::client_api::ConfigDTO;
-use iroha_core::{query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest};
+use iroha_core::{
+    query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest, state::StateView,
+};
+use iroha_crypto::{HashOf, KeyPair, SignatureOf};
 use iroha_data_model::{
     block::{
-        stream::{BlockMessage, BlockSubscriptionRequest},
+        stream::{BlockMessage, BlockSubscriptionRequest, BlockSummaryMessage},
         SignedBlock,
     },
     prelude::*,
-    query::{cursor::ForwardCursor, http, QueryOutputBox, QueryRequest},
+    query::{
+        cursor::ForwardCursor, http, stream::QueryStreamMessage, QueryOutputBox, QueryRequest,
+    },
+    transaction::TransactionPayload,
     BatchedResponse,
 };
 #[cfg(feature = "telemetry")]
 use iroha_telemetry::metrics::Status;
+use parity_scale_codec::DecodeAll as _;
 use tokio::task;
+use warp::hyper::body::Bytes;
 
 use super::*;
-use crate::stream::{Sink, Stream};
+use crate::{
+    chunk::TransactionChunks,
+    idempotency::IdempotencyKeys,
+    query_budget::QueryBudgets,
+    stream::{Sink, Stream},
+};
 
 /// Filter for warp which extracts [`http::ClientQueryRequest`]
 pub fn client_query_request(
@@ -49,11 +62,41 @@ pub async fn handle_transaction(
     chain_id: Arc<ChainId>,
     queue: Arc<Queue>,
     state: Arc<State>,
+    idempotency_keys: Arc<IdempotencyKeys>,
+    idempotency_key: Option<String>,
     transaction: SignedTransaction,
-) -> Result<Empty> {
+) -> Result<Scale<HashOf<SignedTransaction>>> {
+    let hash = transaction.hash();
+    let Some(idempotency_key) = idempotency_key else {
+        return push_transaction(&chain_id, &queue, &state, transaction).map(|()| Scale(hash));
+    };
+
+    let authority = transaction.authority().clone();
+    if let Err(original_hash) = idempotency_keys.reserve(&authority, idempotency_key.clone(), hash)
+    {
+        iroha_logger::debug!(
+            tx_hash=%hash, %original_hash,
+            "Transaction resubmitted under the same idempotency key"
+        );
+        return Ok(Scale(original_hash));
+    }
+    push_transaction(&chain_id, &queue, &state, transaction)
+        .map(|()| Scale(hash))
+        .map_err(|err| {
+            idempotency_keys.release(&authority, idempotency_key);
+            err
+        })
+}
+
+fn push_transaction(
+    chain_id: &ChainId,
+    queue: &Queue,
+    state: &State,
+    transaction: SignedTransaction,
+) -> Result<()> {
     let state_view = state.view();
     let transaction_limits = state_view.config.transaction_limits;
-    let transaction = AcceptedTransaction::accept(transaction, &chain_id, &transaction_limits)
+    let transaction = AcceptedTransaction::accept(transaction, chain_id, &transaction_limits)
         .map_err(Error::AcceptTransaction)?;
     queue
         .push(transaction, &state_view)
@@ -66,29 +109,209 @@ pub async fn handle_transaction(
             Box::new(err)
         })
         .map_err(Error::PushIntoQueue)
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_transaction_chunk(
+    chain_id: Arc<ChainId>,
+    queue: Arc<Queue>,
+    state: Arc<State>,
+    chunks: Arc<TransactionChunks>,
+    body: Bytes,
+) -> Result<Empty> {
+    let chunk = TransactionChunk::decode_all(&mut body.as_ref()).map_err(Error::DecodeBody)?;
+    match chunks.insert(chunk)? {
+        Some(transaction) => {
+            push_transaction(&chain_id, &queue, &state, transaction).map(|()| Empty)
+        }
+        None => Ok(Empty),
+    }
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_simulate_transaction(
+    chain_id: Arc<ChainId>,
+    state: Arc<State>,
+    transaction: SignedTransaction,
+) -> Result<Scale<TransactionSimulation>> {
+    let handle = task::spawn_blocking(move || -> Result<TransactionSimulation> {
+        let mut state_block = state.block();
+        let transaction_limits = state_block.config.transaction_limits;
+        let transaction = AcceptedTransaction::accept(transaction, &chain_id, &transaction_limits)
+            .map_err(Error::AcceptTransaction)?;
+        // `state_block` is dropped without being committed, so none of the changes persist
+        Ok(state_block
+            .transaction_executor()
+            .simulate(transaction, &mut state_block))
+    });
+    handle
+        .await
+        .expect("Failed to join transaction simulation task")
+        .map(Scale)
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_can_account_execute(
+    state: Arc<State>,
+    body: Bytes,
+) -> Result<Scale<ExecutionVerdict>> {
+    let request =
+        SignedCanAccountExecute::decode_all(&mut body.as_ref()).map_err(Error::DecodeBody)?;
+    let handle = task::spawn_blocking(move || state.check_execution(request));
+    handle
+        .await
+        .expect("Failed to join execution check task")
+        .map(Scale)
+        .map_err(Error::Query)
+}
+
+/// Query params used to select pending transactions of multisignature accounts
+#[derive(serde::Deserialize)]
+pub struct PendingTransactionsParams {
+    /// Only return the transactions of this account
+    #[serde(default)]
+    authority: Option<AccountId>,
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_pending_transactions(
+    queue: Arc<Queue>,
+    PendingTransactionsParams { authority }: PendingTransactionsParams,
+) -> Scale<Vec<SignedTransaction>> {
+    Scale(
+        queue
+            .pending_multisig_transactions(authority.as_ref())
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+    )
+}
+
+/// Query params used to select transactions in the queue
+#[derive(serde::Deserialize)]
+pub struct QueuedTransactionsParams {
+    /// Only return the transactions of this account
+    #[serde(default)]
+    authority: Option<AccountId>,
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_queued_transactions(
+    queue: Arc<Queue>,
+    state: Arc<State>,
+    QueuedTransactionsParams { authority }: QueuedTransactionsParams,
+) -> Scale<Vec<SignedTransaction>> {
+    let state_view = state.view();
+    Scale(
+        queue
+            .all_transactions(&state_view)
+            .filter(|tx| {
+                authority
+                    .as_ref()
+                    .map_or(true, |authority| tx.as_ref().authority() == authority)
+            })
+            .map(Into::into)
+            .collect(),
+    )
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_queue_status(queue: Arc<Queue>, state: Arc<State>) -> Scale<QueueStatus> {
+    Scale(queue.status(&state.view()))
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_cancel_transaction(queue: Arc<Queue>, body: Bytes) -> Result<Empty> {
+    let cancellation =
+        CancelTransaction::decode_all(&mut body.as_ref()).map_err(Error::DecodeBody)?;
+    let hash = *cancellation.hash();
+    queue
+        .cancel(cancellation)
+        .map_err(|err| {
+            iroha_logger::warn!(tx_hash=%hash, ?err, "Failed to cancel transaction");
+
+            Box::new(err)
+        })
+        .map_err(Error::PushIntoQueue)
+        .map(|()| Empty)
+}
+
+/// Query params used to select the pending transaction a signature is attached to
+#[derive(serde::Deserialize)]
+pub struct PendingSignatureParams {
+    /// Hash of the transaction payload
+    hash: HashOf<TransactionPayload>,
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_pending_transaction_signature(
+    queue: Arc<Queue>,
+    state: Arc<State>,
+    PendingSignatureParams { hash }: PendingSignatureParams,
+    body: Bytes,
+) -> Result<Empty> {
+    let signature = SignatureOf::<TransactionPayload>::decode_all(&mut body.as_ref())
+        .map_err(Error::DecodeBody)?;
+    let state_view = state.view();
+    queue
+        .attach_signature(hash, signature, &state_view)
+        .map_err(|err| {
+            iroha_logger::warn!(payload_hash=%hash, ?err, "Failed to attach signature");
+
+            Box::new(err)
+        })
+        .map_err(Error::PushIntoQueue)
         .map(|()| Empty)
 }
 
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
+    query_budgets: Arc<QueryBudgets>,
     state: Arc<State>,
     query_request: http::ClientQueryRequest,
 ) -> Result<Scale<BatchedResponse<QueryOutputBox>>> {
+    let handle = task::spawn_blocking(move || {
+        execute_query_request(
+            &live_query_store,
+            &query_budgets,
+            &state.view(),
+            query_request.0,
+        )
+    });
+    handle
+        .await
+        .expect("Failed to join query handling task")
+        .map(Scale)
+        .map_err(Into::into)
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_signed_queries(
+    key_pair: KeyPair,
+    live_query_store: LiveQueryStoreHandle,
+    query_budgets: Arc<QueryBudgets>,
+    state: Arc<State>,
+    query_request: http::ClientQueryRequest,
+) -> Result<Scale<http::SignedQueryResponse>> {
     let handle = task::spawn_blocking(move || {
         let state_view = state.view();
-        match query_request.0 {
-            QueryRequest::Query(signed_query) => {
-                let valid_query = ValidQueryRequest::validate(signed_query, &state_view)?;
-                let query_output = valid_query.execute_and_process(&state_view)?;
-                live_query_store
-                    .handle_query_output(query_output)
-                    .map_err(ValidationFail::from)
-            }
-            QueryRequest::Cursor(cursor) => live_query_store
-                .handle_query_cursor(cursor)
-                .map_err(ValidationFail::from),
-        }
+        let response = execute_query_request(
+            &live_query_store,
+            &query_budgets,
+            &state_view,
+            query_request.0,
+        )?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Failed to get the current system time");
+
+        Ok::<_, ValidationFail>(http::SignedQueryResponse::new(
+            response,
+            state_view.height(),
+            timestamp,
+            &key_pair,
+        ))
     });
     handle
         .await
@@ -97,6 +320,75 @@ pub async fn handle_queries(
         .map_err(Into::into)
 }
 
+/// Execute the query, charging its authority for it, or fetch the next batch of a query executed before.
+///
+/// Fetching by cursor is free since the query was charged for when it was executed.
+fn execute_query_request(
+    live_query_store: &LiveQueryStoreHandle,
+    query_budgets: &QueryBudgets,
+    state_view: &StateView,
+    request: QueryRequest<SignedQuery>,
+) -> Result<BatchedResponse<QueryOutputBox>, ValidationFail> {
+    match request {
+        QueryRequest::Query(signed_query) => {
+            let valid_query = ValidQueryRequest::validate(signed_query, state_view)?;
+            let query_output = valid_query.execute(state_view)?;
+            query_budgets.charge(valid_query.authority(), &query_output)?;
+            let query_output = valid_query.process(query_output)?;
+            live_query_store
+                .handle_query_output(query_output)
+                .map_err(ValidationFail::from)
+        }
+        QueryRequest::Cursor(cursor) => live_query_store
+            .handle_query_cursor(cursor)
+            .map_err(ValidationFail::from),
+    }
+}
+
+/// Send the output of the query received through `stream` batch by batch.
+///
+/// The next batch is taken from the live query store only after the previous one is sent,
+/// so a slow client doesn't make the peer hold more than one batch per query in flight.
+#[iroha_futures::telemetry_future]
+pub async fn handle_queries_stream(
+    live_query_store: LiveQueryStoreHandle,
+    query_budgets: Arc<QueryBudgets>,
+    state: Arc<State>,
+    mut stream: WebSocket,
+) -> eyre::Result<()> {
+    let signed_query: SignedQuery = stream.recv().await?;
+
+    let mut request = QueryRequest::Query(signed_query);
+    loop {
+        let live_query_store = live_query_store.clone();
+        let query_budgets = Arc::clone(&query_budgets);
+        let state = Arc::clone(&state);
+        let response = task::spawn_blocking(move || {
+            execute_query_request(&live_query_store, &query_budgets, &state.view(), request)
+        })
+        .await
+        .expect("Failed to join query handling task");
+
+        match response {
+            Ok(response) => {
+                let BatchedResponse::V1(ref batch) = response;
+                let cursor = batch.cursor().clone();
+                stream.send(QueryStreamMessage::Batch(response)).await?;
+                if cursor.cursor().is_none() {
+                    break;
+                }
+                request = QueryRequest::Cursor(cursor);
+            }
+            Err(error) => {
+                stream.send(QueryStreamMessage::Error(error)).await?;
+                break;
+            }
+        }
+    }
+
+    stream.close().await.map_err(Into::into)
+}
+
 #[derive(serde::Serialize)]
 #[non_exhaustive]
 enum Health {
@@ -127,7 +419,17 @@ pub async fn handle_post_configuration(kiso: KisoHandle, value: ConfigDTO) -> Re
 
 #[iroha_futures::telemetry_future]
 pub async fn handle_blocks_stream(kura: Arc<Kura>, mut stream: WebSocket) -> eyre::Result<()> {
-    let BlockSubscriptionRequest(mut from_height) = stream.recv().await?;
+    let BlockSubscriptionRequest {
+        mut from_height,
+        full_blocks,
+    } = stream.recv().await?;
+
+    if from_height.get() <= kura.pruned_height() {
+        eyre::bail!(
+            "Blocks up to height {} were pruned on this non-archival peer",
+            kura.pruned_height()
+        );
+    }
 
     let mut interval = tokio::time::interval(std::time::Duration::from_millis(10));
     loop {
@@ -154,10 +456,14 @@ pub async fn handle_blocks_stream(kura: Arc<Kura>, mut stream: WebSocket) -> eyr
             // This branch sends blocks
             _ = interval.tick() => {
                 if let Some(block) = kura.get_block_by_height(from_height.get()) {
-                    stream
-                        // TODO: to avoid clone `BlockMessage` could be split into sending and receiving parts
-                        .send(BlockMessage(SignedBlock::clone(&block)))
-                        .await?;
+                    if full_blocks {
+                        stream
+                            // TODO: to avoid clone `BlockMessage` could be split into sending and receiving parts
+                            .send(BlockMessage(SignedBlock::clone(&block)))
+                            .await?;
+                    } else {
+                        stream.send(BlockSummaryMessage(block.as_ref().into())).await?;
+                    }
                     from_height = from_height.checked_add(1).expect("Maximum block height is achieved.");
                 }
             }
@@ -171,8 +477,10 @@ pub async fn handle_blocks_stream(kura: Arc<Kura>, mut stream: WebSocket) -> eyr
 pub mod subscription {
     //! Contains the `handle_subscription` functions and used for general routing.
 
+    use tokio::sync::broadcast::error::RecvError;
+
     use super::*;
-    use crate::event;
+    use crate::{event, event_delivery::EventDeliveries};
 
     /// Type for any error during subscription handling
     #[derive(Debug, displaydoc::Display, thiserror::Error)]
@@ -180,7 +488,7 @@ pub mod subscription {
         /// Event consumption resulted in an error
         Consumer(#[from] Box<event::Error>),
         /// Event reception error
-        Event(#[from] tokio::sync::broadcast::error::RecvError),
+        Event(#[from] RecvError),
         /// `WebSocket` error
         WebSocket(#[from] warp::Error),
         /// A `Close` message is received. Not strictly an Error
@@ -207,13 +515,24 @@ pub mod subscription {
     /// Subscribes `stream` for `events` filtered by filter that is
     /// received through the `stream`
     ///
+    /// If the request specifies a block height, the pipeline events of the blocks
+    /// committed starting from it are replayed from `kura` first
+    ///
     /// There should be a [`warp::filters::ws::Message::close()`]
     /// message to end subscription
     #[iroha_futures::telemetry_future]
-    pub async fn handle_subscription(events: EventsSender, stream: WebSocket) -> eyre::Result<()> {
-        let mut consumer = event::Consumer::new(stream).await?;
-
-        match subscribe_forever(events, &mut consumer).await {
+    pub async fn handle_subscription(
+        events: EventsSender,
+        kura: Arc<Kura>,
+        deliveries: Arc<EventDeliveries>,
+        stream: WebSocket,
+    ) -> eyre::Result<()> {
+        let mut consumer = event::Consumer::new(stream, deliveries).await?;
+        let mut receiver = consumer.subscribe(&events).await?;
+
+        let result = subscribe_forever(&mut receiver, &kura, &mut consumer).await;
+        consumer.unsubscribe(receiver);
+        match result {
             Ok(()) | Err(Error::CloseMessage) => consumer.close_stream().await.map_err(Into::into),
             Err(err) => Err(err.into()),
         }
@@ -223,8 +542,13 @@ pub mod subscription {
     ///
     /// Ideally should return `Result<!>` cause it either runs forever
     /// either returns `Err` variant
-    async fn subscribe_forever(events: EventsSender, consumer: &mut event::Consumer) -> Result<()> {
-        let mut events = events.subscribe();
+    async fn subscribe_forever(
+        events: &mut tokio::sync::broadcast::Receiver<EventBox>,
+        kura: &Kura,
+        consumer: &mut event::Consumer,
+    ) -> Result<()> {
+        // Events are received since the subscription, so that no event is missed while replaying
+        let replayed_height = replay_committed_events(kura, consumer).await?;
 
         loop {
             tokio::select! {
@@ -237,15 +561,57 @@ pub mod subscription {
                 }
                 // This branch catches and sends events
                 event = events.recv() => {
-                    let event = event?;
+                    let event = match event {
+                        Ok(event) => event,
+                        Err(RecvError::Lagged(skipped)) => {
+                            consumer.lagged(skipped)?;
+                            continue;
+                        }
+                        Err(error) => return Err(error.into()),
+                    };
                     iroha_logger::trace!(?event);
-                    consumer.consume(event).await?;
+                    if !is_replayed(&event, replayed_height) {
+                        consumer.consume(event).await?;
+                    }
                 }
                 // Else branch to prevent panic
                 else => ()
             }
         }
     }
+
+    /// Replay pipeline events of the blocks committed starting from the height requested by `consumer`
+    ///
+    /// Returns the height of the last replayed block
+    async fn replay_committed_events(kura: &Kura, consumer: &mut event::Consumer) -> Result<u64> {
+        let Some(from_height) = consumer.from_block_height() else {
+            return Ok(0);
+        };
+
+        // Events of blocks whose bodies were pruned can't be replayed
+        let mut height = from_height.get().max(kura.pruned_height() + 1);
+        while let Some(block) = kura.get_block_by_height(height) {
+            for event in iroha_core::block::committed_block_events(&block) {
+                consumer.consume(event.into()).await?;
+            }
+            height += 1;
+        }
+
+        Ok(height - 1)
+    }
+
+    /// Whether the pipeline `event` belongs to a block that was already replayed
+    fn is_replayed(event: &EventBox, replayed_height: u64) -> bool {
+        match event {
+            EventBox::Pipeline(PipelineEventBox::Block(event)) => {
+                event.header().height() <= replayed_height
+            }
+            EventBox::Pipeline(PipelineEventBox::Transaction(event)) => event
+                .block_height()
+                .is_some_and(|height| height <= replayed_height),
+            _ => false,
+        }
+    }
 }
 
 #[iroha_futures::telemetry_future]
@@ -0,0 +1,315 @@
+//! Harness to test WASM smart contracts and triggers against an in-memory world state,
+//! without starting a network.
+//!
+//! Contracts are built for the `wasm32-unknown-unknown` target with
+//! [`iroha_smart_contract`](https://docs.rs/iroha_smart_contract) and run by the same
+//! runtime as on a peer. See [`TestWorld`] for more details.
+
+use iroha_core::{
+    kura::Kura,
+    query::store::LiveQueryStore,
+    smartcontracts::wasm,
+    state::{State, StateView},
+    tx::{AcceptTransactionFail, AcceptedTransaction, TransactionExecutor},
+    PeersIds, World,
+};
+use iroha_crypto::KeyPair;
+use iroha_data_model::{
+    account::AccountId,
+    domain::Domain,
+    events::EventBox,
+    isi::Instruction,
+    transaction::{Executable, TransactionBuilder, TransactionSimulation, WasmSmartContract},
+    trigger::TriggerId,
+    ChainId,
+};
+
+/// In-memory world state to run smart contracts and triggers against.
+///
+/// Transactions are validated by the executor of the world, the initial one permitting
+/// everything unless it was upgraded. Changes are kept only if the execution succeeds.
+///
+/// # Example
+///
+/// ```no_run
+/// use iroha_crypto::KeyPair;
+/// use iroha_data_model::prelude::*;
+/// use iroha_wasm_test_harness::TestWorld;
+///
+/// let key_pair = KeyPair::random();
+/// let domain_id: DomainId = "wonderland".parse().unwrap();
+/// let alice_id = AccountId::new(domain_id.clone(), key_pair.public_key().clone());
+/// let mut world = TestWorld::new([]);
+/// let registration = world
+///     .submit_instructions(&alice_id, &key_pair, [Register::domain(Domain::new(domain_id))])
+///     .unwrap();
+/// assert!(registration.error.is_none());
+///
+/// let wasm = std::fs::read("path/to/smart_contract.wasm").unwrap();
+/// let execution = world.execute_smart_contract(&alice_id, &key_pair, wasm).unwrap();
+/// assert!(execution.error.is_none(), "{:?}", execution.logs);
+/// ```
+pub struct TestWorld {
+    state: State,
+    chain_id: ChainId,
+    /// Runs the store of live queries, which iterable queries of contracts are kept in
+    _runtime: tokio::runtime::Runtime,
+}
+
+impl TestWorld {
+    /// Create a world consisting of `domains`.
+    ///
+    /// # Panics
+    /// If called from an asynchronous context, or the runtime of the query store can't be started.
+    pub fn new(domains: impl IntoIterator<Item = Domain>) -> Self {
+        Self::with_world(World::with(domains, PeersIds::new()))
+    }
+
+    /// Create a test world out of `world`.
+    ///
+    /// # Panics
+    /// See [`Self::new`].
+    pub fn with_world(world: World) -> Self {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .expect("Failed to start the runtime of the query store");
+        let query_handle = {
+            let _guard = runtime.enter();
+            LiveQueryStore::test().start()
+        };
+
+        Self {
+            state: State::new(world, Kura::blank_kura_for_testing(), query_handle),
+            chain_id: ChainId::from("00000000-0000-0000-0000-000000000000"),
+            _runtime: runtime,
+        }
+    }
+
+    /// Execute `instructions` as `authority`, e.g. to register the objects a contract works with.
+    ///
+    /// Returns the events the instructions emitted along with the rejection reason, if any.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't accepted, e.g. if `key_pair` doesn't belong to `authority`.
+    pub fn submit_instructions(
+        &mut self,
+        authority: &AccountId,
+        key_pair: &KeyPair,
+        instructions: impl IntoIterator<Item = impl Instruction>,
+    ) -> Result<TransactionSimulation, AcceptTransactionFail> {
+        let executable = instructions.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.submit(authority, key_pair, executable.into())
+    }
+
+    /// Execute the compiled smart contract `wasm` as `authority`.
+    ///
+    /// Returns the events the contract emitted and the messages it logged
+    /// along with the rejection reason, if any.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't accepted, e.g. if the contract exceeds the size limit.
+    pub fn execute_smart_contract(
+        &mut self,
+        authority: &AccountId,
+        key_pair: &KeyPair,
+        wasm: impl Into<Vec<u8>>,
+    ) -> Result<TransactionSimulation, AcceptTransactionFail> {
+        let executable = WasmSmartContract::from_compiled(wasm.into());
+        self.submit(authority, key_pair, executable.into())
+    }
+
+    /// Execute the compiled trigger `wasm` registered as `id` on behalf of `authority`
+    /// in response to `event`. The trigger doesn't have to be registered, unless it uses its storage.
+    ///
+    /// # Errors
+    /// Fails if the module can't be loaded, or the execution of the trigger fails.
+    pub fn execute_trigger(
+        &mut self,
+        id: &TriggerId,
+        authority: &AccountId,
+        wasm: impl AsRef<[u8]>,
+        event: impl Into<EventBox>,
+    ) -> Result<(), wasm::Error> {
+        let mut state_block = self.state.block();
+        let module = wasm::load_module(state_block.engine, wasm)?;
+        let mut runtime = wasm::RuntimeBuilder::<wasm::state::Trigger>::new()
+            .with_config(state_block.config.wasm_runtime)
+            .with_engine(state_block.engine.clone())
+            .build()?;
+        let mut limits_executor = wasm::LimitsExecutor::new(u64::MAX);
+
+        let mut state_transaction = state_block.transaction();
+        runtime.execute_trigger_module(
+            &mut state_transaction,
+            id,
+            authority.clone(),
+            &module,
+            event.into(),
+            &mut limits_executor,
+        )?;
+        state_transaction.apply();
+        state_block.commit();
+
+        Ok(())
+    }
+
+    /// View of the current state, e.g. to run queries against it with
+    /// [`ValidQuery`](iroha_core::smartcontracts::ValidQuery).
+    pub fn view(&self) -> StateView<'_> {
+        self.state.view()
+    }
+
+    fn submit(
+        &mut self,
+        authority: &AccountId,
+        key_pair: &KeyPair,
+        executable: Executable,
+    ) -> Result<TransactionSimulation, AcceptTransactionFail> {
+        let tx = TransactionBuilder::new(self.chain_id.clone(), authority.clone())
+            .with_executable(executable)
+            .sign(key_pair);
+
+        let mut state_block = self.state.block();
+        let transaction_limits = state_block.config.transaction_limits;
+        let tx = AcceptedTransaction::accept(tx, &self.chain_id, &transaction_limits)?;
+        let simulation =
+            TransactionExecutor::new(transaction_limits).simulate(tx, &mut state_block);
+        if simulation.error.is_none() {
+            state_block.commit();
+        }
+
+        Ok(simulation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write as _;
+
+    use iroha_core::{
+        smartcontracts::ValidQuery as _,
+        state::{StateReadOnly as _, WorldReadOnly as _},
+    };
+    use iroha_data_model::{isi::InstructionBox, metadata::MetadataValueBox, prelude::*};
+    use parity_scale_codec::Encode;
+    use test_samples::gen_account_in;
+
+    use super::*;
+
+    /// Module whose `main_fn_name` function executes `instruction` and fails if it's rejected.
+    fn module_executing(main_fn_name: &str, instruction: impl Into<InstructionBox>) -> String {
+        let encoded = instruction.into().encode();
+        let mut data = String::with_capacity(3 * encoded.len());
+        for byte in &encoded {
+            write!(data, "\\{byte:02x}").unwrap();
+        }
+
+        format!(
+            r#"
+            (module
+                (import "iroha" "execute_instruction"
+                    (func $exec_fn (param i32 i32) (result i32)))
+
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{data}")
+
+                ;; Allocator which never frees
+                (global $mem_size (mut i32) i32.const {len})
+                (func (export "_iroha_smart_contract_alloc") (param $size i32) (result i32)
+                    global.get $mem_size
+                    (global.set $mem_size
+                        (i32.add (global.get $mem_size) (local.get $size))))
+                (func (export "_iroha_smart_contract_dealloc") (param $size i32) (param $len i32)
+                    nop)
+
+                (func (export "{main_fn_name}") (param)
+                    ;; The encoded result follows its length, its first byte is 1 for an error
+                    (if (i32.load8_u offset=4 (call $exec_fn (i32.const 0) (i32.const {len})))
+                        (then unreachable))))
+            "#,
+            len = encoded.len(),
+        )
+    }
+
+    fn test_world(authority: &AccountId) -> TestWorld {
+        let domain = Domain::new(authority.domain_id.clone()).build(authority);
+        let mut world = TestWorld::new([domain]);
+        let (genesis_id, genesis_key_pair) = gen_account_in("wonderland");
+        let registration = world
+            .submit_instructions(
+                &genesis_id,
+                &genesis_key_pair,
+                [Register::account(Account::new(authority.clone()))],
+            )
+            .unwrap();
+        assert!(registration.error.is_none(), "{:?}", registration.error);
+        world
+    }
+
+    fn metadata_value(world: &TestWorld, account_id: &AccountId, key: &Name) -> MetadataValueBox {
+        FindAccountKeyValueByIdAndKey::new(account_id.clone(), key.clone())
+            .execute(&world.view())
+            .unwrap()
+    }
+
+    #[test]
+    fn smart_contract_changes_are_committed() {
+        let (alice_id, alice_key_pair) = gen_account_in("wonderland");
+        let mut world = test_world(&alice_id);
+        let key: Name = "key".parse().unwrap();
+        let contract = module_executing(
+            "_iroha_smart_contract_main",
+            SetKeyValue::account(alice_id.clone(), key.clone(), 1_u32),
+        );
+
+        let execution = world
+            .execute_smart_contract(&alice_id, &alice_key_pair, contract)
+            .unwrap();
+
+        assert!(execution.error.is_none(), "{:?}", execution.error);
+        assert!(!execution.events.is_empty());
+        assert_eq!(metadata_value(&world, &alice_id, &key), 1_u32.into());
+    }
+
+    #[test]
+    fn rejected_smart_contract_changes_are_discarded() {
+        let (alice_id, alice_key_pair) = gen_account_in("wonderland");
+        let (mad_hatter_id, _) = gen_account_in("wonderland");
+        let mut world = test_world(&alice_id);
+        let contract = module_executing(
+            "_iroha_smart_contract_main",
+            SetKeyValue::account(mad_hatter_id.clone(), "key".parse().unwrap(), 1_u32),
+        );
+
+        let execution = world
+            .execute_smart_contract(&alice_id, &alice_key_pair, contract)
+            .unwrap();
+
+        assert!(execution.error.is_some());
+        assert!(world.view().world().account(&mad_hatter_id).is_err());
+    }
+
+    #[test]
+    fn trigger_changes_are_committed() {
+        let (alice_id, _) = gen_account_in("wonderland");
+        let mut world = test_world(&alice_id);
+        let trigger_id: TriggerId = "increment".parse().unwrap();
+        let key: Name = "key".parse().unwrap();
+        let trigger = module_executing(
+            "_iroha_trigger_main",
+            SetKeyValue::account(alice_id.clone(), key.clone(), 2_u32),
+        );
+        let event = ExecuteTriggerEvent {
+            trigger_id: trigger_id.clone(),
+            authority: alice_id.clone(),
+        };
+
+        world
+            .execute_trigger(&trigger_id, &alice_id, trigger, event)
+            .unwrap();
+
+        assert_eq!(metadata_value(&world, &alice_id, &key), 2_u32.into());
+    }
+}